@@ -0,0 +1,231 @@
+//! 파싱/변환 hot path 벤치마크
+//!
+//! * `parse` - 포인트 클라우드/설정 패킷 파싱 (`KanaviMobilityParser::parse`)
+//! * `projection` - 거리 데이터 -> 3차원 좌표 변환 (`project_points` 투영 표 + SIMD, `scalar` 는 포인트별 삼각함수 계산)
+//! * `frame` - 제품 라인별 전체 채널 패킷 파싱 (1 프레임)
+//! * `bincode` - UDP -> WebSocket 전달 데이터 인코딩/디코딩
+//! * `throughput` - N 대 LiDAR x M Hz 의 1초 분량 패킷을 파싱 -> 인코딩 -> 디코딩
+//! * `voxel` - N 대 LiDAR 1 프레임의 포인트를 하나의 복셀 격자로 누적 (`voxel_keys` + `VoxelGrid`)
+
+use std::hint::black_box;
+use std::net::Ipv4Addr;
+
+use bincode::config::standard;
+use bincode::{decode_from_slice, encode_to_vec};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use lidar_server::lidar::kanavi_mobility::codec::{encode, MODE_CONFIG, MODE_POINT_CLOUD};
+use lidar_server::lidar::kanavi_mobility::{
+    project_points, project_points_scalar, KanaviMobilityData, KanaviMobilityParser,
+};
+use lidar_server::lidar::{LiDARParser, PointCloud};
+use lidar_server::processing::{voxel_keys, VoxelGrid};
+
+const LIDAR_IP: Ipv4Addr = Ipv4Addr::new(192, 168, 123, 200);
+
+/// 제품 라인별 (채널 수, 채널당 포인트 수)
+fn layout(product_line: u8) -> (u8, usize) {
+    match product_line {
+        2 | 3 => (2, 480),
+        7 => (1, 1080),
+        _ => (4, 400),
+    }
+}
+
+fn distances(points: usize) -> Vec<u8> {
+    (0..points)
+        .flat_map(|i| [(i % 50) as u8 + 1, (i % 100) as u8])
+        .collect()
+}
+
+fn point_cloud_packet(product_line: u8, lidar_id: u8, ch: u8) -> Vec<u8> {
+    let (_, points) = layout(product_line);
+    encode(
+        product_line,
+        lidar_id,
+        MODE_POINT_CLOUD,
+        0xF0 | ch,
+        &distances(points),
+    )
+}
+
+fn frame_packets(product_line: u8, lidar_id: u8) -> Vec<Vec<u8>> {
+    let (channels, _) = layout(product_line);
+    (0..channels)
+        .map(|ch| point_cloud_packet(product_line, lidar_id, ch))
+        .collect()
+}
+
+fn parse_one(parser: &mut KanaviMobilityParser, packet: &[u8]) -> KanaviMobilityData {
+    let data = parser
+        .parse(LIDAR_IP, packet)
+        .frame()
+        .expect("valid packet");
+    let data = data
+        .as_any()
+        .downcast_ref::<KanaviMobilityData>()
+        .expect("Kanavi data");
+    decode_from_slice(&encode_to_vec(data, standard()).unwrap(), standard())
+        .unwrap()
+        .0
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for product_line in [1u8, 3, 7] {
+        let packet = point_cloud_packet(product_line, 0, 0);
+        group.throughput(Throughput::Bytes(packet.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("point_cloud", product_line),
+            &packet,
+            |b, packet| {
+                let mut parser = KanaviMobilityParser::new();
+                b.iter(|| parser.parse(LIDAR_IP, black_box(packet)).frame().unwrap());
+            },
+        );
+    }
+
+    let version_info = encode(3, 0, MODE_CONFIG, 0x71, &[1, 2, 3, 4, 5, 6, 0]);
+    group.throughput(Throughput::Bytes(version_info.len() as u64));
+    group.bench_function("config/version_info", |b| {
+        let mut parser = KanaviMobilityParser::new();
+        b.iter(|| {
+            parser
+                .parse(LIDAR_IP, black_box(&version_info))
+                .frame()
+                .unwrap()
+        });
+    });
+    group.finish();
+}
+
+fn bench_projection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("projection");
+    for product_line in [1u8, 3, 7] {
+        let (_, points) = layout(product_line);
+        let data = distances(points);
+        group.throughput(Throughput::Elements(points as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(product_line),
+            &data,
+            |b, data| {
+                b.iter(|| project_points(product_line, 0, black_box(data)));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("scalar", product_line),
+            &data,
+            |b, data| {
+                b.iter(|| project_points_scalar(product_line, 0, black_box(data)));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_frame(c: &mut Criterion) {
+    let mut group = c.benchmark_group("frame");
+    for product_line in [1u8, 3, 7] {
+        let packets = frame_packets(product_line, 0);
+        group.throughput(Throughput::Elements(packets.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(product_line),
+            &packets,
+            |b, packets| {
+                let mut parser = KanaviMobilityParser::new();
+                b.iter(|| {
+                    for packet in packets {
+                        black_box(parser.parse(LIDAR_IP, packet).frame().unwrap());
+                    }
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_bincode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bincode");
+    let mut parser = KanaviMobilityParser::new();
+    let data = parse_one(&mut parser, &point_cloud_packet(1, 0, 0));
+    let encoded = encode_to_vec(&data, standard()).unwrap();
+    group.throughput(Throughput::Bytes(encoded.len() as u64));
+    group.bench_function("encode", |b| {
+        b.iter(|| encode_to_vec(black_box(&data), standard()).unwrap());
+    });
+    group.bench_function("decode", |b| {
+        b.iter(|| {
+            decode_from_slice::<KanaviMobilityData, _>(black_box(&encoded), standard()).unwrap()
+        });
+    });
+    group.finish();
+}
+
+fn bench_throughput(c: &mut Criterion) {
+    const RATE_HZ: usize = 10;
+    let mut group = c.benchmark_group("throughput");
+    group.sample_size(10);
+    for sensors in [1u8, 8, 32] {
+        let packets: Vec<Vec<u8>> = (0..sensors)
+            .flat_map(|lidar_id| frame_packets(1, lidar_id))
+            .collect();
+        let per_second = packets.len() * RATE_HZ;
+        group.throughput(Throughput::Elements(per_second as u64));
+        group.bench_with_input(
+            BenchmarkId::new(format!("{}hz", RATE_HZ), sensors),
+            &packets,
+            |b, packets| {
+                let mut parsers: Vec<KanaviMobilityParser> =
+                    (0..sensors).map(|_| KanaviMobilityParser::new()).collect();
+                b.iter(|| {
+                    for _ in 0..RATE_HZ {
+                        for (i, packet) in packets.iter().enumerate() {
+                            let parser = &mut parsers[i % sensors as usize];
+                            black_box(parse_one(parser, packet));
+                        }
+                    }
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_voxel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("voxel");
+    let (channels, points) = layout(1);
+    let data = distances(points);
+    for sensors in [1usize, 4, 8] {
+        let clouds: Vec<PointCloud> = (0..sensors)
+            .flat_map(|_| (0..channels).map(|ch| project_points(1, ch, &data)))
+            .collect();
+        let total: usize = clouds.iter().map(|cloud| cloud.points.len()).sum();
+        group.throughput(Throughput::Elements(total as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(sensors),
+            &clouds,
+            |b, clouds| {
+                let mut keys = Vec::new();
+                b.iter(|| {
+                    let mut grid = VoxelGrid::new(0.1);
+                    for cloud in clouds {
+                        voxel_keys(&cloud.points, grid.size(), &mut keys);
+                        grid.insert(&cloud.points, &keys);
+                    }
+                    black_box(grid.centroids())
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_parse,
+    bench_projection,
+    bench_frame,
+    bench_bincode,
+    bench_throughput,
+    bench_voxel
+);
+criterion_main!(benches);
@@ -0,0 +1,353 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::lidar::LiDARKey;
+use crate::site::SiteConfig;
+use crate::ws::client::Roi;
+
+/// 알림 규칙 엔진 설정 구조체
+///
+/// # Examples
+/// ```json
+/// {
+///     "enabled": true,
+///     "rules": [
+///         { "name": "offline", "type": "device_offline", "after_ms": 30000 },
+///         { "name": "lane-1", "type": "zone_intrusion", "zone": { "type": "box", "min": [0.0, -1.5], "max": [20.0, 1.5] } },
+///         { "name": "speeding", "type": "over_speed", "max_speed": 16.7, "min_size": 2.0 }
+///     ],
+///     "sinks": [
+///         { "name": "hook", "type": "webhook", "url": "http://alerts.local/hook" },
+///         { "name": "ops", "type": "slack", "webhook_url": "https://hooks.slack.com/services/..." }
+///     ]
+/// }
+/// ```
+///
+/// # Fields
+/// * `enabled` - 알림 규칙 평가 사용 여부
+/// * `check_interval_ms` - 주기적 규칙 평가(장치 오프라인) 및 해제 확인 주기 (ms)
+/// * `clear_ms` - 조건이 이 시간 (ms) 동안 다시 만족되지 않으면 알림 해제
+/// * `rules` - 알림 규칙 목록
+/// * `sinks` - 알림을 전송할 알림 채널 목록 (webhook, Slack, 이메일)
+/// * `history` - 영역 침입 이벤트 디스크 보관 설정
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct AlertConfig {
+    pub enabled: bool,
+    pub check_interval_ms: u64,
+    pub clear_ms: u64,
+    pub rules: Vec<AlertRule>,
+    pub sinks: Vec<SinkConfig>,
+    pub history: EventHistoryConfig,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            check_interval_ms: 1000,
+            clear_ms: 5000,
+            rules: Vec::new(),
+            sinks: Vec::new(),
+            history: EventHistoryConfig::default(),
+        }
+    }
+}
+
+/// 영역 이벤트 디스크 보관 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 영역 침입 알림(발생/해제)을 디스크에 보관할지 여부
+/// * `dir` - 이벤트 파일 저장 디렉터리 (일 단위 `events-<day>.jsonl` 파일)
+/// * `retention_days` - 보관 기간 (일), 지난 파일은 삭제
+/// * `queue_size` - 저장 대기열 크기, 가득 차면 해당 이벤트는 저장하지 않음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct EventHistoryConfig {
+    pub enabled: bool,
+    pub dir: PathBuf,
+    pub retention_days: u64,
+    pub queue_size: usize,
+}
+
+impl Default for EventHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: PathBuf::from("events"),
+            retention_days: 30,
+            queue_size: 256,
+        }
+    }
+}
+
+impl AlertConfig {
+    /// 물체 추적이 필요한 규칙(과속)이 있는지 확인
+    pub fn uses_tracking(&self) -> bool {
+        self.enabled
+            && self
+                .rules
+                .iter()
+                .any(|rule| matches!(rule.kind, AlertKind::OverSpeed { .. }))
+    }
+
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 실패 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 알림 채널 이름 중복 확인
+    /// * 규칙이 참조하는 알림 채널이 존재하는지 확인
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, sink) in self.sinks.iter().enumerate() {
+            if self.sinks[..index]
+                .iter()
+                .any(|other| other.name == sink.name)
+            {
+                return Err(format!("Duplicate alert sink name: {}", sink.name));
+            }
+        }
+        for rule in &self.rules {
+            for name in rule.sinks.iter().flatten() {
+                if !self.sinks.iter().any(|sink| &sink.name == name) {
+                    return Err(format!(
+                        "Alert rule {} references unknown sink: {}",
+                        rule.name, name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 규칙이 참조하는 사이트의 LiDAR 를 규칙의 LiDAR 목록에 추가
+    ///
+    /// # Arguments
+    /// * `sites` - 사이트 설정
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 알 수 없는 사이트를 참조하면 에러 메시지
+    pub fn resolve_sites(&mut self, sites: &SiteConfig) -> Result<(), String> {
+        for rule in &mut self.rules {
+            let Some(names) = &rule.sites else {
+                continue;
+            };
+            let members = sites
+                .members(names)
+                .map_err(|e| format!("Alert rule {}: {}", rule.name, e))?;
+            let lidars = rule.lidars.get_or_insert_with(Vec::new);
+            for key in members {
+                if !lidars.contains(&key) {
+                    lidars.push(key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn default_rearm_ms() -> u64 {
+    60_000
+}
+
+/// 알림 규칙 구조체
+///
+/// # Fields
+/// * `name` - 규칙 이름 (알림에 포함)
+/// * `kind` - 규칙 종류 및 조건 (`type` 필드로 구분)
+/// * `lidars` - 규칙을 적용할 LiDAR 키 목록, None 이면 모든 LiDAR
+/// * `sites` - 규칙을 적용할 사이트 이름 목록, 설정 로드 시 사이트에 속한 LiDAR 를 `lidars` 에 추가
+/// * `rearm_ms` - 알림 발생 후 다시 발생할 수 있기까지의 최소 시간 (ms, 기본값 60000)
+/// * `sinks` - 알림을 전송할 알림 채널 이름 목록, None 이면 모든 알림 채널
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AlertRule {
+    pub name: String,
+    #[serde(flatten)]
+    pub kind: AlertKind,
+    #[serde(default)]
+    pub lidars: Option<Vec<LiDARKey>>,
+    #[serde(default)]
+    pub sites: Option<Vec<String>>,
+    #[serde(default = "default_rearm_ms")]
+    pub rearm_ms: u64,
+    #[serde(default)]
+    pub sinks: Option<Vec<String>>,
+}
+
+impl AlertRule {
+    /// 해당 LiDAR 에 적용되는 규칙인지 확인
+    pub fn applies_to(&self, key: &LiDARKey) -> bool {
+        match &self.lidars {
+            Some(lidars) => lidars.contains(key),
+            None => true,
+        }
+    }
+
+    /// 해당 알림 채널로 전송하는 규칙인지 확인
+    pub fn routes_to(&self, sink: &str) -> bool {
+        match &self.sinks {
+            Some(sinks) => sinks.iter().any(|name| name == sink),
+            None => true,
+        }
+    }
+}
+
+/// 알림 규칙 종류 열거형
+///
+/// # Variants
+/// * `DeviceOffline` - 마지막 수신 후 `after_ms` 이상 데이터가 없음
+/// * `NakRate` - 텔레메트리 샘플 기준 NAK 응답이 분당 `max_per_min` 초과 (텔레메트리 사용 시)
+/// * `ZoneIntrusion` - 한 프레임에서 `zone` 안의 포인트가 `min_points` 이상
+/// * `WindowContamination` - 반사가 없는 포인트 비율이 `min_no_return_ratio` 이상인 프레임이
+///   `frames` 번 연속 (창 오염 지속), 또는 오염 구간이 `recur_window_ms` 안에 `recur_count` 번 이상 시작
+///   (창 오염 반복, `recur_count` 가 0 이면 사용 안 함)
+/// * `OverSpeed` - 추적 중인 물체의 속력이 `max_speed` (m/s) 초과 (`zone` 이 있으면 영역 안의 물체만,
+///   `min_size` 가 있으면 크기(m)가 이 값 이상인 물체만, 좌표는 `objects` 이벤트와 같음)
+/// * `Quarantine` - 파싱 실패가 많아 송신 주소가 격리됨 (`udp.error_budget` 사용 시)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertKind {
+    DeviceOffline {
+        #[serde(default = "default_offline_ms")]
+        after_ms: u64,
+    },
+    NakRate {
+        max_per_min: f32,
+    },
+    ZoneIntrusion {
+        zone: Roi,
+        #[serde(default = "default_min_points")]
+        min_points: usize,
+    },
+    WindowContamination {
+        #[serde(default = "default_no_return_ratio")]
+        min_no_return_ratio: f32,
+        #[serde(default = "default_contamination_frames")]
+        frames: u32,
+        #[serde(default = "default_recur_count")]
+        recur_count: u32,
+        #[serde(default = "default_recur_window_ms")]
+        recur_window_ms: u64,
+    },
+    OverSpeed {
+        max_speed: f32,
+        #[serde(default)]
+        zone: Option<Roi>,
+        #[serde(default)]
+        min_size: Option<f32>,
+    },
+    Quarantine,
+}
+
+fn default_offline_ms() -> u64 {
+    30_000
+}
+
+fn default_min_points() -> usize {
+    1
+}
+
+fn default_no_return_ratio() -> f32 {
+    0.9
+}
+
+fn default_contamination_frames() -> u32 {
+    10
+}
+
+fn default_recur_count() -> u32 {
+    3
+}
+
+fn default_recur_window_ms() -> u64 {
+    3_600_000
+}
+
+/// 알림 채널 설정 구조체
+///
+/// # Fields
+/// * `name` - 알림 채널 이름 (규칙의 `sinks` 에서 참조)
+/// * `kind` - 알림 채널 종류 및 설정 (`type` 필드로 구분)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SinkConfig {
+    pub name: String,
+    #[serde(flatten)]
+    pub kind: SinkKind,
+}
+
+/// 알림 채널 종류 열거형
+///
+/// # Variants
+/// * `Webhook` - 알림 JSON 을 `url` 로 HTTP POST
+/// * `Slack` - Slack incoming webhook(`webhook_url`) 으로 알림 요약 메시지 전송
+/// * `Email` - SMTP 서버로 `to` 에게 알림 이메일 전송
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SinkKind {
+    Webhook {
+        url: String,
+        #[serde(default = "default_timeout_ms")]
+        timeout_ms: u64,
+    },
+    Slack {
+        webhook_url: String,
+        #[serde(default)]
+        channel: Option<String>,
+        #[serde(default = "default_timeout_ms")]
+        timeout_ms: u64,
+    },
+    Email(EmailConfig),
+}
+
+/// 이메일 알림 채널 설정 구조체
+///
+/// # Fields
+/// * `smtp_host` - SMTP 서버 주소
+/// * `smtp_port` - SMTP 서버 포트 (기본값 587)
+/// * `tls` - SMTP 연결 보안 방식 (기본값 `starttls`)
+/// * `username` - SMTP 인증 사용자 이름, None 이면 인증하지 않음
+/// * `password` - SMTP 인증 비밀번호
+/// * `from` - 보내는 사람 주소
+/// * `to` - 받는 사람 주소 목록
+/// * `timeout_ms` - 전송 제한 시간 (ms, 기본값 5000)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EmailConfig {
+    pub smtp_host: String,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub tls: SmtpTls,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+/// SMTP 연결 보안 방식 열거형
+///
+/// # Variants
+/// * `None` - 암호화하지 않음 (내부망 릴레이용)
+/// * `Starttls` - 평문 연결 후 STARTTLS 로 암호화
+/// * `Tls` - 처음부터 TLS 로 연결 (SMTPS)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpTls {
+    None,
+    #[default]
+    Starttls,
+    Tls,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+fn default_timeout_ms() -> u64 {
+    5000
+}
@@ -0,0 +1,528 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::net::Ipv4Addr;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+use crate::alert::config::{AlertConfig, AlertKind};
+use crate::alert::sink::{NotificationResult, Notifier};
+use crate::common::time::unix_time_ms;
+use crate::lidar::kanavi_mobility::KMConfigData;
+use crate::lidar::{LiDARInfo, LiDARKey, Point, PointCloud};
+use crate::processing::TrackedObjects;
+use crate::telemetry::TelemetrySample;
+
+/// 알림 상태 열거형
+///
+/// # Variants
+/// * `Firing` - 조건이 만족되어 알림 발생
+/// * `Resolved` - 조건이 `clear_ms` 동안 만족되지 않아 알림 해제
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertState {
+    Firing,
+    Resolved,
+}
+
+/// 알림 구조체 (WebSocket `alert` 이벤트 및 webhook 본문)
+///
+/// # Fields
+/// * `rule` - 규칙 이름
+/// * `kind` - 규칙 종류 (`device_offline`, `nak_rate`, `zone_intrusion`, `window_contamination`, `over_speed`)
+/// * `key` - 대상 LiDAR 키
+/// * `state` - 알림 상태
+/// * `message` - 알림 내용
+/// * `maintenance` - 권장 정비 조치 (창 오염 알림), 없으면 생략
+/// * `time_ms` - 알림 시각 (Unix time, ms)
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct Alert {
+    pub rule: String,
+    pub kind: String,
+    pub key: LiDARKey,
+    pub state: AlertState,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance: Option<String>,
+    pub time_ms: u64,
+}
+
+impl Alert {
+    /// 한 줄 요약 (Slack 메시지, 이메일 제목에 사용)
+    ///
+    /// # Examples
+    /// ```
+    /// // [FIRING] lane-1 (zone_intrusion) 192.168.123.200:5000 #1/0: 12 point(s) inside zone
+    /// // [FIRING] window (window_contamination) 192.168.123.200:5000 #1/0: ... (Clean the sensor window)
+    /// let text = alert.summary();
+    /// ```
+    pub fn summary(&self) -> String {
+        let state = match self.state {
+            AlertState::Firing => "FIRING",
+            AlertState::Resolved => "RESOLVED",
+        };
+        let mut summary = format!(
+            "[{}] {} ({}) {}:{} #{}/{}: {}",
+            state,
+            self.rule,
+            self.kind,
+            self.key.ip,
+            self.key.port,
+            self.key.product_line,
+            self.key.lidar_id,
+            self.message
+        );
+        if let Some(maintenance) = &self.maintenance {
+            summary.push_str(&format!(" ({})", maintenance));
+        }
+        summary
+    }
+}
+
+/// 규칙/LiDAR 별 알림 상태
+///
+/// # Fields
+/// * `streak` - 조건을 연속으로 만족한 프레임 수
+/// * `episodes` - `recur_window_ms` 안에 시작한 창 오염 구간의 시작 시각
+#[derive(Default)]
+struct RuleState {
+    firing: bool,
+    last_fired: Option<Instant>,
+    last_true: Option<Instant>,
+    streak: u32,
+    episodes: VecDeque<Instant>,
+}
+
+/// LiDAR 설정 응답에서 수집한 필터 상태
+///
+/// # Fields
+/// * `fog_filter` - 안개 필터 값 (0 이면 꺼짐)
+/// * `detection_mode` - 창 오염 감지 모드 (0 이면 꺼짐)
+#[derive(Debug, Default, Clone, Copy)]
+struct FilterState {
+    fog_filter: Option<u8>,
+    detection_mode: Option<u8>,
+}
+
+/// 알림 규칙 엔진
+///
+/// # Examples
+/// ```
+/// let engine = AlertEngine::new(config.alert.clone());
+/// let alerts = engine.observe_frame(key, lidar_data.get_points()).await;
+/// for alert in &alerts {
+///     engine.notify(alert);
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 프레임 규칙(영역 침입, 창 오염)은 수신한 포인트 클라우드로 평가
+/// * 창 오염은 오염 구간(연속 프레임)의 지속과 반복 빈도를 함께 추적하고, 알림에 권장 정비 조치 포함
+///   (설정 응답으로 받은 안개 필터, 창 오염 감지 모드 상태를 반영)
+/// * 과속 규칙은 물체 추적기가 계산한 확정 물체의 속력으로 평가
+/// * NAK 비율 규칙은 텔레메트리 샘플로, 장치 오프라인 규칙은 `check_interval_ms` 마다 장치 목록으로 평가
+/// * 중복 제거: 알림 발생 중에는 같은 규칙/LiDAR 의 알림을 다시 보내지 않음
+/// * 재무장: 알림 해제 후에도 마지막 발생 후 `rearm_ms` 가 지나야 다시 발생
+/// * 조건이 `clear_ms` 동안 만족되지 않으면 `resolved` 알림 발생
+/// * 알림은 규칙의 `sinks` 에 해당하는 알림 채널(webhook, Slack, 이메일)로 전송
+pub struct AlertEngine {
+    config: AlertConfig,
+    states: Mutex<HashMap<(usize, LiDARKey), RuleState>>,
+    filters: Mutex<HashMap<LiDARKey, FilterState>>,
+    notifier: Notifier,
+}
+
+impl AlertEngine {
+    pub fn new(config: AlertConfig) -> Self {
+        let notifier = Notifier::new(&config.sinks);
+        Self {
+            config,
+            states: Mutex::new(HashMap::new()),
+            filters: Mutex::new(HashMap::new()),
+            notifier,
+        }
+    }
+
+    pub fn config(&self) -> &AlertConfig {
+        &self.config
+    }
+
+    /// 수신한 설정 응답에서 안개 필터, 창 오염 감지 모드 상태 기록
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `config` - 설정 응답
+    pub async fn observe_config(&self, key: LiDARKey, config: &KMConfigData) {
+        let mut filters = self.filters.lock().await;
+        match config {
+            KMConfigData::FogFilter(filter) => {
+                filters.entry(key).or_default().fog_filter = Some(filter.filter_value());
+            }
+            KMConfigData::WindowContaminationDetectionMode(mode) => {
+                filters.entry(key).or_default().detection_mode = Some(mode.mode());
+            }
+            _ => {}
+        }
+    }
+
+    /// 수신한 포인트 클라우드로 프레임 규칙 평가
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `points` - 채널별 포인트 클라우드
+    ///
+    /// # Returns
+    /// * `Vec<Alert>` - 새로 발생한 알림
+    pub async fn observe_frame(&self, key: LiDARKey, points: &[PointCloud]) -> Vec<Alert> {
+        let mut states = self.states.lock().await;
+        let mut alerts = Vec::new();
+        for (index, rule) in self.config.rules.iter().enumerate() {
+            if !rule.applies_to(&key) {
+                continue;
+            }
+            let message = match &rule.kind {
+                AlertKind::ZoneIntrusion { zone, min_points } => {
+                    let count = points
+                        .iter()
+                        .flat_map(|cloud| cloud.points.iter())
+                        .filter(|point| zone.contains(point))
+                        .count();
+                    (count >= *min_points)
+                        .then(|| (format!("{} point(s) inside zone", count), None))
+                }
+                AlertKind::WindowContamination {
+                    min_no_return_ratio,
+                    frames,
+                    recur_count,
+                    recur_window_ms,
+                } => {
+                    let total: usize = points.iter().map(|cloud| cloud.points.len()).sum();
+                    if total == 0 {
+                        continue;
+                    }
+                    let no_return = points
+                        .iter()
+                        .flat_map(|cloud| cloud.points.iter())
+                        .filter(|point| point.x == 0.0 && point.y == 0.0 && point.z == 0.0)
+                        .count();
+                    let ratio = no_return as f32 / total as f32;
+                    let contaminated = ratio >= *min_no_return_ratio;
+                    let now = Instant::now();
+                    let window = Duration::from_millis(*recur_window_ms);
+                    let state = states.entry((index, key)).or_default();
+                    if contaminated {
+                        // 오염 구간 시작
+                        if state.streak == 0 {
+                            state.episodes.push_back(now);
+                        }
+                        state.streak = state.streak.saturating_add(1);
+                    } else {
+                        state.streak = 0;
+                    }
+                    while state
+                        .episodes
+                        .front()
+                        .is_some_and(|start| now.duration_since(*start) > window)
+                    {
+                        state.episodes.pop_front();
+                    }
+
+                    let filters = self.filters.lock().await.get(&key).copied();
+                    if state.streak >= *frames {
+                        Some((
+                            format!(
+                                "{:.0}% of points without return for {} frames",
+                                ratio * 100.0,
+                                state.streak
+                            ),
+                            Some(contamination_maintenance(false, filters)),
+                        ))
+                    } else if contaminated
+                        && *recur_count > 0
+                        && state.episodes.len() >= *recur_count as usize
+                    {
+                        Some((
+                            format!(
+                                "Window contamination recurred {} times in {} min",
+                                state.episodes.len(),
+                                recur_window_ms / 60_000
+                            ),
+                            Some(contamination_maintenance(true, filters)),
+                        ))
+                    } else {
+                        None
+                    }
+                }
+                _ => continue,
+            };
+            if let Some((message, maintenance)) = message {
+                alerts.extend(self.raise(&mut states, index, key, message, maintenance));
+            }
+        }
+        alerts
+    }
+
+    /// 추적 중인 물체의 속력으로 과속 규칙 평가
+    ///
+    /// # Arguments
+    /// * `objects` - 한 프레임의 확정 물체 목록
+    ///
+    /// # Returns
+    /// * `Vec<Alert>` - 새로 발생한 알림 (규칙에 해당하는 물체 중 가장 빠른 물체 기준)
+    pub async fn observe_objects(&self, objects: &TrackedObjects) -> Vec<Alert> {
+        let mut states = self.states.lock().await;
+        let mut alerts = Vec::new();
+        for (index, rule) in self.config.rules.iter().enumerate() {
+            let AlertKind::OverSpeed {
+                max_speed,
+                zone,
+                min_size,
+            } = &rule.kind
+            else {
+                continue;
+            };
+            if !rule.applies_to(&objects.key) {
+                continue;
+            }
+            let fastest = objects
+                .objects
+                .iter()
+                .filter(|object| object.speed > *max_speed)
+                .filter(|object| min_size.is_none_or(|min_size| object.size >= min_size))
+                .filter(|object| {
+                    zone.as_ref().is_none_or(|zone| {
+                        zone.contains(&Point {
+                            x: object.position[0],
+                            y: object.position[1],
+                            z: 0.0,
+                        })
+                    })
+                })
+                .max_by(|a, b| a.speed.total_cmp(&b.speed));
+            if let Some(object) = fastest {
+                let message = format!(
+                    "Object #{} at {:.1} m/s ({:.0} km/h) heading {:.0}° (max {:.1} m/s)",
+                    object.id,
+                    object.speed,
+                    object.speed * 3.6,
+                    object.heading,
+                    max_speed
+                );
+                alerts.extend(self.raise(&mut states, index, objects.key, message, None));
+            }
+        }
+        alerts
+    }
+
+    /// 텔레메트리 샘플로 NAK 비율 규칙 평가
+    ///
+    /// # Arguments
+    /// * `samples` - 이번 주기의 텔레메트리 샘플
+    /// * `interval_ms` - 샘플 주기 (ms)
+    ///
+    /// # Returns
+    /// * `Vec<Alert>` - 새로 발생한 알림
+    pub async fn observe_samples(
+        &self,
+        samples: &[TelemetrySample],
+        interval_ms: u64,
+    ) -> Vec<Alert> {
+        let mut states = self.states.lock().await;
+        let mut alerts = Vec::new();
+        for (index, rule) in self.config.rules.iter().enumerate() {
+            let AlertKind::NakRate { max_per_min } = rule.kind else {
+                continue;
+            };
+            for sample in samples.iter().filter(|sample| rule.applies_to(&sample.key)) {
+                let per_min = sample.nak_count as f32 * 60_000.0 / interval_ms.max(1) as f32;
+                if per_min > max_per_min {
+                    let message = format!("{:.1} NAK/min (max {:.1})", per_min, max_per_min);
+                    alerts.extend(self.raise(&mut states, index, sample.key, message, None));
+                }
+            }
+        }
+        alerts
+    }
+
+    /// 송신 주소 격리 규칙 평가
+    ///
+    /// # Arguments
+    /// * `quarantined` - 격리 중인 LiDAR 와 격리 내용 목록
+    ///
+    /// # Returns
+    /// * `Vec<Alert>` - 새로 발생한 알림
+    ///
+    /// # 동작 설명
+    /// * 주기마다 격리 중인 LiDAR 를 전달받아 평가, 격리가 해제되어 전달되지 않으면 `clear_ms` 후 해제
+    pub async fn observe_quarantines(&self, quarantined: &[(LiDARKey, String)]) -> Vec<Alert> {
+        let mut states = self.states.lock().await;
+        let mut alerts = Vec::new();
+        for (index, rule) in self.config.rules.iter().enumerate() {
+            if !matches!(rule.kind, AlertKind::Quarantine) {
+                continue;
+            }
+            for (key, message) in quarantined.iter().filter(|(key, _)| rule.applies_to(key)) {
+                alerts.extend(self.raise(&mut states, index, *key, message.clone(), None));
+            }
+        }
+        alerts
+    }
+
+    /// 장치 오프라인 규칙 평가 및 해제된 알림 확인
+    ///
+    /// # Arguments
+    /// * `lidars` - 등록된 LiDAR 장치 목록
+    ///
+    /// # Returns
+    /// * `Vec<Alert>` - 새로 발생하거나 해제된 알림
+    pub async fn check(&self, lidars: &[LiDARInfo]) -> Vec<Alert> {
+        let mut states = self.states.lock().await;
+        let mut alerts = Vec::new();
+        let now_ms = unix_time_ms();
+        for (index, rule) in self.config.rules.iter().enumerate() {
+            let AlertKind::DeviceOffline { after_ms } = rule.kind else {
+                continue;
+            };
+            // 관리자가 비활성화한 LiDAR 는 데이터를 버리므로 오프라인으로 보지 않음
+            for info in lidars
+                .iter()
+                .filter(|info| !info.disabled && rule.applies_to(&info.key))
+            {
+                let silent_ms = now_ms.saturating_sub(info.last_seen);
+                if silent_ms > after_ms {
+                    let message = format!("No data for {} s", silent_ms / 1000);
+                    alerts.extend(self.raise(&mut states, index, info.key, message, None));
+                }
+            }
+        }
+
+        let clear = Duration::from_millis(self.config.clear_ms);
+        for (&(index, key), state) in states.iter_mut() {
+            if state.firing && state.last_true.is_none_or(|last| last.elapsed() >= clear) {
+                state.firing = false;
+                let rule = &self.config.rules[index];
+                alerts.push(Alert {
+                    rule: rule.name.clone(),
+                    kind: kind_name(&rule.kind).to_string(),
+                    key,
+                    state: AlertState::Resolved,
+                    message: "Condition cleared".to_string(),
+                    maintenance: None,
+                    time_ms: now_ms,
+                });
+            }
+        }
+        alerts
+    }
+
+    /// 조건이 만족된 규칙 처리 (중복 제거 및 재무장 확인)
+    fn raise(
+        &self,
+        states: &mut HashMap<(usize, LiDARKey), RuleState>,
+        index: usize,
+        key: LiDARKey,
+        message: String,
+        maintenance: Option<String>,
+    ) -> Option<Alert> {
+        let rule = &self.config.rules[index];
+        let state = states.entry((index, key)).or_default();
+        state.last_true = Some(Instant::now());
+        if state.firing
+            || state
+                .last_fired
+                .is_some_and(|last| last.elapsed() < Duration::from_millis(rule.rearm_ms))
+        {
+            return None;
+        }
+        state.firing = true;
+        state.last_fired = Some(Instant::now());
+        Some(Alert {
+            rule: rule.name.clone(),
+            kind: kind_name(&rule.kind).to_string(),
+            key,
+            state: AlertState::Firing,
+            message,
+            maintenance,
+            time_ms: unix_time_ms(),
+        })
+    }
+
+    /// 알림을 규칙의 `sinks` 에 해당하는 알림 채널로 전송 (비동기 태스크에서 전송하므로 바로 반환)
+    pub fn notify(&self, alert: &Alert) {
+        let rule = self
+            .config
+            .rules
+            .iter()
+            .find(|rule| rule.name == alert.rule);
+        self.notifier
+            .notify(alert, |sink| rule.is_none_or(|rule| rule.routes_to(sink)));
+    }
+
+    /// 테스트 알림 전송
+    ///
+    /// # Arguments
+    /// * `sink` - 전송할 알림 채널 이름, None 이면 모든 알림 채널
+    ///
+    /// # Returns
+    /// * `Result<Vec<NotificationResult>, String>` - 성공 시 채널별 전송 결과, 알 수 없는 채널이면 에러 메시지
+    pub async fn test_notification(
+        &self,
+        sink: Option<&str>,
+    ) -> Result<Vec<NotificationResult>, String> {
+        let alert = Alert {
+            rule: "test".to_string(),
+            kind: "test".to_string(),
+            key: LiDARKey::new(Ipv4Addr::UNSPECIFIED, 0, 0, 0),
+            state: AlertState::Firing,
+            message: "Test notification from LiDAR server".to_string(),
+            maintenance: None,
+            time_ms: unix_time_ms(),
+        };
+        self.notifier.test(&alert, sink).await
+    }
+}
+
+/// 창 오염 알림의 권장 정비 조치
+///
+/// # Arguments
+/// * `recurring` - 오염이 반복되어 발생한 알림이면 true, 지속되어 발생한 알림이면 false
+/// * `filters` - LiDAR 의 안개 필터, 창 오염 감지 모드 상태
+///
+/// # Returns
+/// * `String` - 청소 작업자가 바로 확인할 수 있는 조치 문장
+fn contamination_maintenance(recurring: bool, filters: Option<FilterState>) -> String {
+    let mut actions = Vec::new();
+    // 안개 필터가 켜져 있으면 날씨로 반사가 줄었을 수 있음
+    if filters
+        .and_then(|filters| filters.fog_filter)
+        .is_some_and(|value| value > 0)
+    {
+        actions.push("Fog filter is on: check for fog or rain at the site first".to_string());
+    }
+    if recurring {
+        actions.push(
+            "Clean the sensor window and look for a recurring source (spray, dust, exhaust); \
+             consider a shorter cleaning interval"
+                .to_string(),
+        );
+    } else {
+        actions.push("Clean the sensor window".to_string());
+    }
+    if filters.and_then(|filters| filters.detection_mode) == Some(0) {
+        actions.push("Enable window contamination detection mode on the LiDAR".to_string());
+    }
+    actions.join("; ")
+}
+
+/// 규칙 종류 이름
+fn kind_name(kind: &AlertKind) -> &'static str {
+    match kind {
+        AlertKind::DeviceOffline { .. } => "device_offline",
+        AlertKind::NakRate { .. } => "nak_rate",
+        AlertKind::ZoneIntrusion { .. } => "zone_intrusion",
+        AlertKind::WindowContamination { .. } => "window_contamination",
+        AlertKind::OverSpeed { .. } => "over_speed",
+        AlertKind::Quarantine => "quarantine",
+    }
+}
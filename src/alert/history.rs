@@ -0,0 +1,220 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, SocketAddrV4};
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+use tracing::*;
+use utoipa::ToSchema;
+
+use crate::alert::config::EventHistoryConfig;
+use crate::alert::engine::{Alert, AlertState};
+use crate::common::daylog::{read_days, DayWriter};
+use crate::common::time::unix_time_ms;
+use crate::lidar::LiDARKey;
+
+/// 영역 이벤트 파일 이름 접두어
+pub const EVENTS_PREFIX: &str = "events-";
+
+/// 조회 기간 기본값 (ms)
+const DEFAULT_RANGE_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// 조회 최대 이벤트 수 기본값
+const DEFAULT_LIMIT: usize = 10_000;
+
+/// 영역 이벤트 구조체
+///
+/// # Examples
+/// ```json
+/// { "time_ms": 1700000000000, "key": { ... }, "zone": "lane-1", "state": "firing", "message": "12 point(s) inside zone" }
+/// ```
+///
+/// # Fields
+/// * `time_ms` - 이벤트 시각 (Unix time, ms)
+/// * `key` - 대상 LiDAR 키
+/// * `zone` - 영역 이름 (`zone_intrusion` 규칙 이름)
+/// * `state` - `firing` 이면 영역 진입, `resolved` 이면 영역이 `clear_ms` 동안 비어 있음
+/// * `message` - 알림 내용
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct ZoneEvent {
+    pub time_ms: u64,
+    pub key: LiDARKey,
+    pub zone: String,
+    pub state: AlertState,
+    pub message: String,
+}
+
+impl ZoneEvent {
+    /// 영역 침입 알림을 영역 이벤트로 변환
+    ///
+    /// # Returns
+    /// * `Option<Self>` - `zone_intrusion` 알림이면 영역 이벤트, 아니면 None
+    pub fn from_alert(alert: &Alert) -> Option<Self> {
+        (alert.kind == "zone_intrusion").then(|| Self {
+            time_ms: alert.time_ms,
+            key: alert.key,
+            zone: alert.rule.clone(),
+            state: alert.state,
+            message: alert.message.clone(),
+        })
+    }
+}
+
+/// 영역 이벤트 조회 조건 (REST `/events` 파라미터, WebSocket `zone_events` 요청 데이터)
+///
+/// # Examples
+/// ```json
+/// { "command": "zone_events", "data": { "from": 1700000000000, "sensor": "192.168.123.200:5000", "zone": "lane-1" } }
+/// ```
+///
+/// # Fields
+/// * `from` - 조회 시작 시각 (Unix time, ms, 기본값: `to` 의 24시간 전)
+/// * `to` - 조회 종료 시각 (Unix time, ms, 기본값: 현재)
+/// * `sensor` - LiDAR 주소 (`ip` 또는 `ip:port`), 없으면 모든 LiDAR
+/// * `zone` - 영역 이름, 없으면 모든 영역
+/// * `limit` - 최대 이벤트 수 (기본값: 10000, 넘으면 최근 이벤트만 반환)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, utoipa::IntoParams)]
+#[serde(default, deny_unknown_fields)]
+pub struct EventQuery {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub sensor: Option<String>,
+    pub zone: Option<String>,
+    pub limit: Option<usize>,
+}
+
+impl EventQuery {
+    /// 조회 기간
+    ///
+    /// # Returns
+    /// * `Result<(u64, u64), String>` - 성공 시 (시작, 종료) 시각, 시작이 종료보다 늦으면 에러 메시지
+    pub fn range(&self) -> Result<(u64, u64), String> {
+        let to = self.to.unwrap_or_else(unix_time_ms);
+        let from = self.from.unwrap_or(to.saturating_sub(DEFAULT_RANGE_MS));
+        if from > to {
+            return Err("from is after to".to_string());
+        }
+        Ok((from, to))
+    }
+
+    /// LiDAR 주소 조건
+    ///
+    /// # Returns
+    /// * `Result<Option<(Ipv4Addr, Option<u16>)>, String>` - 성공 시 IP 와 포트 (조건이 없으면 None),
+    ///   형식이 잘못되면 에러 메시지
+    pub fn sensor_filter(&self) -> Result<Option<(Ipv4Addr, Option<u16>)>, String> {
+        let Some(sensor) = &self.sensor else {
+            return Ok(None);
+        };
+        if let Ok(addr) = sensor.parse::<SocketAddrV4>() {
+            return Ok(Some((*addr.ip(), Some(addr.port()))));
+        }
+        sensor
+            .parse::<Ipv4Addr>()
+            .map(|ip| Some((ip, None)))
+            .map_err(|_| format!("Invalid sensor (expected ip or ip:port): {}", sensor))
+    }
+
+    /// 조회 조건에 맞는 이벤트인지 확인 (기간 제외)
+    ///
+    /// # Arguments
+    /// * `event` - 영역 이벤트
+    /// * `sensor` - `sensor_filter` 결과
+    pub fn matches(&self, event: &ZoneEvent, sensor: Option<(Ipv4Addr, Option<u16>)>) -> bool {
+        let sensor_matches = sensor.is_none_or(|(ip, port)| {
+            event.key.ip == ip && port.is_none_or(|port| event.key.port == port)
+        });
+        let zone_matches = self.zone.as_ref().is_none_or(|zone| &event.zone == zone);
+        sensor_matches && zone_matches
+    }
+}
+
+/// 영역 이벤트를 디스크에 보관하는 이벤트 이력 저장소
+///
+/// # Examples
+/// ```
+/// let history = EventHistory::start(&config.alert.history)?;
+/// history.store(&alert);
+/// let events = history.query(&EventQuery { zone: Some("lane-1".into()), ..Default::default() }).await?;
+/// ```
+///
+/// # 주요 기능
+/// * 영역 침입 알림(발생/해제)을 일 단위 `events-<unix_day>.jsonl` 파일에 한 줄씩 JSON 으로 저장
+/// * 날짜가 바뀌면 새 파일을 만들고 `retention_days` 가 지난 파일 삭제
+/// * 기간/LiDAR/영역으로 이력 조회 (외부 데이터베이스 없이 통행량/점유 보고서 작성용)
+pub struct EventHistory {
+    dir: PathBuf,
+    tx: mpsc::Sender<ZoneEvent>,
+}
+
+impl EventHistory {
+    /// 이벤트 보관 시작
+    ///
+    /// # Arguments
+    /// * `config` - 영역 이벤트 디스크 보관 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 이벤트 이력 저장소, 실패 시 에러 메시지
+    pub fn start(config: &EventHistoryConfig) -> Result<Self, String> {
+        std::fs::create_dir_all(&config.dir)
+            .map_err(|e| format!("Failed to create {}: {}", config.dir.display(), e))?;
+
+        let (tx, mut rx) = mpsc::channel::<ZoneEvent>(config.queue_size.max(1));
+        let mut writer = DayWriter::new(config.dir.clone(), EVENTS_PREFIX, config.retention_days);
+        writer.prune();
+        std::thread::Builder::new()
+            .name("zone-events".to_string())
+            .spawn(move || {
+                while let Some(event) = rx.blocking_recv() {
+                    if let Err(e) = writer.write(&[event]) {
+                        error!("Failed to store zone event: {}", e);
+                    }
+                }
+            })
+            .map_err(|e| format!("Failed to spawn zone event thread: {}", e))?;
+
+        info!(
+            "Storing zone events to {} ({} days)",
+            config.dir.display(),
+            config.retention_days
+        );
+        Ok(Self {
+            dir: config.dir.clone(),
+            tx,
+        })
+    }
+
+    /// 알림 저장 (영역 침입 알림만 저장)
+    pub fn store(&self, alert: &Alert) {
+        let Some(event) = ZoneEvent::from_alert(alert) else {
+            return;
+        };
+        if let Err(mpsc::error::TrySendError::Full(_)) = self.tx.try_send(event) {
+            warn!("Zone event queue full, dropping event");
+        }
+    }
+
+    /// 영역 이벤트 이력 조회
+    ///
+    /// # Arguments
+    /// * `query` - 조회 조건
+    ///
+    /// # Returns
+    /// * `Result<Vec<ZoneEvent>, String>` - 성공 시 오래된 순 이벤트 목록, 조건이 잘못되었거나 읽기 실패 시 에러 메시지
+    pub async fn query(&self, query: &EventQuery) -> Result<Vec<ZoneEvent>, String> {
+        let (from_ms, to_ms) = query.range()?;
+        let sensor = query.sensor_filter()?;
+        let dir = self.dir.clone();
+        let filter = query.clone();
+        let mut events = tokio::task::spawn_blocking(move || {
+            read_days(&dir, EVENTS_PREFIX, from_ms, to_ms, |event: &ZoneEvent| {
+                (from_ms..=to_ms).contains(&event.time_ms) && filter.matches(event, sensor)
+            })
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+        let skip = events
+            .len()
+            .saturating_sub(query.limit.unwrap_or(DEFAULT_LIMIT));
+        Ok(events.split_off(skip))
+    }
+}
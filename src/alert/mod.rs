@@ -0,0 +1,12 @@
+pub mod config;
+pub mod engine;
+pub mod history;
+pub mod sink;
+
+pub use config::{
+    AlertConfig, AlertKind, AlertRule, EmailConfig, EventHistoryConfig, SinkConfig, SinkKind,
+    SmtpTls,
+};
+pub use engine::{Alert, AlertEngine, AlertState};
+pub use history::{EventHistory, EventQuery, ZoneEvent};
+pub use sink::{NotificationResult, NotificationSink, Notifier};
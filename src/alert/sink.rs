@@ -0,0 +1,329 @@
+#[cfg(feature = "email")]
+use lettre::message::header::ContentType;
+#[cfg(feature = "email")]
+use lettre::message::Mailbox;
+#[cfg(feature = "email")]
+use lettre::transport::smtp::authentication::Credentials;
+#[cfg(feature = "email")]
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use schemars::JsonSchema;
+use serde::Serialize;
+#[cfg(feature = "webhook")]
+use serde_json::json;
+use std::sync::Arc;
+#[cfg(any(feature = "webhook", feature = "email"))]
+use std::time::Duration;
+use tracing::*;
+use utoipa::ToSchema;
+
+#[cfg(feature = "email")]
+use crate::alert::config::{EmailConfig, SmtpTls};
+use crate::alert::config::{SinkConfig, SinkKind};
+use crate::alert::engine::Alert;
+
+/// 알림 채널 전송 결과 구조체
+///
+/// # Fields
+/// * `sink` - 알림 채널 이름
+/// * `delivered` - 전송 성공 여부
+/// * `error` - 전송 실패 시 에러 메시지
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct NotificationResult {
+    pub sink: String,
+    pub delivered: bool,
+    pub error: Option<String>,
+}
+
+/// 알림 채널 전송 방식
+///
+/// webhook/Slack 은 `webhook` feature, 이메일은 `email` feature 가 필요합니다.
+enum Transport {
+    #[cfg(feature = "webhook")]
+    Webhook {
+        client: reqwest::Client,
+        url: String,
+        timeout: Duration,
+    },
+    #[cfg(feature = "webhook")]
+    Slack {
+        client: reqwest::Client,
+        webhook_url: String,
+        channel: Option<String>,
+        timeout: Duration,
+    },
+    #[cfg(feature = "email")]
+    Email {
+        transport: AsyncSmtpTransport<Tokio1Executor>,
+        from: Mailbox,
+        to: Vec<Mailbox>,
+    },
+}
+
+/// 알림 채널 구조체
+///
+/// # Examples
+/// ```
+/// let sink = NotificationSink::new(&config)?;
+/// sink.send(&alert).await?;
+/// ```
+pub struct NotificationSink {
+    name: String,
+    transport: Transport,
+}
+
+impl NotificationSink {
+    /// 설정으로 알림 채널 생성
+    ///
+    /// # Arguments
+    /// * `config` - 알림 채널 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 알림 채널,
+    ///   잘못된 이메일 주소나 빌드에서 제외된 전송 방식 등 실패 시 에러 메시지
+    #[cfg_attr(
+        not(any(feature = "webhook", feature = "email")),
+        allow(unreachable_code, unused_variables)
+    )]
+    pub fn new(config: &SinkConfig) -> Result<Self, String> {
+        let transport = match &config.kind {
+            #[cfg(feature = "webhook")]
+            SinkKind::Webhook { url, timeout_ms } => Transport::Webhook {
+                client: reqwest::Client::new(),
+                url: url.clone(),
+                timeout: Duration::from_millis(*timeout_ms),
+            },
+            #[cfg(feature = "webhook")]
+            SinkKind::Slack {
+                webhook_url,
+                channel,
+                timeout_ms,
+            } => Transport::Slack {
+                client: reqwest::Client::new(),
+                webhook_url: webhook_url.clone(),
+                channel: channel.clone(),
+                timeout: Duration::from_millis(*timeout_ms),
+            },
+            #[cfg(feature = "email")]
+            SinkKind::Email(email) => email_transport(email)?,
+            #[cfg(not(feature = "webhook"))]
+            SinkKind::Webhook { .. } | SinkKind::Slack { .. } => {
+                return Err("Webhook and Slack sinks require the `webhook` feature".to_string())
+            }
+            #[cfg(not(feature = "email"))]
+            SinkKind::Email(_) => return Err("Email sinks require the `email` feature".to_string()),
+        };
+        Ok(Self {
+            name: config.name.clone(),
+            transport,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 알림 전송
+    ///
+    /// # Arguments
+    /// * `alert` - 전송할 알림
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 실패 시 에러 메시지
+    #[cfg_attr(
+        not(any(feature = "webhook", feature = "email")),
+        allow(unused_variables)
+    )]
+    pub async fn send(&self, alert: &Alert) -> Result<(), String> {
+        match &self.transport {
+            #[cfg(feature = "webhook")]
+            Transport::Webhook {
+                client,
+                url,
+                timeout,
+            } => post_json(client, url, *timeout, &json!(alert)).await,
+            #[cfg(feature = "webhook")]
+            Transport::Slack {
+                client,
+                webhook_url,
+                channel,
+                timeout,
+            } => {
+                let mut body = json!({ "text": alert.summary() });
+                if let Some(channel) = channel {
+                    body["channel"] = json!(channel);
+                }
+                post_json(client, webhook_url, *timeout, &body).await
+            }
+            #[cfg(feature = "email")]
+            Transport::Email {
+                transport,
+                from,
+                to,
+            } => {
+                let mut builder = Message::builder()
+                    .from(from.clone())
+                    .subject(alert.summary())
+                    .header(ContentType::TEXT_PLAIN);
+                for mailbox in to {
+                    builder = builder.to(mailbox.clone());
+                }
+                let body = serde_json::to_string_pretty(alert).map_err(|e| e.to_string())?;
+                let message = builder.body(body).map_err(|e| e.to_string())?;
+                transport
+                    .send(message)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            }
+            #[cfg(not(any(feature = "webhook", feature = "email")))]
+            _ => unreachable!("no alert sink transport enabled"),
+        }
+    }
+}
+
+/// 이메일 설정으로 SMTP 전송 방식 생성
+#[cfg(feature = "email")]
+fn email_transport(config: &EmailConfig) -> Result<Transport, String> {
+    let from: Mailbox = config
+        .from
+        .parse()
+        .map_err(|e| format!("Invalid from address {}: {}", config.from, e))?;
+    let to = config
+        .to
+        .iter()
+        .map(|address| {
+            address
+                .parse::<Mailbox>()
+                .map_err(|e| format!("Invalid to address {}: {}", address, e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if to.is_empty() {
+        return Err("Email sink without recipients".to_string());
+    }
+
+    let mut builder = match config.tls {
+        SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.smtp_host),
+        SmtpTls::Starttls => {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)
+                .map_err(|e| e.to_string())?
+        }
+        SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_host)
+            .map_err(|e| e.to_string())?,
+    }
+    .port(config.smtp_port)
+    .timeout(Some(Duration::from_millis(config.timeout_ms)));
+    if let Some(username) = &config.username {
+        builder = builder.credentials(Credentials::new(
+            username.clone(),
+            config.password.clone().unwrap_or_default(),
+        ));
+    }
+    Ok(Transport::Email {
+        transport: builder.build(),
+        from,
+        to,
+    })
+}
+
+/// JSON 본문을 HTTP POST 로 전송
+#[cfg(feature = "webhook")]
+async fn post_json(
+    client: &reqwest::Client,
+    url: &str,
+    timeout: Duration,
+    body: &serde_json::Value,
+) -> Result<(), String> {
+    let response = client
+        .post(url)
+        .timeout(timeout)
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("HTTP {}", response.status()))
+    }
+}
+
+/// 알림 채널 관리 구조체
+///
+/// # 동작 설명
+/// * 설정된 알림 채널 생성 (생성에 실패한 채널은 로그를 남기고 제외)
+/// * 규칙의 `sinks` 에 따라 알림을 해당 채널로 전송
+/// * 전송은 별도 태스크에서 수행하며 실패 시 로그만 남김
+pub struct Notifier {
+    sinks: Vec<Arc<NotificationSink>>,
+}
+
+impl Notifier {
+    pub fn new(configs: &[SinkConfig]) -> Self {
+        let sinks = configs
+            .iter()
+            .filter_map(|config| match NotificationSink::new(config) {
+                Ok(sink) => Some(Arc::new(sink)),
+                Err(e) => {
+                    error!("Failed to create alert sink {}: {}", config.name, e);
+                    None
+                }
+            })
+            .collect();
+        Self { sinks }
+    }
+
+    /// 알림 전송 (비동기 태스크에서 전송하므로 바로 반환)
+    ///
+    /// # Arguments
+    /// * `alert` - 전송할 알림
+    /// * `route` - 알림 채널 이름을 받아 전송 대상인지 반환하는 함수
+    pub fn notify(&self, alert: &Alert, route: impl Fn(&str) -> bool) {
+        for sink in self.sinks.iter().filter(|sink| route(sink.name())) {
+            let sink = sink.clone();
+            let alert = alert.clone();
+            tokio::spawn(async move {
+                match sink.send(&alert).await {
+                    Ok(()) => debug!("Alert sink {} delivered", sink.name()),
+                    Err(e) => error!("Failed to send alert to sink {}: {}", sink.name(), e),
+                }
+            });
+        }
+    }
+
+    /// 테스트 알림을 전송하고 결과 반환
+    ///
+    /// # Arguments
+    /// * `alert` - 테스트 알림
+    /// * `sink` - 전송할 알림 채널 이름, None 이면 모든 알림 채널
+    ///
+    /// # Returns
+    /// * `Result<Vec<NotificationResult>, String>` - 성공 시 채널별 전송 결과, 알 수 없는 채널이면 에러 메시지
+    pub async fn test(
+        &self,
+        alert: &Alert,
+        sink: Option<&str>,
+    ) -> Result<Vec<NotificationResult>, String> {
+        let targets: Vec<_> = self
+            .sinks
+            .iter()
+            .filter(|target| sink.is_none_or(|name| target.name() == name))
+            .collect();
+        if let Some(name) = sink {
+            if targets.is_empty() {
+                return Err(format!("Unknown alert sink: {}", name));
+            }
+        }
+
+        let results =
+            futures::future::join_all(targets.iter().map(|target| target.send(alert))).await;
+        Ok(targets
+            .iter()
+            .zip(results)
+            .map(|(target, result)| NotificationResult {
+                sink: target.name().to_string(),
+                delivered: result.is_ok(),
+                error: result.err(),
+            })
+            .collect())
+    }
+}
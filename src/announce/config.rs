@@ -0,0 +1,26 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// LAN 서비스 알림 설정 구조체
+///
+/// # Fields
+/// * `enabled` - mDNS 서비스 알림 사용 여부
+/// * `service_type` - 알림할 서비스 타입 (DNS-SD 형식)
+/// * `instance_name` - 서비스 인스턴스 이름, None 이면 `lidar-server-{ws_port}` 사용
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct AnnounceConfig {
+    pub enabled: bool,
+    pub service_type: String,
+    pub instance_name: Option<String>,
+}
+
+impl Default for AnnounceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            service_type: "_lidar-relay._tcp.local.".to_string(),
+            instance_name: None,
+        }
+    }
+}
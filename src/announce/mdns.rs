@@ -0,0 +1,81 @@
+use mdns_sd::{ServiceDaemon, ServiceInfo};
+use tracing::*;
+
+use crate::announce::config::AnnounceConfig;
+
+/// mDNS(DNS-SD) 서비스 알림 구조체
+///
+/// # Examples
+/// ```
+/// let announcer = MdnsAnnouncer::start(&config.announce, ws_port)?;
+/// // ...
+/// announcer.stop();
+/// ```
+///
+/// # Fields
+/// * `daemon` - mDNS 응답 데몬
+/// * `fullname` - 등록된 서비스의 전체 이름
+///
+/// # 주요 기능
+/// * LAN 상의 클라이언트가 서버 IP 를 직접 입력하지 않고 검색할 수 있도록 서비스 알림
+/// * TXT 레코드로 WebSocket 포트/경로 및 서버 버전 전달
+/// * 호스트의 IP 주소가 변경되면 자동으로 알림 주소 갱신
+pub struct MdnsAnnouncer {
+    daemon: ServiceDaemon,
+    fullname: String,
+}
+
+impl MdnsAnnouncer {
+    /// mDNS 서비스 알림 시작
+    ///
+    /// # Arguments
+    /// * `config` - 서비스 알림 설정
+    /// * `ws_port` - 실제 바인딩된 WebSocket 포트
+    ///
+    /// # Returns
+    /// * `Result<Self, mdns_sd::Error>` - 성공 시 MdnsAnnouncer 인스턴스, 실패 시 mDNS 에러
+    pub fn start(config: &AnnounceConfig, ws_port: u16) -> Result<Self, mdns_sd::Error> {
+        let daemon = ServiceDaemon::new()?;
+
+        let instance_name = config
+            .instance_name
+            .clone()
+            .unwrap_or_else(|| format!("lidar-server-{}", ws_port));
+        let host_name = format!("{}.local.", instance_name.replace(' ', "-"));
+        let ws_port_text = ws_port.to_string();
+        let properties = [
+            ("ws_port", ws_port_text.as_str()),
+            ("ws_path", "/ws"),
+            ("version", env!("CARGO_PKG_VERSION")),
+        ];
+
+        let service = ServiceInfo::new(
+            &config.service_type,
+            &instance_name,
+            &host_name,
+            "",
+            ws_port,
+            &properties[..],
+        )?
+        .enable_addr_auto();
+        let fullname = service.get_fullname().to_string();
+
+        daemon.register(service)?;
+        info!("mDNS service announced: {}", fullname);
+
+        Ok(Self { daemon, fullname })
+    }
+
+    /// mDNS 서비스 알림 중지
+    ///
+    /// # 동작 설명
+    /// * 서비스 등록 해제(goodbye 패킷 송신) 후 데몬 종료
+    pub fn stop(self) {
+        if let Err(e) = self.daemon.unregister(&self.fullname) {
+            error!("Failed to unregister mDNS service: {}", e);
+        }
+        if let Err(e) = self.daemon.shutdown() {
+            error!("Failed to shutdown mDNS daemon: {}", e);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod config;
+#[cfg(feature = "mdns")]
+pub mod mdns;
+
+pub use config::AnnounceConfig;
+#[cfg(feature = "mdns")]
+pub use mdns::MdnsAnnouncer;
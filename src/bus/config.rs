@@ -0,0 +1,50 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::lidar::LiDARKey;
+
+/// 메시지 버스(NATS) 발행 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 메시지 버스 발행 사용 여부 (`nats` feature 필요)
+/// * `url` - NATS 서버 주소 (쉼표로 여러 서버 지정 가능)
+/// * `frame_subject` - 파싱된 LiDAR 데이터 subject, `{ip}`(점 대신 `_`), `{port}`, `{product_line}`, `{lidar_id}` 치환
+/// * `event_subject` - 장치 이벤트 subject, `{event}` 를 이벤트 이름(`config`, `telemetry`, `device_health`, `alert`, `site_status`)으로 치환
+/// * `queue_size` - 발행 대기열 크기, 가득 차면 해당 메시지는 발행하지 않음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct BusConfig {
+    pub enabled: bool,
+    pub url: String,
+    pub frame_subject: String,
+    pub event_subject: String,
+    pub queue_size: usize,
+}
+
+impl Default for BusConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: "nats://127.0.0.1:4222".to_string(),
+            frame_subject: "lidar.frames.{ip}.{port}.{product_line}.{lidar_id}".to_string(),
+            event_subject: "lidar.events.{event}".to_string(),
+            queue_size: 1024,
+        }
+    }
+}
+
+impl BusConfig {
+    /// LiDAR 데이터 subject 생성
+    pub fn frame_subject(&self, key: &LiDARKey) -> String {
+        self.frame_subject
+            .replace("{ip}", &key.ip.to_string().replace('.', "_"))
+            .replace("{port}", &key.port.to_string())
+            .replace("{product_line}", &key.product_line.to_string())
+            .replace("{lidar_id}", &key.lidar_id.to_string())
+    }
+
+    /// 장치 이벤트 subject 생성
+    pub fn event_subject(&self, event: &str) -> String {
+        self.event_subject.replace("{event}", event)
+    }
+}
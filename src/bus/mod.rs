@@ -0,0 +1,7 @@
+pub mod config;
+#[cfg(feature = "nats")]
+pub mod publisher;
+
+pub use config::BusConfig;
+#[cfg(feature = "nats")]
+pub use publisher::BusPublisher;
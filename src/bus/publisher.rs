@@ -0,0 +1,95 @@
+use bytes::Bytes;
+use tokio::sync::mpsc;
+use tracing::*;
+
+use crate::bus::config::BusConfig;
+use crate::common::data::LiDARPacket;
+use crate::ws::message::{split_tagged, WsEvent};
+
+/// 메시지 버스(NATS) 발행자
+///
+/// # Examples
+/// ```
+/// let bus = BusPublisher::start(&config.bus);
+/// bus.publish_frame(&packet);
+/// bus.publish_event(&WsEvent::Alert(alert));
+/// ```
+///
+/// # 동작 설명
+/// * 백그라운드 태스크에서 NATS 서버에 연결 (연결이 끊기면 자동 재연결)
+/// * 파싱된 LiDAR 데이터는 WebSocket v2 binary 프레임과 같은 형식(회사 구분값 + bincode)으로 발행
+/// * 장치 이벤트는 `{"command": <이벤트>, "data": ...}` JSON 으로 발행
+/// * 발행은 대기열에 넣고 바로 반환하며, 대기열이 가득 차면 버림
+pub struct BusPublisher {
+    config: BusConfig,
+    tx: mpsc::Sender<(String, Bytes)>,
+}
+
+impl BusPublisher {
+    /// 발행 시작
+    ///
+    /// # Arguments
+    /// * `config` - 메시지 버스 발행 설정
+    ///
+    /// # Returns
+    /// * `Self` - 발행자 (연결 실패는 로그로 남기고 재시도)
+    pub fn start(config: &BusConfig) -> Self {
+        let (tx, mut rx) = mpsc::channel::<(String, Bytes)>(config.queue_size.max(1));
+        let url = config.url.clone();
+        tokio::spawn(async move {
+            let client = match async_nats::ConnectOptions::new()
+                .retry_on_initial_connect()
+                .connect(url.as_str())
+                .await
+            {
+                Ok(client) => client,
+                Err(e) => {
+                    error!("Failed to connect to NATS {}: {}", url, e);
+                    return;
+                }
+            };
+            info!("Publishing to NATS {}", url);
+            while let Some((subject, payload)) = rx.recv().await {
+                if let Err(e) = client.publish(subject, payload).await {
+                    error!("Failed to publish to NATS: {}", e);
+                }
+            }
+        });
+
+        Self {
+            config: config.clone(),
+            tx,
+        }
+    }
+
+    /// 파싱된 LiDAR 데이터 발행 (녹화 재생 데이터는 제외)
+    pub fn publish_frame(&self, packet: &LiDARPacket) {
+        if packet.replay {
+            return;
+        }
+        let subject = self.config.frame_subject(&packet.key);
+        self.enqueue(subject, Bytes::copy_from_slice(&packet.payload));
+    }
+
+    /// 장치 이벤트 발행
+    pub fn publish_event(&self, event: &WsEvent) {
+        let (command, data) = match split_tagged(event) {
+            Ok(tagged) => tagged,
+            Err(e) => {
+                error!("Failed to serialize bus event: {}", e);
+                return;
+            }
+        };
+        let subject = self.config.event_subject(&command);
+        match serde_json::to_vec(&serde_json::json!({ "command": command, "data": data })) {
+            Ok(json) => self.enqueue(subject, Bytes::from(json)),
+            Err(e) => error!("Failed to serialize bus event: {}", e),
+        }
+    }
+
+    fn enqueue(&self, subject: String, payload: Bytes) {
+        if let Err(mpsc::error::TrySendError::Full(_)) = self.tx.try_send((subject, payload)) {
+            warn!("NATS queue full, dropping message");
+        }
+    }
+}
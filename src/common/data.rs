@@ -0,0 +1,57 @@
+use std::net::SocketAddr;
+use tracing::Span;
+
+use crate::lidar::LiDARKey;
+use crate::logging::TraceId;
+
+/// UDP 리스너에서 WebSocket 서버로 전달하는 LiDAR 데이터
+///
+/// # Fields
+/// * `key` - 데이터를 송신한 LiDAR 키
+/// * `payload` - 회사 구분값(1 byte) + bincode 인코딩 데이터
+/// * `replay` - 녹화 파일 재생 데이터 여부
+/// * `time_ms` - 수신 시각 (Unix time, ms)
+/// * `trace_id` - 수신한 데이터그램의 로그 상관 관계 ID (UDP 수신 span 과 WebSocket 전달 span 에 같은 값 기록)
+#[derive(Debug, Clone)]
+pub struct LiDARPacket {
+    pub key: LiDARKey,
+    pub payload: Vec<u8>,
+    pub replay: bool,
+    pub time_ms: u64,
+    pub trace_id: TraceId,
+}
+
+/// WebSocket 서버에서 UDP 리스너로 전달하는 메시지
+///
+/// # Variants
+/// * `Command` - 특정 LiDAR 로 송신할 명령 프레임, `span` 은 명령을 보낸 WebSocket 요청의 tracing span
+/// * `Discover` - 설정된 검색 대상 주소(`udp.discovery.targets`)로 장치 검색 요청을 바로 송신
+#[derive(Debug, Clone)]
+pub enum OutboundMessage {
+    Command {
+        target: SocketAddr,
+        frame: Vec<u8>,
+        span: Span,
+    },
+    Discover {
+        span: Span,
+    },
+}
+
+impl OutboundMessage {
+    /// 현재 tracing span (WebSocket 요청) 안에서 보낸 명령 프레임
+    pub fn command(target: SocketAddr, frame: Vec<u8>) -> Self {
+        Self::Command {
+            target,
+            frame,
+            span: Span::current(),
+        }
+    }
+
+    /// 현재 tracing span (WebSocket 요청) 안에서 요청한 장치 검색
+    pub fn discover() -> Self {
+        Self::Discover {
+            span: Span::current(),
+        }
+    }
+}
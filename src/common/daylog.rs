@@ -0,0 +1,155 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tracing::*;
+
+use crate::common::time::unix_time_ms;
+
+/// 일 단위 파일 확장자
+pub const DAY_LOG_EXTENSION: &str = "jsonl";
+
+/// 하루 (ms)
+pub const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// 일 단위 파일 경로 (`<prefix><unix_day>.jsonl`)
+pub fn day_path(dir: &Path, prefix: &str, day: u64) -> PathBuf {
+    dir.join(format!("{}{}.{}", prefix, day, DAY_LOG_EXTENSION))
+}
+
+/// 일 단위 파일 이름에서 날짜 (Unix time 기준 일 수) 추출
+pub fn parse_day(path: &Path, prefix: &str) -> Option<u64> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .strip_suffix(DAY_LOG_EXTENSION)?
+        .strip_suffix('.')?
+        .parse()
+        .ok()
+}
+
+/// 기간에 해당하는 일 단위 파일의 레코드 읽기
+///
+/// # Arguments
+/// * `dir` - 파일 저장 디렉터리
+/// * `prefix` - 파일 이름 접두어
+/// * `from_ms` - 조회 시작 시각 (Unix time, ms)
+/// * `to_ms` - 조회 종료 시각 (Unix time, ms)
+/// * `keep` - 반환할 레코드인지 확인하는 함수
+///
+/// # Returns
+/// * `Result<Vec<T>, String>` - 성공 시 저장 순서대로 레코드 목록, 실패 시 에러 메시지
+pub fn read_days<T, F>(
+    dir: &Path,
+    prefix: &str,
+    from_ms: u64,
+    to_ms: u64,
+    mut keep: F,
+) -> Result<Vec<T>, String>
+where
+    T: DeserializeOwned,
+    F: FnMut(&T) -> bool,
+{
+    let mut records = Vec::new();
+    for day in from_ms / DAY_MS..=to_ms / DAY_MS {
+        let path = day_path(dir, prefix, day);
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("Failed to open {}: {}", path.display(), e)),
+        };
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            // 마지막 줄이 쓰는 도중이면 파싱에 실패할 수 있으므로 건너뜀
+            let Ok(record) = serde_json::from_str::<T>(&line) else {
+                continue;
+            };
+            if keep(&record) {
+                records.push(record);
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// 일 단위 파일 쓰기 및 보관 기간 관리
+///
+/// # Fields
+/// * `dir` - 파일 저장 디렉터리
+/// * `prefix` - 파일 이름 접두어
+/// * `retention_days` - 보관 기간 (일)
+/// * `current` - 현재 쓰고 있는 날짜와 파일
+pub struct DayWriter {
+    dir: PathBuf,
+    prefix: &'static str,
+    retention_days: u64,
+    current: Option<(u64, BufWriter<File>)>,
+}
+
+impl DayWriter {
+    pub fn new(dir: PathBuf, prefix: &'static str, retention_days: u64) -> Self {
+        Self {
+            dir,
+            prefix,
+            retention_days: retention_days.max(1),
+            current: None,
+        }
+    }
+
+    /// 레코드 저장 (날짜가 바뀌면 새 파일 생성 후 오래된 파일 삭제)
+    ///
+    /// # 동작 설명
+    /// * 실패하면 현재 파일을 닫으므로 다음 저장 시 파일을 다시 엶
+    pub fn write<T: Serialize>(&mut self, records: &[T]) -> Result<(), String> {
+        let result = self.try_write(records);
+        if result.is_err() {
+            self.current = None;
+        }
+        result
+    }
+
+    fn try_write<T: Serialize>(&mut self, records: &[T]) -> Result<(), String> {
+        let day = unix_time_ms() / DAY_MS;
+        if self
+            .current
+            .as_ref()
+            .is_none_or(|(current, _)| *current != day)
+        {
+            let path = day_path(&self.dir, self.prefix, day);
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+            self.current = Some((day, BufWriter::new(file)));
+            self.prune();
+        }
+
+        let Some((_, writer)) = self.current.as_mut() else {
+            return Ok(());
+        };
+        for record in records {
+            serde_json::to_writer(&mut *writer, record).map_err(|e| e.to_string())?;
+            writer.write_all(b"\n").map_err(|e| e.to_string())?;
+        }
+        writer.flush().map_err(|e| e.to_string())
+    }
+
+    /// 보관 기간이 지난 파일 삭제
+    pub fn prune(&self) {
+        let today = unix_time_ms() / DAY_MS;
+        let oldest = today.saturating_sub(self.retention_days - 1);
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if parse_day(&path, self.prefix).is_some_and(|day| day < oldest) {
+                match std::fs::remove_file(&path) {
+                    Ok(()) => info!("Removed expired {}", path.display()),
+                    Err(e) => error!("Failed to remove {}: {}", path.display(), e),
+                }
+            }
+        }
+    }
+}
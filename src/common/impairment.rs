@@ -0,0 +1,190 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::common::time::unix_time_ms;
+
+/// 네트워크 장애 주입 설정 구조체 (클라이언트 검증용 시험/진단 모드)
+///
+/// # Examples
+/// ```json
+/// { "enabled": true, "drop_percent": 5.0, "delay_percent": 10.0, "delay_ms": [20, 200], "duplicate_percent": 1.0 }
+/// ```
+///
+/// # Fields
+/// * `enabled` - 장애 주입 사용 여부
+/// * `drop_percent` - 버릴 패킷/프레임 비율 (%)
+/// * `delay_percent` - 지연시킬 패킷/프레임 비율 (%)
+/// * `delay_ms` - 지연 시간 범위 `[min, max]` (ms), 범위 안에서 균등 분포로 선택
+/// * `duplicate_percent` - 두 번 전달할 패킷/프레임 비율 (%)
+/// * `seed` - 난수 시드, None 이면 시작 시각으로 정함 (같은 시드는 같은 순서로 장애 발생)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ImpairmentConfig {
+    pub enabled: bool,
+    pub drop_percent: f64,
+    pub delay_percent: f64,
+    pub delay_ms: [u64; 2],
+    pub duplicate_percent: f64,
+    pub seed: Option<u64>,
+}
+
+impl Default for ImpairmentConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            drop_percent: 0.0,
+            delay_percent: 0.0,
+            delay_ms: [0, 100],
+            duplicate_percent: 0.0,
+            seed: None,
+        }
+    }
+}
+
+impl ImpairmentConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 비율이 0 ~ 100 이 아니거나 지연 범위가 잘못되면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, percent) in [
+            ("drop_percent", self.drop_percent),
+            ("delay_percent", self.delay_percent),
+            ("duplicate_percent", self.duplicate_percent),
+        ] {
+            if !(0.0..=100.0).contains(&percent) {
+                return Err(format!("Impairment {} must be within 0 ~ 100", name));
+            }
+        }
+        if self.delay_ms[0] > self.delay_ms[1] {
+            return Err("Impairment delay_ms must be [min, max]".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 패킷/프레임 하나에 적용할 장애
+///
+/// # Fields
+/// * `copies` - 전달할 횟수 (0 이면 버림, 2 이면 중복)
+/// * `delay` - 전달 전 지연 시간
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Impairment {
+    pub copies: usize,
+    pub delay: Duration,
+}
+
+/// 장애 주입 통계 구조체
+///
+/// # Fields
+/// * `total` - 장애 여부를 정한 패킷/프레임 수
+/// * `dropped` - 버린 수
+/// * `delayed` - 지연시킨 수
+/// * `duplicated` - 중복 전달한 수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ImpairmentStats {
+    pub total: u64,
+    pub dropped: u64,
+    pub delayed: u64,
+    pub duplicated: u64,
+}
+
+/// 네트워크 장애 주입기
+///
+/// # Examples
+/// ```
+/// let impairer = Impairer::new(&config.udp.impairment);
+/// let impairment = impairer.decide();
+/// tokio::time::sleep(impairment.delay).await;
+/// for _ in 0..impairment.copies {
+///     handle(&datagram).await;
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 패킷/프레임마다 설정한 비율에 따라 버림, 지연, 중복을 독립적으로 정함 (버린 패킷은 지연/중복하지 않음)
+/// * 여러 수신/전송 태스크에서 잠금 없이 공유할 수 있도록 원자적 카운터 기반 난수(SplitMix64) 사용
+pub struct Impairer {
+    config: ImpairmentConfig,
+    state: AtomicU64,
+    total: AtomicU64,
+    dropped: AtomicU64,
+    delayed: AtomicU64,
+    duplicated: AtomicU64,
+}
+
+impl Impairer {
+    pub fn new(config: &ImpairmentConfig) -> Self {
+        Self {
+            config: config.clone(),
+            state: AtomicU64::new(config.seed.unwrap_or_else(unix_time_ms)),
+            total: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            delayed: AtomicU64::new(0),
+            duplicated: AtomicU64::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &ImpairmentConfig {
+        &self.config
+    }
+
+    /// 다음 패킷/프레임에 적용할 장애
+    pub fn decide(&self) -> Impairment {
+        self.total.fetch_add(1, Ordering::Relaxed);
+        if self.chance(self.config.drop_percent) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return Impairment {
+                copies: 0,
+                delay: Duration::ZERO,
+            };
+        }
+        let delay = if self.chance(self.config.delay_percent) {
+            self.delayed.fetch_add(1, Ordering::Relaxed);
+            let [min, max] = self.config.delay_ms;
+            Duration::from_millis(min + self.random() % (max - min).saturating_add(1))
+        } else {
+            Duration::ZERO
+        };
+        let copies = if self.chance(self.config.duplicate_percent) {
+            self.duplicated.fetch_add(1, Ordering::Relaxed);
+            2
+        } else {
+            1
+        };
+        Impairment { copies, delay }
+    }
+
+    /// 지금까지의 장애 주입 통계
+    pub fn stats(&self) -> ImpairmentStats {
+        ImpairmentStats {
+            total: self.total.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            delayed: self.delayed.load(Ordering::Relaxed),
+            duplicated: self.duplicated.load(Ordering::Relaxed),
+        }
+    }
+
+    /// `percent` % 확률로 true
+    fn chance(&self, percent: f64) -> bool {
+        if percent <= 0.0 {
+            return false;
+        }
+        // 상위 53 bit 로 [0, 1) 균등 분포
+        let sample = (self.random() >> 11) as f64 / (1u64 << 53) as f64;
+        sample * 100.0 < percent
+    }
+
+    /// SplitMix64 난수
+    fn random(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+            .wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
@@ -1 +1,4 @@
 pub mod data;
+pub mod daylog;
+pub mod impairment;
+pub mod time;
@@ -0,0 +1,12 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 현재 시각을 Unix time(ms)으로 반환
+///
+/// # Returns
+/// * `u64` - 1970-01-01 00:00:00 UTC 부터 경과한 밀리초
+pub fn unix_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,329 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+
+use crate::config::ServerConfig;
+
+/// 설정 파일 검사에서 찾은 문제 구조체
+///
+/// # Fields
+/// * `path` - 문제가 있는 항목 경로 (예: `udp.connected[0].local_port`)
+/// * `line` - 항목이 있는 줄 번호 (1 부터, 파일에 없는 항목이면 None)
+/// * `column` - 항목이 있는 열 번호 (1 부터, 파일에 없는 항목이면 None)
+/// * `message` - 문제 설명
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub path: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(
+                f,
+                "{} (line {}, column {}): {}",
+                self.path, line, column, self.message
+            ),
+            _ => write!(f, "{}: {}", self.path, self.message),
+        }
+    }
+}
+
+/// 설정 파일 엄격 검사
+///
+/// # Examples
+/// ```
+/// let config: ServerConfig = serde_json::from_str(&text)?;
+/// for issue in check(&text, &config) {
+///     eprintln!("{}", issue);
+/// }
+/// ```
+///
+/// # Arguments
+/// * `text` - 설정 파일 내용 (JSON)
+/// * `config` - `text` 를 역직렬화한 설정
+///
+/// # Returns
+/// * `Vec<ConfigIssue>` - 찾은 문제 목록 (파일 순서), 문제가 없으면 빈 목록
+///
+/// # 동작 설명
+/// * 알 수 없는 키: 설정을 다시 직렬화한 값에 없는 키 (오타, 다른 버전의 항목 등)
+/// * 포트 충돌: UDP 수신 포트와 연결형 소켓 포트(`udp.connected`)가 겹치거나,
+///   WebSocket 포트 범위(`ws.start_port` 부터 `ws.max_attempts` 개)와 이중화 포트(`replication.listen`)가 겹침
+/// * 멀티캐스트 주소: `udp.multicast_addr` 가 224.0.0.0/4 범위가 아님
+/// * 줄/열 번호는 해당 키가 파일에 있으면 키 위치, 없으면 가장 가까운 상위 항목 위치
+pub fn check(text: &str, config: &ServerConfig) -> Vec<ConfigIssue> {
+    let mut problems = Vec::new();
+    if let (Ok(input), Ok(known)) = (
+        serde_json::from_str::<Value>(text),
+        serde_json::to_value(config),
+    ) {
+        unknown_keys(&input, &known, "", &mut problems);
+    }
+    network_conflicts(config, &mut problems);
+
+    let positions = key_positions(text);
+    let mut issues: Vec<ConfigIssue> = problems
+        .into_iter()
+        .map(|(path, message)| {
+            let position = ancestors(&path).find_map(|path| positions.get(path));
+            ConfigIssue {
+                line: position.map(|&(line, _)| line),
+                column: position.map(|&(_, column)| column),
+                path,
+                message,
+            }
+        })
+        .collect();
+    issues.sort_by_key(|issue| (issue.line.unwrap_or(usize::MAX), issue.column));
+    issues
+}
+
+/// 입력 값에만 있는 객체 키 경로 수집 (양쪽이 모두 객체이거나 길이가 같은 배열인 경우만 비교)
+fn unknown_keys(input: &Value, known: &Value, path: &str, problems: &mut Vec<(String, String)>) {
+    match (input, known) {
+        (Value::Object(input), Value::Object(known)) => {
+            for (key, value) in input {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match known.get(key) {
+                    Some(known) => unknown_keys(value, known, &child, problems),
+                    None => problems.push((child, "unknown key".to_string())),
+                }
+            }
+        }
+        (Value::Array(input), Value::Array(known)) if input.len() == known.len() => {
+            for (index, (input, known)) in input.iter().zip(known).enumerate() {
+                unknown_keys(input, known, &format!("{}[{}]", path, index), problems);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 포트 충돌과 잘못된 멀티캐스트 주소 확인
+fn network_conflicts(config: &ServerConfig, problems: &mut Vec<(String, String)>) {
+    if !config.udp.multicast_addr.is_multicast() {
+        problems.push((
+            "udp.multicast_addr".to_string(),
+            format!(
+                "{} is not a multicast address (224.0.0.0/4)",
+                config.udp.multicast_addr
+            ),
+        ));
+    }
+
+    // UDP 포트
+    let udp_port = config.udp.addr.port();
+    for (index, sensor) in config.udp.connected.iter().enumerate() {
+        let path = format!("udp.connected[{}].local_port", index);
+        if sensor.local_port == udp_port {
+            problems.push((
+                path,
+                format!("port {} is already used by udp.addr", sensor.local_port),
+            ));
+        } else if let Some(other) = config.udp.connected[..index]
+            .iter()
+            .position(|other| other.local_port == sensor.local_port)
+        {
+            problems.push((
+                path,
+                format!(
+                    "port {} is already used by udp.connected[{}]",
+                    sensor.local_port, other
+                ),
+            ));
+        }
+    }
+
+    // TCP 포트
+    let ws_ports = config.ws.start_port as u32
+        ..config.ws.start_port as u32 + config.ws.max_attempts.max(1) as u32;
+    if let Some(listen) = config.replication.listen.as_ref().map(SocketAddr::port) {
+        if ws_ports.contains(&(listen as u32)) {
+            problems.push((
+                "replication.listen".to_string(),
+                format!(
+                    "port {} overlaps the WebSocket ports {}..{}",
+                    listen, ws_ports.start, ws_ports.end
+                ),
+            ));
+        }
+    }
+}
+
+/// 파싱 에러 위치에 해당하는 항목 경로
+///
+/// # Arguments
+/// * `text` - 설정 파일 내용 (JSON)
+/// * `line` - 에러 줄 번호 (1 부터)
+/// * `column` - 에러 열 번호 (1 부터)
+///
+/// # Returns
+/// * `Option<String>` - 에러 위치 앞에서 가장 가까운 키 또는 배열 원소 경로, 없으면 None
+pub fn path_at(text: &str, line: usize, column: usize) -> Option<String> {
+    key_positions(text)
+        .into_iter()
+        .filter(|(_, position)| *position <= (line, column))
+        .max_by_key(|(_, position)| *position)
+        .map(|(path, _)| path)
+}
+
+/// 경로와 상위 경로 (`a.b[0].c` → `a.b[0].c`, `a.b[0]`, `a.b`, `a`)
+fn ancestors(path: &str) -> impl Iterator<Item = &str> {
+    let mut current = Some(path);
+    std::iter::from_fn(move || {
+        let path = current?;
+        current = path.rfind(['.', '[']).map(|index| &path[..index]);
+        Some(path)
+    })
+}
+
+/// JSON 텍스트에서 객체 키와 배열 원소의 경로별 위치 (줄, 열)
+///
+/// # 동작 설명
+/// * 역직렬화에 성공한 텍스트를 가정하며, 잘못된 텍스트는 읽을 수 있는 곳까지만 기록
+/// * 열 번호는 UTF-8 문자 단위
+fn key_positions(text: &str) -> HashMap<String, (usize, usize)> {
+    let mut scanner = Scanner {
+        text,
+        bytes: text.as_bytes(),
+        offset: 0,
+        line: 1,
+        column: 1,
+        positions: HashMap::new(),
+    };
+    scanner.value("");
+    scanner.positions
+}
+
+struct Scanner<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    offset: usize,
+    line: usize,
+    column: usize,
+    positions: HashMap<String, (usize, usize)>,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.offset).copied()
+    }
+
+    fn bump(&mut self) {
+        let Some(byte) = self.peek() else {
+            return;
+        };
+        self.offset += 1;
+        if byte == b'\n' {
+            self.line += 1;
+            self.column = 1;
+        } else if byte & 0xC0 != 0x80 {
+            // UTF-8 연속 byte 는 열로 세지 않음
+            self.column += 1;
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn value(&mut self, path: &str) {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.object(path),
+            Some(b'[') => self.array(path),
+            Some(b'"') => {
+                self.string();
+            }
+            Some(_) => {
+                while self
+                    .peek()
+                    .is_some_and(|byte| !b",]}".contains(&byte) && !byte.is_ascii_whitespace())
+                {
+                    self.bump();
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn object(&mut self, path: &str) {
+        self.bump();
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                self.bump();
+                return;
+            }
+            let position = (self.line, self.column);
+            let key = self.string();
+            let child = if path.is_empty() {
+                key
+            } else {
+                format!("{}.{}", path, key)
+            };
+            self.positions.insert(child.clone(), position);
+            self.skip_whitespace();
+            self.bump(); // ':'
+            self.value(&child);
+            self.skip_whitespace();
+            if self.peek() != Some(b',') {
+                self.bump(); // '}'
+                return;
+            }
+            self.bump();
+        }
+    }
+
+    fn array(&mut self, path: &str) {
+        self.bump();
+        let mut index = 0;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b']') | None => {
+                    self.bump();
+                    return;
+                }
+                Some(_) => {}
+            }
+            let child = format!("{}[{}]", path, index);
+            self.positions
+                .insert(child.clone(), (self.line, self.column));
+            self.value(&child);
+            self.skip_whitespace();
+            if self.peek() != Some(b',') {
+                self.bump(); // ']'
+                return;
+            }
+            self.bump();
+            index += 1;
+        }
+    }
+
+    /// 문자열을 읽고 escape 를 해석한 값 반환
+    fn string(&mut self) -> String {
+        let start = self.offset;
+        self.bump();
+        while let Some(byte) = self.peek() {
+            self.bump();
+            match byte {
+                b'\\' => self.bump(),
+                b'"' => break,
+                _ => {}
+            }
+        }
+        serde_json::from_str(&self.text[start..self.offset]).unwrap_or_default()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod check;
+pub mod server;
+
+pub use check::ConfigIssue;
+pub use server::ServerConfig;
@@ -0,0 +1,141 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::alert::AlertConfig;
+use crate::announce::AnnounceConfig;
+use crate::bus::BusConfig;
+use crate::config::check;
+use crate::influx::InfluxConfig;
+use crate::ipc::IpcConfig;
+use crate::lidar::IdentityConfig;
+use crate::logging::LogConfig;
+use crate::plugin::PluginConfig;
+use crate::processing::ProcessingConfig;
+use crate::record::RecorderConfig;
+use crate::relay::RelayConfig;
+use crate::replication::ReplicationConfig;
+use crate::sim::SimConfig;
+use crate::site::SiteConfig;
+use crate::supervisor::SupervisorConfig;
+use crate::telemetry::TelemetryConfig;
+use crate::udp::UdpListenerConfig;
+use crate::ws::WsServerConfig;
+
+/// 서버 전체 설정 구조체
+///
+/// # Examples
+/// ```
+/// let config = ServerConfig::load(Path::new("lidar_server.json"))?;
+/// ```
+///
+/// # Fields
+/// * `udp` - UDP 리스너 설정
+/// * `ws` - WebSocket 서버 설정
+/// * `announce` - LAN 서비스 알림(mDNS) 설정
+/// * `identity` - LiDAR 식별 전략 설정
+/// * `record` - 녹화/재생 설정
+/// * `processing` - 포인트 클라우드 처리(통계 등) 설정
+/// * `telemetry` - 장치 상태 텔레메트리 설정
+/// * `alert` - 알림 규칙 및 알림 채널 설정
+/// * `plugins` - 외부 파서 플러그인 설정
+/// * `ipc` - 같은 호스트 소비자용 IPC 출력 설정
+/// * `bus` - 메시지 버스(NATS) 발행 설정
+/// * `influx` - 시계열 데이터베이스(InfluxDB) 기록 설정
+/// * `sites` - LiDAR 그룹(사이트) 설정
+/// * `replication` - 이중화(주/대기 서버) 설정
+/// * `relay` - 상위 LiDAR 서버 구독(릴레이) 설정
+/// * `supervisor` - 내부 태스크 감시(패닉 시 재시작) 설정
+/// * `sim` - 녹화에서 추출한 프로파일로 데이터를 보내는 가상 LiDAR(시뮬레이터) 설정
+/// * `log` - 로그 출력 설정
+///
+/// # 설정 파일 예시
+/// ```json
+/// {
+///     "udp": { "addr": "0.0.0.0:5000", "recv_buffer_size": 8388608 },
+///     "ws": { "start_port": 5555 },
+///     "announce": { "enabled": true }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub udp: UdpListenerConfig,
+    pub ws: WsServerConfig,
+    pub announce: AnnounceConfig,
+    pub identity: IdentityConfig,
+    pub record: RecorderConfig,
+    pub processing: ProcessingConfig,
+    pub telemetry: TelemetryConfig,
+    pub alert: AlertConfig,
+    pub plugins: PluginConfig,
+    pub ipc: IpcConfig,
+    pub bus: BusConfig,
+    pub influx: InfluxConfig,
+    pub sites: SiteConfig,
+    pub replication: ReplicationConfig,
+    pub relay: RelayConfig,
+    pub supervisor: SupervisorConfig,
+    pub sim: SimConfig,
+    pub log: LogConfig,
+}
+
+impl ServerConfig {
+    /// 설정 파일 로드
+    ///
+    /// # Arguments
+    /// * `path` - JSON 설정 파일 경로
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 설정, 실패 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 파일이 없으면 기본 설정 반환
+    /// * 파일에 없는 항목은 기본값 사용
+    /// * 파싱 에러는 줄/열 번호와 가까운 항목 경로를 함께 반환
+    /// * 알 수 없는 키, 포트 충돌, 잘못된 멀티캐스트 주소를 모두 찾아 줄/열 번호와 함께 반환 (`check::check`)
+    /// * 알림 규칙이 참조하는 알림 채널 확인
+    /// * 이름 있는 구독 설정(`ws.presets`) 확인
+    /// * 사이트 설정 확인 후 알림 규칙이 참조하는 사이트의 LiDAR 를 규칙에 추가
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let mut config: Self = serde_json::from_str(&text).map_err(|e| {
+            match check::path_at(&text, e.line(), e.column()) {
+                Some(near) => format!("Failed to parse {}: {} (near {})", path.display(), e, near),
+                None => format!("Failed to parse {}: {}", path.display(), e),
+            }
+        })?;
+        let issues = check::check(&text, &config);
+        if !issues.is_empty() {
+            let issues: Vec<String> = issues
+                .iter()
+                .map(|issue| format!("  - {}", issue))
+                .collect();
+            return Err(format!(
+                "Invalid {}:\n{}",
+                path.display(),
+                issues.join("\n")
+            ));
+        }
+        config
+            .alert
+            .validate()
+            .and_then(|_| config.udp.impairment.validate())
+            .and_then(|_| config.udp.error_budget.validate())
+            .and_then(|_| config.ws.validate())
+            .and_then(|_| config.sites.validate())
+            .and_then(|_| config.replication.validate())
+            .and_then(|_| config.sim.validate())
+            .and_then(|_| config.log.validate())
+            .and_then(|_| config.processing.tracking.import_tf_tree(&config.sites))
+            .and_then(|_| config.processing.validate())
+            .and_then(|_| config.alert.resolve_sites(&config.sites))
+            .map_err(|e| format!("Invalid {}: {}", path.display(), e))?;
+        Ok(config)
+    }
+}
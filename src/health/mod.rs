@@ -0,0 +1,5 @@
+pub mod status;
+pub mod systemd;
+
+pub use status::{HealthReport, HealthState};
+pub use systemd::SystemdNotifier;
@@ -0,0 +1,148 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use utoipa::ToSchema;
+
+use crate::common::time::unix_time_ms;
+use crate::udp::socket::MulticastJoin;
+
+#[derive(Debug, Default)]
+struct HealthInner {
+    config_loaded: bool,
+    udp_addr: Option<SocketAddr>,
+    multicast: Vec<MulticastJoin>,
+    ws_addr: Option<SocketAddr>,
+}
+
+/// 서버 시작 상태 (`/healthz`, `/readyz` 응답)
+///
+/// # Fields
+/// * `ready` - 설정 로드, UDP 바인딩, 멀티캐스트 가입(하나 이상의 인터페이스), WebSocket 연결 수락이 모두 완료되면 true
+/// * `config_loaded` - 설정 파일 로드 여부 (파일이 없어 기본 설정을 사용한 경우 포함)
+/// * `udp_bound` - UDP 소켓 바인딩 여부
+/// * `udp_addr` - 바인딩한 UDP 주소, 바인딩 전이면 null
+/// * `multicast` - 인터페이스별 멀티캐스트 가입 결과와 가입/탈퇴 통계 (인터페이스 변경 시 갱신)
+/// * `ws_accepting` - WebSocket 서버의 연결 수락 여부
+/// * `ws_addr` - WebSocket 서버 주소, 바인딩 전이면 null
+/// * `uptime_ms` - 서버 시작 후 경과 시간 (ms)
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct HealthReport {
+    pub ready: bool,
+    pub config_loaded: bool,
+    pub udp_bound: bool,
+    pub udp_addr: Option<String>,
+    pub multicast: Vec<MulticastJoin>,
+    pub ws_accepting: bool,
+    pub ws_addr: Option<String>,
+    pub uptime_ms: u64,
+}
+
+/// 서버 시작 상태 저장소
+///
+/// # Examples
+/// ```
+/// let health = HealthState::new();
+/// health.set_config_loaded();
+/// health.set_udp_bound(config.udp.addr, joins);
+/// health.set_ws_accepting(ws_addr);
+/// let report = health.report();
+/// ```
+///
+/// # 동작 설명
+/// * 각 서브시스템이 시작 단계를 마칠 때 상태를 기록하고, REST 엔드포인트에서 보고서로 조회
+/// * `wait_bound` 로 UDP 소켓과 WebSocket 서버 바인딩 완료를 기다릴 수 있음 (systemd `READY=1` 알림)
+/// * UDP 리스너와 WebSocket 서버가 같은 저장소를 공유
+#[derive(Debug, Clone)]
+pub struct HealthState {
+    started_ms: u64,
+    inner: Arc<Mutex<HealthInner>>,
+    changed: Arc<Notify>,
+}
+
+impl Default for HealthState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HealthState {
+    pub fn new() -> Self {
+        Self {
+            started_ms: unix_time_ms(),
+            inner: Arc::new(Mutex::new(HealthInner::default())),
+            changed: Arc::new(Notify::new()),
+        }
+    }
+
+    /// 설정 로드 완료 기록
+    pub fn set_config_loaded(&self) {
+        self.inner.lock().unwrap().config_loaded = true;
+    }
+
+    /// UDP 소켓 바인딩과 멀티캐스트 가입 결과 기록
+    ///
+    /// # Arguments
+    /// * `addr` - 바인딩한 UDP 주소
+    /// * `multicast` - 인터페이스별 멀티캐스트 가입 결과
+    pub fn set_udp_bound(&self, addr: SocketAddr, multicast: Vec<MulticastJoin>) {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            inner.udp_addr = Some(addr);
+            inner.multicast = multicast;
+        }
+        self.changed.notify_waiters();
+    }
+
+    /// 현재 인터페이스별 멀티캐스트 가입 결과
+    pub fn multicast(&self) -> Vec<MulticastJoin> {
+        self.inner.lock().unwrap().multicast.clone()
+    }
+
+    /// 인터페이스 변경으로 갱신한 멀티캐스트 가입 결과 기록
+    ///
+    /// # Arguments
+    /// * `multicast` - 인터페이스별 멀티캐스트 가입 결과
+    pub fn set_multicast(&self, multicast: Vec<MulticastJoin>) {
+        self.inner.lock().unwrap().multicast = multicast;
+    }
+
+    /// WebSocket 서버 연결 수락 시작 기록
+    pub fn set_ws_accepting(&self, addr: SocketAddr) {
+        self.inner.lock().unwrap().ws_addr = Some(addr);
+        self.changed.notify_waiters();
+    }
+
+    /// UDP 소켓 바인딩과 WebSocket 연결 수락 시작을 모두 마칠 때까지 대기
+    pub async fn wait_bound(&self) {
+        loop {
+            let changed = self.changed.notified();
+            {
+                let inner = self.inner.lock().unwrap();
+                if inner.udp_addr.is_some() && inner.ws_addr.is_some() {
+                    return;
+                }
+            }
+            changed.await;
+        }
+    }
+
+    /// 현재 시작 상태 보고서
+    pub fn report(&self) -> HealthReport {
+        let inner = self.inner.lock().unwrap();
+        let udp_bound = inner.udp_addr.is_some();
+        let ws_accepting = inner.ws_addr.is_some();
+        let multicast_joined = inner.multicast.iter().any(|join| join.joined);
+        HealthReport {
+            ready: inner.config_loaded && udp_bound && multicast_joined && ws_accepting,
+            config_loaded: inner.config_loaded,
+            udp_bound,
+            udp_addr: inner.udp_addr.map(|addr| addr.to_string()),
+            multicast: inner.multicast.clone(),
+            ws_accepting,
+            ws_addr: inner.ws_addr.map(|addr| addr.to_string()),
+            uptime_ms: unix_time_ms().saturating_sub(self.started_ms),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(all(unix, feature = "systemd"))]
+use std::os::unix::net::UnixDatagram;
+use std::time::Duration;
+use tracing::*;
+
+/// systemd 알림(sd_notify) 송신기
+///
+/// # Examples
+/// ```
+/// let notifier = SystemdNotifier::from_env();
+/// notifier.notify("READY=1");
+/// if let Some(interval) = notifier.watchdog_interval() {
+///     // interval 마다 notifier.notify("WATCHDOG=1")
+/// }
+/// notifier.notify("STOPPING=1");
+/// ```
+///
+/// # 동작 설명
+/// * `NOTIFY_SOCKET` 환경 변수의 Unix 데이터그램 소켓으로 상태 문자열 송신 (`@` 로 시작하면 Linux abstract 소켓)
+/// * `WATCHDOG_USEC` 가 설정되어 있고 `WATCHDOG_PID` 가 없거나 현재 프로세스이면 그 절반을 watchdog 주기로 사용
+/// * systemd 에서 실행하지 않았거나(`NOTIFY_SOCKET` 없음) `systemd` feature 없이 빌드한 경우 아무것도 보내지 않음
+pub struct SystemdNotifier {
+    #[cfg(all(unix, feature = "systemd"))]
+    socket: Option<(UnixDatagram, std::os::unix::net::SocketAddr)>,
+    watchdog: Option<Duration>,
+}
+
+impl SystemdNotifier {
+    /// 환경 변수로 알림 송신기 생성
+    #[cfg(all(unix, feature = "systemd"))]
+    pub fn from_env() -> Self {
+        let socket = std::env::var_os("NOTIFY_SOCKET").and_then(|path| {
+            let result = notify_addr(&path).and_then(|addr| Ok((UnixDatagram::unbound()?, addr)));
+            match result {
+                Ok(socket) => {
+                    info!("systemd notification socket: {:?}", path);
+                    Some(socket)
+                }
+                Err(e) => {
+                    error!(
+                        "Failed to open systemd notification socket {:?}: {}",
+                        path, e
+                    );
+                    None
+                }
+            }
+        });
+        let watchdog = socket.as_ref().and_then(|_| watchdog_interval_from_env());
+        if let Some(interval) = watchdog {
+            info!("systemd watchdog enabled ({} ms)", interval.as_millis());
+        }
+        Self { socket, watchdog }
+    }
+
+    /// 환경 변수로 알림 송신기 생성 (`systemd` feature 없이 빌드하면 항상 비활성)
+    #[cfg(not(all(unix, feature = "systemd")))]
+    pub fn from_env() -> Self {
+        if std::env::var_os("NOTIFY_SOCKET").is_some() {
+            error!("systemd notification requires the `systemd` feature on Unix");
+        }
+        Self { watchdog: None }
+    }
+
+    /// watchdog 알림 주기 (`WATCHDOG_USEC` 의 절반), watchdog 미사용 시 None
+    pub fn watchdog_interval(&self) -> Option<Duration> {
+        self.watchdog
+    }
+
+    /// 상태 문자열 송신 (예: `READY=1`, `WATCHDOG=1`, `STOPPING=1`)
+    ///
+    /// 송신 실패 시 경고 로그만 출력
+    pub fn notify(&self, state: &str) {
+        #[cfg(all(unix, feature = "systemd"))]
+        if let Some((socket, addr)) = &self.socket {
+            if let Err(e) = socket.send_to_addr(state.as_bytes(), addr) {
+                warn!("Failed to send systemd notification {}: {}", state, e);
+            }
+        }
+        #[cfg(not(all(unix, feature = "systemd")))]
+        let _ = state;
+    }
+}
+
+/// `NOTIFY_SOCKET` 값을 소켓 주소로 변환
+#[cfg(all(unix, feature = "systemd"))]
+fn notify_addr(path: &std::ffi::OsStr) -> std::io::Result<std::os::unix::net::SocketAddr> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let bytes = path.as_bytes();
+    match bytes.split_first() {
+        #[cfg(target_os = "linux")]
+        Some((b'@', name)) => {
+            use std::os::linux::net::SocketAddrExt;
+            std::os::unix::net::SocketAddr::from_abstract_name(name)
+        }
+        Some(_) => std::os::unix::net::SocketAddr::from_pathname(path),
+        None => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "empty NOTIFY_SOCKET",
+        )),
+    }
+}
+
+/// `WATCHDOG_USEC`, `WATCHDOG_PID` 로 watchdog 알림 주기 계산
+#[cfg(all(unix, feature = "systemd"))]
+fn watchdog_interval_from_env() -> Option<Duration> {
+    if let Ok(pid) = std::env::var("WATCHDOG_PID") {
+        if pid.parse::<u32>().ok() != Some(std::process::id()) {
+            return None;
+        }
+    }
+    let usec: u64 = std::env::var("WATCHDOG_USEC").ok()?.parse().ok()?;
+    (usec > 0).then_some(Duration::from_micros(usec / 2))
+}
@@ -0,0 +1,53 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// InfluxDB 기록 설정 구조체
+///
+/// # Fields
+/// * `enabled` - InfluxDB 기록 사용 여부 (`influx` feature 필요)
+/// * `url` - InfluxDB 주소 (v2 write API `/api/v2/write` 사용, 1.8 이상은 호환 API 로 동작)
+/// * `org` - 조직 이름 (InfluxDB 1.x 는 비워둠)
+/// * `bucket` - 버킷 이름 (InfluxDB 1.x 는 `database/retention_policy`)
+/// * `token` - API 토큰 (InfluxDB 1.x 는 `username:password`)
+/// * `measurement_prefix` - measurement 이름 접두어 (`<prefix>telemetry`, `<prefix>alert`, `<prefix>track`)
+/// * `batch_size` - 한 번에 전송할 최대 줄 수
+/// * `flush_interval_ms` - `batch_size` 를 채우지 못해도 전송하는 주기 (ms)
+/// * `max_retries` - 전송 실패 시 재시도 횟수, 모두 실패하면 해당 배치는 버림
+/// * `retry_delay_ms` - 재시도 간격 (ms), 재시도마다 두 배로 증가
+/// * `timeout_ms` - 전송 제한 시간 (ms)
+/// * `queue_size` - 전송 대기열 크기 (줄 수), 가득 차면 새 데이터는 버림
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct InfluxConfig {
+    pub enabled: bool,
+    pub url: String,
+    pub org: String,
+    pub bucket: String,
+    pub token: Option<String>,
+    pub measurement_prefix: String,
+    pub batch_size: usize,
+    pub flush_interval_ms: u64,
+    pub max_retries: u32,
+    pub retry_delay_ms: u64,
+    pub timeout_ms: u64,
+    pub queue_size: usize,
+}
+
+impl Default for InfluxConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: "http://127.0.0.1:8086".to_string(),
+            org: String::new(),
+            bucket: "lidar".to_string(),
+            token: None,
+            measurement_prefix: "lidar_".to_string(),
+            batch_size: 500,
+            flush_interval_ms: 1000,
+            max_retries: 3,
+            retry_delay_ms: 1000,
+            timeout_ms: 5000,
+            queue_size: 10000,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::alert::{Alert, AlertState};
+use crate::lidar::LiDARKey;
+use crate::processing::TrackedObjects;
+use crate::telemetry::TelemetrySample;
+use crate::ws::client::OutputFrame;
+
+/// 텔레메트리 샘플을 InfluxDB line protocol 로 변환
+///
+/// # Examples
+/// ```
+/// // lidar_telemetry,ip=192.168.123.200,port=5000,product_line=1,lidar_id=0 packet_rate=10,... 1700000000000
+/// let line = telemetry_line("lidar_", &sample);
+/// ```
+pub fn telemetry_line(prefix: &str, sample: &TelemetrySample) -> String {
+    let mut line = format!(
+        "{}telemetry,{} packet_rate={},frame_rate={},mean_points={},config_responses={}i,nak_count={}i,dropped_packets={}i,reorder_depth={}i,late_drops={}i",
+        escape_key(prefix),
+        key_tags(&sample.key),
+        sample.packet_rate,
+        sample.frame_rate,
+        sample.mean_points,
+        sample.config_responses,
+        sample.nak_count,
+        sample.dropped_packets,
+        sample.reorder_depth,
+        sample.late_drops,
+    );
+    if let Some(speed) = sample.motor_speed {
+        line.push_str(&format!(",motor_speed={}i", speed));
+    }
+    line.push_str(&format!(" {}", sample.time_ms));
+    line
+}
+
+/// 알림(영역 침입 등)을 InfluxDB line protocol 로 변환
+///
+/// # Examples
+/// ```
+/// // lidar_alert,rule=lane-1,kind=zone_intrusion,state=firing,ip=...,port=5000,product_line=1,lidar_id=0 firing=1i,message="..." 1700000000000
+/// let line = alert_line("lidar_", &alert);
+/// ```
+pub fn alert_line(prefix: &str, alert: &Alert) -> String {
+    let (state, firing) = match alert.state {
+        AlertState::Firing => ("firing", 1),
+        AlertState::Resolved => ("resolved", 0),
+    };
+    format!(
+        "{}alert,rule={},kind={},state={},{} firing={}i,message=\"{}\" {}",
+        escape_key(prefix),
+        escape_key(&alert.rule),
+        escape_key(&alert.kind),
+        state,
+        key_tags(&alert.key),
+        firing,
+        escape_string(&alert.message),
+        alert.time_ms,
+    )
+}
+
+/// 한 프레임의 확정 물체를 물체마다 InfluxDB line protocol 로 변환
+///
+/// # Examples
+/// ```
+/// // lidar_track,ip=...,port=5000,product_line=1,lidar_id=0,track_id=7,frame=world x=12.3,y=-1.5,vx=13.9,vy=0.2,speed=13.9,heading=0.8,size=4.2 1700000000000
+/// let lines = track_lines("lidar_", &objects);
+/// ```
+pub fn track_lines(prefix: &str, objects: &TrackedObjects) -> Vec<String> {
+    let frame = match objects.frame {
+        OutputFrame::Lidar => "lidar",
+        OutputFrame::World => "world",
+    };
+    objects
+        .objects
+        .iter()
+        .map(|object| {
+            format!(
+                "{}track,{},track_id={},frame={} x={},y={},vx={},vy={},speed={},heading={},size={} {}",
+                escape_key(prefix),
+                key_tags(&objects.key),
+                object.id,
+                frame,
+                object.position[0],
+                object.position[1],
+                object.velocity[0],
+                object.velocity[1],
+                object.speed,
+                object.heading,
+                object.size,
+                objects.time_ms,
+            )
+        })
+        .collect()
+}
+
+/// LiDAR 키 태그
+fn key_tags(key: &LiDARKey) -> String {
+    format!(
+        "ip={},port={},product_line={},lidar_id={}",
+        key.ip, key.port, key.product_line, key.lidar_id
+    )
+}
+
+/// measurement/태그 이름과 값 escape (쉼표, 등호, 공백)
+fn escape_key(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            ',' | '=' | ' ' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' | '\r' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 문자열 필드 값 escape (따옴표, 역슬래시)
+fn escape_string(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace(['\n', '\r'], " ")
+}
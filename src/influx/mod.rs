@@ -0,0 +1,8 @@
+pub mod config;
+pub mod line;
+#[cfg(feature = "influx")]
+pub mod writer;
+
+pub use config::InfluxConfig;
+#[cfg(feature = "influx")]
+pub use writer::InfluxWriter;
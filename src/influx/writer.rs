@@ -0,0 +1,166 @@
+use std::time::Duration;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tracing::*;
+
+use crate::alert::Alert;
+use crate::influx::config::InfluxConfig;
+use crate::influx::line::{alert_line, telemetry_line, track_lines};
+use crate::processing::TrackedObjects;
+use crate::telemetry::TelemetrySample;
+
+/// InfluxDB 시계열 기록기
+///
+/// # Examples
+/// ```
+/// let influx = InfluxWriter::start(&config.influx);
+/// influx.write_telemetry(&samples);
+/// influx.write_alert(&alert);
+/// influx.write_tracks(&objects);
+/// ```
+///
+/// # 동작 설명
+/// * 텔레메트리 샘플(`<prefix>telemetry`), 알림/영역 침입 이벤트(`<prefix>alert`), 추적 물체(`<prefix>track`)를 line protocol 로 변환
+/// * 백그라운드 태스크에서 `batch_size` 줄 또는 `flush_interval_ms` 마다 모아서 전송
+/// * 전송 실패 시 `retry_delay_ms` 부터 두 배씩 늘려가며 `max_retries` 번 재시도, 모두 실패하면 배치를 버림
+/// * 기록은 대기열에 넣고 바로 반환하며, 대기열이 가득 차면 버림
+pub struct InfluxWriter {
+    prefix: String,
+    tx: mpsc::Sender<String>,
+}
+
+impl InfluxWriter {
+    /// 기록 시작
+    ///
+    /// # Arguments
+    /// * `config` - InfluxDB 기록 설정
+    ///
+    /// # Returns
+    /// * `Self` - 기록기
+    pub fn start(config: &InfluxConfig) -> Self {
+        let (tx, rx) = mpsc::channel(config.queue_size.max(1));
+        tokio::spawn(run(config.clone(), rx));
+        info!("Writing time series to InfluxDB {}", config.url);
+        Self {
+            prefix: config.measurement_prefix.clone(),
+            tx,
+        }
+    }
+
+    /// 텔레메트리 샘플 기록
+    pub fn write_telemetry(&self, samples: &[TelemetrySample]) {
+        for sample in samples {
+            self.enqueue(telemetry_line(&self.prefix, sample));
+        }
+    }
+
+    /// 알림 (영역 침입 포함) 기록
+    pub fn write_alert(&self, alert: &Alert) {
+        self.enqueue(alert_line(&self.prefix, alert));
+    }
+
+    /// 한 프레임의 확정 물체 기록 (물체마다 한 줄)
+    pub fn write_tracks(&self, objects: &TrackedObjects) {
+        for line in track_lines(&self.prefix, objects) {
+            self.enqueue(line);
+        }
+    }
+
+    fn enqueue(&self, line: String) {
+        if let Err(TrySendError::Full(_)) = self.tx.try_send(line) {
+            warn!("InfluxDB queue full, dropping point");
+        }
+    }
+}
+
+/// 대기열의 줄을 모아서 전송
+async fn run(config: InfluxConfig, mut rx: mpsc::Receiver<String>) {
+    let client = reqwest::Client::new();
+    let write_url = format!("{}/api/v2/write", config.url.trim_end_matches('/'));
+    let batch_size = config.batch_size.max(1);
+    let mut interval =
+        tokio::time::interval(Duration::from_millis(config.flush_interval_ms.max(1)));
+    let mut batch: Vec<String> = Vec::with_capacity(batch_size);
+
+    loop {
+        tokio::select! {
+            line = rx.recv() => match line {
+                Some(line) => {
+                    batch.push(line);
+                    if batch.len() < batch_size {
+                        continue;
+                    }
+                }
+                None => {
+                    if !batch.is_empty() {
+                        flush(&client, &write_url, &config, &batch).await;
+                    }
+                    return;
+                }
+            },
+            _ = interval.tick() => {
+                if batch.is_empty() {
+                    continue;
+                }
+            }
+        }
+        flush(&client, &write_url, &config, &batch).await;
+        batch.clear();
+    }
+}
+
+/// 배치 전송 (실패 시 재시도)
+async fn flush(client: &reqwest::Client, url: &str, config: &InfluxConfig, batch: &[String]) {
+    let body = batch.join("\n");
+    let mut delay = Duration::from_millis(config.retry_delay_ms);
+    for attempt in 0..=config.max_retries {
+        match write(client, url, config, body.clone()).await {
+            Ok(()) => return,
+            Err(e) if attempt < config.max_retries => {
+                warn!(
+                    "Failed to write to InfluxDB (attempt {}): {}",
+                    attempt + 1,
+                    e
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Err(e) => {
+                error!(
+                    "Failed to write {} points to InfluxDB, dropping: {}",
+                    batch.len(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// v2 write API 로 전송
+async fn write(
+    client: &reqwest::Client,
+    url: &str,
+    config: &InfluxConfig,
+    body: String,
+) -> Result<(), String> {
+    let mut request = client
+        .post(url)
+        .query(&[
+            ("org", config.org.as_str()),
+            ("bucket", config.bucket.as_str()),
+            ("precision", "ms"),
+        ])
+        .header("Content-Type", "text/plain; charset=utf-8")
+        .timeout(Duration::from_millis(config.timeout_ms))
+        .body(body);
+    if let Some(token) = &config.token {
+        request = request.header("Authorization", format!("Token {}", token));
+    }
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        Err(format!("{} {}", status, text.trim()))
+    }
+}
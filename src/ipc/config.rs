@@ -0,0 +1,77 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// 같은 호스트의 프로세스로 LiDAR 데이터를 전달하는 IPC 출력 설정 구조체
+///
+/// # Fields
+/// * `enabled` - IPC 출력 사용 여부
+/// * `path` - Unix 도메인 소켓 경로 (Windows 는 named pipe 이름, 예: `\\.\pipe\lidar_server`)
+/// * `queue_size` - 소비자별 전송 대기열 크기, 가득 차면 오래된 데이터부터 버림
+/// * `shm` - 공유 메모리 출력 설정
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct IpcConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub queue_size: usize,
+    pub shm: ShmConfig,
+}
+
+impl Default for IpcConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_path().to_string(),
+            queue_size: 256,
+            shm: ShmConfig::default(),
+        }
+    }
+}
+
+#[cfg(windows)]
+fn default_path() -> &'static str {
+    r"\\.\pipe\lidar_server"
+}
+
+#[cfg(not(windows))]
+fn default_path() -> &'static str {
+    "/tmp/lidar_server.sock"
+}
+
+/// 공유 메모리 출력 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 공유 메모리 출력 사용 여부 (`shm` feature 필요)
+/// * `path` - 매핑할 파일 경로 (Linux 는 `/dev/shm` 아래 사용 권장)
+/// * `slots` - 슬롯 수 (LiDAR/채널별로 하나씩 사용)
+/// * `max_points` - 슬롯당 최대 포인트 수, 넘는 포인트는 기록하지 않음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ShmConfig {
+    pub enabled: bool,
+    pub path: PathBuf,
+    pub slots: usize,
+    pub max_points: usize,
+}
+
+impl Default for ShmConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_shm_path(),
+            slots: 16,
+            max_points: 8192,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn default_shm_path() -> PathBuf {
+    PathBuf::from("/dev/shm/lidar_server")
+}
+
+#[cfg(not(target_os = "linux"))]
+fn default_shm_path() -> PathBuf {
+    std::env::temp_dir().join("lidar_server.shm")
+}
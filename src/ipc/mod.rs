@@ -0,0 +1,9 @@
+pub mod config;
+pub mod output;
+#[cfg(feature = "shm")]
+pub mod shm;
+
+pub use config::{IpcConfig, ShmConfig};
+pub use output::IpcOutput;
+#[cfg(feature = "shm")]
+pub use shm::{ShmFrame, ShmOutput, ShmReader};
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::*;
+
+use crate::common::data::LiDARPacket;
+use crate::common::time::unix_time_ms;
+use crate::ipc::config::IpcConfig;
+use crate::record::format::{write_entry, write_header, RecordEntry};
+
+/// 같은 호스트의 소비자에게 LiDAR 데이터를 전달하는 IPC 출력
+///
+/// # Examples
+/// ```
+/// let output = IpcOutput::start(&config.ipc)?;
+/// output.send(&packet);
+/// ```
+///
+/// # 주요 기능
+/// * Unix 도메인 소켓 (Windows 는 named pipe) 으로 여러 소비자 연결 허용
+/// * 연결 시 녹화 파일 헤더(`LREC` + 버전)를 보낸 뒤 `[길이(u32, big endian)][bincode 항목]` 을 반복 전송
+///   (녹화 파일과 같은 형식이므로 그대로 파일로 저장하면 재생 가능)
+/// * 녹화 재생 데이터는 전달하지 않음
+/// * 느린 소비자는 대기열이 가득 차면 오래된 데이터를 버림
+pub struct IpcOutput {
+    tx: broadcast::Sender<Arc<Vec<u8>>>,
+}
+
+impl IpcOutput {
+    /// IPC 출력 시작
+    ///
+    /// # Arguments
+    /// * `config` - IPC 출력 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 IPC 출력, 소켓/파이프 생성 실패 시 에러 메시지
+    pub fn start(config: &IpcConfig) -> Result<Self, String> {
+        let (tx, _) = broadcast::channel(config.queue_size.max(1));
+        listen(&config.path, tx.clone())
+            .map_err(|e| format!("Failed to listen on {}: {}", config.path, e))?;
+        info!("IPC output listening on {}", config.path);
+        Ok(Self { tx })
+    }
+
+    /// LiDAR 데이터 전달 (연결된 소비자가 없으면 무시)
+    pub fn send(&self, packet: &LiDARPacket) {
+        if packet.replay || self.tx.receiver_count() == 0 {
+            return;
+        }
+
+        let entry = RecordEntry {
+            time_ms: unix_time_ms(),
+            key: packet.key,
+            payload: packet.payload.clone(),
+        };
+        let mut frame = Vec::with_capacity(packet.payload.len() + 32);
+        if let Err(e) = write_entry(&mut frame, &entry) {
+            error!("Failed to encode IPC frame: {}", e);
+            return;
+        }
+        let _ = self.tx.send(Arc::new(frame));
+    }
+}
+
+/// 소비자에게 헤더와 LiDAR 데이터 전송 (쓰기에 실패하면 연결 종료)
+async fn serve<W: AsyncWrite + Unpin>(mut writer: W, mut rx: broadcast::Receiver<Arc<Vec<u8>>>) {
+    let mut header = Vec::new();
+    if write_header(&mut header).is_err() || writer.write_all(&header).await.is_err() {
+        return;
+    }
+    loop {
+        match rx.recv().await {
+            Ok(frame) => {
+                if let Err(e) = writer.write_all(&frame).await {
+                    debug!("IPC consumer disconnected: {}", e);
+                    return;
+                }
+            }
+            Err(RecvError::Lagged(count)) => {
+                warn!("IPC consumer lagging, dropped {} frames", count);
+            }
+            Err(RecvError::Closed) => return,
+        }
+    }
+}
+
+/// Unix 도메인 소켓 대기 (이전 실행에서 남은 소켓 파일은 삭제)
+#[cfg(unix)]
+fn listen(path: &str, tx: broadcast::Sender<Arc<Vec<u8>>>) -> std::io::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    if std::fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+        std::fs::remove_file(path)?;
+    }
+    let listener = tokio::net::UnixListener::bind(path)?;
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    info!("IPC consumer connected");
+                    tokio::spawn(serve(stream, tx.subscribe()));
+                }
+                Err(e) => error!("Failed to accept IPC consumer: {}", e),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// named pipe 대기 (연결될 때마다 다음 연결용 인스턴스 생성)
+#[cfg(windows)]
+fn listen(path: &str, tx: broadcast::Sender<Arc<Vec<u8>>>) -> std::io::Result<()> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let path = path.to_string();
+    let mut server = ServerOptions::new()
+        .first_pipe_instance(true)
+        .create(&path)?;
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = server.connect().await {
+                error!("Failed to accept IPC consumer: {}", e);
+                continue;
+            }
+            let connected = server;
+            server = match ServerOptions::new().create(&path) {
+                Ok(server) => server,
+                Err(e) => {
+                    error!("Failed to create named pipe {}: {}", path, e);
+                    return;
+                }
+            };
+            info!("IPC consumer connected");
+            tokio::spawn(serve(connected, tx.subscribe()));
+        }
+    });
+    Ok(())
+}
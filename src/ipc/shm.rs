@@ -0,0 +1,365 @@
+use memmap2::{Mmap, MmapMut};
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::net::Ipv4Addr;
+use std::path::Path;
+use std::sync::atomic::{fence, AtomicU32, AtomicU64, Ordering};
+use tokio::sync::Mutex;
+use tracing::*;
+
+use crate::common::time::unix_time_ms;
+use crate::ipc::config::ShmConfig;
+use crate::lidar::{LiDARKey, Point};
+
+/// 공유 메모리 파일 식별자
+pub const SHM_MAGIC: [u8; 4] = *b"LSHM";
+
+/// 공유 메모리 형식 버전
+pub const SHM_VERSION: u32 = 1;
+
+/// 파일 헤더 크기 (bytes)
+pub const HEADER_SIZE: usize = 64;
+
+/// 슬롯 헤더 크기 (bytes)
+pub const SLOT_HEADER_SIZE: usize = 64;
+
+/// 포인트 크기 (x, y, z f32)
+const POINT_SIZE: usize = 12;
+
+// 파일 헤더 오프셋
+const HEADER_VERSION: usize = 4;
+const HEADER_SLOT_COUNT: usize = 8;
+const HEADER_MAX_POINTS: usize = 12;
+const HEADER_SLOT_STRIDE: usize = 16;
+const HEADER_USED_SLOTS: usize = 20;
+
+// 슬롯 헤더 오프셋
+const SLOT_SEQ: usize = 0;
+const SLOT_IP: usize = 8;
+const SLOT_PORT: usize = 12;
+const SLOT_PRODUCT_LINE: usize = 14;
+const SLOT_LIDAR_ID: usize = 15;
+const SLOT_CHANNEL: usize = 16;
+const SLOT_POINT_COUNT: usize = 20;
+const SLOT_TIME_MS: usize = 24;
+
+/// 공유 메모리에서 읽은 프레임
+///
+/// # Fields
+/// * `seq` - 슬롯 갱신 번호 (짝수, 갱신될 때마다 2 씩 증가)
+/// * `key` - LiDAR 키
+/// * `channel` - 채널 번호
+/// * `time_ms` - 기록 시각 (Unix time, ms)
+/// * `points` - 포인트 목록
+#[derive(Debug, Clone)]
+pub struct ShmFrame {
+    pub seq: u64,
+    pub key: LiDARKey,
+    pub channel: u8,
+    pub time_ms: u64,
+    pub points: Vec<Point>,
+}
+
+/// 같은 호스트의 저지연 소비자용 공유 메모리 출력
+///
+/// # Examples
+/// ```
+/// let shm = ShmOutput::start(&config.ipc.shm)?;
+/// shm.publish(key, channel, &cloud.points).await;
+/// ```
+///
+/// # 메모리 구조 (little endian)
+/// * 파일 헤더 64 bytes: `LSHM`, 버전(u32), 슬롯 수(u32), 슬롯당 최대 포인트 수(u32), 슬롯 간격(u32), 사용 중인 슬롯 수(u32)
+/// * 슬롯: 파일 헤더 뒤에 슬롯 간격 단위로 반복
+///   - 0: 갱신 번호 (u64, seqlock: 홀수면 쓰는 중)
+///   - 8: IP (4 bytes), 12: 포트 (u16), 14: 제품 라인, 15: LiDAR ID, 16: 채널
+///   - 20: 포인트 수 (u32), 24: 기록 시각 (u64, Unix time ms)
+///   - 64: 포인트 `[x, y, z]` f32 배열
+///
+/// # 동작 설명
+/// * LiDAR/채널별로 슬롯을 하나씩 할당하여 최신 포인트 클라우드만 유지
+/// * 슬롯이 모두 사용 중이면 새 LiDAR/채널은 기록하지 않음
+/// * 슬롯당 최대 포인트 수를 넘는 포인트는 잘라서 기록
+pub struct ShmOutput {
+    inner: Mutex<ShmWriter>,
+}
+
+/// 공유 메모리 쓰기 상태
+struct ShmWriter {
+    map: MmapMut,
+    slots: HashMap<(LiDARKey, u8), usize>,
+    slot_count: usize,
+    max_points: usize,
+    stride: usize,
+    full_warned: bool,
+}
+
+impl ShmOutput {
+    /// 공유 메모리 출력 시작 (파일을 새로 만들고 헤더 기록)
+    ///
+    /// # Arguments
+    /// * `config` - 공유 메모리 출력 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 공유 메모리 출력, 파일 생성/매핑 실패 시 에러 메시지
+    pub fn start(config: &ShmConfig) -> Result<Self, String> {
+        let slot_count = config.slots.max(1);
+        let max_points = config.max_points.max(1);
+        let stride = SLOT_HEADER_SIZE + (max_points * POINT_SIZE).div_ceil(64) * 64;
+        let size = HEADER_SIZE + slot_count * stride;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&config.path)
+            .map_err(|e| format!("Failed to open {}: {}", config.path.display(), e))?;
+        file.set_len(size as u64)
+            .map_err(|e| format!("Failed to resize {}: {}", config.path.display(), e))?;
+        // SAFETY: 서버가 만든 파일을 매핑하며, 다른 프로세스는 읽기만 한다고 가정
+        let mut map = unsafe { MmapMut::map_mut(&file) }
+            .map_err(|e| format!("Failed to map {}: {}", config.path.display(), e))?;
+
+        map[..4].copy_from_slice(&SHM_MAGIC);
+        put_u32(&mut map, HEADER_SLOT_COUNT, slot_count as u32);
+        put_u32(&mut map, HEADER_MAX_POINTS, max_points as u32);
+        put_u32(&mut map, HEADER_SLOT_STRIDE, stride as u32);
+        put_u32(&mut map, HEADER_USED_SLOTS, 0);
+        // 버전은 마지막에 기록하여 소비자가 초기화가 끝난 파일만 읽도록 함
+        fence(Ordering::Release);
+        put_u32(&mut map, HEADER_VERSION, SHM_VERSION);
+
+        info!(
+            "Shared memory output at {} ({} slots, {} points)",
+            config.path.display(),
+            slot_count,
+            max_points
+        );
+        Ok(Self {
+            inner: Mutex::new(ShmWriter {
+                map,
+                slots: HashMap::new(),
+                slot_count,
+                max_points,
+                stride,
+                full_warned: false,
+            }),
+        })
+    }
+
+    /// LiDAR/채널의 최신 포인트 클라우드 기록
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `channel` - 채널 번호
+    /// * `points` - 포인트 목록
+    pub async fn publish(&self, key: LiDARKey, channel: u8, points: &[Point]) {
+        let mut writer = self.inner.lock().await;
+        let slot = match writer.slots.get(&(key, channel)) {
+            Some(&slot) => slot,
+            None => {
+                let slot = writer.slots.len();
+                if slot >= writer.slot_count {
+                    if !writer.full_warned {
+                        warn!("Shared memory slots full, ignoring new LiDAR/channel");
+                        writer.full_warned = true;
+                    }
+                    return;
+                }
+                writer.slots.insert((key, channel), slot);
+                let used = slot as u32 + 1;
+                atomic_u32(&mut writer.map, HEADER_USED_SLOTS).store(used, Ordering::Release);
+                info!(
+                    "Shared memory slot {} assigned to {}:{} #{}/{} ch {}",
+                    slot, key.ip, key.port, key.product_line, key.lidar_id, channel
+                );
+                slot
+            }
+        };
+        writer.write_slot(slot, key, channel, points);
+    }
+}
+
+impl ShmWriter {
+    /// seqlock 으로 슬롯 갱신
+    fn write_slot(&mut self, slot: usize, key: LiDARKey, channel: u8, points: &[Point]) {
+        let base = HEADER_SIZE + slot * self.stride;
+        let count = points.len().min(self.max_points);
+
+        let seq = atomic_u64(&mut self.map, base + SLOT_SEQ);
+        let start = seq.load(Ordering::Relaxed) | 1;
+        seq.store(start, Ordering::Relaxed);
+        fence(Ordering::Release);
+
+        let slot_data = &mut self.map[base..base + self.stride];
+        slot_data[SLOT_IP..SLOT_IP + 4].copy_from_slice(&key.ip.octets());
+        slot_data[SLOT_PORT..SLOT_PORT + 2].copy_from_slice(&key.port.to_le_bytes());
+        slot_data[SLOT_PRODUCT_LINE] = key.product_line;
+        slot_data[SLOT_LIDAR_ID] = key.lidar_id;
+        slot_data[SLOT_CHANNEL] = channel;
+        slot_data[SLOT_POINT_COUNT..SLOT_POINT_COUNT + 4]
+            .copy_from_slice(&(count as u32).to_le_bytes());
+        slot_data[SLOT_TIME_MS..SLOT_TIME_MS + 8].copy_from_slice(&unix_time_ms().to_le_bytes());
+        for (i, point) in points[..count].iter().enumerate() {
+            let offset = SLOT_HEADER_SIZE + i * POINT_SIZE;
+            slot_data[offset..offset + 4].copy_from_slice(&point.x.to_le_bytes());
+            slot_data[offset + 4..offset + 8].copy_from_slice(&point.y.to_le_bytes());
+            slot_data[offset + 8..offset + 12].copy_from_slice(&point.z.to_le_bytes());
+        }
+
+        atomic_u64(&mut self.map, base + SLOT_SEQ).store(start + 1, Ordering::Release);
+    }
+}
+
+/// 공유 메모리 출력 읽기 (Rust 소비자용)
+///
+/// # Examples
+/// ```
+/// let reader = ShmReader::open(Path::new("/dev/shm/lidar_server"))?;
+/// for slot in 0..reader.used_slots() {
+///     if let Some(frame) = reader.read(slot) {
+///         // frame.points
+///     }
+/// }
+/// ```
+pub struct ShmReader {
+    map: Mmap,
+    slot_count: usize,
+    stride: usize,
+}
+
+impl ShmReader {
+    /// 공유 메모리 파일 열기
+    ///
+    /// # Arguments
+    /// * `path` - 공유 메모리 파일 경로
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 읽기 객체, 파일이 없거나 형식이 다르면 에러 메시지
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let file = std::fs::File::open(path)
+            .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        // SAFETY: 서버가 쓰는 영역은 seqlock 으로 일관성을 확인한 뒤에만 사용
+        let map = unsafe { Mmap::map(&file) }
+            .map_err(|e| format!("Failed to map {}: {}", path.display(), e))?;
+        if map.len() < HEADER_SIZE
+            || map[..4] != SHM_MAGIC
+            || get_u32(&map, HEADER_VERSION) != SHM_VERSION
+        {
+            return Err(format!("Not a shared memory output: {}", path.display()));
+        }
+        fence(Ordering::Acquire);
+
+        let slot_count = get_u32(&map, HEADER_SLOT_COUNT) as usize;
+        let stride = get_u32(&map, HEADER_SLOT_STRIDE) as usize;
+        if stride < SLOT_HEADER_SIZE || map.len() < HEADER_SIZE + slot_count * stride {
+            return Err(format!(
+                "Truncated shared memory output: {}",
+                path.display()
+            ));
+        }
+        Ok(Self {
+            map,
+            slot_count,
+            stride,
+        })
+    }
+
+    /// 사용 중인 슬롯 수
+    pub fn used_slots(&self) -> usize {
+        let used = atomic_u32_ref(&self.map, HEADER_USED_SLOTS).load(Ordering::Acquire);
+        (used as usize).min(self.slot_count)
+    }
+
+    /// 슬롯의 최신 프레임 읽기
+    ///
+    /// # Arguments
+    /// * `slot` - 슬롯 번호
+    ///
+    /// # Returns
+    /// * `Option<ShmFrame>` - 일관된 프레임, 범위를 벗어나거나 아직 기록되지 않았으면 None
+    pub fn read(&self, slot: usize) -> Option<ShmFrame> {
+        if slot >= self.slot_count {
+            return None;
+        }
+        let base = HEADER_SIZE + slot * self.stride;
+        let seq = atomic_u64_ref(&self.map, base + SLOT_SEQ);
+        loop {
+            let start = seq.load(Ordering::Acquire);
+            if start == 0 {
+                return None;
+            }
+            if start & 1 == 1 {
+                std::hint::spin_loop();
+                continue;
+            }
+
+            let slot_data = &self.map[base..base + self.stride];
+            let max_points = (self.stride - SLOT_HEADER_SIZE) / POINT_SIZE;
+            let count = (get_u32(slot_data, SLOT_POINT_COUNT) as usize).min(max_points);
+            let ip: [u8; 4] = slot_data[SLOT_IP..SLOT_IP + 4].try_into().ok()?;
+            let port = u16::from_le_bytes(slot_data[SLOT_PORT..SLOT_PORT + 2].try_into().ok()?);
+            let frame = ShmFrame {
+                seq: start,
+                key: LiDARKey::new(
+                    Ipv4Addr::from(ip),
+                    port,
+                    slot_data[SLOT_PRODUCT_LINE],
+                    slot_data[SLOT_LIDAR_ID],
+                ),
+                channel: slot_data[SLOT_CHANNEL],
+                time_ms: u64::from_le_bytes(
+                    slot_data[SLOT_TIME_MS..SLOT_TIME_MS + 8].try_into().ok()?,
+                ),
+                points: (0..count)
+                    .map(|i| {
+                        let offset = SLOT_HEADER_SIZE + i * POINT_SIZE;
+                        Point {
+                            x: get_f32(slot_data, offset),
+                            y: get_f32(slot_data, offset + 4),
+                            z: get_f32(slot_data, offset + 8),
+                        }
+                    })
+                    .collect(),
+            };
+
+            fence(Ordering::Acquire);
+            if seq.load(Ordering::Relaxed) == start {
+                return Some(frame);
+            }
+        }
+    }
+}
+
+fn put_u32(map: &mut [u8], offset: usize, value: u32) {
+    map[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+fn get_u32(map: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(map[offset..offset + 4].try_into().unwrap_or_default())
+}
+
+fn get_f32(map: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes(map[offset..offset + 4].try_into().unwrap_or_default())
+}
+
+fn atomic_u64(map: &mut [u8], offset: usize) -> &AtomicU64 {
+    // SAFETY: 매핑은 페이지 정렬이며 오프셋은 8 의 배수 (슬롯 간격은 64 의 배수)
+    unsafe { AtomicU64::from_ptr(map[offset..offset + 8].as_mut_ptr().cast()) }
+}
+
+fn atomic_u32(map: &mut [u8], offset: usize) -> &AtomicU32 {
+    // SAFETY: 매핑은 페이지 정렬이며 오프셋은 4 의 배수
+    unsafe { AtomicU32::from_ptr(map[offset..offset + 4].as_mut_ptr().cast()) }
+}
+
+fn atomic_u64_ref(map: &[u8], offset: usize) -> &AtomicU64 {
+    // SAFETY: 읽기 전용 매핑이지만 원자적 load 만 수행
+    unsafe { &*(map[offset..offset + 8].as_ptr() as *const AtomicU64) }
+}
+
+fn atomic_u32_ref(map: &[u8], offset: usize) -> &AtomicU32 {
+    // SAFETY: 읽기 전용 매핑이지만 원자적 load 만 수행
+    unsafe { &*(map[offset..offset + 4].as_ptr() as *const AtomicU32) }
+}
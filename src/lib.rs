@@ -0,0 +1,22 @@
+pub mod alert;
+pub mod announce;
+pub mod bus;
+pub mod common;
+pub mod config;
+pub mod health;
+pub mod influx;
+pub mod ipc;
+pub mod lidar;
+pub mod logging;
+pub mod plugin;
+pub mod processing;
+pub mod proto;
+pub mod record;
+pub mod relay;
+pub mod replication;
+pub mod sim;
+pub mod site;
+pub mod supervisor;
+pub mod telemetry;
+pub mod udp;
+pub mod ws;
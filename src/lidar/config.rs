@@ -0,0 +1,68 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// LiDAR 식별 전략 열거형
+///
+/// # Variants
+/// * `SourceAddress` - 송신 IP + 송신 포트 + 제품 라인 + ID 로 식별 (기본값)
+/// * `SourceIp` - 송신 IP + 제품 라인 + ID 로 식별, NAT 등으로 송신 포트가 바뀌어도 동일 장치로 취급
+/// * `ReportedAddress` - LiDAR 가 보고한 IP(0xD1) + 제품 라인 + ID 로 식별,
+///   하나의 게이트웨이 뒤에 여러 LiDAR 가 있는 경우 사용.
+///   보고된 IP 를 알기 전까지는 `SourceAddress` 와 동일하게 식별
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityStrategy {
+    #[default]
+    SourceAddress,
+    SourceIp,
+    ReportedAddress,
+}
+
+/// LiDAR 식별 설정 구조체
+///
+/// # Fields
+/// * `strategy` - LiDAR 식별 전략
+/// * `conflict_window_ms` - 동일 키에 대해 이 시간 안에 다른 송신 주소가 감지되면 충돌로 판단 (ms)
+/// * `ip_change` - LiDAR IP 변경 감지 설정
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct IdentityConfig {
+    pub strategy: IdentityStrategy,
+    pub conflict_window_ms: u64,
+    pub ip_change: IpChangeConfig,
+}
+
+impl Default for IdentityConfig {
+    fn default() -> Self {
+        Self {
+            strategy: IdentityStrategy::default(),
+            conflict_window_ms: 1000,
+            ip_change: IpChangeConfig::default(),
+        }
+    }
+}
+
+/// LiDAR IP 변경 감지 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 새 키로 수신된 장치가 기존 장치의 IP 변경인지 확인하여 상태를 이동할지 여부
+/// * `match_lidar_id` - MAC 주소나 설정 명령으로 확인할 수 없을 때 제품 라인/ID 로 동일 장치를 판단할지 여부
+///   (같은 제품 라인/ID 의 등록된 장치가 하나이고 `conflict_window_ms` 이상 수신되지 않은 경우에만 적용)
+/// * `command_timeout_ms` - 네트워크 소스 설정 명령(0xD2) 송신 후 새 IP 에서의 수신을 기다리는 시간 (ms)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct IpChangeConfig {
+    pub enabled: bool,
+    pub match_lidar_id: bool,
+    pub command_timeout_ms: u64,
+}
+
+impl Default for IpChangeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            match_lidar_id: true,
+            command_timeout_ms: 30000,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+use crate::lidar::traits::LiDARParser;
+use crate::lidar::CompanyInfo;
+
+/// 빌드에 포함된 LiDAR 드라이버 (회사별 파서) 생성
+///
+/// 제조사 드라이버는 cargo feature 로 선택하며, 비활성화된 드라이버는 등록되지 않습니다.
+///
+/// # Examples
+/// ```
+/// let parsers = drivers();
+/// ```
+///
+/// # Returns
+/// * `HashMap<CompanyInfo, Box<dyn LiDARParser>>` - 회사별 파서
+pub fn drivers() -> HashMap<CompanyInfo, Box<dyn LiDARParser>> {
+    #[allow(unused_mut)]
+    let mut parsers: HashMap<CompanyInfo, Box<dyn LiDARParser>> = HashMap::new();
+
+    #[cfg(feature = "kanavi")]
+    parsers.insert(
+        CompanyInfo::KanaviMobility,
+        Box::new(crate::lidar::kanavi_mobility::KanaviMobilityParser::new()),
+    );
+
+    parsers
+}
+
+/// 빌드에 포함된 LiDAR 드라이버 목록
+///
+/// # Returns
+/// * `Vec<CompanyInfo>` - 활성화된 드라이버의 회사 정보
+pub fn enabled_drivers() -> Vec<CompanyInfo> {
+    drivers().into_keys().collect()
+}
@@ -0,0 +1,320 @@
+use std::net::Ipv4Addr;
+
+use crate::lidar::kanavi_mobility::codec::{encode, MODE_CONFIG, MODE_POINT_CLOUD};
+use crate::lidar::kanavi_mobility::command::*;
+use crate::lidar::kanavi_mobility::readback::SetParam;
+use crate::lidar::kanavi_mobility::types::*;
+use crate::lidar::LiDARKey;
+
+/// Kanavi Mobility 프레임 생성기
+///
+/// # Examples
+/// ```
+/// let frame = KanaviFrameBuilder::new(product_line, lidar_id)
+///     .motor_speed_set(speed)
+///     .build();
+/// // 문서에 없는 명령은 모드/파라미터/데이터를 직접 지정
+/// let frame = KanaviFrameBuilder::for_lidar(&key)
+///     .mode(MODE_CONFIG)
+///     .param(0x62)
+///     .build();
+/// ```
+///
+/// # Fields
+/// * `product_line` - 제품 라인 (0xFF 는 전체 대상)
+/// * `lidar_id` - LiDAR ID (0xFF 는 전체 대상)
+/// * `mode` - 모드 (기본값: 설정 모드 0xCF)
+/// * `param` - 파라미터
+/// * `payload` - 데이터
+///
+/// # 동작 설명
+/// * `build` 에서 헤더(0xFA), 데이터 길이(2 byte, big endian), 체크섬을 채워 `codec::encode` 와 같은 프레임 생성
+/// * 요청 명령은 데이터 없이, 설정 명령은 설정 타입의 `to_bytes` (같은 설정의 요청에 대한 응답 데이터와 같은 형식)로 생성
+///   (`readback` 으로 설정 후 다시 읽은 값과 바로 비교 가능)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KanaviFrameBuilder {
+    product_line: u8,
+    lidar_id: u8,
+    mode: u8,
+    param: u8,
+    payload: Vec<u8>,
+}
+
+impl KanaviFrameBuilder {
+    /// 대상 LiDAR 의 설정 모드 프레임 생성기
+    ///
+    /// # Arguments
+    /// * `product_line` - 제품 라인
+    /// * `lidar_id` - LiDAR ID
+    pub fn new(product_line: u8, lidar_id: u8) -> Self {
+        Self {
+            product_line,
+            lidar_id,
+            mode: MODE_CONFIG,
+            param: 0,
+            payload: Vec::new(),
+        }
+    }
+
+    /// LiDAR 키의 제품 라인/ID 를 대상으로 하는 프레임 생성기
+    pub fn for_lidar(key: &LiDARKey) -> Self {
+        Self::new(key.product_line, key.lidar_id)
+    }
+
+    /// 모든 제품 라인/ID 를 대상으로 하는 프레임 생성기 (장치 검색)
+    pub fn broadcast() -> Self {
+        Self::new(BROADCAST_PRODUCT_LINE, BROADCAST_LIDAR_ID)
+    }
+
+    /// 모드 지정
+    pub fn mode(mut self, mode: u8) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// 파라미터 지정
+    pub fn param(mut self, param: u8) -> Self {
+        self.param = param;
+        self
+    }
+
+    /// 데이터 지정 (기존 데이터 교체)
+    pub fn payload(mut self, payload: &[u8]) -> Self {
+        self.payload = payload.to_vec();
+        self
+    }
+
+    /// 프레임 생성
+    ///
+    /// # Returns
+    /// * `Vec<u8>` - 헤더(0xFA) + 제품 라인 + ID + 모드 + 파라미터 + 길이(2 byte, big endian) + 데이터 + 체크섬
+    pub fn build(&self) -> Vec<u8> {
+        encode(
+            self.product_line,
+            self.lidar_id,
+            self.mode,
+            self.param,
+            &self.payload,
+        )
+    }
+
+    /// 데이터 없는 설정 모드 명령
+    fn config(self, param: u8) -> Self {
+        self.mode(MODE_CONFIG).param(param).payload(&[])
+    }
+
+    /// 설정 모드 명령
+    fn config_with(self, param: u8, payload: &[u8]) -> Self {
+        self.mode(MODE_CONFIG).param(param).payload(payload)
+    }
+
+    /// 기본 설정 요청
+    pub fn basic_config_request(self) -> Self {
+        self.config(PARAM_BASIC_CONFIG_REQUEST)
+    }
+
+    /// 버전 정보 요청
+    pub fn version_info_request(self) -> Self {
+        self.config(PARAM_VERSION_INFO_REQUEST)
+    }
+
+    /// 네트워크 소스 정보 요청
+    pub fn network_source_info_request(self) -> Self {
+        self.config(PARAM_NETWORK_SOURCE_INFO_REQUEST)
+    }
+
+    /// 네트워크 소스 정보 설정
+    ///
+    /// # Arguments
+    /// * `ip_address` - LiDAR IP 주소
+    /// * `mac_address` - LiDAR MAC 주소
+    /// * `subnet_mask` - 서브넷 마스크
+    /// * `gateway` - 게이트웨이
+    /// * `port` - LiDAR 포트
+    ///
+    /// # 동작 설명
+    /// * 데이터는 네트워크 소스 정보 응답과 같은 형식 (IP 4 + MAC 6 + 서브넷 4 + 게이트웨이 4 + 포트 2 byte)
+    pub fn network_source_info_set(
+        self,
+        ip_address: Ipv4Addr,
+        mac_address: [u8; 6],
+        subnet_mask: Ipv4Addr,
+        gateway: Ipv4Addr,
+        port: u16,
+    ) -> Self {
+        let info = NetworkSourceInfo::new(
+            ip_address.octets(),
+            mac_address,
+            subnet_mask.octets(),
+            gateway.octets(),
+            port,
+        );
+        self.config_with(PARAM_NETWORK_SOURCE_INFO_SET, &info.to_bytes())
+    }
+
+    /// 네트워크 목적지 IP 요청
+    pub fn network_destination_ip_request(self) -> Self {
+        self.config(PARAM_NETWORK_DESTINATION_IP_REQUEST)
+    }
+
+    /// 네트워크 목적지 IP 설정 (LiDAR 가 데이터를 보낼 IP)
+    pub fn network_destination_ip_set(self, ip_address: Ipv4Addr) -> Self {
+        self.config_with(
+            PARAM_NETWORK_DESTINATION_IP_SET,
+            &NetworkDestinationIP::new(ip_address.octets()).to_bytes(),
+        )
+    }
+
+    /// 모터 속도 요청
+    pub fn motor_speed_request(self) -> Self {
+        self.config(PARAM_MOTOR_SPEED_REQUEST)
+    }
+
+    /// 모터 속도 설정
+    pub fn motor_speed_set(self, speed: u8) -> Self {
+        self.config_with(PARAM_MOTOR_SPEED_SET, &MotorSpeed::new(speed).to_bytes())
+    }
+
+    /// 경고 영역 요청
+    pub fn warning_area_request(self) -> Self {
+        self.config(PARAM_WARNING_AREA_REQUEST)
+    }
+
+    /// 경고 영역 설정
+    ///
+    /// # Arguments
+    /// * `danger_area` - 위험 영역 거리 (정수부 m, 소수부 cm)
+    /// * `warning_area` - 경고 영역 거리 (정수부 m, 소수부 cm)
+    /// * `caution_area` - 주의 영역 거리 (정수부 m, 소수부 cm)
+    pub fn warning_area_set(
+        self,
+        danger_area: [u8; 2],
+        warning_area: [u8; 2],
+        caution_area: [u8; 2],
+    ) -> Self {
+        let area = WarningArea::new(danger_area, warning_area, caution_area);
+        self.config_with(PARAM_WARNING_AREA_SET, &area.to_bytes())
+    }
+
+    /// 안개 필터 요청
+    pub fn fog_filter_request(self) -> Self {
+        self.config(PARAM_FOG_FILTER_REQUEST)
+    }
+
+    /// 안개 필터 설정
+    pub fn fog_filter_set(self, filter_value: u8) -> Self {
+        self.config_with(
+            PARAM_FOG_FILTER_SET,
+            &FogFilter::new(filter_value).to_bytes(),
+        )
+    }
+
+    /// 오감지 필터 요청
+    pub fn radius_filter_request(self) -> Self {
+        self.config(PARAM_RADIUS_FILTER_REQUEST)
+    }
+
+    /// 오감지 필터 설정
+    pub fn radius_filter_set(self, filter_value: u8) -> Self {
+        self.config_with(
+            PARAM_RADIUS_FILTER_SET,
+            &RadiusFilter::new(filter_value).to_bytes(),
+        )
+    }
+
+    /// 최대 오감지 필터 거리 요청
+    pub fn radius_filter_max_distance_request(self) -> Self {
+        self.config(PARAM_RADIUS_FILTER_MAX_DISTANCE_REQUEST)
+    }
+
+    /// 최대 오감지 필터 거리 설정 (m)
+    pub fn radius_filter_max_distance_set(self, max_distance: u8) -> Self {
+        self.config_with(
+            PARAM_RADIUS_FILTER_MAX_DISTANCE_SET,
+            &RadiusFilterMaxDistance::new(max_distance).to_bytes(),
+        )
+    }
+
+    /// 최소 오감지 필터 거리 요청
+    pub fn radius_filter_min_distance_request(self) -> Self {
+        self.config(PARAM_RADIUS_FILTER_MIN_DISTANCE_REQUEST)
+    }
+
+    /// 최소 오감지 필터 거리 설정 (m)
+    pub fn radius_filter_min_distance_set(self, min_distance: u8) -> Self {
+        self.config_with(
+            PARAM_RADIUS_FILTER_MIN_DISTANCE_SET,
+            &RadiusFilterMinDistance::new(min_distance).to_bytes(),
+        )
+    }
+
+    /// 창 오염 감지 모드 요청
+    pub fn window_contamination_detection_mode_request(self) -> Self {
+        self.config(PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_REQUEST)
+    }
+
+    /// 창 오염 감지 모드 설정
+    pub fn window_contamination_detection_mode_set(self, mode: u8) -> Self {
+        self.config_with(
+            PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_SET,
+            &WindowContaminationDetectionMode::new(mode).to_bytes(),
+        )
+    }
+
+    /// 티칭 모드 요청
+    pub fn teaching_mode_request(self) -> Self {
+        self.config(PARAM_TEACHING_MODE_REQUEST)
+    }
+
+    /// 티칭 모드 설정 (티칭 시작)
+    ///
+    /// # Arguments
+    /// * `range` - 티칭 범위
+    /// * `margin` - 티칭 마진
+    pub fn teaching_mode_set(self, range: u8, margin: u8) -> Self {
+        self.config_with(
+            PARAM_TEACHING_MODE_SET,
+            &TeachingMode::new(range, margin).to_bytes(),
+        )
+    }
+
+    /// 티칭 영역 요청
+    pub fn teaching_area_request(self) -> Self {
+        self.config(PARAM_TEACHING_AREA_REQUEST)
+    }
+
+    /// 설정 데이터를 그대로 쓰는 설정 명령
+    ///
+    /// # Arguments
+    /// * `config` - 설정할 값 (장치가 보고한 설정을 다른 LiDAR 에 복사하는 경우 등)
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 설정 명령 생성기, 설정할 수 없는 데이터(기본 설정, 버전, 티칭 영역, ACK/NAK)면 에러 메시지
+    pub fn set_config(self, config: &KMConfigData) -> Result<Self, String> {
+        let param = config
+            .set_param()
+            .ok_or_else(|| "Config data is read-only".to_string())?;
+        Ok(self.config_with(param, &config.to_bytes()))
+    }
+
+    /// 설정 확인이 가능한 파라미터의 설정 명령 (데이터 길이는 호출하는 쪽에서 확인)
+    pub fn config_set(self, param: &SetParam, payload: &[u8]) -> Self {
+        self.config_with(param.set, payload)
+    }
+
+    /// 설정 확인이 가능한 파라미터의 요청 명령 (설정 후 다시 읽기)
+    pub fn config_request(self, param: &SetParam) -> Self {
+        self.config(param.request)
+    }
+
+    /// 포인트 클라우드 프레임 (시뮬레이터, 테스트 데이터 생성)
+    ///
+    /// # Arguments
+    /// * `ch` - 채널 번호 (파라미터 하위 4 bit)
+    /// * `distances` - 거리 데이터 (2 bytes 씩: 정수부 m, 소수부 cm)
+    pub fn point_cloud(self, ch: u8, distances: &[u8]) -> Self {
+        self.mode(MODE_POINT_CLOUD)
+            .param(0xF0 | (ch & 0x0F))
+            .payload(distances)
+    }
+}
@@ -0,0 +1,316 @@
+use tracing::*;
+
+use crate::lidar::kanavi_mobility::projection::ProjectionTable;
+use crate::lidar::kanavi_mobility::types::*;
+use crate::lidar::parse_stats::ParseError;
+use crate::lidar::traits::ParseOutcome;
+use crate::lidar::types::*;
+
+/// 프레임 헤더
+pub const HEADER: u8 = 0xFA;
+
+/// 설정 모드 (요청/응답 공통)
+pub const MODE_CONFIG: u8 = 0xCF;
+
+/// NAK 응답 모드
+pub const MODE_NAK: u8 = 0xF0;
+
+/// 포인트 클라우드 모드
+pub const MODE_POINT_CLOUD: u8 = 0xDD;
+
+/// 데이터를 제외한 프레임 크기 (헤더 ~ 길이 7 byte + 체크섬 1 byte)
+pub const FRAME_OVERHEAD: usize = 8;
+
+/// 프레임 헤더 필드
+///
+/// # Fields
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+/// * `mode` - 모드
+/// * `param` - 파라미터 (포인트 클라우드는 하위 4 bit 가 채널)
+/// * `data_len` - 데이터 길이 (byte)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameHeader {
+    pub product_line: u8,
+    pub lidar_id: u8,
+    pub mode: u8,
+    pub param: u8,
+    pub data_len: u16,
+}
+
+impl FrameHeader {
+    /// 프레임 앞부분에서 헤더 필드 읽기 (길이/체크섬은 확인하지 않음)
+    ///
+    /// # Returns
+    /// * `Option<FrameHeader>` - 헤더(0xFA)로 시작하고 길이 필드까지 있으면 Some, 아니면 None
+    pub fn parse(frame: &[u8]) -> Option<Self> {
+        match frame {
+            [HEADER, product_line, lidar_id, mode, param, high, low, ..] => Some(Self {
+                product_line: *product_line,
+                lidar_id: *lidar_id,
+                mode: *mode,
+                param: *param,
+                data_len: u16::from_be_bytes([*high, *low]),
+            }),
+            _ => None,
+        }
+    }
+
+    /// 체크섬까지 포함한 프레임 전체 크기 (byte)
+    pub fn frame_len(&self) -> usize {
+        FRAME_OVERHEAD + self.data_len as usize
+    }
+
+    /// 포인트 클라우드 프레임의 채널 번호
+    pub fn channel(&self) -> u8 {
+        self.param & 0x0F
+    }
+}
+
+/// Kanavi Mobility 프레임 생성
+///
+/// # Examples
+/// ```
+/// let frame = encode(product_line, lidar_id, MODE_CONFIG, 0x62, &[speed]);
+/// ```
+///
+/// # Arguments
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+/// * `mode` - 모드
+/// * `param` - 파라미터
+/// * `data` - 데이터
+///
+/// # Returns
+/// * `Vec<u8>` - 헤더(0xFA) + 제품 라인 + ID + 모드 + 파라미터 + 길이(2 byte, big endian) + 데이터 + 체크섬
+pub fn encode(product_line: u8, lidar_id: u8, mode: u8, param: u8, data: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(FRAME_OVERHEAD + data.len());
+    frame.push(HEADER);
+    frame.push(product_line);
+    frame.push(lidar_id);
+    frame.push(mode);
+    frame.push(param);
+    frame.extend_from_slice(&(data.len() as u16).to_be_bytes());
+    frame.extend_from_slice(data);
+    frame.push(checksum(&frame));
+    frame
+}
+
+/// 체크섬 계산
+///
+/// # Arguments
+/// * `bytes` - 헤더부터 데이터 끝까지의 바이트
+///
+/// # Returns
+/// * `u8` - 모든 바이트의 XOR 값
+pub fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |acc, b| acc ^ b)
+}
+
+/// 버퍼 앞부분의 프레임 하나 검증
+///
+/// # Arguments
+/// * `buffer` - 헤더부터 시작하는 데이터 (프레임 뒤에 다른 데이터가 있어도 됨)
+///
+/// # Returns
+/// * `ParseOutcome<FrameHeader>` - 완전한 프레임이면 헤더 필드, 프레임이 아직 다 오지 않았으면 `NeedMoreData`,
+///   헤더(0xFA) 또는 체크섬이 맞지 않으면 실패 원인
+pub fn decode_frame(buffer: &[u8]) -> ParseOutcome<FrameHeader> {
+    match buffer.first() {
+        None => return ParseOutcome::NeedMoreData,
+        Some(&HEADER) => {}
+        Some(_) => return ParseOutcome::Error(ParseError::InvalidHeader),
+    }
+    let Some(header) = FrameHeader::parse(buffer) else {
+        return ParseOutcome::NeedMoreData;
+    };
+    let frame_len = header.frame_len();
+    if buffer.len() < frame_len {
+        return ParseOutcome::NeedMoreData;
+    }
+    if checksum(&buffer[..frame_len - 1]) != buffer[frame_len - 1] {
+        return ParseOutcome::Error(ParseError::Checksum);
+    }
+    ParseOutcome::Frame(header)
+}
+
+/// 프레임의 데이터 부분
+///
+/// # Arguments
+/// * `frame` - 헤더부터 시작하는 프레임
+///
+/// # Returns
+/// * `&[u8]` - 길이 필드만큼의 데이터, 프레임이 짧으면 남은 부분
+pub fn frame_data(frame: &[u8]) -> &[u8] {
+    match frame {
+        [_, _, _, _, _, high, low, data @ ..] => {
+            let len = u16::from_be_bytes([*high, *low]) as usize;
+            &data[..len.min(data.len())]
+        }
+        _ => &[],
+    }
+}
+
+/// 포인트 클라우드 거리 데이터를 3차원 좌표로 변환
+///
+/// # Arguments
+/// * `product_line` - 제품 라인 (수직/수평 FOV 결정)
+/// * `ch` - 채널 번호 (수직 각도 결정)
+/// * `data` - 거리 데이터 (2 bytes 씩: 정수부 m, 소수부 cm)
+///
+/// # Returns
+/// * `PointCloud` - 채널의 포인트 클라우드, 제품 라인에 없는 채널이면 빈 포인트 클라우드
+///
+/// # 동작 설명
+/// * 제품 라인별 투영 표(`ProjectionTable`)의 각도별 cos/sin 을 사용하여 SIMD 로 계산
+pub fn project_points(product_line: u8, ch: u8, data: &[u8]) -> PointCloud {
+    ProjectionTable::for_product_line(product_line).project(ch, data)
+}
+
+/// 설정 응답 데이터 파싱
+///
+/// # Arguments
+/// * `product_line` - 제품 라인
+/// * `param` - 파라미터 (설정 타입)
+/// * `data` - 프레임의 데이터 부분
+///
+/// # Returns
+/// * `Result<KMConfigData, ParseError>` - 성공 시 파싱된 설정 데이터, 실패 시 실패 원인
+///
+/// # 지원하는 설정 타입
+/// * 0x11: 기본 설정
+/// * 0x71: 버전 정보
+/// * 0xD1: 네트워크 소스 정보
+/// * 0xF1: 티칭 영역
+/// * 0x43: 네트워크 목적지 IP
+/// * 0x63: 모터 속도
+/// * 0x83: 경고 영역
+/// * 0xA3: 안개 필터
+/// * 0xC3: 오감지 필터
+/// * 0xE3: 최대 오감지 필터 거리
+/// * 0x05: 창 오염 감지 모드
+/// * 0x15: 티칭 모드
+/// * 0x35: 최소 오감지 필터 거리
+/// * 기타 설정 응답 파라미터: ACK 응답
+pub fn decode_config(product_line: u8, param: u8, data: &[u8]) -> Result<KMConfigData, ParseError> {
+    let min_len = match param {
+        0x11 => 14,
+        0x71 => 7,
+        0xD1 => 20,
+        0x43 => 4,
+        0x83 => 6,
+        0x15 => 2,
+        0xF1 | 0x63 | 0xA3 | 0xC3 | 0xE3 | 0x05 | 0x35 => 1,
+        0x01 | 0x21 | 0x31 | 0x41 | 0x51 | 0x61 | 0x81 | 0x91 | 0xA1 | 0xB1 | 0xC1 | 0xE1
+        | 0x03 | 0x13 | 0x23 | 0x33 | 0x53 | 0x73 | 0x9d | 0xB3 | 0xD3 | 0xF3 | 0x25 | 0x45 => 1,
+        _ => {
+            error!("not supported param {}", param);
+            return Err(ParseError::UnknownParam(param));
+        }
+    };
+    if data.len() < min_len {
+        error!("not enough data");
+        return Err(ParseError::Truncated);
+    }
+
+    let config = match param {
+        // Basic Config
+        0x11 => decode_basic_config(data)?,
+        // Version Info
+        0x71 => KMConfigData::VersionInfo(VersionInfo::new(
+            [data[0], data[1], data[2]],
+            [data[3], data[4], data[5]],
+            data[6],
+        )),
+        // Network Source Info
+        0xD1 => KMConfigData::NetworkSourceInfo(NetworkSourceInfo::new(
+            [data[0], data[1], data[2], data[3]],
+            [data[4], data[5], data[6], data[7], data[8], data[9]],
+            [data[10], data[11], data[12], data[13]],
+            [data[14], data[15], data[16], data[17]],
+            u16::from_be_bytes([data[18], data[19]]),
+        )),
+        // Teaching Area
+        0xF1 => {
+            let points = &data[1..];
+            if data[0] == 1
+                && (points.len() < 2 * TeachingArea::point_count(product_line)
+                    || !points.len().is_multiple_of(2))
+            {
+                error!("not enough teaching area data");
+                return Err(ParseError::Truncated);
+            }
+            KMConfigData::TeachingArea(TeachingArea::parse(product_line, data[0], points.to_vec()))
+        }
+        // Network Destination IP
+        0x43 => KMConfigData::NetworkDestinationIP(NetworkDestinationIP::new([
+            data[0], data[1], data[2], data[3],
+        ])),
+        // Motor Speed
+        0x63 => KMConfigData::MotorSpeed(MotorSpeed::new(data[0])),
+        // Warning Area
+        0x83 => KMConfigData::WarningArea(WarningArea::new(
+            [data[0], data[1]],
+            [data[2], data[3]],
+            [data[4], data[5]],
+        )),
+        // Fog Filter
+        0xA3 => KMConfigData::FogFilter(FogFilter::new(data[0])),
+        // Radius Filter
+        0xC3 => KMConfigData::RadiusFilter(RadiusFilter::new(data[0])),
+        // Radius Filter Max Distance
+        0xE3 => KMConfigData::RadiusFilterMaxDistance(RadiusFilterMaxDistance::new(data[0])),
+        // Window Contamination Detection Mode
+        0x05 => KMConfigData::WindowContaminationDetectionMode(
+            WindowContaminationDetectionMode::new(data[0]),
+        ),
+        // Teaching Mode
+        0x15 => KMConfigData::TeachingMode(TeachingMode::new(data[0], data[1])),
+        // Radius Filter Min Distance
+        0x35 => KMConfigData::RadiusFilterMinDistance(RadiusFilterMinDistance::new(data[0])),
+        // Ack
+        _ => KMConfigData::Ack(data[0]),
+    };
+    Ok(config)
+}
+
+/// 기본 설정 응답 데이터 파싱
+///
+/// # 동작 설명
+/// * 고정 필드 14 byte 뒤에 사용자 영역마다 포인트 개수(1 byte) + 포인트(4 byte 씩)
+/// * 영역 개수만큼의 데이터가 없으면 `Truncated`
+fn decode_basic_config(data: &[u8]) -> Result<KMConfigData, ParseError> {
+    let area_count = data[13];
+    let mut data_idx = 14;
+    let mut areas = Vec::with_capacity(area_count as usize);
+    for _ in 0..area_count {
+        let Some(&point_count) = data.get(data_idx) else {
+            error!("not enough data");
+            return Err(ParseError::Truncated);
+        };
+        data_idx += 1;
+        let points_len = point_count as usize * 4;
+        let Some(points) = data.get(data_idx..data_idx + points_len) else {
+            error!("not enough data");
+            return Err(ParseError::Truncated);
+        };
+        data_idx += points_len;
+        areas.push(UserArea::from_bytes(point_count, points.to_vec()));
+    }
+
+    Ok(KMConfigData::BasicConfig(BasicConfig::new(
+        data[0],
+        data[1],
+        data[2],
+        data[3],
+        data[4],
+        data[5],
+        u16::from_be_bytes([data[6], data[7]]),
+        u16::from_be_bytes([data[8], data[9]]),
+        data[10],
+        data[11],
+        data[12],
+        area_count,
+        areas,
+    )))
+}
@@ -0,0 +1,194 @@
+use std::net::Ipv4Addr;
+
+use crate::lidar::kanavi_mobility::builder::KanaviFrameBuilder;
+use crate::lidar::kanavi_mobility::codec::{HEADER, MODE_CONFIG};
+
+/// 기본 설정 요청 파라미터 (응답 파라미터: 0x11)
+pub const PARAM_BASIC_CONFIG_REQUEST: u8 = 0x10;
+
+/// 버전 정보 요청 파라미터 (응답 파라미터: 0x71)
+pub const PARAM_VERSION_INFO_REQUEST: u8 = 0x70;
+
+/// 네트워크 소스 정보 요청 파라미터 (응답 파라미터: 0xD1)
+pub const PARAM_NETWORK_SOURCE_INFO_REQUEST: u8 = 0xD0;
+
+/// 네트워크 소스 정보 설정 파라미터 (응답 파라미터: 0xD3)
+pub const PARAM_NETWORK_SOURCE_INFO_SET: u8 = 0xD2;
+
+/// 티칭 모드 설정 파라미터 (응답 파라미터: 0x25)
+pub const PARAM_TEACHING_MODE_SET: u8 = 0x24;
+
+/// 티칭 모드 요청 파라미터 (응답 파라미터: 0x15)
+pub const PARAM_TEACHING_MODE_REQUEST: u8 = 0x14;
+
+/// 티칭 영역 요청 파라미터 (응답 파라미터: 0xF1)
+pub const PARAM_TEACHING_AREA_REQUEST: u8 = 0xF0;
+
+/// 경고 영역 설정 파라미터 (응답 파라미터: 0x81)
+pub const PARAM_WARNING_AREA_SET: u8 = 0x80;
+
+/// 경고 영역 요청 파라미터 (응답 파라미터: 0x83)
+pub const PARAM_WARNING_AREA_REQUEST: u8 = 0x82;
+
+/// 네트워크 목적지 IP 설정 파라미터 (응답 파라미터: 0x41)
+pub const PARAM_NETWORK_DESTINATION_IP_SET: u8 = 0x40;
+
+/// 네트워크 목적지 IP 요청 파라미터 (응답 파라미터: 0x43)
+pub const PARAM_NETWORK_DESTINATION_IP_REQUEST: u8 = 0x42;
+
+/// 모터 속도 설정 파라미터 (응답 파라미터: 0x61)
+pub const PARAM_MOTOR_SPEED_SET: u8 = 0x60;
+
+/// 모터 속도 요청 파라미터 (응답 파라미터: 0x63)
+pub const PARAM_MOTOR_SPEED_REQUEST: u8 = 0x62;
+
+/// 안개 필터 설정 파라미터 (응답 파라미터: 0xA1)
+pub const PARAM_FOG_FILTER_SET: u8 = 0xA0;
+
+/// 안개 필터 요청 파라미터 (응답 파라미터: 0xA3)
+pub const PARAM_FOG_FILTER_REQUEST: u8 = 0xA2;
+
+/// 오감지 필터 설정 파라미터 (응답 파라미터: 0xC1)
+pub const PARAM_RADIUS_FILTER_SET: u8 = 0xC0;
+
+/// 오감지 필터 요청 파라미터 (응답 파라미터: 0xC3)
+pub const PARAM_RADIUS_FILTER_REQUEST: u8 = 0xC2;
+
+/// 최대 오감지 필터 거리 설정 파라미터 (응답 파라미터: 0xE1)
+pub const PARAM_RADIUS_FILTER_MAX_DISTANCE_SET: u8 = 0xE0;
+
+/// 최대 오감지 필터 거리 요청 파라미터 (응답 파라미터: 0xE3)
+pub const PARAM_RADIUS_FILTER_MAX_DISTANCE_REQUEST: u8 = 0xE2;
+
+/// 최소 오감지 필터 거리 설정 파라미터 (응답 파라미터: 0x45)
+pub const PARAM_RADIUS_FILTER_MIN_DISTANCE_SET: u8 = 0x44;
+
+/// 최소 오감지 필터 거리 요청 파라미터 (응답 파라미터: 0x35)
+pub const PARAM_RADIUS_FILTER_MIN_DISTANCE_REQUEST: u8 = 0x34;
+
+/// 창 오염 감지 모드 설정 파라미터 (응답 파라미터: 0x03)
+pub const PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_SET: u8 = 0x02;
+
+/// 창 오염 감지 모드 요청 파라미터 (응답 파라미터: 0x05)
+pub const PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_REQUEST: u8 = 0x04;
+
+/// 장치 검색 시 사용하는 전체 대상 제품 라인
+pub const BROADCAST_PRODUCT_LINE: u8 = 0xFF;
+
+/// 장치 검색 시 사용하는 전체 대상 LiDAR ID
+pub const BROADCAST_LIDAR_ID: u8 = 0xFF;
+
+/// 명령 프레임의 대상 제품 라인/LiDAR ID
+///
+/// # Arguments
+/// * `frame` - 명령 프레임
+///
+/// # Returns
+/// * `Option<(u8, u8)>` - 헤더가 맞으면 (제품 라인, LiDAR ID), 아니면 None
+///   (0xFF 는 해당 항목 전체 대상)
+pub fn command_target(frame: &[u8]) -> Option<(u8, u8)> {
+    match frame {
+        [HEADER, product_line, lidar_id, ..] => Some((*product_line, *lidar_id)),
+        _ => None,
+    }
+}
+
+/// 네트워크 소스 정보 설정 명령의 대상과 설정할 IP
+///
+/// # Arguments
+/// * `frame` - 명령 프레임
+///
+/// # Returns
+/// * `Option<(u8, u8, Ipv4Addr)>` - 네트워크 소스 정보 설정 명령이면 (제품 라인, LiDAR ID, 설정할 IP), 아니면 None
+///   (데이터는 네트워크 소스 정보 응답과 같이 IP 주소로 시작)
+pub fn network_source_change(frame: &[u8]) -> Option<(u8, u8, Ipv4Addr)> {
+    match frame {
+        [HEADER, product_line, lidar_id, MODE_CONFIG, PARAM_NETWORK_SOURCE_INFO_SET, _, _, a, b, c, d, ..] => {
+            Some((*product_line, *lidar_id, Ipv4Addr::new(*a, *b, *c, *d)))
+        }
+        _ => None,
+    }
+}
+
+/// 장치 검색용 요청 프레임 생성
+///
+/// # Returns
+/// * `Vec<Vec<u8>>` - 모든 제품 라인/ID 를 대상으로 하는 요청 프레임 목록
+///   - 버전 정보 요청: 장치 존재 확인
+///   - 네트워크 소스 정보 요청: LiDAR 가 보고하는 자신의 IP 확인 (NAT 환경 식별에 사용)
+pub fn discovery_requests() -> Vec<Vec<u8>> {
+    vec![
+        KanaviFrameBuilder::broadcast()
+            .version_info_request()
+            .build(),
+        KanaviFrameBuilder::broadcast()
+            .network_source_info_request()
+            .build(),
+    ]
+}
+
+/// 새로 검색한 장치의 정보 조회용 요청 프레임 생성
+///
+/// # Arguments
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+///
+/// # Returns
+/// * `Vec<Vec<u8>>` - 대상 LiDAR 에 보낼 요청 프레임 목록
+///   - 버전 정보 요청: 펌웨어/하드웨어 버전 확인
+///   - 네트워크 소스 정보 요청: LiDAR 가 보고하는 자신의 IP/MAC 주소 확인
+pub fn identify_requests(product_line: u8, lidar_id: u8) -> Vec<Vec<u8>> {
+    let builder = KanaviFrameBuilder::new(product_line, lidar_id);
+    vec![
+        builder.clone().version_info_request().build(),
+        builder.network_source_info_request().build(),
+    ]
+}
+
+/// 장치 진단용 요청 프레임 생성
+///
+/// # Arguments
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+///
+/// # Returns
+/// * `Vec<Vec<u8>>` - 진단 대상 LiDAR 에 보낼 요청 프레임 목록
+///   - 버전 정보 요청: 응답 여부 및 펌웨어/하드웨어 버전 확인
+///   - 기본 설정 요청: 자가 점검 활성 상태 확인
+pub fn diagnostics_requests(product_line: u8, lidar_id: u8) -> Vec<Vec<u8>> {
+    let builder = KanaviFrameBuilder::new(product_line, lidar_id);
+    vec![
+        builder.clone().version_info_request().build(),
+        builder.basic_config_request().build(),
+    ]
+}
+
+/// 티칭 모드 설정(티칭 시작) 명령 프레임 생성
+///
+/// # Arguments
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+/// * `range` - 티칭 범위
+/// * `margin` - 티칭 마진
+///
+/// # Returns
+/// * `Vec<u8>` - 티칭 모드 설정 프레임 (데이터: 범위, 마진)
+pub fn teaching_mode_command(product_line: u8, lidar_id: u8, range: u8, margin: u8) -> Vec<u8> {
+    KanaviFrameBuilder::new(product_line, lidar_id)
+        .teaching_mode_set(range, margin)
+        .build()
+}
+
+/// 티칭 영역 요청 프레임 생성
+///
+/// # Arguments
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+///
+/// # Returns
+/// * `Vec<u8>` - 티칭 영역 요청 프레임
+pub fn teaching_area_request(product_line: u8, lidar_id: u8) -> Vec<u8> {
+    KanaviFrameBuilder::new(product_line, lidar_id)
+        .teaching_area_request()
+        .build()
+}
@@ -0,0 +1,118 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::lidar::kanavi_mobility::KMConfigData;
+use crate::lidar::LiDARKey;
+
+/// LiDAR 진단 결과 구조체
+///
+/// Kanavi Mobility 설정 프로토콜은 온도를 보고하지 않으므로 응답 여부, 버전, 자가 점검 상태,
+/// 모터 속도, NAK 응답만 포함합니다.
+///
+/// # Fields
+/// * `key` - 진단한 LiDAR 키
+/// * `healthy` - 실패 항목이 없으면 true
+/// * `reachable` - 진단 요청에 하나 이상 응답했는지 여부
+/// * `firmware_version` - 펌웨어 버전 (예: "1.2.3"), 응답이 없으면 null
+/// * `hardware_version` - 하드웨어 버전, 응답이 없으면 null
+/// * `self_check_active` - 자가 점검 활성 여부 (기본 설정 응답), 응답이 없으면 null
+/// * `motor_speed` - 모터 속도 (진단 중 모터 속도 응답을 받은 경우)
+/// * `nak_count` - 진단 중 받은 NAK 응답 수
+/// * `failures` - 실패 항목 목록
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct DiagnosticsReport {
+    pub key: LiDARKey,
+    pub healthy: bool,
+    pub reachable: bool,
+    pub firmware_version: Option<String>,
+    pub hardware_version: Option<String>,
+    pub self_check_active: Option<bool>,
+    pub motor_speed: Option<u8>,
+    pub nak_count: u32,
+    pub failures: Vec<String>,
+}
+
+/// 진단 응답 수집 구조체
+///
+/// # Examples
+/// ```
+/// let mut collector = DiagnosticsCollector::new(key);
+/// collector.observe(config_data);
+/// if collector.is_complete() {
+///     let report = collector.finish();
+/// }
+/// ```
+pub struct DiagnosticsCollector {
+    report: DiagnosticsReport,
+}
+
+impl DiagnosticsCollector {
+    pub fn new(key: LiDARKey) -> Self {
+        Self {
+            report: DiagnosticsReport {
+                key,
+                healthy: false,
+                reachable: false,
+                firmware_version: None,
+                hardware_version: None,
+                self_check_active: None,
+                motor_speed: None,
+                nak_count: 0,
+                failures: Vec::new(),
+            },
+        }
+    }
+
+    /// 진단 대상 LiDAR 의 설정 응답 반영
+    pub fn observe(&mut self, data: &KMConfigData) {
+        self.report.reachable = true;
+        match data {
+            KMConfigData::VersionInfo(version) => {
+                self.report.firmware_version = Some(version.firmware_version_text());
+                self.report.hardware_version = Some(version.hardware_version_text());
+            }
+            KMConfigData::BasicConfig(config) => {
+                self.report.self_check_active = Some(config.self_check_active_state() != 0);
+            }
+            KMConfigData::MotorSpeed(speed) => {
+                self.report.motor_speed = Some(speed.speed());
+            }
+            KMConfigData::Nak(_) => {
+                self.report.nak_count += 1;
+            }
+            _ => {}
+        }
+    }
+
+    /// 진단 요청에 대한 응답을 모두 받았는지 여부
+    pub fn is_complete(&self) -> bool {
+        self.report.firmware_version.is_some() && self.report.self_check_active.is_some()
+    }
+
+    /// 실패 항목을 정리하여 진단 결과 반환
+    pub fn finish(mut self) -> DiagnosticsReport {
+        let report = &mut self.report;
+        if !report.reachable {
+            report.failures.push("No response".to_string());
+        } else {
+            if report.firmware_version.is_none() {
+                report
+                    .failures
+                    .push("Version info not reported".to_string());
+            }
+            if report.self_check_active.is_none() {
+                report
+                    .failures
+                    .push("Basic config not reported".to_string());
+            }
+        }
+        if report.nak_count > 0 {
+            report
+                .failures
+                .push(format!("{} NAK response(s)", report.nak_count));
+        }
+        report.healthy = report.failures.is_empty();
+        self.report
+    }
+}
@@ -1,5 +1,26 @@
+pub mod builder;
+pub mod codec;
+pub mod command;
+pub mod diagnostics;
 pub mod parser;
+pub mod projection;
+pub mod readback;
+pub mod teaching;
 pub mod types;
+pub mod warning_area;
 
+pub use builder::KanaviFrameBuilder;
+pub use codec::project_points;
+pub use diagnostics::{DiagnosticsCollector, DiagnosticsReport};
 pub use parser::*;
+pub use projection::{project_points_scalar, ProjectionTable};
+pub use readback::{
+    ConfigChange, ConfigDifference, ConfigTransactionRequest, ConfigTransactionResult,
+    ConfigVerification, DryRun, RollbackStep, SetConfigRequest, SetConfigResult, SetOutcome,
+    SetParam,
+};
+pub use teaching::{
+    TeachingProgress, TeachingRequest, TeachingResult, TeachingSession, TeachingStage, TeachingZone,
+};
 pub use types::*;
+pub use warning_area::{WarningAreaRequest, WarningAreaResult};
@@ -0,0 +1,173 @@
+use std::f32::consts::PI;
+use std::sync::OnceLock;
+use wide::f32x8;
+
+use crate::lidar::pool::PointPool;
+use crate::lidar::types::*;
+
+/// SIMD 묶음 크기 (`f32x8`)
+const LANES: usize = 8;
+
+/// 수평 각도 분해능 (°)
+const H_FOV_RESOLUTION: f32 = 0.25;
+
+/// 제품 라인별 수직 각도 목록 (°, 채널 순서)과 수평 FOV (°)
+fn profile(product_line: u8) -> (&'static [f32], f32) {
+    match product_line {
+        2 | 3 => (&[0.0, 3.0], 120.0),
+        7 => (&[0.0], 270.0),
+        _ => (&[-1.07, 0.0, 1.07, 2.14], 100.0),
+    }
+}
+
+/// 제품 라인별 구면 좌표 투영 표
+///
+/// # Examples
+/// ```
+/// let table = ProjectionTable::for_product_line(3);
+/// let cloud = table.project(ch, &distances);
+/// ```
+///
+/// # 동작 설명
+/// * 수평 각도별 cos/sin 과 채널별 수직 각도 cos/sin 을 처음 사용할 때 한 번만 계산하여 보관
+/// * 투영은 거리 × 표 값 곱셈만 남으므로 `f32x8` 로 8 포인트씩 계산
+/// * 수평 각도 표는 8 의 배수 길이로 0 을 채워 마지막 묶음도 같은 방식으로 계산
+/// * 포인트 버퍼는 `PointPool::global()` 에서 채널당 최대 포인트 수 용량으로 꺼내 사용
+pub struct ProjectionTable {
+    columns: usize,
+    vertical: Vec<(f32, f32)>,
+    cos_h: Vec<f32x8>,
+    sin_h: Vec<f32x8>,
+}
+
+impl ProjectionTable {
+    fn new(product_line: u8) -> Self {
+        let (v_angles, h_fov) = profile(product_line);
+        let columns = (h_fov / H_FOV_RESOLUTION) as usize;
+        let offset = (180.0 - h_fov) / 2.0;
+        let horizontal = |f: fn(f32) -> f32| -> Vec<f32x8> {
+            (0..columns.div_ceil(LANES))
+                .map(|block| {
+                    let mut values = [0.0; LANES];
+                    for (lane, value) in values.iter_mut().enumerate() {
+                        let index = block * LANES + lane;
+                        if index < columns {
+                            *value = f(PI * (index as f32 * H_FOV_RESOLUTION + offset) / 180.0);
+                        }
+                    }
+                    f32x8::from(values)
+                })
+                .collect()
+        };
+        Self {
+            columns,
+            vertical: v_angles
+                .iter()
+                .map(|v_angle| {
+                    let v_angle = PI * v_angle / 180.0;
+                    (v_angle.cos(), v_angle.sin())
+                })
+                .collect(),
+            cos_h: horizontal(f32::cos),
+            sin_h: horizontal(f32::sin),
+        }
+    }
+
+    /// 제품 라인의 투영 표 (프로세스에서 처음 사용할 때 생성)
+    pub fn for_product_line(product_line: u8) -> &'static ProjectionTable {
+        static NARROW: OnceLock<ProjectionTable> = OnceLock::new();
+        static WIDE: OnceLock<ProjectionTable> = OnceLock::new();
+        static DEFAULT: OnceLock<ProjectionTable> = OnceLock::new();
+        match product_line {
+            2 | 3 => NARROW.get_or_init(|| Self::new(product_line)),
+            7 => WIDE.get_or_init(|| Self::new(product_line)),
+            _ => DEFAULT.get_or_init(|| Self::new(product_line)),
+        }
+    }
+
+    /// 채널 수
+    pub fn channels(&self) -> usize {
+        self.vertical.len()
+    }
+
+    /// 채널당 최대 포인트 수 (수평 FOV / 분해능)
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// 거리 데이터를 3차원 좌표로 변환
+    ///
+    /// # Arguments
+    /// * `ch` - 채널 번호
+    /// * `data` - 거리 데이터 (2 bytes 씩: 정수부 m, 소수부 cm)
+    ///
+    /// # Returns
+    /// * `PointCloud` - 채널의 포인트 클라우드 (최대 `columns` 개), 제품 라인에 없는 채널이면 빈 포인트 클라우드
+    pub fn project(&self, ch: u8, data: &[u8]) -> PointCloud {
+        let Some(&(cos_v, sin_v)) = self.vertical.get(ch as usize) else {
+            return PointCloud::new();
+        };
+        let count = (data.len() / 2).min(self.columns);
+        let cos_v = f32x8::splat(cos_v);
+        let sin_v = f32x8::splat(sin_v);
+        let centimeter = f32x8::splat(0.01);
+
+        let mut points = PointPool::global().take(self.columns);
+        for (block, raw) in data[..count * 2].chunks(2 * LANES).enumerate() {
+            let mut whole = [0.0; LANES];
+            let mut fraction = [0.0; LANES];
+            for (lane, pair) in raw.chunks_exact(2).enumerate() {
+                whole[lane] = pair[0] as f32;
+                fraction[lane] = pair[1] as f32;
+            }
+            let distance = f32x8::from(whole) + f32x8::from(fraction) * centimeter;
+            let h = distance * cos_v;
+            let x = (h * self.cos_h[block]).to_array();
+            let y = (h * self.sin_h[block]).to_array();
+            let z = (distance * sin_v).to_array();
+            points.extend((0..raw.len() / 2).map(|lane| Point {
+                x: x[lane],
+                y: y[lane],
+                z: z[lane],
+            }));
+        }
+        PointCloud { points }
+    }
+}
+
+/// 포인트마다 삼각함수를 계산하는 투영 (표 사용 전 방식)
+///
+/// `ProjectionTable::project` 결과 확인과 벤치마크 비교용
+///
+/// # Arguments
+/// * `product_line` - 제품 라인 (수직/수평 FOV 결정)
+/// * `ch` - 채널 번호 (수직 각도 결정)
+/// * `data` - 거리 데이터 (2 bytes 씩: 정수부 m, 소수부 cm)
+///
+/// # Returns
+/// * `PointCloud` - 채널의 포인트 클라우드, 제품 라인에 없는 채널이면 빈 포인트 클라우드
+pub fn project_points_scalar(product_line: u8, ch: u8, data: &[u8]) -> PointCloud {
+    let (v_angles, h_fov) = profile(product_line);
+    let Some(&v_angle) = v_angles.get(ch as usize) else {
+        return PointCloud::new();
+    };
+
+    let mut fov_points = PointCloud::new();
+    for (h_angle_idx, pair) in data
+        .chunks_exact(2)
+        .enumerate()
+        .take((h_fov / H_FOV_RESOLUTION) as usize)
+    {
+        let dist = pair[0] as f32 + pair[1] as f32 * 0.01;
+        let h = (PI * v_angle / 180.0).cos() * dist;
+        let z = (PI * v_angle / 180.0).tan() * h;
+
+        let h_angle = (h_angle_idx as f32 * H_FOV_RESOLUTION) + ((180.0 - h_fov) / 2.0);
+        let x = (PI * h_angle / 180.0).cos() * h;
+        let y = (PI * h_angle / 180.0).tan() * x;
+
+        fov_points.add_point(Point { x, y, z });
+    }
+
+    fov_points
+}
@@ -0,0 +1,327 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::lidar::kanavi_mobility::command::*;
+use crate::lidar::LiDARKey;
+
+/// 설정 확인이 가능한 설정 파라미터 구조체
+///
+/// # Fields
+/// * `set` - 설정 파라미터 (ACK 파라미터: `set + 1`)
+/// * `request` - 설정 값을 다시 읽는 요청 파라미터 (응답 파라미터: `request + 1`)
+/// * `fields` - 설정 데이터의 필드 이름과 길이 (byte), 응답 데이터도 같은 형식
+pub struct SetParam {
+    pub set: u8,
+    pub request: u8,
+    pub fields: &'static [(&'static str, usize)],
+}
+
+impl SetParam {
+    /// 설정 데이터 길이 (byte)
+    pub fn data_len(&self) -> usize {
+        self.fields.iter().map(|(_, len)| len).sum()
+    }
+}
+
+/// 설정 확인이 가능한 설정 파라미터 목록
+pub const SET_PARAMS: [SetParam; 9] = [
+    SetParam {
+        set: PARAM_NETWORK_DESTINATION_IP_SET,
+        request: PARAM_NETWORK_DESTINATION_IP_REQUEST,
+        fields: &[("ip_address", 4)],
+    },
+    SetParam {
+        set: PARAM_MOTOR_SPEED_SET,
+        request: PARAM_MOTOR_SPEED_REQUEST,
+        fields: &[("speed", 1)],
+    },
+    SetParam {
+        set: PARAM_WARNING_AREA_SET,
+        request: PARAM_WARNING_AREA_REQUEST,
+        fields: &[("danger_area", 2), ("warning_area", 2), ("caution_area", 2)],
+    },
+    SetParam {
+        set: PARAM_FOG_FILTER_SET,
+        request: PARAM_FOG_FILTER_REQUEST,
+        fields: &[("filter_value", 1)],
+    },
+    SetParam {
+        set: PARAM_RADIUS_FILTER_SET,
+        request: PARAM_RADIUS_FILTER_REQUEST,
+        fields: &[("filter_value", 1)],
+    },
+    SetParam {
+        set: PARAM_RADIUS_FILTER_MAX_DISTANCE_SET,
+        request: PARAM_RADIUS_FILTER_MAX_DISTANCE_REQUEST,
+        fields: &[("max_distance", 1)],
+    },
+    SetParam {
+        set: PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_SET,
+        request: PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_REQUEST,
+        fields: &[("mode", 1)],
+    },
+    SetParam {
+        set: PARAM_TEACHING_MODE_SET,
+        request: PARAM_TEACHING_MODE_REQUEST,
+        fields: &[("range", 1), ("margin", 1)],
+    },
+    SetParam {
+        set: PARAM_RADIUS_FILTER_MIN_DISTANCE_SET,
+        request: PARAM_RADIUS_FILTER_MIN_DISTANCE_REQUEST,
+        fields: &[("min_distance", 1)],
+    },
+];
+
+/// 설정 파라미터 조회
+///
+/// # Arguments
+/// * `set` - 설정 파라미터
+///
+/// # Returns
+/// * `Option<&'static SetParam>` - 설정 확인이 가능한 파라미터면 Some, 아니면 None
+pub fn set_param(set: u8) -> Option<&'static SetParam> {
+    SET_PARAMS.iter().find(|param| param.set == set)
+}
+
+/// 설정 요청 값과 장치가 보고한 값이 다른 필드 구조체
+///
+/// # Fields
+/// * `field` - 필드 이름
+/// * `requested` - 설정 요청한 값 (byte)
+/// * `reported` - 장치가 보고한 값 (byte), 응답 데이터가 짧으면 빈 배열
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ConfigDifference {
+    pub field: String,
+    pub requested: Vec<u8>,
+    pub reported: Vec<u8>,
+}
+
+/// 설정 확인 결과 구조체
+///
+/// # Fields
+/// * `verified` - 장치가 보고한 값이 설정 요청한 값과 같으면 true
+/// * `differences` - 값이 다른 필드 목록
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ConfigVerification {
+    pub verified: bool,
+    pub differences: Vec<ConfigDifference>,
+}
+
+impl ConfigVerification {
+    /// 설정 요청 값과 장치가 보고한 값 비교
+    ///
+    /// # Arguments
+    /// * `param` - 설정 파라미터
+    /// * `requested` - 설정 요청한 데이터
+    /// * `reported` - 설정 확인 요청에 대한 응답 데이터
+    ///
+    /// # Returns
+    /// * `Self` - 필드별 비교 결과
+    pub fn compare(param: &SetParam, requested: &[u8], reported: &[u8]) -> Self {
+        let mut differences = Vec::new();
+        let mut offset = 0;
+        for (field, len) in param.fields {
+            let range = offset..offset + len;
+            offset += len;
+            let requested = requested.get(range.clone()).unwrap_or_default();
+            let reported = reported.get(range).unwrap_or_default();
+            if requested != reported {
+                differences.push(ConfigDifference {
+                    field: field.to_string(),
+                    requested: requested.to_vec(),
+                    reported: reported.to_vec(),
+                });
+            }
+        }
+        Self {
+            verified: differences.is_empty(),
+            differences,
+        }
+    }
+}
+
+/// 설정 요청 구조체
+///
+/// # Examples
+/// ```json
+/// { "command": "set_config", "data": { "lidar": { "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 }, "param": 160, "data": [3] } }
+/// ```
+///
+/// # Fields
+/// * `lidar` - 설정할 LiDAR 키
+/// * `param` - 설정 파라미터 (`SET_PARAMS` 중 하나)
+/// * `data` - 설정 데이터 (byte)
+/// * `validate_only` - true 면 검증과 프레임 생성만 하고 LiDAR 로 보내지 않음 (기본값 false)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SetConfigRequest {
+    pub lidar: LiDARKey,
+    pub param: u8,
+    pub data: Vec<u8>,
+    #[serde(default)]
+    pub validate_only: bool,
+}
+
+impl SetConfigRequest {
+    /// 설정 파라미터와 데이터 길이 검증
+    ///
+    /// # Returns
+    /// * `Result<&'static SetParam, String>` - 성공 시 설정 파라미터, 실패 시 에러 메시지
+    pub fn validate(&self) -> Result<&'static SetParam, String> {
+        validate_change(self.param, &self.data)
+    }
+}
+
+/// 설정 파라미터와 데이터 길이 검증
+fn validate_change(set: u8, data: &[u8]) -> Result<&'static SetParam, String> {
+    let param = set_param(set).ok_or_else(|| format!("Unsupported set param 0x{:02X}", set))?;
+    if data.len() != param.data_len() {
+        return Err(format!(
+            "Set param 0x{:02X} requires {} bytes of data, got {}",
+            set,
+            param.data_len(),
+            data.len()
+        ));
+    }
+    Ok(param)
+}
+
+/// 설정 트랜잭션의 설정 하나
+///
+/// # Fields
+/// * `param` - 설정 파라미터 (`SET_PARAMS` 중 하나)
+/// * `data` - 설정 데이터 (byte)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigChange {
+    pub param: u8,
+    pub data: Vec<u8>,
+}
+
+/// 설정 트랜잭션 요청 구조체
+///
+/// # Examples
+/// ```json
+/// { "command": "config_transaction", "data": { "lidar": { "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 }, "changes": [ { "param": 160, "data": [3] }, { "param": 192, "data": [1] } ] } }
+/// ```
+///
+/// # Fields
+/// * `lidar` - 설정할 LiDAR 키
+/// * `changes` - 순서대로 적용할 설정 목록
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigTransactionRequest {
+    pub lidar: LiDARKey,
+    pub changes: Vec<ConfigChange>,
+}
+
+impl ConfigTransactionRequest {
+    /// 설정 목록 검증
+    ///
+    /// # Returns
+    /// * `Result<Vec<&'static SetParam>, String>` - 성공 시 설정 순서대로의 설정 파라미터,
+    ///   설정 목록이 비었거나 같은 파라미터가 두 번 이상 있거나 파라미터/데이터 길이가 잘못되면 에러 메시지
+    pub fn validate(&self) -> Result<Vec<&'static SetParam>, String> {
+        if self.changes.is_empty() {
+            return Err("Config transaction has no changes".to_string());
+        }
+        let mut params: Vec<&'static SetParam> = Vec::with_capacity(self.changes.len());
+        for change in &self.changes {
+            let param = validate_change(change.param, &change.data)?;
+            if params.iter().any(|p| p.set == param.set) {
+                return Err(format!(
+                    "Set param 0x{:02X} appears more than once",
+                    change.param
+                ));
+            }
+            params.push(param);
+        }
+        Ok(params)
+    }
+}
+
+/// 설정 트랜잭션 롤백 단계 구조체
+///
+/// # Fields
+/// * `param` - 되돌린 설정 파라미터
+/// * `restored` - 다시 설정한 트랜잭션 시작 전 값 (byte)
+/// * `verified` - 다시 읽은 값이 트랜잭션 시작 전 값과 같으면 true
+/// * `error` - 되돌리기 실패 원인 (NAK, 제한 시간 초과 등), 성공 시 null
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct RollbackStep {
+    pub param: u8,
+    pub restored: Vec<u8>,
+    pub verified: bool,
+    pub error: Option<String>,
+}
+
+/// 설정 트랜잭션 결과 구조체
+///
+/// # Fields
+/// * `key` - 설정한 LiDAR 키
+/// * `committed` - 모든 설정이 적용되고 다시 읽은 값이 같으면 true
+/// * `applied` - 적용을 확인한 설정 결과 (실패한 경우 롤백 전까지 적용된 설정)
+/// * `error` - 트랜잭션 실패 원인, 성공 시 null
+/// * `rollback` - 실패한 설정부터 역순으로 트랜잭션 시작 전 값을 다시 설정한 결과 (성공 시 빈 목록)
+/// * `rolled_back` - 실패 후 모든 설정을 트랜잭션 시작 전 값으로 되돌렸으면 true
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ConfigTransactionResult {
+    pub key: LiDARKey,
+    pub committed: bool,
+    pub applied: Vec<SetConfigResult>,
+    pub error: Option<String>,
+    pub rollback: Vec<RollbackStep>,
+    pub rolled_back: bool,
+}
+
+/// 설정 결과 구조체
+///
+/// # Fields
+/// * `key` - 설정한 LiDAR 키
+/// * `param` - 설정 파라미터
+/// * `reported` - 설정 후 다시 읽은 데이터 (byte)
+/// * `verified` - 다시 읽은 값이 설정 요청한 값과 같으면 true
+/// * `differences` - 값이 다른 필드 목록
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SetConfigResult {
+    pub key: LiDARKey,
+    pub param: u8,
+    pub reported: Vec<u8>,
+    #[serde(flatten)]
+    pub verification: ConfigVerification,
+}
+
+/// 검증만 한 설정 요청 결과 구조체 (`validate_only`)
+///
+/// # Fields
+/// * `key` - 대상 LiDAR 키
+/// * `validate_only` - 항상 true
+/// * `frame` - 보냈을 명령 프레임 (16진수 문자열)
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DryRun {
+    pub key: LiDARKey,
+    pub validate_only: bool,
+    pub frame: String,
+}
+
+impl DryRun {
+    pub fn new(key: LiDARKey, frame: &[u8]) -> Self {
+        Self {
+            key,
+            validate_only: true,
+            frame: frame.iter().map(|byte| format!("{:02x}", byte)).collect(),
+        }
+    }
+}
+
+/// 설정 요청 처리 결과 열거형
+///
+/// # Variants
+/// * `Applied` - LiDAR 로 보내고 다시 읽어 확인한 결과
+/// * `Validated` - `validate_only` 요청의 검증 결과
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SetOutcome<T> {
+    Applied(T),
+    Validated(DryRun),
+}
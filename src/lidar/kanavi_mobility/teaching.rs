@@ -0,0 +1,220 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::lidar::kanavi_mobility::command::{
+    teaching_area_request, teaching_mode_command, PARAM_TEACHING_MODE_SET,
+};
+use crate::lidar::kanavi_mobility::{KMConfigData, KanaviMobilityData};
+use crate::lidar::LiDARKey;
+
+/// 티칭 요청 구조체
+///
+/// # Examples
+/// ```json
+/// { "command": "teaching", "data": { "lidar": { "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 }, "range": 10, "margin": 20 } }
+/// ```
+///
+/// # Fields
+/// * `lidar` - 티칭할 LiDAR 키
+/// * `range` - 티칭 범위 (티칭 모드 설정 값)
+/// * `margin` - 티칭 마진 (티칭 모드 설정 값)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TeachingRequest {
+    pub lidar: LiDARKey,
+    pub range: u8,
+    pub margin: u8,
+}
+
+/// 티칭 진행 단계 열거형
+///
+/// # Variants
+/// * `Started` - 티칭 모드 설정 명령 송신
+/// * `Acknowledged` - 티칭 모드 설정에 대한 ACK 수신
+/// * `Pending` - 티칭 영역을 요청했으나 NAK 또는 설정되지 않은 영역을 수신 (재요청 대기)
+/// * `Completed` - 설정된 티칭 영역 수신
+/// * `Failed` - 제한 시간 안에 티칭 영역을 받지 못함
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TeachingStage {
+    Started,
+    Acknowledged,
+    Pending,
+    Completed,
+    Failed,
+}
+
+/// 티칭 진행 상황 구조체 (`teaching_progress` 이벤트)
+///
+/// # Fields
+/// * `key` - 티칭 중인 LiDAR 키
+/// * `stage` - 진행 단계
+/// * `attempts` - 티칭 영역 요청 횟수
+/// * `nak_count` - 티칭 중 받은 NAK 응답 수
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TeachingProgress {
+    pub key: LiDARKey,
+    pub stage: TeachingStage,
+    pub attempts: u32,
+    pub nak_count: u32,
+}
+
+/// 티칭 영역 다각형 구조체
+///
+/// # Fields
+/// * `channel` - 수직 채널 번호
+/// * `points` - LiDAR 위치(원점)와 채널의 티칭 경계점으로 이루어진 다각형 꼭짓점 (`[x, y]`, m),
+///   구독 ROI 및 영역 침입 알림의 `polygon` 과 같은 형식
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TeachingZone {
+    pub channel: u8,
+    pub points: Vec<[f32; 2]>,
+}
+
+/// 티칭 결과 구조체
+///
+/// # Fields
+/// * `key` - 티칭한 LiDAR 키
+/// * `range` - 티칭 범위
+/// * `margin` - 티칭 마진
+/// * `attempts` - 티칭 영역 요청 횟수
+/// * `nak_count` - 티칭 중 받은 NAK 응답 수
+/// * `zones` - 채널별 티칭 영역 다각형
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TeachingResult {
+    pub key: LiDARKey,
+    pub range: u8,
+    pub margin: u8,
+    pub attempts: u32,
+    pub nak_count: u32,
+    pub zones: Vec<TeachingZone>,
+}
+
+/// 티칭 진행 관리 구조체
+///
+/// # Examples
+/// ```
+/// let mut session = TeachingSession::new(&request);
+/// send(session.start_frame());
+/// // 주기적으로
+/// send(session.area_request());
+/// // 설정 응답 수신 시
+/// if session.observe(&lidar_data) {
+///     notify(session.progress());
+/// }
+/// if session.is_complete() {
+///     let result = session.finish();
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 티칭 모드 설정 후 티칭 영역을 주기적으로 요청
+/// * 티칭이 끝나기 전에는 장치가 NAK 또는 설정되지 않은 영역으로 응답하므로 실패로 보지 않고 재요청
+/// * 설정된 티칭 영역을 받으면 채널별 다각형으로 변환
+pub struct TeachingSession {
+    range: u8,
+    margin: u8,
+    progress: TeachingProgress,
+    zones: Vec<TeachingZone>,
+}
+
+impl TeachingSession {
+    pub fn new(request: &TeachingRequest) -> Self {
+        Self {
+            range: request.range,
+            margin: request.margin,
+            progress: TeachingProgress {
+                key: request.lidar,
+                stage: TeachingStage::Started,
+                attempts: 0,
+                nak_count: 0,
+            },
+            zones: Vec::new(),
+        }
+    }
+
+    /// 티칭 모드 설정(티칭 시작) 명령 프레임
+    pub fn start_frame(&self) -> Vec<u8> {
+        let key = &self.progress.key;
+        teaching_mode_command(key.product_line, key.lidar_id, self.range, self.margin)
+    }
+
+    /// 티칭 영역 요청 프레임 (요청 횟수 증가)
+    pub fn area_request(&mut self) -> Vec<u8> {
+        self.progress.attempts += 1;
+        let key = &self.progress.key;
+        teaching_area_request(key.product_line, key.lidar_id)
+    }
+
+    /// 티칭 중인 LiDAR 의 설정 응답 반영
+    ///
+    /// # Arguments
+    /// * `data` - 티칭 중인 LiDAR 에서 수신한 데이터
+    ///
+    /// # Returns
+    /// * `bool` - 진행 상황이 바뀌었으면 true
+    pub fn observe(&mut self, data: &KanaviMobilityData) -> bool {
+        let previous = (self.progress.stage, self.progress.nak_count);
+        match data.get_config_data() {
+            Some(KMConfigData::Ack(_))
+                if data.get_param() == PARAM_TEACHING_MODE_SET + 1
+                    && self.progress.stage == TeachingStage::Started =>
+            {
+                self.progress.stage = TeachingStage::Acknowledged;
+            }
+            Some(KMConfigData::Nak(_)) => {
+                self.progress.nak_count += 1;
+                self.progress.stage = TeachingStage::Pending;
+            }
+            Some(KMConfigData::TeachingArea(area)) if area.is_set() => {
+                self.zones = area
+                    .points()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, points)| !points.is_empty())
+                    .map(|(channel, points)| TeachingZone {
+                        channel: channel as u8,
+                        points: std::iter::once([0.0, 0.0])
+                            .chain(points.iter().map(|point| [point.x, point.y]))
+                            .collect(),
+                    })
+                    .collect();
+                self.progress.stage = TeachingStage::Completed;
+            }
+            Some(KMConfigData::TeachingArea(_)) => {
+                self.progress.stage = TeachingStage::Pending;
+            }
+            _ => {}
+        }
+        previous != (self.progress.stage, self.progress.nak_count)
+    }
+
+    /// 현재 진행 상황
+    pub fn progress(&self) -> &TeachingProgress {
+        &self.progress
+    }
+
+    /// 설정된 티칭 영역을 받았는지 여부
+    pub fn is_complete(&self) -> bool {
+        self.progress.stage == TeachingStage::Completed
+    }
+
+    /// 티칭 결과 반환
+    ///
+    /// # Returns
+    /// * `Result<TeachingResult, TeachingProgress>` - 완료되었으면 티칭 결과, 아니면 실패 단계로 바꾼 진행 상황
+    pub fn finish(mut self) -> Result<TeachingResult, TeachingProgress> {
+        if !self.is_complete() {
+            self.progress.stage = TeachingStage::Failed;
+            return Err(self.progress);
+        }
+        Ok(TeachingResult {
+            key: self.progress.key,
+            range: self.range,
+            margin: self.margin,
+            attempts: self.progress.attempts,
+            nak_count: self.progress.nak_count,
+            zones: self.zones,
+        })
+    }
+}
@@ -1,17 +1,19 @@
-use crate::lidar::types::*;
+use crate::lidar::kanavi_mobility::command::*;
 use crate::lidar::traits::*;
+use crate::lidar::types::*;
+use bincode::{Decode, Encode};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::f32::consts::PI;
 use std::net::Ipv4Addr;
-use bincode::{Decode, Encode};
 
 /// 사용자 영역을 나타내는 구조체
 ///
 /// # Fields
 /// * `point_count` - 영역 내 포인트 개수
 /// * `points` - 영역을 구성하는 3차원 점들
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct UserArea {
     point_count: u8,
     points: Vec<Point>,
@@ -40,15 +42,35 @@ impl UserArea {
         result
     }
 
+    /// 응답 데이터 형식 (포인트 개수 1 byte + 포인트마다 x, y 각 2 byte: 부호 있는 정수부 m, 소수부 cm)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.points.len() * 4);
+        bytes.push(self.point_count);
+        for point in &self.points {
+            bytes.extend_from_slice(&Self::coordinate_bytes(point.x));
+            bytes.extend_from_slice(&Self::coordinate_bytes(point.y));
+        }
+        bytes
+    }
+
+    /// 좌표를 정수부/소수부 byte 로 변환 (`parse_coordinate` 의 역변환, 음수는 두 값 모두 음수)
+    fn coordinate_bytes(value: f32) -> [u8; 2] {
+        let centimeters = (value * 100.0).round().clamp(-12799.0, 12799.0) as i32;
+        [
+            (centimeters / 100) as i8 as u8,
+            (centimeters % 100) as i8 as u8,
+        ]
+    }
+
     fn parse_coordinate(bytes: &[u8]) -> f32 {
         let mut value1 = bytes[0] as i8;
         let mut value2 = bytes[1] as i8;
 
         if (value1 >> 7) == 0x01 {
-            value1 = ((bytes[0] ^ 0xFF) as i8 + 1) * -1;
+            value1 = -((bytes[0] ^ 0xFF) as i8 + 1);
         }
         if (value2 >> 7) == 0x01 {
-            value2 = ((bytes[1] ^ 0xFF) as i8 + 1) * -1;
+            value2 = -((bytes[1] ^ 0xFF) as i8 + 1);
         }
 
         value1 as f32 + (value2 as f32 * 0.01)
@@ -71,7 +93,7 @@ impl UserArea {
 /// * `object_size` - 객체 크기
 /// * `area_count` - 사용자 영역 개수
 /// * `areas` - 사용자 영역들
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct BasicConfig {
     output_channel: u8,
     self_check_active_state: u8,
@@ -89,6 +111,7 @@ pub struct BasicConfig {
 }
 
 impl BasicConfig {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         output_channel: u8,
         self_check_active_state: u8,
@@ -120,6 +143,38 @@ impl BasicConfig {
             areas,
         }
     }
+
+    pub fn self_check_active_state(&self) -> u8 {
+        self.self_check_active_state
+    }
+
+    /// 응답 데이터 형식 (고정 필드 14 byte + 사용자 영역)
+    ///
+    /// # 동작 설명
+    /// * 각도는 2 byte big endian, 나머지 고정 필드는 1 byte
+    /// * 사용자 영역은 `UserArea::to_bytes` 를 순서대로 이어 붙임
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            self.output_channel,
+            self.self_check_active_state,
+            self.pulse_active_state,
+            self.pulse_output_mode,
+            self.pulse_pin_mode,
+            self.pulse_pin_channel,
+        ];
+        bytes.extend_from_slice(&self.start_angle.to_be_bytes());
+        bytes.extend_from_slice(&self.finish_angle.to_be_bytes());
+        bytes.extend_from_slice(&[
+            self.min_distance,
+            self.max_distance,
+            self.object_size,
+            self.area_count,
+        ]);
+        for area in &self.areas {
+            bytes.extend(area.to_bytes());
+        }
+        bytes
+    }
 }
 
 /// 버전 정보를 나타내는 구조체
@@ -128,7 +183,7 @@ impl BasicConfig {
 /// * `firmware_version` - 펌웨어 버전
 /// * `hardware_version` - 하드웨어 버전
 /// * `end_target` - 설치 목적
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct VersionInfo {
     firmware_version: [u8; 3],
     hardware_version: [u8; 3],
@@ -143,6 +198,35 @@ impl VersionInfo {
             end_target,
         }
     }
+
+    pub fn firmware_version(&self) -> [u8; 3] {
+        self.firmware_version
+    }
+
+    pub fn hardware_version(&self) -> [u8; 3] {
+        self.hardware_version
+    }
+
+    /// 펌웨어 버전 문자열 (`major.minor.patch`)
+    pub fn firmware_version_text(&self) -> String {
+        format_version(self.firmware_version)
+    }
+
+    /// 하드웨어 버전 문자열 (`major.minor.patch`)
+    pub fn hardware_version_text(&self) -> String {
+        format_version(self.hardware_version)
+    }
+
+    /// 응답 데이터 형식 (펌웨어 버전 3 byte + 하드웨어 버전 3 byte + 설치 목적 1 byte)
+    pub fn to_bytes(&self) -> [u8; 7] {
+        let [fw0, fw1, fw2] = self.firmware_version;
+        let [hw0, hw1, hw2] = self.hardware_version;
+        [fw0, fw1, fw2, hw0, hw1, hw2, self.end_target]
+    }
+}
+
+fn format_version(version: [u8; 3]) -> String {
+    format!("{}.{}.{}", version[0], version[1], version[2])
 }
 
 /// 네트워크 소스 정보를 나타내는 구조체
@@ -153,7 +237,7 @@ impl VersionInfo {
 /// * `subnet_mask` - 서브넷 마스크
 /// * `gateway` - 게이트웨이
 /// * `port` - 포트 번호
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct NetworkSourceInfo {
     ip_address: [u8; 4],
     mac_address: [u8; 6],
@@ -178,6 +262,25 @@ impl NetworkSourceInfo {
             port,
         }
     }
+
+    pub fn get_ip_address(&self) -> Ipv4Addr {
+        Ipv4Addr::from(self.ip_address)
+    }
+
+    pub fn get_mac_address(&self) -> [u8; 6] {
+        self.mac_address
+    }
+
+    /// 설정/응답 데이터 형식 (IP 4 + MAC 6 + 서브넷 마스크 4 + 게이트웨이 4 + 포트 2 byte big endian)
+    pub fn to_bytes(&self) -> [u8; 20] {
+        let mut bytes = [0; 20];
+        bytes[..4].copy_from_slice(&self.ip_address);
+        bytes[4..10].copy_from_slice(&self.mac_address);
+        bytes[10..14].copy_from_slice(&self.subnet_mask);
+        bytes[14..18].copy_from_slice(&self.gateway);
+        bytes[18..].copy_from_slice(&self.port.to_be_bytes());
+        bytes
+    }
 }
 
 /// 티칭 영역을 나타내는 구조체
@@ -185,7 +288,7 @@ impl NetworkSourceInfo {
 /// # Fields
 /// * `is_set` - 티칭 영역 설정 여부
 /// * `points` - 티칭 영역을 구성하는 3차원 점들
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct TeachingArea {
     is_set: u8,
     points: Vec<Vec<Point>>,
@@ -196,6 +299,30 @@ impl TeachingArea {
         Self { is_set, points }
     }
 
+    pub fn is_set(&self) -> bool {
+        self.is_set == 1
+    }
+
+    pub fn points(&self) -> &[Vec<Point>] {
+        &self.points
+    }
+
+    /// 응답 데이터 형식 (설정 여부 1 byte + 설정된 경우 채널/각도 순서의 거리 2 byte 씩: 정수부 m, 소수부 cm)
+    ///
+    /// # 동작 설명
+    /// * 거리는 포인트의 원점 거리를 cm 단위로 반올림 (`parse` 로 다시 읽으면 cm 이하 오차)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.is_set];
+        if self.is_set() {
+            for point in self.points.iter().flatten() {
+                let distance = (point.x * point.x + point.y * point.y + point.z * point.z).sqrt();
+                let centimeters = (distance * 100.0).round().clamp(0.0, 25599.0) as u16;
+                bytes.extend_from_slice(&[(centimeters / 100) as u8, (centimeters % 100) as u8]);
+            }
+        }
+        bytes
+    }
+
     pub fn parse(product_line: u8, is_set: u8, raw_points: Vec<u8>) -> Self {
         let points = if is_set == 1 {
             Self::parse_points(product_line, raw_points)
@@ -206,27 +333,36 @@ impl TeachingArea {
         Self::new(is_set, points)
     }
 
+    /// 제품 라인별 수직 채널 각도 목록과 수평 시야각
+    fn fov(product_line: u8) -> (Vec<f32>, f32) {
+        match product_line {
+            2 | 3 => (vec![0.0, 3.0], 120.0),
+            7 => (vec![0.0], 270.0),
+            _ => (vec![-1.07, 0.0, 1.07, 2.14], 100.0),
+        }
+    }
+
+    /// 제품 라인의 티칭 영역 거리 개수 (채널 수 × 채널당 수평 해상도 0.25° 단위 거리 수)
+    ///
+    /// # Arguments
+    /// * `product_line` - 제품 라인
+    ///
+    /// # Returns
+    /// * `usize` - 설정된 티칭 영역 응답에 들어있어야 하는 거리(2 byte) 개수
+    pub fn point_count(product_line: u8) -> usize {
+        let (fov_list, h_fov) = Self::fov(product_line);
+        fov_list.len() * (h_fov / 0.25) as usize
+    }
+
     pub fn parse_points(product_line: u8, points: Vec<u8>) -> Vec<Vec<Point>> {
         let mut result_points = Vec::new();
-        let mut fov_list: Vec<f32> = vec![-1.07, 0.0, 1.07, 2.14];
+        let (fov_list, h_fov) = Self::fov(product_line);
         let h_fov_resol = 0.25;
-        let mut h_fov = 100.0;
-        match product_line {
-            2 | 3 => {
-                fov_list = vec![0.0, 3.0];
-                h_fov = 120.0;
-            }
-            7 => {
-                fov_list = vec![0.0];
-                h_fov = 270.0;
-            }
-            _ => {}
-        }
 
-        let mut distance: Vec<f32> = Vec::new();
-        for i in (0..points.len() as usize).step_by(2) {
-            distance.push(points[i] as f32 + points[i + 1] as f32 * 0.01);
-        }
+        let distance: Vec<f32> = points
+            .chunks_exact(2)
+            .map(|pair| pair[0] as f32 + pair[1] as f32 * 0.01)
+            .collect();
 
         for v_angle in fov_list.clone() {
             let mut fov_points = Vec::new();
@@ -241,7 +377,9 @@ impl TeachingArea {
                     * (h_fov / h_fov_resol)
                     + h_angle_idx as f32;
 
-                let dist = distance[idx as usize];
+                let Some(&dist) = distance.get(idx as usize) else {
+                    break;
+                };
                 let h = (PI * v_angle / 180.0).cos() * dist;
                 point.z = (PI * v_angle / 180.0).tan() * h;
 
@@ -263,7 +401,7 @@ impl TeachingArea {
 ///
 /// # Fields
 /// * `ip_address` - IP 주소
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct NetworkDestinationIP {
     ip_address: [u8; 4],
 }
@@ -272,13 +410,18 @@ impl NetworkDestinationIP {
     pub fn new(ip_address: [u8; 4]) -> Self {
         Self { ip_address }
     }
+
+    /// 설정/응답 데이터 형식 (IP 4 byte)
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.ip_address
+    }
 }
 
 /// 모터 속도를 나타내는 구조체
 ///
 /// # Fields
 /// * `speed` - 모터 속도
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct MotorSpeed {
     speed: u8,
 }
@@ -287,6 +430,15 @@ impl MotorSpeed {
     pub fn new(speed: u8) -> Self {
         Self { speed }
     }
+
+    pub fn speed(&self) -> u8 {
+        self.speed
+    }
+
+    /// 설정/응답 데이터 형식 (1 byte)
+    pub fn to_bytes(&self) -> [u8; 1] {
+        [self.speed]
+    }
 }
 
 /// 경고 영역을 나타내는 구조체
@@ -295,7 +447,7 @@ impl MotorSpeed {
 /// * `danger_area` - 위험 영역
 /// * `warning_area` - 경고 영역
 /// * `caution_area` - 주의 영역
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct WarningArea {
     danger_area: [u8; 2],
     warning_area: [u8; 2],
@@ -310,13 +462,31 @@ impl WarningArea {
             caution_area,
         }
     }
+
+    /// 위험/경고/주의 영역 거리 (m)
+    pub fn distances(&self) -> [f32; 3] {
+        [self.danger_area, self.warning_area, self.caution_area]
+            .map(|area| area[0] as f32 + area[1] as f32 * 0.01)
+    }
+
+    /// 설정/응답 데이터 형식 (위험, 경고, 주의 순서로 각 2 byte: 정수부 m, 소수부 cm)
+    pub fn to_bytes(&self) -> [u8; 6] {
+        [
+            self.danger_area[0],
+            self.danger_area[1],
+            self.warning_area[0],
+            self.warning_area[1],
+            self.caution_area[0],
+            self.caution_area[1],
+        ]
+    }
 }
 
 /// 안개 필터를 나타내는 구조체
 ///
 /// # Fields
 /// * `filter_value` - 필터 값
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct FogFilter {
     filter_value: u8,
 }
@@ -325,13 +495,22 @@ impl FogFilter {
     pub fn new(filter_value: u8) -> Self {
         Self { filter_value }
     }
+
+    pub fn filter_value(&self) -> u8 {
+        self.filter_value
+    }
+
+    /// 설정/응답 데이터 형식 (1 byte)
+    pub fn to_bytes(&self) -> [u8; 1] {
+        [self.filter_value]
+    }
 }
 
 /// 오감지 필터를 나타내는 구조체
 ///
 /// # Fields
 /// * `filter_value` - 필터 값
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct RadiusFilter {
     filter_value: u8,
 }
@@ -340,13 +519,18 @@ impl RadiusFilter {
     pub fn new(filter_value: u8) -> Self {
         Self { filter_value }
     }
+
+    /// 설정/응답 데이터 형식 (1 byte)
+    pub fn to_bytes(&self) -> [u8; 1] {
+        [self.filter_value]
+    }
 }
 
 /// 최대 오감지 필터 거리를 나타내는 구조체
 ///
 /// # Fields
 /// * `max_distance` - 최대 거리
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct RadiusFilterMaxDistance {
     max_distance: u8,
 }
@@ -355,13 +539,18 @@ impl RadiusFilterMaxDistance {
     pub fn new(max_distance: u8) -> Self {
         Self { max_distance }
     }
+
+    /// 설정/응답 데이터 형식 (1 byte)
+    pub fn to_bytes(&self) -> [u8; 1] {
+        [self.max_distance]
+    }
 }
 
 /// 창 오염 감지 모드를 나타내는 구조체
 ///
 /// # Fields
 /// * `mode` - 모드
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct WindowContaminationDetectionMode {
     mode: u8,
 }
@@ -370,6 +559,15 @@ impl WindowContaminationDetectionMode {
     pub fn new(mode: u8) -> Self {
         Self { mode }
     }
+
+    pub fn mode(&self) -> u8 {
+        self.mode
+    }
+
+    /// 설정/응답 데이터 형식 (1 byte)
+    pub fn to_bytes(&self) -> [u8; 1] {
+        [self.mode]
+    }
 }
 
 /// 티칭 모드를 나타내는 구조체
@@ -377,7 +575,7 @@ impl WindowContaminationDetectionMode {
 /// # Fields
 /// * `range` - 범위
 /// * `margin` - 마진
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct TeachingMode {
     range: u8,
     margin: u8,
@@ -387,13 +585,26 @@ impl TeachingMode {
     pub fn new(range: u8, margin: u8) -> Self {
         Self { range, margin }
     }
+
+    pub fn range(&self) -> u8 {
+        self.range
+    }
+
+    pub fn margin(&self) -> u8 {
+        self.margin
+    }
+
+    /// 설정/응답 데이터 형식 (범위 1 byte + 마진 1 byte)
+    pub fn to_bytes(&self) -> [u8; 2] {
+        [self.range, self.margin]
+    }
 }
 
 /// 최소 오감지 필터 거리를 나타내는 구조체
 ///
 /// # Fields
 /// * `min_distance` - 최소 거리
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct RadiusFilterMinDistance {
     min_distance: u8,
 }
@@ -402,6 +613,11 @@ impl RadiusFilterMinDistance {
     pub fn new(min_distance: u8) -> Self {
         Self { min_distance }
     }
+
+    /// 설정/응답 데이터 형식 (1 byte)
+    pub fn to_bytes(&self) -> [u8; 1] {
+        [self.min_distance]
+    }
 }
 
 /// Kanavi Mobility LiDAR 설정 데이터 열거형
@@ -422,7 +638,7 @@ impl RadiusFilterMinDistance {
 /// * `RadiusFilterMinDistance` - 최소 오감지 필터 거리
 /// * `Ack` - 정상 응답
 /// * `Nak` - 비정상 응답
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub enum KMConfigData {
     BasicConfig(BasicConfig),
     VersionInfo(VersionInfo),
@@ -441,6 +657,54 @@ pub enum KMConfigData {
     Nak(u8),
 }
 
+impl KMConfigData {
+    /// 장치 데이터 형식 (`codec::decode_config` 의 역변환)
+    ///
+    /// # Returns
+    /// * `Vec<u8>` - 같은 설정의 응답 데이터 형식, 설정 명령 데이터도 같은 형식 (ACK 는 응답 코드 1 byte, NAK 는 빈 데이터)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            KMConfigData::BasicConfig(config) => config.to_bytes(),
+            KMConfigData::VersionInfo(info) => info.to_bytes().to_vec(),
+            KMConfigData::NetworkSourceInfo(info) => info.to_bytes().to_vec(),
+            KMConfigData::TeachingArea(area) => area.to_bytes(),
+            KMConfigData::NetworkDestinationIP(ip) => ip.to_bytes().to_vec(),
+            KMConfigData::MotorSpeed(speed) => speed.to_bytes().to_vec(),
+            KMConfigData::WarningArea(area) => area.to_bytes().to_vec(),
+            KMConfigData::FogFilter(filter) => filter.to_bytes().to_vec(),
+            KMConfigData::RadiusFilter(filter) => filter.to_bytes().to_vec(),
+            KMConfigData::RadiusFilterMaxDistance(distance) => distance.to_bytes().to_vec(),
+            KMConfigData::WindowContaminationDetectionMode(mode) => mode.to_bytes().to_vec(),
+            KMConfigData::TeachingMode(mode) => mode.to_bytes().to_vec(),
+            KMConfigData::RadiusFilterMinDistance(distance) => distance.to_bytes().to_vec(),
+            KMConfigData::Ack(code) => vec![*code],
+            KMConfigData::Nak(_) => Vec::new(),
+        }
+    }
+
+    /// `to_bytes` 데이터로 같은 설정을 쓰는 설정 파라미터
+    ///
+    /// # Returns
+    /// * `Option<u8>` - 설정 명령 파라미터, 읽기 전용 정보(기본 설정, 버전, 티칭 영역)나 ACK/NAK 면 None
+    pub fn set_param(&self) -> Option<u8> {
+        match self {
+            KMConfigData::NetworkSourceInfo(_) => Some(PARAM_NETWORK_SOURCE_INFO_SET),
+            KMConfigData::NetworkDestinationIP(_) => Some(PARAM_NETWORK_DESTINATION_IP_SET),
+            KMConfigData::MotorSpeed(_) => Some(PARAM_MOTOR_SPEED_SET),
+            KMConfigData::WarningArea(_) => Some(PARAM_WARNING_AREA_SET),
+            KMConfigData::FogFilter(_) => Some(PARAM_FOG_FILTER_SET),
+            KMConfigData::RadiusFilter(_) => Some(PARAM_RADIUS_FILTER_SET),
+            KMConfigData::RadiusFilterMaxDistance(_) => Some(PARAM_RADIUS_FILTER_MAX_DISTANCE_SET),
+            KMConfigData::WindowContaminationDetectionMode(_) => {
+                Some(PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_SET)
+            }
+            KMConfigData::TeachingMode(_) => Some(PARAM_TEACHING_MODE_SET),
+            KMConfigData::RadiusFilterMinDistance(_) => Some(PARAM_RADIUS_FILTER_MIN_DISTANCE_SET),
+            _ => None,
+        }
+    }
+}
+
 /// Kanavi Mobility LiDAR 데이터 구조체
 ///
 /// # Fields
@@ -452,7 +716,7 @@ pub enum KMConfigData {
 /// * `mode` - 모드
 /// * `param` - 파라미터
 /// * `data` - 설정 데이터
-#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct KanaviMobilityData {
     // 공통 데이터
     raw_data: Vec<u8>,
@@ -496,9 +760,37 @@ impl KanaviMobilityData {
         self.points[ch as usize] = points;
     }
 
+    pub fn replace_points(&mut self, points: Vec<PointCloud>) {
+        self.points = points;
+    }
+
     pub fn set_data(&mut self, data: KMConfigData) {
         self.data = Some(data);
     }
+
+    pub fn get_ip(&self) -> Ipv4Addr {
+        self.ip
+    }
+
+    pub fn get_product_line(&self) -> u8 {
+        self.product_line
+    }
+
+    pub fn get_lidar_id(&self) -> u8 {
+        self.lidar_id
+    }
+
+    pub fn get_mode(&self) -> u8 {
+        self.mode
+    }
+
+    pub fn get_param(&self) -> u8 {
+        self.param
+    }
+
+    pub fn get_config_data(&self) -> Option<&KMConfigData> {
+        self.data.as_ref()
+    }
 }
 
 impl LiDARData for KanaviMobilityData {
@@ -528,6 +820,10 @@ impl LiDARData for KanaviMobilityData {
             | (octets[3] as u64)
     }
 
+    fn take_points(&mut self) -> Vec<PointCloud> {
+        std::mem::take(&mut self.points)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -0,0 +1,125 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::lidar::kanavi_mobility::{ConfigVerification, WarningArea};
+use crate::lidar::LiDARKey;
+
+/// 제품 라인별 최대 감지 거리 (m)
+///
+/// # Arguments
+/// * `product_line` - 제품 라인
+///
+/// # Returns
+/// * `f32` - 최대 감지 거리
+///   - 2, 3: 2채널 120° 제품 (R2)
+///   - 7: 1채널 270° 제품 (R270)
+///   - 그 외: 4채널 100° 제품 (R4)
+pub fn max_range(product_line: u8) -> f32 {
+    match product_line {
+        2 | 3 => 30.0,
+        7 => 25.0,
+        _ => 40.0,
+    }
+}
+
+/// 경고 영역 설정 요청 구조체
+///
+/// # Examples
+/// ```json
+/// { "command": "set_warning_area", "data": { "lidar": { "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 }, "danger": 1.5, "warning": 3.0, "caution": 5.25 } }
+/// ```
+///
+/// # Fields
+/// * `lidar` - 설정할 LiDAR 키
+/// * `danger` - 위험 영역 거리 (m)
+/// * `warning` - 경고 영역 거리 (m)
+/// * `caution` - 주의 영역 거리 (m)
+/// * `validate_only` - true 면 검증과 프레임 생성만 하고 LiDAR 로 보내지 않음 (기본값 false)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WarningAreaRequest {
+    pub lidar: LiDARKey,
+    pub danger: f32,
+    pub warning: f32,
+    pub caution: f32,
+    #[serde(default)]
+    pub validate_only: bool,
+}
+
+impl WarningAreaRequest {
+    /// 거리 검증 후 장치 형식으로 변환
+    ///
+    /// # Returns
+    /// * `Result<WarningArea, String>` - 성공 시 경고 영역 (cm 단위 반올림), 실패 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 모든 거리는 0 이상이고 위험 ≤ 경고 ≤ 주의 순서여야 함
+    /// * 주의 영역 거리는 제품 라인의 최대 감지 거리 이하여야 함
+    pub fn to_area(&self) -> Result<WarningArea, String> {
+        let distances = [
+            ("danger", self.danger),
+            ("warning", self.warning),
+            ("caution", self.caution),
+        ];
+        for (name, distance) in distances {
+            if !distance.is_finite() || distance < 0.0 {
+                return Err(format!("{} must be a non-negative distance", name));
+            }
+        }
+        if self.danger > self.warning || self.warning > self.caution {
+            return Err("Distances must satisfy danger <= warning <= caution".to_string());
+        }
+        let max_range = max_range(self.lidar.product_line);
+        if self.caution > max_range {
+            return Err(format!(
+                "caution {} m exceeds max range {} m of product line {}",
+                self.caution, max_range, self.lidar.product_line
+            ));
+        }
+
+        let encode = |distance: f32| {
+            let cm = (distance * 100.0).round() as u32;
+            [(cm / 100) as u8, (cm % 100) as u8]
+        };
+        Ok(WarningArea::new(
+            encode(self.danger),
+            encode(self.warning),
+            encode(self.caution),
+        ))
+    }
+}
+
+/// 경고 영역 설정 결과 구조체
+///
+/// # Fields
+/// * `key` - 설정한 LiDAR 키
+/// * `danger` - 설정 후 다시 읽은 위험 영역 거리 (m)
+/// * `warning` - 설정 후 다시 읽은 경고 영역 거리 (m)
+/// * `caution` - 설정 후 다시 읽은 주의 영역 거리 (m)
+/// * `max_range` - 제품 라인의 최대 감지 거리 (m)
+/// * `verified` - 다시 읽은 값이 설정 요청한 값과 같으면 true
+/// * `differences` - 값이 다른 필드 목록 (장치 형식 byte)
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct WarningAreaResult {
+    pub key: LiDARKey,
+    pub danger: f32,
+    pub warning: f32,
+    pub caution: f32,
+    pub max_range: f32,
+    #[serde(flatten)]
+    pub verification: ConfigVerification,
+}
+
+impl WarningAreaResult {
+    pub fn new(key: LiDARKey, area: &WarningArea, verification: ConfigVerification) -> Self {
+        let [danger, warning, caution] = area.distances();
+        Self {
+            key,
+            danger,
+            warning,
+            caution,
+            max_range: max_range(key.product_line),
+            verification,
+        }
+    }
+}
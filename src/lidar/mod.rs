@@ -1,7 +1,19 @@
+pub mod config;
+pub mod drivers;
+pub mod parse_stats;
+pub mod pool;
+pub mod registry;
 pub mod traits;
 pub mod types;
 
 pub mod kanavi_mobility;
 
+pub use config::IdentityConfig;
+pub use drivers::{drivers, enabled_drivers};
+pub use parse_stats::{
+    ParseError, ParseStatsStore, ParserStats, RejectionStats, SenderMemoryStats,
+};
+pub use pool::{PointPool, PointPoolStats};
+pub use registry::LiDARRegistry;
 pub use traits::*;
 pub use types::*;
@@ -0,0 +1,347 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+use crate::common::time::unix_time_ms;
+use crate::lidar::pool::{PointPool, PointPoolStats};
+use crate::udp::filter::Rejection;
+
+/// 파싱 실패 원인 열거형
+///
+/// # Variants
+/// * `Truncated` - 프레임 데이터가 설정 응답 파라미터에 필요한 길이보다 짧음
+///   (프레임이 아직 다 오지 않은 경우는 실패가 아니라 `ParseOutcome::NeedMoreData`)
+/// * `Checksum` - 체크섬 불일치
+/// * `UnknownParam` - 지원하지 않는 설정 응답 파라미터
+/// * `InvalidHeader` - 헤더(0xFA) 불일치
+/// * `Other` - 그 외 파싱 실패 (플러그인 파서 에러 포함)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Truncated,
+    Checksum,
+    UnknownParam(u8),
+    InvalidHeader,
+    Other(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Truncated => write!(f, "truncated frame"),
+            ParseError::Checksum => write!(f, "checksum mismatch"),
+            ParseError::UnknownParam(param) => write!(f, "unknown param 0x{:02X}", param),
+            ParseError::InvalidHeader => write!(f, "invalid header"),
+            ParseError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// LiDAR 송신 주소별 파싱 통계 구조체
+///
+/// # Fields
+/// * `ip` - 송신 IP 주소
+/// * `port` - 송신 포트
+/// * `frames_ok` - 파싱에 성공한 프레임 수
+/// * `checksum_failures` - 체크섬이 맞지 않는 프레임 수
+/// * `truncated_frames` - 길이가 부족한 프레임 수
+/// * `unknown_params` - 지원하지 않는 설정 응답 파라미터 수
+/// * `other_errors` - 그 외 파싱 실패 수 (헤더 불일치, 플러그인 파서 에러 등)
+/// * `last_error` - 마지막 파싱 실패 원인, 실패한 적이 없으면 null
+/// * `last_error_ms` - 마지막 파싱 실패 시각 (Unix time, ms), 실패한 적이 없으면 null
+/// * `last_seen_ms` - 마지막으로 데이터를 받은 시각 (Unix time, ms)
+/// * `quarantined_until_ms` - 파싱 실패가 많아 격리 중이면 다시 파싱을 시도할 시각 (Unix time, ms), 아니면 null
+/// * `quarantines` - 격리된 횟수 (`udp.error_budget`)
+/// * `quarantined_frames` - 격리 중에 파싱하지 않고 버린 데이터그램 수
+/// * `buffered_bytes` - 다음 데이터그램과 이어 붙이기 위해 보관 중인 프레임 조각 크기 (byte, 정리 주기마다 갱신)
+/// * `buffer_resets` - 최대 크기(`udp.sender_state.max_buffer_bytes`)를 넘어 파서 버퍼를 비운 횟수
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ParserStats {
+    pub ip: IpAddr,
+    pub port: u16,
+    pub frames_ok: u64,
+    pub checksum_failures: u64,
+    pub truncated_frames: u64,
+    pub unknown_params: u64,
+    pub other_errors: u64,
+    pub last_error: Option<String>,
+    pub last_error_ms: Option<u64>,
+    pub last_seen_ms: u64,
+    pub quarantined_until_ms: Option<u64>,
+    pub quarantines: u64,
+    pub quarantined_frames: u64,
+    pub buffered_bytes: usize,
+    pub buffer_resets: u64,
+}
+
+impl ParserStats {
+    fn new(addr: SocketAddr) -> Self {
+        Self {
+            ip: addr.ip(),
+            port: addr.port(),
+            frames_ok: 0,
+            checksum_failures: 0,
+            truncated_frames: 0,
+            unknown_params: 0,
+            other_errors: 0,
+            last_error: None,
+            last_error_ms: None,
+            last_seen_ms: unix_time_ms(),
+            quarantined_until_ms: None,
+            quarantines: 0,
+            quarantined_frames: 0,
+            buffered_bytes: 0,
+            buffer_resets: 0,
+        }
+    }
+}
+
+/// 송신 주소 필터로 거부한 데이터그램 통계 구조체
+///
+/// # Fields
+/// * `denied` - 차단 목록(`udp.source_filter.deny`)에 있어 버린 데이터그램 수
+/// * `not_allowed` - 허용 목록(`udp.source_filter.allow`)에 없어 버린 데이터그램 수
+/// * `last_rejected_ms` - 마지막으로 거부한 시각 (Unix time, ms), 거부한 적이 없으면 null
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, JsonSchema, ToSchema)]
+pub struct RejectionStats {
+    pub denied: u64,
+    pub not_allowed: u64,
+    pub last_rejected_ms: Option<u64>,
+}
+
+/// 송신 주소별 상태 메모리 통계 구조체
+///
+/// # Fields
+/// * `senders` - 파싱 통계를 보관 중인 송신 주소 수
+/// * `parse_stats_bytes` - 파싱 통계에 사용 중인 대략적인 메모리 (byte)
+/// * `dedup_bytes` - 중복 제거 기록에 사용 중인 대략적인 메모리 (byte)
+/// * `parser_buffer_bytes` - 모든 송신 주소의 파서가 보관 중인 프레임 조각 크기 (byte)
+/// * `total_bytes` - 위 메모리의 합
+/// * `evicted_senders` - 오래되어 삭제한 송신 주소 수 (누적)
+/// * `buffer_resets` - 최대 크기를 넘어 비운 파서 버퍼 수 (모든 송신 주소 누적)
+/// * `point_pool` - 프레임 간 재사용하는 포인트 버퍼 풀 통계 (`total_bytes` 에 보관 중인 버퍼 메모리 포함)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, JsonSchema, ToSchema)]
+pub struct SenderMemoryStats {
+    pub senders: usize,
+    pub parse_stats_bytes: usize,
+    pub dedup_bytes: usize,
+    pub parser_buffer_bytes: usize,
+    pub total_bytes: usize,
+    pub evicted_senders: u64,
+    pub buffer_resets: u64,
+    pub point_pool: PointPoolStats,
+}
+
+/// 송신 주소별 상태 메모리 카운터 (정리 태스크와 수신 루프가 기록)
+#[derive(Debug, Default)]
+struct MemoryCounters {
+    dedup_bytes: AtomicUsize,
+    parser_buffer_bytes: AtomicUsize,
+    evicted_senders: AtomicU64,
+    buffer_resets: AtomicU64,
+}
+
+/// 송신 주소 필터 거부 카운터 (수신 루프에서 잠금 없이 기록)
+#[derive(Debug, Default)]
+struct RejectionCounters {
+    denied: AtomicU64,
+    not_allowed: AtomicU64,
+    last_rejected_ms: AtomicU64,
+}
+
+/// 파싱 통계 저장소
+///
+/// # Examples
+/// ```
+/// let stats = ParseStatsStore::default();
+/// stats.record_ok(src_addr).await;
+/// stats.record_error(src_addr, &ParseError::Checksum).await;
+/// let list = stats.list().await;
+/// ```
+///
+/// # 주요 기능
+/// * UDP 리스너가 송신 주소별로 파싱 성공/실패를 기록
+/// * WebSocket 서버의 `parser_stats` 요청에 통계 제공 (케이블/네트워크 불량 진단)
+/// * 송신 주소 필터로 거부한 데이터그램은 송신 주소별로 나누지 않고 거부 이유별 수만 기록
+/// * 오래된 송신 주소 삭제와 송신 주소별 상태의 메모리 사용량 기록 (UDP 리스너의 정리 태스크)
+#[derive(Debug, Clone, Default)]
+pub struct ParseStatsStore {
+    inner: Arc<Mutex<HashMap<SocketAddr, ParserStats>>>,
+    rejected: Arc<RejectionCounters>,
+    memory: Arc<MemoryCounters>,
+}
+
+impl ParseStatsStore {
+    /// 파싱 성공 기록
+    pub async fn record_ok(&self, addr: SocketAddr) {
+        let mut inner = self.inner.lock().await;
+        let stats = inner.entry(addr).or_insert_with(|| ParserStats::new(addr));
+        stats.frames_ok += 1;
+        stats.last_seen_ms = unix_time_ms();
+    }
+
+    /// 파싱 실패 기록
+    ///
+    /// # Arguments
+    /// * `addr` - 송신 주소
+    /// * `error` - 파싱 실패 원인
+    pub async fn record_error(&self, addr: SocketAddr, error: &ParseError) {
+        let mut inner = self.inner.lock().await;
+        let stats = inner.entry(addr).or_insert_with(|| ParserStats::new(addr));
+        match error {
+            ParseError::Truncated => stats.truncated_frames += 1,
+            ParseError::Checksum => stats.checksum_failures += 1,
+            ParseError::UnknownParam(_) => stats.unknown_params += 1,
+            ParseError::InvalidHeader | ParseError::Other(_) => stats.other_errors += 1,
+        }
+        stats.last_error = Some(error.to_string());
+        stats.last_error_ms = Some(unix_time_ms());
+        stats.last_seen_ms = unix_time_ms();
+    }
+
+    /// 격리 시작 기록
+    ///
+    /// # Arguments
+    /// * `addr` - 송신 주소
+    /// * `until_ms` - 다시 파싱을 시도할 시각 (Unix time, ms)
+    pub async fn record_quarantine(&self, addr: SocketAddr, until_ms: u64) {
+        let mut inner = self.inner.lock().await;
+        let stats = inner.entry(addr).or_insert_with(|| ParserStats::new(addr));
+        stats.quarantines += 1;
+        stats.quarantined_until_ms = Some(until_ms);
+    }
+
+    /// 격리 해제 기록
+    pub async fn record_recovered(&self, addr: SocketAddr) {
+        if let Some(stats) = self.inner.lock().await.get_mut(&addr) {
+            stats.quarantined_until_ms = None;
+        }
+    }
+
+    /// 격리 중이라 파싱하지 않고 버린 데이터그램 기록
+    pub async fn record_quarantined_drop(&self, addr: SocketAddr) {
+        let mut inner = self.inner.lock().await;
+        let stats = inner.entry(addr).or_insert_with(|| ParserStats::new(addr));
+        stats.quarantined_frames += 1;
+        stats.last_seen_ms = unix_time_ms();
+    }
+
+    /// 오래된 송신 주소의 파싱 통계 삭제
+    ///
+    /// # Arguments
+    /// * `ttl_ms` - 이 시간 동안 데이터를 받지 않은 송신 주소 삭제 (ms)
+    ///
+    /// # Returns
+    /// * `usize` - 삭제한 송신 주소 수
+    pub async fn evict_stale(&self, ttl_ms: u64) -> usize {
+        let now = unix_time_ms();
+        let mut inner = self.inner.lock().await;
+        let before = inner.len();
+        inner.retain(|_, stats| now.saturating_sub(stats.last_seen_ms) <= ttl_ms);
+        let evicted = before - inner.len();
+        self.memory
+            .evicted_senders
+            .fetch_add(evicted as u64, Ordering::Relaxed);
+        evicted
+    }
+
+    /// 최대 크기를 넘어 송신 주소의 파서 버퍼를 비운 것을 기록
+    pub async fn record_buffer_reset(&self, addr: SocketAddr) {
+        self.memory.buffer_resets.fetch_add(1, Ordering::Relaxed);
+        let mut inner = self.inner.lock().await;
+        let stats = inner.entry(addr).or_insert_with(|| ParserStats::new(addr));
+        stats.buffer_resets += 1;
+        stats.buffered_bytes = 0;
+    }
+
+    /// 파싱 통계 밖의 송신 주소별 상태 메모리 사용량 기록
+    ///
+    /// # Arguments
+    /// * `dedup_bytes` - 중복 제거 기록 메모리 (byte)
+    /// * `parser_buffers` - 송신 주소별 파서 버퍼 크기 (byte)
+    ///
+    /// # 동작 설명
+    /// * 파서 버퍼 크기의 합은 `parser_buffer_bytes`, 송신 주소별 크기는 파싱 통계의 `buffered_bytes` 로 기록
+    /// * 파서 버퍼가 없는 송신 주소(삭제되었거나 플러그인이 처리한 송신 주소)는 0
+    pub async fn record_memory(
+        &self,
+        dedup_bytes: usize,
+        parser_buffers: &HashMap<SocketAddr, usize>,
+    ) {
+        self.memory
+            .dedup_bytes
+            .store(dedup_bytes, Ordering::Relaxed);
+        self.memory
+            .parser_buffer_bytes
+            .store(parser_buffers.values().sum(), Ordering::Relaxed);
+        for (addr, stats) in self.inner.lock().await.iter_mut() {
+            stats.buffered_bytes = parser_buffers.get(addr).copied().unwrap_or(0);
+        }
+    }
+
+    /// 송신 주소별 상태 메모리 통계
+    pub async fn memory(&self) -> SenderMemoryStats {
+        let (senders, parse_stats_bytes) = {
+            let inner = self.inner.lock().await;
+            let bytes = inner
+                .values()
+                .map(|stats| {
+                    std::mem::size_of::<(SocketAddr, ParserStats)>()
+                        + stats.last_error.as_ref().map_or(0, String::capacity)
+                })
+                .sum();
+            (inner.len(), bytes)
+        };
+        let dedup_bytes = self.memory.dedup_bytes.load(Ordering::Relaxed);
+        let parser_buffer_bytes = self.memory.parser_buffer_bytes.load(Ordering::Relaxed);
+        let point_pool = PointPool::global().stats();
+        SenderMemoryStats {
+            senders,
+            parse_stats_bytes,
+            dedup_bytes,
+            parser_buffer_bytes,
+            total_bytes: parse_stats_bytes
+                + dedup_bytes
+                + parser_buffer_bytes
+                + point_pool.pooled_bytes,
+            evicted_senders: self.memory.evicted_senders.load(Ordering::Relaxed),
+            buffer_resets: self.memory.buffer_resets.load(Ordering::Relaxed),
+            point_pool,
+        }
+    }
+
+    /// 송신 주소 필터 거부 기록
+    pub fn record_rejected(&self, rejection: Rejection) {
+        let counter = match rejection {
+            Rejection::Denied => &self.rejected.denied,
+            Rejection::NotAllowed => &self.rejected.not_allowed,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        self.rejected
+            .last_rejected_ms
+            .store(unix_time_ms(), Ordering::Relaxed);
+    }
+
+    /// 송신 주소 필터 거부 통계
+    pub fn rejected(&self) -> RejectionStats {
+        let last_rejected_ms = self.rejected.last_rejected_ms.load(Ordering::Relaxed);
+        RejectionStats {
+            denied: self.rejected.denied.load(Ordering::Relaxed),
+            not_allowed: self.rejected.not_allowed.load(Ordering::Relaxed),
+            last_rejected_ms: (last_rejected_ms > 0).then_some(last_rejected_ms),
+        }
+    }
+
+    /// 송신 주소 순서로 정렬된 파싱 통계 목록
+    pub async fn list(&self) -> Vec<ParserStats> {
+        let inner = self.inner.lock().await;
+        let mut list: Vec<ParserStats> = inner.values().cloned().collect();
+        list.sort_by_key(|stats| (stats.ip, stats.port));
+        list
+    }
+}
@@ -0,0 +1,138 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use utoipa::ToSchema;
+
+use crate::lidar::types::*;
+
+/// 전역 포인트 버퍼 풀에 보관할 최대 버퍼 수
+const MAX_POOLED_BUFFERS: usize = 512;
+
+/// 포인트 버퍼 풀 통계 구조체
+///
+/// # Fields
+/// * `allocated` - 풀에 맞는 버퍼가 없어 새로 할당한 버퍼 수 (누적)
+/// * `reused` - 풀에서 꺼내 다시 사용한 버퍼 수 (누적)
+/// * `discarded` - 풀이 가득 차서 반환하지 못하고 해제한 버퍼 수 (누적)
+/// * `pooled` - 현재 풀에 보관 중인 버퍼 수
+/// * `pooled_bytes` - 현재 풀에 보관 중인 버퍼 메모리 (byte)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, JsonSchema, ToSchema)]
+pub struct PointPoolStats {
+    pub allocated: u64,
+    pub reused: u64,
+    pub discarded: u64,
+    pub pooled: usize,
+    pub pooled_bytes: usize,
+}
+
+/// 포인트 버퍼 풀
+///
+/// # Examples
+/// ```
+/// let mut points = PointPool::global().take(table.columns());
+/// points.push(point);
+/// // 전달이 끝난 프레임의 버퍼 반환
+/// PointPool::global().recycle(data.take_points());
+/// ```
+///
+/// # 동작 설명
+/// * 버퍼를 용량(제품 라인별 채널당 포인트 수)별로 나눠 보관하고, 같은 용량을 요청하면 비워서 다시 사용
+/// * 보관 중인 버퍼가 `max_buffers` 개이면 반환한 버퍼는 해제
+/// * 수신 태스크 여러 개가 함께 사용하므로 짧은 동기 잠금으로 보호
+pub struct PointPool {
+    max_buffers: usize,
+    buffers: Mutex<HashMap<usize, Vec<Vec<Point>>>>,
+    allocated: AtomicU64,
+    reused: AtomicU64,
+    discarded: AtomicU64,
+}
+
+impl PointPool {
+    pub fn new(max_buffers: usize) -> Self {
+        Self {
+            max_buffers,
+            buffers: Mutex::new(HashMap::new()),
+            allocated: AtomicU64::new(0),
+            reused: AtomicU64::new(0),
+            discarded: AtomicU64::new(0),
+        }
+    }
+
+    /// 파서와 UDP 리스너가 함께 사용하는 풀
+    pub fn global() -> &'static PointPool {
+        static POOL: OnceLock<PointPool> = OnceLock::new();
+        POOL.get_or_init(|| PointPool::new(MAX_POOLED_BUFFERS))
+    }
+
+    /// 빈 버퍼 꺼내기
+    ///
+    /// # Arguments
+    /// * `capacity` - 필요한 용량 (포인트 수)
+    ///
+    /// # Returns
+    /// * `Vec<Point>` - 같은 용량의 보관 버퍼, 없으면 새로 할당한 버퍼
+    pub fn take(&self, capacity: usize) -> Vec<Point> {
+        let buffer = self
+            .buffers
+            .lock()
+            .unwrap()
+            .get_mut(&capacity)
+            .and_then(Vec::pop);
+        match buffer {
+            Some(buffer) => {
+                self.reused.fetch_add(1, Ordering::Relaxed);
+                buffer
+            }
+            None => {
+                self.allocated.fetch_add(1, Ordering::Relaxed);
+                Vec::with_capacity(capacity)
+            }
+        }
+    }
+
+    /// 버퍼 반환
+    ///
+    /// # Arguments
+    /// * `buffer` - 다 사용한 버퍼 (내용은 지움)
+    pub fn give(&self, mut buffer: Vec<Point>) {
+        if buffer.capacity() == 0 {
+            return;
+        }
+        buffer.clear();
+        let mut buffers = self.buffers.lock().unwrap();
+        if buffers.values().map(Vec::len).sum::<usize>() >= self.max_buffers {
+            self.discarded.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        buffers.entry(buffer.capacity()).or_default().push(buffer);
+    }
+
+    /// 프레임의 채널별 포인트 버퍼를 모두 반환
+    pub fn recycle(&self, clouds: Vec<PointCloud>) {
+        for cloud in clouds {
+            self.give(cloud.points);
+        }
+    }
+
+    /// 풀 통계
+    pub fn stats(&self) -> PointPoolStats {
+        let (pooled, pooled_bytes) = self.buffers.lock().unwrap().iter().fold(
+            (0, 0),
+            |(count, bytes), (capacity, buffers)| {
+                (
+                    count + buffers.len(),
+                    bytes + buffers.len() * capacity * std::mem::size_of::<Point>(),
+                )
+            },
+        );
+        PointPoolStats {
+            allocated: self.allocated.load(Ordering::Relaxed),
+            reused: self.reused.load(Ordering::Relaxed),
+            discarded: self.discarded.load(Ordering::Relaxed),
+            pooled,
+            pooled_bytes,
+        }
+    }
+}
@@ -0,0 +1,553 @@
+use std::collections::{HashMap, HashSet};
+use std::net::{Ipv4Addr, SocketAddrV4};
+use std::sync::{Arc, RwLock};
+use tokio::sync::{broadcast, Mutex};
+use tracing::*;
+
+use crate::common::time::unix_time_ms;
+use crate::lidar::config::{IdentityConfig, IdentityStrategy};
+use crate::lidar::types::*;
+
+/// 송신 주소 + 제품 라인 + ID 조합
+type SourceIdentity = (SocketAddrV4, u8, u8);
+
+/// 저장소 내부 상태
+///
+/// # Fields
+/// * `lidar_infos` - LiDAR 키별 장치 정보
+/// * `reported_ips` - 송신 주소별로 LiDAR 가 보고한 자신의 IP 주소 (0xD1)
+/// * `pending_ips` - 제품 라인/ID 별로 네트워크 소스 설정 명령(0xD2)으로 지정한 IP 와 대기 만료 시각
+#[derive(Debug, Default)]
+struct RegistryInner {
+    lidar_infos: HashMap<LiDARKey, LiDARInfo>,
+    reported_ips: HashMap<SourceIdentity, Ipv4Addr>,
+    pending_ips: HashMap<(u8, u8), (Ipv4Addr, u64)>,
+}
+
+/// 비활성화한 LiDAR 목록 (수신 경로에서 잠금 없이 빠르게 확인하기 위해 별도로 유지)
+///
+/// # Fields
+/// * `keys` - 비활성화한 LiDAR 키
+/// * `sources` - 비활성화한 LiDAR 만 사용하는 송신 주소 (파싱 전에 버림)
+#[derive(Debug, Default)]
+struct Muted {
+    keys: HashSet<LiDARKey>,
+    sources: HashSet<SocketAddrV4>,
+}
+
+impl Muted {
+    /// 장치 정보의 `disabled` 로부터 다시 계산
+    ///
+    /// 같은 송신 주소를 사용하는 활성 LiDAR 가 있으면 해당 주소는 파싱 후 키로만 확인
+    fn from_infos(lidar_infos: &HashMap<LiDARKey, LiDARInfo>) -> Self {
+        let mut muted = Self::default();
+        let mut active = HashSet::new();
+        for info in lidar_infos.values() {
+            let source = SocketAddrV4::new(info.ip, info.port);
+            if info.disabled {
+                muted.keys.insert(info.key);
+                muted.sources.insert(source);
+            } else {
+                active.insert(source);
+            }
+        }
+        muted.sources.retain(|source| !active.contains(source));
+        muted
+    }
+}
+
+/// 검색된 LiDAR 장치 저장소
+///
+/// # Examples
+/// ```
+/// let registry = LiDARRegistry::new(IdentityConfig::default());
+/// let key = registry
+///     .update(CompanyInfo::KanaviMobility, src_addr, product_line, lidar_id, None, None)
+///     .await;
+/// let lidar_infos = registry.list().await;
+/// ```
+///
+/// # Fields
+/// * `config` - LiDAR 식별 설정
+/// * `inner` - 장치 정보 및 보고된 IP 저장소
+/// * `changes` - LiDAR 키 변경 알림 채널
+/// * `discoveries` - 새 LiDAR 등록 알림 채널
+/// * `muted` - 비활성화한 LiDAR 키와 송신 주소
+///
+/// # 주요 기능
+/// * UDP 리스너에서 수신/검색한 LiDAR 장치 등록 및 갱신
+/// * 식별 전략에 따라 송신 주소를 LiDAR 키로 변환
+/// * 보고된 IP 를 알게 되면 송신 주소 기준으로 등록된 장치를 새 키로 이동
+/// * IP 가 바뀐 장치를 MAC 주소, 네트워크 소스 설정 명령, 제품 라인/ID 로 확인하여 기존 장치 정보를 새 키로 이동
+/// * 동일 키에 서로 다른 송신 주소가 동시에 감지되면 충돌로 기록
+/// * 관리자가 비활성화한 LiDAR 의 수신 데이터를 버리도록 UDP 리스너에 제공
+/// * WebSocket 서버의 `lidar_list` 요청에 장치 목록 제공
+/// * 복제 시 동일한 저장소를 공유
+#[derive(Debug, Clone)]
+pub struct LiDARRegistry {
+    config: IdentityConfig,
+    inner: Arc<Mutex<RegistryInner>>,
+    changes: broadcast::Sender<IdentityChange>,
+    discoveries: broadcast::Sender<LiDARKey>,
+    muted: Arc<RwLock<Muted>>,
+}
+
+impl Default for LiDARRegistry {
+    fn default() -> Self {
+        Self::new(IdentityConfig::default())
+    }
+}
+
+impl LiDARRegistry {
+    pub fn new(config: IdentityConfig) -> Self {
+        Self {
+            config,
+            inner: Arc::new(Mutex::new(RegistryInner::default())),
+            changes: broadcast::channel(64).0,
+            discoveries: broadcast::channel(64).0,
+            muted: Arc::new(RwLock::new(Muted::default())),
+        }
+    }
+
+    /// LiDAR 키 변경 알림 구독
+    ///
+    /// # Returns
+    /// * `broadcast::Receiver<IdentityChange>` - 장치 정보가 새 키로 이동할 때마다 변경 정보를 받는 수신자
+    pub fn subscribe_changes(&self) -> broadcast::Receiver<IdentityChange> {
+        self.changes.subscribe()
+    }
+
+    /// 새 LiDAR 등록 알림 구독
+    ///
+    /// # Returns
+    /// * `broadcast::Receiver<LiDARKey>` - UDP 로 처음 수신한 LiDAR 가 등록될 때마다 키를 받는 수신자
+    ///   (복제한 장치 정보와 IP 가 바뀐 기존 장치는 알리지 않음)
+    pub fn subscribe_discoveries(&self) -> broadcast::Receiver<LiDARKey> {
+        self.discoveries.subscribe()
+    }
+
+    /// 네트워크 소스 설정 명령(0xD2) 송신 기록
+    ///
+    /// # Arguments
+    /// * `product_line` - 명령 대상 제품 라인
+    /// * `lidar_id` - 명령 대상 LiDAR ID
+    /// * `ip` - 설정할 IP 주소
+    ///
+    /// # 동작 설명
+    /// * `ip_change.command_timeout_ms` 안에 같은 제품 라인/ID 의 장치가 해당 IP 로 수신되면 기존 장치의 IP 변경으로 판단
+    pub async fn expect_ip_change(&self, product_line: u8, lidar_id: u8, ip: Ipv4Addr) {
+        if !self.config.ip_change.enabled {
+            return;
+        }
+        let deadline = unix_time_ms() + self.config.ip_change.command_timeout_ms;
+        info!(
+            "Expecting LiDAR IP change to {} (product line: {}, id: {})",
+            ip, product_line, lidar_id
+        );
+        self.inner
+            .lock()
+            .await
+            .pending_ips
+            .insert((product_line, lidar_id), (ip, deadline));
+    }
+
+    /// LiDAR 장치 정보 등록 또는 갱신
+    ///
+    /// # Arguments
+    /// * `company` - LiDAR 제조사
+    /// * `src_addr` - 송신 주소
+    /// * `product_line` - 제품 라인
+    /// * `lidar_id` - LiDAR ID
+    /// * `reported_ip` - 이번 패킷에서 LiDAR 가 보고한 자신의 IP 주소 (0xD1 응답인 경우)
+    /// * `mac` - 이번 패킷에서 LiDAR 가 보고한 MAC 주소 (0xD1 응답인 경우)
+    ///
+    /// # Returns
+    /// * `LiDARKey` - 식별 전략에 따라 결정된 LiDAR 키
+    ///
+    /// # 동작 설명
+    /// * 처음 수신한 키이거나 MAC 주소를 새로 알게 되면 IP 가 바뀐 기존 장치인지 확인
+    /// * 기존 장치로 판단되면 장치 정보(충돌/중복 수 등)를 새 키로 이동하고 키 변경 알림 전송
+    pub async fn update(
+        &self,
+        company: CompanyInfo,
+        src_addr: SocketAddrV4,
+        product_line: u8,
+        lidar_id: u8,
+        reported_ip: Option<Ipv4Addr>,
+        mac: Option<[u8; 6]>,
+    ) -> LiDARKey {
+        let now = unix_time_ms();
+        let mut inner = self.inner.lock().await;
+
+        let source = (src_addr, product_line, lidar_id);
+        if let Some(ip) = reported_ip {
+            inner.reported_ips.insert(source, ip);
+        }
+        let reported_ip = inner.reported_ips.get(&source).copied();
+
+        let source_key = LiDARKey::new(*src_addr.ip(), src_addr.port(), product_line, lidar_id);
+        let key = match self.config.strategy {
+            IdentityStrategy::SourceAddress => source_key,
+            IdentityStrategy::SourceIp => LiDARKey::new(*src_addr.ip(), 0, product_line, lidar_id),
+            IdentityStrategy::ReportedAddress => match reported_ip {
+                Some(ip) => LiDARKey::new(ip, 0, product_line, lidar_id),
+                None => source_key,
+            },
+        };
+
+        // 보고된 IP 를 알게 되면 송신 주소 기준으로 등록된 장치를 새 키로 이동
+        if key != source_key {
+            if let Some(mut info) = inner.lidar_infos.remove(&source_key) {
+                info!(
+                    "LiDAR identity migrated: {}:{} -> {} (product line: {}, id: {})",
+                    source_key.ip, source_key.port, key.ip, product_line, lidar_id
+                );
+                info.key = key;
+                inner.lidar_infos.entry(key).or_insert(info);
+                self.notify(source_key, key, src_addr, IdentityChangeReason::ReportedIp);
+            }
+        }
+
+        let mac = mac.map(format_mac);
+        // 처음 수신한 키이거나 MAC 주소를 새로 알게 된 경우에만 기존 장치인지 확인
+        let new_mac = mac.is_some()
+            && inner
+                .lidar_infos
+                .get(&key)
+                .is_some_and(|info| info.mac != mac);
+        if self.config.ip_change.enabled && (new_mac || !inner.lidar_infos.contains_key(&key)) {
+            if let Some((previous, reason)) =
+                self.find_previous(&mut inner, key, src_addr, reported_ip, mac.as_deref(), now)
+            {
+                Self::migrate(&mut inner, previous, key, src_addr);
+                info!(
+                    "LiDAR IP changed ({:?}): {}:{} -> {} (product line: {}, id: {})",
+                    reason, previous.ip, previous.port, src_addr, product_line, lidar_id
+                );
+                self.notify(previous, key, src_addr, reason);
+            }
+        }
+
+        match inner.lidar_infos.get_mut(&key) {
+            Some(info) => {
+                if info.ip != *src_addr.ip() || info.port != src_addr.port() {
+                    if now.saturating_sub(info.last_seen) < self.config.conflict_window_ms {
+                        info.conflicts += 1;
+                        if info.conflicts == 1 || info.conflicts % 1000 == 0 {
+                            warn!(
+                                "LiDAR identity conflict ({} times): {}:{} and {} share key {:?}",
+                                info.conflicts, info.ip, info.port, src_addr, key
+                            );
+                        }
+                    } else {
+                        info!(
+                            "LiDAR source address changed: {}:{} -> {}",
+                            info.ip, info.port, src_addr
+                        );
+                    }
+                    info.ip = *src_addr.ip();
+                    info.port = src_addr.port();
+                }
+                info.company = company;
+                info.reported_ip = reported_ip.or(info.reported_ip);
+                info.mac = mac.or(info.mac.take());
+                info.last_seen = now;
+            }
+            None => {
+                info!(
+                    "New LiDAR found: {} (product line: {}, id: {})",
+                    src_addr, product_line, lidar_id
+                );
+                inner.lidar_infos.insert(
+                    key,
+                    LiDARInfo {
+                        key,
+                        company,
+                        ip: *src_addr.ip(),
+                        port: src_addr.port(),
+                        product_line,
+                        lidar_id,
+                        reported_ip,
+                        mac,
+                        conflicts: 0,
+                        duplicates: 0,
+                        first_seen: now,
+                        last_seen: now,
+                        disabled: false,
+                        firmware_version: None,
+                    },
+                );
+                let _ = self.discoveries.send(key);
+            }
+        }
+
+        // 비활성화한 장치의 주소나 키가 바뀌었을 수 있으므로 다시 계산
+        if inner
+            .lidar_infos
+            .get(&key)
+            .is_some_and(|info| info.disabled)
+        {
+            self.refresh_muted(&inner);
+        }
+
+        key
+    }
+
+    /// 새 키로 수신된 장치가 IP 가 바뀐 기존 장치인지 확인
+    ///
+    /// # Arguments
+    /// * `inner` - 저장소 내부 상태
+    /// * `key` - 새 LiDAR 키
+    /// * `src_addr` - 송신 주소
+    /// * `reported_ip` - LiDAR 가 보고한 자신의 IP 주소
+    /// * `mac` - LiDAR 가 보고한 MAC 주소
+    /// * `now` - 현재 시각 (Unix time, ms)
+    ///
+    /// # Returns
+    /// * `Option<(LiDARKey, IdentityChangeReason)>` - 기존 장치의 키와 판단 근거, 기존 장치가 아니면 None
+    ///
+    /// # 동작 설명
+    /// * 같은 제품 라인/ID 의 다른 키로 등록된 장치만 후보로 사용하며, MAC 주소가 다른 장치는 제외
+    /// * 후보 중 MAC 주소가 같은 장치가 있으면 해당 장치
+    /// * 네트워크 소스 설정 명령으로 지정한 IP 에서 수신되었고 후보가 하나이면 해당 장치
+    /// * `match_lidar_id` 사용 시 새로 등록되는 키이고 후보가 하나이며 `conflict_window_ms` 이상 수신되지 않았으면 해당 장치
+    fn find_previous(
+        &self,
+        inner: &mut RegistryInner,
+        key: LiDARKey,
+        src_addr: SocketAddrV4,
+        reported_ip: Option<Ipv4Addr>,
+        mac: Option<&str>,
+        now: u64,
+    ) -> Option<(LiDARKey, IdentityChangeReason)> {
+        let candidates: Vec<&LiDARInfo> = inner
+            .lidar_infos
+            .values()
+            .filter(|info| {
+                info.key != key
+                    && info.product_line == key.product_line
+                    && info.lidar_id == key.lidar_id
+                    && (mac.is_none() || info.mac.is_none() || info.mac.as_deref() == mac)
+            })
+            .collect();
+
+        if let Some(info) = candidates
+            .iter()
+            .find(|info| mac.is_some() && info.mac.as_deref() == mac)
+        {
+            return Some((info.key, IdentityChangeReason::Mac));
+        }
+
+        let pending = (key.product_line, key.lidar_id);
+        inner.pending_ips.retain(|_, (_, deadline)| *deadline > now);
+        let commanded = inner
+            .pending_ips
+            .get(&pending)
+            .is_some_and(|(ip, _)| *ip == *src_addr.ip() || Some(*ip) == reported_ip);
+        let [info] = candidates.as_slice() else {
+            return None;
+        };
+        if commanded {
+            let previous = info.key;
+            inner.pending_ips.remove(&pending);
+            return Some((previous, IdentityChangeReason::Command));
+        }
+
+        let registered = inner.lidar_infos.contains_key(&key);
+        let silent = now.saturating_sub(info.last_seen) >= self.config.conflict_window_ms;
+        (self.config.ip_change.match_lidar_id && !registered && silent)
+            .then_some((info.key, IdentityChangeReason::LidarId))
+    }
+
+    /// 기존 장치 정보를 새 키로 이동
+    ///
+    /// # 동작 설명
+    /// * 새 키로 이미 등록된 정보가 있으면 충돌/중복 수를 합치고 최근 수신 정보를 유지
+    /// * 보고된 IP 는 새 송신 주소에서 보고된 값만 사용
+    fn migrate(
+        inner: &mut RegistryInner,
+        previous: LiDARKey,
+        key: LiDARKey,
+        src_addr: SocketAddrV4,
+    ) {
+        let Some(mut info) = inner.lidar_infos.remove(&previous) else {
+            return;
+        };
+        info.reported_ip = None;
+        if let Some(current) = inner.lidar_infos.remove(&key) {
+            info.company = current.company;
+            info.reported_ip = current.reported_ip;
+            info.mac = current.mac.or(info.mac);
+            info.conflicts += current.conflicts;
+            info.duplicates += current.duplicates;
+            info.disabled |= current.disabled;
+            info.first_seen = info.first_seen.min(current.first_seen);
+            info.last_seen = current.last_seen;
+            info.firmware_version = current.firmware_version.or(info.firmware_version);
+        }
+        info.key = key;
+        info.ip = *src_addr.ip();
+        info.port = src_addr.port();
+        inner.lidar_infos.insert(key, info);
+    }
+
+    /// LiDAR 키 변경 알림 전송 (수신자가 없으면 무시)
+    fn notify(
+        &self,
+        previous: LiDARKey,
+        key: LiDARKey,
+        src_addr: SocketAddrV4,
+        reason: IdentityChangeReason,
+    ) {
+        let _ = self.changes.send(IdentityChange {
+            previous,
+            key,
+            ip: *src_addr.ip(),
+            port: src_addr.port(),
+            reason,
+            time_ms: unix_time_ms(),
+        });
+    }
+
+    /// 중복으로 버린 데이터그램 기록
+    ///
+    /// # Returns
+    /// * `u64` - 해당 LiDAR 의 누적 중복 데이터그램 수, 등록되지 않은 키이면 0
+    pub async fn record_duplicate(&self, key: &LiDARKey) -> u64 {
+        let mut inner = self.inner.lock().await;
+        match inner.lidar_infos.get_mut(key) {
+            Some(info) => {
+                info.duplicates += 1;
+                info.duplicates
+            }
+            None => 0,
+        }
+    }
+
+    /// LiDAR 가 보고한 펌웨어 버전 기록
+    ///
+    /// # Arguments
+    /// * `key` - 대상 LiDAR 키
+    /// * `version` - 버전 정보 응답(0xC0)의 펌웨어 버전 (`major.minor.patch`)
+    ///
+    /// 등록되지 않은 키이면 무시
+    pub async fn set_firmware_version(&self, key: &LiDARKey, version: String) {
+        let mut inner = self.inner.lock().await;
+        if let Some(info) = inner.lidar_infos.get_mut(key) {
+            if info.firmware_version.as_deref() != Some(version.as_str()) {
+                info!(
+                    "LiDAR {}:{} (product line: {}, id: {}) firmware version: {}",
+                    key.ip, key.port, key.product_line, key.lidar_id, version
+                );
+                info.firmware_version = Some(version);
+            }
+        }
+    }
+
+    /// 다른 서버(이중화 주 서버)에서 복제한 장치 정보 반영
+    ///
+    /// # Arguments
+    /// * `lidar_infos` - 복제한 장치 정보 목록, 등록되지 않았거나 더 최근에 수신한 장치만 반영
+    ///
+    /// 비활성화 여부는 서버마다 따로 관리하므로 복제한 값 대신 이 서버의 값을 유지
+    pub async fn import(&self, lidar_infos: Vec<LiDARInfo>) {
+        let mut inner = self.inner.lock().await;
+        let mut muted_changed = false;
+        for mut info in lidar_infos {
+            match inner.lidar_infos.get(&info.key) {
+                Some(existing) if existing.last_seen >= info.last_seen => {}
+                existing => {
+                    info.disabled = existing.is_some_and(|existing| existing.disabled);
+                    muted_changed |= info.disabled;
+                    inner.lidar_infos.insert(info.key, info);
+                }
+            }
+        }
+        if muted_changed {
+            self.refresh_muted(&inner);
+        }
+    }
+
+    /// LiDAR 활성화/비활성화
+    ///
+    /// # Arguments
+    /// * `key` - 대상 LiDAR 키
+    /// * `enabled` - false 면 비활성화, true 면 다시 활성화
+    ///
+    /// # Returns
+    /// * `Result<LiDARInfo, String>` - 성공 시 변경한 장치 정보, 등록되지 않은 키이면 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 비활성화한 LiDAR 의 데이터그램은 파싱/전달하지 않고 버리며, 장치 목록에는 `disabled: true` 로 남음
+    pub async fn set_enabled(&self, key: &LiDARKey, enabled: bool) -> Result<LiDARInfo, String> {
+        let mut inner = self.inner.lock().await;
+        let info = inner
+            .lidar_infos
+            .get_mut(key)
+            .ok_or_else(|| format!("LiDAR not found: {}:{}", key.ip, key.port))?;
+        if info.disabled != enabled {
+            return Ok(info.clone());
+        }
+        info.disabled = !enabled;
+        let info = info.clone();
+        info!(
+            "LiDAR {}:{} (product line: {}, id: {}) {}",
+            key.ip,
+            key.port,
+            key.product_line,
+            key.lidar_id,
+            if enabled { "enabled" } else { "disabled" }
+        );
+        self.refresh_muted(&inner);
+        Ok(info)
+    }
+
+    /// 비활성화한 LiDAR 만 사용하는 송신 주소인지 확인 (파싱 전에 사용)
+    pub fn is_muted_source(&self, addr: &SocketAddrV4) -> bool {
+        self.muted
+            .read()
+            .is_ok_and(|muted| muted.sources.contains(addr))
+    }
+
+    /// 비활성화한 LiDAR 인지 확인
+    pub fn is_disabled(&self, key: &LiDARKey) -> bool {
+        self.muted
+            .read()
+            .is_ok_and(|muted| muted.keys.contains(key))
+    }
+
+    /// 비활성화한 LiDAR 목록 다시 계산
+    fn refresh_muted(&self, inner: &RegistryInner) {
+        let muted = Muted::from_infos(&inner.lidar_infos);
+        if let Ok(mut current) = self.muted.write() {
+            *current = muted;
+        }
+    }
+
+    /// 등록된 LiDAR 장치 정보 조회
+    ///
+    /// # Returns
+    /// * `Option<LiDARInfo>` - 장치 정보, 등록되지 않은 키이면 None
+    pub async fn get(&self, key: &LiDARKey) -> Option<LiDARInfo> {
+        self.inner.lock().await.lidar_infos.get(key).cloned()
+    }
+
+    /// 등록된 LiDAR 장치 목록 반환
+    ///
+    /// # Returns
+    /// * `Vec<LiDARInfo>` - IP, 포트 순으로 정렬된 장치 목록
+    pub async fn list(&self) -> Vec<LiDARInfo> {
+        let inner = self.inner.lock().await;
+        let mut result: Vec<LiDARInfo> = inner.lidar_infos.values().cloned().collect();
+        result.sort_by_key(|info| (info.ip, info.port, info.product_line, info.lidar_id));
+        result
+    }
+}
+
+/// MAC 주소를 `aa:bb:cc:dd:ee:ff` 형식 문자열로 변환
+fn format_mac(mac: [u8; 6]) -> String {
+    mac.iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(":")
+}
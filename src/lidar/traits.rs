@@ -1,6 +1,74 @@
+use crate::lidar::parse_stats::ParseError;
 use crate::lidar::types::*;
 use std::{any::Any, net::Ipv4Addr};
 
+/// 파싱 실패 원인 (파싱 통계의 실패 분류와 같은 타입)
+pub type LiDARError = ParseError;
+
+/// 파서에 데이터를 넣은 결과
+///
+/// # Variants
+/// * `NeedMoreData` - 프레임 앞부분만 받아 다음 데이터그램과 이어 붙이기 위해 보관 중 (실패 아님)
+/// * `Frame` - 프레임 하나를 파싱함
+/// * `Skipped` - 완전한 프레임이지만 전달할 내용이 없어 건너뜀 (실패 아님)
+/// * `Error` - 프레임이 잘못되어 버림
+///
+/// # Examples
+/// ```
+/// match parser.parse(ip, &datagram) {
+///     ParseOutcome::Frame(data) => dispatch(data),
+///     ParseOutcome::NeedMoreData => {}
+///     ParseOutcome::Skipped { reason } => debug!("skipped: {}", reason),
+///     ParseOutcome::Error(e) => stats.record_error(src_addr, &e).await,
+/// }
+/// ```
+#[derive(Debug)]
+pub enum ParseOutcome<T = Box<dyn LiDARData>> {
+    NeedMoreData,
+    Frame(T),
+    Skipped { reason: String },
+    Error(LiDARError),
+}
+
+impl<T> ParseOutcome<T> {
+    /// 프레임을 파싱했는지 확인
+    pub fn is_frame(&self) -> bool {
+        matches!(self, ParseOutcome::Frame(_))
+    }
+
+    /// 파싱한 프레임 (프레임이 아니면 None)
+    pub fn frame(self) -> Option<T> {
+        match self {
+            ParseOutcome::Frame(frame) => Some(frame),
+            _ => None,
+        }
+    }
+
+    /// 파싱 실패 원인 (실패가 아니면 None)
+    pub fn error(&self) -> Option<&LiDARError> {
+        match self {
+            ParseOutcome::Error(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// 프레임 값 변환 (다른 결과는 그대로 유지)
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ParseOutcome<U> {
+        match self {
+            ParseOutcome::NeedMoreData => ParseOutcome::NeedMoreData,
+            ParseOutcome::Frame(frame) => ParseOutcome::Frame(f(frame)),
+            ParseOutcome::Skipped { reason } => ParseOutcome::Skipped { reason },
+            ParseOutcome::Error(e) => ParseOutcome::Error(e),
+        }
+    }
+}
+
+impl<T> From<LiDARError> for ParseOutcome<T> {
+    fn from(error: LiDARError) -> Self {
+        ParseOutcome::Error(error)
+    }
+}
+
 /// LiDAR 데이터 파서 트레이트
 ///
 /// # 주요 기능
@@ -10,20 +78,37 @@ use std::{any::Any, net::Ipv4Addr};
 /// # 구현 예시
 /// ```rust
 /// impl LiDARParser for KanaviMobilityParser {
-///     fn parse(&mut self, data: &[u8]) -> Result<Box<dyn LiDARData>, ()> {
+///     fn parse(&mut self, ip: Ipv4Addr, data: &[u8]) -> ParseOutcome {
 ///         // 파싱 로직 구현
 ///     }
 /// }
 /// ```
 pub trait LiDARParser: Send {
-    /// 바이트 데이터를 파싱하여 LiDAR 데이터로 변환
+    /// 바이트 데이터를 파서 버퍼에 추가하고 프레임 하나를 파싱
     ///
     /// # Arguments
-    /// * `data` - 파싱할 바이트 데이터
+    /// * `ip` - 송신 IP 주소
+    /// * `data` - 파싱할 바이트 데이터 (버퍼에 남은 프레임만 파싱할 때는 빈 슬라이스)
+    ///
+    /// # Returns
+    /// * `ParseOutcome` - 파싱한 프레임, 데이터 부족, 건너뜀 또는 실패 원인
+    fn parse(&mut self, ip: Ipv4Addr, data: &[u8]) -> ParseOutcome;
+
+    /// 다음 데이터그램과 이어 붙이기 위해 보관 중인 데이터 크기 (byte)
+    fn buffered_len(&self) -> usize {
+        0
+    }
+
+    /// 보관 중인 데이터를 버리고 메모리 반환 (다음 데이터그램부터 새로 파싱)
+    fn reset(&mut self) {}
+
+    /// 보관 중인 데이터에 아직 파싱하지 않은 완전한 프레임이 있는지 확인
     ///
     /// # Returns
-    /// * `Result<Box<dyn LiDARData>, ()>` - 성공 시 파싱된 데이터, 실패 시 에러
-    fn parse(&mut self, ip: Ipv4Addr, data: &[u8]) -> Result<Box<dyn LiDARData>, ()>;
+    /// * `bool` - 한 데이터그램에 프레임이 여러 개 있어 남은 프레임이 있으면 true (빈 데이터로 `parse` 를 다시 호출)
+    fn has_frame(&self) -> bool {
+        false
+    }
 }
 
 /// LiDAR 데이터 트레이트
@@ -76,9 +161,18 @@ pub trait LiDARData: Send {
     /// * `u64` - LiDAR 키
     fn get_key(&self) -> u64;
 
+    /// 포인트 클라우드 데이터를 꺼내고 빈 목록으로 교체 (버퍼 재사용에 사용)
+    ///
+    /// # Returns
+    /// * `Vec<PointCloud>` - 채널별 포인트 클라우드
+    fn take_points(&mut self) -> Vec<PointCloud> {
+        Vec::new()
+    }
+
     fn as_any(&self) -> &dyn Any;
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 pub struct EmptyLiDARData;
 
@@ -94,11 +188,11 @@ impl LiDARData for EmptyLiDARData {
     fn get_company_info(&self) -> CompanyInfo {
         CompanyInfo::Unknown
     }
-    
+
     fn get_data(&self) -> Option<&dyn Any> {
         None
     }
-    
+
     fn get_key(&self) -> u64 {
         0
     }
@@ -106,4 +200,4 @@ impl LiDARData for EmptyLiDARData {
     fn as_any(&self) -> &dyn Any {
         self
     }
-}
\ No newline at end of file
+}
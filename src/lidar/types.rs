@@ -1,13 +1,18 @@
 use bincode::{Decode, Encode};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::net::Ipv4Addr;
+use utoipa::ToSchema;
 
 /// LiDAR 제조사 정보를 나타내는 열거형
 ///
 /// # Variants
 /// * `KanaviMobility` - Kanavi Mobility사의 LiDAR
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// * `Plugin` - 외부 파서 플러그인으로 처리하는 LiDAR
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, ToSchema)]
 pub enum CompanyInfo {
     KanaviMobility = 0,
+    Plugin = 1,
     Unknown,
 }
 
@@ -17,6 +22,7 @@ impl TryFrom<u8> for CompanyInfo {
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(CompanyInfo::KanaviMobility),
+            1 => Ok(CompanyInfo::Plugin),
             _ => Ok(CompanyInfo::Unknown),
         }
     }
@@ -28,7 +34,7 @@ impl TryFrom<u8> for CompanyInfo {
 /// * `x` - X 좌표
 /// * `y` - Y 좌표
 /// * `z` - Z 좌표
-#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct Point {
     pub x: f32,
     pub y: f32,
@@ -45,7 +51,7 @@ pub struct Point {
 /// let mut cloud = PointCloud::new();
 /// cloud.add_point(Point { x: 1.0, y: 2.0, z: 3.0 });
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, Encode, Decode)]
 pub struct PointCloud {
     pub points: Vec<Point>,
 }
@@ -59,3 +65,137 @@ impl PointCloud {
         self.points.push(point);
     }
 }
+
+/// LiDAR 장치를 구분하는 키
+///
+/// # Fields
+/// * `ip` - 식별에 사용하는 IP 주소 (식별 전략에 따라 송신 IP 또는 LiDAR 가 보고한 IP)
+/// * `port` - 식별에 사용하는 송신 포트, 포트를 사용하지 않는 식별 전략에서는 0
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    ToSchema,
+    Encode,
+    Decode,
+)]
+#[serde(deny_unknown_fields)]
+pub struct LiDARKey {
+    #[schema(value_type = String, example = "192.168.123.200")]
+    pub ip: Ipv4Addr,
+    pub port: u16,
+    pub product_line: u8,
+    pub lidar_id: u8,
+}
+
+impl LiDARKey {
+    pub fn new(ip: Ipv4Addr, port: u16, product_line: u8, lidar_id: u8) -> Self {
+        Self {
+            ip,
+            port,
+            product_line,
+            lidar_id,
+        }
+    }
+}
+
+/// 검색된 LiDAR 장치 정보를 나타내는 구조체
+///
+/// # Fields
+/// * `key` - LiDAR 키
+/// * `company` - LiDAR 제조사
+/// * `ip` - LiDAR 의 현재 송신 IP 주소 (NAT 뒤에 있는 경우 게이트웨이 주소)
+/// * `port` - LiDAR 의 현재 송신 포트
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+/// * `reported_ip` - LiDAR 가 네트워크 소스 정보(0xD1)로 보고한 자신의 IP 주소
+/// * `mac` - LiDAR 가 네트워크 소스 정보(0xD1)로 보고한 MAC 주소 (`aa:bb:cc:dd:ee:ff` 형식)
+/// * `conflicts` - 동일 키에 서로 다른 송신 주소가 동시에 감지된 횟수
+/// * `duplicates` - 중복 프레임 제거 사용 시 중복으로 버린 데이터그램 수
+/// * `first_seen` - 처음 수신 시각 (Unix time, ms)
+/// * `last_seen` - 마지막 수신 시각 (Unix time, ms)
+/// * `disabled` - 관리자가 비활성화하여 수신 데이터를 파싱/전달하지 않는 중인지 여부
+/// * `firmware_version` - LiDAR 가 버전 정보 응답(0xC0)으로 마지막으로 보고한 펌웨어 버전 (`major.minor.patch`), 조회한 적이 없으면 null
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct LiDARInfo {
+    pub key: LiDARKey,
+    pub company: CompanyInfo,
+    #[schema(value_type = String, example = "192.168.123.200")]
+    pub ip: Ipv4Addr,
+    pub port: u16,
+    pub product_line: u8,
+    pub lidar_id: u8,
+    #[schema(value_type = Option<String>)]
+    pub reported_ip: Option<Ipv4Addr>,
+    #[serde(default)]
+    pub mac: Option<String>,
+    pub conflicts: u32,
+    #[serde(default)]
+    pub duplicates: u64,
+    #[serde(default)]
+    pub first_seen: u64,
+    pub last_seen: u64,
+    #[serde(default)]
+    pub disabled: bool,
+    #[serde(default)]
+    pub firmware_version: Option<String>,
+}
+
+/// LiDAR 장치 정보와 현재 동작 상태 (`lidar_list` 응답, `GET /lidars`)
+///
+/// # Fields
+/// * `info` - 장치 정보 (같은 객체에 펼쳐서 직렬화)
+/// * `online` - 마지막 수신 후 `offline_ms` 가 지나지 않았으면 true (속한 사이트가 없으면 5000 ms 기준)
+/// * `frame_rate` - 마지막 텔레메트리 샘플의 포인트 클라우드 수신 주기 (frames/s), 샘플이 없으면 null
+/// * `motor_speed` - 마지막으로 보고된 모터 속도, 보고된 적이 없으면 null
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct LiDARStatus {
+    #[serde(flatten)]
+    pub info: LiDARInfo,
+    pub online: bool,
+    pub frame_rate: Option<f32>,
+    pub motor_speed: Option<u8>,
+}
+
+/// LiDAR 키 변경 사유 열거형
+///
+/// # Variants
+/// * `ReportedIp` - 보고된 IP(0xD1)를 알게 되어 송신 주소 기준 키에서 보고된 IP 기준 키로 이동
+/// * `Mac` - 새 주소에서 보고된 MAC 주소가 기존 장치와 같음
+/// * `Command` - 네트워크 소스 설정 명령으로 지정한 IP 에서 같은 제품 라인/ID 의 장치가 수신됨
+/// * `LidarId` - 같은 제품 라인/ID 의 기존 장치가 수신되지 않는 동안 새 주소에서 수신됨
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityChangeReason {
+    ReportedIp,
+    Mac,
+    Command,
+    LidarId,
+}
+
+/// LiDAR 키 변경 정보를 나타내는 구조체
+///
+/// # Fields
+/// * `previous` - 이전 LiDAR 키
+/// * `key` - 새 LiDAR 키
+/// * `ip` - 새 송신 IP 주소
+/// * `port` - 새 송신 포트
+/// * `reason` - 동일 장치로 판단한 근거
+/// * `time_ms` - 변경 시각 (Unix time, ms)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IdentityChange {
+    pub previous: LiDARKey,
+    pub key: LiDARKey,
+    pub ip: Ipv4Addr,
+    pub port: u16,
+    pub reason: IdentityChangeReason,
+    pub time_ms: u64,
+}
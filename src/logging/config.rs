@@ -0,0 +1,76 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// 로그 출력 형식 열거형
+///
+/// # Variants
+/// * `Text` - 사람이 읽는 한 줄 텍스트 (기본값)
+/// * `Json` - 한 줄에 JSON 객체 하나 (Loki, ELK 등 로그 수집기용, 필드 이름은 `logging::json` 참고)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// 로그 설정 구조체
+///
+/// # Examples
+/// ```json
+/// { "format": "json", "dir": "/var/log/lidar-server", "max_file_bytes": 10485760, "max_files": 10 }
+/// ```
+///
+/// # Fields
+/// * `format` - 로그 출력 형식 (터미널과 파일에 같은 형식으로 출력)
+/// * `dir` - 로그 파일 디렉터리
+/// * `file_name` - 현재 로그 파일 이름, 회전한 파일은 `<이름>.<unix time ms>.<확장자>` 로 보관
+/// * `max_file_bytes` - 로그 파일 하나의 최대 크기 (byte), 넘으면 회전
+/// * `daily` - 날짜(UTC)가 바뀌면 크기와 관계없이 회전할지 여부
+/// * `max_files` - 보관할 회전 파일 최대 개수 (현재 파일 제외)
+/// * `max_total_bytes` - 현재 파일을 포함한 로그 파일 전체 최대 크기 (byte), 넘으면 오래된 보관 파일부터 삭제
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct LogConfig {
+    pub format: LogFormat,
+    pub dir: PathBuf,
+    pub file_name: String,
+    pub max_file_bytes: u64,
+    pub daily: bool,
+    pub max_files: usize,
+    pub max_total_bytes: u64,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::default(),
+            dir: PathBuf::from("logs"),
+            file_name: "lidar-server.log".to_string(),
+            max_file_bytes: 16 * 1024 * 1024,
+            daily: true,
+            max_files: 14,
+            max_total_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+impl LogConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 실패 시 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        if self.file_name.is_empty() || self.file_name.contains(['/', '\\']) {
+            return Err("log.file_name must be a file name without directories".to_string());
+        }
+        if self.max_file_bytes == 0 {
+            return Err("log.max_file_bytes must be greater than 0".to_string());
+        }
+        if self.max_total_bytes < self.max_file_bytes {
+            return Err("log.max_total_bytes must not be less than max_file_bytes".to_string());
+        }
+        Ok(())
+    }
+}
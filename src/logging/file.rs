@@ -0,0 +1,303 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::UNIX_EPOCH;
+use utoipa::ToSchema;
+
+use crate::common::daylog::DAY_MS;
+use crate::common::time::unix_time_ms;
+use crate::logging::config::LogConfig;
+
+/// 로그 파일 회전 결과
+///
+/// # Fields
+/// * `rotated` - 보관 파일로 이름을 바꾼 이전 로그 파일 경로, 현재 파일이 비어 있어 회전하지 않았으면 null
+/// * `removed` - 보관 개수/전체 크기 제한으로 삭제한 보관 파일 경로 (오래된 순)
+/// * `files` - 회전 후 남은 로그 파일 수 (현재 파일 포함)
+/// * `total_bytes` - 회전 후 남은 로그 파일 전체 크기 (byte)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, JsonSchema, ToSchema)]
+pub struct LogRotation {
+    pub rotated: Option<String>,
+    pub removed: Vec<String>,
+    pub files: usize,
+    pub total_bytes: u64,
+}
+
+/// 현재 로그 파일 상태
+struct CurrentFile {
+    file: File,
+    bytes: u64,
+    day: u64,
+}
+
+/// 크기/날짜 기준으로 회전하는 로그 파일
+///
+/// # Examples
+/// ```
+/// let log_file = LogFile::install(&config.log)?;
+/// tracing_subscriber::fmt().with_writer(move || log_file).init();
+/// // 관리 요청으로 바로 회전
+/// let rotation = LogFile::global().unwrap().rotate()?;
+/// ```
+///
+/// # 동작 설명
+/// * `<dir>/<file_name>` 에 기록하고, 기록하면 `max_file_bytes` 를 넘거나 (`daily` 사용 시) 날짜(UTC)가 바뀌면
+///   `<dir>/<stem>.<unix time ms>.<ext>` 보관 파일로 이름을 바꾼 뒤 새 파일에 기록
+/// * 회전할 때와 시작할 때 보관 파일이 `max_files` 개를 넘거나, 현재 파일이 최대 크기까지 커졌을 때의 전체 크기가
+///   `max_total_bytes` 를 넘으면 오래된 보관 파일부터 삭제
+/// * 로그 한 줄은 버퍼 없이 바로 파일에 기록, 회전 요청(`rotate`)은 현재 파일을 디스크에 동기화(fsync)한 뒤 회전
+/// * 파일 기록 중 에러는 로그로 남길 수 없으므로 표준 에러로 출력
+pub struct LogFile {
+    config: LogConfig,
+    current: Mutex<CurrentFile>,
+}
+
+impl LogFile {
+    /// 로그 파일 열기 (시작 시 보관 파일 정리)
+    ///
+    /// # Arguments
+    /// * `config` - 로그 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 로그 파일, 디렉터리나 파일을 만들지 못하면 에러 메시지
+    pub fn open(config: &LogConfig) -> Result<Self, String> {
+        std::fs::create_dir_all(&config.dir)
+            .map_err(|e| format!("Failed to create {}: {}", config.dir.display(), e))?;
+        let path = config.dir.join(&config.file_name);
+        let file = open_append(&path)?;
+        let metadata = file
+            .metadata()
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let modified_ms = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or_else(unix_time_ms, |elapsed| elapsed.as_millis() as u64);
+        let log_file = Self {
+            config: config.clone(),
+            current: Mutex::new(CurrentFile {
+                file,
+                bytes: metadata.len(),
+                day: modified_ms / DAY_MS,
+            }),
+        };
+        let bytes = log_file.lock().bytes;
+        log_file.enforce_retention(bytes);
+        Ok(log_file)
+    }
+
+    /// 로그 파일을 열어 프로세스 전체에서 사용하도록 등록 (`global` 로 조회)
+    ///
+    /// # Returns
+    /// * `Result<&'static LogFile, String>` - 성공 시 등록한 로그 파일, 열지 못했거나 이미 등록했으면 에러 메시지
+    pub fn install(config: &LogConfig) -> Result<&'static LogFile, String> {
+        let log_file = Self::open(config)?;
+        GLOBAL
+            .set(log_file)
+            .map_err(|_| "Log file is already installed".to_string())?;
+        Ok(GLOBAL.get().expect("installed above"))
+    }
+
+    /// 등록된 로그 파일 (`install` 전이면 None)
+    pub fn global() -> Option<&'static LogFile> {
+        GLOBAL.get()
+    }
+
+    /// 현재 로그 파일 경로
+    pub fn path(&self) -> PathBuf {
+        self.config.dir.join(&self.config.file_name)
+    }
+
+    /// 현재 파일을 디스크에 동기화하고 바로 회전
+    ///
+    /// # Returns
+    /// * `Result<LogRotation, String>` - 성공 시 회전 결과, 동기화/이름 변경/새 파일 생성에 실패하면 에러 메시지
+    pub fn rotate(&self) -> Result<LogRotation, String> {
+        let mut current = self.lock();
+        current
+            .file
+            .sync_data()
+            .map_err(|e| format!("Failed to flush {}: {}", self.path().display(), e))?;
+        let rotated = if current.bytes > 0 {
+            Some(self.rotate_current(&mut current)?)
+        } else {
+            None
+        };
+        let bytes = current.bytes;
+        drop(current);
+        let (removed, files, total_bytes) = self.enforce_retention(bytes);
+        Ok(LogRotation {
+            rotated: rotated.map(|path| path.display().to_string()),
+            removed: removed
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect(),
+            files,
+            total_bytes,
+        })
+    }
+
+    /// 보관 파일 목록 (오래된 순, 경로와 크기)
+    pub fn archives(&self) -> Vec<(PathBuf, u64)> {
+        let (stem, ext) = split_name(&self.config.file_name);
+        let Ok(entries) = std::fs::read_dir(&self.config.dir) else {
+            return Vec::new();
+        };
+        let mut archives: Vec<(u64, PathBuf, u64)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                let stamp = archive_stamp(name.to_str()?, stem, ext)?;
+                let bytes = entry.metadata().ok()?.len();
+                Some((stamp, entry.path(), bytes))
+            })
+            .collect();
+        archives.sort();
+        archives
+            .into_iter()
+            .map(|(_, path, bytes)| (path, bytes))
+            .collect()
+    }
+
+    /// 로그 한 줄 기록 (필요하면 먼저 회전)
+    fn append(&self, buf: &[u8]) -> io::Result<usize> {
+        let mut current = self.lock();
+        let now_ms = unix_time_ms();
+        let next_day = self.config.daily && now_ms / DAY_MS != current.day;
+        let too_large = current.bytes + buf.len() as u64 > self.config.max_file_bytes;
+        if current.bytes > 0 && (next_day || too_large) {
+            match self.rotate_current(&mut current) {
+                Ok(_) => {
+                    let bytes = current.bytes;
+                    self.enforce_retention(bytes);
+                }
+                Err(e) => eprintln!("{}", e),
+            }
+        }
+        current.day = now_ms / DAY_MS;
+        current.file.write_all(buf)?;
+        current.bytes += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    /// 현재 파일을 보관 파일로 이름을 바꾸고 새 파일 열기
+    ///
+    /// # Returns
+    /// * `Result<PathBuf, String>` - 성공 시 보관 파일 경로
+    fn rotate_current(&self, current: &mut CurrentFile) -> Result<PathBuf, String> {
+        let path = self.path();
+        let (stem, ext) = split_name(&self.config.file_name);
+        // 같은 ms 에 여러 번 회전해도 이름이 겹치지 않도록 다음 값 사용
+        let mut stamp = unix_time_ms();
+        let mut archive = self.config.dir.join(archive_name(stem, ext, stamp));
+        while archive.exists() {
+            stamp += 1;
+            archive = self.config.dir.join(archive_name(stem, ext, stamp));
+        }
+        std::fs::rename(&path, &archive).map_err(|e| {
+            format!(
+                "Failed to rotate {} to {}: {}",
+                path.display(),
+                archive.display(),
+                e
+            )
+        })?;
+        current.file = open_append(&path)?;
+        current.bytes = 0;
+        current.day = unix_time_ms() / DAY_MS;
+        Ok(archive)
+    }
+
+    /// 보관 개수/전체 크기 제한을 넘는 오래된 보관 파일 삭제
+    ///
+    /// # Arguments
+    /// * `current_bytes` - 현재 파일 크기 (byte)
+    ///
+    /// # Returns
+    /// * `(Vec<PathBuf>, usize, u64)` - 삭제한 보관 파일, 남은 로그 파일 수(현재 파일 포함), 남은 전체 크기
+    fn enforce_retention(&self, current_bytes: u64) -> (Vec<PathBuf>, usize, u64) {
+        let mut archives = self.archives();
+        let mut archived: u64 = archives.iter().map(|(_, bytes)| bytes).sum();
+        // 현재 파일이 최대 크기까지 커져도 전체 크기를 넘지 않도록 보관 파일 크기 제한
+        let max_archived = self
+            .config
+            .max_total_bytes
+            .saturating_sub(self.config.max_file_bytes);
+        let mut removed = Vec::new();
+        while !archives.is_empty()
+            && (archives.len() > self.config.max_files || archived > max_archived)
+        {
+            let (path, bytes) = archives.remove(0);
+            match std::fs::remove_file(&path) {
+                Ok(()) => {
+                    archived -= bytes;
+                    removed.push(path);
+                }
+                Err(e) => {
+                    eprintln!("Failed to remove old log {}: {}", path.display(), e);
+                    break;
+                }
+            }
+        }
+        (removed, archives.len() + 1, archived + current_bytes)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CurrentFile> {
+        // 로그 기록 중 패닉해도 로그 파일은 계속 사용
+        self.current
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Write for &LogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.append(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.lock().file.flush()
+    }
+}
+
+/// 프로세스 전체에서 사용하는 로그 파일 (`LogFile::install`)
+static GLOBAL: OnceLock<LogFile> = OnceLock::new();
+
+fn open_append(path: &Path) -> Result<File, String> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))
+}
+
+/// 파일 이름을 확장자 앞부분과 확장자로 분리 (`lidar-server.log` -> (`lidar-server`, `log`))
+fn split_name(file_name: &str) -> (&str, &str) {
+    file_name.rsplit_once('.').unwrap_or((file_name, ""))
+}
+
+/// 보관 파일 이름 (`<stem>.<unix time ms>.<ext>`)
+fn archive_name(stem: &str, ext: &str, stamp: u64) -> String {
+    if ext.is_empty() {
+        format!("{}.{}", stem, stamp)
+    } else {
+        format!("{}.{}.{}", stem, stamp, ext)
+    }
+}
+
+/// 보관 파일 이름에서 회전 시각 추출 (보관 파일이 아니면 None)
+fn archive_stamp(name: &str, stem: &str, ext: &str) -> Option<u64> {
+    let rest = name.strip_prefix(stem)?.strip_prefix('.')?;
+    let stamp = if ext.is_empty() {
+        rest
+    } else {
+        rest.strip_suffix(ext)?.strip_suffix('.')?
+    };
+    if stamp.is_empty() || !stamp.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    stamp.parse().ok()
+}
@@ -0,0 +1,159 @@
+use serde_json::{Map, Value};
+use std::fmt;
+use tracing::field::{Field, Visit};
+use tracing::span::Record;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, FormattedFields};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::common::time::unix_time_ms;
+
+/// tracing 필드를 JSON 객체로 모으는 visitor
+#[derive(Default)]
+struct JsonVisitor(Map<String, Value>);
+
+impl Visit for JsonVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(
+            field.name().to_string(),
+            Value::from(format!("{:?}", value)),
+        );
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+        self.0
+            .insert(field.name().to_string(), Value::from(value.to_string()));
+    }
+}
+
+/// span 필드를 JSON 객체 문자열로 저장하는 필드 형식
+///
+/// `JsonFormat` 이 span 필드를 다시 읽어 로그 한 줄의 `context` 로 합칩니다.
+pub struct JsonFields;
+
+impl<'writer> FormatFields<'writer> for JsonFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        mut writer: Writer<'writer>,
+        fields: R,
+    ) -> fmt::Result {
+        let mut visitor = JsonVisitor::default();
+        fields.record(&mut visitor);
+        write!(writer, "{}", Value::Object(visitor.0))
+    }
+
+    fn add_fields(
+        &self,
+        current: &'writer mut FormattedFields<Self>,
+        fields: &Record<'_>,
+    ) -> fmt::Result {
+        let mut visitor = JsonVisitor(parse_object(&current.fields));
+        fields.record(&mut visitor);
+        current.fields = Value::Object(visitor.0).to_string();
+        Ok(())
+    }
+}
+
+/// 로그 한 줄을 JSON 객체 하나로 출력하는 이벤트 형식 (`log.format: "json"`)
+///
+/// # Examples
+/// ```json
+/// {"time_ms":1718000000000,"level":"ERROR","target":"lidar_server::udp::listener",
+///  "file":"src/udp/listener.rs","line":1002,"thread":"tokio-runtime-worker",
+///  "message":"Failed to parse LiDAR data from 192.168.123.200:5000: checksum mismatch",
+///  "trace_id":"3f9a0c1e5b7d2a40","spans":["udp_frame"],"context":{"src":"192.168.123.200:5000"},"fields":{}}
+/// ```
+///
+/// # 필드
+/// * `time_ms` - 기록 시각 (Unix time, ms)
+/// * `level` - 로그 레벨 (`TRACE` ~ `ERROR`)
+/// * `target` - 로그를 기록한 모듈 경로 (`access` 등 별도 target 포함)
+/// * `file`, `line` - 소스 위치 (알 수 없으면 null)
+/// * `thread` - 스레드 이름 (이름이 없으면 스레드 ID)
+/// * `message` - 로그 메시지
+/// * `trace_id` - 가장 안쪽 span 의 상관 관계 ID (`udp_frame`, `ws_frame`, `ws_request`), 없으면 null
+/// * `spans` - 바깥쪽부터의 span 이름 목록
+/// * `context` - span 필드 (`trace_id` 제외, 같은 이름은 안쪽 span 값 사용)
+/// * `fields` - 로그 이벤트의 `message` 외 필드
+pub struct JsonFormat;
+
+impl<S, N> FormatEvent<S, N> for JsonFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let mut visitor = JsonVisitor::default();
+        event.record(&mut visitor);
+        let mut fields = visitor.0;
+        let message = fields.remove("message").unwrap_or(Value::Null);
+
+        let mut spans = Vec::new();
+        let mut context = Map::new();
+        if let Some(scope) = ctx.event_scope() {
+            for span in scope.from_root() {
+                spans.push(Value::from(span.name()));
+                if let Some(formatted) = span.extensions().get::<FormattedFields<N>>() {
+                    context.extend(parse_object(&formatted.fields));
+                }
+            }
+        }
+        let trace_id = context.remove("trace_id").unwrap_or(Value::Null);
+
+        let thread = std::thread::current();
+        let thread = match thread.name() {
+            Some(name) => name.to_string(),
+            None => format!("{:?}", thread.id()),
+        };
+
+        let mut line = Map::new();
+        line.insert("time_ms".to_string(), Value::from(unix_time_ms()));
+        line.insert("level".to_string(), Value::from(metadata.level().as_str()));
+        line.insert("target".to_string(), Value::from(metadata.target()));
+        line.insert("file".to_string(), Value::from(metadata.file()));
+        line.insert("line".to_string(), Value::from(metadata.line()));
+        line.insert("thread".to_string(), Value::from(thread));
+        line.insert("message".to_string(), message);
+        line.insert("trace_id".to_string(), trace_id);
+        line.insert("spans".to_string(), Value::Array(spans));
+        line.insert("context".to_string(), Value::Object(context));
+        line.insert("fields".to_string(), Value::Object(fields));
+        writeln!(writer, "{}", Value::Object(line))
+    }
+}
+
+/// `JsonFields` 가 저장한 span 필드 문자열을 JSON 객체로 변환 (다른 형식이면 빈 객체)
+fn parse_object(fields: &str) -> Map<String, Value> {
+    match serde_json::from_str(fields) {
+        Ok(Value::Object(object)) => object,
+        _ => Map::new(),
+    }
+}
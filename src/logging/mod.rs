@@ -0,0 +1,35 @@
+pub mod config;
+pub mod file;
+pub mod json;
+
+pub use config::{LogConfig, LogFormat};
+pub use file::{LogFile, LogRotation};
+pub use json::{JsonFields, JsonFormat};
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+/// 로그 상관 관계 ID
+///
+/// UDP 데이터그램 처리(`udp_frame`), WebSocket 전달(`ws_frame`), WebSocket 요청(`ws_request`) span 의
+/// `trace_id` 필드로 기록하여 한 프레임/요청에서 나온 로그를 서로 연결합니다.
+/// 16자리 16진수로 표시하며, 서버 시작마다 다른 값에서 시작하여 재시작 전후의 ID 가 겹치지 않습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TraceId(pub u64);
+
+impl TraceId {
+    /// 새 ID 생성 (프로세스 안에서 겹치지 않음)
+    pub fn next() -> Self {
+        static BASE: OnceLock<u64> = OnceLock::new();
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let base = *BASE.get_or_init(|| uuid::Uuid::new_v4().as_u64_pair().0);
+        Self(base.wrapping_add(COUNTER.fetch_add(1, Ordering::Relaxed)))
+    }
+}
+
+impl fmt::Display for TraceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
@@ -1,67 +1,51 @@
-mod common;
-mod lidar;
-mod udp;
-mod ws;
-
-use std::net::{SocketAddr, TcpListener};
-use tokio::sync::broadcast;
+#[cfg(feature = "mdns")]
+use lidar_server::announce::MdnsAnnouncer;
+use lidar_server::config::ServerConfig;
+use lidar_server::health::{HealthState, SystemdNotifier};
+use lidar_server::lidar::{LiDARRegistry, ParseStatsStore};
+use lidar_server::logging::{JsonFields, JsonFormat, LogConfig, LogFile, LogFormat};
+use lidar_server::relay::RelayState;
+use lidar_server::replication::{self, ReplicationRole, SubscriptionStore};
+use lidar_server::sim;
+use lidar_server::supervisor::install_panic_hook;
+use lidar_server::telemetry::TelemetryStore;
+use lidar_server::udp::UdpListener;
+use lidar_server::ws::{reserve_port, WsServer};
+use std::future::Future;
+use std::path::{Path, PathBuf};
 use tracing::*;
-use tracing_appender::rolling::{RollingFileAppender, Rotation};
-use tracing_subscriber::{self, fmt::writer::MakeWriterExt, EnvFilter};
-use udp::UdpListener;
-use ws::WsServer;
-
-/// 사용 가능한 포트 찾기
-///
-/// # Examples
-/// ```
-/// let port: u16 = find_available_port(5555, 10)
-/// ```
-///
-/// # Arguments
-/// start_port: 시작 포트
-/// max_attempts: 최대 시도 횟수, 시도 시 마다 start_port + 1 을 하여 시도
-///
-/// # Returns
-/// 사용 가능한 포트 번호
-fn find_available_port(start_port: u16, max_attempts: u16) -> u16 {
-    let mut ret = start_port;
-    for port in start_port..start_port + max_attempts {
-        let addr = SocketAddr::from(([0, 0, 0, 0], port));
-        match TcpListener::bind(addr) {
-            Ok(_) => {
-                ret = port;
-                break;
-            }
-            Err(_) => {
-                // 열린 포트 존재
-                continue;
-            }
-        }
-    }
-    return ret;
-}
+use tracing_subscriber::fmt::writer::{BoxMakeWriter, MakeWriterExt};
+use tracing_subscriber::{self, EnvFilter};
 
 /// 로깅 시스템 초기화
 ///
 /// # Examples
 /// ```
-/// setup_logger()
+/// setup_logger(&config.log)
 /// ```
 ///
 /// # Arguments
-/// 없음
+/// * `config` - 로그 설정 (출력 형식, 로그 파일 회전/보관)
 ///
 /// # Returns
 /// 없음
 ///
 /// # 설정 내용
-/// * 로그 파일: logs/lidar-server-YYYY-MM-DD.log
+/// * 로그 파일: logs/lidar-server.log, `log.max_file_bytes` 를 넘거나 날짜가 바뀌면 회전하고
+///   보관 개수(`log.max_files`)와 전체 크기(`log.max_total_bytes`)를 넘는 오래된 파일 삭제
+///   (파일을 열지 못하면 터미널에만 출력)
 /// * 로그 레벨: Release 빌드일 때는 INFO 이상, Debug 빌드일 때는 TRACE 이상
 /// * 포함 정보: 시간, 스레드 ID/이름, 파일 위치, 라인 번호
 /// * 로그 출력: 터미널과 파일 모두에 출력
-fn setup_logger() {
-    let file_appender = RollingFileAppender::new(Rotation::DAILY, "logs", "lidar-server.log");
+/// * `log.format` 이 `json` 이면 한 줄에 JSON 객체 하나로 출력 (span 필드와 `trace_id` 포함)
+fn setup_logger(config: &LogConfig) {
+    let log_file = match LogFile::install(config) {
+        Ok(log_file) => Some(log_file),
+        Err(e) => {
+            eprintln!("File logging disabled: {}", e);
+            None
+        }
+    };
 
     // 환경에 따른 로그 레벨 설정
     let filter = if cfg!(debug_assertions) {
@@ -72,16 +56,190 @@ fn setup_logger() {
         EnvFilter::from_default_env().add_directive(tracing::Level::INFO.into())
     };
 
-    tracing_subscriber::fmt()
+    let writer = match log_file {
+        Some(log_file) => BoxMakeWriter::new(std::io::stdout.and(move || log_file)),
+        None => BoxMakeWriter::new(std::io::stdout),
+    };
+    let builder = tracing_subscriber::fmt()
         .with_env_filter(filter)
-        .with_writer(file_appender.and(std::io::stdout))
-        .with_ansi(false)
-        .with_thread_ids(true)
-        .with_thread_names(true)
-        .with_file(true)
-        .with_line_number(true)
-        .with_target(false)
-        .init();
+        .with_writer(writer)
+        .with_ansi(false);
+    match config.format {
+        LogFormat::Text => builder
+            .with_thread_ids(true)
+            .with_thread_names(true)
+            .with_file(true)
+            .with_line_number(true)
+            .with_target(false)
+            .init(),
+        LogFormat::Json => builder
+            .fmt_fields(JsonFields)
+            .event_format(JsonFormat)
+            .init(),
+    }
+}
+
+/// systemd watchdog 알림을 보내며 future 실행
+///
+/// # Arguments
+/// * `notifier` - systemd 알림 송신기
+/// * `future` - 실행할 future
+///
+/// # Returns
+/// * `F::Output` - future 결과
+///
+/// # 동작 설명
+/// * watchdog 사용 시 future 가 끝날 때까지 `WATCHDOG_USEC` 의 절반마다 `WATCHDOG=1` 송신
+/// * watchdog 미사용 시 future 를 그대로 실행
+async fn with_watchdog<F: Future>(notifier: &SystemdNotifier, future: F) -> F::Output {
+    let Some(period) = notifier.watchdog_interval() else {
+        return future.await;
+    };
+    tokio::pin!(future);
+    let mut interval = tokio::time::interval(period);
+    loop {
+        tokio::select! {
+            output = &mut future => return output,
+            _ = interval.tick() => notifier.notify("WATCHDOG=1"),
+        }
+    }
+}
+
+/// 설정 파일 검사 후 종료 (`--check-config`)
+///
+/// # Arguments
+/// * `path` - 검사할 설정 파일 경로
+///
+/// # 동작 설명
+/// * 로거를 초기화하지 않고 결과를 표준 출력/에러로 출력
+/// * 파일이 없거나 문제가 있으면 exit code 1, 문제가 없으면 0
+fn check_config(path: &Path) -> ! {
+    if !path.exists() {
+        eprintln!("{} not found", path.display());
+        std::process::exit(1);
+    }
+    match ServerConfig::load(path) {
+        Ok(_) => {
+            println!("{}: OK", path.display());
+            std::process::exit(0);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// 녹화 파일에서 시뮬레이션 프로파일 추출 후 종료 (`--extract-profile <recording> <output>`)
+///
+/// # Arguments
+/// * `recording` - 녹화 파일 경로 (.lrec)
+/// * `output` - 저장할 프로파일 파일 경로 (JSON)
+///
+/// # 동작 설명
+/// * 로거를 초기화하지 않고 LiDAR 별 요약을 표준 출력으로 출력
+/// * 추출하지 못하면 exit code 1, 성공하면 0
+fn extract_profile(recording: &Path, output: &Path) -> ! {
+    let profile = match sim::extract_profile(recording) {
+        Ok(profile) => profile,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    for sensor in &profile.sensors {
+        println!(
+            "product line {} LiDAR {}: {} frames, {:.1} ms (+/- {:.1} ms), {} channels, {} bytes/scan",
+            sensor.product_line,
+            sensor.lidar_id,
+            sensor.frames,
+            sensor.frame_interval_ms,
+            sensor.frame_jitter_ms,
+            sensor.channels.len(),
+            sensor.scan_bytes()
+        );
+    }
+    if let Err(e) = profile.save(output) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+    println!("{}: OK", output.display());
+    std::process::exit(0);
+}
+
+/// 부하 테스트 실행 (`bench`)
+///
+/// # Arguments
+/// * `args` - `bench` 뒤의 인자 (`BenchOptions::parse` 참고)
+///
+/// # Returns
+/// * `i32` - exit code (인자가 잘못되었거나 서버를 시작하지 못하면 1)
+///
+/// # 동작 설명
+/// * 로거를 초기화하지 않고 결과 표만 표준 출력으로 출력
+#[cfg(feature = "bench")]
+async fn bench(args: &[String]) -> i32 {
+    use lidar_server::sim::bench::{self, BenchOptions};
+
+    let options = match BenchOptions::parse(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+    let config = match &options.config {
+        Some(path) => match ServerConfig::load(path) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("{}", e);
+                return 1;
+            }
+        },
+        None => ServerConfig::default(),
+    };
+    println!(
+        "Running bench: up to {} sensors, {} clients, {} steps over {} s",
+        options.sensors,
+        options.clients,
+        options.steps,
+        options.duration.as_secs()
+    );
+    match bench::run(config, &options).await {
+        Ok(report) => {
+            println!("{}", report);
+            0
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        }
+    }
+}
+
+/// 종료 신호(Ctrl+C, Unix 는 SIGTERM 포함) 대기
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+            }
+            Err(e) => {
+                error!("Failed to listen for SIGTERM: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
 }
 
 /// LiDAR 서버 메인 함수
@@ -94,8 +252,25 @@ fn setup_logger() {
 /// WebSocket 서버와 UDP 리스너가 동시에 실행되며, 각각 독립적인 태스크로 관리
 ///
 /// # 서버 구성
+/// * 설정 파일: 첫 번째 실행 인자 또는 `lidar_server.json` (없으면 기본 설정 사용)
+/// * 설정 검사: `--check-config` 인자가 있으면 서버를 시작하지 않고 설정 파일만 검사한 뒤 종료 (문제가 있으면 exit code 1)
+/// * 프로파일 추출: `--extract-profile <recording> <output>` 인자가 있으면 녹화 파일에서 시뮬레이션 프로파일만 추출한 뒤 종료
+/// * 부하 테스트: 첫 번째 인자가 `bench` 이면 서버와 가상 LiDAR/클라이언트를 함께 실행하여 감당 가능한 부하를 출력한 뒤 종료 (`bench` feature 필요)
+/// * 시뮬레이터: `sim.enabled` 설정 시 프로파일을 따르는 가상 LiDAR 가 서버 UDP 주소로 데이터 송신
 /// * WebSocket 서버: `0.0.0.0:5555` (포트 사용 중이면 자동으로 다음 포트 시도)
-/// * UDP 리스너: `0.0.0.0:5000` (TODO: 클라이언트 설정에 따라 port 변경 가능)
+/// * UDP 리스너: `UdpListenerConfig` 기본값 `0.0.0.0:5000` (소켓 버퍼, 배치 수신, 전용 수신 스레드 설정 가능)
+/// * mDNS 서비스 알림: `announce.enabled` 설정 시 `_lidar-relay._tcp` 로 WebSocket 포트 알림
+/// * 메시지 버스: `bus.enabled` 설정 시 NATS 로 LiDAR 데이터와 장치 이벤트 발행
+/// * IPC 출력: `ipc.enabled` 설정 시 Unix 도메인 소켓(Windows 는 named pipe)으로 LiDAR 데이터 전달
+/// * 이중화: `replication.role` 이 `standby` 이면 주 서버 상태를 복제하다가 주 서버가 멈춘 뒤에 UDP 리스너 시작,
+///   `replication.listen` 설정 시 (대기 서버는 역할을 넘겨받은 뒤) 대기 서버에게 상태 전송
+/// * 릴레이: `relay.upstreams` 설정 시 상위 LiDAR 서버의 WebSocket 을 구독하여 UDP 로 받은 데이터처럼 전달,
+///   `relay.uplinks` 설정 시 클라우드 수집 서버에 먼저 연결하여 등록한 뒤 로컬 WebSocket 프로토콜을 그 연결로 전달
+/// * 상태 확인: `/healthz`(활성), `/readyz`(설정 로드, UDP 바인딩, 멀티캐스트 가입, WebSocket 연결 수락 완료 시 200)
+/// * systemd: `NOTIFY_SOCKET` 이 있으면 UDP/WebSocket 바인딩 후 `READY=1`, `WATCHDOG_USEC` 가 있으면 주기적으로 `WATCHDOG=1`,
+///   종료 신호(SIGTERM, Ctrl+C)를 받으면 `STOPPING=1` 송신 후 종료 (`systemd` feature 필요)
+/// * 태스크 감시: UDP 수신/송신, WebSocket 전달 등 내부 태스크가 패닉하면 로그를 남기고 `supervisor` 설정에 따라 다시 시작,
+///   `supervisor.window_ms` 안에 `supervisor.max_restarts` 번 넘게 패닉하면 프로세스 종료
 ///
 /// # 통신 흐름
 /// 1. LiDAR -> UDP -> WebSocket -> 클라이언트
@@ -106,39 +281,218 @@ fn setup_logger() {
 /// * `ws_to_udp`: WebSocket에서 UDP로의 데이터 전송 (tokio mpsc 채널, 버퍼 크기: 1)
 #[tokio::main]
 async fn main() {
-    setup_logger();
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("bench") {
+        #[cfg(feature = "bench")]
+        std::process::exit(bench(&args[2..]).await);
+        #[cfg(not(feature = "bench"))]
+        {
+            eprintln!("bench requires the `bench` feature");
+            std::process::exit(1);
+        }
+    }
+    if let Some(index) = args.iter().position(|arg| arg == "--extract-profile") {
+        let (Some(recording), Some(output)) = (args.get(index + 1), args.get(index + 2)) else {
+            eprintln!("Usage: --extract-profile <recording> <output>");
+            std::process::exit(1);
+        };
+        extract_profile(Path::new(recording), Path::new(output));
+    }
+
+    let check_only = std::env::args().any(|arg| arg == "--check-config");
+    let config_path = std::env::args()
+        .skip(1)
+        .find(|arg| !arg.starts_with("--"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("lidar_server.json"));
+    if check_only {
+        check_config(&config_path);
+    }
+
+    // 로그 형식이 설정에 있으므로 설정을 먼저 읽고, 읽지 못하면 기본 형식으로 에러 기록
+    let loaded = ServerConfig::load(&config_path);
+    setup_logger(
+        loaded
+            .as_ref()
+            .map(|config| &config.log)
+            .unwrap_or(&LogConfig::default()),
+    );
+    install_panic_hook();
     info!("Start LiDAR Server!");
+    info!(
+        "LiDAR drivers: {:?}",
+        lidar_server::lidar::enabled_drivers()
+    );
+
+    let health = HealthState::new();
+    let notifier = SystemdNotifier::from_env();
+    let config = match loaded {
+        Ok(config) => config,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+    health.set_config_loaded();
 
     // UDP <-> WS 양방향 채널 생성
     let (udp_to_ws_tx, udp_to_ws_rx) = tokio::sync::mpsc::channel(1);
     let (ws_to_udp_tx, ws_to_udp_rx) = tokio::sync::mpsc::channel(1);
 
-    let start_port = 5555;
-    let max_attempts = 10;
-    let ws_port = find_available_port(start_port, max_attempts);
-    if ws_port == start_port + max_attempts {
-        error!("Failed to find available port");
+    let start_port = config.ws.start_port;
+    let max_attempts = config.ws.max_attempts;
+    let Some((ws_port, ws_listener)) = reserve_port(start_port, max_attempts) else {
+        error!(
+            "Failed to find available port in {}..{}",
+            start_port,
+            start_port.saturating_add(max_attempts)
+        );
         return;
-    }
+    };
+    info!("WebSocket port reserved: {}", ws_port);
 
-    let ws_addr: SocketAddr = format!("0.0.0.0:{}", ws_port).parse().unwrap();
-    let mut ws_server = WsServer::new(ws_to_udp_tx, udp_to_ws_rx);
+    let registry = LiDARRegistry::new(config.identity.clone());
+    let telemetry = TelemetryStore::new(config.telemetry.clone());
+    let parse_stats = ParseStatsStore::default();
+    let subscriptions = SubscriptionStore::default();
+    let relay = RelayState::new(config.relay.server_id.clone());
+    let mut ws_server = WsServer::new(
+        config.ws.clone(),
+        config.record.clone(),
+        config.processing.clone(),
+        ws_to_udp_tx,
+        udp_to_ws_rx,
+        registry.clone(),
+        telemetry,
+        parse_stats.clone(),
+        config.alert.clone(),
+        config.ipc.clone(),
+        config.bus.clone(),
+        config.influx.clone(),
+        config.sites.clone(),
+        subscriptions.clone(),
+        relay.clone(),
+        health.clone(),
+        config.supervisor.clone(),
+    );
     let ws_handle = tokio::spawn(async move {
-        ws_server.start(ws_addr).await;
+        ws_server.start(ws_listener).await;
     });
 
-    let udp_addr: SocketAddr = "0.0.0.0:5000".parse().unwrap();
-    let mut udp_listener = match UdpListener::new(udp_addr, udp_to_ws_tx, ws_to_udp_rx).await {
+    #[cfg(feature = "relay")]
+    {
+        lidar_server::relay::start_uplinks(&config.relay, relay.clone(), ws_port);
+        lidar_server::relay::start_relay(
+            &config.relay,
+            relay,
+            registry.clone(),
+            udp_to_ws_tx.clone(),
+        );
+    }
+    #[cfg(not(feature = "relay"))]
+    if !config.relay.upstreams.is_empty() || !config.relay.uplinks.is_empty() {
+        error!("Relay upstreams and uplinks require the `relay` feature");
+    }
+
+    let replication = config.replication.clone();
+    if replication.role == ReplicationRole::Standby {
+        with_watchdog(
+            &notifier,
+            replication::follow(&replication, &registry, &subscriptions),
+        )
+        .await;
+        warn!("Primary heartbeat lost, taking over UDP listening");
+    }
+    if replication.role != ReplicationRole::None {
+        if let Some(listen) = replication.listen {
+            let registry = registry.clone();
+            let subscriptions = subscriptions.clone();
+            tokio::spawn(async move {
+                if let Err(e) = replication::serve(
+                    listen,
+                    replication.heartbeat_interval_ms,
+                    registry,
+                    subscriptions,
+                )
+                .await
+                {
+                    error!("{}", e);
+                }
+            });
+        }
+    }
+
+    let udp_addr = config.udp.addr;
+    let mut udp_listener = match UdpListener::new(
+        config.udp,
+        registry,
+        parse_stats,
+        health.clone(),
+        config.supervisor.clone(),
+        udp_to_ws_tx,
+        ws_to_udp_rx,
+    )
+    .await
+    {
         Ok(listener) => listener,
         Err(e) => {
             error!("Failed to create UDP listener: {}", e);
             return;
         }
     };
+    #[cfg(feature = "plugins")]
+    udp_listener
+        .set_plugins(lidar_server::plugin::load_plugins(&config.plugins))
+        .await;
+    #[cfg(not(feature = "plugins"))]
+    if config.plugins.enabled {
+        error!("Parser plugins require the `plugins` feature");
+    }
     let udp_handle = tokio::spawn(async move {
         udp_listener.start().await;
     });
 
-    info!("UDP: {:?}, WS: {:?}", udp_addr, ws_addr);
-    _ = tokio::join!(udp_handle, ws_handle);
+    if config.sim.enabled {
+        if let Err(e) = sim::start(&config.sim, sim::local_target(udp_addr)) {
+            error!("Failed to start simulated LiDARs: {}", e);
+        }
+    }
+
+    #[cfg(feature = "mdns")]
+    let announcer = if config.announce.enabled {
+        match MdnsAnnouncer::start(&config.announce, ws_port) {
+            Ok(announcer) => Some(announcer),
+            Err(e) => {
+                error!("Failed to start mDNS announcement: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    #[cfg(not(feature = "mdns"))]
+    if config.announce.enabled {
+        error!("mDNS announcement requires the `mdns` feature");
+    }
+
+    info!("UDP: {:?}, WS port: {}", udp_addr, ws_port);
+    let ready = async {
+        health.wait_bound().await;
+        notifier.notify("READY=1");
+        std::future::pending::<()>().await
+    };
+    with_watchdog(&notifier, async {
+        tokio::select! {
+            _ = async { tokio::join!(udp_handle, ws_handle) } => {}
+            _ = ready => {}
+            _ = shutdown_signal() => info!("Shutting down"),
+        }
+    })
+    .await;
+    notifier.notify("STOPPING=1");
+
+    #[cfg(feature = "mdns")]
+    if let Some(announcer) = announcer {
+        announcer.stop();
+    }
 }
@@ -0,0 +1,80 @@
+//! 외부 파서 플러그인 C ABI 정의
+//!
+//! 플러그인은 아래 심볼을 `extern "C"` 로 export 하는 동적 라이브러리입니다.
+//!
+//! ```c
+//! uint32_t lidar_plugin_abi_version(void);          // PLUGIN_ABI_VERSION 과 같아야 함
+//! const char *lidar_plugin_name(void);              // NUL 종료 문자열, 라이브러리 수명 동안 유효
+//! void *lidar_plugin_create(void);                  // 파서 상태 생성 (NULL 이면 등록 실패)
+//! void lidar_plugin_destroy(void *state);           // 파서 상태 해제
+//! int32_t lidar_plugin_parse(void *state, const uint8_t *data, size_t len,
+//!                            LidarPluginFrame *out);
+//! ```
+//!
+//! `lidar_plugin_parse` 반환값
+//! * `1` - 포인트 프레임 파싱 성공, `out` 에 결과 기록
+//! * `0` - 해당 플러그인의 패킷이 아님 (다음 플러그인/내장 드라이버로 전달)
+//! * 음수 - 해당 플러그인의 패킷이지만 파싱 실패 (패킷 무시)
+//!
+//! `out->points` 는 같은 상태로 다음 `lidar_plugin_parse` 또는 `lidar_plugin_destroy` 를
+//! 호출하기 전까지 유효해야 합니다.
+
+/// 서버가 지원하는 플러그인 ABI 버전
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// ABI 버전 함수 심볼
+pub const SYMBOL_ABI_VERSION: &[u8] = b"lidar_plugin_abi_version\0";
+/// 플러그인 이름 함수 심볼
+pub const SYMBOL_NAME: &[u8] = b"lidar_plugin_name\0";
+/// 파서 상태 생성 함수 심볼
+pub const SYMBOL_CREATE: &[u8] = b"lidar_plugin_create\0";
+/// 파서 상태 해제 함수 심볼
+pub const SYMBOL_DESTROY: &[u8] = b"lidar_plugin_destroy\0";
+/// 파싱 함수 심볼
+pub const SYMBOL_PARSE: &[u8] = b"lidar_plugin_parse\0";
+
+/// 플러그인 포인트 (m 단위 3차원 좌표)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LidarPluginPoint {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// 플러그인 파싱 결과 프레임
+///
+/// # Fields
+/// * `product_line` - LiDAR 키에 사용할 제품 라인
+/// * `lidar_id` - LiDAR 키에 사용할 LiDAR ID
+/// * `channel` - 포인트 채널 번호
+/// * `points` - 포인트 배열
+/// * `point_count` - 포인트 수
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LidarPluginFrame {
+    pub product_line: u8,
+    pub lidar_id: u8,
+    pub channel: u8,
+    pub points: *const LidarPluginPoint,
+    pub point_count: usize,
+}
+
+impl Default for LidarPluginFrame {
+    fn default() -> Self {
+        Self {
+            product_line: 0,
+            lidar_id: 0,
+            channel: 0,
+            points: std::ptr::null(),
+            point_count: 0,
+        }
+    }
+}
+
+pub type AbiVersionFn = unsafe extern "C" fn() -> u32;
+pub type NameFn = unsafe extern "C" fn() -> *const std::ffi::c_char;
+pub type CreateFn = unsafe extern "C" fn() -> *mut std::ffi::c_void;
+pub type DestroyFn = unsafe extern "C" fn(*mut std::ffi::c_void);
+pub type ParseFn =
+    unsafe extern "C" fn(*mut std::ffi::c_void, *const u8, usize, *mut LidarPluginFrame) -> i32;
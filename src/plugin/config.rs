@@ -0,0 +1,24 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// 외부 파서 플러그인 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 플러그인 사용 여부 (`plugins` feature 필요)
+/// * `dir` - 플러그인 동적 라이브러리(`.so`/`.dll`/`.dylib`) 디렉터리, 시작 시 파일 이름 순으로 등록
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct PluginConfig {
+    pub enabled: bool,
+    pub dir: PathBuf,
+}
+
+impl Default for PluginConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: PathBuf::from("plugins"),
+        }
+    }
+}
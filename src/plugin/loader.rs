@@ -0,0 +1,214 @@
+use libloading::Library;
+use std::ffi::{c_void, CStr};
+use std::net::Ipv4Addr;
+use std::path::{Path, PathBuf};
+use tracing::*;
+
+use crate::lidar::kanavi_mobility::codec::MODE_POINT_CLOUD;
+use crate::lidar::kanavi_mobility::KanaviMobilityData;
+use crate::lidar::{Point, PointCloud};
+use crate::plugin::abi::*;
+use crate::plugin::config::PluginConfig;
+
+/// 동적 라이브러리로 로드한 외부 파서 플러그인
+///
+/// # Examples
+/// ```
+/// let mut plugin = PluginParser::load(Path::new("plugins/libacme.so"))?;
+/// if let Some(Ok(data)) = plugin.parse(ip, &datagram) {
+///     // data.get_points()
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * ABI 버전이 `PLUGIN_ABI_VERSION` 과 다르면 로드 실패
+/// * 파싱 결과는 서버 프레임 형식(`KanaviMobilityData` 포인트 클라우드)으로 변환하여
+///   구독/텔레메트리/녹화 등 기존 처리 흐름을 그대로 사용
+pub struct PluginParser {
+    name: String,
+    path: PathBuf,
+    state: *mut c_void,
+    parse: ParseFn,
+    destroy: DestroyFn,
+    // 함수 포인터가 가리키는 라이브러리, 상태 해제 후 마지막에 해제
+    _library: Library,
+}
+
+// 플러그인 상태는 한 번에 하나의 스레드에서만 사용 (`&mut self` 로만 접근)
+unsafe impl Send for PluginParser {}
+
+impl PluginParser {
+    /// 플러그인 로드
+    ///
+    /// # Arguments
+    /// * `path` - 플러그인 동적 라이브러리 경로
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 플러그인 파서, 심볼 누락/ABI 버전 불일치 등 실패 시 에러 메시지
+    pub fn load(path: &Path) -> Result<Self, String> {
+        // SAFETY: 플러그인 디렉터리의 라이브러리는 운영자가 설치한 신뢰할 수 있는 코드로 간주
+        unsafe {
+            let library = Library::new(path).map_err(|e| e.to_string())?;
+            let abi_version = *library
+                .get::<AbiVersionFn>(SYMBOL_ABI_VERSION)
+                .map_err(|e| e.to_string())?;
+            let version = abi_version();
+            if version != PLUGIN_ABI_VERSION {
+                return Err(format!(
+                    "Unsupported plugin ABI version {} (expected {})",
+                    version, PLUGIN_ABI_VERSION
+                ));
+            }
+
+            let name_fn = *library
+                .get::<NameFn>(SYMBOL_NAME)
+                .map_err(|e| e.to_string())?;
+            let create = *library
+                .get::<CreateFn>(SYMBOL_CREATE)
+                .map_err(|e| e.to_string())?;
+            let destroy = *library
+                .get::<DestroyFn>(SYMBOL_DESTROY)
+                .map_err(|e| e.to_string())?;
+            let parse = *library
+                .get::<ParseFn>(SYMBOL_PARSE)
+                .map_err(|e| e.to_string())?;
+
+            let name_ptr = name_fn();
+            let name = if name_ptr.is_null() {
+                path.display().to_string()
+            } else {
+                CStr::from_ptr(name_ptr).to_string_lossy().into_owned()
+            };
+            let state = create();
+            if state.is_null() {
+                return Err(format!("Plugin {} failed to create parser state", name));
+            }
+
+            Ok(Self {
+                name,
+                path: path.to_path_buf(),
+                state,
+                parse,
+                destroy,
+                _library: library,
+            })
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 패킷 파싱
+    ///
+    /// # Arguments
+    /// * `ip` - 송신자 IP
+    /// * `data` - 수신한 UDP 데이터
+    ///
+    /// # Returns
+    /// * `None` - 해당 플러그인의 패킷이 아님
+    /// * `Some(Ok(KanaviMobilityData))` - 서버 프레임 형식으로 변환한 포인트 클라우드
+    /// * `Some(Err(String))` - 해당 플러그인의 패킷이지만 파싱 실패
+    pub fn parse(
+        &mut self,
+        ip: Ipv4Addr,
+        data: &[u8],
+    ) -> Option<Result<KanaviMobilityData, String>> {
+        let mut frame = LidarPluginFrame::default();
+        // SAFETY: 상태는 load 에서 생성되어 drop 전까지 유효하며, data 는 호출 동안만 사용됨
+        let result = unsafe { (self.parse)(self.state, data.as_ptr(), data.len(), &mut frame) };
+        match result {
+            0 => None,
+            1 => Some(Ok(self.to_frame(ip, data, &frame))),
+            code => Some(Err(format!("Plugin {} returned {}", self.name, code))),
+        }
+    }
+
+    /// 플러그인 프레임을 서버 프레임 형식으로 변환
+    fn to_frame(&self, ip: Ipv4Addr, data: &[u8], frame: &LidarPluginFrame) -> KanaviMobilityData {
+        let points = if frame.points.is_null() || frame.point_count == 0 {
+            &[][..]
+        } else {
+            // SAFETY: ABI 상 points 는 다음 parse 호출 전까지 point_count 개의 포인트를 가리킴
+            unsafe { std::slice::from_raw_parts(frame.points, frame.point_count) }
+        };
+        let mut cloud = PointCloud::new();
+        for point in points {
+            cloud.add_point(Point {
+                x: point.x,
+                y: point.y,
+                z: point.z,
+            });
+        }
+
+        let mut lidar_data = KanaviMobilityData::new(
+            data.to_vec(),
+            frame.product_line,
+            frame.lidar_id,
+            MODE_POINT_CLOUD,
+            frame.channel,
+            ip,
+        );
+        lidar_data.set_points(frame.channel, cloud);
+        lidar_data
+    }
+}
+
+impl Drop for PluginParser {
+    fn drop(&mut self) {
+        // SAFETY: 상태는 load 에서 생성되었고 한 번만 해제됨
+        unsafe { (self.destroy)(self.state) };
+    }
+}
+
+/// 플러그인 디렉터리의 동적 라이브러리를 파일 이름 순으로 로드
+///
+/// # Arguments
+/// * `config` - 플러그인 설정
+///
+/// # Returns
+/// * `Vec<PluginParser>` - 로드에 성공한 플러그인 (실패한 플러그인은 로그를 남기고 제외)
+pub fn load_plugins(config: &PluginConfig) -> Vec<PluginParser> {
+    if !config.enabled {
+        return Vec::new();
+    }
+
+    let entries = match std::fs::read_dir(&config.dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            error!("Failed to read plugin dir {}: {}", config.dir.display(), e);
+            return Vec::new();
+        }
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION)
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .filter_map(|path| match PluginParser::load(path) {
+            Ok(plugin) => {
+                info!(
+                    "Loaded parser plugin {} ({})",
+                    plugin.name(),
+                    path.display()
+                );
+                Some(plugin)
+            }
+            Err(e) => {
+                error!("Failed to load plugin {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,8 @@
+pub mod abi;
+pub mod config;
+#[cfg(feature = "plugins")]
+pub mod loader;
+
+pub use config::PluginConfig;
+#[cfg(feature = "plugins")]
+pub use loader::{load_plugins, PluginParser};
@@ -0,0 +1,414 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::*;
+use utoipa::ToSchema;
+
+use crate::common::time::unix_time_ms;
+use crate::lidar::{LiDARKey, Point, PointCloud};
+use crate::processing::config::{CalibrationConfig, Extrinsic};
+use crate::processing::icp::{align, Alignment, IcpParams};
+use crate::processing::odometry::Pose;
+
+/// 스냅샷 수집 요청 구조체 (`POST /calibration/snapshots` 본문)
+///
+/// # Examples
+/// ```json
+/// { "source": { "ip": "192.168.123.201", "port": 5000, "product_line": 1, "lidar_id": 0 }, "target": { "ip": "192.168.123.200", "port": 5000, "product_line": 1, "lidar_id": 0 } }
+/// ```
+///
+/// # Fields
+/// * `source` - 설치 자세를 추정할 LiDAR
+/// * `target` - 기준 LiDAR
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CaptureRequest {
+    pub source: LiDARKey,
+    pub target: LiDARKey,
+}
+
+/// 수집한 스냅샷 정보 구조체
+///
+/// # Fields
+/// * `id` - 스냅샷 ID (정합 요청에 사용)
+/// * `source` - 설치 자세를 추정할 LiDAR
+/// * `target` - 기준 LiDAR
+/// * `started_ms` - 수집 시작 시각 (Unix time, ms)
+/// * `duration_ms` - 수집 시간 (ms)
+/// * `source_points` - `source` 포인트 수
+/// * `target_points` - `target` 포인트 수
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct SnapshotInfo {
+    pub id: u64,
+    pub source: LiDARKey,
+    pub target: LiDARKey,
+    pub started_ms: u64,
+    pub duration_ms: u64,
+    pub source_points: usize,
+    pub target_points: usize,
+}
+
+/// 정합 요청 구조체 (`POST /calibration/align` 본문)
+///
+/// # Examples
+/// ```json
+/// { "snapshot": 1, "initial": { "position": [0.0, 2.0, 0.0], "orientation": [0.0, 0.0, 0.0, 1.0] }, "max_distance": 0.5 }
+/// ```
+///
+/// # Fields
+/// * `snapshot` - 스냅샷 ID
+/// * `initial` - `source` → `target` 초기 변환 추정값, 없으면 변환 없음
+/// * `max_iterations` - 최대 반복 횟수, 없으면 `processing.calibration.max_iterations`
+/// * `max_distance` - 대응점 최대 거리 (m), 없으면 `processing.calibration.max_distance`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AlignRequest {
+    pub snapshot: u64,
+    #[serde(default)]
+    pub initial: Option<Pose>,
+    #[serde(default)]
+    pub max_iterations: Option<u32>,
+    #[serde(default)]
+    pub max_distance: Option<f32>,
+}
+
+/// 정합 결과 구조체
+///
+/// # Fields
+/// * `snapshot` - 스냅샷 ID
+/// * `source` - 설치 자세를 추정한 LiDAR
+/// * `target` - 기준 LiDAR
+/// * `alignment` - `source` → `target` 변환과 정합 지표 (`fitness`, `rmse`)
+/// * `extrinsic` - 저장할 `source` 설치 자세 (`target` 설치 자세가 있으면 이어 붙인 월드 좌표 자세,
+///   없으면 `target` 좌표를 월드 좌표로 본 자세)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct CalibrationResult {
+    pub snapshot: u64,
+    pub source: LiDARKey,
+    pub target: LiDARKey,
+    pub alignment: Alignment,
+    pub extrinsic: Extrinsic,
+}
+
+/// 수집 중인 스냅샷
+struct Capture {
+    id: u64,
+    until: Instant,
+    source: LiDARKey,
+    target: LiDARKey,
+    source_points: Vec<Point>,
+    target_points: Vec<Point>,
+}
+
+/// 수집한 스냅샷
+struct Snapshot {
+    info: SnapshotInfo,
+    source_points: Arc<Vec<Point>>,
+    target_points: Arc<Vec<Point>>,
+}
+
+/// 두 LiDAR 사이 설치 자세 추정(캘리브레이션) 도우미
+///
+/// # Examples
+/// ```
+/// let calibration = CalibrationStore::new(config.processing.calibration.clone(), &config.processing.tracking.extrinsics);
+/// let info = calibration.capture(&CaptureRequest { source, target }).await?;
+/// let request = AlignRequest { snapshot: info.id, initial: None, max_iterations: None, max_distance: None };
+/// let result = calibration.align(&request).await?;
+/// calibration.save(&result.extrinsic).await?;
+/// ```
+///
+/// # 동작 설명
+/// * 스냅샷: 두 LiDAR 에서 같은 `capture_ms` 구간 동안 수신한 포인트를 모아 보관 (녹화 재생 데이터 제외)
+/// * 정합: 스냅샷에 점-점 ICP 를 별도 스레드에서 실행하여 `source` → `target` 변환과 정합 지표 반환
+/// * 저장: 설치 자세를 `extrinsics_file` 에 저장 (임시 파일에 쓴 뒤 이름 변경), 서버 시작 시 다시 읽음
+pub struct CalibrationStore {
+    config: CalibrationConfig,
+    next_id: Mutex<u64>,
+    captures: Mutex<Vec<Capture>>,
+    snapshots: Mutex<VecDeque<Snapshot>>,
+    saved: Mutex<Vec<Extrinsic>>,
+    extrinsics: RwLock<HashMap<LiDARKey, Extrinsic>>,
+}
+
+impl CalibrationStore {
+    /// 캘리브레이션 도우미 생성
+    ///
+    /// # Arguments
+    /// * `config` - 캘리브레이션 설정
+    /// * `configured` - 설정 파일의 설치 자세 (`processing.tracking.extrinsics`)
+    ///
+    /// # 동작 설명
+    /// * `extrinsics_file` 을 읽어 설정의 설치 자세에 반영 (같은 LiDAR 는 저장된 값 우선, 읽기 실패 시 경고 후 무시)
+    pub fn new(config: CalibrationConfig, configured: &[Extrinsic]) -> Self {
+        let saved = match load_extrinsics(&config.extrinsics_file) {
+            Ok(saved) => saved,
+            Err(e) => {
+                warn!("{}", e);
+                Vec::new()
+            }
+        };
+        let extrinsics = configured
+            .iter()
+            .chain(saved.iter())
+            .map(|extrinsic| (extrinsic.lidar, extrinsic.clone()))
+            .collect();
+        Self {
+            config,
+            next_id: Mutex::new(0),
+            captures: Mutex::new(Vec::new()),
+            snapshots: Mutex::new(VecDeque::new()),
+            saved: Mutex::new(saved),
+            extrinsics: RwLock::new(extrinsics),
+        }
+    }
+
+    pub fn config(&self) -> &CalibrationConfig {
+        &self.config
+    }
+
+    /// 현재 사용 중인 설치 자세 목록 (설정 + 저장된 값)
+    pub fn extrinsics(&self) -> Vec<Extrinsic> {
+        let mut extrinsics: Vec<Extrinsic> =
+            self.extrinsics.read().unwrap().values().cloned().collect();
+        extrinsics.sort_by_key(|extrinsic| {
+            let key = extrinsic.lidar;
+            (key.ip, key.port, key.product_line, key.lidar_id)
+        });
+        extrinsics
+    }
+
+    /// 두 LiDAR 스냅샷 수집
+    ///
+    /// # Arguments
+    /// * `request` - 수집할 LiDAR
+    ///
+    /// # Returns
+    /// * `Result<SnapshotInfo, String>` - 성공 시 스냅샷 정보, 같은 LiDAR 이거나 수집 시간 동안 포인트가 없으면 에러 메시지
+    pub async fn capture(&self, request: &CaptureRequest) -> Result<SnapshotInfo, String> {
+        if request.source == request.target {
+            return Err("Source and target must be different LiDARs".to_string());
+        }
+        let id = {
+            let mut next_id = self.next_id.lock().await;
+            *next_id += 1;
+            *next_id
+        };
+        let started_ms = unix_time_ms();
+        let duration = Duration::from_millis(self.config.capture_ms);
+        {
+            let mut captures = self.captures.lock().await;
+            // 요청이 취소되어 남은 수집은 정리
+            let now = Instant::now();
+            captures.retain(|capture| capture.until + duration > now);
+            captures.push(Capture {
+                id,
+                until: now + duration,
+                source: request.source,
+                target: request.target,
+                source_points: Vec::new(),
+                target_points: Vec::new(),
+            });
+        }
+        tokio::time::sleep(duration).await;
+
+        let capture = {
+            let mut captures = self.captures.lock().await;
+            let index = captures
+                .iter()
+                .position(|capture| capture.id == id)
+                .ok_or_else(|| format!("Snapshot {} was lost", id))?;
+            captures.remove(index)
+        };
+        for (key, points) in [
+            (capture.source, &capture.source_points),
+            (capture.target, &capture.target_points),
+        ] {
+            if points.is_empty() {
+                return Err(format!(
+                    "No points from {}:{} within {} ms",
+                    key.ip, key.port, self.config.capture_ms
+                ));
+            }
+        }
+
+        let source_points = thin(capture.source_points, self.config.max_points);
+        let target_points = thin(capture.target_points, self.config.max_points);
+        let info = SnapshotInfo {
+            id,
+            source: capture.source,
+            target: capture.target,
+            started_ms,
+            duration_ms: unix_time_ms().saturating_sub(started_ms),
+            source_points: source_points.len(),
+            target_points: target_points.len(),
+        };
+        let mut snapshots = self.snapshots.lock().await;
+        snapshots.push_back(Snapshot {
+            info: info.clone(),
+            source_points: Arc::new(source_points),
+            target_points: Arc::new(target_points),
+        });
+        while snapshots.len() > self.config.max_snapshots.max(1) {
+            snapshots.pop_front();
+        }
+        Ok(info)
+    }
+
+    /// 수신한 포인트 클라우드를 수집 중인 스냅샷에 추가
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `points` - 채널별 포인트 클라우드
+    pub async fn observe(&self, key: LiDARKey, points: &[PointCloud]) {
+        let mut captures = self.captures.lock().await;
+        let now = Instant::now();
+        for capture in captures.iter_mut().filter(|capture| now <= capture.until) {
+            let collected = if capture.source == key {
+                &mut capture.source_points
+            } else if capture.target == key {
+                &mut capture.target_points
+            } else {
+                continue;
+            };
+            // 반사가 없는 포인트(원점)는 정합에 사용하지 않음
+            collected.extend(
+                points
+                    .iter()
+                    .flat_map(|cloud| cloud.points.iter())
+                    .filter(|point| point.x != 0.0 || point.y != 0.0 || point.z != 0.0)
+                    .cloned(),
+            );
+        }
+    }
+
+    /// 스냅샷 정합
+    ///
+    /// # Arguments
+    /// * `request` - 정합 요청
+    ///
+    /// # Returns
+    /// * `Result<CalibrationResult, String>` - 성공 시 정합 결과, 스냅샷이 없거나 파라미터가 잘못되면 에러 메시지
+    pub async fn align(&self, request: &AlignRequest) -> Result<CalibrationResult, String> {
+        let initial = request.initial.unwrap_or(Pose::IDENTITY);
+        initial.validate()?;
+        let params = IcpParams {
+            max_iterations: request.max_iterations.unwrap_or(self.config.max_iterations),
+            max_distance: request.max_distance.unwrap_or(self.config.max_distance),
+            tolerance: self.config.tolerance,
+        };
+        if !(params.max_distance.is_finite() && params.max_distance > 0.0) {
+            return Err("max_distance must be a positive number".to_string());
+        }
+        let (info, source_points, target_points) = {
+            let snapshots = self.snapshots.lock().await;
+            let snapshot = snapshots
+                .iter()
+                .find(|snapshot| snapshot.info.id == request.snapshot)
+                .ok_or_else(|| format!("Unknown snapshot: {}", request.snapshot))?;
+            (
+                snapshot.info.clone(),
+                snapshot.source_points.clone(),
+                snapshot.target_points.clone(),
+            )
+        };
+
+        let alignment = tokio::task::spawn_blocking(move || {
+            align(&source_points, &target_points, &initial, &params)
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+        info!(
+            "Aligned snapshot {} ({}:{} -> {}:{}): fitness {:.3}, rmse {:.3} m, {} iterations",
+            info.id,
+            info.source.ip,
+            info.source.port,
+            info.target.ip,
+            info.target.port,
+            alignment.fitness,
+            alignment.rmse,
+            alignment.iterations
+        );
+        // target 설치 자세가 없으면 target 좌표를 월드 좌표로 봄
+        let target_pose = self
+            .extrinsics
+            .read()
+            .unwrap()
+            .get(&info.target)
+            .map_or(Pose::IDENTITY, |extrinsic| extrinsic.pose);
+        let pose = target_pose.compose(&alignment.transform);
+        Ok(CalibrationResult {
+            snapshot: info.id,
+            source: info.source,
+            target: info.target,
+            alignment,
+            extrinsic: Extrinsic {
+                lidar: info.source,
+                pose,
+            },
+        })
+    }
+
+    /// 설치 자세 저장
+    ///
+    /// # Arguments
+    /// * `extrinsic` - 저장할 설치 자세 (같은 LiDAR 의 저장된 자세는 교체)
+    ///
+    /// # Returns
+    /// * `Result<Vec<Extrinsic>, String>` - 성공 시 저장된 설치 자세 목록, 자세가 잘못되었거나 파일 쓰기 실패 시 에러 메시지
+    pub async fn save(&self, extrinsic: &Extrinsic) -> Result<Vec<Extrinsic>, String> {
+        extrinsic.pose.validate()?;
+        let mut saved = self.saved.lock().await;
+        let mut updated = saved.clone();
+        updated.retain(|other| other.lidar != extrinsic.lidar);
+        updated.push(extrinsic.clone());
+
+        let path = self.config.extrinsics_file.clone();
+        let text = serde_json::to_vec_pretty(&updated).map_err(|e| e.to_string())?;
+        tokio::task::spawn_blocking(move || {
+            let tmp = path.with_extension("json.tmp");
+            std::fs::write(&tmp, text)
+                .and_then(|_| std::fs::rename(&tmp, &path))
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+
+        info!(
+            "Saved extrinsic for {}:{} to {}",
+            extrinsic.lidar.ip,
+            extrinsic.lidar.port,
+            self.config.extrinsics_file.display()
+        );
+        *saved = updated.clone();
+        self.extrinsics
+            .write()
+            .unwrap()
+            .insert(extrinsic.lidar, extrinsic.clone());
+        Ok(updated)
+    }
+}
+
+/// 저장된 설치 자세 파일 읽기 (파일이 없으면 빈 목록)
+pub fn load_extrinsics(path: &Path) -> Result<Vec<Extrinsic>, String> {
+    let text = match std::fs::read(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+    serde_json::from_slice(&text).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+/// 포인트가 `max_points` 보다 많으면 일정 간격으로 골라 줄임
+fn thin(points: Vec<Point>, max_points: usize) -> Vec<Point> {
+    let max_points = max_points.max(1);
+    if points.len() <= max_points {
+        return points;
+    }
+    let step = points.len().div_ceil(max_points);
+    points.into_iter().step_by(step).collect()
+}
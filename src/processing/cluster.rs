@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::lidar::{Point, PointCloud};
+use crate::processing::config::TrackingConfig;
+
+/// 물체 후보(클러스터) 구조체
+///
+/// # Fields
+/// * `center` - 포인트 평균 위치 `[x, y]` (m)
+/// * `min` - x, y 최솟값 (m)
+/// * `max` - x, y 최댓값 (m)
+/// * `points` - 포인트 수
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster {
+    pub center: [f32; 2],
+    pub min: [f32; 2],
+    pub max: [f32; 2],
+    pub points: usize,
+}
+
+impl Cluster {
+    /// 바닥 면적 기준 크기 (가로, 세로 중 긴 변, m)
+    pub fn size(&self) -> f32 {
+        (self.max[0] - self.min[0]).max(self.max[1] - self.min[1])
+    }
+}
+
+/// 포인트 클라우드를 x, y 평면 격자로 나누고 이웃한 격자끼리 묶어 물체 후보 생성
+///
+/// # Examples
+/// ```
+/// let clusters = cluster(lidar_data.get_points(), &config.processing.tracking);
+/// ```
+///
+/// # Arguments
+/// * `points` - 채널별 포인트 클라우드
+/// * `config` - 물체 추적 설정 (`cell_size`, `min_points`, `z_range`)
+///
+/// # Returns
+/// * `Vec<Cluster>` - 포인트 수가 `min_points` 이상인 물체 후보
+///
+/// # 동작 설명
+/// * 반사가 없는 포인트(원점)와 `z_range` 밖의 포인트(바닥, 천장 등)는 제외
+/// * 포인트가 있는 격자를 8 방향 이웃끼리 연결하여 한 물체로 봄
+pub fn cluster(points: &[PointCloud], config: &TrackingConfig) -> Vec<Cluster> {
+    let cell_size = if config.cell_size > 0.0 {
+        config.cell_size
+    } else {
+        TrackingConfig::default().cell_size
+    };
+    let mut cells: HashMap<(i32, i32), Vec<&Point>> = HashMap::new();
+    for point in points.iter().flat_map(|cloud| cloud.points.iter()) {
+        if point.x == 0.0 && point.y == 0.0 && point.z == 0.0 {
+            continue;
+        }
+        if config
+            .z_range
+            .is_some_and(|[min, max]| point.z < min || point.z > max)
+        {
+            continue;
+        }
+        let cell = (
+            (point.x / cell_size).floor() as i32,
+            (point.y / cell_size).floor() as i32,
+        );
+        cells.entry(cell).or_default().push(point);
+    }
+
+    let mut clusters = Vec::new();
+    let mut visited = HashSet::new();
+    for &start in cells.keys() {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut stack = vec![start];
+        let mut members = Vec::new();
+        while let Some((cx, cy)) = stack.pop() {
+            members.extend(cells[&(cx, cy)].iter().copied());
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    let neighbor = (cx + dx, cy + dy);
+                    if cells.contains_key(&neighbor) && visited.insert(neighbor) {
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+        if members.len() < config.min_points.max(1) {
+            continue;
+        }
+
+        let mut min = [f32::MAX; 2];
+        let mut max = [f32::MIN; 2];
+        let mut sum = [0.0f32; 2];
+        for point in &members {
+            min = [min[0].min(point.x), min[1].min(point.y)];
+            max = [max[0].max(point.x), max[1].max(point.y)];
+            sum = [sum[0] + point.x, sum[1] + point.y];
+        }
+        let count = members.len() as f32;
+        clusters.push(Cluster {
+            center: [sum[0] / count, sum[1] / count],
+            min,
+            max,
+            points: members.len(),
+        });
+    }
+    clusters
+}
@@ -0,0 +1,650 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use utoipa::ToSchema;
+
+use crate::lidar::LiDARKey;
+use crate::processing::occupancy::OccupancyEncoding;
+use crate::processing::odometry::Pose;
+use crate::processing::tf::TfTree;
+use crate::site::SiteConfig;
+use crate::ws::client::Roi;
+
+/// 포인트 클라우드 처리 설정 구조체
+///
+/// # Fields
+/// * `statistics` - 프레임 통계 스트림 설정
+/// * `occupancy` - 2D 점유 격자 스트림 설정
+/// * `reorder` - 여러 LiDAR 프레임 시각 순 정렬 설정
+/// * `deskew` - 포인트별 측정 시각 추정 및 움직임 왜곡 보정 설정
+/// * `odometry` - 클라이언트가 보내는 자기 위치/속도(odometry) 입력 설정
+/// * `tracking` - 물체 검출(클러스터링) 및 추적 설정
+/// * `counting` - 추적한 물체의 통과/진입 횟수 집계 설정
+/// * `calibration` - 두 LiDAR 사이 설치 자세 추정(캘리브레이션) 설정
+/// * `inference` - 프레임별 ONNX 모델 실행(엣지 추론) 설정
+/// * `voxel` - 여러 LiDAR 를 합칠 때 사용하는 복셀 누적 설정
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ProcessingConfig {
+    pub statistics: StatisticsConfig,
+    pub occupancy: OccupancyConfig,
+    pub reorder: ReorderConfig,
+    pub deskew: DeskewConfig,
+    pub odometry: OdometryConfig,
+    pub tracking: TrackingConfig,
+    pub counting: CountingConfig,
+    pub calibration: CalibrationConfig,
+    pub inference: InferenceConfig,
+    pub voxel: VoxelConfig,
+}
+
+impl ProcessingConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 실패 시 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        self.tracking
+            .validate()
+            .and_then(|_| self.counting.validate())
+            .and_then(|_| self.inference.validate())
+            .and_then(|_| self.voxel.validate())
+    }
+}
+
+/// 프레임 통계 설정 구조체
+///
+/// # Fields
+/// * `interval_ms` - 클라이언트별, LiDAR 별 통계 전송 최소 간격 (ms)
+/// * `sector_count` - 거리 통계를 계산할 수평 각도 구간 수 (360° 를 균등 분할)
+/// * `cell_size` - 점유 격자 요약에 사용할 격자 크기 (m)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct StatisticsConfig {
+    pub interval_ms: u64,
+    pub sector_count: usize,
+    pub cell_size: f32,
+}
+
+impl Default for StatisticsConfig {
+    fn default() -> Self {
+        Self {
+            interval_ms: 1000,
+            sector_count: 12,
+            cell_size: 0.5,
+        }
+    }
+}
+
+/// 2D 점유 격자 설정 구조체
+///
+/// # Fields
+/// * `resolution` - 격자 크기 (m)
+/// * `extent` - 격자 범위 `[min_x, min_y, max_x, max_y]` (m), 한 변은 최대 4096 격자
+/// * `fuse` - true 이면 구독 중인 모든 LiDAR 의 최신 프레임을 합쳐 하나의 격자 생성, false 이면 LiDAR 별 격자 생성
+/// * `ray_trace` - 센서 원점에서 포인트까지 지나는 격자를 빈 공간으로 표시할지 여부
+/// * `interval_ms` - 클라이언트별 격자 전송 최소 간격 (ms), LiDAR 별 격자는 LiDAR 마다 적용
+/// * `encoding` - 격자 인코딩 (`png` 또는 `raw`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct OccupancyConfig {
+    pub resolution: f32,
+    pub extent: [f32; 4],
+    pub fuse: bool,
+    pub ray_trace: bool,
+    pub interval_ms: u64,
+    pub encoding: OccupancyEncoding,
+}
+
+impl Default for OccupancyConfig {
+    fn default() -> Self {
+        Self {
+            resolution: 0.1,
+            extent: [-20.0, -20.0, 20.0, 20.0],
+            fuse: false,
+            ray_trace: true,
+            interval_ms: 200,
+            encoding: OccupancyEncoding::Png,
+        }
+    }
+}
+
+/// 프레임 정렬 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 전달 전에 프레임을 시각 순으로 정렬할지 여부
+/// * `max_delay_ms` - 늦게 도착하는 프레임을 기다리는 최대 시간 (ms), 전달 지연이 이만큼 늘어남
+/// * `max_pending` - 정렬 대기 최대 프레임 수, 넘으면 가장 이른 프레임부터 바로 전달
+/// * `skew` - LiDAR 별 시계 차이(알려진 전송 지연), 수신 시각에서 빼서 정렬
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ReorderConfig {
+    pub enabled: bool,
+    pub max_delay_ms: u64,
+    pub max_pending: usize,
+    pub skew: Vec<ClockSkew>,
+}
+
+impl Default for ReorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_delay_ms: 50,
+            max_pending: 1024,
+            skew: Vec::new(),
+        }
+    }
+}
+
+/// LiDAR 시계 차이 구조체
+///
+/// # Fields
+/// * `lidar` - LiDAR 키
+/// * `offset_ms` - 이 LiDAR 프레임이 다른 LiDAR 보다 늦게 도착하는 시간 (ms)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClockSkew {
+    pub lidar: LiDARKey,
+    pub offset_ms: u64,
+}
+
+/// 움직임 왜곡 보정(de-skew) 설정 구조체
+///
+/// # Fields
+/// * `scan_period_ms` - 한 번 수평 스캔에 걸리는 시간 (ms), 채널 데이터의 포인트(열)는 이 시간 동안 순서대로 측정된 것으로 봄
+/// * `velocity` - 외부에서 제공한 자기 속도 (LiDAR 좌표 기준), `deskew` 를 구독한 클라이언트에게 보정 적용
+///   (odometry 입력에 속도가 있으면 odometry 속도 사용)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct DeskewConfig {
+    pub scan_period_ms: f32,
+    pub velocity: EgoVelocity,
+}
+
+impl Default for DeskewConfig {
+    fn default() -> Self {
+        Self {
+            scan_period_ms: 100.0,
+            velocity: EgoVelocity::default(),
+        }
+    }
+}
+
+/// 자기 속도(ego-velocity) 구조체
+///
+/// # Fields
+/// * `linear` - 선속도 `[x, y, z]` (m/s, LiDAR 좌표)
+/// * `angular` - 각속도 `[x, y, z]` (rad/s, LiDAR 좌표 축 기준 회전)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct EgoVelocity {
+    pub linear: [f32; 3],
+    pub angular: [f32; 3],
+}
+
+/// 자기 위치/속도(odometry) 입력 설정 구조체
+///
+/// # Fields
+/// * `max_age_ms` - 프레임 시각과 가장 가까운 odometry 샘플의 최대 시각 차이 (ms), 넘으면 해당 샘플을 사용하지 않음
+/// * `history` - 보관할 최근 odometry 샘플 수 (프레임 시각의 위치 보간에 사용)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct OdometryConfig {
+    pub max_age_ms: u64,
+    pub history: usize,
+}
+
+impl Default for OdometryConfig {
+    fn default() -> Self {
+        Self {
+            max_age_ms: 200,
+            history: 256,
+        }
+    }
+}
+
+/// 물체 검출(클러스터링) 및 추적 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 물체 추적 사용 여부
+/// * `cell_size` - 포인트를 묶을 x, y 평면 격자 크기 (m), 이웃한 격자의 포인트는 한 물체로 봄
+/// * `min_points` - 물체로 볼 최소 포인트 수
+/// * `z_range` - 사용할 포인트 높이 범위 `[min, max]` (m, LiDAR 좌표), None 이면 모든 포인트 (바닥 제거용)
+/// * `max_distance` - 이전 프레임 물체와 같은 물체로 볼 최대 거리 (m, 예측 위치 기준)
+/// * `max_missed` - 물체가 보이지 않아도 유지할 최대 프레임 수
+/// * `min_hits` - 확정된 물체로 볼 최소 연속 검출 프레임 수 (집계는 확정된 물체만 사용)
+/// * `extrinsics` - LiDAR 별 설치 자세, 설정된 LiDAR 의 물체 속도와 방향은 월드 좌표로 계산
+/// * `tf_tree` - 설정 로드 시 읽을 TF 트리 파일 (JSON, `.yaml`/`.yml` 이면 YAML), 같은 LiDAR 는 `extrinsics` 보다 우선
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct TrackingConfig {
+    pub enabled: bool,
+    pub cell_size: f32,
+    pub min_points: usize,
+    pub z_range: Option<[f32; 2]>,
+    pub max_distance: f32,
+    pub max_missed: u32,
+    pub min_hits: u32,
+    pub extrinsics: Vec<Extrinsic>,
+    pub tf_tree: Option<PathBuf>,
+}
+
+impl Default for TrackingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cell_size: 0.3,
+            min_points: 5,
+            z_range: None,
+            max_distance: 1.5,
+            max_missed: 5,
+            min_hits: 3,
+            extrinsics: Vec::new(),
+            tf_tree: None,
+        }
+    }
+}
+
+impl TrackingConfig {
+    /// LiDAR 의 설치 자세
+    pub fn extrinsic(&self, key: &LiDARKey) -> Option<&Pose> {
+        self.extrinsics
+            .iter()
+            .find(|extrinsic| extrinsic.lidar == *key)
+            .map(|extrinsic| &extrinsic.pose)
+    }
+
+    /// 물체 추적 설정 유효성 검사 (LiDAR 별 설치 자세가 하나씩만 있는지, 자세 값이 유효한지 확인)
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, extrinsic) in self.extrinsics.iter().enumerate() {
+            let key = extrinsic.lidar;
+            if self.extrinsics[..index]
+                .iter()
+                .any(|other| other.lidar == key)
+            {
+                return Err(format!(
+                    "Duplicate tracking extrinsic for {}:{}",
+                    key.ip, key.port
+                ));
+            }
+            extrinsic
+                .pose
+                .validate()
+                .map_err(|e| format!("Tracking extrinsic for {}:{}: {}", key.ip, key.port, e))?;
+        }
+        Ok(())
+    }
+
+    /// `tf_tree` 파일을 읽어 `extrinsics` 에 반영
+    ///
+    /// # Arguments
+    /// * `sites` - 좌표계 이름으로 LiDAR 를 찾을 사이트 설정
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 파일을 읽을 수 없거나 트리가 잘못되면 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 각 LiDAR 좌표계의 월드 자세를 계산하여 같은 LiDAR 의 설정 값을 교체
+    pub fn import_tf_tree(&mut self, sites: &SiteConfig) -> Result<(), String> {
+        let Some(path) = &self.tf_tree else {
+            return Ok(());
+        };
+        let imported = TfTree::load(path)?
+            .resolve(sites)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        for extrinsic in imported {
+            self.extrinsics
+                .retain(|other| other.lidar != extrinsic.lidar);
+            self.extrinsics.push(extrinsic);
+        }
+        Ok(())
+    }
+}
+
+/// LiDAR 설치 자세(외부 파라미터) 구조체
+///
+/// # Examples
+/// ```json
+/// { "lidar": { "ip": "192.168.123.200", "port": 5000, "product_line": 1, "lidar_id": 0 }, "pose": { "position": [0.0, 0.0, 5.0], "orientation": [0.0, 0.0, 0.7071, 0.7071] } }
+/// ```
+///
+/// # Fields
+/// * `lidar` - LiDAR 키
+/// * `pose` - 월드 좌표에서 LiDAR 자세
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct Extrinsic {
+    pub lidar: LiDARKey,
+    pub pose: Pose,
+}
+
+/// 통과/진입 횟수 집계 설정 구조체
+///
+/// # Examples
+/// ```json
+/// {
+///     "tripwires": [ { "name": "gate", "from": [5.0, -3.0], "to": [5.0, 3.0] } ],
+///     "zones": [ { "name": "crosswalk", "zone": { "type": "box", "min": [8.0, -2.0], "max": [12.0, 2.0] } } ],
+///     "buckets": [ { "name": "person", "max_size": 1.0 }, { "name": "vehicle" } ]
+/// }
+/// ```
+///
+/// # Fields
+/// * `tripwires` - 방향이 있는 통과선 목록
+/// * `zones` - 진입/진출을 세는 영역 목록
+/// * `buckets` - 물체 크기 구분 (작은 것부터, 물체 크기가 `max_size` 이하인 첫 구분으로 집계, 해당 없으면 `other`)
+/// * `summary_interval_ms` - `count_summary` 이벤트 전송 주기 (ms)
+/// * `persist` - 일별 집계 디스크 보관 설정
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct CountingConfig {
+    pub tripwires: Vec<Tripwire>,
+    pub zones: Vec<CountZone>,
+    pub buckets: Vec<SizeBucket>,
+    pub summary_interval_ms: u64,
+    pub persist: CountPersistConfig,
+}
+
+impl Default for CountingConfig {
+    fn default() -> Self {
+        Self {
+            tripwires: Vec::new(),
+            zones: Vec::new(),
+            buckets: vec![
+                SizeBucket {
+                    name: "person".to_string(),
+                    max_size: Some(1.0),
+                },
+                SizeBucket {
+                    name: "car".to_string(),
+                    max_size: Some(5.5),
+                },
+                SizeBucket {
+                    name: "truck".to_string(),
+                    max_size: None,
+                },
+            ],
+            summary_interval_ms: 10_000,
+            persist: CountPersistConfig::default(),
+        }
+    }
+}
+
+impl CountingConfig {
+    /// 통과선 또는 영역이 있는지 확인
+    pub fn is_enabled(&self) -> bool {
+        !self.tripwires.is_empty() || !self.zones.is_empty()
+    }
+
+    /// 물체 크기에 해당하는 크기 구분 이름
+    pub fn bucket(&self, size: f32) -> &str {
+        self.buckets
+            .iter()
+            .find(|bucket| bucket.max_size.is_none_or(|max| size <= max))
+            .map_or("other", |bucket| bucket.name.as_str())
+    }
+
+    /// 설정 유효성 검사
+    ///
+    /// # 동작 설명
+    /// * 통과선/영역 이름 중복 확인 (집계 결과에서 이름으로 구분)
+    /// * 통과선 길이와 영역 형식 확인
+    pub fn validate(&self) -> Result<(), String> {
+        let names: Vec<&str> = self
+            .tripwires
+            .iter()
+            .map(|tripwire| tripwire.name.as_str())
+            .chain(self.zones.iter().map(|zone| zone.name.as_str()))
+            .collect();
+        for (index, name) in names.iter().enumerate() {
+            if names[..index].contains(name) {
+                return Err(format!("Duplicate counter name: {}", name));
+            }
+        }
+        for tripwire in &self.tripwires {
+            if tripwire.from == tripwire.to {
+                return Err(format!(
+                    "Tripwire {} needs two different points",
+                    tripwire.name
+                ));
+            }
+        }
+        for zone in &self.zones {
+            zone.zone
+                .validate()
+                .map_err(|e| format!("Counting zone {}: {}", zone.name, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// 방향이 있는 통과선 구조체
+///
+/// # Fields
+/// * `name` - 통과선 이름
+/// * `lidar` - 적용할 LiDAR 키, None 이면 모든 LiDAR (LiDAR 별로 따로 집계)
+/// * `from` - 시작점 `[x, y]` (m)
+/// * `to` - 끝점 `[x, y]` (m), `from` 에서 `to` 를 바라볼 때 왼쪽에서 오른쪽으로 지나가면 `forward`, 반대는 `backward`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Tripwire {
+    pub name: String,
+    #[serde(default)]
+    pub lidar: Option<LiDARKey>,
+    pub from: [f32; 2],
+    pub to: [f32; 2],
+}
+
+/// 진입/진출을 세는 영역 구조체
+///
+/// # Fields
+/// * `name` - 영역 이름
+/// * `lidar` - 적용할 LiDAR 키, None 이면 모든 LiDAR (LiDAR 별로 따로 집계)
+/// * `zone` - 영역 (구독 ROI 와 같은 형식), 들어오면 `forward`, 나가면 `backward`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CountZone {
+    pub name: String,
+    #[serde(default)]
+    pub lidar: Option<LiDARKey>,
+    pub zone: Roi,
+}
+
+/// 물체 크기 구분 구조체
+///
+/// # Fields
+/// * `name` - 구분 이름 (예: `person`, `car`)
+/// * `max_size` - 최대 크기 (m, 바닥 면적의 긴 변), None 이면 제한 없음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SizeBucket {
+    pub name: String,
+    #[serde(default)]
+    pub max_size: Option<f32>,
+}
+
+/// 일별 집계 디스크 보관 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 일별 집계를 디스크에 보관할지 여부 (재시작해도 그날 집계를 이어서 셈)
+/// * `dir` - 집계 파일 저장 디렉터리 (일 단위 `counts-<day>.jsonl` 파일)
+/// * `interval_ms` - 그날 집계를 저장하는 주기 (ms)
+/// * `retention_days` - 보관 기간 (일), 지난 파일은 삭제
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct CountPersistConfig {
+    pub enabled: bool,
+    pub dir: PathBuf,
+    pub interval_ms: u64,
+    pub retention_days: u64,
+}
+
+impl Default for CountPersistConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: PathBuf::from("counts"),
+            interval_ms: 60_000,
+            retention_days: 90,
+        }
+    }
+}
+
+/// 설치 자세 추정(캘리브레이션) 설정 구조체
+///
+/// # Fields
+/// * `enabled` - `/calibration/..` 엔드포인트 사용 여부
+/// * `capture_ms` - 스냅샷 수집 시간 (ms), 두 LiDAR 의 포인트를 같은 시간 구간 동안 모음
+/// * `max_points` - LiDAR 별 스냅샷 최대 포인트 수, 넘으면 일정 간격으로 골라 줄임
+/// * `max_snapshots` - 보관할 최근 스냅샷 수
+/// * `max_iterations` - ICP 기본 최대 반복 횟수
+/// * `max_distance` - ICP 기본 대응점 최대 거리 (m)
+/// * `tolerance` - ICP 수렴 기준 (한 번 반복의 이동 m, 회전 rad)
+/// * `extrinsics_file` - 저장한 설치 자세 파일 (`processing.tracking.extrinsics` 와 같은 형식의 JSON 배열),
+///   서버 시작 시 읽어서 물체 추적에 사용 (같은 LiDAR 는 파일 값 우선)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct CalibrationConfig {
+    pub enabled: bool,
+    pub capture_ms: u64,
+    pub max_points: usize,
+    pub max_snapshots: usize,
+    pub max_iterations: u32,
+    pub max_distance: f32,
+    pub tolerance: f32,
+    pub extrinsics_file: PathBuf,
+}
+
+impl Default for CalibrationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            capture_ms: 500,
+            max_points: 50_000,
+            max_snapshots: 8,
+            max_iterations: 50,
+            max_distance: 1.0,
+            tolerance: 1e-4,
+            extrinsics_file: PathBuf::from("extrinsics.json"),
+        }
+    }
+}
+
+/// 추론 모델 입력 종류 열거형
+///
+/// # Variants
+/// * `RangeImage` - 채널(행) × 수평 각도(열) 거리 영상, `max_range` 로 나눠 0 ~ 1 (포인트가 없으면 0)
+/// * `OccupancyGrid` - `processing.occupancy` 설정의 2D 점유 격자 (점유 1, 빈 공간 0, 미확인 0.5)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InferenceInput {
+    #[default]
+    RangeImage,
+    OccupancyGrid,
+}
+
+/// 엣지 추론 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 추론 사용 여부 (`onnx` feature 필요)
+/// * `model` - ONNX 모델 파일, 입력은 `[1, 1, 높이, 너비]` f32 텐서 하나, 출력은 f32 텐서
+/// * `input` - 모델 입력 종류
+/// * `interval_ms` - LiDAR 별 추론 최소 간격 (ms), 이전 추론이 끝나지 않았으면 프레임을 건너뜀
+/// * `rows` - 거리 영상 행 수 (채널 수, 넘는 채널은 무시)
+/// * `columns` - 거리 영상 열 수 (360° 를 균등 분할)
+/// * `max_range` - 거리 영상 정규화 거리 (m), 더 먼 포인트는 1
+/// * `labels` - 첫 번째 출력의 분류 이름 목록, 출력 크기와 같으면 가장 높은 값의 이름과 값을 함께 전송
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct InferenceConfig {
+    pub enabled: bool,
+    pub model: PathBuf,
+    pub input: InferenceInput,
+    pub interval_ms: u64,
+    pub rows: usize,
+    pub columns: usize,
+    pub max_range: f32,
+    pub labels: Vec<String>,
+}
+
+impl Default for InferenceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            model: PathBuf::from("model.onnx"),
+            input: InferenceInput::RangeImage,
+            interval_ms: 500,
+            rows: 16,
+            columns: 512,
+            max_range: 100.0,
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl InferenceConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 거리 영상 크기나 정규화 거리가 0 이하이면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled || self.input != InferenceInput::RangeImage {
+            return Ok(());
+        }
+        if self.rows == 0 || self.columns == 0 {
+            return Err("inference rows and columns must be positive".to_string());
+        }
+        if self.max_range.is_nan() || self.max_range <= 0.0 {
+            return Err("inference max_range must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 복셀 좌표 계산 방식 열거형
+///
+/// # Variants
+/// * `Cpu` - CPU 에서 계산 (벡터화하기 쉬운 반복문)
+/// * `Gpu` - wgpu compute shader 로 계산 (`gpu` feature 필요), 사용할 수 없으면 CPU 로 대체
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VoxelBackend {
+    #[default]
+    Cpu,
+    Gpu,
+}
+
+/// 복셀 누적 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 여러 LiDAR 프레임을 합칠 때(`occupancy.fuse`) 복셀마다 포인트 하나(중심)로 줄인 뒤 사용할지 여부
+/// * `size` - 복셀 한 변의 길이 (m)
+/// * `backend` - 복셀 좌표 계산 방식
+/// * `gpu_min_points` - GPU 로 계산할 최소 포인트 수, 더 적으면 전송 비용이 커서 CPU 로 계산
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct VoxelConfig {
+    pub enabled: bool,
+    pub size: f32,
+    pub backend: VoxelBackend,
+    pub gpu_min_points: usize,
+}
+
+impl Default for VoxelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            size: 0.1,
+            backend: VoxelBackend::Cpu,
+            gpu_min_points: 20_000,
+        }
+    }
+}
+
+impl VoxelConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 복셀 크기가 0 이하이면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        if self.enabled && (self.size.is_nan() || self.size <= 0.0) {
+            return Err("voxel size must be positive".to_string());
+        }
+        Ok(())
+    }
+}
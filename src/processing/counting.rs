@@ -0,0 +1,364 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use tokio::sync::{mpsc, Mutex};
+use tracing::*;
+use utoipa::ToSchema;
+
+use crate::common::daylog::{read_days, DayWriter, DAY_MS};
+use crate::common::time::unix_time_ms;
+use crate::lidar::{LiDARKey, Point};
+use crate::processing::config::CountingConfig;
+use crate::processing::tracker::TrackStep;
+
+/// 일별 집계 파일 이름 접두어
+pub const COUNTS_PREFIX: &str = "counts-";
+
+/// 마지막 이동 후 이 시간 (ms) 이 지난 물체의 통과/진입 기록은 삭제
+const TRACK_MEMO_TTL_MS: u64 = 60_000;
+
+/// 통과선/영역 하나의 LiDAR 별 집계 구조체
+///
+/// # Fields
+/// * `counter` - 통과선 또는 영역 이름
+/// * `key` - LiDAR 키
+/// * `forward` - 크기 구분별 횟수 (통과선: 왼쪽 → 오른쪽 통과, 영역: 진입)
+/// * `backward` - 크기 구분별 횟수 (통과선: 오른쪽 → 왼쪽 통과, 영역: 진출)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct CountTotals {
+    pub counter: String,
+    pub key: LiDARKey,
+    pub forward: BTreeMap<String, u64>,
+    pub backward: BTreeMap<String, u64>,
+}
+
+/// 하루 집계 구조체 (`count_summary` 이벤트, `/counts` 응답, 일별 집계 파일의 한 줄)
+///
+/// # Examples
+/// ```json
+/// { "day": 19675, "time_ms": 1700000000000, "counters": [ { "counter": "gate", "key": { ... }, "forward": { "person": 12 }, "backward": { "car": 3 } } ] }
+/// ```
+///
+/// # Fields
+/// * `day` - 집계 날짜 (Unix time 기준 일 수, UTC)
+/// * `time_ms` - 집계 시각 (Unix time, ms)
+/// * `counters` - 통과선/영역, LiDAR 별 집계 (그날 한 번 이상 센 것만 포함)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct CountRollup {
+    pub day: u64,
+    pub time_ms: u64,
+    pub counters: Vec<CountTotals>,
+}
+
+/// 물체별 통과/진입 기록
+#[derive(Default)]
+struct TrackMemo {
+    crossed: HashSet<usize>,
+    inside: HashSet<usize>,
+    last_ms: u64,
+}
+
+/// 집계 내부 상태
+#[derive(Default)]
+struct CountState {
+    day: u64,
+    totals: HashMap<(String, LiDARKey), CountTotals>,
+    tracks: HashMap<(LiDARKey, u64), TrackMemo>,
+}
+
+impl CountState {
+    fn rollup(&self) -> CountRollup {
+        let mut counters: Vec<CountTotals> = self.totals.values().cloned().collect();
+        counters.sort_by(|a, b| {
+            (&a.counter, a.key.ip, a.key.port, a.key.lidar_id).cmp(&(
+                &b.counter,
+                b.key.ip,
+                b.key.port,
+                b.key.lidar_id,
+            ))
+        });
+        CountRollup {
+            day: self.day,
+            time_ms: unix_time_ms(),
+            counters,
+        }
+    }
+
+    fn add(&mut self, counter: &str, key: LiDARKey, bucket: &str, forward: bool) {
+        let totals = self
+            .totals
+            .entry((counter.to_string(), key))
+            .or_insert_with(|| CountTotals {
+                counter: counter.to_string(),
+                key,
+                forward: BTreeMap::new(),
+                backward: BTreeMap::new(),
+            });
+        let counts = if forward {
+            &mut totals.forward
+        } else {
+            &mut totals.backward
+        };
+        *counts.entry(bucket.to_string()).or_default() += 1;
+    }
+}
+
+/// 통과선/영역 통과 횟수 집계기
+///
+/// # Examples
+/// ```
+/// let counts = CountStore::start(&config.processing.counting)?;
+/// counts.observe(&steps).await;
+/// let rollup = counts.summary().await;
+/// ```
+///
+/// # 동작 설명
+/// * 확정된 물체의 이동이 통과선을 지나면 방향과 크기 구분별로 한 번 셈 (같은 물체는 통과선마다 한 번만 셈)
+/// * 물체 위치가 영역 밖에서 안으로 들어오면 진입, 안에서 밖으로 나가면 진출로 셈
+/// * 날짜(UTC)가 바뀌면 전날 집계를 저장한 뒤 0 부터 다시 셈
+/// * `persist.enabled` 이면 `interval_ms` 마다 그날 집계를 `counts-<day>.jsonl` 에 한 줄씩 추가하고,
+///   시작 시 오늘 마지막 집계를 읽어 이어서 셈
+pub struct CountStore {
+    config: CountingConfig,
+    state: Mutex<CountState>,
+    dir: Option<PathBuf>,
+    tx: Option<mpsc::Sender<CountRollup>>,
+}
+
+impl CountStore {
+    /// 집계 시작
+    ///
+    /// # Arguments
+    /// * `config` - 집계 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 집계기, 집계 보관 디렉터리 생성 실패 시 에러 메시지
+    pub fn start(config: &CountingConfig) -> Result<Self, String> {
+        let today = unix_time_ms() / DAY_MS;
+        let mut state = CountState {
+            day: today,
+            ..Default::default()
+        };
+        if !config.persist.enabled {
+            return Ok(Self {
+                config: config.clone(),
+                state: Mutex::new(state),
+                dir: None,
+                tx: None,
+            });
+        }
+
+        let dir = config.persist.dir.clone();
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        match last_rollup(&dir, today) {
+            Ok(Some(rollup)) => {
+                info!("Resuming {} counters for today", rollup.counters.len());
+                for totals in rollup.counters {
+                    state
+                        .totals
+                        .insert((totals.counter.clone(), totals.key), totals);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to load today's counts: {}", e),
+        }
+
+        let (tx, mut rx) = mpsc::channel::<CountRollup>(16);
+        let mut writer = DayWriter::new(dir.clone(), COUNTS_PREFIX, config.persist.retention_days);
+        writer.prune();
+        std::thread::Builder::new()
+            .name("counts".to_string())
+            .spawn(move || {
+                while let Some(rollup) = rx.blocking_recv() {
+                    if let Err(e) = writer.write(&[rollup]) {
+                        error!("Failed to store counts: {}", e);
+                    }
+                }
+            })
+            .map_err(|e| format!("Failed to spawn counts thread: {}", e))?;
+
+        info!(
+            "Storing daily counts to {} ({} days)",
+            dir.display(),
+            config.persist.retention_days
+        );
+        Ok(Self {
+            config: config.clone(),
+            state: Mutex::new(state),
+            dir: Some(dir),
+            tx: Some(tx),
+        })
+    }
+
+    pub fn config(&self) -> &CountingConfig {
+        &self.config
+    }
+
+    /// 물체 이동으로 통과/진입 집계
+    ///
+    /// # Arguments
+    /// * `steps` - 이번 프레임의 확정 물체 이동
+    pub async fn observe(&self, steps: &[TrackStep]) {
+        if steps.is_empty() {
+            return;
+        }
+        let mut state = self.state.lock().await;
+        self.roll_over(&mut state);
+        for step in steps {
+            let bucket = self.config.bucket(step.size);
+            let mut memo = state
+                .tracks
+                .remove(&(step.key, step.id))
+                .unwrap_or_default();
+            memo.last_ms = step.time_ms;
+
+            for (index, tripwire) in self.config.tripwires.iter().enumerate() {
+                if tripwire.lidar.is_some_and(|lidar| lidar != step.key)
+                    || memo.crossed.contains(&index)
+                {
+                    continue;
+                }
+                if let Some(forward) =
+                    crossing(tripwire.from, tripwire.to, step.previous, step.position)
+                {
+                    memo.crossed.insert(index);
+                    state.add(&tripwire.name, step.key, bucket, forward);
+                }
+            }
+
+            let position = Point {
+                x: step.position[0],
+                y: step.position[1],
+                z: 0.0,
+            };
+            for (index, zone) in self.config.zones.iter().enumerate() {
+                if zone.lidar.is_some_and(|lidar| lidar != step.key) {
+                    continue;
+                }
+                let inside = zone.zone.contains(&position);
+                if inside == memo.inside.contains(&index) {
+                    continue;
+                }
+                if inside {
+                    memo.inside.insert(index);
+                } else {
+                    memo.inside.remove(&index);
+                }
+                state.add(&zone.name, step.key, bucket, inside);
+            }
+            state.tracks.insert((step.key, step.id), memo);
+        }
+    }
+
+    /// 오늘 집계 (`count_summary` 이벤트)
+    ///
+    /// # 동작 설명
+    /// * 날짜가 바뀌었으면 전날 집계를 저장하고 초기화
+    /// * 오래 이동하지 않은 물체의 통과/진입 기록 정리
+    pub async fn summary(&self) -> CountRollup {
+        let mut state = self.state.lock().await;
+        self.roll_over(&mut state);
+        let now_ms = unix_time_ms();
+        state
+            .tracks
+            .retain(|_, memo| now_ms.saturating_sub(memo.last_ms) < TRACK_MEMO_TTL_MS);
+        state.rollup()
+    }
+
+    /// 오늘 집계를 일별 집계 파일에 저장 (`persist.enabled` 일 때)
+    pub async fn persist(&self) {
+        let rollup = self.summary().await;
+        self.store(rollup);
+    }
+
+    /// 날짜별 집계 조회
+    ///
+    /// # Arguments
+    /// * `day` - 날짜 (Unix time 기준 일 수), None 이면 오늘
+    ///
+    /// # Returns
+    /// * `Result<Option<CountRollup>, String>` - 성공 시 그날 마지막 집계 (저장된 집계가 없으면 None),
+    ///   읽기 실패 시 에러 메시지
+    pub async fn query(&self, day: Option<u64>) -> Result<Option<CountRollup>, String> {
+        let today = unix_time_ms() / DAY_MS;
+        let day = day.unwrap_or(today);
+        if day == today {
+            return Ok(Some(self.summary().await));
+        }
+        let Some(dir) = self.dir.clone() else {
+            return Ok(None);
+        };
+        tokio::task::spawn_blocking(move || last_rollup(&dir, day))
+            .await
+            .map_err(|e| e.to_string())?
+    }
+
+    /// 날짜가 바뀌었으면 전날 집계를 저장하고 초기화
+    fn roll_over(&self, state: &mut CountState) {
+        let today = unix_time_ms() / DAY_MS;
+        if state.day == today {
+            return;
+        }
+        if !state.totals.is_empty() {
+            self.store(state.rollup());
+        }
+        state.day = today;
+        state.totals.clear();
+    }
+
+    fn store(&self, rollup: CountRollup) {
+        let Some(tx) = &self.tx else {
+            return;
+        };
+        if let Err(mpsc::error::TrySendError::Full(_)) = tx.try_send(rollup) {
+            warn!("Counts queue full, dropping rollup");
+        }
+    }
+}
+
+/// 저장된 날짜의 마지막 집계 읽기 (자정 직후 저장한 전날 집계는 다음 날 파일에 있음)
+fn last_rollup(dir: &std::path::Path, day: u64) -> Result<Option<CountRollup>, String> {
+    let from_ms = day * DAY_MS;
+    let to_ms = from_ms + 2 * DAY_MS - 1;
+    let rollups = read_days(
+        dir,
+        COUNTS_PREFIX,
+        from_ms,
+        to_ms,
+        |rollup: &CountRollup| rollup.day == day,
+    )?;
+    Ok(rollups.into_iter().max_by_key(|rollup| rollup.time_ms))
+}
+
+/// 물체 이동이 통과선을 지났는지 확인
+///
+/// # Arguments
+/// * `from`, `to` - 통과선 시작점, 끝점
+/// * `previous`, `position` - 물체의 이전 위치, 현재 위치
+///
+/// # Returns
+/// * `Option<bool>` - 지났으면 방향 (`from` → `to` 를 바라볼 때 왼쪽에서 오른쪽이면 true), 아니면 None
+pub fn crossing(
+    from: [f32; 2],
+    to: [f32; 2],
+    previous: [f32; 2],
+    position: [f32; 2],
+) -> Option<bool> {
+    let cross = |origin: [f32; 2], end: [f32; 2], point: [f32; 2]| {
+        (end[0] - origin[0]) * (point[1] - origin[1])
+            - (end[1] - origin[1]) * (point[0] - origin[0])
+    };
+    // 통과선 기준 왼쪽이면 양수
+    let before = cross(from, to, previous) > 0.0;
+    let after = cross(from, to, position) > 0.0;
+    if before == after {
+        return None;
+    }
+    // 이동 경로 기준으로 통과선 양 끝이 서로 다른 쪽에 있어야 선분 안에서 지난 것
+    if (cross(previous, position, from) > 0.0) == (cross(previous, position, to) > 0.0) {
+        return None;
+    }
+    Some(before)
+}
@@ -0,0 +1,56 @@
+use crate::lidar::Point;
+use crate::processing::config::{DeskewConfig, EgoVelocity};
+
+impl DeskewConfig {
+    /// 채널 데이터에서 포인트의 측정 시각 (스캔 시작 기준, µs)
+    ///
+    /// # Arguments
+    /// * `index` - 채널 데이터에서 포인트(열) 번호
+    /// * `count` - 채널 데이터의 포인트 수
+    pub fn offset_us(&self, index: usize, count: usize) -> u32 {
+        if count == 0 {
+            return 0;
+        }
+        (self.scan_period_ms.max(0.0) * 1000.0 * index as f32 / count as f32) as u32
+    }
+
+    /// 스캔 시작 시각 (Unix time, ms)
+    ///
+    /// # Arguments
+    /// * `time_ms` - 채널 데이터 수신 시각, 스캔이 끝난 시각으로 봄
+    pub fn scan_start_ms(&self, time_ms: u64) -> u64 {
+        time_ms.saturating_sub(self.scan_period_ms.max(0.0) as u64)
+    }
+
+    /// 포인트를 스캔이 끝난 시각의 LiDAR 좌표로 보정
+    ///
+    /// # Arguments
+    /// * `point` - 측정 시각의 LiDAR 좌표 포인트
+    /// * `index` - 채널 데이터에서 포인트(열) 번호
+    /// * `count` - 채널 데이터의 포인트 수
+    /// * `velocity` - 스캔 동안 일정하다고 보는 자기 속도
+    ///
+    /// # Returns
+    /// * `Point` - 보정한 포인트
+    ///
+    /// # 동작 설명
+    /// * 측정 시각부터 스캔 끝까지의 시간 `dt` 동안 LiDAR 가 움직인 만큼 되돌림:
+    ///   `p' = p - (v + ω × p) * dt` (한 스캔 동안의 회전은 작다고 보고 1차 근사)
+    pub fn deskew(
+        &self,
+        point: &Point,
+        index: usize,
+        count: usize,
+        velocity: &EgoVelocity,
+    ) -> Point {
+        let elapsed_s =
+            (self.scan_period_ms.max(0.0) * 1000.0 - self.offset_us(index, count) as f32) / 1e6;
+        let [vx, vy, vz] = velocity.linear;
+        let [wx, wy, wz] = velocity.angular;
+        Point {
+            x: point.x - (vx + wy * point.z - wz * point.y) * elapsed_s,
+            y: point.y - (vy + wz * point.x - wx * point.z) * elapsed_s,
+            z: point.z - (vz + wx * point.y - wy * point.x) * elapsed_s,
+        }
+    }
+}
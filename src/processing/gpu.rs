@@ -0,0 +1,210 @@
+use std::sync::Mutex;
+use wgpu::util::DeviceExt;
+
+use crate::lidar::Point;
+use crate::processing::voxel::VoxelKey;
+
+/// 작업 그룹 크기 (셰이더의 `@workgroup_size` 와 같아야 함)
+const WORKGROUP_SIZE: u32 = 256;
+
+/// 포인트별 복셀 좌표 계산 셰이더
+const SHADER: &str = r#"
+struct Params {
+    inverse: f32,
+    count: u32,
+    _pad0: u32,
+    _pad1: u32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> points: array<vec4<f32>>;
+@group(0) @binding(2) var<storage, read_write> keys: array<vec4<i32>>;
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.x;
+    if (i >= params.count) {
+        return;
+    }
+    keys[i] = vec4<i32>(vec3<i32>(floor(points[i].xyz * params.inverse)), 0);
+}
+"#;
+
+/// wgpu compute shader 복셀 좌표 계산기
+///
+/// # 동작 설명
+/// * 포인트를 `vec4<f32>` 로 올려 포인트마다 하나의 invocation 이 복셀 좌표를 계산하고, 결과를 읽어 옴
+/// * 호출마다 버퍼를 만들고 완료될 때까지 기다림 (blocking), 한 번에 하나의 계산만 진행
+/// * 버퍼 크기가 장치 제한을 넘으면 에러를 반환하여 CPU 로 계산하도록 함
+pub struct GpuVoxelizer {
+    name: String,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    lock: Mutex<()>,
+}
+
+impl GpuVoxelizer {
+    /// GPU 장치 초기화 및 셰이더 컴파일
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 계산기, 사용할 수 있는 GPU 가 없으면 에러 메시지
+    pub async fn new() -> Result<Self, String> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await
+            .ok_or_else(|| "No GPU adapter found".to_string())?;
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("voxel"),
+                    required_limits: wgpu::Limits::downlevel_defaults(),
+                    ..Default::default()
+                },
+                None,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("voxel"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("voxel"),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+        Ok(Self {
+            name: adapter.get_info().name,
+            device,
+            queue,
+            pipeline,
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// GPU 어댑터 이름
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 포인트별 복셀 좌표 계산
+    ///
+    /// # Arguments
+    /// * `points` - 포인트 목록
+    /// * `size` - 복셀 한 변의 길이 (m)
+    ///
+    /// # Returns
+    /// * `Result<Vec<VoxelKey>, String>` - 성공 시 포인트 순서대로 복셀 좌표, 포인트가 너무 많거나 GPU 실행에 실패하면 에러 메시지
+    pub fn voxel_keys(&self, points: &[Point], size: f32) -> Result<Vec<VoxelKey>, String> {
+        if points.is_empty() {
+            return Ok(Vec::new());
+        }
+        let len = (points.len() * 16) as u64;
+        let limits = self.device.limits();
+        let groups = (points.len() as u32).div_ceil(WORKGROUP_SIZE);
+        if len > limits.max_storage_buffer_binding_size as u64
+            || groups > limits.max_compute_workgroups_per_dimension
+        {
+            return Err(format!("Too many points for GPU ({})", points.len()));
+        }
+        let _guard = self.lock.lock().map_err(|e| e.to_string())?;
+
+        let mut params = Vec::with_capacity(16);
+        params.extend((1.0 / size).to_le_bytes());
+        params.extend((points.len() as u32).to_le_bytes());
+        params.extend([0; 8]);
+        let params = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("voxel params"),
+                contents: &params,
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let input: Vec<u8> = points
+            .iter()
+            .flat_map(|point| [point.x, point.y, point.z, 0.0])
+            .flat_map(f32::to_le_bytes)
+            .collect();
+        let input = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("voxel points"),
+                contents: &input,
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        let output = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("voxel keys"),
+            size: len,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("voxel staging"),
+            size: len,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("voxel"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: input.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: output.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(groups, 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&output, 0, &staging, 0, len);
+        self.queue.submit([encoder.finish()]);
+
+        let slice = staging.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            _ = tx.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        rx.recv()
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())?;
+
+        let keys = {
+            let data = slice.get_mapped_range();
+            data.chunks_exact(16)
+                .map(|key| {
+                    let axis =
+                        |i: usize| i32::from_le_bytes(key[i * 4..i * 4 + 4].try_into().unwrap());
+                    [axis(0), axis(1), axis(2)]
+                })
+                .collect()
+        };
+        staging.unmap();
+        Ok(keys)
+    }
+}
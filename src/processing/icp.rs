@@ -0,0 +1,278 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::lidar::Point;
+use crate::processing::odometry::Pose;
+
+/// 점-점 ICP 정합 파라미터 구조체
+///
+/// # Fields
+/// * `max_iterations` - 최대 반복 횟수
+/// * `max_distance` - 대응점으로 볼 최대 거리 (m)
+/// * `tolerance` - 한 번 반복의 이동(m)과 회전(rad)이 모두 이 값보다 작으면 수렴으로 봄
+#[derive(Debug, Clone, Copy)]
+pub struct IcpParams {
+    pub max_iterations: u32,
+    pub max_distance: f32,
+    pub tolerance: f32,
+}
+
+/// ICP 정합 결과 구조체
+///
+/// # Fields
+/// * `transform` - `source` 포인트를 `target` 좌표로 옮기는 변환
+/// * `fitness` - `max_distance` 안에 대응점이 있는 `source` 포인트 비율 (0 ~ 1)
+/// * `rmse` - 대응점 사이 거리의 제곱 평균 제곱근 (m)
+/// * `correspondences` - 대응점 수
+/// * `iterations` - 반복 횟수
+/// * `converged` - `tolerance` 안으로 수렴했는지 여부
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct Alignment {
+    pub transform: Pose,
+    pub fitness: f32,
+    pub rmse: f32,
+    pub correspondences: usize,
+    pub iterations: u32,
+    pub converged: bool,
+}
+
+/// 점-점 ICP 로 두 포인트 집합 정합
+///
+/// # Examples
+/// ```
+/// let alignment = align(&source, &target, &Pose::IDENTITY, &params);
+/// println!("fitness {:.2}, rmse {:.3} m", alignment.fitness, alignment.rmse);
+/// ```
+///
+/// # Arguments
+/// * `source` - 옮길 포인트 (예: 보정할 LiDAR)
+/// * `target` - 기준 포인트 (예: 기준 LiDAR)
+/// * `initial` - 초기 변환 추정값
+/// * `params` - 정합 파라미터
+///
+/// # Returns
+/// * `Alignment` - 추정한 변환과 정합 지표
+///
+/// # 동작 설명
+/// * `target` 을 `max_distance` 크기 3D 격자로 나누어 가장 가까운 포인트를 찾음
+/// * 대응점 쌍으로 최적 회전(Horn 의 쿼터니언 방법)과 이동을 구하고 변환에 누적
+/// * 대응점이 3 개 미만이면 중단 (수렴하지 않음)
+pub fn align(source: &[Point], target: &[Point], initial: &Pose, params: &IcpParams) -> Alignment {
+    let max_distance = params.max_distance.max(f32::EPSILON);
+    let grid = NeighborGrid::new(target, max_distance);
+    let mut transform = initial.compose(&Pose::IDENTITY);
+    let mut iterations = 0;
+    let mut converged = false;
+    while iterations < params.max_iterations {
+        let pairs = correspond(source, &grid, &transform, max_distance);
+        if pairs.len() < 3 {
+            break;
+        }
+        iterations += 1;
+        let delta = best_fit(&pairs);
+        transform = delta.compose(&transform);
+
+        let moved = delta.position.iter().map(|v| v * v).sum::<f32>().sqrt();
+        let turned = 2.0 * delta.orientation[3].abs().min(1.0).acos();
+        if moved < params.tolerance && turned < params.tolerance {
+            converged = true;
+            break;
+        }
+    }
+
+    let pairs = correspond(source, &grid, &transform, max_distance);
+    let squared: f32 = pairs.iter().map(|(p, q)| distance_squared(p, q)).sum();
+    Alignment {
+        transform,
+        fitness: if source.is_empty() {
+            0.0
+        } else {
+            pairs.len() as f32 / source.len() as f32
+        },
+        rmse: if pairs.is_empty() {
+            0.0
+        } else {
+            (squared / pairs.len() as f32).sqrt()
+        },
+        correspondences: pairs.len(),
+        iterations,
+        converged,
+    }
+}
+
+/// 가장 가까운 포인트 검색용 3D 격자
+struct NeighborGrid<'a> {
+    points: &'a [Point],
+    cell_size: f32,
+    cells: HashMap<(i32, i32, i32), Vec<usize>>,
+}
+
+impl<'a> NeighborGrid<'a> {
+    fn new(points: &'a [Point], cell_size: f32) -> Self {
+        let mut cells: HashMap<(i32, i32, i32), Vec<usize>> = HashMap::new();
+        for (index, point) in points.iter().enumerate() {
+            cells.entry(cell(point, cell_size)).or_default().push(index);
+        }
+        Self {
+            points,
+            cell_size,
+            cells,
+        }
+    }
+
+    /// `max_distance` 안에서 가장 가까운 포인트 (격자 크기가 `max_distance` 이므로 이웃 격자만 확인)
+    fn nearest(&self, point: &Point, max_distance: f32) -> Option<&'a Point> {
+        let (cx, cy, cz) = cell(point, self.cell_size);
+        let mut best = None;
+        let mut best_distance = max_distance * max_distance;
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(indices) = self.cells.get(&(cx + dx, cy + dy, cz + dz)) else {
+                        continue;
+                    };
+                    for &index in indices {
+                        let candidate = &self.points[index];
+                        let distance = distance_squared(point, candidate);
+                        if distance <= best_distance {
+                            best_distance = distance;
+                            best = Some(candidate);
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+}
+
+fn cell(point: &Point, cell_size: f32) -> (i32, i32, i32) {
+    (
+        (point.x / cell_size).floor() as i32,
+        (point.y / cell_size).floor() as i32,
+        (point.z / cell_size).floor() as i32,
+    )
+}
+
+/// 현재 변환으로 옮긴 `source` 포인트와 가장 가까운 `target` 포인트 쌍
+fn correspond(
+    source: &[Point],
+    grid: &NeighborGrid,
+    transform: &Pose,
+    max_distance: f32,
+) -> Vec<(Point, Point)> {
+    source
+        .iter()
+        .filter_map(|point| {
+            let moved = transform.apply(point);
+            grid.nearest(&moved, max_distance)
+                .map(|nearest| (moved, nearest.clone()))
+        })
+        .collect()
+}
+
+fn distance_squared(a: &Point, b: &Point) -> f32 {
+    (a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)
+}
+
+/// 대응점 쌍 `(p, q)` 에서 `q ≈ R p + t` 인 최적 변환 (Horn, 1987)
+fn best_fit(pairs: &[(Point, Point)]) -> Pose {
+    let count = pairs.len() as f32;
+    let mut p_mean = [0.0f32; 3];
+    let mut q_mean = [0.0f32; 3];
+    for (p, q) in pairs {
+        p_mean = [p_mean[0] + p.x, p_mean[1] + p.y, p_mean[2] + p.z];
+        q_mean = [q_mean[0] + q.x, q_mean[1] + q.y, q_mean[2] + q.z];
+    }
+    p_mean = p_mean.map(|v| v / count);
+    q_mean = q_mean.map(|v| v / count);
+
+    // 교차 공분산 S[i][j] = Σ p_i q_j
+    let mut s = [[0.0f32; 3]; 3];
+    for (p, q) in pairs {
+        let p = [p.x - p_mean[0], p.y - p_mean[1], p.z - p_mean[2]];
+        let q = [q.x - q_mean[0], q.y - q_mean[1], q.z - q_mean[2]];
+        for (row, pi) in s.iter_mut().zip(p) {
+            for (value, qj) in row.iter_mut().zip(q) {
+                *value += pi * qj;
+            }
+        }
+    }
+    let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+    let n = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+    ];
+    // 가장 큰 고유값의 고유벡터가 회전 쿼터니언 [w, x, y, z]
+    let [w, x, y, z] = largest_eigenvector(n);
+    let rotation = Pose {
+        position: [0.0; 3],
+        orientation: [x, y, z, w],
+    };
+    let rotated = rotation.apply(&Point {
+        x: p_mean[0],
+        y: p_mean[1],
+        z: p_mean[2],
+    });
+    Pose {
+        position: [
+            q_mean[0] - rotated.x,
+            q_mean[1] - rotated.y,
+            q_mean[2] - rotated.z,
+        ],
+        orientation: rotation.orientation,
+    }
+}
+
+/// 4x4 대칭 행렬의 가장 큰 고유값에 해당하는 단위 고유벡터 (Jacobi 방법)
+fn largest_eigenvector(mut a: [[f32; 4]; 4]) -> [f32; 4] {
+    let mut v = [[0.0f32; 4]; 4];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    for _ in 0..50 {
+        let off: f32 = (0..4)
+            .flat_map(|i| (i + 1..4).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        if off < 1e-12 {
+            break;
+        }
+        for p in 0..4 {
+            for q in p + 1..4 {
+                if a[p][q].abs() < 1e-12 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                // A ← Jᵀ A J, V ← V J
+                for row in a.iter_mut().chain(v.iter_mut()) {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (upper, lower) = a.split_at_mut(q);
+                for (pk, qk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    let (apk, aqk) = (*pk, *qk);
+                    *pk = c * apk - s * aqk;
+                    *qk = s * apk + c * aqk;
+                }
+            }
+        }
+    }
+    let largest = (0..4)
+        .max_by(|&i, &j| a[i][i].total_cmp(&a[j][j]))
+        .unwrap_or(0);
+    let vector = [v[0][largest], v[1][largest], v[2][largest], v[3][largest]];
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm < 1e-6 {
+        return [1.0, 0.0, 0.0, 0.0];
+    }
+    vector.map(|v| v / norm)
+}
@@ -0,0 +1,105 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+
+use crate::lidar::{LiDARKey, PointCloud};
+use crate::processing::occupancy::{Cell, OccupancyGrid};
+
+/// 모델 출력 텐서 구조체
+///
+/// # Fields
+/// * `shape` - 텐서 모양
+/// * `values` - 행 우선 순서로 펼친 값
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct InferenceOutput {
+    pub shape: Vec<usize>,
+    pub values: Vec<f32>,
+}
+
+/// 프레임 추론 결과 구조체 (`inference` 이벤트)
+///
+/// # Examples
+/// ```json
+/// { "key": { ... }, "time_ms": 1700000000000, "outputs": [ { "shape": [1, 3], "values": [0.1, 0.8, 0.1] } ], "label": "person", "score": 0.8 }
+/// ```
+///
+/// # Fields
+/// * `key` - 프레임을 보낸 LiDAR 키
+/// * `time_ms` - 프레임 수신 시각 (ms, UNIX epoch)
+/// * `outputs` - 모델 출력 순서대로 출력 텐서
+/// * `label` - `labels` 설정 시 첫 번째 출력에서 가장 높은 값의 분류 이름
+/// * `score` - `label` 의 출력 값
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct InferenceResult {
+    pub key: LiDARKey,
+    pub time_ms: u64,
+    pub outputs: Vec<InferenceOutput>,
+    pub label: Option<String>,
+    pub score: Option<f32>,
+}
+
+/// 채널별 포인트 클라우드를 거리 영상으로 변환
+///
+/// # Arguments
+/// * `points` - 채널별 포인트 클라우드 (채널 순서가 행 순서)
+/// * `rows` - 행 수 (넘는 채널은 무시)
+/// * `columns` - 열 수 (-180° 부터 180° 까지 균등 분할)
+/// * `max_range` - 정규화 거리 (m)
+///
+/// # Returns
+/// * `Vec<f32>` - `rows × columns` 행 우선 거리 영상, 격자마다 가장 가까운 포인트의 거리 / `max_range` (최대 1, 포인트가 없으면 0)
+pub fn range_image(points: &[PointCloud], rows: usize, columns: usize, max_range: f32) -> Vec<f32> {
+    let mut image = vec![0.0; rows * columns];
+    for (row, cloud) in points.iter().take(rows).enumerate() {
+        for point in &cloud.points {
+            let range = (point.x * point.x + point.y * point.y + point.z * point.z).sqrt();
+            if !range.is_finite() || range == 0.0 {
+                continue;
+            }
+            let azimuth = point.y.atan2(point.x);
+            let column = (((azimuth + PI) / (2.0 * PI) * columns as f32) as usize).min(columns - 1);
+            let value = (range / max_range).min(1.0);
+            let cell = &mut image[row * columns + column];
+            if *cell == 0.0 || value < *cell {
+                *cell = value;
+            }
+        }
+    }
+    image
+}
+
+/// 점유 격자를 모델 입력 값으로 변환
+///
+/// # Returns
+/// * `Vec<f32>` - `height × width` 행 우선 값 (첫 행이 y 최솟값, 점유 1, 빈 공간 0, 미확인 0.5)
+pub fn occupancy_tensor(grid: &OccupancyGrid) -> Vec<f32> {
+    (0..grid.height())
+        .flat_map(|y| (0..grid.width()).map(move |x| (x, y)))
+        .map(|(x, y)| match grid.cell(x, y) {
+            Some(Cell::Occupied) => 1.0,
+            Some(Cell::Free) => 0.0,
+            _ => 0.5,
+        })
+        .collect()
+}
+
+/// 첫 번째 출력에서 가장 높은 값의 분류
+///
+/// # Arguments
+/// * `outputs` - 모델 출력 텐서
+/// * `labels` - 분류 이름 목록
+///
+/// # Returns
+/// * `Option<(String, f32)>` - 분류 이름과 값, 이름 목록이 비어 있거나 첫 번째 출력 크기와 다르면 None
+pub fn classify(outputs: &[InferenceOutput], labels: &[String]) -> Option<(String, f32)> {
+    let values = &outputs.first()?.values;
+    if labels.is_empty() || values.len() != labels.len() {
+        return None;
+    }
+    values
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| value.is_finite())
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, &value)| (labels[index].clone(), value))
+}
@@ -0,0 +1,42 @@
+pub mod calibration;
+pub mod cluster;
+pub mod config;
+pub mod counting;
+pub mod deskew;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod icp;
+pub mod inference;
+pub mod occupancy;
+pub mod odometry;
+#[cfg(feature = "onnx")]
+pub mod onnx;
+pub mod reorder;
+pub mod statistics;
+pub mod tf;
+pub mod tracker;
+pub mod voxel;
+
+pub use calibration::{
+    load_extrinsics, AlignRequest, CalibrationResult, CalibrationStore, CaptureRequest,
+    SnapshotInfo,
+};
+pub use cluster::{cluster, Cluster};
+pub use config::{
+    CalibrationConfig, ClockSkew, CountPersistConfig, CountZone, CountingConfig, DeskewConfig,
+    EgoVelocity, Extrinsic, InferenceConfig, InferenceInput, OccupancyConfig, OdometryConfig,
+    ProcessingConfig, ReorderConfig, SizeBucket, StatisticsConfig, TrackingConfig, Tripwire,
+    VoxelBackend, VoxelConfig,
+};
+pub use counting::{crossing, CountRollup, CountStore, CountTotals};
+pub use icp::{align, Alignment, IcpParams};
+pub use inference::{InferenceOutput, InferenceResult};
+pub use occupancy::{FrameAccumulator, OccupancyEncoding, OccupancyGrid};
+pub use odometry::{FrameMotion, OdometrySample, OdometryStore, Pose};
+#[cfg(feature = "onnx")]
+pub use onnx::InferenceEngine;
+pub use reorder::{Reorder, ReorderBuffer};
+pub use statistics::FrameStatistics;
+pub use tf::{frame_name, TfFormat, TfFrame, TfTree, WORLD_FRAME};
+pub use tracker::{ObjectTracker, Track, TrackStep, TrackedObject, TrackedObjects};
+pub use voxel::{voxel_keys, VoxelGrid, VoxelKey, Voxelizer};
@@ -0,0 +1,276 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::lidar::{LiDARKey, Point, PointCloud};
+use crate::processing::config::OccupancyConfig;
+use crate::processing::voxel::Voxelizer;
+
+/// 한 변의 최대 격자 수
+const MAX_CELLS_PER_SIDE: usize = 4096;
+
+/// PNG 격자 값 (map_server 규칙: 흰색 빈 공간, 검은색 점유, 회색 미확인)
+const PNG_FREE: u8 = 254;
+const PNG_OCCUPIED: u8 = 0;
+const PNG_UNKNOWN: u8 = 205;
+
+/// 격자 상태 열거형
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Unknown,
+    Free,
+    Occupied,
+}
+
+/// 점유 격자 인코딩 열거형
+///
+/// # Variants
+/// * `Png` - 8-bit grayscale PNG (빈 공간 254, 점유 0, 미확인 205), 첫 행이 y 최댓값
+/// * `Raw` - 격자당 1 byte (빈 공간 0, 점유 100, 미확인 255), 첫 행이 y 최솟값 (`origin`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OccupancyEncoding {
+    #[default]
+    Png,
+    Raw,
+}
+
+/// 2D 점유 격자 구조체
+///
+/// # Examples
+/// ```
+/// let mut grid = OccupancyGrid::new(&config);
+/// grid.insert(&points);
+/// let png = grid.encode(OccupancyEncoding::Png)?;
+/// ```
+///
+/// # 동작 설명
+/// * 센서 원점 (0, 0) 에서 각 포인트까지 광선이 지나는 격자는 빈 공간으로 표시
+/// * 포인트가 있는 격자는 점유로 표시 (빈 공간보다 우선)
+/// * 범위 밖의 포인트는 범위 안에서 지나는 격자만 반영
+pub struct OccupancyGrid {
+    resolution: f32,
+    origin: [f32; 2],
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    ray_trace: bool,
+}
+
+impl OccupancyGrid {
+    /// 설정한 해상도와 범위로 빈 (미확인) 격자 생성
+    pub fn new(config: &OccupancyConfig) -> Self {
+        let resolution = if config.resolution > 0.0 {
+            config.resolution
+        } else {
+            OccupancyConfig::default().resolution
+        };
+        let [min_x, min_y, max_x, max_y] = config.extent;
+        let side = |min: f32, max: f32| {
+            (((max - min) / resolution).ceil().max(1.0) as usize).min(MAX_CELLS_PER_SIDE)
+        };
+        let width = side(min_x, max_x);
+        let height = side(min_y, max_y);
+        Self {
+            resolution,
+            origin: [min_x, min_y],
+            width,
+            height,
+            cells: vec![Cell::Unknown; width * height],
+            ray_trace: config.ray_trace,
+        }
+    }
+
+    pub fn resolution(&self) -> f32 {
+        self.resolution
+    }
+
+    pub fn origin(&self) -> [f32; 2] {
+        self.origin
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// 격자 상태 조회 (범위 밖이면 None)
+    pub fn cell(&self, x: usize, y: usize) -> Option<Cell> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// 포인트 클라우드 반영
+    ///
+    /// # Arguments
+    /// * `points` - 채널별 포인트 클라우드
+    pub fn insert(&mut self, points: &[PointCloud]) {
+        let hits: Vec<(i64, i64)> = points
+            .iter()
+            .flat_map(|cloud| cloud.points.iter())
+            .filter_map(|point| self.hit_cell(point))
+            .collect();
+
+        if self.ray_trace {
+            if let Some(origin) = self.index(0.0, 0.0) {
+                for &hit in &hits {
+                    self.trace(origin, hit);
+                }
+            }
+        }
+        for &(x, y) in &hits {
+            if let Some(index) = self.in_bounds(x, y) {
+                self.cells[index] = Cell::Occupied;
+            }
+        }
+    }
+
+    /// 반사가 있는 포인트의 격자 좌표
+    fn hit_cell(&self, point: &Point) -> Option<(i64, i64)> {
+        if !point.x.is_finite() || !point.y.is_finite() || (point.x == 0.0 && point.y == 0.0) {
+            return None;
+        }
+        Some((
+            ((point.x - self.origin[0]) / self.resolution).floor() as i64,
+            ((point.y - self.origin[1]) / self.resolution).floor() as i64,
+        ))
+    }
+
+    /// 좌표 (m) 의 격자 좌표, 범위 밖이면 None
+    fn index(&self, x: f32, y: f32) -> Option<(i64, i64)> {
+        let cell = (
+            ((x - self.origin[0]) / self.resolution).floor() as i64,
+            ((y - self.origin[1]) / self.resolution).floor() as i64,
+        );
+        self.in_bounds(cell.0, cell.1).map(|_| cell)
+    }
+
+    fn in_bounds(&self, x: i64, y: i64) -> Option<usize> {
+        (x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height)
+            .then(|| y as usize * self.width + x as usize)
+    }
+
+    /// 원점 격자에서 포인트 격자 직전까지 Bresenham 직선을 따라 빈 공간 표시
+    ///
+    /// 원점은 범위 안에 있으므로 범위를 벗어나면 더 이상 범위 안으로 돌아오지 않아 중단
+    fn trace(&mut self, (mut x, mut y): (i64, i64), (to_x, to_y): (i64, i64)) {
+        let dx = (to_x - x).abs();
+        let dy = -(to_y - y).abs();
+        let sx = if x < to_x { 1 } else { -1 };
+        let sy = if y < to_y { 1 } else { -1 };
+        let mut err = dx + dy;
+        while (x, y) != (to_x, to_y) {
+            let Some(index) = self.in_bounds(x, y) else {
+                break;
+            };
+            if self.cells[index] == Cell::Unknown {
+                self.cells[index] = Cell::Free;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// 격자 인코딩
+    ///
+    /// # Returns
+    /// * `Result<Vec<u8>, String>` - 성공 시 인코딩한 데이터, 실패 시 에러 메시지
+    pub fn encode(&self, encoding: OccupancyEncoding) -> Result<Vec<u8>, String> {
+        match encoding {
+            OccupancyEncoding::Raw => Ok(self
+                .cells
+                .iter()
+                .map(|cell| match cell {
+                    Cell::Free => 0,
+                    Cell::Occupied => 100,
+                    Cell::Unknown => 255,
+                })
+                .collect()),
+            OccupancyEncoding::Png => {
+                let mut image = Vec::with_capacity(self.cells.len());
+                for row in self.cells.chunks(self.width).rev() {
+                    image.extend(row.iter().map(|cell| match cell {
+                        Cell::Free => PNG_FREE,
+                        Cell::Occupied => PNG_OCCUPIED,
+                        Cell::Unknown => PNG_UNKNOWN,
+                    }));
+                }
+
+                let mut png = Vec::new();
+                let mut encoder =
+                    png::Encoder::new(&mut png, self.width as u32, self.height as u32);
+                encoder.set_color(png::ColorType::Grayscale);
+                encoder.set_depth(png::BitDepth::Eight);
+                let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+                writer.write_image_data(&image).map_err(|e| e.to_string())?;
+                writer.finish().map_err(|e| e.to_string())?;
+                Ok(png)
+            }
+        }
+    }
+
+    /// 격자를 base64 로 인코딩
+    pub fn encode_base64(&self, encoding: OccupancyEncoding) -> Result<String, String> {
+        self.encode(encoding).map(|data| STANDARD.encode(data))
+    }
+}
+
+/// LiDAR 채널별 최신 포인트 클라우드 저장소
+///
+/// 채널별로 수신되는 데이터를 모아 LiDAR 한 대 또는 여러 대의 점유 격자를 만들 때 사용
+#[derive(Default)]
+pub struct FrameAccumulator {
+    frames: HashMap<(LiDARKey, usize), PointCloud>,
+}
+
+impl FrameAccumulator {
+    /// 비어 있지 않은 채널의 포인트 클라우드를 최신 데이터로 교체
+    pub fn update(&mut self, key: LiDARKey, points: &[PointCloud]) {
+        for (ch, cloud) in points.iter().enumerate() {
+            if !cloud.points.is_empty() {
+                self.frames.insert((key, ch), cloud.clone());
+            }
+        }
+    }
+
+    /// 저장된 포인트 클라우드로 점유 격자 생성
+    ///
+    /// # Arguments
+    /// * `key` - 대상 LiDAR, None 이면 모든 LiDAR 를 합쳐서 생성
+    /// * `config` - 점유 격자 설정
+    pub fn grid(&self, key: Option<&LiDARKey>, config: &OccupancyConfig) -> OccupancyGrid {
+        let mut grid = OccupancyGrid::new(config);
+        for ((frame_key, _), cloud) in &self.frames {
+            if key.is_none_or(|key| key == frame_key) {
+                grid.insert(std::slice::from_ref(cloud));
+            }
+        }
+        grid
+    }
+
+    /// 저장된 모든 LiDAR 포인트 클라우드를 복셀로 줄여 점유 격자 생성
+    ///
+    /// # Arguments
+    /// * `config` - 점유 격자 설정
+    /// * `voxelizer` - 복셀 누적기
+    ///
+    /// # 동작 설명
+    /// * 여러 LiDAR 가 같은 곳을 보면 겹치는 포인트가 복셀마다 하나(평균 위치)로 합쳐져 광선 추적 횟수가 줄어듦
+    pub fn voxel_grid(&self, config: &OccupancyConfig, voxelizer: &Voxelizer) -> OccupancyGrid {
+        let voxels = voxelizer.voxelize(self.frames.values());
+        let mut grid = OccupancyGrid::new(config);
+        grid.insert(std::slice::from_ref(&voxels.centroids()));
+        grid
+    }
+}
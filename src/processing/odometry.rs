@@ -0,0 +1,365 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use utoipa::ToSchema;
+
+use crate::common::time::unix_time_ms;
+use crate::lidar::Point;
+use crate::processing::config::{DeskewConfig, EgoVelocity, OdometryConfig};
+
+/// LiDAR 자세(pose) 구조체
+///
+/// LiDAR 좌표를 월드 좌표로 변환하는 자세입니다. (`월드 = R(orientation) * LiDAR + position`)
+///
+/// # Fields
+/// * `position` - 월드 좌표에서 LiDAR 위치 `[x, y, z]` (m)
+/// * `orientation` - 월드 좌표에서 LiDAR 방향 쿼터니언 `[x, y, z, w]` (저장 시 정규화)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Pose {
+    pub position: [f32; 3],
+    pub orientation: [f32; 4],
+}
+
+impl Pose {
+    /// 변환이 없는 자세 (LiDAR 좌표 = 월드 좌표)
+    pub const IDENTITY: Pose = Pose {
+        position: [0.0, 0.0, 0.0],
+        orientation: [0.0, 0.0, 0.0, 1.0],
+    };
+
+    /// 자세 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 값이 유한하지 않거나 쿼터니언 크기가 0 이면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        if !self
+            .position
+            .iter()
+            .chain(self.orientation.iter())
+            .all(|v| v.is_finite())
+        {
+            return Err("Pose values must be finite".to_string());
+        }
+        if norm(&self.orientation) < 1e-6 {
+            return Err("Pose orientation must be a non-zero quaternion".to_string());
+        }
+        Ok(())
+    }
+
+    /// LiDAR 좌표의 포인트를 월드 좌표로 변환
+    pub fn apply(&self, point: &Point) -> Point {
+        let [x, y, z] = rotate(&self.orientation, [point.x, point.y, point.z]);
+        Point {
+            x: x + self.position[0],
+            y: y + self.position[1],
+            z: z + self.position[2],
+        }
+    }
+
+    /// LiDAR 좌표의 방향 벡터(속도 등)를 월드 좌표로 회전 (위치는 더하지 않음)
+    pub fn rotate(&self, vector: [f32; 3]) -> [f32; 3] {
+        rotate(&normalize(self.orientation), vector)
+    }
+
+    /// 두 자세를 이은 자세 (`other` 를 적용한 뒤 이 자세를 적용)
+    pub fn compose(&self, other: &Pose) -> Pose {
+        let orientation = normalize(self.orientation);
+        let moved = rotate(&orientation, other.position);
+        Pose {
+            position: [
+                moved[0] + self.position[0],
+                moved[1] + self.position[1],
+                moved[2] + self.position[2],
+            ],
+            orientation: normalize(multiply(&orientation, &normalize(other.orientation))),
+        }
+    }
+
+    /// 두 자세 사이의 자세 (위치는 선형 보간, 방향은 정규화 선형 보간)
+    ///
+    /// # Arguments
+    /// * `other` - 끝 자세
+    /// * `ratio` - 보간 비율 (0 이면 이 자세, 1 이면 `other`)
+    pub fn interpolate(&self, other: &Pose, ratio: f32) -> Pose {
+        // 같은 회전을 나타내는 두 쿼터니언 중 가까운 쪽으로 보간
+        let sign = if dot(&self.orientation, &other.orientation) < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+        let mut position = [0.0; 3];
+        for (i, value) in position.iter_mut().enumerate() {
+            *value = self.position[i] + (other.position[i] - self.position[i]) * ratio;
+        }
+        let mut orientation = [0.0; 4];
+        for (i, value) in orientation.iter_mut().enumerate() {
+            *value =
+                self.orientation[i] + (sign * other.orientation[i] - self.orientation[i]) * ratio;
+        }
+        Pose {
+            position,
+            orientation: normalize(orientation),
+        }
+    }
+
+    /// 일정한 자기 속도로 `dt_s` 초 동안 움직인 자세 (음수이면 이전 자세)
+    fn advance(&self, velocity: &EgoVelocity, dt_s: f32) -> Pose {
+        let moved = rotate(&self.orientation, velocity.linear);
+        let mut position = self.position;
+        for (i, value) in position.iter_mut().enumerate() {
+            *value += moved[i] * dt_s;
+        }
+        // LiDAR 좌표 기준 각속도이므로 오른쪽에 곱함 (작은 회전 근사)
+        let [wx, wy, wz] = velocity.angular;
+        let delta = [wx * dt_s / 2.0, wy * dt_s / 2.0, wz * dt_s / 2.0, 1.0];
+        Pose {
+            position,
+            orientation: normalize(multiply(&self.orientation, &delta)),
+        }
+    }
+}
+
+/// 자기 위치/속도(odometry) 샘플 구조체
+///
+/// # Examples
+/// ```json
+/// { "time_ms": 1700000000000, "pose": { "position": [1.0, 2.0, 0.0], "orientation": [0.0, 0.0, 0.0, 1.0] }, "velocity": { "linear": [0.0, 5.0, 0.0] } }
+/// { "velocity": { "linear": [0.0, 5.0, 0.0], "angular": [0.0, 0.0, 0.1] } }
+/// ```
+///
+/// # Fields
+/// * `time_ms` - 샘플 시각 (Unix time, ms, 서버 시계 기준), 없으면 서버 수신 시각
+/// * `pose` - 월드 좌표에서 LiDAR 자세, 월드 좌표 출력(`frame: "world"`)에 사용
+/// * `velocity` - LiDAR 좌표 기준 자기 속도, 움직임 왜곡 보정(`deskew`)과 자세 외삽에 사용
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OdometrySample {
+    #[serde(default)]
+    pub time_ms: Option<u64>,
+    #[serde(default)]
+    pub pose: Option<Pose>,
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    pub velocity: Option<EgoVelocity>,
+}
+
+impl OdometrySample {
+    /// 샘플 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 자세와 속도가 모두 없거나 값이 잘못되면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        if self.pose.is_none() && self.velocity.is_none() {
+            return Err("Odometry sample needs pose or velocity".to_string());
+        }
+        if let Some(pose) = &self.pose {
+            pose.validate()?;
+        }
+        if let Some(velocity) = &self.velocity {
+            if !velocity
+                .linear
+                .iter()
+                .chain(velocity.angular.iter())
+                .all(|v| v.is_finite())
+            {
+                return Err("Odometry velocity must be finite".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn time(&self) -> u64 {
+        self.time_ms.unwrap_or_default()
+    }
+}
+
+/// 한 프레임(스캔)의 움직임 정보 구조체
+///
+/// # Fields
+/// * `velocity` - 움직임 왜곡 보정에 사용할 자기 속도 (odometry 속도, 없으면 `processing.deskew.velocity`)
+/// * `poses` - 스캔 시작/끝 시각의 LiDAR 자세, odometry 자세가 없으면 None
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameMotion {
+    pub velocity: EgoVelocity,
+    pub poses: Option<(Pose, Pose)>,
+}
+
+impl FrameMotion {
+    /// 스캔 중 측정 시각의 LiDAR 자세
+    ///
+    /// # Arguments
+    /// * `ratio` - 스캔 시작부터 끝까지 중 측정 시각의 비율 (0 ~ 1)
+    pub fn pose(&self, ratio: f32) -> Option<Pose> {
+        self.poses
+            .as_ref()
+            .map(|(start, end)| start.interpolate(end, ratio))
+    }
+}
+
+/// 자기 위치/속도(odometry) 저장소
+///
+/// # Examples
+/// ```
+/// let odometry = OdometryStore::new(config.processing.odometry.clone());
+/// odometry.update(sample)?;
+/// let motion = odometry.frame_motion(packet.time_ms, &config.processing.deskew);
+/// ```
+///
+/// # 동작 설명
+/// * WebSocket `odometry` 명령과 REST `POST /odometry` 로 받은 샘플을 시각 순으로 최근 `history` 개 보관
+/// * 프레임 시각의 자세는 앞뒤 샘플로 보간하고, 한쪽 샘플만 있으면 그 샘플의 속도로 외삽 (`max_age_ms` 이내)
+/// * 여러 LiDAR 가 같은 샘플을 사용하므로 각 LiDAR 좌표가 같은 기준으로 정렬되어 있다고 가정
+#[derive(Debug, Clone)]
+pub struct OdometryStore {
+    config: OdometryConfig,
+    samples: Arc<Mutex<VecDeque<OdometrySample>>>,
+}
+
+impl OdometryStore {
+    pub fn new(config: OdometryConfig) -> Self {
+        Self {
+            config,
+            samples: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// odometry 샘플 추가
+    ///
+    /// # Arguments
+    /// * `sample` - 추가할 샘플
+    ///
+    /// # Returns
+    /// * `Result<OdometrySample, String>` - 성공 시 저장한 샘플 (시각 포함, 쿼터니언 정규화), 실패 시 에러 메시지
+    pub fn update(&self, mut sample: OdometrySample) -> Result<OdometrySample, String> {
+        sample.validate()?;
+        sample.time_ms = Some(sample.time_ms.unwrap_or_else(unix_time_ms));
+        if let Some(pose) = &mut sample.pose {
+            pose.orientation = normalize(pose.orientation);
+        }
+
+        let mut samples = self.samples.lock().unwrap();
+        let index = samples.partition_point(|stored| stored.time() <= sample.time());
+        samples.insert(index, sample);
+        while samples.len() > self.config.history.max(1) {
+            samples.pop_front();
+        }
+        Ok(sample)
+    }
+
+    /// 가장 최근 odometry 샘플
+    pub fn latest(&self) -> Option<OdometrySample> {
+        self.samples.lock().unwrap().back().copied()
+    }
+
+    /// 프레임의 움직임 정보
+    ///
+    /// # Arguments
+    /// * `time_ms` - 프레임 수신 시각 (스캔이 끝난 시각으로 봄)
+    /// * `deskew` - 스캔 시간 및 기본 자기 속도 설정
+    pub fn frame_motion(&self, time_ms: u64, deskew: &DeskewConfig) -> FrameMotion {
+        let samples = self.samples.lock().unwrap();
+        let start_ms = deskew.scan_start_ms(time_ms);
+        FrameMotion {
+            velocity: self
+                .velocity_at(&samples, time_ms)
+                .unwrap_or(deskew.velocity),
+            poses: self
+                .pose_at(&samples, start_ms)
+                .zip(self.pose_at(&samples, time_ms)),
+        }
+    }
+
+    /// 시각이 가장 가까운 샘플의 속도 (`max_age_ms` 이내)
+    fn velocity_at(&self, samples: &VecDeque<OdometrySample>, time_ms: u64) -> Option<EgoVelocity> {
+        samples
+            .iter()
+            .filter(|sample| sample.velocity.is_some())
+            .map(|sample| (sample.time().abs_diff(time_ms), sample))
+            .filter(|(diff, _)| *diff <= self.config.max_age_ms)
+            .min_by_key(|(diff, _)| *diff)
+            .and_then(|(_, sample)| sample.velocity)
+    }
+
+    /// 해당 시각의 LiDAR 자세 (앞뒤 샘플로 보간, 한쪽만 있으면 `max_age_ms` 이내에서 외삽)
+    fn pose_at(&self, samples: &VecDeque<OdometrySample>, time_ms: u64) -> Option<Pose> {
+        let mut before = None;
+        let mut after = None;
+        for sample in samples.iter().filter(|sample| sample.pose.is_some()) {
+            if sample.time() <= time_ms {
+                before = Some(sample);
+            } else {
+                after = Some(sample);
+                break;
+            }
+        }
+
+        match (before, after) {
+            (Some(a), Some(b)) => {
+                let ratio = (time_ms - a.time()) as f32 / (b.time() - a.time()) as f32;
+                Some(a.pose?.interpolate(&b.pose?, ratio))
+            }
+            (Some(sample), None) | (None, Some(sample))
+                if sample.time().abs_diff(time_ms) <= self.config.max_age_ms =>
+            {
+                let pose = sample.pose?;
+                let dt_s = (time_ms as f64 - sample.time() as f64) as f32 / 1000.0;
+                Some(match &sample.velocity {
+                    Some(velocity) => pose.advance(velocity, dt_s),
+                    None => pose,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 쿼터니언으로 벡터 회전
+fn rotate(q: &[f32; 4], v: [f32; 3]) -> [f32; 3] {
+    // v' = v + 2w(u × v) + 2u × (u × v)
+    let u = [q[0], q[1], q[2]];
+    let t = cross(&u, &v).map(|c| c * 2.0);
+    let ut = cross(&u, &t);
+    [
+        v[0] + q[3] * t[0] + ut[0],
+        v[1] + q[3] * t[1] + ut[1],
+        v[2] + q[3] * t[2] + ut[2],
+    ]
+}
+
+/// 쿼터니언 곱 `a * b`
+fn multiply(a: &[f32; 4], b: &[f32; 4]) -> [f32; 4] {
+    let [ax, ay, az, aw] = *a;
+    let [bx, by, bz, bw] = *b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+fn cross(a: &[f32; 3], b: &[f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: &[f32; 4], b: &[f32; 4]) -> f32 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn norm(q: &[f32; 4]) -> f32 {
+    dot(q, q).sqrt()
+}
+
+fn normalize(q: [f32; 4]) -> [f32; 4] {
+    let n = norm(&q);
+    if n < 1e-6 {
+        return [0.0, 0.0, 0.0, 1.0];
+    }
+    q.map(|v| v / n)
+}
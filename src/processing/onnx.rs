@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, Mutex};
+use tracing::*;
+use tract_onnx::prelude::*;
+
+use crate::lidar::{LiDARKey, PointCloud};
+use crate::processing::config::{InferenceConfig, InferenceInput, OccupancyConfig};
+use crate::processing::inference::{
+    classify, occupancy_tensor, range_image, InferenceOutput, InferenceResult,
+};
+use crate::processing::occupancy::OccupancyGrid;
+
+type Model = TypedRunnableModel<TypedModel>;
+
+/// ONNX 모델 프레임 추론기
+///
+/// # Examples
+/// ```
+/// let engine = InferenceEngine::load(&config.inference, &config.occupancy)?;
+/// let mut results = engine.subscribe();
+/// engine.observe(key, time_ms, &points).await;
+/// let result = results.recv().await?;
+/// ```
+///
+/// # 동작 설명
+/// * 시작할 때 모델을 읽고 입력 모양(`[1, 1, 높이, 너비]`)을 고정하여 최적화
+/// * LiDAR 별로 `interval_ms` 마다 프레임을 거리 영상 또는 점유 격자로 변환하여 blocking 스레드에서 실행
+/// * 이전 추론이 끝나지 않았으면 프레임을 건너뛰어 수신 처리가 밀리지 않도록 함
+/// * 결과는 broadcast 채널로 전달 (`subscribe`)
+pub struct InferenceEngine {
+    config: InferenceConfig,
+    occupancy: OccupancyConfig,
+    model: Arc<Model>,
+    shape: (usize, usize),
+    busy: Arc<AtomicBool>,
+    last_run: Mutex<HashMap<LiDARKey, Instant>>,
+    results: broadcast::Sender<InferenceResult>,
+}
+
+impl InferenceEngine {
+    /// 모델 읽기
+    ///
+    /// # Arguments
+    /// * `config` - 추론 설정
+    /// * `occupancy` - 점유 격자 설정 (`input` 이 `occupancy_grid` 일 때 격자 크기와 범위)
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 추론기, 모델을 읽지 못하거나 입력 모양이 맞지 않으면 에러 메시지
+    pub fn load(config: &InferenceConfig, occupancy: &OccupancyConfig) -> Result<Self, String> {
+        let shape = match config.input {
+            InferenceInput::RangeImage => (config.rows, config.columns),
+            InferenceInput::OccupancyGrid => {
+                let grid = OccupancyGrid::new(occupancy);
+                (grid.height(), grid.width())
+            }
+        };
+        let model = tract_onnx::onnx()
+            .model_for_path(&config.model)
+            .and_then(|model| model.with_input_fact(0, f32::fact([1, 1, shape.0, shape.1]).into()))
+            .and_then(|model| model.into_optimized())
+            .and_then(|model| model.into_runnable())
+            .map_err(|e| format!("Failed to load {}: {}", config.model.display(), e))?;
+        info!(
+            "Inference model {} loaded (input {:?}, {}x{})",
+            config.model.display(),
+            config.input,
+            shape.0,
+            shape.1
+        );
+        Ok(Self {
+            config: config.clone(),
+            occupancy: occupancy.clone(),
+            model: Arc::new(model),
+            shape,
+            busy: Arc::new(AtomicBool::new(false)),
+            last_run: Mutex::new(HashMap::new()),
+            results: broadcast::channel(64).0,
+        })
+    }
+
+    /// 추론 결과 수신기
+    pub fn subscribe(&self) -> broadcast::Receiver<InferenceResult> {
+        self.results.subscribe()
+    }
+
+    /// 프레임 추론 요청
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `time_ms` - 프레임 수신 시각
+    /// * `points` - 채널별 포인트 클라우드
+    ///
+    /// # 동작 설명
+    /// * LiDAR 의 마지막 추론 뒤 `interval_ms` 가 지나지 않았거나 다른 추론이 진행 중이면 건너뜀
+    /// * 결과는 실행이 끝난 뒤 `subscribe` 수신기로 전달
+    pub async fn observe(&self, key: LiDARKey, time_ms: u64, points: &[PointCloud]) {
+        {
+            let mut last_run = self.last_run.lock().await;
+            let interval = Duration::from_millis(self.config.interval_ms);
+            if last_run
+                .get(&key)
+                .is_some_and(|last| last.elapsed() < interval)
+            {
+                return;
+            }
+            if self.busy.swap(true, Ordering::AcqRel) {
+                return;
+            }
+            last_run.insert(key, Instant::now());
+        }
+
+        let input = match self.config.input {
+            InferenceInput::RangeImage => range_image(
+                points,
+                self.config.rows,
+                self.config.columns,
+                self.config.max_range,
+            ),
+            InferenceInput::OccupancyGrid => {
+                let mut grid = OccupancyGrid::new(&self.occupancy);
+                grid.insert(points);
+                occupancy_tensor(&grid)
+            }
+        };
+        let model = self.model.clone();
+        let shape = self.shape;
+        let busy = self.busy.clone();
+        let labels = self.config.labels.clone();
+        let results = self.results.clone();
+        tokio::spawn(async move {
+            let outputs = tokio::task::spawn_blocking(move || run(&model, shape, input)).await;
+            busy.store(false, Ordering::Release);
+            let outputs = match outputs {
+                Ok(Ok(outputs)) => outputs,
+                Ok(Err(e)) => {
+                    warn!("Inference failed for {}:{}: {}", key.ip, key.port, e);
+                    return;
+                }
+                Err(e) => {
+                    error!("Inference task failed: {}", e);
+                    return;
+                }
+            };
+            let (label, score) = classify(&outputs, &labels).unzip();
+            _ = results.send(InferenceResult {
+                key,
+                time_ms,
+                outputs,
+                label,
+                score,
+            });
+        });
+    }
+}
+
+/// 모델 실행
+fn run(model: &Model, shape: (usize, usize), input: Vec<f32>) -> TractResult<Vec<InferenceOutput>> {
+    let input: Tensor =
+        tract_ndarray::Array4::from_shape_vec((1, 1, shape.0, shape.1), input)?.into();
+    model
+        .run(tvec!(input.into()))?
+        .iter()
+        .map(|output| {
+            let view = output.to_array_view::<f32>()?;
+            Ok(InferenceOutput {
+                shape: view.shape().to_vec(),
+                values: view.iter().copied().collect(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,147 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::common::data::LiDARPacket;
+use crate::lidar::LiDARKey;
+use crate::processing::config::ReorderConfig;
+
+/// 정렬 대기 중인 프레임
+struct Pending {
+    time_ms: u64,
+    seq: u64,
+    packet: LiDARPacket,
+}
+
+impl PartialEq for Pending {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Pending {}
+
+impl PartialOrd for Pending {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pending {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.time_ms, self.seq).cmp(&(other.time_ms, other.seq))
+    }
+}
+
+/// 프레임 추가 결과
+///
+/// # Variants
+/// * `Buffered` - 정렬 대기열에 추가됨, `depth` 는 이 프레임보다 늦은 시각으로 먼저 도착해 대기 중인 프레임 수
+/// * `Late` - 같은 시각 이후의 프레임이 이미 전달되어 버려짐
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reorder {
+    Buffered { depth: usize },
+    Late,
+}
+
+/// 여러 LiDAR 의 프레임을 수신 시각 순으로 정렬하는 버퍼
+///
+/// # Examples
+/// ```
+/// let mut reorder = ReorderBuffer::new(&config.processing.reorder);
+/// match reorder.push(packet) {
+///     Reorder::Buffered { depth } => { /* 재정렬 깊이 기록 */ }
+///     Reorder::Late => { /* 늦게 도착해 버려짐 */ }
+/// }
+/// for packet in reorder.release(unix_time_ms()) {
+///     // 시각 순으로 전달
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 프레임 시각은 수신 시각(`LiDARPacket.time_ms`)에서 LiDAR 별 시계 차이(`skew`)를 뺀 값 (수신 시각보다 늦어지지 않음)
+/// * 프레임 시각 + `max_delay_ms` 가 지나면 시각 순으로 전달
+/// * 이미 전달한 프레임보다 이른 시각의 프레임은 늦게 도착한 것으로 보고 버림
+/// * 대기 중인 프레임이 `max_pending` 을 넘으면 가장 이른 프레임부터 바로 전달
+pub struct ReorderBuffer {
+    max_delay_ms: u64,
+    max_pending: usize,
+    skew: HashMap<LiDARKey, u64>,
+    pending: BinaryHeap<Reverse<Pending>>,
+    seq: u64,
+    released_ms: u64,
+}
+
+impl ReorderBuffer {
+    pub fn new(config: &ReorderConfig) -> Self {
+        Self {
+            max_delay_ms: config.max_delay_ms,
+            max_pending: config.max_pending.max(1),
+            skew: config
+                .skew
+                .iter()
+                .map(|skew| (skew.lidar, skew.offset_ms))
+                .collect(),
+            pending: BinaryHeap::new(),
+            seq: 0,
+            released_ms: 0,
+        }
+    }
+
+    /// 프레임 추가
+    ///
+    /// # Arguments
+    /// * `packet` - 수신한 LiDAR 데이터
+    ///
+    /// # Returns
+    /// * `Reorder` - 대기열 추가 여부와 재정렬 깊이
+    pub fn push(&mut self, packet: LiDARPacket) -> Reorder {
+        let skew = self.skew.get(&packet.key).copied().unwrap_or(0);
+        let time_ms = packet.time_ms.saturating_sub(skew);
+        if time_ms < self.released_ms {
+            return Reorder::Late;
+        }
+
+        let depth = self
+            .pending
+            .iter()
+            .filter(|Reverse(pending)| pending.time_ms > time_ms)
+            .count();
+        self.seq += 1;
+        self.pending.push(Reverse(Pending {
+            time_ms,
+            seq: self.seq,
+            packet,
+        }));
+        Reorder::Buffered { depth }
+    }
+
+    /// 다음 프레임을 전달할 시각 (Unix time, ms), 대기 중인 프레임이 없으면 None
+    pub fn next_release_ms(&self) -> Option<u64> {
+        self.pending
+            .peek()
+            .map(|Reverse(pending)| pending.time_ms + self.max_delay_ms)
+    }
+
+    /// 전달할 시각이 된 프레임을 시각 순으로 꺼냄
+    ///
+    /// # Arguments
+    /// * `now_ms` - 현재 시각 (Unix time, ms)
+    ///
+    /// # Returns
+    /// * `Vec<LiDARPacket>` - 시각 순으로 정렬된 프레임
+    pub fn release(&mut self, now_ms: u64) -> Vec<LiDARPacket> {
+        let mut released = Vec::new();
+        while let Some(Reverse(pending)) = self.pending.peek() {
+            let due = pending.time_ms + self.max_delay_ms <= now_ms;
+            if !due && self.pending.len() <= self.max_pending {
+                break;
+            }
+            let Some(Reverse(pending)) = self.pending.pop() else {
+                break;
+            };
+            self.released_ms = pending.time_ms;
+            released.push(pending.packet);
+        }
+        released
+    }
+}
@@ -0,0 +1,143 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::HashSet;
+
+use crate::lidar::PointCloud;
+use crate::processing::config::StatisticsConfig;
+
+/// 프레임 통계 구조체
+///
+/// 원본 포인트 없이 분석 대시보드에서 사용할 수 있도록 프레임 단위로 계산한 요약 정보입니다.
+///
+/// # Examples
+/// ```
+/// let statistics = FrameStatistics::compute(lidar_data.get_points(), &StatisticsConfig::default());
+/// ```
+///
+/// # Fields
+/// * `point_count` - 전체 포인트 수
+/// * `valid_count` - 거리가 0 보다 큰 (반사가 있는) 포인트 수
+/// * `sectors` - 수평 각도 구간별 거리 통계
+/// * `occupancy` - 점유 격자 요약
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct FrameStatistics {
+    pub point_count: usize,
+    pub valid_count: usize,
+    pub sectors: Vec<SectorStatistics>,
+    pub occupancy: OccupancySummary,
+}
+
+/// 수평 각도 구간별 거리 통계
+///
+/// # Fields
+/// * `start_deg` - 구간 시작 각도 (x 축 기준, -180° ~ 180°)
+/// * `end_deg` - 구간 끝 각도
+/// * `count` - 구간 안의 유효 포인트 수
+/// * `min_range` - 최소 거리 (m), 포인트가 없으면 null
+/// * `mean_range` - 평균 거리 (m), 포인트가 없으면 null
+/// * `max_range` - 최대 거리 (m), 포인트가 없으면 null
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SectorStatistics {
+    pub start_deg: f32,
+    pub end_deg: f32,
+    pub count: usize,
+    pub min_range: Option<f32>,
+    pub mean_range: Option<f32>,
+    pub max_range: Option<f32>,
+}
+
+/// 점유 격자 요약
+///
+/// # Fields
+/// * `cell_size` - 격자 크기 (m)
+/// * `occupied_cells` - 유효 포인트가 하나 이상 있는 격자 수
+/// * `bounds` - 점유 영역 경계 `[min_x, min_y, max_x, max_y]` (m), 점유 격자가 없으면 null
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct OccupancySummary {
+    pub cell_size: f32,
+    pub occupied_cells: usize,
+    pub bounds: Option<[f32; 4]>,
+}
+
+impl FrameStatistics {
+    /// 채널별 포인트 클라우드로 프레임 통계 계산
+    ///
+    /// # Arguments
+    /// * `points` - 채널별 포인트 클라우드
+    /// * `config` - 통계 설정
+    ///
+    /// # Returns
+    /// * `Self` - 프레임 통계
+    pub fn compute(points: &[PointCloud], config: &StatisticsConfig) -> Self {
+        let sector_count = config.sector_count.max(1);
+        let sector_deg = 360.0 / sector_count as f32;
+        let mut sectors: Vec<(usize, f32, f32, f32)> =
+            vec![(0, f32::INFINITY, 0.0, f32::NEG_INFINITY); sector_count];
+        let mut cells = HashSet::new();
+        let mut bounds: Option<[f32; 4]> = None;
+        let mut point_count = 0;
+        let mut valid_count = 0;
+
+        for point in points.iter().flat_map(|cloud| cloud.points.iter()) {
+            point_count += 1;
+            let range = (point.x * point.x + point.y * point.y + point.z * point.z).sqrt();
+            if !range.is_finite() || range <= 0.0 {
+                continue;
+            }
+            valid_count += 1;
+
+            let angle = point.y.atan2(point.x).to_degrees();
+            let index = (((angle + 180.0) / sector_deg) as usize).min(sector_count - 1);
+            let sector = &mut sectors[index];
+            sector.0 += 1;
+            sector.1 = sector.1.min(range);
+            sector.2 += range;
+            sector.3 = sector.3.max(range);
+
+            if config.cell_size > 0.0 {
+                let cell = (
+                    (point.x / config.cell_size).floor() as i32,
+                    (point.y / config.cell_size).floor() as i32,
+                );
+                if cells.insert(cell) {
+                    let (x, y) = (
+                        cell.0 as f32 * config.cell_size,
+                        cell.1 as f32 * config.cell_size,
+                    );
+                    let b = bounds.get_or_insert([x, y, x, y]);
+                    b[0] = b[0].min(x);
+                    b[1] = b[1].min(y);
+                    b[2] = b[2].max(x + config.cell_size);
+                    b[3] = b[3].max(y + config.cell_size);
+                }
+            }
+        }
+
+        let sectors = sectors
+            .into_iter()
+            .enumerate()
+            .map(|(i, (count, min, sum, max))| {
+                let has_points = count > 0;
+                SectorStatistics {
+                    start_deg: -180.0 + i as f32 * sector_deg,
+                    end_deg: -180.0 + (i + 1) as f32 * sector_deg,
+                    count,
+                    min_range: has_points.then_some(min),
+                    mean_range: has_points.then(|| sum / count as f32),
+                    max_range: has_points.then_some(max),
+                }
+            })
+            .collect();
+
+        Self {
+            point_count,
+            valid_count,
+            sectors,
+            occupancy: OccupancySummary {
+                cell_size: config.cell_size,
+                occupied_cells: cells.len(),
+                bounds,
+            },
+        }
+    }
+}
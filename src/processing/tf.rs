@@ -0,0 +1,287 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use utoipa::ToSchema;
+
+use crate::lidar::LiDARKey;
+use crate::processing::config::Extrinsic;
+use crate::processing::odometry::Pose;
+use crate::site::SiteConfig;
+
+/// TF 트리의 최상위(월드) 좌표계 이름
+pub const WORLD_FRAME: &str = "world";
+
+/// TF 트리 파일 형식 열거형
+///
+/// # Variants
+/// * `Json` - JSON (기본값)
+/// * `Yaml` - YAML (`yaml` feature 필요)
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TfFormat {
+    #[default]
+    Json,
+    Yaml,
+}
+
+impl TfFormat {
+    /// 파일 확장자로 형식 결정 (`.yaml`, `.yml` 이면 YAML, 그 외는 JSON)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("yaml" | "yml") => TfFormat::Yaml,
+            _ => TfFormat::Json,
+        }
+    }
+
+    /// HTTP 응답 Content-Type
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            TfFormat::Json => "application/json",
+            TfFormat::Yaml => "application/yaml; charset=utf-8",
+        }
+    }
+
+    /// 이 빌드에서 사용할 수 있는 형식인지 확인
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 사용 가능하면 Ok(()), 필요한 feature 가 빠졌으면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            TfFormat::Json => Ok(()),
+            TfFormat::Yaml if cfg!(feature = "yaml") => Ok(()),
+            TfFormat::Yaml => Err("YAML TF trees require the `yaml` feature".to_string()),
+        }
+    }
+}
+
+/// TF 트리 좌표계 구조체
+///
+/// # Examples
+/// ```json
+/// { "name": "building-a/lidar_0", "parent": "world", "lidar": { "ip": "192.168.123.200", "port": 5000, "product_line": 1, "lidar_id": 0 }, "pose": { "position": [0.0, 0.0, 5.0], "orientation": [0.0, 0.0, 0.0, 1.0] } }
+/// ```
+///
+/// # Fields
+/// * `name` - 좌표계 이름 (사이트에 속한 LiDAR 는 `<사이트 이름>/lidar_<사이트 lidars 목록 순서>`)
+/// * `parent` - 부모 좌표계 이름 (기본값 `world`)
+/// * `lidar` - 좌표계에 해당하는 LiDAR 키, None 이면 `name` 으로 사이트 설정에서 찾고 없으면 중간 좌표계로 봄
+/// * `pose` - 부모 좌표계에서 이 좌표계의 자세
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct TfFrame {
+    pub name: String,
+    #[serde(default = "default_parent")]
+    pub parent: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lidar: Option<LiDARKey>,
+    pub pose: Pose,
+}
+
+fn default_parent() -> String {
+    WORLD_FRAME.to_string()
+}
+
+/// 정적 TF 트리 구조체 (LiDAR 설치 자세 내보내기/가져오기 형식)
+///
+/// # Examples
+/// ```yaml
+/// frames:
+///   - name: building-a
+///     pose: { position: [120.0, 40.0, 0.0], orientation: [0.0, 0.0, 0.0, 1.0] }
+///   - name: building-a/lidar_0
+///     parent: building-a
+///     pose: { position: [0.0, 0.0, 5.0], orientation: [0.0, 0.0, 0.7071, 0.7071] }
+/// ```
+///
+/// # Fields
+/// * `frames` - 좌표계 목록 (순서 무관, 부모 좌표계는 `world` 또는 목록의 다른 좌표계)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct TfTree {
+    pub frames: Vec<TfFrame>,
+}
+
+impl TfTree {
+    /// 설치 자세 목록으로 TF 트리 생성 (모든 LiDAR 좌표계의 부모는 `world`)
+    ///
+    /// # Arguments
+    /// * `extrinsics` - LiDAR 설치 자세 목록
+    /// * `sites` - 좌표계 이름을 정할 사이트 설정
+    pub fn from_extrinsics(extrinsics: &[Extrinsic], sites: &SiteConfig) -> Self {
+        Self {
+            frames: extrinsics
+                .iter()
+                .map(|extrinsic| TfFrame {
+                    name: frame_name(&extrinsic.lidar, sites),
+                    parent: WORLD_FRAME.to_string(),
+                    lidar: Some(extrinsic.lidar),
+                    pose: extrinsic.pose,
+                })
+                .collect(),
+        }
+    }
+
+    /// 각 LiDAR 좌표계의 월드 좌표 자세 계산
+    ///
+    /// # Arguments
+    /// * `sites` - `lidar` 가 없는 좌표계의 LiDAR 를 이름으로 찾을 사이트 설정
+    ///
+    /// # Returns
+    /// * `Result<Vec<Extrinsic>, String>` - 성공 시 LiDAR 설치 자세 목록 (트리 순서),
+    ///   좌표계 이름이 중복되거나 부모가 없거나 순환하거나 자세가 잘못되면 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 부모 좌표계의 월드 자세에 좌표계 자세를 이어 붙임 (`parent_pose.compose(&pose)`)
+    /// * LiDAR 를 찾을 수 없는 좌표계는 다른 좌표계의 부모로만 사용
+    pub fn resolve(&self, sites: &SiteConfig) -> Result<Vec<Extrinsic>, String> {
+        let mut indices = HashMap::new();
+        for (index, frame) in self.frames.iter().enumerate() {
+            if frame.name == WORLD_FRAME {
+                return Err(format!("Frame name {} is reserved", WORLD_FRAME));
+            }
+            if indices.insert(frame.name.as_str(), index).is_some() {
+                return Err(format!("Duplicate TF frame: {}", frame.name));
+            }
+            frame
+                .pose
+                .validate()
+                .map_err(|e| format!("TF frame {}: {}", frame.name, e))?;
+        }
+
+        let mut resolved: Vec<Option<Pose>> = vec![None; self.frames.len()];
+        let mut extrinsics: Vec<Extrinsic> = Vec::new();
+        for (index, frame) in self.frames.iter().enumerate() {
+            let pose = self.world_pose(index, &indices, &mut resolved)?;
+            let Some(lidar) = frame.lidar.or_else(|| site_lidar(&frame.name, sites)) else {
+                continue;
+            };
+            if extrinsics.iter().any(|other| other.lidar == lidar) {
+                return Err(format!(
+                    "LiDAR {}:{} appears in more than one TF frame",
+                    lidar.ip, lidar.port
+                ));
+            }
+            extrinsics.push(Extrinsic { lidar, pose });
+        }
+        Ok(extrinsics)
+    }
+
+    /// 좌표계의 월드 자세 (부모 방향으로 따라가며 계산, 계산한 값은 `resolved` 에 보관)
+    fn world_pose(
+        &self,
+        index: usize,
+        indices: &HashMap<&str, usize>,
+        resolved: &mut [Option<Pose>],
+    ) -> Result<Pose, String> {
+        // 아직 계산하지 않은 조상 좌표계 (자식부터)
+        let mut chain = vec![index];
+        let mut base = loop {
+            let current = chain[chain.len() - 1];
+            if let Some(pose) = resolved[current] {
+                chain.pop();
+                break pose;
+            }
+            let parent = &self.frames[current].parent;
+            if parent == WORLD_FRAME {
+                break Pose::IDENTITY;
+            }
+            let &parent_index = indices.get(parent.as_str()).ok_or_else(|| {
+                format!(
+                    "TF frame {} has unknown parent {}",
+                    self.frames[current].name, parent
+                )
+            })?;
+            if chain.contains(&parent_index) {
+                return Err(format!(
+                    "TF frame {} has a cyclic parent chain",
+                    self.frames[index].name
+                ));
+            }
+            chain.push(parent_index);
+        };
+        for &current in chain.iter().rev() {
+            base = base.compose(&self.frames[current].pose);
+            resolved[current] = Some(base);
+        }
+        Ok(base)
+    }
+
+    /// TF 트리 직렬화
+    ///
+    /// # Arguments
+    /// * `format` - 파일 형식
+    ///
+    /// # Returns
+    /// * `Result<String, String>` - 성공 시 직렬화한 문자열, 필요한 feature 가 빠졌으면 에러 메시지
+    pub fn encode(&self, format: TfFormat) -> Result<String, String> {
+        match format {
+            TfFormat::Json => serde_json::to_string_pretty(self).map_err(|e| e.to_string()),
+            #[cfg(feature = "yaml")]
+            TfFormat::Yaml => serde_yaml::to_string(self).map_err(|e| e.to_string()),
+            // 필요한 feature 가 빠진 형식
+            #[allow(unreachable_patterns)]
+            _ => Err(format.validate().err().unwrap_or_default()),
+        }
+    }
+
+    /// TF 트리 역직렬화
+    ///
+    /// # Arguments
+    /// * `text` - 직렬화한 문자열
+    /// * `format` - 파일 형식
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 TF 트리, 형식이 잘못되었거나 필요한 feature 가 빠졌으면 에러 메시지
+    pub fn decode(text: &str, format: TfFormat) -> Result<Self, String> {
+        match format {
+            TfFormat::Json => serde_json::from_str(text).map_err(|e| e.to_string()),
+            #[cfg(feature = "yaml")]
+            TfFormat::Yaml => serde_yaml::from_str(text).map_err(|e| e.to_string()),
+            #[allow(unreachable_patterns)]
+            _ => Err(format.validate().err().unwrap_or_default()),
+        }
+    }
+
+    /// TF 트리 파일 읽기 (확장자로 형식 결정)
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Self::decode(&text, TfFormat::from_path(path))
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    }
+}
+
+/// LiDAR 좌표계 이름
+///
+/// # Arguments
+/// * `key` - LiDAR 키
+/// * `sites` - 사이트 설정
+///
+/// # Returns
+/// * `String` - 처음 속한 사이트가 있으면 `<사이트 이름>/lidar_<사이트 lidars 목록 순서>`,
+///   없으면 `lidar_<ip>_<port>_<product_line>_<lidar_id>`
+pub fn frame_name(key: &LiDARKey, sites: &SiteConfig) -> String {
+    sites
+        .sites
+        .iter()
+        .find_map(|site| {
+            site.lidars
+                .iter()
+                .position(|lidar| lidar == key)
+                .map(|index| format!("{}/lidar_{}", site.name, index))
+        })
+        .unwrap_or_else(|| {
+            format!(
+                "lidar_{}_{}_{}_{}",
+                key.ip, key.port, key.product_line, key.lidar_id
+            )
+        })
+}
+
+/// `<사이트 이름>/lidar_<순서>` 이름의 LiDAR 키
+fn site_lidar(name: &str, sites: &SiteConfig) -> Option<LiDARKey> {
+    let (site, lidar) = name.rsplit_once('/')?;
+    let index: usize = lidar.strip_prefix("lidar_")?.parse().ok()?;
+    sites.get(site)?.lidars.get(index).copied()
+}
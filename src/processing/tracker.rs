@@ -0,0 +1,357 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tokio::sync::Mutex;
+
+use crate::lidar::{LiDARKey, Point, PointCloud};
+use crate::processing::cluster::{cluster, Cluster};
+use crate::processing::config::{Extrinsic, TrackingConfig};
+use crate::processing::odometry::Pose;
+use crate::ws::client::OutputFrame;
+
+/// 속도, 크기 갱신 시 새 측정값 비중
+const SMOOTHING: f32 = 0.5;
+
+/// 추적 중인 물체 구조체
+///
+/// # Fields
+/// * `id` - 물체 ID (서버 실행 중 고유)
+/// * `position` - 마지막 위치 `[x, y]` (m)
+/// * `velocity` - 추정 속도 `[x, y]` (m/s)
+/// * `size` - 추정 크기 (m, 바닥 면적의 긴 변)
+/// * `hits` - 검출된 프레임 수
+/// * `missed` - 마지막 검출 후 놓친 프레임 수
+/// * `last_ms` - 마지막 검출 시각 (Unix time, ms)
+#[derive(Debug, Clone)]
+pub struct Track {
+    pub id: u64,
+    pub position: [f32; 2],
+    pub velocity: [f32; 2],
+    pub size: f32,
+    pub hits: u32,
+    pub missed: u32,
+    pub last_ms: u64,
+}
+
+/// 확정된 물체의 한 프레임 이동 구조체
+///
+/// # Fields
+/// * `key` - 물체를 검출한 LiDAR 키
+/// * `id` - 물체 ID
+/// * `previous` - 이전 프레임 위치 `[x, y]` (m)
+/// * `position` - 현재 위치 `[x, y]` (m)
+/// * `velocity` - 추정 속도 `[x, y]` (m/s)
+/// * `size` - 추정 크기 (m)
+/// * `time_ms` - 프레임 시각 (Unix time, ms)
+#[derive(Debug, Clone)]
+pub struct TrackStep {
+    pub key: LiDARKey,
+    pub id: u64,
+    pub previous: [f32; 2],
+    pub position: [f32; 2],
+    pub velocity: [f32; 2],
+    pub size: f32,
+    pub time_ms: u64,
+}
+
+/// 확정된 물체의 속도와 방향 구조체
+///
+/// # Fields
+/// * `id` - 물체 ID
+/// * `position` - 위치 `[x, y]` (m)
+/// * `velocity` - 속도 `[x, y]` (m/s)
+/// * `speed` - 속력 (m/s)
+/// * `heading` - 진행 방향 (°, x 축에서 반시계 방향 0 ~ 360, 멈춰 있으면 0)
+/// * `size` - 크기 (m)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrackedObject {
+    pub id: u64,
+    pub position: [f32; 2],
+    pub velocity: [f32; 2],
+    pub speed: f32,
+    pub heading: f32,
+    pub size: f32,
+}
+
+/// 한 프레임의 확정 물체 목록 구조체 (`objects` 이벤트)
+///
+/// # Examples
+/// ```json
+/// { "key": { ... }, "time_ms": 1700000000000, "frame": "world", "objects": [ { "id": 7, "position": [12.3, -1.5], "velocity": [13.9, 0.2], "speed": 13.9, "heading": 0.8, "size": 4.2 } ] }
+/// ```
+///
+/// # Fields
+/// * `key` - LiDAR 키
+/// * `time_ms` - 프레임 시각 (Unix time, ms)
+/// * `frame` - 위치, 속도 좌표계 (`processing.tracking.extrinsics` 에 설치 자세가 있으면 `world`, 없으면 `lidar`)
+/// * `objects` - 이번 프레임에 검출된 확정 물체
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrackedObjects {
+    pub key: LiDARKey,
+    pub time_ms: u64,
+    pub frame: OutputFrame,
+    pub objects: Vec<TrackedObject>,
+}
+
+/// LiDAR 별 물체 추적기
+///
+/// # Examples
+/// ```
+/// let tracker = ObjectTracker::new(config.processing.tracking.clone());
+/// let steps = tracker.observe(packet.key, packet.time_ms, lidar_data.get_points()).await;
+/// counts.observe(&steps).await;
+/// let objects = tracker.objects(packet.key, packet.time_ms, &steps);
+/// ```
+///
+/// # 동작 설명
+/// * 프레임마다 포인트를 물체 후보로 묶고, 이전 물체의 예측 위치(위치 + 속도 × 경과 시간)와 가까운 순서로 짝지음
+/// * `max_distance` 보다 먼 후보는 새 물체로 추적 시작, `max_missed` 프레임 넘게 보이지 않은 물체는 제거
+/// * `min_hits` 프레임 이상 검출된 물체만 이동(`TrackStep`)으로 반환
+/// * 속도와 방향은 LiDAR 좌표로 추적하고, 설치 자세(`extrinsics`)가 있는 LiDAR 는 `objects` 에서 월드 좌표로 변환
+pub struct ObjectTracker {
+    config: TrackingConfig,
+    state: Mutex<TrackerState>,
+    extrinsics: RwLock<HashMap<LiDARKey, Pose>>,
+}
+
+/// 추적기 내부 상태
+#[derive(Default)]
+struct TrackerState {
+    next_id: u64,
+    tracks: HashMap<LiDARKey, Vec<Track>>,
+}
+
+impl ObjectTracker {
+    pub fn new(config: TrackingConfig) -> Self {
+        let extrinsics = config
+            .extrinsics
+            .iter()
+            .map(|extrinsic| (extrinsic.lidar, extrinsic.pose))
+            .collect();
+        Self {
+            config,
+            state: Mutex::new(TrackerState::default()),
+            extrinsics: RwLock::new(extrinsics),
+        }
+    }
+
+    pub fn config(&self) -> &TrackingConfig {
+        &self.config
+    }
+
+    /// 한 프레임으로 물체 추적 갱신
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `time_ms` - 프레임 시각 (Unix time, ms)
+    /// * `points` - 채널별 포인트 클라우드
+    ///
+    /// # Returns
+    /// * `Vec<TrackStep>` - 이번 프레임에 검출된 확정 물체의 이동
+    pub async fn observe(
+        &self,
+        key: LiDARKey,
+        time_ms: u64,
+        points: &[PointCloud],
+    ) -> Vec<TrackStep> {
+        let clusters = cluster(points, &self.config);
+        let mut state = self.state.lock().await;
+        let TrackerState { next_id, tracks } = &mut *state;
+        let tracks = tracks.entry(key).or_default();
+
+        // 예측 위치와 가까운 순서로 짝짓기
+        let mut pairs = Vec::new();
+        for (track_index, track) in tracks.iter().enumerate() {
+            let predicted = predict(track, time_ms);
+            for (cluster_index, cluster) in clusters.iter().enumerate() {
+                let distance = distance(predicted, cluster.center);
+                if distance <= self.config.max_distance {
+                    pairs.push((distance, track_index, cluster_index));
+                }
+            }
+        }
+        pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut track_matched = vec![false; tracks.len()];
+        let mut cluster_matched = vec![false; clusters.len()];
+        let mut steps = Vec::new();
+        for (_, track_index, cluster_index) in pairs {
+            if track_matched[track_index] || cluster_matched[cluster_index] {
+                continue;
+            }
+            track_matched[track_index] = true;
+            cluster_matched[cluster_index] = true;
+
+            let track = &mut tracks[track_index];
+            let previous = track.position;
+            update(track, &clusters[cluster_index], time_ms);
+            if track.hits >= self.config.min_hits {
+                steps.push(TrackStep {
+                    key,
+                    id: track.id,
+                    previous,
+                    position: track.position,
+                    velocity: track.velocity,
+                    size: track.size,
+                    time_ms,
+                });
+            }
+        }
+
+        for (track, matched) in tracks.iter_mut().zip(&track_matched) {
+            if !matched {
+                track.missed += 1;
+            }
+        }
+        tracks.retain(|track| track.missed <= self.config.max_missed);
+
+        for (cluster, matched) in clusters.iter().zip(&cluster_matched) {
+            if *matched {
+                continue;
+            }
+            *next_id += 1;
+            tracks.push(Track {
+                id: *next_id,
+                position: cluster.center,
+                velocity: [0.0, 0.0],
+                size: cluster.size(),
+                hits: 1,
+                missed: 0,
+                last_ms: time_ms,
+            });
+        }
+        steps
+    }
+
+    /// 물체 이동을 속도와 방향으로 변환
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `time_ms` - 프레임 시각 (Unix time, ms)
+    /// * `steps` - `observe` 가 반환한 물체 이동
+    ///
+    /// # Returns
+    /// * `TrackedObjects` - 설치 자세가 있으면 월드 좌표, 없으면 LiDAR 좌표의 물체 속도와 방향
+    pub fn objects(&self, key: LiDARKey, time_ms: u64, steps: &[TrackStep]) -> TrackedObjects {
+        let pose = self.extrinsic(&key);
+        let objects = steps
+            .iter()
+            .filter(|step| step.key == key)
+            .map(|step| {
+                let (position, velocity) = match pose {
+                    Some(pose) => {
+                        let position = pose.apply(&Point {
+                            x: step.position[0],
+                            y: step.position[1],
+                            z: 0.0,
+                        });
+                        let velocity = pose.rotate([step.velocity[0], step.velocity[1], 0.0]);
+                        ([position.x, position.y], [velocity[0], velocity[1]])
+                    }
+                    None => (step.position, step.velocity),
+                };
+                TrackedObject {
+                    id: step.id,
+                    position,
+                    velocity,
+                    speed: velocity[0].hypot(velocity[1]),
+                    heading: heading(velocity),
+                    size: step.size,
+                }
+            })
+            .collect();
+        TrackedObjects {
+            key,
+            time_ms,
+            frame: if pose.is_some() {
+                OutputFrame::World
+            } else {
+                OutputFrame::Lidar
+            },
+            objects,
+        }
+    }
+
+    /// LiDAR 의 설치 자세
+    pub fn extrinsic(&self, key: &LiDARKey) -> Option<Pose> {
+        self.extrinsics.read().unwrap().get(key).copied()
+    }
+
+    /// 설치 자세 변경 (캘리브레이션 결과 저장 시), 다음 프레임부터 적용
+    pub fn set_extrinsic(&self, extrinsic: &Extrinsic) {
+        self.extrinsics
+            .write()
+            .unwrap()
+            .insert(extrinsic.lidar, extrinsic.pose);
+    }
+
+    /// LiDAR 키 변경 시 추적 중인 물체를 새 키로 이동
+    pub async fn rename(&self, previous: &LiDARKey, key: LiDARKey) {
+        let mut state = self.state.lock().await;
+        if let Some(tracks) = state.tracks.remove(previous) {
+            state.tracks.insert(key, tracks);
+        }
+    }
+}
+
+/// 경과 시간만큼 이동한 예측 위치
+fn predict(track: &Track, time_ms: u64) -> [f32; 2] {
+    let dt = time_ms.saturating_sub(track.last_ms) as f32 / 1000.0;
+    [
+        track.position[0] + track.velocity[0] * dt,
+        track.position[1] + track.velocity[1] * dt,
+    ]
+}
+
+/// 짝지은 물체 후보로 위치, 속도, 크기 갱신
+fn update(track: &mut Track, cluster: &Cluster, time_ms: u64) {
+    let dt = time_ms.saturating_sub(track.last_ms) as f32 / 1000.0;
+    if dt > 0.0 {
+        let measured = [
+            (cluster.center[0] - track.position[0]) / dt,
+            (cluster.center[1] - track.position[1]) / dt,
+        ];
+        // 두 번째 검출에서 처음으로 속도를 측정하므로 그대로 사용
+        track.velocity = if track.hits == 1 {
+            measured
+        } else {
+            [
+                track.velocity[0] + (measured[0] - track.velocity[0]) * SMOOTHING,
+                track.velocity[1] + (measured[1] - track.velocity[1]) * SMOOTHING,
+            ]
+        };
+    }
+    track.position = cluster.center;
+    track.size += (cluster.size() - track.size) * SMOOTHING;
+    track.hits = track.hits.saturating_add(1);
+    track.missed = 0;
+    track.last_ms = time_ms;
+}
+
+/// 속도 벡터의 진행 방향
+///
+/// # Arguments
+/// * `velocity` - 속도 벡터 (m/s)
+///
+/// # Returns
+/// * `f32` - x 축에서 반시계 방향 각도 (°, 0 이상 360 미만), 정지 상태면 0
+pub fn heading(velocity: [f32; 2]) -> f32 {
+    if velocity == [0.0, 0.0] {
+        return 0.0;
+    }
+    let heading = velocity[1]
+        .atan2(velocity[0])
+        .to_degrees()
+        .rem_euclid(360.0);
+    // 아주 작은 음수 각도는 rem_euclid 결과가 360.0 으로 반올림됨
+    if heading >= 360.0 {
+        0.0
+    } else {
+        heading
+    }
+}
+
+fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+}
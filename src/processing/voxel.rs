@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use tracing::*;
+
+use crate::lidar::{Point, PointCloud};
+use crate::processing::config::{VoxelBackend, VoxelConfig};
+#[cfg(feature = "gpu")]
+use crate::processing::gpu::GpuVoxelizer;
+
+/// 복셀 좌표 (x, y, z 격자 번호)
+pub type VoxelKey = [i32; 3];
+
+/// CPU 복셀 좌표 계산 묶음 크기 (컴파일러가 벡터 명령으로 바꾸기 쉬운 고정 크기)
+const LANES: usize = 8;
+
+/// 포인트별 복셀 좌표 계산 (CPU)
+///
+/// # Arguments
+/// * `points` - 포인트 목록
+/// * `size` - 복셀 한 변의 길이 (m)
+/// * `keys` - 결과를 담을 버퍼 (기존 내용은 지우고 포인트 순서대로 채움)
+///
+/// # 동작 설명
+/// * `LANES` 개씩 고정 크기 배열로 계산하여 분기 없는 반복문이 SIMD 명령으로 바뀌도록 함
+/// * 유효하지 않은 포인트도 좌표를 계산하므로 걸러내는 것은 `VoxelGrid::insert` 에서 처리
+pub fn voxel_keys(points: &[Point], size: f32, keys: &mut Vec<VoxelKey>) {
+    let inverse = 1.0 / size;
+    keys.clear();
+    keys.reserve(points.len());
+    let mut chunks = points.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        let mut lane = [[0; 3]; LANES];
+        for (key, point) in lane.iter_mut().zip(chunk) {
+            *key = voxel_key(point, inverse);
+        }
+        keys.extend_from_slice(&lane);
+    }
+    keys.extend(
+        chunks
+            .remainder()
+            .iter()
+            .map(|point| voxel_key(point, inverse)),
+    );
+}
+
+#[inline]
+fn voxel_key(point: &Point, inverse: f32) -> VoxelKey {
+    [
+        (point.x * inverse).floor() as i32,
+        (point.y * inverse).floor() as i32,
+        (point.z * inverse).floor() as i32,
+    ]
+}
+
+/// 복셀별 누적 값
+#[derive(Debug, Clone, Copy, Default)]
+struct VoxelSum {
+    sum: [f32; 3],
+    count: u32,
+}
+
+/// 복셀 해시 누적 구조체
+///
+/// # Examples
+/// ```
+/// let mut grid = VoxelGrid::new(0.1);
+/// voxel_keys(&cloud.points, grid.size(), &mut keys);
+/// grid.insert(&cloud.points, &keys);
+/// let fused = grid.centroids();
+/// ```
+///
+/// # 동작 설명
+/// * 복셀 좌표를 키로 포인트 좌표 합과 개수를 누적 (차지한 복셀만 저장하므로 범위 제한 없음)
+/// * 좌표가 유한하지 않거나 원점(반사 없음)인 포인트는 무시
+/// * 여러 LiDAR 의 포인트가 같은 복셀에 들어오면 하나로 합쳐짐
+pub struct VoxelGrid {
+    size: f32,
+    voxels: HashMap<VoxelKey, VoxelSum>,
+}
+
+impl VoxelGrid {
+    pub fn new(size: f32) -> Self {
+        Self {
+            size,
+            voxels: HashMap::new(),
+        }
+    }
+
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    /// 포인트가 있는 복셀 수
+    pub fn len(&self) -> usize {
+        self.voxels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.voxels.is_empty()
+    }
+
+    /// 복셀에 누적된 포인트 수 (없으면 0)
+    pub fn count(&self, key: &VoxelKey) -> u32 {
+        self.voxels.get(key).map_or(0, |voxel| voxel.count)
+    }
+
+    /// 포인트 누적
+    ///
+    /// # Arguments
+    /// * `points` - 포인트 목록
+    /// * `keys` - 포인트별 복셀 좌표 (`voxel_keys` 또는 GPU 로 계산, 포인트와 같은 순서)
+    pub fn insert(&mut self, points: &[Point], keys: &[VoxelKey]) {
+        for (point, key) in points.iter().zip(keys) {
+            if !point.x.is_finite()
+                || !point.y.is_finite()
+                || !point.z.is_finite()
+                || (point.x == 0.0 && point.y == 0.0 && point.z == 0.0)
+            {
+                continue;
+            }
+            let voxel = self.voxels.entry(*key).or_default();
+            voxel.sum[0] += point.x;
+            voxel.sum[1] += point.y;
+            voxel.sum[2] += point.z;
+            voxel.count += 1;
+        }
+    }
+
+    /// 복셀별 포인트 평균 위치 (복셀 순서는 정해지지 않음)
+    pub fn centroids(&self) -> PointCloud {
+        PointCloud {
+            points: self
+                .voxels
+                .values()
+                .map(|voxel| {
+                    let count = voxel.count as f32;
+                    Point {
+                        x: voxel.sum[0] / count,
+                        y: voxel.sum[1] / count,
+                        z: voxel.sum[2] / count,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 여러 LiDAR 프레임 복셀 누적기
+///
+/// # Examples
+/// ```
+/// let voxelizer = Voxelizer::new(&config.processing.voxel).await;
+/// let grid = voxelizer.voxelize(frames.iter());
+/// ```
+///
+/// # 동작 설명
+/// * `backend` 가 `gpu` 이고 GPU 를 사용할 수 있으면 포인트 수가 `gpu_min_points` 이상인 프레임 묶음의 복셀 좌표를 GPU 에서 계산
+/// * GPU 를 사용할 수 없거나 계산에 실패하면 CPU 로 계산
+/// * 복셀 해시 누적은 항상 CPU 에서 처리
+pub struct Voxelizer {
+    config: VoxelConfig,
+    #[cfg(feature = "gpu")]
+    gpu: Option<GpuVoxelizer>,
+}
+
+impl Voxelizer {
+    /// 복셀 누적기 생성 (`backend` 가 `gpu` 이면 GPU 장치 초기화)
+    pub async fn new(config: &VoxelConfig) -> Self {
+        #[cfg(feature = "gpu")]
+        let gpu = if config.backend == VoxelBackend::Gpu {
+            match GpuVoxelizer::new().await {
+                Ok(gpu) => {
+                    info!("GPU voxelization on {}", gpu.name());
+                    Some(gpu)
+                }
+                Err(e) => {
+                    warn!("GPU voxelization unavailable, using CPU: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        #[cfg(not(feature = "gpu"))]
+        if config.backend == VoxelBackend::Gpu {
+            warn!("GPU voxelization requires the `gpu` feature, using CPU");
+        }
+
+        Self {
+            config: config.clone(),
+            #[cfg(feature = "gpu")]
+            gpu,
+        }
+    }
+
+    pub fn config(&self) -> &VoxelConfig {
+        &self.config
+    }
+
+    /// 실제 사용하는 복셀 좌표 계산 방식 (GPU 를 사용할 수 없으면 `Cpu`)
+    pub fn backend(&self) -> VoxelBackend {
+        #[cfg(feature = "gpu")]
+        if self.gpu.is_some() {
+            return VoxelBackend::Gpu;
+        }
+        VoxelBackend::Cpu
+    }
+
+    /// 포인트 클라우드 묶음을 하나의 복셀 격자로 누적
+    ///
+    /// # Arguments
+    /// * `clouds` - 합칠 포인트 클라우드 (LiDAR/채널별 최신 프레임)
+    ///
+    /// # Returns
+    /// * `VoxelGrid` - 누적한 복셀 격자
+    pub fn voxelize<'a>(&self, clouds: impl IntoIterator<Item = &'a PointCloud>) -> VoxelGrid {
+        #[cfg(feature = "gpu")]
+        let clouds: Vec<&PointCloud> = clouds.into_iter().collect();
+        #[cfg(feature = "gpu")]
+        if let Some(grid) = self.gpu_voxelize(&clouds) {
+            return grid;
+        }
+
+        let mut grid = VoxelGrid::new(self.config.size);
+        let mut keys = Vec::new();
+        for cloud in clouds {
+            voxel_keys(&cloud.points, self.config.size, &mut keys);
+            grid.insert(&cloud.points, &keys);
+        }
+        grid
+    }
+
+    /// GPU 로 복셀 좌표를 계산하여 누적, GPU 미사용 또는 포인트가 적거나 실패하면 None
+    #[cfg(feature = "gpu")]
+    fn gpu_voxelize(&self, clouds: &[&PointCloud]) -> Option<VoxelGrid> {
+        let gpu = self.gpu.as_ref()?;
+        let total: usize = clouds.iter().map(|cloud| cloud.points.len()).sum();
+        if total < self.config.gpu_min_points {
+            return None;
+        }
+        let points: Vec<Point> = clouds
+            .iter()
+            .flat_map(|cloud| cloud.points.iter().cloned())
+            .collect();
+        match gpu.voxel_keys(&points, self.config.size) {
+            Ok(keys) => {
+                let mut grid = VoxelGrid::new(self.config.size);
+                grid.insert(&points, &keys);
+                Some(grid)
+            }
+            Err(e) => {
+                warn!("GPU voxelization failed, using CPU: {}", e);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::lidar::kanavi_mobility::KanaviFrameBuilder;
+use crate::proto::message::{Command, ErrorBody, LiDARList, ServerData, ServerMessage};
+
+/// JSON 봉투 내용을 `ServerMessage` 로 변환
+///
+/// # Examples
+/// ```
+/// let message = server_message("event", None, "telemetry", Some(&data), None);
+/// let bytes = message.encode_to_vec();
+/// ```
+///
+/// # Arguments
+/// * `kind` - 메시지 종류 (`response`, `error`, `event`)
+/// * `id` - 요청 ID
+/// * `command` - 명령 또는 이벤트 이름
+/// * `data` - JSON 데이터
+/// * `error` - 에러 코드와 메시지
+///
+/// # Returns
+/// * `ServerMessage` - Protobuf 메시지
+///
+/// # 동작 설명
+/// * `point_cloud`, `lidar_list`, `telemetry`, `alert`, `site_status`, `lidar_identity_changed` 데이터는 해당 메시지로 변환
+/// * 그 외 명령이나 변환할 수 없는 데이터는 JSON 문자열(`json`)로 전달
+pub fn server_message(
+    kind: &str,
+    id: Option<&Value>,
+    command: &str,
+    data: Option<&Value>,
+    error: Option<(&str, &str)>,
+) -> ServerMessage {
+    ServerMessage {
+        r#type: kind.to_string(),
+        id: id.map(Value::to_string).unwrap_or_default(),
+        command: command.to_string(),
+        error: error.map(|(code, message)| ErrorBody {
+            code: code.to_string(),
+            message: message.to_string(),
+        }),
+        data: data
+            .filter(|data| !data.is_null())
+            .map(|data| typed_data(command, data)),
+    }
+}
+
+/// 명령별 Protobuf 데이터 변환, 형식이 없거나 변환에 실패하면 JSON 문자열
+fn typed_data(command: &str, data: &Value) -> ServerData {
+    let typed = match command {
+        "point_cloud" => from_value(data).map(ServerData::ScanFrame),
+        "lidar_list" => from_value(data).map(|lidars| ServerData::LidarList(LiDARList { lidars })),
+        "telemetry" => from_value(data).map(ServerData::Telemetry),
+        "alert" => from_value(data).map(ServerData::Alert),
+        "site_status" => from_value(data).map(ServerData::SiteStatus),
+        "lidar_identity_changed" => from_value(data).map(ServerData::IdentityChange),
+        _ => None,
+    };
+    typed.unwrap_or_else(|| ServerData::Json(data.to_string()))
+}
+
+fn from_value<T: DeserializeOwned>(data: &Value) -> Option<T> {
+    serde_json::from_value(data.clone()).ok()
+}
+
+/// Protobuf `Command` 를 LiDAR 명령 프레임으로 변환
+///
+/// # Arguments
+/// * `data` - 클라이언트가 보낸 Protobuf `Command`
+///
+/// # Returns
+/// * `Result<Vec<u8>, String>` - 성공 시 명령 프레임, 실패 시 에러 메시지
+pub fn command_frame(data: &[u8]) -> Result<Vec<u8>, String> {
+    let command = Command::decode(data).map_err(|e| format!("Invalid command: {}", e))?;
+    let byte = |name: &str, value: u32| {
+        u8::try_from(value).map_err(|_| format!("Invalid command: {} {} out of range", name, value))
+    };
+    if command.data.len() > u16::MAX as usize {
+        return Err(format!(
+            "Invalid command: data length {} out of range",
+            command.data.len()
+        ));
+    }
+    Ok(KanaviFrameBuilder::new(
+        byte("product_line", command.product_line)?,
+        byte("lidar_id", command.lidar_id)?,
+    )
+    .mode(byte("mode", command.mode)?)
+    .param(byte("param", command.param)?)
+    .payload(&command.data)
+    .build())
+}
@@ -0,0 +1,276 @@
+//! `proto/lidar_server.proto` 의 메시지 정의
+//!
+//! `.proto` 파일과 같은 필드 번호를 사용하며, 필드 이름이 JSON 메시지와 같으므로
+//! JSON 데이터에서 바로 변환할 수 있도록 `Deserialize` 도 구현합니다.
+use serde::Deserialize;
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct LiDARKey {
+    #[prost(string, tag = "1")]
+    pub ip: String,
+    #[prost(uint32, tag = "2")]
+    pub port: u32,
+    #[prost(uint32, tag = "3")]
+    pub product_line: u32,
+    #[prost(uint32, tag = "4")]
+    pub lidar_id: u32,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct Point {
+    #[prost(float, tag = "1")]
+    pub x: f32,
+    #[prost(float, tag = "2")]
+    pub y: f32,
+    #[prost(float, tag = "3")]
+    pub z: f32,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct PointCloud {
+    #[prost(message, repeated, tag = "1")]
+    pub points: Vec<Point>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct ScanFrame {
+    #[prost(message, optional, tag = "1")]
+    pub key: Option<LiDARKey>,
+    #[prost(message, repeated, tag = "2")]
+    pub points: Vec<PointCloud>,
+    #[prost(bool, tag = "3")]
+    pub replay: bool,
+    #[prost(uint64, tag = "4")]
+    pub time_ms: u64,
+    #[prost(message, optional, tag = "5")]
+    pub convention: Option<OutputConvention>,
+    #[prost(message, optional, tag = "6")]
+    pub timing: Option<FrameTiming>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct FrameTiming {
+    #[prost(uint64, tag = "1")]
+    pub scan_start_ms: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub offsets_us: Vec<PointOffsets>,
+}
+
+/// JSON 에서는 채널별 측정 시각 배열(`[[...], ...]`)이므로 배열에서 바로 변환
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(from = "Vec<u32>")]
+pub struct PointOffsets {
+    #[prost(uint32, repeated, tag = "1")]
+    pub offsets_us: Vec<u32>,
+}
+
+impl From<Vec<u32>> for PointOffsets {
+    fn from(offsets_us: Vec<u32>) -> Self {
+        Self { offsets_us }
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct OutputConvention {
+    #[prost(string, repeated, tag = "1")]
+    pub axes: Vec<String>,
+    #[prost(string, tag = "2")]
+    pub unit: String,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct LiDARInfo {
+    #[prost(message, optional, tag = "1")]
+    pub key: Option<LiDARKey>,
+    #[prost(string, tag = "2")]
+    pub company: String,
+    #[prost(string, tag = "3")]
+    pub ip: String,
+    #[prost(uint32, tag = "4")]
+    pub port: u32,
+    #[prost(uint32, tag = "5")]
+    pub product_line: u32,
+    #[prost(uint32, tag = "6")]
+    pub lidar_id: u32,
+    #[prost(string, optional, tag = "7")]
+    pub reported_ip: Option<String>,
+    #[prost(uint32, tag = "8")]
+    pub conflicts: u32,
+    #[prost(uint64, tag = "9")]
+    pub duplicates: u64,
+    #[prost(uint64, tag = "10")]
+    pub last_seen: u64,
+    #[prost(string, optional, tag = "11")]
+    pub mac: Option<String>,
+    #[prost(bool, tag = "12")]
+    pub disabled: bool,
+    #[prost(uint64, tag = "13")]
+    pub first_seen: u64,
+    #[prost(string, optional, tag = "14")]
+    pub firmware_version: Option<String>,
+    #[prost(bool, tag = "15")]
+    pub online: bool,
+    #[prost(float, optional, tag = "16")]
+    pub frame_rate: Option<f32>,
+    #[prost(uint32, optional, tag = "17")]
+    pub motor_speed: Option<u32>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct LiDARList {
+    #[prost(message, repeated, tag = "1")]
+    pub lidars: Vec<LiDARInfo>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct TelemetrySample {
+    #[prost(message, optional, tag = "1")]
+    pub key: Option<LiDARKey>,
+    #[prost(uint64, tag = "2")]
+    pub time_ms: u64,
+    #[prost(float, tag = "3")]
+    pub packet_rate: f32,
+    #[prost(float, tag = "4")]
+    pub frame_rate: f32,
+    #[prost(float, tag = "5")]
+    pub mean_points: f32,
+    #[prost(uint32, tag = "6")]
+    pub config_responses: u32,
+    #[prost(uint32, tag = "7")]
+    pub nak_count: u32,
+    #[prost(uint32, tag = "8")]
+    pub dropped_packets: u32,
+    #[prost(uint32, optional, tag = "9")]
+    pub motor_speed: Option<u32>,
+    #[prost(uint32, tag = "10")]
+    pub reorder_depth: u32,
+    #[prost(uint32, tag = "11")]
+    pub late_drops: u32,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct Alert {
+    #[prost(string, tag = "1")]
+    pub rule: String,
+    #[prost(string, tag = "2")]
+    pub kind: String,
+    #[prost(message, optional, tag = "3")]
+    pub key: Option<LiDARKey>,
+    #[prost(string, tag = "4")]
+    pub state: String,
+    #[prost(string, tag = "5")]
+    pub message: String,
+    #[prost(uint64, tag = "6")]
+    pub time_ms: u64,
+    #[prost(string, optional, tag = "7")]
+    pub maintenance: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct SiteMemberStatus {
+    #[prost(message, optional, tag = "1")]
+    pub key: Option<LiDARKey>,
+    #[prost(bool, tag = "2")]
+    pub online: bool,
+    #[prost(uint64, optional, tag = "3")]
+    pub last_seen: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct SiteStatus {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(bool, tag = "2")]
+    pub online: bool,
+    #[prost(uint32, tag = "3")]
+    pub online_lidars: u32,
+    #[prost(uint32, tag = "4")]
+    pub quorum: u32,
+    #[prost(message, repeated, tag = "5")]
+    pub lidars: Vec<SiteMemberStatus>,
+    #[prost(uint64, tag = "6")]
+    pub time_ms: u64,
+}
+
+#[derive(Clone, PartialEq, Deserialize, prost::Message)]
+#[serde(default)]
+pub struct LiDARIdentityChange {
+    #[prost(message, optional, tag = "1")]
+    pub previous: Option<LiDARKey>,
+    #[prost(message, optional, tag = "2")]
+    pub key: Option<LiDARKey>,
+    #[prost(string, tag = "3")]
+    pub ip: String,
+    #[prost(uint32, tag = "4")]
+    pub port: u32,
+    #[prost(string, tag = "5")]
+    pub reason: String,
+    #[prost(uint64, tag = "6")]
+    pub time_ms: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ErrorBody {
+    #[prost(string, tag = "1")]
+    pub code: String,
+    #[prost(string, tag = "2")]
+    pub message: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ServerMessage {
+    #[prost(string, tag = "1")]
+    pub r#type: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+    #[prost(string, tag = "3")]
+    pub command: String,
+    #[prost(message, optional, tag = "4")]
+    pub error: Option<ErrorBody>,
+    #[prost(oneof = "ServerData", tags = "10, 11, 12, 13, 14, 15, 16")]
+    pub data: Option<ServerData>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum ServerData {
+    #[prost(message, tag = "10")]
+    ScanFrame(ScanFrame),
+    #[prost(message, tag = "11")]
+    LidarList(LiDARList),
+    #[prost(message, tag = "12")]
+    Telemetry(TelemetrySample),
+    #[prost(message, tag = "13")]
+    Alert(Alert),
+    #[prost(message, tag = "14")]
+    SiteStatus(SiteStatus),
+    #[prost(string, tag = "15")]
+    Json(String),
+    #[prost(message, tag = "16")]
+    IdentityChange(LiDARIdentityChange),
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Command {
+    #[prost(uint32, tag = "1")]
+    pub product_line: u32,
+    #[prost(uint32, tag = "2")]
+    pub lidar_id: u32,
+    #[prost(uint32, tag = "3")]
+    pub mode: u32,
+    #[prost(uint32, tag = "4")]
+    pub param: u32,
+    #[prost(bytes = "vec", tag = "5")]
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,9 @@
+#[cfg(feature = "protobuf")]
+pub mod encode;
+#[cfg(feature = "protobuf")]
+pub mod message;
+
+#[cfg(feature = "protobuf")]
+pub use encode::{command_frame, server_message};
+#[cfg(feature = "protobuf")]
+pub use message::ServerMessage;
@@ -0,0 +1,57 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// 녹화 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 수신한 LiDAR 데이터 녹화 여부 (서버 시작 시 새 녹화 파일 생성)
+/// * `dir` - 녹화 파일 저장 및 재생 디렉터리
+/// * `queue_size` - 녹화 대기열 크기, 가득 차면 해당 데이터는 녹화하지 않음
+/// * `spool` - 구독자가 없을 때 디스크 임시 저장 설정
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct RecorderConfig {
+    pub enabled: bool,
+    pub dir: PathBuf,
+    pub queue_size: usize,
+    pub spool: SpoolConfig,
+}
+
+impl Default for RecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: PathBuf::from("recordings"),
+            queue_size: 1024,
+            spool: SpoolConfig::default(),
+        }
+    }
+}
+
+/// 디스크 임시 저장(store-and-forward) 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 구독자가 없는 LiDAR 데이터를 녹화 디렉터리에 임시 저장할지 여부
+/// * `max_bytes` - 임시 저장 최대 크기 (bytes), 넘으면 오래된 세그먼트부터 삭제
+/// * `segment_bytes` - 세그먼트 파일 크기 (bytes)
+/// * `queue_size` - 임시 저장 대기열 크기, 가득 차면 해당 데이터는 저장하지 않음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SpoolConfig {
+    pub enabled: bool,
+    pub max_bytes: u64,
+    pub segment_bytes: u64,
+    pub queue_size: usize,
+}
+
+impl Default for SpoolConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_bytes: 256 * 1024 * 1024,
+            segment_bytes: 16 * 1024 * 1024,
+            queue_size: 1024,
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use bincode::config::standard;
+use bincode::{decode_from_slice, encode_to_vec, Decode, Encode};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::lidar::LiDARKey;
+
+/// 녹화 파일 식별자
+pub const MAGIC: [u8; 4] = *b"LREC";
+
+/// 녹화 파일 형식 버전
+pub const VERSION: u8 = 1;
+
+/// 녹화 파일 확장자
+pub const EXTENSION: &str = "lrec";
+
+/// 녹화 세션 목록 파일(manifest) 확장자
+pub const MANIFEST_EXTENSION: &str = "json";
+
+/// 녹화 항목 구조체
+///
+/// 파일 형식: `LREC` + 버전(1 byte) 헤더 뒤에 `[길이(u32, big endian)][bincode 항목]` 반복
+///
+/// # Fields
+/// * `time_ms` - 수신 시각 (Unix time, ms)
+/// * `key` - 데이터를 송신한 LiDAR 키
+/// * `payload` - 회사 구분값(1 byte) + bincode 인코딩 데이터
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct RecordEntry {
+    pub time_ms: u64,
+    pub key: LiDARKey,
+    pub payload: Vec<u8>,
+}
+
+/// 녹화 파일 정보 구조체
+///
+/// # Fields
+/// * `name` - 파일 이름
+/// * `size` - 파일 크기 (bytes)
+/// * `modified_ms` - 마지막 수정 시각 (Unix time, ms)
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct RecordingInfo {
+    pub name: String,
+    pub size: u64,
+    pub modified_ms: u64,
+}
+
+/// 녹화 중 표시한 주석 마커
+///
+/// # Fields
+/// * `time_ms` - 마커 시각 (Unix time, ms), 녹화 항목의 `time_ms` 와 같은 기준
+/// * `label` - 마커 내용 (예: `pedestrian crossed`)
+/// * `key` - 마커와 관련된 LiDAR 키, 전체 장면이면 null
+/// * `client_id` - 마커를 추가한 클라이언트 UUID
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct Marker {
+    pub time_ms: u64,
+    pub label: String,
+    pub key: Option<LiDARKey>,
+    pub client_id: Uuid,
+}
+
+/// 녹화 세션 목록 파일(manifest) 구조체
+///
+/// 녹화 파일과 같은 이름에 확장자만 `json` 인 파일로 저장합니다. (예: `session-1700000000000.json`)
+///
+/// # Fields
+/// * `recording` - 녹화 파일 이름
+/// * `started_ms` - 녹화 시작 시각 (Unix time, ms)
+/// * `markers` - 추가한 순서대로 주석 마커 목록
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct Manifest {
+    pub recording: String,
+    pub started_ms: u64,
+    pub markers: Vec<Marker>,
+}
+
+/// 녹화 파일의 목록 파일(manifest) 경로
+///
+/// # Arguments
+/// * `recording` - 녹화 파일 경로
+pub fn manifest_path(recording: &Path) -> PathBuf {
+    recording.with_extension(MANIFEST_EXTENSION)
+}
+
+/// 목록 파일 쓰기
+///
+/// # 동작 설명
+/// * 임시 파일에 쓴 뒤 이름을 바꿔 쓰는 도중 종료되어도 이전 목록 파일이 남도록 함
+pub fn write_manifest(path: &Path, manifest: &Manifest) -> io::Result<()> {
+    let text = serde_json::to_vec_pretty(manifest)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let tmp = path.with_extension(format!("{}.tmp", MANIFEST_EXTENSION));
+    std::fs::write(&tmp, text)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// 목록 파일 읽기
+pub fn read_manifest(path: &Path) -> io::Result<Manifest> {
+    let text = std::fs::read(path)?;
+    serde_json::from_slice(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub fn write_header<W: Write>(writer: &mut W) -> io::Result<()> {
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&[VERSION])
+}
+
+pub fn read_header<R: Read>(reader: &mut R) -> io::Result<()> {
+    let mut header = [0u8; 5];
+    reader.read_exact(&mut header)?;
+    if header[..4] != MAGIC || header[4] != VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Not a recording file",
+        ));
+    }
+    Ok(())
+}
+
+pub fn write_entry<W: Write>(writer: &mut W, entry: &RecordEntry) -> io::Result<()> {
+    let encoded = encode_to_vec(entry, standard())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    writer.write_all(&(encoded.len() as u32).to_be_bytes())?;
+    writer.write_all(&encoded)
+}
+
+/// 녹화 항목 읽기
+///
+/// # Returns
+/// * `io::Result<Option<(RecordEntry, u64)>>` - 항목과 읽은 크기 (bytes), 파일 끝이면 None
+pub fn read_entry<R: Read>(reader: &mut R) -> io::Result<Option<(RecordEntry, u64)>> {
+    let mut len = [0u8; 4];
+    match reader.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+
+    let len = u32::from_be_bytes(len) as usize;
+    let mut encoded = vec![0u8; len];
+    reader.read_exact(&mut encoded)?;
+    let (entry, _) = decode_from_slice(&encoded, standard())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(Some((entry, 4 + len as u64)))
+}
+
+/// 녹화 파일 경로 생성
+///
+/// # Arguments
+/// * `dir` - 녹화 디렉터리
+/// * `name` - 녹화 파일 이름
+///
+/// # Returns
+/// * `Result<PathBuf, String>` - 성공 시 파일 경로, 디렉터리 밖을 가리키는 이름이면 에러 메시지
+pub fn recording_path(dir: &Path, name: &str) -> Result<PathBuf, String> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && !name.contains(['/', '\\'])
+        && name.ends_with(&format!(".{}", EXTENSION));
+    if !valid {
+        return Err(format!("Invalid recording name: {}", name));
+    }
+    Ok(dir.join(name))
+}
+
+/// 녹화 파일 목록 조회
+///
+/// # Arguments
+/// * `dir` - 녹화 디렉터리
+///
+/// # Returns
+/// * `io::Result<Vec<RecordingInfo>>` - 이름순 녹화 파일 목록, 디렉터리가 없으면 빈 목록
+pub fn list_recordings(dir: &Path) -> io::Result<Vec<RecordingInfo>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut recordings = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(EXTENSION) {
+            continue;
+        }
+
+        let metadata = entry.metadata()?;
+        let modified_ms = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        recordings.push(RecordingInfo {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            size: metadata.len(),
+            modified_ms,
+        });
+    }
+    recordings.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(recordings)
+}
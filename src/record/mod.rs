@@ -0,0 +1,10 @@
+pub mod config;
+pub mod format;
+pub mod playback;
+pub mod recorder;
+pub mod spool;
+
+pub use config::RecorderConfig;
+pub use playback::{PlaybackStatus, Player};
+pub use recorder::Recorder;
+pub use spool::Spool;
@@ -0,0 +1,296 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tracing::*;
+use utoipa::ToSchema;
+
+use crate::common::data::LiDARPacket;
+use crate::logging::TraceId;
+use crate::record::format::{read_entry, read_header, recording_path};
+
+/// 재생 제어 확인 주기
+const CONTROL_POLL: Duration = Duration::from_millis(20);
+
+/// 재생 상태 구조체
+///
+/// # Fields
+/// * `name` - 재생 중인 녹화 파일 이름
+/// * `playing` - 재생 스레드 동작 여부 (끝까지 재생했거나 정지하면 false)
+/// * `paused` - 일시 정지 여부
+/// * `speed` - 재생 속도 배율
+/// * `position_ms` - 녹화 시작 기준 현재 재생 위치 (ms)
+/// * `duration_ms` - 녹화 전체 길이 (ms)
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct PlaybackStatus {
+    pub name: String,
+    pub playing: bool,
+    pub paused: bool,
+    pub speed: f64,
+    pub position_ms: u64,
+    pub duration_ms: u64,
+}
+
+/// 재생 스레드와 공유하는 제어 상태
+struct Control {
+    status: PlaybackStatus,
+    seek_ms: Option<u64>,
+    stop: bool,
+}
+
+/// 녹화 파일 재생기
+///
+/// # Examples
+/// ```
+/// let player = Player::new(config.record.dir.clone(), replay_tx);
+/// player.start("session-1700000000000.lrec", 2.0)?;
+/// player.seek(10_000)?;
+/// ```
+///
+/// # 주요 기능
+/// * 녹화 파일을 기록된 시간 간격대로 재생 (속도 배율 적용)
+/// * 재생/일시 정지/탐색/속도 변경/정지
+/// * 재생 데이터는 `replay = true` 로 표시하여 일반 구독 경로로 전달
+/// * 동시에 하나의 녹화 파일만 재생 (새로 재생하면 이전 재생 정지)
+pub struct Player {
+    dir: PathBuf,
+    tx: mpsc::Sender<LiDARPacket>,
+    control: Mutex<Option<Arc<Mutex<Control>>>>,
+}
+
+impl Player {
+    pub fn new(dir: PathBuf, tx: mpsc::Sender<LiDARPacket>) -> Self {
+        Self {
+            dir,
+            tx,
+            control: Mutex::new(None),
+        }
+    }
+
+    /// 녹화 디렉터리
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 녹화 파일 재생 시작
+    ///
+    /// # Arguments
+    /// * `name` - 녹화 파일 이름
+    /// * `speed` - 재생 속도 배율 (0 보다 커야 함)
+    ///
+    /// # Returns
+    /// * `Result<PlaybackStatus, String>` - 성공 시 재생 상태, 실패 시 에러 메시지
+    pub fn start(&self, name: &str, speed: f64) -> Result<PlaybackStatus, String> {
+        validate_speed(speed)?;
+        let path = recording_path(&self.dir, name)?;
+        let (index, duration_ms) = build_index(&path)?;
+
+        let control = Arc::new(Mutex::new(Control {
+            status: PlaybackStatus {
+                name: name.to_string(),
+                playing: true,
+                paused: false,
+                speed,
+                position_ms: 0,
+                duration_ms,
+            },
+            seek_ms: None,
+            stop: false,
+        }));
+
+        let thread_control = control.clone();
+        let tx = self.tx.clone();
+        std::thread::Builder::new()
+            .name("playback".to_string())
+            .spawn(move || {
+                if let Err(e) = run(&path, &index, &thread_control, &tx) {
+                    error!("Playback of {} failed: {}", path.display(), e);
+                }
+                thread_control.lock().unwrap().status.playing = false;
+            })
+            .map_err(|e| format!("Failed to spawn playback thread: {}", e))?;
+
+        let mut current = self.control.lock().unwrap();
+        if let Some(previous) = current.replace(control.clone()) {
+            previous.lock().unwrap().stop = true;
+        }
+        info!("Playback started: {} (x{})", name, speed);
+        let status = control.lock().unwrap().status.clone();
+        Ok(status)
+    }
+
+    /// 일시 정지
+    pub fn pause(&self) -> Result<PlaybackStatus, String> {
+        self.update(|control| control.status.paused = true)
+    }
+
+    /// 일시 정지 해제
+    pub fn resume(&self) -> Result<PlaybackStatus, String> {
+        self.update(|control| control.status.paused = false)
+    }
+
+    /// 재생 위치 이동
+    ///
+    /// # Arguments
+    /// * `position_ms` - 녹화 시작 기준 이동할 위치 (ms)
+    pub fn seek(&self, position_ms: u64) -> Result<PlaybackStatus, String> {
+        self.update(|control| {
+            control.seek_ms = Some(position_ms);
+            control.status.position_ms = position_ms.min(control.status.duration_ms);
+        })
+    }
+
+    /// 재생 속도 변경
+    ///
+    /// # Arguments
+    /// * `speed` - 재생 속도 배율 (0 보다 커야 함)
+    pub fn set_speed(&self, speed: f64) -> Result<PlaybackStatus, String> {
+        validate_speed(speed)?;
+        self.update(|control| control.status.speed = speed)
+    }
+
+    /// 재생 정지
+    pub fn stop(&self) -> Result<PlaybackStatus, String> {
+        let control = self
+            .control
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| "No playback".to_string())?;
+        let mut control = control.lock().unwrap();
+        control.stop = true;
+        control.status.playing = false;
+        info!("Playback stopped: {}", control.status.name);
+        Ok(control.status.clone())
+    }
+
+    /// 현재 재생 상태 (재생한 적이 없거나 정지했으면 None)
+    pub fn status(&self) -> Option<PlaybackStatus> {
+        self.control
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|control| control.lock().unwrap().status.clone())
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Control)) -> Result<PlaybackStatus, String> {
+        let current = self.control.lock().unwrap();
+        let control = current.as_ref().ok_or_else(|| "No playback".to_string())?;
+        let mut control = control.lock().unwrap();
+        if !control.status.playing {
+            return Err(format!("Playback finished: {}", control.status.name));
+        }
+        f(&mut control);
+        Ok(control.status.clone())
+    }
+}
+
+fn validate_speed(speed: f64) -> Result<(), String> {
+    if speed.is_finite() && speed > 0.0 {
+        Ok(())
+    } else {
+        Err(format!("Invalid playback speed: {}", speed))
+    }
+}
+
+/// 녹화 파일의 (녹화 시작 기준 시각, 파일 위치) 색인과 전체 길이 생성
+fn build_index(path: &Path) -> Result<(Vec<(u64, u64)>, u64), String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut reader = BufReader::new(file);
+    read_header(&mut reader).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let mut index = Vec::new();
+    let mut offset = 5u64;
+    let mut start_ms = None;
+    loop {
+        match read_entry(&mut reader) {
+            Ok(Some((entry, len))) => {
+                let start_ms = *start_ms.get_or_insert(entry.time_ms);
+                index.push((entry.time_ms.saturating_sub(start_ms), offset));
+                offset += len;
+            }
+            Ok(None) => break,
+            Err(e) => {
+                // 녹화 중 종료되어 마지막 항목이 잘린 경우 그 앞까지만 재생
+                warn!("{}: truncated at {} bytes: {}", path.display(), offset, e);
+                break;
+            }
+        }
+    }
+
+    let duration_ms = index.last().map(|(time, _)| *time).unwrap_or(0);
+    Ok((index, duration_ms))
+}
+
+/// 재생 스레드 본체
+fn run(
+    path: &Path,
+    index: &[(u64, u64)],
+    control: &Mutex<Control>,
+    tx: &mpsc::Sender<LiDARPacket>,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut next = 0;
+    // (기준 실제 시각, 기준 재생 위치, 기준 속도)
+    let mut base: Option<(Instant, u64, f64)> = None;
+
+    loop {
+        let (paused, speed) = {
+            let mut control = control.lock().unwrap();
+            if control.stop {
+                return Ok(());
+            }
+            if let Some(seek_ms) = control.seek_ms.take() {
+                next = index.partition_point(|(time, _)| *time < seek_ms);
+                base = None;
+            }
+            (control.status.paused, control.status.speed)
+        };
+
+        if paused {
+            base = None;
+            std::thread::sleep(CONTROL_POLL);
+            continue;
+        }
+
+        let Some(&(time_ms, offset)) = index.get(next) else {
+            info!("Playback finished: {}", path.display());
+            return Ok(());
+        };
+
+        let (base_instant, base_ms, base_speed) =
+            *base.get_or_insert((Instant::now(), time_ms, speed));
+        if base_speed != speed {
+            base = None;
+            continue;
+        }
+
+        let due = base_instant
+            + Duration::from_secs_f64(time_ms.saturating_sub(base_ms) as f64 / 1000.0 / speed);
+        let now = Instant::now();
+        if now < due {
+            std::thread::sleep((due - now).min(CONTROL_POLL));
+            continue;
+        }
+
+        reader.seek(SeekFrom::Start(offset))?;
+        if let Some((entry, _)) = read_entry(&mut reader)? {
+            let packet = LiDARPacket {
+                key: entry.key,
+                payload: entry.payload,
+                replay: true,
+                time_ms,
+                trace_id: TraceId::next(),
+            };
+            if tx.blocking_send(packet).is_err() {
+                return Ok(());
+            }
+        }
+        control.lock().unwrap().status.position_ms = time_ms;
+        next += 1;
+    }
+}
@@ -0,0 +1,139 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tokio::sync::mpsc;
+use tracing::*;
+
+use crate::common::data::LiDARPacket;
+use crate::common::time::unix_time_ms;
+use crate::record::config::RecorderConfig;
+use crate::record::format::{
+    manifest_path, write_entry, write_header, write_manifest, Manifest, Marker, RecordEntry,
+    EXTENSION,
+};
+
+/// LiDAR 데이터 녹화기
+///
+/// # Examples
+/// ```
+/// let recorder = Recorder::start(&config.record)?;
+/// recorder.record(&packet);
+/// recorder.annotate(marker)?;
+/// ```
+///
+/// # 주요 기능
+/// * 서버 시작 시 `session-<unix_ms>.lrec` 녹화 파일과 `session-<unix_ms>.json` 목록 파일(manifest) 생성
+/// * 별도 스레드에서 파일 쓰기 (수신 경로를 막지 않음), 대기열이 비면 flush
+/// * 대기열이 가득 차면 해당 데이터는 녹화하지 않고 경고 로그 출력
+/// * 주석 마커를 추가할 때마다 목록 파일 갱신
+pub struct Recorder {
+    tx: mpsc::Sender<LiDARPacket>,
+    manifest_path: PathBuf,
+    manifest: Mutex<Manifest>,
+}
+
+impl Recorder {
+    /// 녹화 시작
+    ///
+    /// # Arguments
+    /// * `config` - 녹화 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 녹화기, 실패 시 에러 메시지
+    pub fn start(config: &RecorderConfig) -> Result<Self, String> {
+        std::fs::create_dir_all(&config.dir)
+            .map_err(|e| format!("Failed to create {}: {}", config.dir.display(), e))?;
+
+        let started_ms = unix_time_ms();
+        let name = format!("session-{}.{}", started_ms, EXTENSION);
+        let path = config.dir.join(&name);
+        let file = File::create(&path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        let mut writer = BufWriter::new(file);
+        write_header(&mut writer)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+        let manifest_path = manifest_path(&path);
+        let manifest = Manifest {
+            recording: name,
+            started_ms,
+            markers: Vec::new(),
+        };
+        write_manifest(&manifest_path, &manifest)
+            .map_err(|e| format!("Failed to write {}: {}", manifest_path.display(), e))?;
+
+        let (tx, mut rx) = mpsc::channel::<LiDARPacket>(config.queue_size.max(1));
+        let thread_path = path.clone();
+        std::thread::Builder::new()
+            .name("recorder".to_string())
+            .spawn(move || {
+                while let Some(packet) = rx.blocking_recv() {
+                    let entry = RecordEntry {
+                        time_ms: unix_time_ms(),
+                        key: packet.key,
+                        payload: packet.payload,
+                    };
+                    // 대기 중인 데이터가 없으면 flush 하여 종료 시 유실 최소화
+                    let result = write_entry(&mut writer, &entry).and_then(|_| {
+                        if rx.is_empty() {
+                            writer.flush()
+                        } else {
+                            Ok(())
+                        }
+                    });
+                    if let Err(e) = result {
+                        error!("Failed to write {}: {}", thread_path.display(), e);
+                        break;
+                    }
+                }
+            })
+            .map_err(|e| format!("Failed to spawn recorder thread: {}", e))?;
+
+        info!("Recording to {}", path.display());
+        Ok(Self {
+            tx,
+            manifest_path,
+            manifest: Mutex::new(manifest),
+        })
+    }
+
+    /// 녹화 파일 이름
+    pub fn name(&self) -> String {
+        self.manifest.lock().unwrap().recording.clone()
+    }
+
+    /// LiDAR 데이터 녹화 (재생 중인 데이터는 녹화하지 않음)
+    pub fn record(&self, packet: &LiDARPacket) {
+        if packet.replay {
+            return;
+        }
+        if let Err(mpsc::error::TrySendError::Full(_)) = self.tx.try_send(packet.clone()) {
+            warn!("Recorder queue full, dropping packet");
+        }
+    }
+
+    /// 주석 마커를 목록 파일에 추가
+    ///
+    /// # Arguments
+    /// * `marker` - 추가할 마커
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 목록 파일 쓰기 실패 시 에러 메시지 (마커는 추가하지 않음)
+    ///
+    /// # 동작 설명
+    /// * 목록 파일 전체를 다시 쓰므로 blocking 스레드에서 호출
+    pub fn annotate(&self, marker: Marker) -> Result<(), String> {
+        let mut manifest = self.manifest.lock().unwrap();
+        manifest.markers.push(marker);
+        if let Err(e) = write_manifest(&self.manifest_path, &manifest) {
+            manifest.markers.pop();
+            return Err(format!(
+                "Failed to write {}: {}",
+                self.manifest_path.display(),
+                e
+            ));
+        }
+        Ok(())
+    }
+}
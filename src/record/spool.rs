@@ -0,0 +1,202 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tokio::sync::{mpsc, oneshot};
+use tracing::*;
+
+use crate::common::data::LiDARPacket;
+use crate::common::time::unix_time_ms;
+use crate::record::config::SpoolConfig;
+use crate::record::format::{
+    list_recordings, write_entry, write_header, RecordEntry, RecordingInfo, EXTENSION,
+};
+
+/// 임시 저장 파일 이름 접두어
+pub const SPOOL_PREFIX: &str = "spool-";
+
+/// 임시 저장 스레드 명령
+enum SpoolCommand {
+    Store(LiDARPacket),
+    Rotate(oneshot::Sender<()>),
+}
+
+/// 구독자가 없을 때 LiDAR 데이터를 디스크에 임시 저장하는 링 버퍼
+///
+/// # Examples
+/// ```
+/// let spool = Spool::start(&config.record.dir, &config.record.spool)?;
+/// spool.store(&packet);
+/// let segments = spool.rotate().await?;
+/// ```
+///
+/// # 주요 기능
+/// * `spool-<unix_ms>.lrec` 세그먼트 파일에 녹화 파일 형식으로 저장 (`playback` 으로 재생 가능)
+/// * 세그먼트가 `segment_bytes` 를 넘으면 새 세그먼트 생성
+/// * 전체 크기가 `max_bytes` 를 넘으면 가장 오래된 세그먼트부터 삭제
+pub struct Spool {
+    dir: PathBuf,
+    tx: mpsc::Sender<SpoolCommand>,
+}
+
+impl Spool {
+    /// 임시 저장 시작
+    ///
+    /// # Arguments
+    /// * `dir` - 세그먼트 파일 저장 디렉터리 (녹화 디렉터리)
+    /// * `config` - 임시 저장 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 임시 저장소, 실패 시 에러 메시지
+    pub fn start(dir: &Path, config: &SpoolConfig) -> Result<Self, String> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+        let (tx, mut rx) = mpsc::channel::<SpoolCommand>(config.queue_size.max(1));
+        let mut writer = SegmentWriter {
+            dir: dir.to_path_buf(),
+            segment_bytes: config.segment_bytes.max(1),
+            max_bytes: config.max_bytes,
+            current: None,
+        };
+        std::thread::Builder::new()
+            .name("spool".to_string())
+            .spawn(move || {
+                while let Some(command) = rx.blocking_recv() {
+                    match command {
+                        SpoolCommand::Store(packet) => {
+                            if let Err(e) = writer.store(packet) {
+                                error!("Failed to spool LiDAR data: {}", e);
+                                writer.current = None;
+                            }
+                        }
+                        SpoolCommand::Rotate(ack) => {
+                            writer.close();
+                            let _ = ack.send(());
+                        }
+                    }
+                }
+                writer.close();
+            })
+            .map_err(|e| format!("Failed to spawn spool thread: {}", e))?;
+
+        info!(
+            "Spooling to {} (max {} bytes)",
+            dir.display(),
+            config.max_bytes
+        );
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            tx,
+        })
+    }
+
+    /// LiDAR 데이터 임시 저장 (재생 중인 데이터는 저장하지 않음)
+    pub fn store(&self, packet: &LiDARPacket) {
+        if packet.replay {
+            return;
+        }
+        if let Err(mpsc::error::TrySendError::Full(_)) =
+            self.tx.try_send(SpoolCommand::Store(packet.clone()))
+        {
+            warn!("Spool queue full, dropping packet");
+        }
+    }
+
+    /// 현재 세그먼트를 닫고 저장된 세그먼트 목록 반환
+    ///
+    /// # Returns
+    /// * `Result<Vec<RecordingInfo>, String>` - 성공 시 재생 가능한 세그먼트 목록, 실패 시 에러 메시지
+    pub async fn rotate(&self) -> Result<Vec<RecordingInfo>, String> {
+        let (ack_tx, ack_rx) = oneshot::channel();
+        self.tx
+            .send(SpoolCommand::Rotate(ack_tx))
+            .await
+            .map_err(|_| "Spool stopped".to_string())?;
+        ack_rx.await.map_err(|_| "Spool stopped".to_string())?;
+
+        let dir = self.dir.clone();
+        let recordings = tokio::task::spawn_blocking(move || list_segments(&dir))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())?;
+        Ok(recordings)
+    }
+}
+
+/// 세그먼트 파일 목록 (오래된 순)
+fn list_segments(dir: &Path) -> std::io::Result<Vec<RecordingInfo>> {
+    Ok(list_recordings(dir)?
+        .into_iter()
+        .filter(|recording| recording.name.starts_with(SPOOL_PREFIX))
+        .collect())
+}
+
+/// 현재 쓰고 있는 세그먼트
+struct Segment {
+    writer: BufWriter<File>,
+    bytes: u64,
+}
+
+/// 세그먼트 파일 쓰기 및 링 버퍼 크기 관리
+struct SegmentWriter {
+    dir: PathBuf,
+    segment_bytes: u64,
+    max_bytes: u64,
+    current: Option<Segment>,
+}
+
+impl SegmentWriter {
+    fn store(&mut self, packet: LiDARPacket) -> std::io::Result<()> {
+        if self.current.is_none() {
+            self.open()?;
+        }
+
+        let entry = RecordEntry {
+            time_ms: unix_time_ms(),
+            key: packet.key,
+            payload: packet.payload,
+        };
+        let Some(segment) = self.current.as_mut() else {
+            return Ok(());
+        };
+        write_entry(&mut segment.writer, &entry)?;
+        segment.bytes += 4 + entry.payload.len() as u64;
+        if segment.bytes >= self.segment_bytes {
+            self.close();
+        }
+        Ok(())
+    }
+
+    fn open(&mut self) -> std::io::Result<()> {
+        let path = self
+            .dir
+            .join(format!("{}{}.{}", SPOOL_PREFIX, unix_time_ms(), EXTENSION));
+        let mut writer = BufWriter::new(File::create(&path)?);
+        write_header(&mut writer)?;
+        self.current = Some(Segment { writer, bytes: 5 });
+        self.evict()
+    }
+
+    fn close(&mut self) {
+        if let Some(mut segment) = self.current.take() {
+            if let Err(e) = segment.writer.flush() {
+                error!("Failed to flush spool segment: {}", e);
+            }
+        }
+    }
+
+    /// 전체 크기가 최대 크기를 넘으면 오래된 세그먼트부터 삭제 (현재 세그먼트 제외)
+    fn evict(&self) -> std::io::Result<()> {
+        let segments = list_segments(&self.dir)?;
+        let mut total: u64 = segments.iter().map(|segment| segment.size).sum();
+        for segment in &segments[..segments.len().saturating_sub(1)] {
+            if total <= self.max_bytes {
+                break;
+            }
+            std::fs::remove_file(self.dir.join(&segment.name))?;
+            total -= segment.size;
+            debug!("Evicted spool segment {}", segment.name);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use std::time::{Duration, Instant};
+
+/// 클라우드 수집 서버 연결(uplink) 전송량 예산
+///
+/// # Examples
+/// ```
+/// let mut budget = UplinkBudget::new(64);
+/// if budget.try_send(event.len(), Instant::now()) {
+///     remote.send(event).await?;
+/// }
+/// tokio::time::sleep(budget.reserve(reply.len(), Instant::now())).await;
+/// remote.send(reply).await?;
+/// ```
+///
+/// # 동작 설명
+/// * 초당 `max_kbps` 만큼 채워지는 토큰 버킷 (최대 1초 분량까지 모아 둠)
+/// * 이벤트, 텔레메트리 등 버려도 되는 메시지는 남은 예산이 없으면 버림
+/// * 요청 응답과 스냅샷은 버리지 않고, 앞서 빌려 쓴 예산을 갚을 때까지 기다린 뒤 전송 (평균 전송량 유지)
+#[derive(Debug)]
+pub struct UplinkBudget {
+    bytes_per_sec: f64,
+    tokens: f64,
+    last: Instant,
+    dropped: u64,
+}
+
+impl UplinkBudget {
+    /// 전송량 예산 생성
+    ///
+    /// # Arguments
+    /// * `max_kbps` - 최대 전송량 (kbit/s)
+    pub fn new(max_kbps: u64) -> Self {
+        let bytes_per_sec = (max_kbps.max(1) * 1000 / 8) as f64;
+        Self {
+            bytes_per_sec,
+            tokens: bytes_per_sec,
+            last: Instant::now(),
+            dropped: 0,
+        }
+    }
+
+    /// 버려도 되는 메시지 전송 가능 여부 확인
+    ///
+    /// # Arguments
+    /// * `len` - 메시지 크기 (bytes)
+    /// * `now` - 현재 시각
+    ///
+    /// # Returns
+    /// * `bool` - 예산이 남아 있으면 사용 처리 후 true, 없으면 버린 수를 늘리고 false
+    pub fn try_send(&mut self, len: usize, now: Instant) -> bool {
+        self.refill(now);
+        if self.tokens < len as f64 {
+            self.dropped += 1;
+            return false;
+        }
+        self.tokens -= len as f64;
+        true
+    }
+
+    /// 버리면 안 되는 메시지의 예산 사용
+    ///
+    /// # Arguments
+    /// * `len` - 메시지 크기 (bytes)
+    /// * `now` - 현재 시각
+    ///
+    /// # Returns
+    /// * `Duration` - 전송 전에 기다릴 시간 (앞서 빌려 쓴 예산을 갚는 시간, 빌린 예산이 없으면 0)
+    pub fn reserve(&mut self, len: usize, now: Instant) -> Duration {
+        self.refill(now);
+        let wait = if self.tokens < 0.0 {
+            Duration::from_secs_f64(-self.tokens / self.bytes_per_sec)
+        } else {
+            Duration::ZERO
+        };
+        self.tokens -= len as f64;
+        wait
+    }
+
+    /// 예산이 없어 버린 메시지 수
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.last = self.last.max(now);
+        self.tokens = (self.tokens + elapsed * self.bytes_per_sec).min(self.bytes_per_sec);
+    }
+}
@@ -0,0 +1,257 @@
+use bincode::config::standard;
+use bincode::decode_from_slice;
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::net::Ipv4Addr;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::*;
+
+use crate::common::data::LiDARPacket;
+use crate::common::time::unix_time_ms;
+use crate::lidar::kanavi_mobility::KanaviMobilityData;
+use crate::lidar::{CompanyInfo, LiDARInfo, LiDARRegistry};
+use crate::logging::TraceId;
+use crate::relay::config::{RelayConfig, UpstreamConfig};
+use crate::relay::state::RelayState;
+use crate::ws::chunk::ChunkAssembler;
+use crate::ws::client::{OutputFrame, Subscription};
+use crate::ws::protocol::{Envelope, EnvelopeKind};
+use crate::ws::{framing, BinaryFraming, WireEncoding};
+
+/// 모르는 LiDAR 데이터를 받았을 때 `lidar_list` 를 다시 요청하는 최소 간격
+const UNKNOWN_LIST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 상위 서버 LiDAR 검색용 키 (송신 IP, 제품 라인, LiDAR ID)
+type UpstreamIdentity = (Ipv4Addr, u8, u8);
+
+/// 상위 LiDAR 서버 구독 시작 (릴레이 클라이언트)
+///
+/// # Examples
+/// ```
+/// start_relay(&config.relay, relay, registry.clone(), udp_to_ws_tx.clone());
+/// ```
+///
+/// # Arguments
+/// * `config` - 릴레이 연결 설정
+/// * `relay` - 릴레이 연결 고리 상태
+/// * `registry` - 상위 서버의 LiDAR 를 등록할 저장소
+/// * `udp_to_ws_tx` - 받은 LiDAR 데이터를 WebSocket 서버로 전달하는 채널
+///
+/// # 동작 설명
+/// * 상위 서버마다 v2 프로토콜로 연결하여 `hello` (`relay_path` 포함), `lidar_list`, `subscribe` 요청
+/// * 상위 서버가 보낸 포인트 클라우드 binary 프레임을 UDP 에서 받은 데이터처럼 그대로 전달
+///   (녹화, IPC, 메시지 버스 등 기존 처리 흐름을 그대로 사용)
+/// * LiDAR 키와 장치 정보는 상위 서버의 `lidar_list` 를 그대로 사용하여 여러 단계를 거쳐도 유지
+/// * `list_interval_ms` 마다, 그리고 목록에 없는 LiDAR 데이터를 받으면 `lidar_list` 를 다시 요청
+/// * 연결 고리가 감지되거나 연결이 끊기면 `reconnect_ms` 뒤 다시 연결
+pub fn start_relay(
+    config: &RelayConfig,
+    relay: RelayState,
+    registry: LiDARRegistry,
+    udp_to_ws_tx: mpsc::Sender<LiDARPacket>,
+) {
+    for upstream in config.upstreams.clone() {
+        let relay = relay.clone();
+        let registry = registry.clone();
+        let udp_to_ws_tx = udp_to_ws_tx.clone();
+        tokio::spawn(async move {
+            loop {
+                match relay_upstream(&upstream, &relay, &registry, &udp_to_ws_tx).await {
+                    Ok(()) => warn!("Upstream {} closed the connection", upstream.url),
+                    Err(e) => error!("Upstream {}: {}", upstream.url, e),
+                }
+                relay.set_upstream_path(&upstream.url, None);
+                tokio::time::sleep(Duration::from_millis(upstream.reconnect_ms.max(1))).await;
+            }
+        });
+    }
+}
+
+/// 상위 서버 하나에 연결하여 연결이 끊길 때까지 LiDAR 데이터 전달
+async fn relay_upstream(
+    upstream: &UpstreamConfig,
+    relay: &RelayState,
+    registry: &LiDARRegistry,
+    udp_to_ws_tx: &mpsc::Sender<LiDARPacket>,
+) -> Result<(), String> {
+    let (mut ws, _) = connect_async(upstream.url.as_str())
+        .await
+        .map_err(|e| format!("Failed to connect: {}", e))?;
+
+    let hello = json!({
+        "command": "hello",
+        "data": { "protocol_version": 2, "relay_path": relay.path() }
+    });
+    ws.send(Message::text(hello.to_string()))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut lidars: HashMap<UpstreamIdentity, LiDARInfo> = HashMap::new();
+    let mut list_interval =
+        tokio::time::interval(Duration::from_millis(upstream.list_interval_ms.max(1)));
+    let mut subscribed = false;
+    let mut binary_framing = BinaryFraming::None;
+    let mut chunks = ChunkAssembler::default();
+    let mut last_list = Instant::now();
+
+    loop {
+        let message = tokio::select! {
+            message = ws.next() => match message {
+                Some(Ok(message)) => message,
+                Some(Err(e)) => return Err(e.to_string()),
+                None => return Ok(()),
+            },
+            _ = list_interval.tick(), if subscribed => {
+                request_list(&mut ws).await?;
+                last_list = Instant::now();
+                continue;
+            }
+        };
+
+        match message {
+            Message::Text(text) => {
+                let Ok(mut envelope) = serde_json::from_str::<Envelope>(&text) else {
+                    continue;
+                };
+                if let Some(chunk) = &envelope.chunk {
+                    // 나눠 받은 응답은 마지막 조각까지 모아서 원래 응답으로 처리
+                    let message = match chunks.push(chunk) {
+                        Ok(Some(message)) => message,
+                        Ok(None) => continue,
+                        Err(e) => {
+                            warn!(
+                                "Dropping chunked {} from {}: {}",
+                                envelope.command, upstream.url, e
+                            );
+                            continue;
+                        }
+                    };
+                    let Ok(assembled) = serde_json::from_slice::<Envelope>(&message) else {
+                        continue;
+                    };
+                    envelope = assembled;
+                }
+                if envelope.kind == EnvelopeKind::Error {
+                    let message = envelope.error.map(|e| e.message).unwrap_or_default();
+                    return Err(format!("{} failed: {}", envelope.command, message));
+                }
+                match envelope.command.as_str() {
+                    "hello" => {
+                        let path = upstream_path(envelope.data.as_ref());
+                        if relay.is_loop(&path) {
+                            return Err(format!("Relay loop detected: {:?}", path));
+                        }
+                        info!("Relaying from {} (path {:?})", upstream.url, path);
+                        relay.set_upstream_path(&upstream.url, Some(path));
+
+                        // 응답/이벤트는 JSON 텍스트로만 해석하므로 전송 형식은 항상 JSON (자릿수 제한 없음),
+                        // UDP 로 받은 데이터처럼 다시 제공하므로 좌표계는 항상 보정하지 않은 LiDAR 좌표
+                        let subscription = Subscription {
+                            encoding: WireEncoding::Json,
+                            precision: None,
+                            convention: None,
+                            timing: false,
+                            deskew: false,
+                            frame: OutputFrame::Lidar,
+                            ..upstream.subscription.clone().unwrap_or_default()
+                        };
+                        binary_framing = subscription.framing;
+                        let subscription =
+                            serde_json::to_value(subscription).map_err(|e| e.to_string())?;
+                        ws.send(Message::text(
+                            json!({ "command": "subscribe", "data": subscription }).to_string(),
+                        ))
+                        .await
+                        .map_err(|e| e.to_string())?;
+                        subscribed = true;
+                    }
+                    "lidar_list" => {
+                        let infos: Vec<LiDARInfo> = envelope
+                            .data
+                            .and_then(|data| serde_json::from_value(data).ok())
+                            .unwrap_or_default();
+                        lidars = infos
+                            .into_iter()
+                            .map(|info| ((info.ip, info.product_line, info.lidar_id), info))
+                            .collect();
+                    }
+                    _ => {}
+                }
+            }
+            Message::Binary(payload) => {
+                let payload = match binary_framing {
+                    BinaryFraming::None => &payload[..],
+                    BinaryFraming::Crc32 => match framing::unwrap(&payload) {
+                        Ok(payload) => payload,
+                        Err(e) => {
+                            warn!("Dropping corrupted frame from {}: {}", upstream.url, e);
+                            continue;
+                        }
+                    },
+                };
+                let Some(source) = frame_source(payload) else {
+                    continue;
+                };
+                let Some(info) = lidars.get(&source) else {
+                    // 상위 서버의 LiDAR 목록을 받기 전의 데이터는 버림
+                    if last_list.elapsed() >= UNKNOWN_LIST_INTERVAL {
+                        request_list(&mut ws).await?;
+                        last_list = Instant::now();
+                    }
+                    continue;
+                };
+                let mut info = info.clone();
+                info.last_seen = unix_time_ms();
+                let key = info.key;
+                registry.import(vec![info]).await;
+                if registry.is_disabled(&key) {
+                    continue;
+                }
+                let _ = udp_to_ws_tx
+                    .send(LiDARPacket {
+                        key,
+                        payload: payload.to_vec(),
+                        replay: false,
+                        time_ms: unix_time_ms(),
+                        trace_id: TraceId::next(),
+                    })
+                    .await;
+            }
+            Message::Close(_) => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+/// 상위 서버에 `lidar_list` 요청
+async fn request_list<S>(ws: &mut S) -> Result<(), String>
+where
+    S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+{
+    ws.send(Message::text(
+        json!({ "command": "lidar_list" }).to_string(),
+    ))
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// `hello` 응답의 상위 서버 경로
+fn upstream_path(data: Option<&Value>) -> Vec<String> {
+    data.and_then(|data| data.get("relay_path"))
+        .and_then(|path| serde_json::from_value(path.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// binary 프레임(회사 구분값 + bincode)의 송신 LiDAR
+fn frame_source(payload: &[u8]) -> Option<UpstreamIdentity> {
+    let (&company, encoded) = payload.split_first()?;
+    if CompanyInfo::try_from(company).ok()? != CompanyInfo::KanaviMobility {
+        return None;
+    }
+    let (data, _): (KanaviMobilityData, usize) = decode_from_slice(encoded, standard()).ok()?;
+    Some((data.get_ip(), data.get_product_line(), data.get_lidar_id()))
+}
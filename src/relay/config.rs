@@ -0,0 +1,121 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::ws::client::Subscription;
+
+/// 릴레이 연결 설정 구조체
+///
+/// # Examples
+/// ```json
+/// {
+///     "server_id": "edge-1",
+///     "upstreams": [
+///         { "url": "ws://192.168.0.10:5555/ws", "subscription": { "lidars": [ ... ] } }
+///     ],
+///     "uplinks": [
+///         { "url": "wss://cloud.example.com/sites/connect", "name": "plant-a" }
+///     ]
+/// }
+/// ```
+///
+/// # Fields
+/// * `server_id` - 릴레이 연결 고리 확인에 사용하는 서버 ID, None 이면 시작할 때마다 UUID 생성
+/// * `upstreams` - 구독할 상위 LiDAR 서버 목록 (`relay` feature 필요)
+/// * `uplinks` - 이 서버가 먼저 연결하는 클라우드 수집 서버 목록 (`relay` feature 필요), 들어오는 연결을 막은 현장용
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct RelayConfig {
+    pub server_id: Option<String>,
+    pub upstreams: Vec<UpstreamConfig>,
+    pub uplinks: Vec<UplinkConfig>,
+}
+
+/// 상위 LiDAR 서버 설정 구조체
+///
+/// # Fields
+/// * `url` - 상위 서버 WebSocket 주소 (예: `ws://192.168.0.10:5555/ws`)
+/// * `subscription` - 상위 서버에 보낼 구독 설정, None 이면 모든 LiDAR 의 포인트 클라우드
+/// * `reconnect_ms` - 연결이 끊긴 뒤 다시 연결하기까지 대기 시간 (ms, 기본값 3000)
+/// * `list_interval_ms` - 상위 서버의 LiDAR 목록 갱신 주기 (ms, 기본값 5000)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UpstreamConfig {
+    pub url: String,
+    #[serde(default)]
+    pub subscription: Option<Subscription>,
+    #[serde(default = "default_reconnect_ms")]
+    pub reconnect_ms: u64,
+    #[serde(default = "default_list_interval_ms")]
+    pub list_interval_ms: u64,
+}
+
+/// 클라우드 수집 서버 연결(uplink) 설정 구조체
+///
+/// # Fields
+/// * `url` - 수집 서버 WebSocket 주소 (예: `wss://cloud.example.com/sites/connect`)
+/// * `name` - 등록 시 알릴 현장 이름, None 이면 서버 ID 만 알림
+/// * `reconnect_ms` - 첫 재연결 대기 시간 (ms, 기본값 1000), 연속 실패 시 두 배씩 증가
+/// * `max_reconnect_ms` - 최대 재연결 대기 시간 (ms, 기본값 60000)
+/// * `register_timeout_ms` - `register` 응답 대기 시간 (ms, 기본값 10000)
+/// * `ping_interval_ms` - 연결 유지 Ping 전송 주기 (ms, 기본값 15000, 0 이면 보내지 않음)
+/// * `raw` - 포인트 클라우드와 데이터 프레임도 전달할지 여부 (기본값 false: 이벤트, 텔레메트리, 통과 횟수, 요청 응답과
+///   `snapshot` 요청으로 받은 프레임만 전달하고 원본 포인트 클라우드는 현장에 남김)
+/// * `max_kbps` - 수집 서버로 보내는 전체 전송량 제한 (kbit/s), None 이면 제한 없음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UplinkConfig {
+    pub url: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default = "default_uplink_reconnect_ms")]
+    pub reconnect_ms: u64,
+    #[serde(default = "default_max_reconnect_ms")]
+    pub max_reconnect_ms: u64,
+    #[serde(default = "default_register_timeout_ms")]
+    pub register_timeout_ms: u64,
+    #[serde(default = "default_ping_interval_ms")]
+    pub ping_interval_ms: u64,
+    #[serde(default)]
+    pub raw: bool,
+    #[serde(default)]
+    pub max_kbps: Option<u64>,
+}
+
+impl UplinkConfig {
+    /// 연속 실패 횟수에 따른 재연결 대기 시간
+    ///
+    /// # Arguments
+    /// * `failures` - 연속 실패 횟수 (1 부터)
+    ///
+    /// # Returns
+    /// * `u64` - `reconnect_ms` 를 실패할 때마다 두 배로 늘린 대기 시간 (ms), `max_reconnect_ms` 를 넘지 않음
+    pub fn backoff_ms(&self, failures: u32) -> u64 {
+        let shift = failures.saturating_sub(1).min(32);
+        self.reconnect_ms
+            .max(1)
+            .saturating_mul(1u64 << shift)
+            .min(self.max_reconnect_ms.max(self.reconnect_ms).max(1))
+    }
+}
+
+fn default_uplink_reconnect_ms() -> u64 {
+    1000
+}
+
+fn default_max_reconnect_ms() -> u64 {
+    60000
+}
+
+fn default_register_timeout_ms() -> u64 {
+    10000
+}
+
+fn default_ping_interval_ms() -> u64 {
+    15000
+}
+
+fn default_reconnect_ms() -> u64 {
+    3000
+}
+
+fn default_list_interval_ms() -> u64 {
+    5000
+}
@@ -0,0 +1,15 @@
+pub mod budget;
+#[cfg(feature = "relay")]
+pub mod client;
+pub mod config;
+pub mod state;
+#[cfg(feature = "relay")]
+pub mod uplink;
+
+pub use budget::UplinkBudget;
+#[cfg(feature = "relay")]
+pub use client::start_relay;
+pub use config::{RelayConfig, UplinkConfig, UpstreamConfig};
+pub use state::RelayState;
+#[cfg(feature = "relay")]
+pub use uplink::start_uplinks;
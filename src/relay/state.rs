@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// 릴레이 연결 고리 상태
+///
+/// # Examples
+/// ```
+/// let relay = RelayState::new(config.relay.server_id.clone());
+/// let path = relay.path();
+/// if relay.is_loop(&upstream_path) {
+///     // 연결 종료
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * `path` - 이 서버 ID 와 연결된 모든 상위 서버의 경로 (첫 번째가 이 서버)
+/// * 하위 서버는 `hello.relay_path` 로 자신의 경로를 보내고, 상위 서버는 자신의 ID 가 있으면 거부
+/// * 하위 서버는 `hello` 응답의 `relay_path` 에 자신의 ID 가 있으면 연결 종료
+#[derive(Debug, Clone)]
+pub struct RelayState {
+    server_id: String,
+    upstream_paths: Arc<Mutex<HashMap<String, Vec<String>>>>,
+}
+
+impl RelayState {
+    /// 릴레이 상태 생성
+    ///
+    /// # Arguments
+    /// * `server_id` - 서버 ID, None 이면 UUID 생성
+    pub fn new(server_id: Option<String>) -> Self {
+        Self {
+            server_id: server_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+            upstream_paths: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn server_id(&self) -> &str {
+        &self.server_id
+    }
+
+    /// 이 서버와 상위 서버들의 ID 경로
+    pub fn path(&self) -> Vec<String> {
+        let mut path = vec![self.server_id.clone()];
+        let upstream_paths = self.upstream_paths.lock().unwrap();
+        for id in upstream_paths.values().flatten() {
+            if !path.contains(id) {
+                path.push(id.clone());
+            }
+        }
+        path
+    }
+
+    /// 다른 서버 경로에 이 서버가 포함되어 있는지 확인
+    pub fn is_loop(&self, path: &[String]) -> bool {
+        path.contains(&self.server_id)
+    }
+
+    /// 상위 서버 경로 등록/해제 (None 이면 해제)
+    pub fn set_upstream_path(&self, url: &str, path: Option<Vec<String>>) {
+        let mut upstream_paths = self.upstream_paths.lock().unwrap();
+        match path {
+            Some(path) => upstream_paths.insert(url.to_string(), path),
+            None => upstream_paths.remove(url),
+        };
+    }
+}
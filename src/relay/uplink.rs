@@ -0,0 +1,331 @@
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::*;
+
+use crate::lidar::LiDARKey;
+use crate::relay::budget::UplinkBudget;
+use crate::relay::config::{RelayConfig, UplinkConfig};
+use crate::relay::state::RelayState;
+use crate::ws::message::RequestMessage;
+use crate::ws::protocol::{Envelope, EnvelopeKind, ErrorBody, ErrorCode};
+
+/// 연결이 이 시간 이상 유지되면 재연결 대기 시간을 처음 값으로 되돌림
+const STABLE_CONNECTION: Duration = Duration::from_secs(30);
+
+/// `snapshot` 요청의 프레임 수신 대기 시간
+const SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 클라우드 수집 서버 연결(uplink) 시작
+///
+/// # Examples
+/// ```
+/// start_uplinks(&config.relay, relay, ws_port);
+/// ```
+///
+/// # Arguments
+/// * `config` - 릴레이 연결 설정 (`uplinks`)
+/// * `relay` - 릴레이 연결 고리 상태 (등록 시 서버 ID 와 경로 전달)
+/// * `ws_port` - 이 서버의 WebSocket 포트 (수집 서버 연결을 로컬 `/ws` 로 이어 줌)
+///
+/// # 동작 설명
+/// * 수집 서버마다 이 서버가 먼저 WebSocket 으로 연결하여 `register` 요청 (`server_id`, `name`, `relay_path`, `server_version`)
+/// * 수집 서버가 `register` 응답을 보내면 로컬 `/ws` 에 연결하고 두 연결 사이의 메시지를 그대로 전달
+///   (수집 서버는 일반 WebSocket 클라이언트와 같은 명령/스트림 프로토콜 사용: `hello`, `subscribe` 등)
+/// * `raw` 가 false 이면 로컬 제어 전용 연결(`/ws/control`)을 사용하여 원본 포인트 클라우드는 보내지 않고,
+///   수집 서버가 `snapshot` 을 요청하면 LiDAR 하나의 프레임 한 개만 따로 받아서 전송
+/// * `max_kbps` 를 넘으면 이벤트/텔레메트리는 버리고, 요청 응답과 스냅샷은 예산을 갚을 때까지 기다린 뒤 전송
+/// * 연결이 끊기거나 등록에 실패하면 `reconnect_ms` 부터 두 배씩 늘려 `max_reconnect_ms` 까지 기다린 뒤 다시 연결
+pub fn start_uplinks(config: &RelayConfig, relay: RelayState, ws_port: u16) {
+    for uplink in config.uplinks.clone() {
+        let relay = relay.clone();
+        tokio::spawn(async move {
+            let mut failures = 0;
+            loop {
+                let connected = Instant::now();
+                match run_uplink(&uplink, &relay, ws_port).await {
+                    Ok(()) => warn!("Uplink {} closed", uplink.url),
+                    Err(e) => error!("Uplink {}: {}", uplink.url, e),
+                }
+                failures = if connected.elapsed() >= STABLE_CONNECTION {
+                    1
+                } else {
+                    failures + 1
+                };
+                let backoff_ms = uplink.backoff_ms(failures);
+                info!("Reconnecting uplink {} in {} ms", uplink.url, backoff_ms);
+                tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+            }
+        });
+    }
+}
+
+/// 수집 서버 하나에 연결하여 연결이 끊길 때까지 로컬 `/ws` 와 메시지 전달
+async fn run_uplink(uplink: &UplinkConfig, relay: &RelayState, ws_port: u16) -> Result<(), String> {
+    let (mut remote, _) = connect_async(uplink.url.as_str())
+        .await
+        .map_err(|e| format!("Failed to connect: {}", e))?;
+
+    let register = json!({
+        "command": "register",
+        "data": {
+            "server_id": relay.server_id(),
+            "name": uplink.name,
+            "relay_path": relay.path(),
+            "server_version": env!("CARGO_PKG_VERSION"),
+        }
+    });
+    remote
+        .send(Message::text(register.to_string()))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(uplink.register_timeout_ms);
+    loop {
+        let message = tokio::time::timeout_at(deadline, remote.next())
+            .await
+            .map_err(|_| "Registration timed out".to_string())?;
+        let text = match message {
+            Some(Ok(Message::Text(text))) => text,
+            Some(Ok(Message::Close(_))) | None => {
+                return Err("Closed before registration".to_string())
+            }
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => return Err(e.to_string()),
+        };
+        let Ok(envelope) = serde_json::from_str::<Envelope>(&text) else {
+            continue;
+        };
+        if envelope.command != "register" {
+            continue;
+        }
+        if envelope.kind == EnvelopeKind::Error {
+            let message = envelope.error.map(|e| e.message).unwrap_or_default();
+            return Err(format!("Registration rejected: {}", message));
+        }
+        break;
+    }
+
+    // 원본 포인트 클라우드를 보내지 않을 때는 제어 전용 연결로 이벤트/응답만 받음
+    let local_url = if uplink.raw {
+        format!("ws://127.0.0.1:{}/ws", ws_port)
+    } else {
+        format!("ws://127.0.0.1:{}/ws/control", ws_port)
+    };
+    let (mut local, _) = connect_async(local_url.as_str())
+        .await
+        .map_err(|e| format!("Failed to connect to {}: {}", local_url, e))?;
+    info!("Uplink {} registered as {}", uplink.url, relay.server_id());
+
+    let mut budget = uplink.max_kbps.map(UplinkBudget::new);
+    let (snapshot_tx, mut snapshot_rx) = mpsc::channel::<Vec<Message>>(4);
+    let mut ping = tokio::time::interval(Duration::from_millis(uplink.ping_interval_ms.max(1)));
+    let result = 'session: loop {
+        tokio::select! {
+            message = remote.next() => match message {
+                Some(Ok(Message::Close(_))) | None => break Ok(()),
+                Some(Ok(Message::Text(text))) => match snapshot_request(&text) {
+                    Some(request) => {
+                        let snapshot_tx = snapshot_tx.clone();
+                        tokio::spawn(async move {
+                            _ = snapshot_tx.send(snapshot(ws_port, request).await).await;
+                        });
+                    }
+                    None => {
+                        if let Err(e) = local.send(Message::Text(text)).await {
+                            break Err(e.to_string());
+                        }
+                    }
+                },
+                Some(Ok(message @ Message::Binary(_))) => {
+                    if let Err(e) = local.send(message).await {
+                        break Err(e.to_string());
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => break Err(e.to_string()),
+            },
+            message = local.next() => match message {
+                Some(Ok(Message::Close(_))) | None => {
+                    _ = remote.close(None).await;
+                    break Err("Local WebSocket closed".to_string());
+                }
+                Some(Ok(message @ (Message::Text(_) | Message::Binary(_)))) => {
+                    let reply = matches!(&message, Message::Text(text) if is_reply(text));
+                    if let Err(e) = send_budgeted(&mut remote, &mut budget, message, reply).await {
+                        break Err(e);
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => break Err(e.to_string()),
+            },
+            Some(messages) = snapshot_rx.recv() => {
+                for message in messages {
+                    if let Err(e) = send_budgeted(&mut remote, &mut budget, message, true).await {
+                        break 'session Err(e);
+                    }
+                }
+            }
+            _ = ping.tick(), if uplink.ping_interval_ms > 0 => {
+                if let Err(e) = remote.send(Message::Ping(Default::default())).await {
+                    break Err(e.to_string());
+                }
+            }
+        }
+    };
+
+    if let Some(budget) = budget.filter(|budget| budget.dropped() > 0) {
+        info!(
+            "Uplink {} dropped {} messages over the {} kbps budget",
+            uplink.url,
+            budget.dropped(),
+            uplink.max_kbps.unwrap_or_default()
+        );
+    }
+    result
+}
+
+/// 전송량 예산에 따라 수집 서버로 메시지 전송
+///
+/// # Arguments
+/// * `remote` - 수집 서버 연결
+/// * `budget` - 전송량 예산 (None 이면 제한 없음)
+/// * `message` - 보낼 메시지
+/// * `reply` - 요청 응답/스냅샷 여부 (true 이면 버리지 않고 예산을 갚을 때까지 기다린 뒤 전송)
+///
+/// # Returns
+/// * `Result<(), String>` - 성공(또는 예산이 없어 버림) 시 Ok(()), 전송 실패 시 에러 메시지
+async fn send_budgeted<S>(
+    remote: &mut S,
+    budget: &mut Option<UplinkBudget>,
+    message: Message,
+    reply: bool,
+) -> Result<(), String>
+where
+    S: SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+{
+    if let Some(budget) = budget {
+        if reply {
+            tokio::time::sleep(budget.reserve(message.len(), Instant::now())).await;
+        } else if !budget.try_send(message.len(), Instant::now()) {
+            return Ok(());
+        }
+    }
+    remote.send(message).await.map_err(|e| e.to_string())
+}
+
+/// 로컬 서버가 보낸 Text 메시지가 요청 응답인지 확인 (v2 봉투의 `type` 이 `event` 가 아니거나 v1 응답의 `status` 가 있음)
+fn is_reply(text: &str) -> bool {
+    let Ok(value) = serde_json::from_str::<Value>(text) else {
+        return false;
+    };
+    match value.get("type").and_then(Value::as_str) {
+        Some(kind) => kind != "event",
+        None => value.get("status").is_some(),
+    }
+}
+
+/// 수집 서버가 보낸 `snapshot` 요청
+fn snapshot_request(text: &str) -> Option<RequestMessage> {
+    serde_json::from_str::<RequestMessage>(text)
+        .ok()
+        .filter(|request| request.command == "snapshot")
+}
+
+/// `snapshot` 요청 데이터
+///
+/// # Fields
+/// * `lidar` - 프레임을 받을 LiDAR 키
+/// * `decimation` - 포인트 솎아내기 (N 개 중 1 개, `subscribe` 의 `decimation` 과 동일)
+#[derive(Debug, Deserialize)]
+struct SnapshotRequest {
+    lidar: LiDARKey,
+    #[serde(default)]
+    decimation: Option<u32>,
+}
+
+/// `snapshot` 요청 처리
+///
+/// # Returns
+/// * `Vec<Message>` - 성공 시 응답(`lidar`, `bytes`)과 포인트 클라우드 binary 프레임, 실패 시 에러 응답
+async fn snapshot(ws_port: u16, request: RequestMessage) -> Vec<Message> {
+    let envelope = match take_snapshot(ws_port, &request.data).await {
+        Ok((lidar, frame)) => {
+            let envelope = Envelope {
+                kind: EnvelopeKind::Response,
+                id: request.id,
+                command: request.command,
+                data: Some(json!({ "lidar": lidar, "bytes": frame.len() })),
+                error: None,
+                chunk: None,
+            };
+            return vec![
+                Message::text(serde_json::to_string(&envelope).unwrap_or_default()),
+                Message::binary(frame),
+            ];
+        }
+        Err(message) => Envelope {
+            kind: EnvelopeKind::Error,
+            id: request.id,
+            command: request.command,
+            data: None,
+            error: Some(ErrorBody {
+                code: ErrorCode::InvalidRequest,
+                message,
+            }),
+            chunk: None,
+        },
+    };
+    vec![Message::text(
+        serde_json::to_string(&envelope).unwrap_or_default(),
+    )]
+}
+
+/// 로컬 `/ws` 에 따로 연결하여 LiDAR 하나의 포인트 클라우드 프레임 한 개 수신
+async fn take_snapshot(ws_port: u16, data: &Value) -> Result<(LiDARKey, Vec<u8>), String> {
+    let request: SnapshotRequest =
+        serde_json::from_value(data.clone()).map_err(|e| e.to_string())?;
+    let url = format!("ws://127.0.0.1:{}/ws", ws_port);
+    let (mut local, _) = connect_async(url.as_str())
+        .await
+        .map_err(|e| format!("Failed to connect to {}: {}", url, e))?;
+    for message in [
+        json!({ "command": "hello", "data": { "protocol_version": 2 } }),
+        json!({
+            "command": "subscribe",
+            "data": { "lidars": [request.lidar], "decimation": request.decimation }
+        }),
+    ] {
+        local
+            .send(Message::text(message.to_string()))
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    let frame = tokio::time::timeout(SNAPSHOT_TIMEOUT, async {
+        while let Some(message) = local.next().await {
+            match message.map_err(|e| e.to_string())? {
+                Message::Binary(frame) => return Ok(frame.to_vec()),
+                Message::Text(text) => {
+                    let Ok(envelope) = serde_json::from_str::<Envelope>(&text) else {
+                        continue;
+                    };
+                    if let Some(error) = envelope.error {
+                        return Err(error.message);
+                    }
+                }
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+        Err("Local WebSocket closed".to_string())
+    })
+    .await
+    .map_err(|_| "No frame received from the LiDAR".to_string());
+    _ = local.close(None).await;
+    frame?.map(|frame| (request.lidar, frame))
+}
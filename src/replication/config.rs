@@ -0,0 +1,72 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+
+/// 이중화(주/대기 서버) 설정 구조체
+///
+/// # Examples
+/// ```json
+/// { "role": "primary", "listen": "0.0.0.0:5700" }
+/// { "role": "standby", "primary": "192.168.0.10:5700", "listen": "0.0.0.0:5700" }
+/// ```
+///
+/// # Fields
+/// * `role` - 서버 역할
+/// * `listen` - 대기 서버의 연결을 받을 주소 (주 서버는 필수, 대기 서버는 역할을 넘겨받은 뒤 사용)
+/// * `primary` - 대기 서버가 연결할 주 서버 주소 (대기 서버는 필수)
+/// * `heartbeat_interval_ms` - 주 서버가 상태(heartbeat)를 보내는 주기 (ms)
+/// * `takeover_after_ms` - 대기 서버가 이 시간 (ms) 동안 상태를 받지 못하면 UDP 수신을 넘겨받음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ReplicationConfig {
+    pub role: ReplicationRole,
+    pub listen: Option<SocketAddr>,
+    pub primary: Option<SocketAddr>,
+    pub heartbeat_interval_ms: u64,
+    pub takeover_after_ms: u64,
+}
+
+impl Default for ReplicationConfig {
+    fn default() -> Self {
+        Self {
+            role: ReplicationRole::None,
+            listen: None,
+            primary: None,
+            heartbeat_interval_ms: 1000,
+            takeover_after_ms: 5000,
+        }
+    }
+}
+
+impl ReplicationConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 실패 시 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        match self.role {
+            ReplicationRole::Primary if self.listen.is_none() => {
+                Err("Replication primary requires `listen`".to_string())
+            }
+            ReplicationRole::Standby if self.primary.is_none() => {
+                Err("Replication standby requires `primary`".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// 서버 역할 열거형
+///
+/// # Variants
+/// * `None` - 이중화 미사용
+/// * `Primary` - 주 서버, 대기 서버에게 검색된 LiDAR 와 구독 상태를 주기적으로 전송
+/// * `Standby` - 대기 서버, 주 서버 상태를 복제하다가 주 서버가 멈추면 UDP 수신을 넘겨받음
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicationRole {
+    #[default]
+    None,
+    Primary,
+    Standby,
+}
@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::*;
+use uuid::Uuid;
+
+use crate::common::time::unix_time_ms;
+use crate::lidar::{LiDARInfo, LiDARRegistry};
+use crate::replication::config::ReplicationConfig;
+use crate::replication::store::SubscriptionStore;
+use crate::ws::client::Subscription;
+
+/// 주 서버가 대기 서버로 보내는 상태 (heartbeat 겸용)
+///
+/// 연결마다 `heartbeat_interval_ms` 주기로 한 줄씩 JSON 으로 전송합니다.
+///
+/// # Fields
+/// * `time_ms` - 전송 시각 (Unix time, ms)
+/// * `lidars` - 검색된 LiDAR 장치 목록
+/// * `subscriptions` - 클라이언트 UUID 별 구독 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicationSnapshot {
+    pub time_ms: u64,
+    pub lidars: Vec<LiDARInfo>,
+    pub subscriptions: HashMap<Uuid, Subscription>,
+}
+
+/// 대기 서버 연결을 받아 주기적으로 상태 전송 (주 서버)
+///
+/// # Arguments
+/// * `addr` - 대기 서버의 연결을 받을 주소
+/// * `interval_ms` - 상태 전송 주기 (ms)
+/// * `registry` - 검색된 LiDAR 장치 저장소
+/// * `subscriptions` - 클라이언트 구독 저장소
+///
+/// # Returns
+/// * `Result<(), String>` - 바인딩 실패 시 에러 메시지, 성공하면 반환하지 않음
+pub async fn serve(
+    addr: SocketAddr,
+    interval_ms: u64,
+    registry: LiDARRegistry,
+    subscriptions: SubscriptionStore,
+) -> Result<(), String> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("Failed to listen on {}: {}", addr, e))?;
+    info!("Replication listening on {}", addr);
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                info!("Standby connected: {}", peer);
+                let registry = registry.clone();
+                let subscriptions = subscriptions.clone();
+                tokio::spawn(async move {
+                    if let Err(e) =
+                        send_snapshots(stream, interval_ms, registry, subscriptions).await
+                    {
+                        warn!("Standby {} disconnected: {}", peer, e);
+                    }
+                });
+            }
+            Err(e) => error!("Failed to accept standby: {}", e),
+        }
+    }
+}
+
+/// 연결된 대기 서버로 상태 전송 (전송 실패 시 종료)
+async fn send_snapshots(
+    mut stream: TcpStream,
+    interval_ms: u64,
+    registry: LiDARRegistry,
+    subscriptions: SubscriptionStore,
+) -> Result<(), String> {
+    let mut interval = tokio::time::interval(Duration::from_millis(interval_ms.max(1)));
+    loop {
+        interval.tick().await;
+        let snapshot = ReplicationSnapshot {
+            time_ms: unix_time_ms(),
+            lidars: registry.list().await,
+            subscriptions: subscriptions.local().await,
+        };
+        let mut line = serde_json::to_vec(&snapshot).map_err(|e| e.to_string())?;
+        line.push(b'\n');
+        stream.write_all(&line).await.map_err(|e| e.to_string())?;
+    }
+}
+
+/// 주 서버 상태를 복제하다가 주 서버가 멈추면 반환 (대기 서버)
+///
+/// # Arguments
+/// * `config` - 이중화 설정
+/// * `registry` - 복제한 LiDAR 장치를 등록할 저장소
+/// * `subscriptions` - 복제한 구독을 보관할 저장소
+///
+/// # 동작 설명
+/// * 주 서버에 연결하여 상태를 받을 때마다 LiDAR 장치와 구독 복제 (연결이 끊기면 재연결)
+/// * 시작 후 또는 마지막 상태 수신 후 `takeover_after_ms` 동안 상태를 받지 못하면 반환
+pub async fn follow(
+    config: &ReplicationConfig,
+    registry: &LiDARRegistry,
+    subscriptions: &SubscriptionStore,
+) {
+    let Some(primary) = config.primary else {
+        return;
+    };
+    let takeover_after = Duration::from_millis(config.takeover_after_ms);
+    let retry = Duration::from_millis(config.heartbeat_interval_ms.max(1));
+    let mut last_heartbeat = Instant::now();
+    info!("Standby following primary {}", primary);
+
+    loop {
+        let Some(remaining) = takeover_after.checked_sub(last_heartbeat.elapsed()) else {
+            return;
+        };
+        let stream = match tokio::time::timeout(remaining, TcpStream::connect(primary)).await {
+            Ok(Ok(stream)) => stream,
+            Ok(Err(e)) => {
+                debug!("Failed to connect to primary {}: {}", primary, e);
+                tokio::time::sleep(retry.min(remaining)).await;
+                continue;
+            }
+            Err(_) => return,
+        };
+
+        let mut lines = BufReader::new(stream).lines();
+        loop {
+            let Some(remaining) = takeover_after.checked_sub(last_heartbeat.elapsed()) else {
+                return;
+            };
+            match tokio::time::timeout(remaining, lines.next_line()).await {
+                Ok(Ok(Some(line))) => match serde_json::from_str::<ReplicationSnapshot>(&line) {
+                    Ok(snapshot) => {
+                        last_heartbeat = Instant::now();
+                        registry.import(snapshot.lidars).await;
+                        subscriptions.replace_mirrored(snapshot.subscriptions).await;
+                    }
+                    Err(e) => warn!("Invalid replication snapshot: {}", e),
+                },
+                Ok(Ok(None)) => {
+                    warn!("Primary {} closed the replication link", primary);
+                    break;
+                }
+                Ok(Err(e)) => {
+                    warn!("Replication link to {} failed: {}", primary, e);
+                    break;
+                }
+                Err(_) => return,
+            }
+        }
+    }
+}
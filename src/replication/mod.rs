@@ -0,0 +1,7 @@
+pub mod config;
+pub mod link;
+pub mod store;
+
+pub use config::{ReplicationConfig, ReplicationRole};
+pub use link::{follow, serve, ReplicationSnapshot};
+pub use store::SubscriptionStore;
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::ws::client::Subscription;
+
+#[derive(Default)]
+struct StoreInner {
+    local: HashMap<Uuid, Subscription>,
+    mirrored: HashMap<Uuid, Subscription>,
+}
+
+/// 이중화용 클라이언트 구독 저장소
+///
+/// # Examples
+/// ```
+/// let store = SubscriptionStore::default();
+/// store.set(client_id, Some(subscription)).await;
+/// let snapshot = store.local().await;
+/// ```
+///
+/// # 동작 설명
+/// * `local` - 이 서버에 연결된 클라이언트의 구독 (주 서버가 대기 서버로 전송)
+/// * `mirrored` - 주 서버에서 복제한 구독, 역할을 넘겨받은 뒤 클라이언트가 `hello.resume` 으로 이전 클라이언트 UUID 를 보내면 복원
+/// * 복제 시 동일한 저장소를 공유
+#[derive(Clone, Default)]
+pub struct SubscriptionStore {
+    inner: Arc<Mutex<StoreInner>>,
+}
+
+impl SubscriptionStore {
+    /// 이 서버에 연결된 클라이언트의 구독 갱신 (None 이면 제거)
+    pub async fn set(&self, client_id: Uuid, subscription: Option<Subscription>) {
+        let mut inner = self.inner.lock().await;
+        match subscription {
+            Some(subscription) => inner.local.insert(client_id, subscription),
+            None => inner.local.remove(&client_id),
+        };
+    }
+
+    /// 이 서버에 연결된 클라이언트의 구독 목록
+    pub async fn local(&self) -> HashMap<Uuid, Subscription> {
+        self.inner.lock().await.local.clone()
+    }
+
+    /// 주 서버에서 복제한 구독으로 교체
+    pub async fn replace_mirrored(&self, subscriptions: HashMap<Uuid, Subscription>) {
+        self.inner.lock().await.mirrored = subscriptions;
+    }
+
+    /// 복제한 구독 꺼내기
+    ///
+    /// # Returns
+    /// * `Option<Subscription>` - 주 서버에서 해당 클라이언트가 사용하던 구독, 없으면 None
+    pub async fn take_mirrored(&self, client_id: &Uuid) -> Option<Subscription> {
+        self.inner.lock().await.mirrored.remove(client_id)
+    }
+}
@@ -0,0 +1,611 @@
+use bincode::config::standard;
+use bincode::decode_from_slice;
+use futures::{SinkExt, StreamExt};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::UdpSocket;
+use tokio::sync::watch;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::*;
+
+use crate::config::ServerConfig;
+use crate::health::HealthState;
+use crate::lidar::{LiDARRegistry, ParseStatsStore};
+use crate::relay::RelayState;
+use crate::replication::SubscriptionStore;
+use crate::sim::profile::{CaptureProfile, ChannelProfile, SensorProfile};
+use crate::sim::synth::SyntheticSensor;
+use crate::telemetry::TelemetryStore;
+use crate::udp::UdpListener;
+use crate::ws::{reserve_port, WsServer};
+
+/// 단계 시작 후 측정에서 제외하는 시간 (연결, 구독, LiDAR 등록)
+const WARMUP: Duration = Duration::from_secs(1);
+
+/// 측정 끝에 전송 중인 데이터를 기다리는 시간
+const DRAIN: Duration = Duration::from_millis(500);
+
+/// 송신 기록을 보관하는 시간 (이보다 늦게 도착한 프레임은 지연 시간에 포함하지 않음)
+const SENT_TTL: Duration = Duration::from_secs(5);
+
+/// 부하 테스트 옵션
+///
+/// # Fields
+/// * `config` - 서버 설정 파일 (없으면 기본 설정)
+/// * `sensors` - 마지막 단계의 가상 LiDAR 수
+/// * `clients` - 마지막 단계의 가상 WebSocket 클라이언트 수
+/// * `duration` - 전체 측정 시간 (단계마다 `duration / steps`)
+/// * `steps` - 부하를 나누어 늘리는 단계 수
+/// * `profile` - 가상 LiDAR 프로파일 파일 (`--extract-profile` 결과), 없으면 제품 라인 3 기본 프로파일
+/// * `max_drop_ratio` - 감당 가능으로 판단하는 최대 손실 비율
+/// * `max_p99_ms` - 감당 가능으로 판단하는 최대 p99 지연 시간 (ms)
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    pub config: Option<PathBuf>,
+    pub sensors: usize,
+    pub clients: usize,
+    pub duration: Duration,
+    pub steps: usize,
+    pub profile: Option<PathBuf>,
+    pub max_drop_ratio: f64,
+    pub max_p99_ms: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            config: None,
+            sensors: 16,
+            clients: 8,
+            duration: Duration::from_secs(60),
+            steps: 4,
+            profile: None,
+            max_drop_ratio: 0.01,
+            max_p99_ms: 100.0,
+        }
+    }
+}
+
+impl BenchOptions {
+    /// 명령행 인자 해석 (`bench` 뒤의 인자)
+    ///
+    /// # Examples
+    /// ```
+    /// let options = BenchOptions::parse(&["--sensors", "32", "--clients", "16", "--duration", "120"])?;
+    /// ```
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 옵션, 모르는 인자나 잘못된 값이면 에러 메시지
+    pub fn parse<S: AsRef<str>>(args: &[S]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter().map(|arg| arg.as_ref());
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("{} requires a value", arg))
+            };
+            match arg {
+                "--config" => options.config = Some(PathBuf::from(value()?)),
+                "--sensors" => options.sensors = number(arg, value()?)?,
+                "--clients" => options.clients = number(arg, value()?)?,
+                "--duration" => options.duration = Duration::from_secs(number(arg, value()?)?),
+                "--steps" => options.steps = number(arg, value()?)?,
+                "--profile" => options.profile = Some(PathBuf::from(value()?)),
+                "--max-drop" => options.max_drop_ratio = number(arg, value()?)?,
+                "--max-p99-ms" => options.max_p99_ms = number(arg, value()?)?,
+                _ => return Err(format!("Unknown bench argument: {}", arg)),
+            }
+        }
+        options.validate()?;
+        Ok(options)
+    }
+
+    /// 옵션 유효성 검사
+    pub fn validate(&self) -> Result<(), String> {
+        if self.sensors == 0 {
+            return Err("--sensors must be at least 1".to_string());
+        }
+        if self.steps == 0 {
+            return Err("--steps must be at least 1".to_string());
+        }
+        if self.duration / self.steps as u32 <= WARMUP {
+            return Err(format!(
+                "--duration must be longer than {} s per step",
+                WARMUP.as_secs()
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.max_drop_ratio) {
+            return Err("--max-drop must be 0.0 ~ 1.0".to_string());
+        }
+        if self.max_p99_ms.is_nan() || self.max_p99_ms <= 0.0 {
+            return Err("--max-p99-ms must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+
+    /// 단계별 (가상 LiDAR 수, 클라이언트 수), 마지막 단계가 `sensors`, `clients`
+    pub fn stages(&self) -> Vec<(usize, usize)> {
+        (1..=self.steps)
+            .map(|step| {
+                (
+                    (self.sensors * step).div_ceil(self.steps),
+                    (self.clients * step).div_ceil(self.steps),
+                )
+            })
+            .collect()
+    }
+}
+
+fn number<T: std::str::FromStr>(arg: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", arg, value))
+}
+
+/// 단계별 측정 결과
+///
+/// # Fields
+/// * `sensors` - 가상 LiDAR 수
+/// * `clients` - 가상 WebSocket 클라이언트 수
+/// * `sent` - 가상 LiDAR 가 보낸 데이터그램 수
+/// * `parsed` - 서버가 파싱한 데이터그램 수
+/// * `delivered` - 클라이언트들이 받은 포인트 클라우드 프레임 수 (모든 클라이언트 합)
+/// * `udp_drop_ratio` - 서버가 받지 못하거나 파싱하지 못한 데이터그램 비율
+/// * `ws_drop_ratio` - 클라이언트에 도착하지 않은 프레임 비율 (`sent * clients` 기준, UDP 손실 포함)
+/// * `p50_ms` / `p99_ms` / `max_ms` - 송신부터 클라이언트 수신까지 지연 시간 (ms)
+/// * `sustainable` - 손실 비율과 p99 지연 시간이 기준 이하인지 여부
+#[derive(Debug, Clone, Serialize)]
+pub struct StageReport {
+    pub sensors: usize,
+    pub clients: usize,
+    pub sent: u64,
+    pub parsed: u64,
+    pub delivered: u64,
+    pub udp_drop_ratio: f64,
+    pub ws_drop_ratio: f64,
+    pub p50_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    pub sustainable: bool,
+}
+
+/// 부하 테스트 결과
+///
+/// # Fields
+/// * `stages` - 단계별 측정 결과
+/// * `max_sensors` / `max_clients` - 기준을 만족한 마지막 단계의 가상 LiDAR/클라이언트 수 (만족한 단계가 없으면 0)
+/// * `saturated` - 기준을 넘은 단계가 있는지 여부 (false 면 시험한 부하보다 더 감당할 수 있음)
+/// * `scan_bytes` - 가상 LiDAR 한 대의 스캔당 데이터그램 크기 합 (bytes)
+/// * `scan_rate_hz` - 가상 LiDAR 한 대의 초당 스캔 수
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub stages: Vec<StageReport>,
+    pub max_sensors: usize,
+    pub max_clients: usize,
+    pub saturated: bool,
+    pub scan_bytes: usize,
+    pub scan_rate_hz: f64,
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Sensor load: {} bytes/scan at {:.1} Hz ({:.1} kB/s per sensor)",
+            self.scan_bytes,
+            self.scan_rate_hz,
+            self.scan_bytes as f64 * self.scan_rate_hz / 1000.0
+        )?;
+        writeln!(
+            f,
+            "{:>7} {:>7} {:>9} {:>9} {:>11} {:>8} {:>8} {:>8} {:>8} {:>8}  ok",
+            "sensors",
+            "clients",
+            "sent",
+            "parsed",
+            "delivered",
+            "udp drop",
+            "ws drop",
+            "p50 ms",
+            "p99 ms",
+            "max ms"
+        )?;
+        for stage in &self.stages {
+            writeln!(
+                f,
+                "{:>7} {:>7} {:>9} {:>9} {:>11} {:>7.2}% {:>7.2}% {:>8.1} {:>8.1} {:>8.1}  {}",
+                stage.sensors,
+                stage.clients,
+                stage.sent,
+                stage.parsed,
+                stage.delivered,
+                stage.udp_drop_ratio * 100.0,
+                stage.ws_drop_ratio * 100.0,
+                stage.p50_ms,
+                stage.p99_ms,
+                stage.max_ms,
+                if stage.sustainable { "yes" } else { "no" }
+            )?;
+        }
+        if self.saturated {
+            write!(
+                f,
+                "Max sustainable: {} sensors, {} clients",
+                self.max_sensors, self.max_clients
+            )
+        } else {
+            write!(
+                f,
+                "Max sustainable: at least {} sensors, {} clients (no stage exceeded the limits)",
+                self.max_sensors, self.max_clients
+            )
+        }
+    }
+}
+
+/// 데이터그램 송신 기록 (모든 가상 LiDAR 공유)
+#[derive(Default)]
+struct SentLog {
+    count: AtomicU64,
+    times: Mutex<HashMap<u32, Instant>>,
+}
+
+impl SentLog {
+    fn record(&self, datagram: &[u8], at: Instant) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        let mut times = self.times.lock().unwrap();
+        if times.len() > 100_000 {
+            times.retain(|_, sent| at.duration_since(*sent) < SENT_TTL);
+        }
+        times.insert(crc32fast::hash(datagram), at);
+    }
+
+    fn sent_at(&self, datagram: &[u8]) -> Option<Instant> {
+        self.times
+            .lock()
+            .unwrap()
+            .get(&crc32fast::hash(datagram))
+            .copied()
+    }
+}
+
+/// 클라이언트 수신 기록 (모든 가상 클라이언트 공유)
+#[derive(Default)]
+struct ReceiveLog {
+    count: AtomicU64,
+    latencies_us: Mutex<Vec<u64>>,
+}
+
+/// 서버와 가상 LiDAR/클라이언트를 함께 실행하여 감당 가능한 부하 측정
+///
+/// # Examples
+/// ```
+/// let report = bench::run(config, &BenchOptions::parse(&args[2..])?).await?;
+/// println!("{}", report);
+/// ```
+///
+/// # Arguments
+/// * `config` - 서버 설정 (UDP 는 127.0.0.1 임시 포트, 장치 검색은 사용하지 않도록 바꿔서 실행)
+/// * `options` - 부하 테스트 옵션
+///
+/// # Returns
+/// * `Result<BenchReport, String>` - 성공 시 단계별 측정 결과, 서버를 시작하지 못하거나 프로파일을 읽지 못하면 에러 메시지
+///
+/// # 동작 설명
+/// * 같은 프로세스에서 UDP 리스너와 WebSocket 서버를 시작
+/// * 단계마다 가상 LiDAR 와 클라이언트를 마지막 단계 수의 `step / steps` 까지 늘리고,
+///   `WARMUP` 뒤부터 단계 끝까지 송신/파싱/수신 수와 지연 시간을 측정
+/// * 가상 LiDAR 는 데이터그램마다 CRC32 와 송신 시각을 기록하고, 클라이언트는 받은 프레임의 원본 데이터그램(`raw_data`)으로
+///   송신 시각을 찾아 지연 시간 계산
+/// * 손실 비율이 `max_drop_ratio` 이하이고 p99 지연 시간이 `max_p99_ms` 이하인 마지막 단계를 감당 가능한 부하로 보고
+/// * 가상 LiDAR/클라이언트도 같은 호스트에서 실행하므로 결과는 보수적인 값
+pub async fn run(mut config: ServerConfig, options: &BenchOptions) -> Result<BenchReport, String> {
+    options.validate()?;
+    let profile = match &options.profile {
+        Some(path) => CaptureProfile::load(path)?
+            .sensors
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("No sensors in {}", path.display()))?,
+        None => default_profile(),
+    };
+
+    config.udp.addr = SocketAddr::from(([127, 0, 0, 1], 0));
+    config.udp.discovery.enabled = false;
+    let (udp_addr, ws_port, parse_stats) = start_server(config).await?;
+    info!("Bench server: UDP {}, WS port {}", udp_addr, ws_port);
+
+    let sent = Arc::new(SentLog::default());
+    let received = Arc::new(ReceiveLog::default());
+    let (stop_tx, stop_rx) = watch::channel(false);
+    let stage_duration = options.duration / options.steps as u32;
+    let mut sensors = 0;
+    let mut clients = 0;
+    let mut stages = Vec::new();
+
+    for (target_sensors, target_clients) in options.stages() {
+        while clients < target_clients {
+            let url = format!("ws://127.0.0.1:{}/ws", ws_port);
+            tokio::spawn(drive_client(
+                url,
+                sent.clone(),
+                received.clone(),
+                stop_rx.clone(),
+            ));
+            clients += 1;
+        }
+        while sensors < target_sensors {
+            let sensor = SyntheticSensor::new(profile.clone(), sensors as u64 + 1);
+            tokio::spawn(drive_sensor(
+                sensor,
+                udp_addr,
+                sent.clone(),
+                stop_rx.clone(),
+            ));
+            sensors += 1;
+        }
+        tokio::time::sleep(WARMUP).await;
+
+        let sent_start = sent.count.load(Ordering::Relaxed);
+        let parsed_start = parsed(&parse_stats).await;
+        let received_start = received.count.load(Ordering::Relaxed);
+        received.latencies_us.lock().unwrap().clear();
+        tokio::time::sleep(stage_duration - WARMUP).await;
+        let sent_count = sent.count.load(Ordering::Relaxed) - sent_start;
+        tokio::time::sleep(DRAIN).await;
+        let parsed_count = parsed(&parse_stats).await.saturating_sub(parsed_start);
+        let delivered = received.count.load(Ordering::Relaxed) - received_start;
+        let mut latencies = std::mem::take(&mut *received.latencies_us.lock().unwrap());
+
+        let stage = stage_report(
+            sensors,
+            clients,
+            sent_count,
+            parsed_count,
+            delivered,
+            &mut latencies,
+            options,
+        );
+        info!(
+            "Bench stage {} sensors / {} clients: ws drop {:.2}%, p99 {:.1} ms",
+            stage.sensors,
+            stage.clients,
+            stage.ws_drop_ratio * 100.0,
+            stage.p99_ms
+        );
+        stages.push(stage);
+    }
+    let _ = stop_tx.send(true);
+
+    let last_ok = stages.iter().take_while(|stage| stage.sustainable).last();
+    Ok(BenchReport {
+        max_sensors: last_ok.map(|stage| stage.sensors).unwrap_or(0),
+        max_clients: last_ok.map(|stage| stage.clients).unwrap_or(0),
+        saturated: stages.iter().any(|stage| !stage.sustainable),
+        scan_bytes: profile.scan_bytes(),
+        scan_rate_hz: 1000.0 / profile.frame_interval_ms.max(1.0),
+        stages,
+    })
+}
+
+/// 단계 측정 값으로 결과 계산 (`latencies` 는 정렬됨)
+pub fn stage_report(
+    sensors: usize,
+    clients: usize,
+    sent: u64,
+    parsed: u64,
+    delivered: u64,
+    latencies_us: &mut [u64],
+    options: &BenchOptions,
+) -> StageReport {
+    latencies_us.sort_unstable();
+    let percentile = |p: f64| {
+        if latencies_us.is_empty() {
+            return 0.0;
+        }
+        let index = ((latencies_us.len() as f64 * p).ceil() as usize).clamp(1, latencies_us.len());
+        latencies_us[index - 1] as f64 / 1000.0
+    };
+    let ratio = |lost: u64, total: u64| {
+        if total == 0 {
+            0.0
+        } else {
+            lost as f64 / total as f64
+        }
+    };
+    let expected = sent * clients as u64;
+    let udp_drop_ratio = ratio(sent.saturating_sub(parsed), sent);
+    let ws_drop_ratio = ratio(expected.saturating_sub(delivered), expected);
+    let p99_ms = percentile(0.99);
+    StageReport {
+        sensors,
+        clients,
+        sent,
+        parsed,
+        delivered,
+        udp_drop_ratio,
+        ws_drop_ratio,
+        p50_ms: percentile(0.5),
+        p99_ms,
+        max_ms: percentile(1.0),
+        sustainable: udp_drop_ratio.max(ws_drop_ratio) <= options.max_drop_ratio
+            && p99_ms <= options.max_p99_ms,
+    }
+}
+
+/// 프로파일이 없을 때 사용하는 제품 라인 3 (2 채널, 120°) 기본 프로파일
+fn default_profile() -> SensorProfile {
+    SensorProfile {
+        product_line: 3,
+        lidar_id: 0,
+        frames: 0,
+        frame_interval_ms: 40.0,
+        frame_jitter_ms: 1.0,
+        channels: (0..2)
+            .map(|channel| ChannelProfile {
+                channel,
+                points: 480,
+                mean_distance: 10.0,
+                distance_stddev: 4.0,
+                no_return_ratio: 0.05,
+            })
+            .collect(),
+    }
+}
+
+/// 서버가 파싱한 데이터그램 수 합
+async fn parsed(parse_stats: &ParseStatsStore) -> u64 {
+    parse_stats
+        .list()
+        .await
+        .iter()
+        .map(|stats| stats.frames_ok)
+        .sum()
+}
+
+/// UDP 리스너와 WebSocket 서버 시작
+///
+/// # Returns
+/// * `Result<(SocketAddr, u16, ParseStatsStore), String>` - UDP 주소, WebSocket 포트, 파싱 통계 저장소
+async fn start_server(config: ServerConfig) -> Result<(SocketAddr, u16, ParseStatsStore), String> {
+    let (udp_to_ws_tx, udp_to_ws_rx) = tokio::sync::mpsc::channel(1);
+    let (ws_to_udp_tx, ws_to_udp_rx) = tokio::sync::mpsc::channel(1);
+    let (ws_port, ws_listener) = reserve_port(config.ws.start_port, config.ws.max_attempts)
+        .ok_or_else(|| "Failed to find available WebSocket port".to_string())?;
+
+    let health = HealthState::new();
+    let registry = LiDARRegistry::new(config.identity.clone());
+    let parse_stats = ParseStatsStore::default();
+    let mut ws_server = WsServer::new(
+        config.ws.clone(),
+        config.record.clone(),
+        config.processing.clone(),
+        ws_to_udp_tx,
+        udp_to_ws_rx,
+        registry.clone(),
+        TelemetryStore::new(config.telemetry.clone()),
+        parse_stats.clone(),
+        config.alert.clone(),
+        config.ipc.clone(),
+        config.bus.clone(),
+        config.influx.clone(),
+        config.sites.clone(),
+        SubscriptionStore::default(),
+        RelayState::new(config.relay.server_id.clone()),
+        health.clone(),
+        config.supervisor.clone(),
+    );
+    tokio::spawn(async move {
+        ws_server.start(ws_listener).await;
+    });
+
+    let mut udp_listener = UdpListener::new(
+        config.udp,
+        registry,
+        parse_stats.clone(),
+        health.clone(),
+        config.supervisor,
+        udp_to_ws_tx,
+        ws_to_udp_rx,
+    )
+    .await
+    .map_err(|e| format!("Failed to create UDP listener: {}", e))?;
+    let udp_addr = udp_listener
+        .local_addr()
+        .map_err(|e| format!("Failed to read UDP address: {}", e))?;
+    tokio::spawn(async move {
+        udp_listener.start().await;
+    });
+    health.wait_bound().await;
+    Ok((udp_addr, ws_port, parse_stats))
+}
+
+/// 가상 LiDAR 하나 실행 (송신 기록 포함)
+async fn drive_sensor(
+    mut sensor: SyntheticSensor,
+    target: SocketAddr,
+    sent: Arc<SentLog>,
+    mut stop: watch::Receiver<bool>,
+) {
+    let socket = match UdpSocket::bind("127.0.0.1:0").await {
+        Ok(socket) => socket,
+        Err(e) => {
+            error!("Failed to bind bench sensor: {}", e);
+            return;
+        }
+    };
+    let mut next = tokio::time::Instant::now();
+    while !*stop.borrow() {
+        for datagram in sensor.next_scan() {
+            sent.record(&datagram, Instant::now());
+            let _ = socket.send_to(&datagram, target).await;
+        }
+        next += sensor.next_interval();
+        tokio::select! {
+            _ = tokio::time::sleep_until(next) => {}
+            _ = stop.changed() => {}
+        }
+    }
+}
+
+/// 가상 WebSocket 클라이언트 하나 실행 (전체 LiDAR 구독)
+async fn drive_client(
+    url: String,
+    sent: Arc<SentLog>,
+    received: Arc<ReceiveLog>,
+    mut stop: watch::Receiver<bool>,
+) {
+    let mut ws = match connect_async(url.as_str()).await {
+        Ok((ws, _)) => ws,
+        Err(e) => {
+            error!("Bench client failed to connect to {}: {}", url, e);
+            return;
+        }
+    };
+    for request in [
+        json!({ "command": "hello", "data": { "protocol_version": 2 } }),
+        json!({ "command": "subscribe", "data": {} }),
+    ] {
+        if let Err(e) = ws.send(Message::text(request.to_string())).await {
+            error!("Bench client request failed: {}", e);
+            return;
+        }
+    }
+
+    loop {
+        let message = tokio::select! {
+            message = ws.next() => message,
+            _ = stop.changed() => break,
+        };
+        let payload = match message {
+            Some(Ok(Message::Binary(payload))) => payload,
+            Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+            Some(Ok(_)) => continue,
+        };
+        let now = Instant::now();
+        // 회사 구분값 다음의 bincode 데이터는 원본 데이터그램(`raw_data`)으로 시작
+        let Some((_, encoded)) = payload.split_first() else {
+            continue;
+        };
+        let Ok((raw, _)) = decode_from_slice::<Vec<u8>, _>(encoded, standard()) else {
+            continue;
+        };
+        received.count.fetch_add(1, Ordering::Relaxed);
+        if let Some(sent_at) = sent.sent_at(&raw) {
+            received
+                .latencies_us
+                .lock()
+                .unwrap()
+                .push(now.duration_since(sent_at).as_micros() as u64);
+        }
+    }
+    let _ = ws.close(None).await;
+}
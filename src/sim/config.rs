@@ -0,0 +1,99 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+/// 가상 LiDAR(시뮬레이터) 설정 구조체
+///
+/// # Examples
+/// ```json
+/// {
+///     "enabled": true,
+///     "seed": 7,
+///     "sources": [
+///         { "profile": "profiles/pl3-parking.json", "count": 8, "rate_scale": 1.0 }
+///     ]
+/// }
+/// ```
+///
+/// # Fields
+/// * `enabled` - 서버 시작 시 가상 LiDAR 송신 여부
+/// * `target` - 데이터그램을 보낼 주소, 없으면 서버 UDP 주소 (0.0.0.0 이면 127.0.0.1)
+/// * `seed` - 난수 seed (같은 seed 면 같은 스트림 생성)
+/// * `sources` - 프로파일별 가상 LiDAR 구성
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SimConfig {
+    pub enabled: bool,
+    pub target: Option<SocketAddr>,
+    pub seed: u64,
+    pub sources: Vec<SimSource>,
+}
+
+impl Default for SimConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target: None,
+            seed: 1,
+            sources: Vec::new(),
+        }
+    }
+}
+
+/// 프로파일 하나로 만드는 가상 LiDAR 구성
+///
+/// # Fields
+/// * `profile` - `--extract-profile` 로 만든 프로파일 파일 경로
+/// * `count` - 프로파일의 LiDAR 마다 만들 가상 LiDAR 수
+/// * `rate_scale` - 스캔 속도 배율 (1.0 이면 녹화와 같은 속도)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SimSource {
+    pub profile: PathBuf,
+    pub count: usize,
+    pub rate_scale: f64,
+}
+
+impl Default for SimSource {
+    fn default() -> Self {
+        Self {
+            profile: PathBuf::new(),
+            count: 1,
+            rate_scale: 1.0,
+        }
+    }
+}
+
+impl SimConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 실패 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 사용 시 프로파일 경로가 비어 있지 않은지, 가상 LiDAR 수가 1 이상인지, 속도 배율이 0 보다 큰지 확인
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+        for source in &self.sources {
+            if source.profile.as_os_str().is_empty() {
+                return Err("sim.sources[].profile must not be empty".to_string());
+            }
+            if source.count == 0 {
+                return Err(format!(
+                    "sim source {} count must be at least 1",
+                    source.profile.display()
+                ));
+            }
+            if !(source.rate_scale.is_finite() && source.rate_scale > 0.0) {
+                return Err(format!(
+                    "sim source {} rate_scale must be greater than 0",
+                    source.profile.display()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod config;
+pub mod profile;
+pub mod synth;
+
+pub use config::{SimConfig, SimSource};
+pub use profile::{extract_profile, CaptureProfile, ChannelProfile, ProfileBuilder, SensorProfile};
+pub use synth::{run_sensor, SimRng, SyntheticSensor};
+
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::task::JoinHandle;
+use tracing::*;
+
+/// 서버 UDP 주소를 가상 LiDAR 가 보낼 주소로 변환 (0.0.0.0 이면 127.0.0.1)
+pub fn local_target(udp_addr: SocketAddr) -> SocketAddr {
+    if udp_addr.ip().is_unspecified() {
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), udp_addr.port())
+    } else {
+        udp_addr
+    }
+}
+
+/// 설정의 프로파일로 가상 LiDAR 시작
+///
+/// # Arguments
+/// * `config` - 시뮬레이터 설정
+/// * `target` - `config.target` 이 없을 때 보낼 주소
+///
+/// # Returns
+/// * `Result<Vec<JoinHandle<()>>, String>` - 성공 시 가상 LiDAR 태스크 목록, 프로파일을 읽지 못하면 에러 메시지
+///
+/// # 동작 설명
+/// * 각 `sources` 항목의 프로파일에 있는 LiDAR 마다 `count` 대씩 생성
+/// * 가상 LiDAR 마다 seed 를 1 씩 늘려 같은 프로파일이라도 서로 다른 스트림 생성
+pub fn start(config: &SimConfig, target: SocketAddr) -> Result<Vec<JoinHandle<()>>, String> {
+    let target = config.target.unwrap_or(target);
+    let mut sensors = Vec::new();
+    for source in &config.sources {
+        let profile = CaptureProfile::load(&source.profile)?;
+        for sensor in &profile.sensors {
+            for _ in 0..source.count {
+                sensors.push((sensor.clone(), source.rate_scale));
+            }
+        }
+    }
+
+    info!("Starting {} simulated LiDARs to {}", sensors.len(), target);
+    Ok(sensors
+        .into_iter()
+        .enumerate()
+        .map(|(index, (profile, rate_scale))| {
+            let sensor = SyntheticSensor::new(profile, config.seed.wrapping_add(index as u64));
+            tokio::spawn(async move {
+                if let Err(e) = run_sensor(sensor, target, rate_scale).await {
+                    error!("Simulated LiDAR stopped: {}", e);
+                }
+            })
+        })
+        .collect())
+}
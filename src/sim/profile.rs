@@ -0,0 +1,313 @@
+use bincode::config::standard;
+use bincode::decode_from_slice;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::lidar::kanavi_mobility::codec::{frame_data, FrameHeader, MODE_POINT_CLOUD};
+use crate::lidar::kanavi_mobility::KanaviMobilityData;
+use crate::lidar::{CompanyInfo, LiDARData, LiDARKey};
+use crate::record::format::{read_entry, read_header};
+
+/// 채널별 통계
+///
+/// # Fields
+/// * `channel` - 채널 번호 (파라미터 하위 4 bit)
+/// * `points` - 데이터그램 하나의 평균 포인트 수 (데이터 길이 / 2)
+/// * `mean_distance` - 반사가 있는 포인트의 평균 거리 (m)
+/// * `distance_stddev` - 반사가 있는 포인트의 거리 표준 편차 (m)
+/// * `no_return_ratio` - 반사가 없는 포인트(거리 0) 비율 (0.0 ~ 1.0)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ChannelProfile {
+    pub channel: u8,
+    pub points: usize,
+    pub mean_distance: f64,
+    pub distance_stddev: f64,
+    pub no_return_ratio: f64,
+}
+
+/// LiDAR 한 대의 통계 프로파일
+///
+/// # Fields
+/// * `product_line` - 제품 라인
+/// * `lidar_id` - LiDAR ID
+/// * `frames` - 프로파일을 만든 스캔 수
+/// * `frame_interval_ms` - 스캔 시작 간격 평균 (ms)
+/// * `frame_jitter_ms` - 스캔 시작 간격 표준 편차 (ms)
+/// * `channels` - 스캔 안에서 전송되는 순서대로 정렬한 채널별 통계
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SensorProfile {
+    pub product_line: u8,
+    pub lidar_id: u8,
+    pub frames: u64,
+    pub frame_interval_ms: f64,
+    pub frame_jitter_ms: f64,
+    pub channels: Vec<ChannelProfile>,
+}
+
+impl SensorProfile {
+    /// 스캔 하나의 데이터그램 크기 합 (bytes, 헤더와 체크섬 포함)
+    pub fn scan_bytes(&self) -> usize {
+        self.channels
+            .iter()
+            .map(|channel| 8 + channel.points * 2)
+            .sum()
+    }
+}
+
+/// 녹화 파일에서 추출한 프로파일 묶음
+///
+/// # Examples
+/// ```json
+/// {
+///     "source": "session-1700000000000.lrec",
+///     "duration_ms": 60000,
+///     "sensors": [
+///         {
+///             "product_line": 3,
+///             "lidar_id": 0,
+///             "frames": 1500,
+///             "frame_interval_ms": 40.0,
+///             "frame_jitter_ms": 1.2,
+///             "channels": [
+///                 { "channel": 0, "points": 480, "mean_distance": 12.4, "distance_stddev": 5.1, "no_return_ratio": 0.08 }
+///             ]
+///         }
+///     ]
+/// }
+/// ```
+///
+/// # Fields
+/// * `source` - 추출한 녹화 파일 이름
+/// * `duration_ms` - 녹화 길이 (ms)
+/// * `sensors` - LiDAR 별 프로파일
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CaptureProfile {
+    pub source: String,
+    pub duration_ms: u64,
+    pub sensors: Vec<SensorProfile>,
+}
+
+impl CaptureProfile {
+    /// 프로파일 파일 읽기
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 프로파일, 파일을 읽거나 해석하지 못하면 에러 메시지
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        serde_json::from_slice(&text)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    }
+
+    /// 프로파일 파일 저장
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = serde_json::to_vec_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, text).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+/// 평균/분산 누적 (Welford)
+#[derive(Debug, Default)]
+struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn stddev(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            (self.m2 / (self.count - 1) as f64).sqrt()
+        }
+    }
+}
+
+/// 채널별 누적 통계
+#[derive(Debug, Default)]
+struct ChannelStats {
+    datagrams: u64,
+    points: u64,
+    no_return: u64,
+    distance: RunningStats,
+}
+
+/// LiDAR 별 누적 통계
+#[derive(Debug)]
+struct SensorStats {
+    product_line: u8,
+    lidar_id: u8,
+    last_scan_ms: Option<u64>,
+    frames: u64,
+    interval: RunningStats,
+    order: Vec<u8>,
+    channels: BTreeMap<u8, ChannelStats>,
+}
+
+/// 원본 프레임에서 프로파일 생성
+///
+/// # Examples
+/// ```
+/// let mut builder = ProfileBuilder::default();
+/// builder.observe(key, time_ms, lidar_data.get_raw_data());
+/// let sensors = builder.finish();
+/// ```
+///
+/// # 동작 설명
+/// * 포인트 클라우드(0xDD) 프레임만 사용하고 설정 응답 등 다른 프레임은 무시
+/// * 채널 순서는 처음 나타난 순서를 사용하고, 첫 채널이 다시 나타나면 새 스캔 시작으로 보고 스캔 시작 간격을 누적
+/// * LiDAR 는 `LiDARKey` 로 구분하고 결과는 키 순서로 정렬
+#[derive(Debug, Default)]
+pub struct ProfileBuilder {
+    sensors: HashMap<LiDARKey, SensorStats>,
+}
+
+impl ProfileBuilder {
+    /// 프레임 하나 누적
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `time_ms` - 수신 시각 (ms)
+    /// * `frame` - 헤더(0xFA)부터 체크섬까지의 원본 프레임
+    pub fn observe(&mut self, key: LiDARKey, time_ms: u64, frame: &[u8]) {
+        let Some(header) = FrameHeader::parse(frame) else {
+            return;
+        };
+        if header.mode != MODE_POINT_CLOUD {
+            return;
+        }
+        let data = frame_data(frame);
+        let channel = header.channel();
+
+        let sensor = self.sensors.entry(key).or_insert_with(|| SensorStats {
+            product_line: header.product_line,
+            lidar_id: header.lidar_id,
+            last_scan_ms: None,
+            frames: 0,
+            interval: RunningStats::default(),
+            order: Vec::new(),
+            channels: BTreeMap::new(),
+        });
+
+        if sensor.order.first().is_none_or(|first| *first == channel) {
+            if let Some(last_scan_ms) = sensor.last_scan_ms {
+                sensor
+                    .interval
+                    .push(time_ms.saturating_sub(last_scan_ms) as f64);
+            }
+            sensor.last_scan_ms = Some(time_ms);
+            sensor.frames += 1;
+        }
+        if !sensor.order.contains(&channel) {
+            sensor.order.push(channel);
+        }
+
+        let stats = sensor.channels.entry(channel).or_default();
+        stats.datagrams += 1;
+        for point in data.chunks_exact(2) {
+            stats.points += 1;
+            if point[0] == 0 && point[1] == 0 {
+                stats.no_return += 1;
+            } else {
+                stats
+                    .distance
+                    .push(point[0] as f64 + point[1] as f64 * 0.01);
+            }
+        }
+    }
+
+    /// 누적한 통계로 LiDAR 별 프로파일 생성
+    pub fn finish(self) -> Vec<SensorProfile> {
+        let mut sensors: Vec<(LiDARKey, SensorStats)> = self.sensors.into_iter().collect();
+        sensors.sort_by_key(|(key, _)| (key.ip, key.port, key.product_line, key.lidar_id));
+        sensors
+            .into_iter()
+            .map(|(_, sensor)| SensorProfile {
+                product_line: sensor.product_line,
+                lidar_id: sensor.lidar_id,
+                frames: sensor.frames,
+                frame_interval_ms: sensor.interval.mean,
+                frame_jitter_ms: sensor.interval.stddev(),
+                channels: sensor
+                    .order
+                    .iter()
+                    .map(|channel| {
+                        let stats = &sensor.channels[channel];
+                        ChannelProfile {
+                            channel: *channel,
+                            points: (stats.points as f64 / stats.datagrams as f64).round() as usize,
+                            mean_distance: stats.distance.mean,
+                            distance_stddev: stats.distance.stddev(),
+                            no_return_ratio: if stats.points == 0 {
+                                0.0
+                            } else {
+                                stats.no_return as f64 / stats.points as f64
+                            },
+                        }
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// 녹화 파일에서 프로파일 추출
+///
+/// # Arguments
+/// * `path` - 녹화 파일 경로 (.lrec)
+///
+/// # Returns
+/// * `Result<CaptureProfile, String>` - 성공 시 프로파일, 파일을 읽지 못하거나 포인트 클라우드가 없으면 에러 메시지
+///
+/// # 동작 설명
+/// * Kanavi Mobility 항목의 원본 프레임(`raw_data`)만 사용하고 다른 회사/플러그인 항목은 무시
+pub fn extract_profile(path: &Path) -> Result<CaptureProfile, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut reader = BufReader::new(file);
+    read_header(&mut reader).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let mut builder = ProfileBuilder::default();
+    let mut first_ms = None;
+    let mut last_ms = 0;
+    while let Some((entry, _)) =
+        read_entry(&mut reader).map_err(|e| format!("{}: {}", path.display(), e))?
+    {
+        first_ms.get_or_insert(entry.time_ms);
+        last_ms = entry.time_ms;
+        let Some((company, encoded)) = entry.payload.split_first() else {
+            continue;
+        };
+        if *company != CompanyInfo::KanaviMobility as u8 {
+            continue;
+        }
+        if let Ok((lidar_data, _)) = decode_from_slice::<KanaviMobilityData, _>(encoded, standard())
+        {
+            builder.observe(entry.key, entry.time_ms, lidar_data.get_raw_data());
+        }
+    }
+
+    let sensors = builder.finish();
+    if sensors.is_empty() {
+        return Err(format!("No point cloud frames in {}", path.display()));
+    }
+    Ok(CaptureProfile {
+        source: path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        duration_ms: last_ms.saturating_sub(first_ms.unwrap_or(last_ms)),
+        sensors,
+    })
+}
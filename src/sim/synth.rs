@@ -0,0 +1,164 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tokio::time::Instant;
+use tracing::*;
+
+use crate::lidar::kanavi_mobility::KanaviFrameBuilder;
+use crate::sim::profile::SensorProfile;
+
+/// 이웃 포인트 거리의 상관 계수 (1 에 가까울수록 스캔 안의 거리가 부드럽게 변함)
+const NEIGHBOR_CORRELATION: f64 = 0.9;
+
+/// 최대 거리 (m, 정수부 1 byte + 소수부 0.99)
+const MAX_DISTANCE: f64 = 255.99;
+
+/// 시뮬레이션용 난수 생성기 (xorshift64*)
+///
+/// 같은 seed 면 같은 스트림을 만들어 soak 테스트 결과를 재현할 수 있음
+#[derive(Debug, Clone)]
+pub struct SimRng(u64);
+
+impl SimRng {
+    pub fn new(seed: u64) -> Self {
+        // 0 이면 계속 0 이 나오므로 고정 값과 섞음
+        Self((seed ^ 0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// 0.0 이상 1.0 미만 균등 분포
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// 표준 정규 분포 (Box-Muller)
+    pub fn next_gaussian(&mut self) -> f64 {
+        let u1 = self.next_f64().max(f64::MIN_POSITIVE);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+}
+
+/// 프로파일을 따르는 가상 LiDAR
+///
+/// # Examples
+/// ```
+/// let mut sensor = SyntheticSensor::new(profile.sensors[0].clone(), 42);
+/// for datagram in sensor.next_scan() {
+///     socket.send_to(&datagram, target).await?;
+/// }
+/// tokio::time::sleep(sensor.next_interval()).await;
+/// ```
+///
+/// # 동작 설명
+/// * 스캔마다 프로파일의 채널 순서대로 채널당 포인트 클라우드(0xDD) 프레임 하나씩 생성
+/// * 거리는 채널의 평균/표준 편차를 따르고, 이웃 포인트끼리 상관(AR(1))을 두어 실제 스캔처럼 연속적으로 변함
+/// * `no_return_ratio` 비율만큼 반사 없음(거리 0) 포인트 생성
+/// * 스캔 간격은 `frame_interval_ms` 평균, `frame_jitter_ms` 표준 편차의 정규 분포
+pub struct SyntheticSensor {
+    profile: SensorProfile,
+    rng: SimRng,
+}
+
+impl SyntheticSensor {
+    pub fn new(profile: SensorProfile, seed: u64) -> Self {
+        Self {
+            profile,
+            rng: SimRng::new(seed),
+        }
+    }
+
+    /// 가상 LiDAR 의 프로파일
+    pub fn profile(&self) -> &SensorProfile {
+        &self.profile
+    }
+
+    /// 스캔 하나의 데이터그램 생성
+    ///
+    /// # Returns
+    /// * `Vec<Vec<u8>>` - 채널 순서대로 정렬한 포인트 클라우드 프레임 (데이터그램 하나에 프레임 하나)
+    pub fn next_scan(&mut self) -> Vec<Vec<u8>> {
+        let mut datagrams = Vec::with_capacity(self.profile.channels.len());
+        for channel in &self.profile.channels {
+            let mut data = Vec::with_capacity(channel.points * 2);
+            let mut noise = self.rng.next_gaussian();
+            let innovation = (1.0 - NEIGHBOR_CORRELATION * NEIGHBOR_CORRELATION).sqrt();
+            for _ in 0..channel.points {
+                noise = NEIGHBOR_CORRELATION * noise + innovation * self.rng.next_gaussian();
+                if self.rng.next_f64() < channel.no_return_ratio {
+                    data.extend_from_slice(&[0, 0]);
+                    continue;
+                }
+                let distance = (channel.mean_distance + channel.distance_stddev * noise)
+                    .clamp(0.01, MAX_DISTANCE);
+                let meters = distance.trunc();
+                let centimeters = ((distance - meters) * 100.0).round().min(99.0);
+                data.extend_from_slice(&[meters as u8, centimeters as u8]);
+            }
+            datagrams.push(
+                KanaviFrameBuilder::new(self.profile.product_line, self.profile.lidar_id)
+                    .point_cloud(channel.channel, &data)
+                    .build(),
+            );
+        }
+        datagrams
+    }
+
+    /// 다음 스캔까지 기다릴 시간
+    pub fn next_interval(&mut self) -> Duration {
+        let interval = self.profile.frame_interval_ms
+            + self.profile.frame_jitter_ms * self.rng.next_gaussian();
+        Duration::from_secs_f64(interval.max(1.0) / 1000.0)
+    }
+}
+
+/// 가상 LiDAR 송신 실행
+///
+/// # Arguments
+/// * `sensor` - 가상 LiDAR
+/// * `target` - 데이터그램을 보낼 주소 (서버 UDP 주소)
+/// * `rate_scale` - 스캔 속도 배율 (2.0 이면 프로파일보다 2 배 빠르게 전송)
+///
+/// # Returns
+/// * `std::io::Result<()>` - 소켓을 열지 못하면 에러, 그 외에는 태스크가 취소될 때까지 반환하지 않음
+///
+/// # 동작 설명
+/// * LiDAR 마다 별도 소켓(임시 포트)을 사용하므로 서버에서는 송신 주소가 다른 LiDAR 로 보임
+/// * 스캔 시작 시각을 누적하여 전송 시간만큼 주기가 밀리지 않음
+/// * 서버가 보내는 명령(검색, 설정 요청)에는 응답하지 않음
+pub async fn run_sensor(
+    mut sensor: SyntheticSensor,
+    target: SocketAddr,
+    rate_scale: f64,
+) -> std::io::Result<()> {
+    let bind: SocketAddr = if target.ip().is_loopback() {
+        "127.0.0.1:0".parse().unwrap()
+    } else {
+        "0.0.0.0:0".parse().unwrap()
+    };
+    let socket = UdpSocket::bind(bind).await?;
+    info!(
+        "Simulating product line {} LiDAR {} from {} to {}",
+        sensor.profile.product_line,
+        sensor.profile.lidar_id,
+        socket.local_addr()?,
+        target
+    );
+
+    let mut next = Instant::now();
+    loop {
+        for datagram in sensor.next_scan() {
+            if let Err(e) = socket.send_to(&datagram, target).await {
+                warn!("Failed to send simulated datagram to {}: {}", target, e);
+            }
+        }
+        next += sensor.next_interval().div_f64(rate_scale);
+        tokio::time::sleep_until(next).await;
+    }
+}
@@ -0,0 +1,142 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::lidar::LiDARKey;
+
+/// 사이트(LiDAR 그룹) 설정 구조체
+///
+/// # Examples
+/// ```json
+/// {
+///     "check_interval_ms": 1000,
+///     "sites": [
+///         {
+///             "name": "building-a",
+///             "lidars": [
+///                 { "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 },
+///                 { "ip": "192.168.123.201", "port": 5000, "product_line": 3, "lidar_id": 0 }
+///             ],
+///             "quorum": 1
+///         }
+///     ]
+/// }
+/// ```
+///
+/// # Fields
+/// * `check_interval_ms` - 사이트 상태 확인 주기 (ms)
+/// * `sites` - 사이트 목록
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SiteConfig {
+    pub check_interval_ms: u64,
+    pub sites: Vec<SiteDefinition>,
+}
+
+impl Default for SiteConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_ms: 1000,
+            sites: Vec::new(),
+        }
+    }
+}
+
+impl SiteConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 실패 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 사이트 이름 중복 확인
+    /// * 정족수가 사이트의 LiDAR 수 이하인지 확인
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, site) in self.sites.iter().enumerate() {
+            if self.sites[..index]
+                .iter()
+                .any(|other| other.name == site.name)
+            {
+                return Err(format!("Duplicate site name: {}", site.name));
+            }
+            if site.quorum.is_some_and(|quorum| quorum > site.lidars.len()) {
+                return Err(format!(
+                    "Site {} quorum exceeds its {} LiDAR(s)",
+                    site.name,
+                    site.lidars.len()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// 사이트 이름으로 사이트 조회
+    pub fn get(&self, name: &str) -> Option<&SiteDefinition> {
+        self.sites.iter().find(|site| site.name == name)
+    }
+
+    /// LiDAR 를 오프라인으로 판단하는 기준 시간
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    ///
+    /// # Returns
+    /// * `u64` - LiDAR 가 속한 사이트들의 `offline_ms` 중 가장 짧은 값 (ms), 속한 사이트가 없으면 기본값 5000
+    pub fn offline_ms(&self, key: &LiDARKey) -> u64 {
+        self.sites
+            .iter()
+            .filter(|site| site.lidars.contains(key))
+            .map(|site| site.offline_ms)
+            .min()
+            .unwrap_or_else(default_offline_ms)
+    }
+
+    /// 사이트 목록에 속한 LiDAR 키 목록
+    ///
+    /// # Arguments
+    /// * `names` - 사이트 이름 목록
+    ///
+    /// # Returns
+    /// * `Result<Vec<LiDARKey>, String>` - 성공 시 중복을 제거한 LiDAR 키 목록, 알 수 없는 사이트이면 에러 메시지
+    pub fn members(&self, names: &[String]) -> Result<Vec<LiDARKey>, String> {
+        let mut keys = Vec::new();
+        for name in names {
+            let site = self
+                .get(name)
+                .ok_or_else(|| format!("Unknown site: {}", name))?;
+            for key in &site.lidars {
+                if !keys.contains(key) {
+                    keys.push(*key);
+                }
+            }
+        }
+        Ok(keys)
+    }
+}
+
+/// 사이트 정의 구조체
+///
+/// # Fields
+/// * `name` - 사이트 이름
+/// * `lidars` - 사이트에 속한 LiDAR 키 목록
+/// * `quorum` - 사이트를 온라인으로 판단할 최소 온라인 LiDAR 수, None 이면 모든 LiDAR
+/// * `offline_ms` - 마지막 수신 후 이 시간 (ms) 동안 데이터가 없으면 LiDAR 를 오프라인으로 판단 (기본값 5000)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SiteDefinition {
+    pub name: String,
+    pub lidars: Vec<LiDARKey>,
+    #[serde(default)]
+    pub quorum: Option<usize>,
+    #[serde(default = "default_offline_ms")]
+    pub offline_ms: u64,
+}
+
+impl SiteDefinition {
+    /// 사이트를 온라인으로 판단할 최소 온라인 LiDAR 수
+    pub fn quorum(&self) -> usize {
+        self.quorum.unwrap_or(self.lidars.len())
+    }
+}
+
+fn default_offline_ms() -> u64 {
+    5000
+}
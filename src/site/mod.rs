@@ -0,0 +1,5 @@
+pub mod config;
+pub mod monitor;
+
+pub use config::{SiteConfig, SiteDefinition};
+pub use monitor::{SiteMemberStatus, SiteMonitor, SiteStatus};
@@ -0,0 +1,150 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::HashMap;
+use tokio::sync::Mutex;
+use tracing::*;
+use utoipa::ToSchema;
+
+use crate::common::time::unix_time_ms;
+use crate::lidar::{LiDARInfo, LiDARKey};
+use crate::site::config::{SiteConfig, SiteDefinition};
+
+/// 사이트 상태 (`site_list` 응답, `site_status` 이벤트)
+///
+/// # Fields
+/// * `name` - 사이트 이름
+/// * `online` - 온라인 LiDAR 수가 정족수 이상이면 true
+/// * `online_lidars` - 온라인 LiDAR 수
+/// * `quorum` - 사이트를 온라인으로 판단할 최소 온라인 LiDAR 수
+/// * `lidars` - LiDAR 별 상태
+/// * `time_ms` - 상태 확인 시각 (Unix time, ms)
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct SiteStatus {
+    pub name: String,
+    pub online: bool,
+    pub online_lidars: usize,
+    pub quorum: usize,
+    pub lidars: Vec<SiteMemberStatus>,
+    pub time_ms: u64,
+}
+
+/// 사이트에 속한 LiDAR 상태
+///
+/// # Fields
+/// * `key` - LiDAR 키
+/// * `online` - 마지막 수신 후 `offline_ms` 가 지나지 않았으면 true
+/// * `last_seen` - 마지막 수신 시각 (Unix time, ms), 수신한 적이 없으면 null
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct SiteMemberStatus {
+    pub key: LiDARKey,
+    pub online: bool,
+    pub last_seen: Option<u64>,
+}
+
+/// 사이트 상태 감시기
+///
+/// # Examples
+/// ```
+/// let monitor = SiteMonitor::new(config.sites.clone());
+/// let lidars = registry.list().await;
+/// for status in monitor.check(&lidars).await {
+///     // site_status 이벤트 전송
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 등록된 LiDAR 목록의 마지막 수신 시각으로 사이트별 온라인 LiDAR 수 계산
+/// * 온라인 LiDAR 수가 정족수 이상이면 사이트 온라인
+/// * `check` 는 이전 확인 이후 온라인/오프라인이 바뀐 사이트만 반환 (처음 확인은 변경으로 보지 않음)
+pub struct SiteMonitor {
+    config: SiteConfig,
+    online: Mutex<HashMap<String, bool>>,
+}
+
+impl SiteMonitor {
+    pub fn new(config: SiteConfig) -> Self {
+        Self {
+            config,
+            online: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &SiteConfig {
+        &self.config
+    }
+
+    /// 모든 사이트 상태
+    ///
+    /// # Arguments
+    /// * `lidars` - 등록된 LiDAR 장치 목록
+    ///
+    /// # Returns
+    /// * `Vec<SiteStatus>` - 설정 순서대로 사이트 상태
+    pub fn status(&self, lidars: &[LiDARInfo]) -> Vec<SiteStatus> {
+        let now_ms = unix_time_ms();
+        self.config
+            .sites
+            .iter()
+            .map(|site| site_status(site, lidars, now_ms))
+            .collect()
+    }
+
+    /// 사이트 상태 확인
+    ///
+    /// # Arguments
+    /// * `lidars` - 등록된 LiDAR 장치 목록
+    ///
+    /// # Returns
+    /// * `Vec<SiteStatus>` - 온라인/오프라인이 바뀐 사이트 상태
+    pub async fn check(&self, lidars: &[LiDARInfo]) -> Vec<SiteStatus> {
+        let mut online = self.online.lock().await;
+        let mut changed = Vec::new();
+        for status in self.status(lidars) {
+            match online.insert(status.name.clone(), status.online) {
+                Some(previous) if previous != status.online => {
+                    info!(
+                        "Site {} is {} ({}/{} LiDARs online, quorum {})",
+                        status.name,
+                        if status.online { "online" } else { "offline" },
+                        status.online_lidars,
+                        status.lidars.len(),
+                        status.quorum
+                    );
+                    changed.push(status);
+                }
+                _ => {}
+            }
+        }
+        changed
+    }
+}
+
+/// 사이트 상태 계산
+fn site_status(site: &SiteDefinition, lidars: &[LiDARInfo], now_ms: u64) -> SiteStatus {
+    let members: Vec<SiteMemberStatus> = site
+        .lidars
+        .iter()
+        .map(|key| {
+            let last_seen = lidars
+                .iter()
+                .find(|info| info.key == *key)
+                .map(|info| info.last_seen);
+            SiteMemberStatus {
+                key: *key,
+                online: last_seen
+                    .is_some_and(|last_seen| now_ms.saturating_sub(last_seen) <= site.offline_ms),
+                last_seen,
+            }
+        })
+        .collect();
+    let online_lidars = members.iter().filter(|member| member.online).count();
+    let quorum = site.quorum();
+    SiteStatus {
+        name: site.name.clone(),
+        online: online_lidars >= quorum,
+        online_lidars,
+        quorum,
+        lidars: members,
+        time_ms: now_ms,
+    }
+}
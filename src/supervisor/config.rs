@@ -0,0 +1,29 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// 내부 태스크 감시 설정 구조체
+///
+/// # Fields
+/// * `initial_backoff_ms` - 패닉한 태스크를 처음 다시 시작하기 전 대기 시간 (ms), 다시 패닉할 때마다 두 배
+/// * `max_backoff_ms` - 다시 시작 대기 시간 최대값 (ms)
+/// * `max_restarts` - `window_ms` 안에 허용하는 재시작 횟수, 넘으면 프로세스 종료 (systemd 등 외부 감시자가 다시 시작)
+/// * `window_ms` - 재시작 횟수를 세는 기간 (ms), 태스크가 이 시간 이상 실행되면 대기 시간도 초기화
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SupervisorConfig {
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub max_restarts: usize,
+    pub window_ms: u64,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 10000,
+            max_restarts: 5,
+            window_ms: 60000,
+        }
+    }
+}
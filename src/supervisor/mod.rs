@@ -0,0 +1,5 @@
+pub mod config;
+pub mod task;
+
+pub use config::SupervisorConfig;
+pub use task::{install_panic_hook, supervise};
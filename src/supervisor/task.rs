@@ -0,0 +1,119 @@
+use futures::FutureExt;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tracing::*;
+
+use crate::supervisor::config::SupervisorConfig;
+
+/// 패닉 정보를 로그 파일에도 남기도록 panic hook 설치
+///
+/// 기존 hook(표준 에러 출력, `RUST_BACKTRACE` backtrace)도 그대로 호출
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let thread = std::thread::current();
+        error!(
+            "Thread {} panicked: {}",
+            thread.name().unwrap_or("<unnamed>"),
+            info
+        );
+        default_hook(info);
+    }));
+}
+
+/// 패닉하면 다시 시작하는 감시 태스크 실행
+///
+/// # Examples
+/// ```
+/// let handle = supervise("udp-send", &config.supervisor, move || {
+///     let rx = rx.clone();
+///     async move { send_loop(rx).await }
+/// });
+/// ```
+///
+/// # Arguments
+/// * `name` - 로그에 사용할 태스크 이름
+/// * `config` - 태스크 감시 설정
+/// * `factory` - 실행할 future 생성 함수, 다시 시작할 때마다 호출
+///
+/// # Returns
+/// * `JoinHandle<()>` - 감시 태스크 핸들, abort 하면 실행 중인 future 도 함께 종료
+///
+/// # 동작 설명
+/// * future 가 정상 종료되면 다시 시작하지 않고 종료
+/// * 패닉하면 태스크 이름, 실행 시간, 패닉 메시지를 로그로 남기고 대기 후 다시 시작
+///   (대기 시간은 `initial_backoff_ms` 부터 패닉할 때마다 두 배, 최대 `max_backoff_ms`)
+/// * `window_ms` 안에 `max_restarts` 번 넘게 패닉하면 프로세스 종료 (exit code 1)
+pub fn supervise<F, Fut>(
+    name: &'static str,
+    config: &SupervisorConfig,
+    mut factory: F,
+) -> JoinHandle<()>
+where
+    F: FnMut() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let config = config.clone();
+    tokio::spawn(async move {
+        let initial_backoff = Duration::from_millis(config.initial_backoff_ms);
+        let max_backoff = Duration::from_millis(config.max_backoff_ms);
+        let window = Duration::from_millis(config.window_ms);
+        let mut backoff = initial_backoff;
+        let mut restarts: VecDeque<Instant> = VecDeque::new();
+        loop {
+            let started = Instant::now();
+            let Err(payload) = AssertUnwindSafe(factory()).catch_unwind().await else {
+                debug!("Task {} finished", name);
+                return;
+            };
+            let elapsed = started.elapsed();
+            if elapsed >= window {
+                backoff = initial_backoff;
+            }
+
+            let now = Instant::now();
+            while restarts
+                .front()
+                .is_some_and(|restart| now.duration_since(*restart) > window)
+            {
+                restarts.pop_front();
+            }
+            if restarts.len() >= config.max_restarts {
+                error!(
+                    "Task {} panicked {} times within {} ms, last: {}; exiting",
+                    name,
+                    restarts.len() + 1,
+                    config.window_ms,
+                    panic_message(payload.as_ref())
+                );
+                std::process::exit(1);
+            }
+            restarts.push_back(now);
+
+            error!(
+                "Task {} panicked after {} ms: {}; restarting in {} ms ({}/{})",
+                name,
+                elapsed.as_millis(),
+                panic_message(payload.as_ref()),
+                backoff.as_millis(),
+                restarts.len(),
+                config.max_restarts
+            );
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(max_backoff);
+        }
+    })
+}
+
+/// 패닉 payload 에서 메시지 추출
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
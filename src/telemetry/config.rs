@@ -0,0 +1,57 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// 장치 상태 텔레메트리 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 텔레메트리 수집 사용 여부
+/// * `sample_interval_ms` - 샘플 생성 및 `telemetry` 이벤트 전송 주기 (ms)
+/// * `history` - LiDAR 별로 메모리에 보관할 최대 샘플 수
+/// * `persist` - 샘플 디스크 보관 설정
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    pub sample_interval_ms: u64,
+    pub history: usize,
+    pub persist: MetricHistoryConfig,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sample_interval_ms: 1000,
+            history: 600,
+            persist: MetricHistoryConfig::default(),
+        }
+    }
+}
+
+/// 메트릭 디스크 보관 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 텔레메트리 샘플을 디스크에 보관할지 여부
+/// * `dir` - 메트릭 파일 저장 디렉터리 (일 단위 `metrics-<day>.jsonl` 파일)
+/// * `retention_days` - 보관 기간 (일), 지난 파일은 삭제
+/// * `queue_size` - 저장 대기열 크기, 가득 차면 해당 샘플은 저장하지 않음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct MetricHistoryConfig {
+    pub enabled: bool,
+    pub dir: PathBuf,
+    pub retention_days: u64,
+    pub queue_size: usize,
+}
+
+impl Default for MetricHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: PathBuf::from("metrics"),
+            retention_days: 7,
+            queue_size: 64,
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+use tracing::*;
+
+use crate::common::daylog::{read_days, DayWriter};
+use crate::lidar::LiDARKey;
+use crate::telemetry::config::MetricHistoryConfig;
+use crate::telemetry::store::TelemetrySample;
+
+/// 메트릭 파일 이름 접두어
+pub const METRICS_PREFIX: &str = "metrics-";
+
+/// 텔레메트리 샘플을 디스크에 보관하는 메트릭 이력 저장소
+///
+/// # Examples
+/// ```
+/// let history = MetricHistory::start(&config.telemetry.persist)?;
+/// history.store(&samples);
+/// let samples = history.query(key, from_ms, to_ms, Some(60_000), 1000).await?;
+/// ```
+///
+/// # 주요 기능
+/// * 일 단위 `metrics-<unix_day>.jsonl` 파일에 샘플을 한 줄씩 JSON 으로 저장
+/// * 날짜가 바뀌면 새 파일을 만들고 `retention_days` 가 지난 파일 삭제
+/// * 기간/집계 간격을 지정해 LiDAR 별 이력 조회 (Prometheus 가 없는 현장용)
+pub struct MetricHistory {
+    dir: PathBuf,
+    tx: mpsc::Sender<Vec<TelemetrySample>>,
+}
+
+impl MetricHistory {
+    /// 메트릭 보관 시작
+    ///
+    /// # Arguments
+    /// * `config` - 메트릭 디스크 보관 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 메트릭 이력 저장소, 실패 시 에러 메시지
+    pub fn start(config: &MetricHistoryConfig) -> Result<Self, String> {
+        std::fs::create_dir_all(&config.dir)
+            .map_err(|e| format!("Failed to create {}: {}", config.dir.display(), e))?;
+
+        let (tx, mut rx) = mpsc::channel::<Vec<TelemetrySample>>(config.queue_size.max(1));
+        let mut writer = DayWriter::new(config.dir.clone(), METRICS_PREFIX, config.retention_days);
+        writer.prune();
+        std::thread::Builder::new()
+            .name("metrics".to_string())
+            .spawn(move || {
+                while let Some(samples) = rx.blocking_recv() {
+                    if let Err(e) = writer.write(&samples) {
+                        error!("Failed to store metrics: {}", e);
+                    }
+                }
+            })
+            .map_err(|e| format!("Failed to spawn metrics thread: {}", e))?;
+
+        info!(
+            "Storing metrics to {} ({} days)",
+            config.dir.display(),
+            config.retention_days
+        );
+        Ok(Self {
+            dir: config.dir.clone(),
+            tx,
+        })
+    }
+
+    /// 샘플 저장
+    pub fn store(&self, samples: &[TelemetrySample]) {
+        if samples.is_empty() {
+            return;
+        }
+        if let Err(mpsc::error::TrySendError::Full(_)) = self.tx.try_send(samples.to_vec()) {
+            warn!("Metrics queue full, dropping samples");
+        }
+    }
+
+    /// LiDAR 메트릭 이력 조회
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `from_ms` - 조회 시작 시각 (Unix time, ms)
+    /// * `to_ms` - 조회 종료 시각 (Unix time, ms)
+    /// * `step_ms` - 집계 간격 (ms), None 이면 저장된 샘플 그대로 반환
+    /// * `limit` - 최대 샘플 수 (넘으면 최근 샘플만 반환)
+    ///
+    /// # Returns
+    /// * `Result<Vec<TelemetrySample>, String>` - 성공 시 오래된 순 샘플 목록, 실패 시 에러 메시지
+    pub async fn query(
+        &self,
+        key: LiDARKey,
+        from_ms: u64,
+        to_ms: u64,
+        step_ms: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<TelemetrySample>, String> {
+        let dir = self.dir.clone();
+        let samples = tokio::task::spawn_blocking(move || {
+            read_days(
+                &dir,
+                METRICS_PREFIX,
+                from_ms,
+                to_ms,
+                |sample: &TelemetrySample| {
+                    sample.key == key && (from_ms..=to_ms).contains(&sample.time_ms)
+                },
+            )
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+        let mut samples = match step_ms {
+            Some(step_ms) => aggregate(samples, step_ms.max(1)),
+            None => samples,
+        };
+        let skip = samples.len().saturating_sub(limit);
+        Ok(samples.split_off(skip))
+    }
+}
+
+/// `step_ms` 간격으로 샘플 집계
+///
+/// 주기(packet_rate, frame_rate)와 평균 포인트 수는 평균, 횟수는 합계, 재정렬 깊이는 최대값, 모터 속도는 마지막 값을 사용하며
+/// 집계된 샘플의 시각은 구간 시작 시각입니다.
+fn aggregate(samples: Vec<TelemetrySample>, step_ms: u64) -> Vec<TelemetrySample> {
+    let mut result: Vec<(TelemetrySample, u32)> = Vec::new();
+    for sample in samples {
+        let bucket = sample.time_ms / step_ms * step_ms;
+        match result.last_mut() {
+            Some((acc, count)) if acc.time_ms == bucket => {
+                acc.packet_rate += sample.packet_rate;
+                acc.frame_rate += sample.frame_rate;
+                acc.mean_points += sample.mean_points;
+                acc.config_responses += sample.config_responses;
+                acc.nak_count += sample.nak_count;
+                acc.dropped_packets += sample.dropped_packets;
+                acc.motor_speed = sample.motor_speed.or(acc.motor_speed);
+                acc.reorder_depth = acc.reorder_depth.max(sample.reorder_depth);
+                acc.late_drops += sample.late_drops;
+                *count += 1;
+            }
+            _ => result.push((
+                TelemetrySample {
+                    time_ms: bucket,
+                    ..sample
+                },
+                1,
+            )),
+        }
+    }
+    result
+        .into_iter()
+        .map(|(mut sample, count)| {
+            sample.packet_rate /= count as f32;
+            sample.frame_rate /= count as f32;
+            sample.mean_points /= count as f32;
+            sample
+        })
+        .collect()
+}
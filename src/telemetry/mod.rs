@@ -0,0 +1,7 @@
+pub mod config;
+pub mod history;
+pub mod store;
+
+pub use config::{MetricHistoryConfig, TelemetryConfig};
+pub use history::MetricHistory;
+pub use store::{TelemetrySample, TelemetryStore};
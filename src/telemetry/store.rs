@@ -0,0 +1,293 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+use crate::common::time::unix_time_ms;
+use crate::lidar::kanavi_mobility::{KMConfigData, KanaviMobilityData};
+use crate::lidar::{LiDARData, LiDARKey};
+use crate::processing::Reorder;
+use crate::telemetry::config::TelemetryConfig;
+
+/// LiDAR 상태 텔레메트리 샘플
+///
+/// Kanavi Mobility 프로토콜은 온도/전압을 보고하지 않으므로 수신 데이터에서 관측할 수 있는
+/// 값과 LiDAR 가 보고한 모터 속도만 포함합니다.
+///
+/// # Fields
+/// * `key` - LiDAR 키
+/// * `time_ms` - 샘플 생성 시각 (Unix time, ms)
+/// * `packet_rate` - 샘플 구간 동안의 패킷 수신 주기 (packets/s)
+/// * `frame_rate` - 샘플 구간 동안의 포인트 클라우드 수신 주기 (frames/s)
+/// * `mean_points` - 프레임당 평균 포인트 수
+/// * `config_responses` - 샘플 구간 동안 받은 설정 응답 수
+/// * `nak_count` - 샘플 구간 동안 받은 NAK 응답 수
+/// * `dropped_packets` - 샘플 구간 동안 채널 순서가 건너뛰어 유실된 것으로 추정되는 패킷 수
+/// * `motor_speed` - 마지막으로 보고된 모터 속도, 보고된 적이 없으면 null
+/// * `reorder_depth` - 샘플 구간 동안 프레임 정렬 시 이 LiDAR 프레임보다 먼저 대기 중이던 늦은 시각 프레임 수의 최대값
+/// * `late_drops` - 샘플 구간 동안 정렬 대기 시간보다 늦게 도착해 버려진 프레임 수
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct TelemetrySample {
+    pub key: LiDARKey,
+    pub time_ms: u64,
+    #[serde(default)]
+    pub packet_rate: f32,
+    pub frame_rate: f32,
+    pub mean_points: f32,
+    pub config_responses: u32,
+    pub nak_count: u32,
+    #[serde(default)]
+    pub dropped_packets: u32,
+    pub motor_speed: Option<u8>,
+    #[serde(default)]
+    pub reorder_depth: u32,
+    #[serde(default)]
+    pub late_drops: u32,
+}
+
+/// 샘플 구간 동안의 누적값
+struct Accumulator {
+    since: Instant,
+    packets: u32,
+    frames: u32,
+    points: u64,
+    config_responses: u32,
+    nak_count: u32,
+    dropped_packets: u32,
+    motor_speed: Option<u8>,
+    reorder_depth: u32,
+    late_drops: u32,
+}
+
+impl Accumulator {
+    fn new() -> Self {
+        Self {
+            since: Instant::now(),
+            packets: 0,
+            frames: 0,
+            points: 0,
+            config_responses: 0,
+            nak_count: 0,
+            dropped_packets: 0,
+            motor_speed: None,
+            reorder_depth: 0,
+            late_drops: 0,
+        }
+    }
+}
+
+/// LiDAR 별 채널 순서 추적 (패킷 유실 추정)
+///
+/// 포인트 클라우드 패킷은 채널 순서대로 수신되므로 이전 채널 다음이 아닌 채널이 수신되면
+/// 그 사이의 채널 패킷이 유실된 것으로 추정합니다. 채널 수는 수신한 가장 큰 채널 번호로 학습합니다.
+#[derive(Default)]
+struct ChannelTracker {
+    last: Option<usize>,
+    count: usize,
+}
+
+impl ChannelTracker {
+    /// 수신한 채널 반영
+    ///
+    /// # Returns
+    /// * `u32` - 유실된 것으로 추정되는 패킷 수
+    fn observe(&mut self, channel: usize) -> u32 {
+        self.count = self.count.max(channel + 1);
+        let dropped = match self.last {
+            Some(last) => {
+                let expected = (last + 1) % self.count;
+                (channel + self.count - expected) % self.count
+            }
+            None => 0,
+        };
+        self.last = Some(channel);
+        dropped as u32
+    }
+}
+
+#[derive(Default)]
+struct TelemetryInner {
+    current: HashMap<LiDARKey, Accumulator>,
+    history: HashMap<LiDARKey, VecDeque<TelemetrySample>>,
+    channels: HashMap<LiDARKey, ChannelTracker>,
+}
+
+/// LiDAR 별 텔레메트리 저장소
+///
+/// # Examples
+/// ```
+/// let telemetry = TelemetryStore::new(TelemetryConfig::default());
+/// telemetry.observe(key, &lidar_data).await;
+/// let samples = telemetry.sample().await;
+/// let recent = telemetry.history(&key, 100).await;
+/// ```
+///
+/// # 주요 기능
+/// * 수신한 LiDAR 데이터로 샘플 구간 누적값 갱신
+/// * `sample_interval_ms` 마다 샘플 생성, LiDAR 별 최근 `history` 개를 링 버퍼로 보관
+/// * 복제 시 동일한 저장소를 공유
+#[derive(Clone)]
+pub struct TelemetryStore {
+    config: TelemetryConfig,
+    inner: Arc<Mutex<TelemetryInner>>,
+}
+
+impl TelemetryStore {
+    pub fn new(config: TelemetryConfig) -> Self {
+        Self {
+            config,
+            inner: Arc::new(Mutex::new(TelemetryInner::default())),
+        }
+    }
+
+    pub fn config(&self) -> &TelemetryConfig {
+        &self.config
+    }
+
+    /// 수신한 LiDAR 데이터 반영
+    pub async fn observe(&self, key: LiDARKey, lidar_data: &KanaviMobilityData) {
+        if !self.config.enabled {
+            return;
+        }
+
+        let mut inner = self.inner.lock().await;
+        let points = lidar_data.get_points();
+        let dropped = match points.iter().position(|cloud| !cloud.points.is_empty()) {
+            Some(channel) => inner.channels.entry(key).or_default().observe(channel),
+            None => 0,
+        };
+        let acc = inner.current.entry(key).or_insert_with(Accumulator::new);
+        acc.packets += 1;
+        acc.dropped_packets += dropped;
+        if !points.is_empty() {
+            acc.frames += 1;
+            acc.points += points
+                .iter()
+                .map(|cloud| cloud.points.len() as u64)
+                .sum::<u64>();
+        }
+        if let Some(config_data) = lidar_data.get_config_data() {
+            acc.config_responses += 1;
+            match config_data {
+                KMConfigData::Nak(_) => acc.nak_count += 1,
+                KMConfigData::MotorSpeed(speed) => acc.motor_speed = Some(speed.speed()),
+                _ => {}
+            }
+        }
+    }
+
+    /// 프레임 정렬 결과 반영
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `reorder` - 프레임 정렬 버퍼에 추가한 결과
+    pub async fn observe_reorder(&self, key: LiDARKey, reorder: Reorder) {
+        if !self.config.enabled {
+            return;
+        }
+
+        let mut inner = self.inner.lock().await;
+        let acc = inner.current.entry(key).or_insert_with(Accumulator::new);
+        match reorder {
+            Reorder::Buffered { depth } => {
+                acc.reorder_depth = acc.reorder_depth.max(depth as u32);
+            }
+            Reorder::Late => acc.late_drops += 1,
+        }
+    }
+
+    /// 샘플 구간을 마감하고 LiDAR 별 샘플 생성
+    ///
+    /// # Returns
+    /// * `Vec<TelemetrySample>` - 이번 구간에 데이터를 수신한 LiDAR 들의 샘플
+    pub async fn sample(&self) -> Vec<TelemetrySample> {
+        let mut inner = self.inner.lock().await;
+        let time_ms = unix_time_ms();
+        let mut samples = Vec::new();
+        for (key, acc) in inner.current.drain() {
+            let elapsed = acc.since.elapsed().as_secs_f32();
+            samples.push(TelemetrySample {
+                key,
+                time_ms,
+                packet_rate: if elapsed > 0.0 {
+                    acc.packets as f32 / elapsed
+                } else {
+                    0.0
+                },
+                frame_rate: if elapsed > 0.0 {
+                    acc.frames as f32 / elapsed
+                } else {
+                    0.0
+                },
+                mean_points: if acc.frames > 0 {
+                    acc.points as f32 / acc.frames as f32
+                } else {
+                    0.0
+                },
+                config_responses: acc.config_responses,
+                nak_count: acc.nak_count,
+                dropped_packets: acc.dropped_packets,
+                motor_speed: acc.motor_speed,
+                reorder_depth: acc.reorder_depth,
+                late_drops: acc.late_drops,
+            });
+        }
+
+        for sample in samples.iter_mut() {
+            let history = inner.history.entry(sample.key).or_default();
+            // 이번 구간에 보고되지 않았으면 마지막으로 보고된 모터 속도 유지
+            if sample.motor_speed.is_none() {
+                sample.motor_speed = history.back().and_then(|last| last.motor_speed);
+            }
+            history.push_back(sample.clone());
+            while history.len() > self.config.history.max(1) {
+                history.pop_front();
+            }
+        }
+        samples
+    }
+
+    /// 최근 샘플 조회
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `last` - 최대 샘플 수
+    ///
+    /// # Returns
+    /// * `Option<Vec<TelemetrySample>>` - 오래된 순 샘플 목록, 샘플이 없는 LiDAR 이면 None
+    pub async fn history(&self, key: &LiDARKey, last: usize) -> Option<Vec<TelemetrySample>> {
+        let inner = self.inner.lock().await;
+        let history = inner.history.get(key)?;
+        let skip = history.len().saturating_sub(last);
+        Some(history.iter().skip(skip).cloned().collect())
+    }
+
+    /// LiDAR 키 변경 반영 (IP 가 바뀐 장치의 샘플 이력을 새 키로 이동)
+    ///
+    /// # Arguments
+    /// * `previous` - 이전 LiDAR 키
+    /// * `key` - 새 LiDAR 키
+    ///
+    /// # 동작 설명
+    /// * 이전 키의 샘플 이력 뒤에 새 키로 수신한 샘플 이력을 이어 붙이고, 이전 샘플의 키는 새 키로 변경
+    /// * 이전 키로 누적 중인 구간 값은 버림 (새 키의 구간 값이 이어서 누적됨)
+    pub async fn rename(&self, previous: &LiDARKey, key: LiDARKey) {
+        let mut inner = self.inner.lock().await;
+        inner.current.remove(previous);
+        inner.channels.remove(previous);
+        let Some(mut history) = inner.history.remove(previous) else {
+            return;
+        };
+        for sample in history.iter_mut() {
+            sample.key = key;
+        }
+        history.extend(inner.history.remove(&key).unwrap_or_default());
+        while history.len() > self.config.history.max(1) {
+            history.pop_front();
+        }
+        inner.history.insert(key, history);
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::udp::config::ErrorBudgetConfig;
+
+/// 송신 주소의 격리 상태
+///
+/// # Variants
+/// * `Closed` - 정상 (파싱)
+/// * `Open` - 격리 중, `until` 까지 파싱하지 않고 버림
+/// * `HalfOpen` - 격리 해제 후 시험 기간 (파싱하되 다시 실패가 많으면 더 오래 격리)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BreakerState {
+    Closed,
+    Open { until: Instant },
+    HalfOpen,
+}
+
+/// 송신 주소별 파싱 실패 기록
+#[derive(Debug)]
+struct SenderBudget {
+    state: BreakerState,
+    window_start: Instant,
+    frames: u32,
+    errors: u32,
+    retry: Duration,
+    samples: VecDeque<Vec<u8>>,
+    last_seen: Instant,
+}
+
+/// 격리 상태 변경 이벤트
+///
+/// # Variants
+/// * `Quarantined` - 파싱 실패 비율이 허용량을 넘어 격리 시작
+///   (`error_ratio`: 기간 안의 실패 비율, `frames`: 기간 안의 프레임 수, `retry`: 다시 시도하기까지 대기 시간,
+///   `samples`: 진단용 최근 실패 데이터그램 앞부분)
+/// * `Recovered` - 시험 기간에 `min_frames` 만큼 허용량 안에서 파싱되어 격리 해제
+#[derive(Debug, Clone, PartialEq)]
+pub enum BreakerEvent {
+    Quarantined {
+        addr: SocketAddr,
+        error_ratio: f64,
+        frames: u32,
+        retry: Duration,
+        samples: Vec<Vec<u8>>,
+    },
+    Recovered {
+        addr: SocketAddr,
+    },
+}
+
+/// 파싱 실패가 많은 송신 주소 격리기 (circuit breaker)
+///
+/// # Examples
+/// ```
+/// let breaker = CircuitBreaker::new(&config.error_budget);
+/// if breaker.allow(src_addr) {
+///     // 프레임 앞부분만 받은 경우(`NeedMoreData`)는 기록하지 않음
+///     let ok = match parser.parse(ip, data) {
+///         ParseOutcome::Frame(_) => true,
+///         ParseOutcome::Error(_) => false,
+///         _ => return,
+///     };
+///     if let Some(event) = breaker.record(src_addr, ok, data) {
+///         // 격리/복구 로그, 파싱 통계 갱신
+///     }
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 송신 주소마다 `window_ms` 기간의 프레임 수와 파싱 실패 수를 세고, `min_frames` 이상 받은 뒤
+///   실패 비율이 `max_error_ratio` 를 넘으면 `retry_ms` 동안 격리
+/// * 격리 중인 송신 주소의 데이터그램은 파싱하지 않음 (`allow` 가 false)
+/// * 대기 시간이 지나면 시험 기간으로 다시 파싱하고, `min_frames` 만큼 허용량 안에서 파싱되면 복구,
+///   다시 허용량을 넘으면 대기 시간을 두 배로 늘려(`max_retry_ms` 까지) 다시 격리
+/// * 실패한 데이터그램은 최근 `sample_frames` 개의 앞부분 `sample_bytes` 만 보관하여 격리할 때 함께 전달
+pub struct CircuitBreaker {
+    config: ErrorBudgetConfig,
+    senders: Mutex<HashMap<SocketAddr, SenderBudget>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: &ErrorBudgetConfig) -> Self {
+        Self {
+            config: config.clone(),
+            senders: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 송신 주소의 데이터그램을 파싱할지 확인
+    ///
+    /// # Returns
+    /// * `bool` - 격리 중이면 false, 격리 대기 시간이 지났으면 시험 기간으로 바꾸고 true
+    pub fn allow(&self, addr: SocketAddr) -> bool {
+        let mut senders = self.senders.lock().unwrap();
+        let Some(sender) = senders.get_mut(&addr) else {
+            return true;
+        };
+        let now = Instant::now();
+        sender.last_seen = now;
+        match sender.state {
+            BreakerState::Open { until } if now < until => false,
+            BreakerState::Open { .. } => {
+                sender.state = BreakerState::HalfOpen;
+                sender.reset_window(now);
+                true
+            }
+            _ => true,
+        }
+    }
+
+    /// 파싱 결과 기록
+    ///
+    /// # Arguments
+    /// * `addr` - 송신 주소
+    /// * `ok` - 파싱 성공 여부
+    /// * `datagram` - 파싱한 데이터그램 (실패 시 진단용 샘플로 보관)
+    ///
+    /// # Returns
+    /// * `Option<BreakerEvent>` - 격리를 시작하거나 복구했으면 이벤트
+    pub fn record(&self, addr: SocketAddr, ok: bool, datagram: &[u8]) -> Option<BreakerEvent> {
+        let now = Instant::now();
+        let mut senders = self.senders.lock().unwrap();
+        let sender = senders.entry(addr).or_insert_with(|| SenderBudget {
+            state: BreakerState::Closed,
+            window_start: now,
+            frames: 0,
+            errors: 0,
+            retry: Duration::from_millis(self.config.retry_ms),
+            samples: VecDeque::new(),
+            last_seen: now,
+        });
+        sender.last_seen = now;
+        if let BreakerState::Open { .. } = sender.state {
+            // 격리 전에 수신하여 파싱 중이던 프레임
+            return None;
+        }
+        if now.duration_since(sender.window_start) >= Duration::from_millis(self.config.window_ms) {
+            sender.reset_window(now);
+        }
+
+        sender.frames += 1;
+        if !ok {
+            sender.errors += 1;
+            if self.config.sample_frames > 0 {
+                if sender.samples.len() >= self.config.sample_frames {
+                    sender.samples.pop_front();
+                }
+                let len = datagram.len().min(self.config.sample_bytes);
+                sender.samples.push_back(datagram[..len].to_vec());
+            }
+        }
+        if sender.frames < self.config.min_frames {
+            return None;
+        }
+
+        let error_ratio = sender.errors as f64 / sender.frames as f64;
+        if error_ratio > self.config.max_error_ratio {
+            let retry = if sender.state == BreakerState::HalfOpen {
+                (sender.retry * 2).min(Duration::from_millis(self.config.max_retry_ms))
+            } else {
+                Duration::from_millis(self.config.retry_ms)
+            };
+            let frames = sender.frames;
+            sender.retry = retry;
+            sender.state = BreakerState::Open { until: now + retry };
+            sender.reset_window(now);
+            return Some(BreakerEvent::Quarantined {
+                addr,
+                error_ratio,
+                frames,
+                retry,
+                samples: sender.samples.drain(..).collect(),
+            });
+        }
+        if sender.state == BreakerState::HalfOpen {
+            sender.state = BreakerState::Closed;
+            sender.retry = Duration::from_millis(self.config.retry_ms);
+            sender.samples.clear();
+            return Some(BreakerEvent::Recovered { addr });
+        }
+        None
+    }
+
+    /// 격리 중인지 확인
+    pub fn is_quarantined(&self, addr: &SocketAddr) -> bool {
+        self.senders
+            .lock()
+            .unwrap()
+            .get(addr)
+            .is_some_and(|sender| matches!(sender.state, BreakerState::Open { .. }))
+    }
+
+    /// 오래된 송신 주소의 기록 삭제 (격리 중인 송신 주소는 유지)
+    ///
+    /// # Arguments
+    /// * `ttl` - 이 시간 동안 데이터를 받지 않은 송신 주소 삭제
+    ///
+    /// # Returns
+    /// * `usize` - 삭제한 송신 주소 수
+    pub fn evict_idle(&self, ttl: Duration) -> usize {
+        let mut senders = self.senders.lock().unwrap();
+        let before = senders.len();
+        senders.retain(|_, sender| {
+            matches!(sender.state, BreakerState::Open { .. }) || sender.last_seen.elapsed() <= ttl
+        });
+        before - senders.len()
+    }
+}
+
+impl SenderBudget {
+    fn reset_window(&mut self, now: Instant) {
+        self.window_start = now;
+        self.frames = 0;
+        self.errors = 0;
+    }
+}
+
+/// 데이터그램을 로그용 16진수 문자열로 변환 (`FA 03 01 DD ...`)
+pub fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
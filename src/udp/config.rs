@@ -0,0 +1,290 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use crate::common::impairment::ImpairmentConfig;
+use crate::udp::filter::Ipv4Net;
+
+/// UDP 리스너 설정 구조체
+///
+/// # Examples
+/// ```
+/// let config = UdpListenerConfig {
+///     recv_buffer_size: Some(8 * 1024 * 1024),
+///     recv_batch_size: 32,
+///     dedicated_recv_thread: true,
+///     ..Default::default()
+/// };
+/// ```
+///
+/// # Fields
+/// * `addr` - 바인딩할 소켓 주소
+/// * `multicast_addr` - 가입할 멀티캐스트 그룹 주소
+/// * `recv_buffer_size` - 소켓 수신 버퍼 크기 (SO_RCVBUF), None 이면 OS 기본값 사용
+/// * `send_buffer_size` - 소켓 송신 버퍼 크기 (SO_SNDBUF), None 이면 OS 기본값 사용
+/// * `busy_poll_us` - 소켓 busy poll 시간 (SO_BUSY_POLL, 마이크로초), Linux 에서만 적용
+/// * `recv_batch_size` - 한 번에 수신할 최대 데이터그램 수, 1 보다 크면 Linux 에서 recvmmsg 사용
+/// * `dedicated_recv_thread` - 수신 루프를 별도의 OS 스레드(전용 런타임)에서 실행할지 여부
+/// * `recv_thread_core` - 전용 수신 스레드를 고정할 CPU 코어 번호, Linux 에서만 적용
+/// * `discovery` - LiDAR 장치 검색 설정
+/// * `dedup` - 중복 프레임 제거 설정
+/// * `connected` - 서버의 별도 포트로 유니캐스트 송신하는 LiDAR 목록, LiDAR 마다 연결형(connected) 소켓으로 수신
+/// * `impairment` - 수신한 데이터그램의 장애 주입 설정 (시험용, 파싱 전에 적용)
+/// * `interface_watch` - 네트워크 인터페이스 변경 감시 설정 (멀티캐스트 가입 갱신)
+/// * `source_filter` - 송신 주소 허용/차단 목록 (수신 직후, 파싱 전에 적용)
+/// * `sender_state` - 송신 주소별 상태(파싱 통계, 중복 제거 기록, 파서 버퍼) 정리 설정
+/// * `error_budget` - 파싱 실패가 많은 송신 주소 격리(circuit breaker) 설정
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct UdpListenerConfig {
+    pub addr: SocketAddr,
+    pub multicast_addr: Ipv4Addr,
+    pub recv_buffer_size: Option<usize>,
+    pub send_buffer_size: Option<usize>,
+    pub busy_poll_us: Option<u32>,
+    pub recv_batch_size: usize,
+    pub dedicated_recv_thread: bool,
+    pub recv_thread_core: Option<usize>,
+    pub discovery: DiscoveryConfig,
+    pub dedup: DedupConfig,
+    pub connected: Vec<ConnectedSensorConfig>,
+    pub impairment: ImpairmentConfig,
+    pub interface_watch: InterfaceWatchConfig,
+    pub source_filter: SourceFilterConfig,
+    pub sender_state: SenderStateConfig,
+    pub error_budget: ErrorBudgetConfig,
+}
+
+impl Default for UdpListenerConfig {
+    fn default() -> Self {
+        Self {
+            addr: SocketAddr::from(([0, 0, 0, 0], 5000)),
+            multicast_addr: Ipv4Addr::new(224, 0, 0, 5),
+            recv_buffer_size: None,
+            send_buffer_size: None,
+            busy_poll_us: None,
+            recv_batch_size: 1,
+            dedicated_recv_thread: false,
+            recv_thread_core: None,
+            discovery: DiscoveryConfig::default(),
+            dedup: DedupConfig::default(),
+            connected: Vec::new(),
+            impairment: ImpairmentConfig::default(),
+            interface_watch: InterfaceWatchConfig::default(),
+            source_filter: SourceFilterConfig::default(),
+            sender_state: SenderStateConfig::default(),
+            error_budget: ErrorBudgetConfig::default(),
+        }
+    }
+}
+
+/// 송신 주소별 상태 정리 설정 구조체
+///
+/// 한 번 데이터를 보낸 송신 주소의 상태가 계속 쌓이거나, 잘못된 프레임 조각으로 파서 버퍼가
+/// 커지지 않도록 오래된 상태를 정리하고 버퍼 크기를 제한합니다.
+///
+/// # Fields
+/// * `ttl_ms` - 이 시간 동안 데이터가 없는 송신 주소의 파싱 통계, 중복 제거 기록과 파서를 삭제 (ms), 0 이면 삭제하지 않음
+/// * `max_buffer_bytes` - 송신 주소별 파서가 프레임 조각을 보관하는 버퍼의 최대 크기 (byte), 넘으면 그 송신 주소의 버퍼를 비우고 다시 시작
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SenderStateConfig {
+    pub ttl_ms: u64,
+    pub max_buffer_bytes: usize,
+}
+
+impl Default for SenderStateConfig {
+    fn default() -> Self {
+        Self {
+            ttl_ms: 600_000,
+            max_buffer_bytes: 131_072,
+        }
+    }
+}
+
+/// 송신 주소별 파싱 실패 허용량(error budget) 설정 구조체
+///
+/// 손상된 프레임을 계속 보내는 LiDAR 하나가 에러 로그와 파싱 CPU 를 차지하지 않도록
+/// 파싱 실패 비율이 높은 송신 주소를 일정 시간 격리(파싱하지 않고 버림)합니다.
+///
+/// # Examples
+/// ```json
+/// { "enabled": true, "window_ms": 10000, "min_frames": 20, "max_error_ratio": 0.5, "retry_ms": 30000 }
+/// ```
+///
+/// # Fields
+/// * `enabled` - 사용 여부
+/// * `window_ms` - 파싱 실패 비율을 세는 기간 (ms)
+/// * `min_frames` - 기간 안에 이 수 이상 프레임을 받았을 때만 비율 판단 (격리 해제 후 시험 기간에는 이 수만큼 성공하면 복구)
+/// * `max_error_ratio` - 허용하는 파싱 실패 비율 (0.0 ~ 1.0), 넘으면 격리
+/// * `retry_ms` - 격리 후 다시 파싱을 시도하기까지 대기 시간 (ms), 시험 기간에 다시 격리되면 두 배씩 증가
+/// * `max_retry_ms` - 격리 대기 시간 최대값 (ms)
+/// * `sample_frames` - 격리할 때 진단용으로 로그에 남기는 최근 실패 데이터그램 수
+/// * `sample_bytes` - 로그에 남기는 데이터그램 앞부분 크기 (byte)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ErrorBudgetConfig {
+    pub enabled: bool,
+    pub window_ms: u64,
+    pub min_frames: u32,
+    pub max_error_ratio: f64,
+    pub retry_ms: u64,
+    pub max_retry_ms: u64,
+    pub sample_frames: usize,
+    pub sample_bytes: usize,
+}
+
+impl Default for ErrorBudgetConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_ms: 10_000,
+            min_frames: 20,
+            max_error_ratio: 0.5,
+            retry_ms: 30_000,
+            max_retry_ms: 600_000,
+            sample_frames: 3,
+            sample_bytes: 64,
+        }
+    }
+}
+
+impl ErrorBudgetConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 (), 실패 시 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.max_error_ratio) {
+            return Err("udp.error_budget.max_error_ratio must be 0.0 ~ 1.0".to_string());
+        }
+        if self.window_ms == 0 || self.min_frames == 0 {
+            return Err("udp.error_budget.window_ms and min_frames must be at least 1".to_string());
+        }
+        if self.max_retry_ms < self.retry_ms {
+            return Err("udp.error_budget.max_retry_ms must not be less than retry_ms".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// UDP 송신 주소 필터 설정 구조체
+///
+/// 같은 네트워크의 다른 멀티캐스트 트래픽이 파싱 통계 등에 쌓이지 않도록 송신 주소로 걸러냅니다.
+///
+/// # Examples
+/// ```json
+/// { "allow": ["192.168.123.0/24"], "deny": ["192.168.123.99"] }
+/// ```
+///
+/// # Fields
+/// * `allow` - 허용할 송신 주소 범위 (CIDR), 비어 있으면 차단 목록에 없는 모든 주소 허용
+/// * `deny` - 차단할 송신 주소 범위 (CIDR), 허용 목록보다 우선
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SourceFilterConfig {
+    pub allow: Vec<Ipv4Net>,
+    pub deny: Vec<Ipv4Net>,
+}
+
+impl SourceFilterConfig {
+    /// 필터 사용 여부 (허용/차단 목록 중 하나라도 있으면 true)
+    pub fn is_enabled(&self) -> bool {
+        !self.allow.is_empty() || !self.deny.is_empty()
+    }
+}
+
+/// 네트워크 인터페이스 변경 감시 설정 구조체
+///
+/// 시작 후에 연결된 인터페이스(USB 이더넷, VPN 등)에서도 멀티캐스트를 받을 수 있도록
+/// 인터페이스 목록을 주기적으로 확인하여 멀티캐스트 가입/탈퇴를 갱신합니다.
+///
+/// # Fields
+/// * `enabled` - 인터페이스 변경 감시 사용 여부
+/// * `interval_ms` - 인터페이스 목록 확인 주기 (ms)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct InterfaceWatchConfig {
+    pub enabled: bool,
+    pub interval_ms: u64,
+}
+
+impl Default for InterfaceWatchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_ms: 2000,
+        }
+    }
+}
+
+/// LiDAR 장치 검색 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 주기적 장치 검색 사용 여부
+/// * `interval_ms` - 검색 요청 송신 주기 (ms)
+/// * `targets` - 검색 요청을 송신할 주소 목록 (브로드캐스트, 멀티캐스트, 유니캐스트 모두 가능)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct DiscoveryConfig {
+    pub enabled: bool,
+    pub interval_ms: u64,
+    pub targets: Vec<SocketAddr>,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_ms: 5000,
+            targets: vec![SocketAddr::from(([255, 255, 255, 255], 5000))],
+        }
+    }
+}
+
+/// 중복 프레임 제거 설정 구조체
+///
+/// 멀티캐스트와 유니캐스트로 동시에 송신하도록 설정된 LiDAR 의 중복 수신을 제거합니다.
+///
+/// # Fields
+/// * `enabled` - 중복 프레임 제거 사용 여부
+/// * `window_ms` - 같은 LiDAR 의 같은 데이터그램을 중복으로 판단하는 시간 (ms)
+/// * `max_entries` - LiDAR 별로 보관할 최근 데이터그램 해시 수
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct DedupConfig {
+    pub enabled: bool,
+    pub window_ms: u64,
+    pub max_entries: usize,
+}
+
+impl Default for DedupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_ms: 50,
+            max_entries: 64,
+        }
+    }
+}
+
+/// 연결형 소켓으로 수신할 LiDAR 설정 구조체
+///
+/// # Examples
+/// ```json
+/// { "sensor": "192.168.123.200:5000", "local_port": 5001 }
+/// ```
+///
+/// # Fields
+/// * `sensor` - LiDAR 송신 주소 (IP, 포트)
+/// * `local_port` - LiDAR 가 데이터를 보내도록 설정된 서버 포트 (`addr` 의 IP 에 바인딩)
+///
+/// # 동작 설명
+/// * 리스너 시작 시 `local_port` 에 바인딩하고 `sensor` 로 connect 한 소켓을 만들어 해당 LiDAR 의 데이터만 수신
+/// * LiDAR 로 보내는 명령도 같은 소켓으로 송신하므로 NAT 뒤의 LiDAR 도 응답이 같은 매핑으로 돌아옴
+/// * 장치 검색/식별 과정에서 LiDAR 의 송신 주소가 바뀐 것으로 확인되면 새 주소로 다시 connect
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ConnectedSensorConfig {
+    pub sensor: SocketAddrV4,
+    pub local_port: u16,
+}
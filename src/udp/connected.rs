@@ -0,0 +1,126 @@
+use socket2::{Domain, Protocol, Socket, Type};
+use std::collections::HashMap;
+use std::io;
+use std::net::{SocketAddr, SocketAddrV4};
+use std::sync::{Arc, RwLock};
+use tokio::net::UdpSocket;
+use tokio::task::JoinHandle;
+
+use crate::udp::config::UdpListenerConfig;
+use crate::udp::socket::{apply_socket_options, set_reuse};
+
+/// LiDAR 하나의 연결형 소켓
+///
+/// # Fields
+/// * `socket` - LiDAR 주소로 connect 한 소켓
+/// * `local_port` - 바인딩한 서버 포트 (LiDAR 가 데이터를 보내는 포트)
+/// * `task` - 수신 태스크, 소켓을 교체하거나 제거하면 중단
+struct ConnectedEntry {
+    socket: Arc<UdpSocket>,
+    local_port: u16,
+    task: JoinHandle<()>,
+}
+
+/// LiDAR 별 연결형(connected) UDP 소켓 저장소
+///
+/// # Examples
+/// ```
+/// let socket = Arc::new(bind_connected(&config, sensor, 5001)?);
+/// let task = tokio::spawn(recv_loop(socket.clone()));
+/// connected.insert(sensor, 5001, socket, task);
+/// if let Some(socket) = connected.get(&target) {
+///     socket.send(&frame).await?;
+/// }
+/// ```
+///
+/// # 주요 기능
+/// * LiDAR 주소별 연결형 소켓과 수신 태스크 관리 (같은 LiDAR 를 다시 연결하면 이전 소켓의 수신 중단)
+/// * 명령 송신 시 대상 LiDAR 의 연결형 소켓 조회 (응답이 같은 소켓으로 돌아오고 NAT 매핑이 유지됨)
+#[derive(Clone, Default)]
+pub struct ConnectedSockets {
+    entries: Arc<RwLock<HashMap<SocketAddrV4, ConnectedEntry>>>,
+}
+
+impl ConnectedSockets {
+    /// 연결형 소켓 등록 (같은 LiDAR 의 이전 소켓은 수신 태스크를 중단하고 교체)
+    pub fn insert(
+        &self,
+        sensor: SocketAddrV4,
+        local_port: u16,
+        socket: Arc<UdpSocket>,
+        task: JoinHandle<()>,
+    ) {
+        let entry = ConnectedEntry {
+            socket,
+            local_port,
+            task,
+        };
+        if let Some(previous) = self.write().insert(sensor, entry) {
+            previous.task.abort();
+        }
+    }
+
+    /// 연결형 소켓 제거 (수신 태스크 중단)
+    ///
+    /// # Returns
+    /// * `Option<u16>` - 제거한 소켓의 서버 포트, 등록되지 않은 LiDAR 이면 None
+    pub fn remove(&self, sensor: &SocketAddrV4) -> Option<u16> {
+        self.write().remove(sensor).map(|entry| {
+            entry.task.abort();
+            entry.local_port
+        })
+    }
+
+    /// 대상 주소의 연결형 소켓
+    pub fn get(&self, target: &SocketAddr) -> Option<Arc<UdpSocket>> {
+        let SocketAddr::V4(target) = target else {
+            return None;
+        };
+        self.read()
+            .get(target)
+            .map(|entry| Arc::clone(&entry.socket))
+    }
+
+    /// 연결형 소켓을 사용하는 LiDAR 주소와 서버 포트 목록
+    pub fn list(&self) -> Vec<(SocketAddrV4, u16)> {
+        self.read()
+            .iter()
+            .map(|(sensor, entry)| (*sensor, entry.local_port))
+            .collect()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<SocketAddrV4, ConnectedEntry>> {
+        self.entries.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<SocketAddrV4, ConnectedEntry>> {
+        self.entries.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// LiDAR 전용 포트에 바인딩하고 LiDAR 주소로 connect 한 UDP 소켓 생성
+///
+/// # Arguments
+/// * `config` - UDP 리스너 설정 (바인딩 IP, 소켓 버퍼 등 옵션)
+/// * `sensor` - LiDAR 송신 주소
+/// * `local_port` - LiDAR 가 데이터를 보내도록 설정된 서버 포트
+///
+/// # Returns
+/// * `io::Result<UdpSocket>` - 성공 시 소켓, 실패 시 IO 에러
+///
+/// # 동작 설명
+/// * 공용 소켓과 같은 주소 재사용, 소켓 버퍼, busy poll 옵션 적용
+/// * connect 한 소켓은 커널이 해당 LiDAR 주소에서 온 데이터그램만 전달하므로 다른 송신자의 트래픽과 분리됨
+pub fn bind_connected(
+    config: &UdpListenerConfig,
+    sensor: SocketAddrV4,
+    local_port: u16,
+) -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    set_reuse(&socket)?;
+    apply_socket_options(&socket, config)?;
+    socket.bind(&SocketAddr::new(config.addr.ip(), local_port).into())?;
+    socket.connect(&SocketAddr::V4(sensor).into())?;
+    socket.set_nonblocking(true)?;
+    UdpSocket::from_std(socket.into())
+}
@@ -0,0 +1,94 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use crate::lidar::LiDARKey;
+use crate::udp::config::DedupConfig;
+
+/// 중복 프레임 제거기
+///
+/// # Examples
+/// ```
+/// let mut dedup = Deduplicator::new(&config.dedup);
+/// if dedup.is_duplicate(key, &datagram) {
+///     return;
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 같은 LiDAR 가 멀티캐스트와 유니캐스트로 동시에 보내는 경우처럼 같은 데이터그램이 두 번 수신되는 것을 제거
+/// * Kanavi Mobility 프레임에는 시퀀스 번호가 없으므로 LiDAR 키 + 데이터그램 해시로 비교
+/// * LiDAR 별로 최근 `window_ms` 동안 수신한 해시를 최대 `max_entries` 개 보관
+pub struct Deduplicator {
+    window: Duration,
+    max_entries: usize,
+    recent: HashMap<LiDARKey, VecDeque<(u64, Instant)>>,
+}
+
+impl Deduplicator {
+    pub fn new(config: &DedupConfig) -> Self {
+        Self {
+            window: Duration::from_millis(config.window_ms),
+            max_entries: config.max_entries.max(1),
+            recent: HashMap::new(),
+        }
+    }
+
+    /// 중복 여부 확인 후 기록
+    ///
+    /// # Arguments
+    /// * `key` - LiDAR 키
+    /// * `data` - 수신한 데이터그램
+    ///
+    /// # Returns
+    /// * `bool` - 최근 `window_ms` 안에 같은 LiDAR 에서 같은 데이터그램을 받았으면 true
+    pub fn is_duplicate(&mut self, key: LiDARKey, data: &[u8]) -> bool {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+        let hash = hasher.finish();
+        let now = Instant::now();
+
+        let recent = self.recent.entry(key).or_default();
+        while recent
+            .front()
+            .is_some_and(|(_, time)| now.duration_since(*time) > self.window)
+        {
+            recent.pop_front();
+        }
+        if recent.iter().any(|(seen, _)| *seen == hash) {
+            return true;
+        }
+        if recent.len() >= self.max_entries {
+            recent.pop_front();
+        }
+        recent.push_back((hash, now));
+        false
+    }
+
+    /// `window_ms` 동안 데이터그램을 받지 않은 LiDAR 의 기록 삭제
+    ///
+    /// # Returns
+    /// * `usize` - 삭제한 LiDAR 수
+    pub fn evict_idle(&mut self) -> usize {
+        let now = Instant::now();
+        let before = self.recent.len();
+        self.recent.retain(|_, recent| {
+            recent
+                .back()
+                .is_some_and(|(_, time)| now.duration_since(*time) <= self.window)
+        });
+        before - self.recent.len()
+    }
+
+    /// 기록에 사용 중인 대략적인 메모리 크기 (byte)
+    pub fn memory_bytes(&self) -> usize {
+        self.recent
+            .values()
+            .map(|recent| {
+                std::mem::size_of::<(LiDARKey, VecDeque<(u64, Instant)>)>()
+                    + recent.capacity() * std::mem::size_of::<(u64, Instant)>()
+            })
+            .sum()
+    }
+}
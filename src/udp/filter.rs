@@ -0,0 +1,164 @@
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+
+use crate::udp::config::SourceFilterConfig;
+
+/// IPv4 주소 범위 (CIDR)
+///
+/// # Examples
+/// ```
+/// let net: Ipv4Net = "192.168.123.0/24".parse()?;
+/// assert!(net.contains(Ipv4Addr::new(192, 168, 123, 200)));
+/// ```
+///
+/// # 동작 설명
+/// * `a.b.c.d/n` 형식, 접두사 길이를 생략하면 주소 하나(`/32`)
+/// * 설정 파일에서는 문자열로 읽고 씀
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Ipv4Net {
+    addr: Ipv4Addr,
+    prefix: u8,
+}
+
+impl Ipv4Net {
+    /// 주소 범위 생성 (접두사 밖의 bit 는 0 으로 맞춤)
+    ///
+    /// # Returns
+    /// * `Option<Self>` - 접두사 길이가 32 보다 크면 None
+    pub fn new(addr: Ipv4Addr, prefix: u8) -> Option<Self> {
+        (prefix <= 32).then(|| Self {
+            addr: Ipv4Addr::from(u32::from(addr) & Self::mask(prefix)),
+            prefix,
+        })
+    }
+
+    /// 주소가 범위 안에 있는지 확인
+    pub fn contains(&self, ip: Ipv4Addr) -> bool {
+        u32::from(ip) & Self::mask(self.prefix) == u32::from(self.addr)
+    }
+
+    fn mask(prefix: u8) -> u32 {
+        u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0)
+    }
+}
+
+impl FromStr for Ipv4Net {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix) = match s.split_once('/') {
+            Some((addr, prefix)) => (
+                addr,
+                prefix
+                    .parse::<u8>()
+                    .map_err(|_| format!("Invalid prefix length in {}", s))?,
+            ),
+            None => (s, 32),
+        };
+        let addr = addr
+            .parse::<Ipv4Addr>()
+            .map_err(|_| format!("Invalid IPv4 address in {}", s))?;
+        Self::new(addr, prefix).ok_or_else(|| format!("Prefix length must be 0 ~ 32: {}", s))
+    }
+}
+
+impl TryFrom<String> for Ipv4Net {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Ipv4Net> for String {
+    fn from(net: Ipv4Net) -> Self {
+        net.to_string()
+    }
+}
+
+impl fmt::Display for Ipv4Net {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+impl JsonSchema for Ipv4Net {
+    fn schema_name() -> String {
+        "Ipv4Net".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// 송신 주소 필터에서 거부한 이유
+///
+/// # Variants
+/// * `Denied` - 차단 목록(`deny`)에 있음
+/// * `NotAllowed` - 허용 목록(`allow`)을 사용하는데 목록에 없음
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    Denied,
+    NotAllowed,
+}
+
+/// UDP 송신 주소 필터
+///
+/// # Examples
+/// ```
+/// let filter = SourceFilter::new(&config.source_filter);
+/// if let Some(rejection) = filter.check(&src_addr) {
+///     parse_stats.record_rejected(rejection);
+///     return;
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 차단 목록이 허용 목록보다 우선
+/// * 허용 목록이 비어 있으면 차단 목록에 없는 모든 주소 허용
+/// * 데이터그램마다 호출되므로 메모리 할당 없이 목록만 순회
+#[derive(Debug, Clone)]
+pub struct SourceFilter {
+    allow: Vec<Ipv4Net>,
+    deny: Vec<Ipv4Net>,
+}
+
+impl SourceFilter {
+    pub fn new(config: &SourceFilterConfig) -> Self {
+        Self {
+            allow: config.allow.clone(),
+            deny: config.deny.clone(),
+        }
+    }
+
+    /// 송신 주소 검사
+    ///
+    /// # Arguments
+    /// * `src_addr` - 송신자 주소 (IPv6 주소는 어떤 범위에도 속하지 않는 것으로 처리)
+    ///
+    /// # Returns
+    /// * `Option<Rejection>` - 허용하면 None, 거부하면 거부 이유
+    pub fn check(&self, src_addr: &SocketAddr) -> Option<Rejection> {
+        let ip = match src_addr {
+            SocketAddr::V4(addr) => Some(*addr.ip()),
+            SocketAddr::V6(_) => None,
+        };
+        let matches =
+            |nets: &[Ipv4Net]| ip.is_some_and(|ip| nets.iter().any(|net| net.contains(ip)));
+
+        if matches(&self.deny) {
+            Some(Rejection::Denied)
+        } else if !self.allow.is_empty() && !matches(&self.allow) {
+            Some(Rejection::NotAllowed)
+        } else {
+            None
+        }
+    }
+}
@@ -1,37 +1,119 @@
-use crate::lidar::kanavi_mobility::parser::KanaviMobilityParser;
+use crate::common::data::{LiDARPacket, OutboundMessage};
+use crate::common::impairment::Impairer;
+use crate::common::time::unix_time_ms;
+use crate::health::HealthState;
+use crate::lidar::kanavi_mobility::command::discovery_requests;
 use crate::lidar::kanavi_mobility::*;
-use crate::lidar::{traits::*, CompanyInfo};
+#[cfg(feature = "plugins")]
+use crate::lidar::ParseError;
+use crate::lidar::{
+    traits::*, CompanyInfo, IdentityChange, LiDARRegistry, ParseStatsStore, PointPool,
+};
+use crate::logging::TraceId;
+#[cfg(feature = "plugins")]
+use crate::plugin::PluginParser;
+use crate::supervisor::{supervise, SupervisorConfig};
+use crate::udp::breaker::{hex, BreakerEvent, CircuitBreaker};
+use crate::udp::config::{DiscoveryConfig, SenderStateConfig, UdpListenerConfig};
+use crate::udp::connected::{bind_connected, ConnectedSockets};
+use crate::udp::dedup::Deduplicator;
+use crate::udp::filter::SourceFilter;
+use crate::udp::reassembly::SenderParsers;
+use crate::udp::socket::{
+    apply_socket_options, is_transient_recv_error, join_multicast, list_multicast_interfaces,
+    multicast_interfaces, pin_current_thread, set_reuse, sync_multicast, BatchReceiver,
+};
 use bincode::config::standard;
-use bincode::encode_into_slice;
-use network_interface::{NetworkInterface, NetworkInterfaceConfig};
-use socket2::{Domain, Protocol, Socket, Type};
-use std::collections::HashMap;
-use std::net::{Ipv4Addr, SocketAddr};
-use std::sync::Arc;
+use bincode::encode_to_vec;
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::sync::{Arc, RwLock};
 use tokio::net::UdpSocket;
-use tokio::sync::Mutex;
+use tokio::sync::{broadcast, Mutex};
 use tracing::*;
 
+/// 송신 주소별 파서 저장소 타입
+type ParserMap = Arc<Mutex<SenderParsers>>;
+
+/// 로컬 주소 목록 타입 (인터페이스 변경 시 갱신)
+type LocalAddrs = Arc<RwLock<Vec<SocketAddr>>>;
+
+/// 외부 파서 플러그인 저장소 타입
+#[cfg(feature = "plugins")]
+type PluginList = Arc<Mutex<Vec<PluginParser>>>;
+
+/// UDP 수신 루프에서 공유하는 상태
+///
+/// # Fields
+/// * `parsers` - 송신 주소별 LiDAR 회사별 파서
+/// * `plugins` - 외부 파서 플러그인 (내장 드라이버보다 먼저 시도)
+/// * `registry` - 검색된 LiDAR 장치 저장소
+/// * `udp_to_ws_tx` - WebSocket으로 데이터를 전송하는 mpsc 채널 송신자
+/// * `local_addrs` - 자기 자신이 송신한 패킷(검색 요청 브로드캐스트 등)을 걸러내기 위한 로컬 주소 목록
+/// * `dedup` - 중복 프레임 제거기 (미사용 시 None)
+/// * `max_buffer_bytes` - 송신 주소별 파서 버퍼 최대 크기 (넘으면 해당 송신 주소의 버퍼를 비움)
+/// * `source_filter` - 송신 주소 허용/차단 필터 (미사용 시 None)
+/// * `impairment` - 수신 데이터그램 장애 주입기 (미사용 시 None)
+/// * `breaker` - 파싱 실패가 많은 송신 주소 격리기 (미사용 시 None)
+#[derive(Clone)]
+struct RecvContext {
+    parsers: ParserMap,
+    #[cfg(feature = "plugins")]
+    plugins: PluginList,
+    registry: LiDARRegistry,
+    parse_stats: ParseStatsStore,
+    udp_to_ws_tx: tokio::sync::mpsc::Sender<LiDARPacket>,
+    local_addrs: LocalAddrs,
+    dedup: Option<Arc<Mutex<Deduplicator>>>,
+    max_buffer_bytes: usize,
+    source_filter: Option<Arc<SourceFilter>>,
+    impairment: Option<Arc<Impairer>>,
+    breaker: Option<Arc<CircuitBreaker>>,
+}
+
 /// UDP 리스너 구조체
 ///
 /// # 구조체 필드
 /// * `socket` - UDP 통신을 위한 소켓
-/// * `addr` - 바인딩된 소켓 주소
+/// * `config` - UDP 리스너 설정 (바인딩 주소, 소켓 버퍼, 수신 스레드 등)
 /// * `udp_to_ws_tx` - UDP에서 WebSocket으로 데이터를 전송하는 mpsc 채널 송신자
 /// * `ws_to_udp_rx` - WebSocket에서 UDP로 데이터를 수신하는 mpsc 채널 수신자
-/// * `parsers` - LiDAR 회사별 파서를 저장하는 HashMap
+/// * `parsers` - 송신 주소별로 LiDAR 회사별 파서를 저장하는 저장소
+/// * `plugins` - 외부 파서 플러그인 목록
+/// * `registry` - 검색된 LiDAR 장치 저장소
+/// * `parse_stats` - 송신 주소별 파싱 통계 저장소
+/// * `supervisor` - 내부 태스크 감시 설정
+/// * `connected` - LiDAR 별 연결형 소켓 (`connected` 설정)
+/// * `health` - 서버 시작 상태 저장소 (인터페이스별 멀티캐스트 가입 결과 갱신)
+/// * `local_addrs` - 자기 자신이 송신한 패킷을 걸러내기 위한 로컬 주소 목록
+/// * `dedup` - 중복 프레임 제거기 (공용 소켓과 연결형 소켓이 공유, 미사용 시 None)
+/// * `breaker` - 파싱 실패가 많은 송신 주소 격리기 (공용 소켓과 연결형 소켓이 공유, 미사용 시 None)
 ///
 /// # 주요 기능
 /// * UDP 소켓을 통한 데이터 수신 및 WebSocket으로의 전달
 /// * WebSocket으로부터 받은 데이터를 UDP로 전송
 /// * LiDAR 데이터 파싱 및 처리
+/// * 데이터를 송신한 LiDAR 장치 등록 및 주기적 장치 검색 요청 송신
+/// * 별도 포트로 송신하는 LiDAR 는 LiDAR 별 연결형 소켓으로 수신/송신
+/// * 네트워크 인터페이스 추가/제거 시 멀티캐스트 가입/탈퇴
+/// * 오래된 송신 주소별 상태 정리 및 메모리 사용량 기록
 /// * 양방향 데이터 스트림의 관리 및 에러 처리
 pub struct UdpListener {
     socket: Arc<UdpSocket>,
-    addr: SocketAddr,
-    udp_to_ws_tx: tokio::sync::mpsc::Sender<Vec<u8>>,
-    ws_to_udp_rx: Option<tokio::sync::mpsc::Receiver<Vec<u8>>>,
-    parsers: Arc<Mutex<HashMap<CompanyInfo, Box<dyn LiDARParser>>>>,
+    config: UdpListenerConfig,
+    udp_to_ws_tx: tokio::sync::mpsc::Sender<LiDARPacket>,
+    ws_to_udp_rx: Option<tokio::sync::mpsc::Receiver<OutboundMessage>>,
+    parsers: ParserMap,
+    #[cfg(feature = "plugins")]
+    plugins: PluginList,
+    registry: LiDARRegistry,
+    parse_stats: ParseStatsStore,
+    supervisor: SupervisorConfig,
+    connected: ConnectedSockets,
+    health: HealthState,
+    local_addrs: LocalAddrs,
+    dedup: Option<Arc<Mutex<Deduplicator>>>,
+    breaker: Option<Arc<CircuitBreaker>>,
 }
 
 impl UdpListener {
@@ -39,12 +121,25 @@ impl UdpListener {
     ///
     /// # Examples
     /// ```
-    /// let udp_addr: SocketAddr = "0.0.0.0:5000".parse().unwrap();
-    /// let udp_listener = UdpListener::new(udp_addr, udp_to_ws_tx, ws_to_udp_rx).await?;
+    /// let config = UdpListenerConfig::default();
+    /// let udp_listener = UdpListener::new(
+    ///     config,
+    ///     registry,
+    ///     parse_stats,
+    ///     health,
+    ///     SupervisorConfig::default(),
+    ///     udp_to_ws_tx,
+    ///     ws_to_udp_rx,
+    /// )
+    /// .await?;
     /// ```
     ///
     /// # Arguments
-    /// * `addr` - 바인딩할 소켓 주소
+    /// * `config` - UDP 리스너 설정
+    /// * `registry` - 검색된 LiDAR 장치 저장소
+    /// * `parse_stats` - 송신 주소별 파싱 통계 저장소
+    /// * `health` - 서버 시작 상태 저장소 (바인딩 주소와 멀티캐스트 가입 결과 기록)
+    /// * `supervisor` - 내부 태스크 감시 설정
     /// * `udp_to_ws_tx` - UDP에서 WebSocket으로 데이터를 전송하는 mpsc 채널 송신자
     /// * `ws_to_udp_rx` - WebSocket에서 UDP로 데이터를 수신하는 mpsc 채널 수신자
     ///
@@ -52,149 +147,1091 @@ impl UdpListener {
     /// * `Result<Self, std::io::Error>` - 성공 시 UdpListener 인스턴스, 실패 시 IO 에러
     ///
     /// # 동작 설명
-    /// * 지정된 주소에 UDP 소켓을 바인딩
-    /// * 멀티캐스트 그룹 가입
+    /// * 설정에 따라 소켓 버퍼 크기 및 busy poll 옵션 적용
+    /// * 플랫폼별 주소 재사용 옵션 적용 후 지정된 주소에 UDP 소켓을 바인딩
+    /// * 멀티캐스트 그룹 가입 (Windows 어댑터 특성을 고려하여 인터페이스 필터링)
+    /// * 바인딩 주소와 인터페이스별 멀티캐스트 가입 결과를 시작 상태 저장소에 기록
+    /// * 장치 검색 사용 시 브로드캐스트 송신 허용 및 멀티캐스트 루프백 해제
     /// * 소켓과 채널들을 포함하는 UdpListener 인스턴스 생성
     pub async fn new(
-        addr: SocketAddr,
-        udp_to_ws_tx: tokio::sync::mpsc::Sender<Vec<u8>>,
-        ws_to_udp_rx: tokio::sync::mpsc::Receiver<Vec<u8>>,
+        config: UdpListenerConfig,
+        registry: LiDARRegistry,
+        parse_stats: ParseStatsStore,
+        health: HealthState,
+        supervisor: SupervisorConfig,
+        udp_to_ws_tx: tokio::sync::mpsc::Sender<LiDARPacket>,
+        ws_to_udp_rx: tokio::sync::mpsc::Receiver<OutboundMessage>,
     ) -> Result<Self, std::io::Error> {
         let socket2 = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
-        socket2.set_reuse_address(true)?;
-        socket2.bind(&addr.into())?;
+        set_reuse(&socket2)?;
+        apply_socket_options(&socket2, &config)?;
+        socket2.bind(&config.addr.into())?;
+        socket2.set_nonblocking(true)?;
         let socket = UdpSocket::from_std(socket2.into())?;
 
-        // 모든 네트워크 인터페이스 가져오기
-        let interfaces = NetworkInterface::show().unwrap();
+        // 모든 네트워크 인터페이스에서 멀티캐스트 가입
+        let joins = join_multicast(&socket, config.multicast_addr);
+        health.set_udp_bound(socket.local_addr()?, joins);
+
+        // 주기적 검색을 사용하지 않아도 요청 시 검색(`OutboundMessage::Discover`)에 사용
+        socket.set_broadcast(true)?;
+        socket.set_multicast_loop_v4(false)?;
+
+        let local_addrs = to_local_addrs(&multicast_interfaces(), config.addr.port());
+        let dedup = config
+            .dedup
+            .enabled
+            .then(|| Arc::new(Mutex::new(Deduplicator::new(&config.dedup))));
+        let breaker = config
+            .error_budget
+            .enabled
+            .then(|| Arc::new(CircuitBreaker::new(&config.error_budget)));
 
-        for interface in interfaces {
-            let vec_addr = interface.addr;
-            for addr in vec_addr {
-                if let network_interface::Addr::V4(ipv4) = addr {
-                    info!("Joining multicast on interface: {}", ipv4.ip);
-                    let _ = socket.join_multicast_v4(Ipv4Addr::new(224, 0, 0, 5), ipv4.ip);
-                }
-            }
-        }
         Ok(Self {
             socket: Arc::new(socket),
-            addr,
+            config,
             udp_to_ws_tx,
             ws_to_udp_rx: Some(ws_to_udp_rx),
-            parsers: Arc::new(Mutex::new(HashMap::new())),
+            parsers: Arc::new(Mutex::new(SenderParsers::default())),
+            #[cfg(feature = "plugins")]
+            plugins: Arc::new(Mutex::new(Vec::new())),
+            registry,
+            parse_stats,
+            supervisor,
+            connected: ConnectedSockets::default(),
+            health,
+            local_addrs: Arc::new(RwLock::new(local_addrs)),
+            dedup,
+            breaker,
         })
     }
 
+    /// 외부 파서 플러그인 등록 (`start` 전에 호출)
+    ///
+    /// # Examples
+    /// ```
+    /// udp_listener.set_plugins(load_plugins(&config.plugins)).await;
+    /// ```
+    ///
+    /// # Arguments
+    /// * `plugins` - 등록할 플러그인, 수신한 패킷은 등록 순서대로 플러그인에 먼저 전달
+    #[cfg(feature = "plugins")]
+    pub async fn set_plugins(&mut self, plugins: Vec<PluginParser>) {
+        *self.plugins.lock().await = plugins;
+    }
+
+    /// 바인딩한 UDP 주소 (`addr` 포트가 0 이면 운영체제가 고른 포트)
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
     /// UDP 리스너의 메인 실행 함수
     ///
     /// # Examples
     /// ```
-    /// let udp_listener = UdpListener::new(udp_addr, udp_to_ws_tx, ws_to_udp_rx).await?;
+    /// let udp_listener = UdpListener::new(
+    ///     config,
+    ///     registry,
+    ///     parse_stats,
+    ///     health,
+    ///     supervisor,
+    ///     udp_to_ws_tx,
+    ///     ws_to_udp_rx,
+    /// )
+    /// .await?;
     /// udp_listener.start().await;
     /// ```
     ///
     /// # 동작 설명
-    /// * 두 개의 비동기 태스크를 생성하여 실행:
+    /// * 비동기 태스크를 생성하여 실행:
     ///   - UDP 수신 태스크:
-    ///     * UDP 소켓으로부터 데이터를 수신
+    ///     * UDP 소켓으로부터 데이터를 수신 (설정 시 recvmmsg 배치 수신)
     ///     * LiDAR 데이터 파싱 및 인코딩
+    ///     * 송신한 LiDAR 장치를 저장소에 등록
     ///     * WebSocket으로 전달
+    ///     * `dedicated_recv_thread` 설정 시 전용 OS 스레드의 단일 스레드 런타임에서 실행
     ///   - 채널 통신 태스크:
     ///     * WebSocket으로부터 받은 데이터를 처리
-    ///     * UDP로 전송
+    ///     * LiDAR 명령 프레임은 대상 주소로 UDP 전송
+    ///   - 장치 검색 태스크 (`discovery.enabled` 설정 시):
+    ///     * 설정된 주소로 주기적으로 검색 요청 송신, 응답은 UDP 수신 태스크에서 처리
+    ///   - 인터페이스 감시 태스크 (`interface_watch.enabled` 설정 시):
+    ///     * 주기적으로 인터페이스 목록을 확인하여 새 인터페이스는 멀티캐스트 가입, 사라진 인터페이스는 탈퇴
+    ///   - 연결형 소켓 수신 태스크 (`connected` 설정 시):
+    ///     * LiDAR 마다 전용 포트에 바인딩하고 LiDAR 주소로 connect 한 소켓으로 수신, 처리는 UDP 수신 태스크와 동일
+    ///     * 장치 식별 과정에서 LiDAR 의 송신 주소가 바뀌면 새 주소로 다시 연결
+    /// * 각 태스크는 감시 태스크로 실행하여 패닉하면 다시 시작 (전용 수신 스레드가 패닉하면 새 스레드 생성)
     /// * 에러 발생 시 로깅 처리
     /// * 양방향 통신의 지속적인 모니터링 및 관리
     pub async fn start(&mut self) {
+        // 장치 검색
+        let discovery_handle = if self.config.discovery.enabled {
+            let socket = Arc::clone(&self.socket);
+            let config = self.config.discovery.clone();
+            Some(supervise("udp-discovery", &self.supervisor, move || {
+                Self::discovery_loop(Arc::clone(&socket), config.clone())
+            }))
+        } else {
+            None
+        };
+
+        // 네트워크 인터페이스 변경 감시
+        let interface_handle = if self.config.interface_watch.enabled {
+            let socket = Arc::clone(&self.socket);
+            let config = self.config.clone();
+            let health = self.health.clone();
+            let local_addrs = self.local_addrs.clone();
+            Some(supervise("udp-interfaces", &self.supervisor, move || {
+                Self::interface_loop(
+                    Arc::clone(&socket),
+                    config.clone(),
+                    health.clone(),
+                    local_addrs.clone(),
+                )
+            }))
+        } else {
+            None
+        };
+
+        // 송신 주소별 상태 정리
+        let sweep_handle = {
+            let config = self.config.sender_state.clone();
+            let parse_stats = self.parse_stats.clone();
+            let dedup = self.dedup.clone();
+            let breaker = self.breaker.clone();
+            let parsers = self.parsers.clone();
+            supervise("udp-sweep", &self.supervisor, move || {
+                Self::sweep_loop(
+                    config.clone(),
+                    parse_stats.clone(),
+                    dedup.clone(),
+                    breaker.clone(),
+                    parsers.clone(),
+                )
+            })
+        };
+
+        if self.config.impairment.enabled {
+            warn!(
+                "Injecting impairment into received datagrams: {:?}",
+                self.config.impairment
+            );
+        }
+
         // UDP 통신
-        let recv_socket = Arc::clone(&self.socket);
-        let udp_to_ws_tx = self.udp_to_ws_tx.clone();
-        let prasers = self.parsers.clone();
-        let recv_handle = tokio::spawn(async move {
-            let mut buf = vec![0u8; 65535];
-            loop {
-                match recv_socket.recv_from(&mut buf).await {
-                    Ok((size, _src_addr)) => {
-                        let data = buf[..size].to_vec();
-                        let mut parser_guard = prasers.lock().await;
-                        let parse_result: Result<Box<dyn LiDARData>, ()>;
-
-                        // 추후 필요 시 회사 별 구분값에 따라 처리 필요
-                        if true || data[0] == 0xFA || _src_addr.port() == 5000 {
-                            parser_guard
-                                .entry(CompanyInfo::KanaviMobility)
-                                .or_insert_with(|| Box::new(KanaviMobilityParser::new()));
-
-                            let ip = if let SocketAddr::V4(addr) = _src_addr {
-                                *addr.ip()
-                            } else {
-                                Ipv4Addr::new(0, 0, 0, 0)
-                            };
-
-                            parse_result = parser_guard
-                                .get_mut(&CompanyInfo::KanaviMobility)
-                                .unwrap()
-                                .parse(ip, &data);
-                        } else {
-                            // 추후 필요 시 다른 회사 파서 추가 필요
-                            error!("Unknown company");
-                            parse_result = Err(());
-                        }
+        let socket = Arc::clone(&self.socket);
+        let batch_size = self.config.recv_batch_size;
+        let context = self.recv_context();
+        let recv_handle = if self.config.dedicated_recv_thread {
+            let core = self.config.recv_thread_core;
+            supervise("udp-recv", &self.supervisor, move || {
+                Self::recv_thread(Arc::clone(&socket), core, batch_size, context.clone())
+            })
+        } else {
+            supervise("udp-recv", &self.supervisor, move || {
+                Self::recv_loop(Arc::clone(&socket), batch_size, context.clone())
+            })
+        };
 
-                        if let Err(e) = parse_result {
-                            error!("Failed to parse LiDAR data: {:?}", e);
-                            continue;
-                        }
+        // LiDAR 별 연결형 소켓
+        let identity_handle = if self.config.connected.is_empty() {
+            None
+        } else {
+            let context = self.recv_context();
+            for sensor in &self.config.connected {
+                Self::connect_sensor(
+                    &self.config,
+                    &self.connected,
+                    &context,
+                    &self.supervisor,
+                    sensor.sensor,
+                    sensor.local_port,
+                );
+            }
+            let config = self.config.clone();
+            let connected = self.connected.clone();
+            let supervisor = self.supervisor.clone();
+            Some(supervise("udp-identity", &self.supervisor, move || {
+                Self::identity_loop(
+                    config.clone(),
+                    connected.clone(),
+                    context.clone(),
+                    supervisor.clone(),
+                    context.registry.subscribe_changes(),
+                )
+            }))
+        };
 
-                        let mut final_data = vec![CompanyInfo::KanaviMobility as u8];
-                        let data = parse_result.unwrap();
-                        match data.get_company_info() {
-                            CompanyInfo::KanaviMobility => {
-                                let mut encoded_data: Vec<u8> = vec![0u8; 65535];
-                                if let Some(kv_data) = data.as_any().downcast_ref::<KanaviMobilityData>() {
-                                    let _ = encode_into_slice(kv_data, &mut encoded_data, standard());
-                                    final_data.extend_from_slice(&encoded_data);
-                                }
-                            }
-                            _ => {
-                                error!("Unknown company");
-                            }
-                        }
+        // Channel 통신
+        let rx = Arc::new(Mutex::new(self.ws_to_udp_rx.take().unwrap()));
+        let socket = Arc::clone(&self.socket);
+        let connected = self.connected.clone();
+        let discovery = self.config.discovery.clone();
+        let send_handle = supervise("udp-send", &self.supervisor, move || {
+            Self::send_loop(
+                Arc::clone(&socket),
+                connected.clone(),
+                discovery.clone(),
+                Arc::clone(&rx),
+            )
+        });
 
-                        // send to ws
-                        let _ = udp_to_ws_tx.send(final_data).await;
-                    }
-                    Err(e) => {
-                        eprintln!("Failed to receive data: {}", e);
+        // 두 태스크가 완료될 때까지 대기
+        let _ = tokio::join!(recv_handle, send_handle);
+
+        if let Some(handle) = discovery_handle {
+            handle.abort();
+        }
+        if let Some(handle) = identity_handle {
+            handle.abort();
+        }
+        if let Some(handle) = interface_handle {
+            handle.abort();
+        }
+        sweep_handle.abort();
+        for (sensor, _) in self.connected.list() {
+            self.connected.remove(&sensor);
+        }
+    }
+
+    /// LiDAR 연결형 소켓을 만들고 수신 태스크 시작
+    ///
+    /// # Arguments
+    /// * `config` - UDP 리스너 설정
+    /// * `connected` - 연결형 소켓 저장소
+    /// * `context` - 수신 루프 공유 상태
+    /// * `supervisor` - 내부 태스크 감시 설정
+    /// * `sensor` - LiDAR 송신 주소
+    /// * `local_port` - LiDAR 가 데이터를 보내는 서버 포트
+    ///
+    /// # 동작 설명
+    /// * 소켓 생성에 실패하면 에러 로그만 남기며, 해당 LiDAR 는 공용 소켓으로 받을 수 있는 경우에만 수신
+    fn connect_sensor(
+        config: &UdpListenerConfig,
+        connected: &ConnectedSockets,
+        context: &RecvContext,
+        supervisor: &SupervisorConfig,
+        sensor: SocketAddrV4,
+        local_port: u16,
+    ) {
+        let socket = match bind_connected(config, sensor, local_port) {
+            Ok(socket) => Arc::new(socket),
+            Err(e) => {
+                error!(
+                    "Failed to bind connected socket for {} on port {}: {}",
+                    sensor, local_port, e
+                );
+                return;
+            }
+        };
+        info!(
+            "Receiving {} on connected socket port {}",
+            sensor, local_port
+        );
+
+        let task = {
+            let socket = Arc::clone(&socket);
+            let context = context.clone();
+            supervise("udp-connected", supervisor, move || {
+                Self::connected_loop(Arc::clone(&socket), sensor, context.clone())
+            })
+        };
+        connected.insert(sensor, local_port, socket, task);
+    }
+
+    /// 연결형 소켓 수신 루프
+    ///
+    /// # Arguments
+    /// * `socket` - LiDAR 주소로 connect 한 소켓
+    /// * `sensor` - LiDAR 송신 주소
+    /// * `context` - 수신 루프 공유 상태
+    async fn connected_loop(socket: Arc<UdpSocket>, sensor: SocketAddrV4, context: RecvContext) {
+        let mut buffer = vec![0u8; 65535];
+        loop {
+            match socket.recv(&mut buffer).await {
+                Ok(len) => {
+                    Self::receive(&context, &buffer[..len], SocketAddr::V4(sensor)).await;
+                }
+                Err(e) if is_transient_recv_error(&e) => {
+                    debug!("Ignoring transient receive error from {}: {}", sensor, e);
+                }
+                Err(e) => {
+                    error!("Failed to receive data from {}: {}", sensor, e);
+                }
+            }
+        }
+    }
+
+    /// 연결형 소켓을 사용하는 LiDAR 의 송신 주소가 바뀌면 새 주소로 다시 연결
+    ///
+    /// # Arguments
+    /// * `config` - UDP 리스너 설정
+    /// * `connected` - 연결형 소켓 저장소
+    /// * `context` - 수신 루프 공유 상태
+    /// * `supervisor` - 내부 태스크 감시 설정
+    /// * `changes` - LiDAR 키 변경 알림 수신자
+    ///
+    /// # 동작 설명
+    /// * 바뀐 주소의 데이터(검색 응답 등)는 공용 소켓으로 수신되어 장치 저장소가 같은 장치로 판단하면 변경 알림 전송
+    /// * 이전 주소의 연결형 소켓을 닫고 같은 서버 포트에 새 주소로 connect 한 소켓을 만듦
+    async fn identity_loop(
+        config: UdpListenerConfig,
+        connected: ConnectedSockets,
+        context: RecvContext,
+        supervisor: SupervisorConfig,
+        mut changes: broadcast::Receiver<IdentityChange>,
+    ) {
+        loop {
+            let change = match changes.recv().await {
+                Ok(change) => change,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("Missed {} LiDAR identity changes", skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => return,
+            };
+            let previous = SocketAddrV4::new(change.previous.ip, change.previous.port);
+            let current = SocketAddrV4::new(change.ip, change.port);
+            if previous == current {
+                continue;
+            }
+            let Some(local_port) = connected.remove(&previous) else {
+                continue;
+            };
+            info!(
+                "LiDAR moved from {} to {}, reconnecting port {}",
+                previous, current, local_port
+            );
+            Self::connect_sensor(
+                &config,
+                &connected,
+                &context,
+                &supervisor,
+                current,
+                local_port,
+            );
+        }
+    }
+
+    /// 수신 루프에서 사용할 공유 상태 생성
+    fn recv_context(&self) -> RecvContext {
+        RecvContext {
+            parsers: self.parsers.clone(),
+            #[cfg(feature = "plugins")]
+            plugins: self.plugins.clone(),
+            registry: self.registry.clone(),
+            parse_stats: self.parse_stats.clone(),
+            udp_to_ws_tx: self.udp_to_ws_tx.clone(),
+            local_addrs: self.local_addrs.clone(),
+            dedup: self.dedup.clone(),
+            max_buffer_bytes: self.config.sender_state.max_buffer_bytes,
+            source_filter: self
+                .config
+                .source_filter
+                .is_enabled()
+                .then(|| Arc::new(SourceFilter::new(&self.config.source_filter))),
+            impairment: self
+                .config
+                .impairment
+                .enabled
+                .then(|| Arc::new(Impairer::new(&self.config.impairment))),
+            breaker: self.breaker.clone(),
+        }
+    }
+
+    /// 주기적으로 네트워크 인터페이스 목록을 확인하여 멀티캐스트 가입 갱신
+    ///
+    /// # Arguments
+    /// * `socket` - 멀티캐스트에 가입한 UDP 소켓
+    /// * `config` - UDP 리스너 설정 (멀티캐스트 그룹, 바인딩 포트, 감시 주기)
+    /// * `health` - 인터페이스별 멀티캐스트 가입 결과를 기록할 시작 상태 저장소
+    /// * `local_addrs` - 수신 루프와 공유하는 로컬 주소 목록
+    ///
+    /// # 동작 설명
+    /// * 새 인터페이스는 가입, 사라진 인터페이스는 탈퇴, 가입에 실패했던 인터페이스는 재시도 (`sync_multicast`)
+    /// * 가입 결과와 인터페이스별 가입/탈퇴 통계는 시작 상태 저장소에 기록 (`/readyz` 의 `multicast`)
+    /// * 인터페이스 주소가 바뀌면 자기 자신이 송신한 패킷을 걸러내는 로컬 주소 목록도 갱신
+    /// * 상태를 시작 상태 저장소에서 읽으므로 태스크가 다시 시작되어도 통계가 유지됨
+    async fn interface_loop(
+        socket: Arc<UdpSocket>,
+        config: UdpListenerConfig,
+        health: HealthState,
+        local_addrs: LocalAddrs,
+    ) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_millis(
+            config.interface_watch.interval_ms.max(100),
+        ));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            // 인터페이스 조회는 OS 호출이므로 블로킹 스레드에서 실행
+            // 조회에 실패하면 모든 인터페이스가 사라진 것으로 보지 않도록 다음 주기까지 대기
+            let interfaces = match tokio::task::spawn_blocking(list_multicast_interfaces).await {
+                Ok(Ok(interfaces)) => interfaces,
+                Ok(Err(e)) => {
+                    warn!("Failed to enumerate network interfaces: {}", e);
+                    continue;
+                }
+                Err(e) => {
+                    error!("Interface enumeration task failed: {}", e);
+                    continue;
+                }
+            };
+
+            let mut joins = health.multicast();
+            if sync_multicast(&socket, config.multicast_addr, &mut joins, &interfaces) {
+                health.set_multicast(joins);
+            }
+
+            let addrs = to_local_addrs(&interfaces, config.addr.port());
+            let mut current = local_addrs.write().unwrap();
+            if *current != addrs {
+                debug!("Local addresses changed: {:?}", addrs);
+                *current = addrs;
+            }
+            drop(current);
+        }
+    }
+
+    /// 주기적으로 송신 주소별 상태를 정리하고 메모리 사용량 기록
+    ///
+    /// # Arguments
+    /// * `config` - 송신 주소별 상태 정리 설정
+    /// * `parse_stats` - 파싱 통계 저장소 (오래된 송신 주소 삭제, 메모리 사용량 기록)
+    /// * `dedup` - 중복 프레임 제거기 (미사용 시 None)
+    /// * `breaker` - 파싱 실패가 많은 송신 주소 격리기 (미사용 시 None)
+    /// * `parsers` - 송신 주소별 파서 (오래된 송신 주소 삭제, 버퍼 크기 조회)
+    ///
+    /// # 동작 설명
+    /// * `ttl_ms` 의 1/4 주기(1 ~ 60 초)로 실행, `ttl_ms` 가 0 이면 삭제 없이 메모리 사용량만 10 초마다 기록
+    /// * `ttl_ms` 동안 데이터가 없는 송신 주소의 파싱 통계 삭제
+    /// * 중복 제거 기록은 `dedup.window_ms` 동안 데이터그램이 없는 LiDAR 삭제 (이미 중복 판단에 쓰이지 않는 기록)
+    /// * 격리기 기록은 `ttl_ms` 동안 데이터가 없는 송신 주소 삭제 (격리 중인 송신 주소는 유지)
+    /// * 파서는 `ttl_ms` 동안 데이터가 없는 송신 주소 삭제 (보관 중인 프레임 조각도 버림)
+    /// * 파서 버퍼 크기는 송신 주소별(`parser_stats` 의 `buffered_bytes`)과 합계(`/udp/memory`)로 기록
+    async fn sweep_loop(
+        config: SenderStateConfig,
+        parse_stats: ParseStatsStore,
+        dedup: Option<Arc<Mutex<Deduplicator>>>,
+        breaker: Option<Arc<CircuitBreaker>>,
+        parsers: ParserMap,
+    ) {
+        let period_ms = if config.ttl_ms == 0 {
+            10_000
+        } else {
+            (config.ttl_ms / 4).clamp(1000, 60_000)
+        };
+        let mut interval = tokio::time::interval(std::time::Duration::from_millis(period_ms));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            if config.ttl_ms > 0 {
+                let evicted = parse_stats.evict_stale(config.ttl_ms).await;
+                if evicted > 0 {
+                    debug!("Evicted parse stats of {} idle senders", evicted);
+                }
+                let ttl = std::time::Duration::from_millis(config.ttl_ms);
+                if let Some(breaker) = &breaker {
+                    breaker.evict_idle(ttl);
+                }
+                let evicted = parsers.lock().await.evict_idle(ttl);
+                if evicted > 0 {
+                    debug!("Evicted parsers of {} idle senders", evicted);
+                }
+            }
+            let dedup_bytes = match &dedup {
+                Some(dedup) => {
+                    let mut dedup = dedup.lock().await;
+                    dedup.evict_idle();
+                    dedup.memory_bytes()
+                }
+                None => 0,
+            };
+            let parser_buffers = parsers.lock().await.buffered();
+            parse_stats
+                .record_memory(dedup_bytes, &parser_buffers)
+                .await;
+        }
+    }
+
+    /// 주기적으로 LiDAR 장치 검색 요청 송신
+    ///
+    /// # Arguments
+    /// * `socket` - 송신할 UDP 소켓 (응답이 수신 소켓으로 돌아오도록 동일 소켓 사용)
+    /// * `config` - 장치 검색 설정
+    ///
+    /// # 동작 설명
+    /// * Kanavi Mobility 버전 정보/네트워크 소스 정보 요청 프레임을 모든 검색 대상 주소로 송신
+    /// * 응답(0x71, 0xD1)은 수신 루프에서 일반 데이터와 동일하게 파싱되어 장치 저장소에 등록
+    async fn discovery_loop(socket: Arc<UdpSocket>, config: DiscoveryConfig) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_millis(
+            config.interval_ms.max(100),
+        ));
+        loop {
+            interval.tick().await;
+            Self::send_discovery(&socket, &config.targets).await;
+        }
+    }
+
+    /// 모든 검색 대상 주소로 장치 검색 요청 송신
+    ///
+    /// # Returns
+    /// * `usize` - 송신한 요청 수
+    async fn send_discovery(socket: &UdpSocket, targets: &[SocketAddr]) -> usize {
+        let requests = discovery_requests();
+        let mut sent = 0;
+        for target in targets {
+            for request in &requests {
+                match socket.send_to(request, target).await {
+                    Ok(_) => sent += 1,
+                    Err(e) => warn!("Failed to send discovery request to {}: {}", target, e),
+                }
+            }
+        }
+        sent
+    }
+
+    /// WebSocket 으로부터 받은 메시지 처리 루프
+    ///
+    /// # Arguments
+    /// * `socket` - 명령 프레임을 송신할 UDP 소켓
+    /// * `connected` - LiDAR 별 연결형 소켓 (대상 LiDAR 의 연결형 소켓이 있으면 해당 소켓으로 송신)
+    /// * `discovery` - 장치 검색 설정 (요청 시 검색의 대상 주소)
+    /// * `rx` - WebSocket에서 UDP로 데이터를 수신하는 mpsc 채널 수신자 (감시 태스크가 다시 시작할 때 재사용)
+    ///
+    /// # 동작 설명
+    /// * LiDAR 명령 프레임은 대상 주소로 UDP 전송 (명령을 보낸 WebSocket 요청의 span 안에서 기록)
+    /// * 장치 검색 요청은 주기적 검색 사용 여부와 관계없이 검색 대상 주소로 바로 송신
+    /// * 채널이 닫히면 종료
+    async fn send_loop(
+        socket: Arc<UdpSocket>,
+        connected: ConnectedSockets,
+        discovery: DiscoveryConfig,
+        rx: Arc<Mutex<tokio::sync::mpsc::Receiver<OutboundMessage>>>,
+    ) {
+        let mut rx = rx.lock().await;
+        loop {
+            match rx.recv().await {
+                Some(OutboundMessage::Command {
+                    target,
+                    frame,
+                    span,
+                }) => {
+                    let sent = match connected.get(&target) {
+                        Some(socket) => socket.send(&frame).instrument(span.clone()).await,
+                        None => {
+                            socket
+                                .send_to(&frame, target)
+                                .instrument(span.clone())
+                                .await
+                        }
+                    };
+                    match sent {
+                        Ok(_) => span.in_scope(|| {
+                            debug!("Command sent to {} ({} bytes)", target, frame.len())
+                        }),
+                        Err(e) => {
+                            span.in_scope(|| error!("Failed to send command to {}: {}", target, e))
+                        }
                     }
                 }
+                Some(OutboundMessage::Discover { span }) => {
+                    let sent = Self::send_discovery(&socket, &discovery.targets)
+                        .instrument(span.clone())
+                        .await;
+                    span.in_scope(|| {
+                        info!(
+                            "Discovery sweep sent {} requests to {:?}",
+                            sent, discovery.targets
+                        )
+                    });
+                }
+                None => {
+                    error!("Channel closed");
+                    break;
+                }
             }
-        });
+        }
+    }
 
-        // Channel 통신
-        let mut rx = self.ws_to_udp_rx.take().unwrap();
-        let tx = self.udp_to_ws_tx.clone();
-        let send_socket = Arc::clone(&self.socket);
-        let addr = self.addr;
-        let send_handle = tokio::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Some(data) => {
-                        debug!(
-                            "WS -> UDP data received: {:?}",
-                            String::from_utf8(data.clone()).unwrap()
-                        );
-                        debug!("UDP -> WS data send: {:?}", data);
-                        let _ = tx.send(data.clone()).await;
+    /// 전용 OS 스레드에서 UDP 수신 루프 실행
+    ///
+    /// # Arguments
+    /// * `socket` - 수신할 UDP 소켓 (복제하여 전용 스레드에 등록)
+    /// * `core` - 스레드를 고정할 CPU 코어 (미사용 시 None)
+    /// * `batch_size` - 한 번에 수신할 최대 데이터그램 수
+    /// * `context` - 수신 루프 공유 상태
+    ///
+    /// # 동작 설명
+    /// * 소켓을 복제하여 전용 스레드의 단일 스레드 런타임에 등록
+    /// * 설정된 경우 스레드를 지정한 CPU 코어에 고정
+    /// * 수신, 파싱, 인코딩을 모두 전용 스레드에서 처리하여 메인 런타임과 경합하지 않도록 함
+    /// * 스레드가 종료될 때까지 대기하고, 스레드가 패닉하면 같은 패닉을 다시 발생시켜 감시 태스크가 다시 시작하도록 함
+    async fn recv_thread(
+        socket: Arc<UdpSocket>,
+        core: Option<usize>,
+        batch_size: usize,
+        context: RecvContext,
+    ) {
+        let socket = match SockRef::from(socket.as_ref()).try_clone() {
+            Ok(socket) => socket,
+            Err(e) => {
+                error!("Failed to clone UDP socket: {}", e);
+                return;
+            }
+        };
+
+        let thread = std::thread::Builder::new()
+            .name("udp-recv".to_string())
+            .spawn(move || {
+                if let Some(core) = core {
+                    pin_current_thread(core);
+                }
+
+                let runtime = match tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                {
+                    Ok(runtime) => runtime,
+                    Err(e) => {
+                        error!("Failed to build UDP receive runtime: {}", e);
+                        return;
                     }
-                    None => {
-                        error!("Channel closed");
+                };
+
+                runtime.block_on(async move {
+                    let socket = match UdpSocket::from_std(socket.into()) {
+                        Ok(socket) => Arc::new(socket),
+                        Err(e) => {
+                            error!("Failed to register UDP socket: {}", e);
+                            return;
+                        }
+                    };
+                    Self::recv_loop(socket, batch_size, context).await;
+                });
+            });
+        let thread = match thread {
+            Ok(thread) => thread,
+            Err(e) => {
+                error!("Failed to spawn UDP receive thread: {}", e);
+                return;
+            }
+        };
+
+        match tokio::task::spawn_blocking(move || thread.join()).await {
+            Ok(Ok(())) => {}
+            Ok(Err(payload)) => std::panic::resume_unwind(payload),
+            Err(e) => error!("Failed to join UDP receive thread: {}", e),
+        }
+    }
+
+    /// UDP 수신 루프
+    ///
+    /// # Arguments
+    /// * `socket` - 수신할 UDP 소켓
+    /// * `batch_size` - 한 번에 수신할 최대 데이터그램 수
+    /// * `context` - 수신 루프 공유 상태
+    async fn recv_loop(socket: Arc<UdpSocket>, batch_size: usize, context: RecvContext) {
+        let mut receiver = BatchReceiver::new(batch_size, 65535);
+        loop {
+            match receiver.recv(&socket).await {
+                Ok(count) => {
+                    for i in 0..count {
+                        let (data, src_addr) = receiver.datagram(i);
+                        Self::receive(&context, data, src_addr).await;
                     }
                 }
+                Err(e) if is_transient_recv_error(&e) => {
+                    debug!("Ignoring transient receive error: {}", e);
+                }
+                Err(e) => {
+                    error!("Failed to receive data: {}", e);
+                }
+            }
+        }
+    }
+
+    /// 장애 주입을 적용하여 수신한 데이터그램 처리
+    ///
+    /// # Arguments
+    /// * `context` - 수신 루프 공유 상태
+    /// * `data` - 수신한 데이터
+    /// * `src_addr` - 송신자 주소
+    ///
+    /// # 동작 설명
+    /// * 송신 주소 필터가 거부한 데이터그램은 복사나 통계 항목 생성 없이 거부 수만 기록하고 버림
+    /// * 장애 주입 미사용 시 바로 처리
+    /// * 버릴 데이터그램은 처리하지 않고, 중복할 데이터그램은 두 번 처리
+    /// * 지연할 데이터그램은 복사하여 별도 태스크에서 지연 후 처리 (뒤에 받은 데이터그램보다 늦게 처리될 수 있음)
+    async fn receive(context: &RecvContext, data: &[u8], src_addr: SocketAddr) {
+        if let Some(rejection) = context
+            .source_filter
+            .as_ref()
+            .and_then(|filter| filter.check(&src_addr))
+        {
+            trace!(
+                "Rejected {} bytes from {}: {:?}",
+                data.len(),
+                src_addr,
+                rejection
+            );
+            context.parse_stats.record_rejected(rejection);
+            return;
+        }
+
+        let Some(impairer) = &context.impairment else {
+            Self::handle_datagram(context, data, src_addr).await;
+            return;
+        };
+        let impairment = impairer.decide();
+        if impairment.copies == 0 {
+            trace!("Impairment dropped {} bytes from {}", data.len(), src_addr);
+            return;
+        }
+        if impairment.delay.is_zero() {
+            for _ in 0..impairment.copies {
+                Self::handle_datagram(context, data, src_addr).await;
+            }
+            return;
+        }
+        let context = context.clone();
+        let data = data.to_vec();
+        tokio::spawn(async move {
+            tokio::time::sleep(impairment.delay).await;
+            for _ in 0..impairment.copies {
+                Self::handle_datagram(&context, &data, src_addr).await;
             }
         });
+    }
 
-        // 두 태스크가 완료될 때까지 대기
-        let _ = tokio::join!(recv_handle, send_handle);
+    /// 수신한 데이터그램을 `udp_frame` tracing span 안에서 처리
+    ///
+    /// # Arguments
+    /// * `context` - 수신 루프 공유 상태
+    /// * `data` - 수신한 데이터
+    /// * `src_addr` - 송신자 주소
+    ///
+    /// # 동작 설명
+    /// * 데이터그램마다 새 `trace_id` 를 만들어 span 에 기록하고, WebSocket 으로 전달하는 패킷에도 같은 값을 담아
+    ///   파싱 에러와 WebSocket 전달 로그(`ws_frame` span)를 연결
+    async fn handle_datagram(context: &RecvContext, data: &[u8], src_addr: SocketAddr) {
+        let trace_id = TraceId::next();
+        Self::parse_datagram(context, data, src_addr, trace_id)
+            .instrument(info_span!("udp_frame", trace_id = %trace_id, src = %src_addr))
+            .await;
     }
+
+    /// 수신한 데이터그램을 파싱하여 WebSocket으로 전달
+    ///
+    /// # Arguments
+    /// * `context` - 수신 루프 공유 상태
+    /// * `data` - 수신한 데이터
+    /// * `src_addr` - 송신자 주소
+    /// * `trace_id` - 데이터그램의 로그 상관 관계 ID
+    ///
+    /// # 동작 설명
+    /// * 자기 자신이 송신한 패킷과 비활성화한 LiDAR 만 사용하는 송신 주소의 패킷은 파싱하지 않고 무시
+    /// * 외부 파서 플러그인에 먼저 전달하고, 처리한 플러그인이 없으면 송신 주소의 회사별 파서로 LiDAR 데이터 파싱
+    /// * 송신 주소의 파서 버퍼가 `max_buffer_bytes` 를 넘으면 그 송신 주소의 버퍼만 비움
+    /// * 한 데이터그램에 프레임이 여러 개 있으면 프레임마다 따로 파싱하여 전달
+    /// * 파싱에 성공하면 송신한 LiDAR 장치를 저장소에 등록/갱신
+    /// * 중복 프레임 제거 사용 시 최근에 받은 것과 같은 데이터그램은 중복 수만 기록하고 버림
+    ///   (네트워크 소스 정보 응답이면 보고된 IP/MAC 주소도 함께 전달)
+    /// * 회사 구분값(1 byte) + bincode 인코딩 데이터 형태로 WebSocket에 전달
+    async fn parse_datagram(
+        context: &RecvContext,
+        data: &[u8],
+        src_addr: SocketAddr,
+        trace_id: TraceId,
+    ) {
+        if context.local_addrs.read().unwrap().contains(&src_addr) {
+            return;
+        }
+        if let SocketAddr::V4(addr) = src_addr {
+            if context.registry.is_muted_source(&addr) {
+                trace!("Dropping {} bytes from disabled LiDAR {}", data.len(), addr);
+                return;
+            }
+        }
+        if let Some(breaker) = &context.breaker {
+            if !breaker.allow(src_addr) {
+                trace!(
+                    "Dropping {} bytes from quarantined {}",
+                    data.len(),
+                    src_addr
+                );
+                context.parse_stats.record_quarantined_drop(src_addr).await;
+                return;
+            }
+        }
+
+        let ip = if let SocketAddr::V4(addr) = src_addr {
+            *addr.ip()
+        } else {
+            Ipv4Addr::new(0, 0, 0, 0)
+        };
+
+        #[cfg(feature = "plugins")]
+        {
+            let plugin_result = context
+                .plugins
+                .lock()
+                .await
+                .iter_mut()
+                .find_map(|plugin| plugin.parse(ip, data));
+            match plugin_result {
+                Some(Ok(kv_data)) => {
+                    context.parse_stats.record_ok(src_addr).await;
+                    Self::record_budget(context, src_addr, true, data).await;
+                    Self::forward(
+                        context,
+                        CompanyInfo::Plugin,
+                        src_addr,
+                        &kv_data,
+                        None,
+                        trace_id,
+                    )
+                    .await;
+                    return;
+                }
+                Some(Err(e)) => {
+                    error!("Failed to parse LiDAR data: {}", e);
+                    context
+                        .parse_stats
+                        .record_error(src_addr, &ParseError::Other(e))
+                        .await;
+                    Self::record_budget(context, src_addr, false, data).await;
+                    return;
+                }
+                None => {}
+            }
+        }
+
+        let mut parser_guard = context.parsers.lock().await;
+
+        // 추후 필요 시 회사 별 구분값에 따라 처리 필요 (현재는 Kanavi Mobility 만 지원)
+        let Some(parser) = parser_guard.get_mut(src_addr, CompanyInfo::KanaviMobility) else {
+            trace!("No driver for {}, dropping {} bytes", src_addr, data.len());
+            return;
+        };
+
+        // 한 데이터그램에 프레임이 여러 개 있으면 남은 프레임도 모두 파싱
+        // (같은 송신 주소의 데이터를 받는 다른 수신 태스크와 섞이지 않도록 잠금을 유지한 채 파싱)
+        let mut results = vec![parser.parse(ip, data)];
+        while parser.has_frame() {
+            results.push(parser.parse(ip, &[]));
+        }
+        // 잘못된 프레임 조각이 계속 쌓이지 않도록 송신 주소별 버퍼 크기 제한
+        let buffered = parser.buffered_len();
+        let overflow = buffered > context.max_buffer_bytes;
+        if overflow {
+            warn!(
+                "Parser buffer of {} reached {} bytes, resetting",
+                src_addr, buffered
+            );
+            parser.reset();
+        }
+        drop(parser_guard);
+        if overflow {
+            context.parse_stats.record_buffer_reset(src_addr).await;
+        }
+
+        if results.len() > 1 {
+            trace!("{} frames in one datagram from {}", results.len(), src_addr);
+        }
+        for result in results {
+            match result {
+                ParseOutcome::Frame(parsed) => {
+                    context.parse_stats.record_ok(src_addr).await;
+                    Self::record_budget(context, src_addr, true, data).await;
+                    Self::dispatch(context, src_addr, parsed, trace_id).await;
+                }
+                ParseOutcome::NeedMoreData => {
+                    trace!("Waiting for the rest of a frame from {}", src_addr);
+                }
+                ParseOutcome::Skipped { reason } => {
+                    debug!("Skipped frame from {}: {}", src_addr, reason);
+                }
+                ParseOutcome::Error(e) => {
+                    error!("Failed to parse LiDAR data from {}: {}", src_addr, e);
+                    context.parse_stats.record_error(src_addr, &e).await;
+                    Self::record_budget(context, src_addr, false, data).await;
+                }
+            }
+        }
+    }
+
+    /// 파싱 결과를 격리기에 기록하고 격리 상태가 바뀌면 로그와 파싱 통계 갱신
+    ///
+    /// # Arguments
+    /// * `context` - 수신 루프 공유 상태
+    /// * `src_addr` - 송신자 주소
+    /// * `ok` - 파싱 성공 여부
+    /// * `data` - 파싱한 데이터그램 (격리 시 진단용 샘플)
+    ///
+    /// # 동작 설명
+    /// * 격리를 시작하면 실패 비율, 다시 시도할 때까지의 시간, 최근 실패 데이터그램 앞부분(16진수)을 기록
+    /// * 격리 상태는 파싱 통계의 `quarantined_until_ms` 로 노출 (`parser_stats`, `quarantine` 알림 규칙)
+    async fn record_budget(context: &RecvContext, src_addr: SocketAddr, ok: bool, data: &[u8]) {
+        let Some(breaker) = &context.breaker else {
+            return;
+        };
+        match breaker.record(src_addr, ok, data) {
+            Some(BreakerEvent::Quarantined {
+                addr,
+                error_ratio,
+                frames,
+                retry,
+                samples,
+            }) => {
+                warn!(
+                    "Quarantining {}: {:.0}% of {} frames failed to parse, retrying in {:?}",
+                    addr,
+                    error_ratio * 100.0,
+                    frames,
+                    retry
+                );
+                for sample in &samples {
+                    warn!("Quarantine sample from {}: {}", addr, hex(sample));
+                }
+                let until_ms = unix_time_ms() + retry.as_millis() as u64;
+                context.parse_stats.record_quarantine(addr, until_ms).await;
+            }
+            Some(BreakerEvent::Recovered { addr }) => {
+                info!("{} recovered from quarantine", addr);
+                context.parse_stats.record_recovered(addr).await;
+            }
+            None => {}
+        }
+    }
+
+    /// 파싱한 프레임을 회사별로 처리
+    ///
+    /// # Arguments
+    /// * `context` - 수신 루프 공유 상태
+    /// * `src_addr` - 송신자 주소
+    /// * `data` - 파싱한 프레임
+    /// * `trace_id` - 데이터그램의 로그 상관 관계 ID
+    ///
+    /// # 동작 설명
+    /// * 전달(인코딩)이 끝난 프레임의 포인트 버퍼는 `PointPool` 로 반환하여 다음 프레임 파싱에 재사용
+    async fn dispatch(
+        context: &RecvContext,
+        src_addr: SocketAddr,
+        mut data: Box<dyn LiDARData>,
+        trace_id: TraceId,
+    ) {
+        trace!(
+            "LiDAR {:#012x}: {} bytes parsed",
+            data.get_key(),
+            data.get_raw_data().len()
+        );
+
+        match data.get_company_info() {
+            CompanyInfo::KanaviMobility => {
+                if let Some(kv_data) = data.as_any().downcast_ref::<KanaviMobilityData>() {
+                    let network = match kv_data.get_config_data() {
+                        Some(KMConfigData::NetworkSourceInfo(info)) => Some(info),
+                        _ => None,
+                    };
+                    Self::forward(
+                        context,
+                        CompanyInfo::KanaviMobility,
+                        src_addr,
+                        kv_data,
+                        network,
+                        trace_id,
+                    )
+                    .await;
+                }
+            }
+            _ => {
+                error!("Unknown company");
+            }
+        }
+        PointPool::global().recycle(data.take_points());
+    }
+
+    /// 파싱된 데이터의 LiDAR 를 저장소에 등록하고 WebSocket 으로 전달
+    ///
+    /// # Arguments
+    /// * `context` - 수신 루프 공유 상태
+    /// * `company` - 장치 저장소에 등록할 회사 정보
+    /// * `src_addr` - 송신자 주소
+    /// * `kv_data` - 서버 프레임 형식 데이터
+    /// * `network` - 네트워크 소스 정보 응답으로 보고된 IP/MAC 주소
+    /// * `trace_id` - 데이터그램의 로그 상관 관계 ID (WebSocket 으로 전달하는 패킷에 포함)
+    ///
+    /// # 동작 설명
+    /// * 비활성화한 LiDAR 의 데이터(같은 송신 주소를 활성 LiDAR 와 함께 사용하는 경우)는 WebSocket 으로 전달하지 않음
+    /// * 중복 프레임 제거 사용 시 중복 데이터그램은 WebSocket 으로 전달하지 않음
+    /// * 플러그인 데이터도 같은 프레임 형식이므로 회사 구분값은 항상 Kanavi Mobility 로 인코딩
+    async fn forward(
+        context: &RecvContext,
+        company: CompanyInfo,
+        src_addr: SocketAddr,
+        kv_data: &KanaviMobilityData,
+        network: Option<&NetworkSourceInfo>,
+        trace_id: TraceId,
+    ) {
+        let SocketAddr::V4(src_addr) = src_addr else {
+            return;
+        };
+        let key = context
+            .registry
+            .update(
+                company,
+                src_addr,
+                kv_data.get_product_line(),
+                kv_data.get_lidar_id(),
+                network.map(NetworkSourceInfo::get_ip_address),
+                network.map(NetworkSourceInfo::get_mac_address),
+            )
+            .await;
+        if context.registry.is_disabled(&key) {
+            return;
+        }
+
+        if let Some(dedup) = &context.dedup {
+            if dedup.lock().await.is_duplicate(key, kv_data.get_raw_data()) {
+                let count = context.registry.record_duplicate(&key).await;
+                if count == 1 || count % 1000 == 0 {
+                    debug!("Dropped {} duplicate datagrams from {}", count, src_addr);
+                }
+                return;
+            }
+        }
+
+        let mut final_data = vec![CompanyInfo::KanaviMobility as u8];
+        match encode_to_vec(kv_data, standard()) {
+            Ok(encoded_data) => final_data.extend_from_slice(&encoded_data),
+            Err(e) => {
+                error!("Failed to encode LiDAR data: {}", e);
+                return;
+            }
+        }
+
+        // send to ws
+        let _ = context
+            .udp_to_ws_tx
+            .send(LiDARPacket {
+                key,
+                payload: final_data,
+                replay: false,
+                time_ms: unix_time_ms(),
+                trace_id,
+            })
+            .await;
+    }
+}
+
+/// 바인딩 포트와 로컬 인터페이스 주소 조합으로 로컬 주소 목록 구성
+fn to_local_addrs(interfaces: &[Ipv4Addr], port: u16) -> Vec<SocketAddr> {
+    interfaces
+        .iter()
+        .map(|&ip| SocketAddr::from((ip, port)))
+        .collect()
 }
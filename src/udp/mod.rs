@@ -1,3 +1,11 @@
+pub mod breaker;
+pub mod config;
+pub mod connected;
+pub mod dedup;
+pub mod filter;
 pub mod listener;
+pub mod reassembly;
+pub mod socket;
 
-pub use listener::UdpListener; 
\ No newline at end of file
+pub use config::UdpListenerConfig;
+pub use listener::UdpListener;
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use crate::lidar::traits::LiDARParser;
+use crate::lidar::{drivers, CompanyInfo};
+
+/// 송신 주소 하나의 회사별 파서
+struct SenderParser {
+    parsers: HashMap<CompanyInfo, Box<dyn LiDARParser>>,
+    last_seen: Instant,
+}
+
+/// 송신 주소별 파서 저장소
+///
+/// # Examples
+/// ```
+/// let mut parsers = SenderParsers::default();
+/// if let Some(parser) = parsers.get_mut(src_addr, CompanyInfo::KanaviMobility) {
+///     let outcome = parser.parse(ip, &datagram);
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 파서는 다음 데이터그램과 이어 붙일 프레임 조각을 보관하므로 송신 주소마다 따로 생성
+///   (여러 LiDAR 의 조각이 한 버퍼에 섞이거나, 한 송신 주소의 잘못된 조각 때문에 다른 LiDAR 의 버퍼가 비워지지 않도록 함)
+/// * 프레임 헤더를 파싱하기 전에는 제품 라인/LiDAR ID 를 알 수 없으므로 LiDAR 키의 송신 주소(IP, 포트) 단위로 구분
+/// * 처음 데이터를 받은 송신 주소는 빌드에 포함된 드라이버(`drivers`)로 파서 생성
+#[derive(Default)]
+pub struct SenderParsers {
+    senders: HashMap<SocketAddr, SenderParser>,
+}
+
+impl SenderParsers {
+    /// 송신 주소의 회사별 파서 조회 (없으면 생성)
+    ///
+    /// # Arguments
+    /// * `addr` - 송신 주소
+    /// * `company` - LiDAR 회사
+    ///
+    /// # Returns
+    /// * `Option<&mut Box<dyn LiDARParser>>` - 파서, 빌드에 해당 회사 드라이버가 없으면 None
+    pub fn get_mut(
+        &mut self,
+        addr: SocketAddr,
+        company: CompanyInfo,
+    ) -> Option<&mut Box<dyn LiDARParser>> {
+        let sender = self.senders.entry(addr).or_insert_with(|| SenderParser {
+            parsers: drivers(),
+            last_seen: Instant::now(),
+        });
+        sender.last_seen = Instant::now();
+        sender.parsers.get_mut(&company)
+    }
+
+    /// 송신 주소별로 보관 중인 프레임 조각 크기 (byte)
+    ///
+    /// # Returns
+    /// * `HashMap<SocketAddr, usize>` - 송신 주소별 파서 버퍼 크기의 합
+    pub fn buffered(&self) -> HashMap<SocketAddr, usize> {
+        self.senders
+            .iter()
+            .map(|(addr, sender)| {
+                let bytes = sender.parsers.values().map(|p| p.buffered_len()).sum();
+                (*addr, bytes)
+            })
+            .collect()
+    }
+
+    /// 파서를 보관 중인 송신 주소 수
+    pub fn len(&self) -> usize {
+        self.senders.len()
+    }
+
+    /// 파서를 보관 중인 송신 주소가 없는지 확인
+    pub fn is_empty(&self) -> bool {
+        self.senders.is_empty()
+    }
+
+    /// 오래된 송신 주소의 파서 삭제 (보관 중인 프레임 조각도 함께 버림)
+    ///
+    /// # Arguments
+    /// * `ttl` - 이 시간 동안 데이터를 받지 않은 송신 주소 삭제
+    ///
+    /// # Returns
+    /// * `usize` - 삭제한 송신 주소 수
+    pub fn evict_idle(&mut self, ttl: Duration) -> usize {
+        let now = Instant::now();
+        let before = self.senders.len();
+        self.senders
+            .retain(|_, sender| now.duration_since(sender.last_seen) <= ttl);
+        before - self.senders.len()
+    }
+}
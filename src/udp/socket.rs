@@ -0,0 +1,538 @@
+use network_interface::{NetworkInterface, NetworkInterfaceConfig};
+use schemars::JsonSchema;
+use serde::Serialize;
+use socket2::Socket;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::net::UdpSocket;
+use tracing::*;
+use utoipa::ToSchema;
+
+use crate::common::time::unix_time_ms;
+use crate::udp::config::UdpListenerConfig;
+
+/// 주소 재사용 옵션 적용
+///
+/// # Arguments
+/// * `socket` - 옵션을 적용할 소켓
+///
+/// # Returns
+/// * `io::Result<()>` - 성공 시 Ok(()), 실패 시 IO 에러
+///
+/// # 동작 설명
+/// * 모든 플랫폼: SO_REUSEADDR 설정
+///   - Windows 에서는 SO_REUSEADDR 만으로 동일 포트에 여러 멀티캐스트 리스너 바인딩 가능
+/// * Unix: SO_REUSEPORT 추가 설정 (macOS/BSD 에서 동일 포트 멀티캐스트 수신 시 필요)
+pub fn set_reuse(socket: &Socket) -> io::Result<()> {
+    socket.set_reuse_address(true)?;
+
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    socket.set_reuse_port(true)?;
+
+    Ok(())
+}
+
+/// 멀티캐스트 가입에 사용할 IPv4 인터페이스 주소 목록 조회
+///
+/// # Returns
+/// * `Vec<Ipv4Addr>` - 중복이 제거된 인터페이스 주소 목록
+///
+/// # 동작 설명
+/// * 인터페이스 조회 실패 시 빈 목록 반환 (에러 로그 출력)
+/// * 제외하는 주소는 `list_multicast_interfaces` 참고
+pub fn multicast_interfaces() -> Vec<Ipv4Addr> {
+    list_multicast_interfaces().unwrap_or_else(|e| {
+        error!("Failed to enumerate network interfaces: {}", e);
+        Vec::new()
+    })
+}
+
+/// 멀티캐스트 가입에 사용할 IPv4 인터페이스 주소 목록 조회 (조회 실패를 구분)
+///
+/// # Returns
+/// * `Result<Vec<Ipv4Addr>, String>` - 중복이 제거된 인터페이스 주소 목록, 조회 실패 시 에러 메시지
+///
+/// # 동작 설명
+/// * 다음 주소는 제외:
+///   - 0.0.0.0: Windows 에서 연결되지 않은 어댑터가 보고하는 주소
+///   - 169.254.x.x: Windows 에서 DHCP 실패 시 할당되는 APIPA(link-local) 주소
+///   - 127.x.x.x: 루프백
+/// * Windows 에서 동일 어댑터가 여러 번 조회되는 경우가 있어 중복 제거
+pub fn list_multicast_interfaces() -> Result<Vec<Ipv4Addr>, String> {
+    let interfaces = NetworkInterface::show().map_err(|e| e.to_string())?;
+
+    let mut result: Vec<Ipv4Addr> = Vec::new();
+    for interface in interfaces {
+        for addr in interface.addr {
+            if let network_interface::Addr::V4(ipv4) = addr {
+                let ip = ipv4.ip;
+                if ip.is_unspecified() || ip.is_link_local() || ip.is_loopback() {
+                    trace!("Skipping interface {} ({})", interface.name, ip);
+                    continue;
+                }
+                if !result.contains(&ip) {
+                    result.push(ip);
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// 인터페이스별 멀티캐스트 가입 결과
+///
+/// # Fields
+/// * `group` - 멀티캐스트 그룹 주소
+/// * `interface` - 가입한 인터페이스 주소 (0.0.0.0 이면 OS 기본 인터페이스)
+/// * `joined` - 가입 성공 여부
+/// * `error` - 가입 실패 시 에러 메시지
+/// * `present` - 인터페이스가 현재 존재하는지 여부 (제거된 인터페이스도 통계를 위해 목록에 남김)
+/// * `joins` - 가입 성공 횟수
+/// * `leaves` - 인터페이스 제거로 탈퇴한 횟수
+/// * `failures` - 가입 실패 횟수
+/// * `changed_ms` - 마지막으로 가입/탈퇴한 시각 (Unix ms)
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct MulticastJoin {
+    #[schema(value_type = String, example = "224.0.0.5")]
+    pub group: Ipv4Addr,
+    #[schema(value_type = String, example = "192.168.123.10")]
+    pub interface: Ipv4Addr,
+    pub joined: bool,
+    pub error: Option<String>,
+    pub present: bool,
+    pub joins: u64,
+    pub leaves: u64,
+    pub failures: u64,
+    pub changed_ms: u64,
+}
+
+impl MulticastJoin {
+    /// 가입 시도 결과로 생성
+    fn new(group: Ipv4Addr, interface: Ipv4Addr, result: io::Result<()>) -> Self {
+        let mut join = Self {
+            group,
+            interface,
+            joined: false,
+            error: None,
+            present: true,
+            joins: 0,
+            leaves: 0,
+            failures: 0,
+            changed_ms: 0,
+        };
+        join.record(result);
+        join
+    }
+
+    /// 가입 시도 결과 기록
+    fn record(&mut self, result: io::Result<()>) {
+        match result {
+            Ok(()) => {
+                self.joined = true;
+                self.error = None;
+                self.joins += 1;
+                self.changed_ms = unix_time_ms();
+            }
+            Err(e) => {
+                self.joined = false;
+                self.error = Some(e.to_string());
+                self.failures += 1;
+            }
+        }
+    }
+}
+
+/// 사용 가능한 모든 인터페이스에서 멀티캐스트 그룹 가입
+///
+/// # Arguments
+/// * `socket` - 가입할 UDP 소켓
+/// * `group` - 멀티캐스트 그룹 주소
+///
+/// # Returns
+/// * `Vec<MulticastJoin>` - 가입을 시도한 인터페이스별 결과
+///
+/// # 동작 설명
+/// * `multicast_interfaces` 로 조회한 인터페이스마다 가입 시도, 실패 시 경고 로그
+/// * 가입 가능한 인터페이스가 하나도 없으면 OS 기본 인터페이스(0.0.0.0)로 가입 시도
+pub fn join_multicast(socket: &UdpSocket, group: Ipv4Addr) -> Vec<MulticastJoin> {
+    let mut joins = Vec::new();
+    for ip in multicast_interfaces() {
+        let result = socket.join_multicast_v4(group, ip);
+        match &result {
+            Ok(()) => info!("Joining multicast {} on interface: {}", group, ip),
+            Err(e) => warn!(
+                "Failed to join multicast {} on interface {}: {}",
+                group, ip, e
+            ),
+        }
+        joins.push(MulticastJoin::new(group, ip, result));
+    }
+
+    if !joins.iter().any(|join| join.joined) {
+        let result = socket.join_multicast_v4(group, Ipv4Addr::UNSPECIFIED);
+        match &result {
+            Ok(()) => info!("Joining multicast {} on default interface", group),
+            Err(e) => error!("Failed to join multicast {}: {}", group, e),
+        }
+        joins.push(MulticastJoin::new(group, Ipv4Addr::UNSPECIFIED, result));
+    }
+    joins
+}
+
+/// 현재 인터페이스 목록에 맞춰 멀티캐스트 가입 갱신 (인터페이스 hot-plug 처리)
+///
+/// # Arguments
+/// * `socket` - 가입한 UDP 소켓
+/// * `group` - 멀티캐스트 그룹 주소
+/// * `joins` - 지금까지의 인터페이스별 가입 결과 (갱신됨)
+/// * `interfaces` - 현재 인터페이스 주소 목록 (`multicast_interfaces` 결과)
+///
+/// # Returns
+/// * `bool` - 가입 상태나 인터페이스 존재 여부가 바뀌었으면 true
+///
+/// # 동작 설명
+/// * 새로 나타난 인터페이스와 가입에 실패했던 인터페이스는 가입 시도
+///   - 같은 인터페이스에 이미 가입된 경우(기본 인터페이스 가입과 겹침)의 AddrInUse 는 성공으로 처리
+///   - 실패 경고 로그는 인터페이스가 나타났을 때만 출력하고, 이후 재시도 실패는 debug 로그
+/// * 사라진 인터페이스는 탈퇴 시도 (인터페이스가 이미 없어 OS 가 가입을 정리했으면 실패해도 탈퇴로 기록)
+/// * OS 기본 인터페이스(0.0.0.0) 가입은 그대로 유지
+pub fn sync_multicast(
+    socket: &UdpSocket,
+    group: Ipv4Addr,
+    joins: &mut Vec<MulticastJoin>,
+    interfaces: &[Ipv4Addr],
+) -> bool {
+    let mut changed = false;
+
+    for join in joins.iter_mut() {
+        if join.interface.is_unspecified() || !join.present || interfaces.contains(&join.interface)
+        {
+            continue;
+        }
+        join.present = false;
+        changed = true;
+        if join.joined {
+            if let Err(e) = socket.leave_multicast_v4(group, join.interface) {
+                debug!(
+                    "Leaving multicast {} on removed interface {}: {}",
+                    group, join.interface, e
+                );
+            }
+            join.joined = false;
+            join.leaves += 1;
+            join.changed_ms = unix_time_ms();
+        }
+        info!(
+            "Interface {} removed from multicast {}",
+            join.interface, group
+        );
+    }
+
+    for &ip in interfaces {
+        let index = joins.iter().position(|join| join.interface == ip);
+        if index.is_some_and(|index| joins[index].joined && joins[index].present) {
+            continue;
+        }
+        // 기본 인터페이스 가입과 같은 인터페이스면 이미 가입되어 있음
+        let result = match socket.join_multicast_v4(group, ip) {
+            Err(e) if e.kind() == io::ErrorKind::AddrInUse => Ok(()),
+            result => result,
+        };
+        let appeared = index.is_none_or(|index| !joins[index].present);
+        match &result {
+            Ok(()) => info!("Joining multicast {} on new interface: {}", group, ip),
+            Err(e) if appeared => warn!(
+                "Failed to join multicast {} on new interface {}: {}",
+                group, ip, e
+            ),
+            Err(e) => debug!(
+                "Retrying multicast {} on interface {} failed: {}",
+                group, ip, e
+            ),
+        }
+        changed |= result.is_ok() || appeared;
+        match index {
+            Some(index) => {
+                joins[index].present = true;
+                joins[index].record(result);
+            }
+            None => joins.push(MulticastJoin::new(group, ip, result)),
+        }
+    }
+    changed
+}
+
+/// 무시해도 되는 수신 에러인지 확인
+///
+/// # Arguments
+/// * `e` - 수신 시 발생한 IO 에러
+///
+/// # Returns
+/// * `bool` - 무시 가능하면 true
+///
+/// # 동작 설명
+/// * Windows 는 이전에 송신한 목적지에서 ICMP port unreachable 을 받으면
+///   다음 recv_from 에서 WSAECONNRESET(ConnectionReset) 을 반환함
+/// * UDP 에서는 의미 없는 에러이므로 수신 루프를 계속 진행
+pub fn is_transient_recv_error(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::Interrupted
+    )
+}
+
+/// 설정에 따라 소켓 버퍼 크기 및 busy poll 옵션 적용
+///
+/// # Arguments
+/// * `socket` - 옵션을 적용할 소켓
+/// * `config` - UDP 리스너 설정
+///
+/// # Returns
+/// * `io::Result<()>` - 성공 시 Ok(()), 실패 시 IO 에러
+///
+/// # 동작 설명
+/// * SO_RCVBUF / SO_SNDBUF 설정 후 실제 적용된 값을 로깅 (커널 제한으로 요청값과 다를 수 있음)
+/// * SO_BUSY_POLL 은 Linux 에서만 적용, 다른 플랫폼에서는 경고 로그만 출력
+pub fn apply_socket_options(socket: &Socket, config: &UdpListenerConfig) -> io::Result<()> {
+    if let Some(size) = config.recv_buffer_size {
+        socket.set_recv_buffer_size(size)?;
+        info!(
+            "UDP recv buffer size: requested {}, actual {}",
+            size,
+            socket.recv_buffer_size()?
+        );
+    }
+
+    if let Some(size) = config.send_buffer_size {
+        socket.set_send_buffer_size(size)?;
+        info!(
+            "UDP send buffer size: requested {}, actual {}",
+            size,
+            socket.send_buffer_size()?
+        );
+    }
+
+    if let Some(us) = config.busy_poll_us {
+        set_busy_poll(socket, us)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn set_busy_poll(socket: &Socket, us: u32) -> io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let value = us as libc::c_int;
+    // SAFETY: 유효한 소켓 fd 와 c_int 크기의 값 포인터를 전달
+    let ret = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_BUSY_POLL,
+            &value as *const libc::c_int as *const libc::c_void,
+            std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+    if ret < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    info!("UDP busy poll: {} us", us);
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_busy_poll(_socket: &Socket, _us: u32) -> io::Result<()> {
+    warn!("SO_BUSY_POLL is not supported on this platform");
+    Ok(())
+}
+
+/// 현재 스레드를 지정한 CPU 코어에 고정
+///
+/// # Arguments
+/// * `core` - 고정할 CPU 코어 번호
+///
+/// # 동작 설명
+/// * Linux 에서만 sched_setaffinity 로 적용, 다른 플랫폼에서는 경고 로그만 출력
+/// * 실패 시 에러 로그만 출력하고 계속 진행
+#[cfg(target_os = "linux")]
+pub fn pin_current_thread(core: usize) {
+    // SAFETY: cpu_set_t 는 0 으로 초기화 가능한 비트셋이며, 현재 스레드(0)에 적용
+    let ret = unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_SET(core, &mut set);
+        libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set)
+    };
+    if ret < 0 {
+        error!(
+            "Failed to pin UDP receive thread to core {}: {}",
+            core,
+            io::Error::last_os_error()
+        );
+    } else {
+        info!("UDP receive thread pinned to core {}", core);
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn pin_current_thread(core: usize) {
+    warn!(
+        "Thread pinning is not supported on this platform (core {})",
+        core
+    );
+}
+
+/// 여러 데이터그램을 한 번에 수신하기 위한 배치 수신기
+///
+/// # Fields
+/// * `bufs` - 데이터그램별 수신 버퍼
+/// * `received` - 마지막 수신 결과 (수신 크기, 송신자 주소)
+///
+/// # 주요 기능
+/// * Linux: recvmmsg 시스템 콜로 최대 `batch_size` 개의 데이터그램을 한 번에 수신
+/// * 그 외 플랫폼 또는 `batch_size` 가 1 인 경우: recv_from 으로 단건 수신
+///
+/// # Examples
+/// ```
+/// let mut receiver = BatchReceiver::new(32, 65535);
+/// let count = receiver.recv(&socket).await?;
+/// for i in 0..count {
+///     let (data, src_addr) = receiver.datagram(i);
+/// }
+/// ```
+pub struct BatchReceiver {
+    bufs: Vec<Vec<u8>>,
+    received: Vec<(usize, SocketAddr)>,
+}
+
+impl BatchReceiver {
+    pub fn new(batch_size: usize, buf_size: usize) -> Self {
+        let batch_size = batch_size.max(1);
+        Self {
+            bufs: vec![vec![0u8; buf_size]; batch_size],
+            received: Vec::with_capacity(batch_size),
+        }
+    }
+
+    /// 소켓으로부터 데이터그램 수신
+    ///
+    /// # Arguments
+    /// * `socket` - 수신할 UDP 소켓
+    ///
+    /// # Returns
+    /// * `io::Result<usize>` - 성공 시 수신한 데이터그램 수, 실패 시 IO 에러
+    pub async fn recv(&mut self, socket: &UdpSocket) -> io::Result<usize> {
+        self.received.clear();
+
+        #[cfg(target_os = "linux")]
+        if self.bufs.len() > 1 {
+            loop {
+                socket.readable().await?;
+                match socket.try_io(tokio::io::Interest::READABLE, || {
+                    recvmmsg(socket, &mut self.bufs, &mut self.received)
+                }) {
+                    Ok(()) => return Ok(self.received.len()),
+                    Err(e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        let (size, src_addr) = socket.recv_from(&mut self.bufs[0]).await?;
+        self.received.push((size, src_addr));
+        Ok(1)
+    }
+
+    /// 마지막으로 수신한 i 번째 데이터그램 반환
+    ///
+    /// # Arguments
+    /// * `i` - 데이터그램 인덱스 (recv 반환값 미만)
+    ///
+    /// # Returns
+    /// * `(&[u8], SocketAddr)` - 수신 데이터와 송신자 주소
+    pub fn datagram(&self, i: usize) -> (&[u8], SocketAddr) {
+        let (size, src_addr) = self.received[i];
+        (&self.bufs[i][..size], src_addr)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn recvmmsg(
+    socket: &UdpSocket,
+    bufs: &mut [Vec<u8>],
+    received: &mut Vec<(usize, SocketAddr)>,
+) -> io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let count = bufs.len();
+    // SAFETY: sockaddr_storage / mmsghdr 는 0 으로 초기화 가능한 C 구조체
+    let mut addrs: Vec<libc::sockaddr_storage> = vec![unsafe { std::mem::zeroed() }; count];
+    let mut iovecs: Vec<libc::iovec> = bufs
+        .iter_mut()
+        .map(|buf| libc::iovec {
+            iov_base: buf.as_mut_ptr() as *mut libc::c_void,
+            iov_len: buf.len(),
+        })
+        .collect();
+    let mut msgs: Vec<libc::mmsghdr> = Vec::with_capacity(count);
+    for i in 0..count {
+        let mut msg: libc::mmsghdr = unsafe { std::mem::zeroed() };
+        msg.msg_hdr.msg_name = &mut addrs[i] as *mut libc::sockaddr_storage as *mut libc::c_void;
+        msg.msg_hdr.msg_namelen = std::mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+        msg.msg_hdr.msg_iov = &mut iovecs[i];
+        msg.msg_hdr.msg_iovlen = 1;
+        msgs.push(msg);
+    }
+
+    // SAFETY: msgs 의 모든 포인터는 이 함수 안에서 유효한 버퍼를 가리킴
+    let ret = unsafe {
+        libc::recvmmsg(
+            socket.as_raw_fd(),
+            msgs.as_mut_ptr(),
+            count as libc::c_uint,
+            libc::MSG_DONTWAIT,
+            std::ptr::null_mut(),
+        )
+    };
+    if ret < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    for i in 0..ret as usize {
+        if let Some(src_addr) = sockaddr_to_socket_addr(&addrs[i]) {
+            received.push((msgs[i].msg_len as usize, src_addr));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn sockaddr_to_socket_addr(addr: &libc::sockaddr_storage) -> Option<SocketAddr> {
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+    match addr.ss_family as libc::c_int {
+        libc::AF_INET => {
+            // SAFETY: ss_family 가 AF_INET 이면 sockaddr_in 으로 해석 가능
+            let addr =
+                unsafe { &*(addr as *const libc::sockaddr_storage as *const libc::sockaddr_in) };
+            Some(SocketAddr::V4(SocketAddrV4::new(
+                Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr)),
+                u16::from_be(addr.sin_port),
+            )))
+        }
+        libc::AF_INET6 => {
+            // SAFETY: ss_family 가 AF_INET6 이면 sockaddr_in6 으로 해석 가능
+            let addr =
+                unsafe { &*(addr as *const libc::sockaddr_storage as *const libc::sockaddr_in6) };
+            Some(SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::from(addr.sin6_addr.s6_addr),
+                u16::from_be(addr.sin6_port),
+                addr.sin6_flowinfo,
+                addr.sin6_scope_id,
+            )))
+        }
+        _ => None,
+    }
+}
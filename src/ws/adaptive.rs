@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::lidar::LiDARKey;
+use crate::ws::config::AdaptiveConfig;
+use crate::ws::message::QualityData;
+
+/// 지수 이동 평균 가중치
+const LATENCY_ALPHA: f64 = 0.2;
+
+/// 클라이언트별 전송 품질 제어 구조체
+///
+/// # Examples
+/// ```
+/// let mut quality = QualityController::new();
+/// if quality.should_send(&key) {
+///     let started = Instant::now();
+///     sender.send(message).await?;
+///     if let Some(change) = quality.observe(started.elapsed(), &config) {
+///         // quality 이벤트 전송
+///     }
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 포인트 클라우드 전송 시간의 지수 이동 평균으로 전송 지연 추정
+/// * 지연이 `degrade_latency_ms` 를 넘으면 품질 단계를 올려 LiDAR 별로 2^단계 프레임 중 1 프레임만 전송
+/// * 지연이 `recover_latency_ms` 아래로 내려가면 품질 단계를 한 단계씩 복구
+/// * 단계 변경은 `interval_ms` 마다 최대 한 번
+pub struct QualityController {
+    level: u8,
+    latency_ms: f64,
+    last_change: Instant,
+    frames: HashMap<LiDARKey, u64>,
+}
+
+impl Default for QualityController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QualityController {
+    pub fn new() -> Self {
+        Self {
+            level: 0,
+            latency_ms: 0.0,
+            last_change: Instant::now(),
+            frames: HashMap::new(),
+        }
+    }
+
+    /// 현재 품질 단계 (0 이면 모든 프레임 전송)
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// 포인트 클라우드 전송 지연 추정값 (ms)
+    pub fn latency_ms(&self) -> f64 {
+        self.latency_ms
+    }
+
+    /// 전송할 프레임 간격 (2^단계)
+    pub fn frame_interval(&self) -> u64 {
+        1 << self.level
+    }
+
+    /// 해당 LiDAR 의 이번 프레임을 전송할지 여부
+    pub fn should_send(&mut self, key: &LiDARKey) -> bool {
+        let interval = self.frame_interval();
+        let count = self.frames.entry(*key).or_default();
+        let send = count.is_multiple_of(interval);
+        *count = count.wrapping_add(1);
+        send
+    }
+
+    /// 전송 시간을 반영하고 품질 단계가 바뀌면 변경 정보 반환
+    ///
+    /// # Arguments
+    /// * `latency` - 프레임 전송에 걸린 시간
+    /// * `config` - 적응형 전송 설정
+    ///
+    /// # Returns
+    /// * `Option<QualityData>` - 품질 단계가 바뀐 경우 `quality` 이벤트 데이터
+    pub fn observe(&mut self, latency: Duration, config: &AdaptiveConfig) -> Option<QualityData> {
+        let sample = latency.as_secs_f64() * 1000.0;
+        self.latency_ms = self.latency_ms * (1.0 - LATENCY_ALPHA) + sample * LATENCY_ALPHA;
+
+        if !config.enabled || self.last_change.elapsed() < Duration::from_millis(config.interval_ms)
+        {
+            return None;
+        }
+
+        if self.latency_ms > config.degrade_latency_ms as f64 && self.level < config.max_level {
+            self.level += 1;
+        } else if self.latency_ms < config.recover_latency_ms as f64 && self.level > 0 {
+            self.level -= 1;
+        } else {
+            return None;
+        }
+        self.last_change = Instant::now();
+
+        Some(QualityData {
+            level: self.level,
+            frame_interval: self.frame_interval(),
+            latency_ms: self.latency_ms,
+            degraded: self.level > 0,
+        })
+    }
+}
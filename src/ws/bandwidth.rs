@@ -0,0 +1,137 @@
+use std::time::{Duration, Instant};
+
+/// 전송량 측정 구간
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// 클라이언트별 전송 대역폭 제어 구조체
+///
+/// # Examples
+/// ```
+/// let mut bandwidth = BandwidthController::new();
+/// bandwidth.set_cap(Some(1_000_000), config.max_level);
+/// if bandwidth.should_send() {
+///     let points = subscription.prepare_points(points, deskew, motion, bandwidth.decimation());
+///     sender.send(message).await?;
+///     bandwidth.record(size);
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 클라이언트에게 보낸 모든 메시지 크기를 1초 구간마다 집계하여 전송량(bytes/s) 계산
+/// * 구간 전송량이 제한을 넘으면 단계를 올려 포인트 클라우드를 2^단계 개 포인트 중 1 개만 전송,
+///   제한의 절반 아래로 내려가면 단계를 한 단계씩 복구 (구간마다 최대 한 번)
+/// * 현재 구간에서 이미 제한만큼 보냈으면 다음 구간까지 포인트 클라우드 프레임을 버림
+pub struct BandwidthController {
+    cap: Option<u64>,
+    max_level: u8,
+    level: u8,
+    window_start: Instant,
+    window_bytes: u64,
+    bytes_per_sec: f64,
+    bytes_sent: u64,
+    frames_dropped: u64,
+}
+
+impl Default for BandwidthController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BandwidthController {
+    pub fn new() -> Self {
+        Self {
+            cap: None,
+            max_level: 0,
+            level: 0,
+            window_start: Instant::now(),
+            window_bytes: 0,
+            bytes_per_sec: 0.0,
+            bytes_sent: 0,
+            frames_dropped: 0,
+        }
+    }
+
+    /// 전송 대역폭 제한 설정 (단계 초기화)
+    ///
+    /// # Arguments
+    /// * `cap` - 최대 전송량 (bytes/s), None 이면 제한 없음
+    /// * `max_level` - 최대 솎아내기 단계
+    pub fn set_cap(&mut self, cap: Option<u64>, max_level: u8) {
+        self.cap = cap;
+        self.max_level = max_level;
+        self.level = 0;
+    }
+
+    /// 최대 전송량 (bytes/s)
+    pub fn cap(&self) -> Option<u64> {
+        self.cap
+    }
+
+    /// 현재 솎아내기 단계 (0 이면 모든 포인트 전송)
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// 마지막 구간의 전송량 (bytes/s)
+    pub fn bytes_per_sec(&self) -> f64 {
+        self.bytes_per_sec
+    }
+
+    /// 전체 전송량 (bytes)
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent
+    }
+
+    /// 대역폭 제한 또는 데이터 대기열이 가득 차서 버린 데이터 프레임 수
+    pub fn frames_dropped(&self) -> u64 {
+        self.frames_dropped
+    }
+
+    /// 포인트 클라우드에 추가로 적용할 솎아내기 간격 (2^단계)
+    pub fn decimation(&self) -> usize {
+        1 << self.level
+    }
+
+    /// 보낸 메시지 크기 기록
+    pub fn record(&mut self, bytes: usize) {
+        self.roll();
+        self.window_bytes += bytes as u64;
+        self.bytes_sent += bytes as u64;
+    }
+
+    /// 데이터 대기열이 가득 차서 버린 프레임 기록
+    pub fn record_drop(&mut self) {
+        self.frames_dropped += 1;
+    }
+
+    /// 이번 포인트 클라우드 프레임을 전송할지 여부 (현재 구간에서 제한만큼 보냈으면 false)
+    pub fn should_send(&mut self) -> bool {
+        self.roll();
+        if self.cap.is_some_and(|cap| self.window_bytes >= cap) {
+            self.frames_dropped += 1;
+            return false;
+        }
+        true
+    }
+
+    /// 구간이 지났으면 전송량을 계산하고 단계 조정
+    fn roll(&mut self) {
+        let elapsed = self.window_start.elapsed();
+        if elapsed < WINDOW {
+            return;
+        }
+        self.bytes_per_sec = self.window_bytes as f64 / elapsed.as_secs_f64();
+        self.window_start = Instant::now();
+        self.window_bytes = 0;
+
+        let Some(cap) = self.cap else {
+            return;
+        };
+        if self.bytes_per_sec > cap as f64 && self.level < self.max_level {
+            self.level += 1;
+        } else if self.bytes_per_sec < cap as f64 / 2.0 && self.level > 0 {
+            self.level -= 1;
+        }
+    }
+}
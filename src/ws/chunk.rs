@@ -0,0 +1,190 @@
+use axum::extract::ws::Message;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::ws::protocol::{Envelope, EnvelopeKind};
+
+/// 나눠 보낸 응답 조각 내용 구조체
+///
+/// # Examples
+/// ```json
+/// { "type": "chunk", "id": 7, "command": "teaching", "chunk": { "part": 1, "parts": 3, "size": 150000, "base64": false, "data": "{\"type\":\"response\",..." } }
+/// ```
+///
+/// # Fields
+/// * `part` - 조각 번호 (1 부터 시작)
+/// * `parts` - 전체 조각 수
+/// * `size` - 원래 응답 메시지 크기 (bytes)
+/// * `base64` - 원래 응답이 binary 메시지(CBOR/MessagePack/Protobuf)이면 true, `data` 는 base64 로 인코딩한 조각
+/// * `data` - 원래 응답 메시지 조각
+///
+/// # 동작 설명
+/// * 조각은 한 응답의 것끼리 순서대로 이어서 전송되며, 다른 메시지가 사이에 끼지 않음
+/// * 클라이언트는 `part` 1 부터 `parts` 까지의 `data` 를 이어 붙이고(`base64` 이면 각각 디코딩 후),
+///   길이가 `size` 와 같은지 확인한 뒤 평소와 같은 응답 메시지로 해석
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ChunkBody {
+    pub part: usize,
+    pub parts: usize,
+    pub size: usize,
+    pub base64: bool,
+    pub data: String,
+}
+
+/// 크기 제한을 넘는 응답 메시지를 조각 메시지로 나누기
+///
+/// # Examples
+/// ```
+/// for message in split(message, 256 * 1024, reply.id.as_ref(), &reply.command) {
+///     client.send(message).await?;
+/// }
+/// ```
+///
+/// # Arguments
+/// * `message` - 전송할 응답 메시지
+/// * `chunk_size` - 조각 하나에 담을 원래 메시지 최대 크기 (bytes)
+/// * `id` - 요청 ID
+/// * `command` - 요청 명령
+///
+/// # Returns
+/// * `Vec<Message>` - 크기 제한 이하이면 원래 메시지 하나, 넘으면 JSON 텍스트 조각 메시지 목록
+pub fn split(
+    message: Message,
+    chunk_size: usize,
+    id: Option<&Value>,
+    command: &str,
+) -> Vec<Message> {
+    let chunk_size = chunk_size.max(1);
+    let (pieces, size, base64) = match &message {
+        Message::Text(text) if text.len() > chunk_size => {
+            (split_text(text.as_str(), chunk_size), text.len(), false)
+        }
+        Message::Binary(data) if data.len() > chunk_size => (
+            data.chunks(chunk_size)
+                .map(|piece| STANDARD.encode(piece))
+                .collect(),
+            data.len(),
+            true,
+        ),
+        _ => return vec![message],
+    };
+
+    let parts = pieces.len();
+    pieces
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, data)| {
+            let envelope = Envelope {
+                kind: EnvelopeKind::Chunk,
+                id: id.cloned(),
+                command: command.to_string(),
+                data: None,
+                error: None,
+                chunk: Some(ChunkBody {
+                    part: index + 1,
+                    parts,
+                    size,
+                    base64,
+                    data,
+                }),
+            };
+            serde_json::to_string(&envelope)
+                .ok()
+                .map(|json| Message::Text(json.into()))
+        })
+        .collect()
+}
+
+/// 문자열을 UTF-8 문자 경계에서 최대 `chunk_size` bytes 씩 나누기
+fn split_text(text: &str, chunk_size: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut end = chunk_size.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        // 문자 하나가 조각 크기보다 크면 문자 단위로 자름
+        if end == 0 {
+            end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+        }
+        let (piece, remainder) = rest.split_at(end);
+        pieces.push(piece.to_string());
+        rest = remainder;
+    }
+    pieces
+}
+
+/// 조각 메시지를 원래 응답으로 다시 합치는 구조체 (릴레이, Rust 클라이언트에서 사용)
+///
+/// # Examples
+/// ```
+/// if let Some(chunk) = &envelope.chunk {
+///     match assembler.push(chunk)? {
+///         Some(message) => handle(message),
+///         None => continue,
+///     }
+/// }
+/// ```
+///
+/// # Fields
+/// * `buffer` - 지금까지 받은 원래 메시지 데이터
+/// * `next_part` - 다음에 받아야 할 조각 번호
+#[derive(Debug, Default)]
+pub struct ChunkAssembler {
+    buffer: Vec<u8>,
+    next_part: usize,
+}
+
+impl ChunkAssembler {
+    /// 조각 추가
+    ///
+    /// # Arguments
+    /// * `chunk` - 받은 조각
+    ///
+    /// # Returns
+    /// * `Result<Option<Vec<u8>>, String>` - 마지막 조각이면 원래 메시지 데이터, 아직 남았으면 None,
+    ///   순서가 어긋나거나 크기가 맞지 않으면 에러 메시지 (받던 데이터는 버림)
+    pub fn push(&mut self, chunk: &ChunkBody) -> Result<Option<Vec<u8>>, String> {
+        if chunk.part == 1 {
+            self.buffer.clear();
+            self.next_part = 1;
+        }
+        if chunk.part == 0 || chunk.part != self.next_part || chunk.part > chunk.parts {
+            self.reset();
+            return Err(format!("Unexpected chunk {}/{}", chunk.part, chunk.parts));
+        }
+        if chunk.base64 {
+            let data = STANDARD.decode(&chunk.data).map_err(|e| {
+                self.reset();
+                format!("Invalid chunk data: {}", e)
+            })?;
+            self.buffer.extend_from_slice(&data);
+        } else {
+            self.buffer.extend_from_slice(chunk.data.as_bytes());
+        }
+        if chunk.part < chunk.parts {
+            self.next_part += 1;
+            return Ok(None);
+        }
+
+        let message = std::mem::take(&mut self.buffer);
+        self.reset();
+        if message.len() != chunk.size {
+            return Err(format!(
+                "Chunked message size mismatch: expected {} bytes, received {} bytes",
+                chunk.size,
+                message.len()
+            ));
+        }
+        Ok(Some(message))
+    }
+
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.next_part = 0;
+    }
+}
@@ -0,0 +1,650 @@
+use axum::extract::ws::{Message, WebSocket};
+use futures::stream::SplitSink;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::common::impairment::Impairer;
+use crate::common::time::unix_time_ms;
+use crate::lidar::{LiDARKey, Point, PointCloud};
+use crate::processing::{DeskewConfig, FrameAccumulator, FrameMotion};
+use crate::site::SiteConfig;
+use crate::ws::adaptive::QualityController;
+use crate::ws::bandwidth::BandwidthController;
+use crate::ws::config::StreamPreset;
+use crate::ws::convention::OutputConvention;
+use crate::ws::encoding::{WireEncoding, WireFormat, MAX_PRECISION};
+use crate::ws::framing::BinaryFraming;
+use crate::ws::lanes::OutboundLanes;
+use crate::ws::protocol::ProtocolVersion;
+
+/// LiDAR 데이터 구독 설정 구조체
+///
+/// # Examples
+/// ```json
+/// { "command": "subscribe", "data": { "lidars": [ { "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 } ] } }
+/// { "command": "subscribe", "data": { "roi": { "type": "box", "min": [0.0, -1.5], "max": [20.0, 1.5] } } }
+/// { "command": "subscribe", "data": { "channels": [1] } }
+/// { "command": "subscribe", "data": { "stream": "statistics" } }
+/// { "command": "subscribe", "data": { "stream": "occupancy" } }
+/// { "command": "subscribe", "data": { "stream": "objects" } }
+/// { "command": "subscribe", "data": { "sites": ["building-a"], "stream": "occupancy" } }
+/// { "command": "subscribe", "data": { "encoding": "cbor" } }
+/// { "command": "subscribe", "data": { "convention": { "axes": ["y", "-x", "z"], "unit": "cm" } } }
+/// { "command": "subscribe", "data": { "timing": true, "deskew": true } }
+/// { "command": "subscribe", "data": { "frame": "world" } }
+/// { "command": "subscribe", "data": { "max_rate_hz": 2.0, "decimation": 8 } }
+/// { "command": "subscribe", "data": { "preset": "thumbnail", "lidars": [ ... ] } }
+/// { "command": "subscribe", "data": { "role": "dashboard", "max_bytes_per_sec": 1000000 } }
+/// { "command": "subscribe", "data": { "encoding": "cbor", "framing": "crc32" } }
+/// { "command": "subscribe", "data": { "precision": 3 } }
+/// ```
+///
+/// # Fields
+/// * `lidars` - 구독할 LiDAR 키 목록, None 이면 모든 LiDAR 구독
+/// * `sites` - 구독할 사이트 이름 목록, 사이트에 속한 LiDAR 를 `lidars` 에 추가
+/// * `roi` - 관심 영역, 설정하면 영역 안의 포인트만 전송
+/// * `channels` - 구독할 채널 번호 목록, None 이면 모든 채널 구독
+/// * `stream` - 전송할 데이터 종류 (기본값: 포인트 클라우드)
+/// * `encoding` - 응답/이벤트 전송 형식 (`json`, `cbor`, `msgpack`, `protobuf`, 기본값: `json`), 구독 응답부터 적용
+/// * `precision` - JSON 메시지의 실수(좌표 등)를 반올림할 소수점 아래 자릿수 (0 ~ 9), None 이면 전체 자릿수, 구독 응답부터 적용
+/// * `convention` - 포인트 클라우드 출력 좌표계(축 변환, 길이 단위), None 이면 LiDAR 좌표(m) 그대로 전송
+/// * `timing` - 포인트 클라우드에 포인트별 측정 시각(스캔 시작 기준 µs) 포함 여부
+/// * `deskew` - 자기 속도로 움직임 왜곡을 보정한 포인트 클라우드 수신 여부
+/// * `frame` - 포인트 클라우드 기준 좌표계 (`lidar`, `world`, 기본값: `lidar`)
+/// * `max_rate_hz` - LiDAR 별 포인트 클라우드 최대 전송 주기 (Hz), None 이면 제한 없음
+/// * `decimation` - 채널별로 N 개 포인트 중 1 개만 전송 (1 이상), None 이면 모든 포인트 전송
+/// * `preset` - 서버 설정(`ws.presets`)의 구독 설정 이름, 함께 보낸 항목은 구독 설정보다 우선
+/// * `role` - 서버 설정(`ws.bandwidth.roles`)의 전송 대역폭 제한 이름
+/// * `max_bytes_per_sec` - 클라이언트가 요청하는 최대 전송량 (bytes/s), 서버 제한보다 크면 서버 제한 적용
+/// * `framing` - binary 메시지 무결성 봉투 (`none`, `crc32`, 기본값: `none`), 구독 응답부터 적용
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Subscription {
+    #[serde(default)]
+    pub lidars: Option<Vec<LiDARKey>>,
+    #[serde(default)]
+    pub sites: Option<Vec<String>>,
+    #[serde(default)]
+    pub roi: Option<Roi>,
+    #[serde(default)]
+    pub channels: Option<Vec<u8>>,
+    #[serde(default)]
+    pub stream: StreamType,
+    #[serde(default)]
+    pub encoding: WireEncoding,
+    #[serde(default)]
+    pub precision: Option<u8>,
+    #[serde(default)]
+    pub convention: Option<OutputConvention>,
+    #[serde(default)]
+    pub timing: bool,
+    #[serde(default)]
+    pub deskew: bool,
+    #[serde(default)]
+    pub frame: OutputFrame,
+    #[serde(default)]
+    pub max_rate_hz: Option<f32>,
+    #[serde(default)]
+    pub decimation: Option<u32>,
+    #[serde(default)]
+    pub preset: Option<String>,
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub max_bytes_per_sec: Option<u64>,
+    #[serde(default)]
+    pub framing: BinaryFraming,
+}
+
+/// 구독 설정에 맞게 필터링/변환한 포인트 클라우드
+///
+/// # Fields
+/// * `points` - 채널별 포인트 클라우드
+/// * `offsets_us` - `timing` 구독 시 `points` 와 같은 순서의 포인트별 측정 시각 (스캔 시작 기준, µs)
+#[derive(Debug, Clone)]
+pub struct FilteredPoints {
+    pub points: Vec<PointCloud>,
+    pub offsets_us: Option<Vec<Vec<u32>>>,
+}
+
+/// 포인트 클라우드 기준 좌표계 열거형
+///
+/// # Variants
+/// * `Lidar` - LiDAR 좌표 (`lidar`)
+/// * `World` - odometry 자세로 변환한 월드 좌표 (`world`), 포인트별 측정 시각의 자세를 사용하므로
+///   움직임 왜곡도 함께 보정되며 프레임 시각의 자세가 없으면 포인트 클라우드를 전송하지 않음
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFrame {
+    #[default]
+    Lidar,
+    World,
+}
+
+/// 구독 데이터 종류 열거형
+///
+/// # Variants
+/// * `Points` - 포인트 클라우드 (`point_cloud`)
+/// * `Statistics` - 낮은 주기의 프레임 통계 (`statistics`), 포인트 클라우드는 전송하지 않음
+/// * `Occupancy` - 2D 점유 격자 (`occupancy_grid`), 포인트 클라우드는 전송하지 않음
+/// * `Objects` - 추적 중인 물체의 위치, 속력, 진행 방향 (`objects`), 포인트 클라우드는 전송하지 않음
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamType {
+    #[default]
+    Points,
+    Statistics,
+    Occupancy,
+    Objects,
+}
+
+impl Subscription {
+    /// 해당 LiDAR 데이터를 구독 중인지 확인
+    pub fn matches(&self, key: &LiDARKey) -> bool {
+        match &self.lidars {
+            Some(lidars) => lidars.contains(key),
+            None => true,
+        }
+    }
+
+    /// 구독 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 실패 시 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        self.encoding.validate()?;
+        if self
+            .precision
+            .is_some_and(|precision| precision > MAX_PRECISION)
+        {
+            return Err(format!("precision must be at most {}", MAX_PRECISION));
+        }
+        if let Some(convention) = &self.convention {
+            convention.validate()?;
+        }
+        if self
+            .max_rate_hz
+            .is_some_and(|rate_hz| !rate_hz.is_finite() || rate_hz <= 0.0)
+        {
+            return Err("max_rate_hz must be positive".to_string());
+        }
+        if self.decimation == Some(0) {
+            return Err("decimation must be at least 1".to_string());
+        }
+        if self.max_bytes_per_sec == Some(0) {
+            return Err("max_bytes_per_sec must be positive".to_string());
+        }
+        match &self.roi {
+            Some(roi) => roi.validate(),
+            None => Ok(()),
+        }
+    }
+
+    /// 이름 있는 구독 설정(`preset`)을 적용한 구독 설정
+    ///
+    /// # Arguments
+    /// * `presets` - 서버 설정의 구독 설정 목록 (`ws.presets`)
+    /// * `data` - 클라이언트가 보낸 구독 데이터, 여기에 있는 항목은 구독 설정보다 우선
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 합친 구독 설정 (`preset` 이 없으면 그대로), 알 수 없는 이름이면 에러 메시지
+    pub fn resolve_preset(self, presets: &[StreamPreset], data: &Value) -> Result<Self, String> {
+        let Some(name) = &self.preset else {
+            return Ok(self);
+        };
+        let preset = presets
+            .iter()
+            .find(|preset| preset.name == *name)
+            .ok_or_else(|| format!("Unknown preset: {}", name))?;
+
+        let mut merged = serde_json::to_value(&preset.subscription).map_err(|e| e.to_string())?;
+        if let (Value::Object(merged), Value::Object(data)) = (&mut merged, data) {
+            for (field, value) in data {
+                merged.insert(field.clone(), value.clone());
+            }
+        }
+        let subscription: Self = serde_json::from_value(merged).map_err(|e| e.to_string())?;
+        subscription.validate()?;
+        Ok(subscription)
+    }
+
+    /// 구독할 사이트의 LiDAR 를 구독 LiDAR 목록에 추가
+    ///
+    /// # Arguments
+    /// * `sites` - 사이트 설정
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 알 수 없는 사이트이면 에러 메시지
+    pub fn resolve_sites(&mut self, sites: &SiteConfig) -> Result<(), String> {
+        let Some(names) = &self.sites else {
+            return Ok(());
+        };
+        let lidars = self.lidars.get_or_insert_with(Vec::new);
+        for key in sites.members(names)? {
+            if !lidars.contains(&key) {
+                lidars.push(key);
+            }
+        }
+        Ok(())
+    }
+
+    /// 구독 중인 채널의 포인트가 있는지 확인
+    ///
+    /// 채널별로 수신되는 데이터 중 구독하지 않은 채널의 데이터는 전송하지 않기 위해 사용
+    pub fn matches_channels(&self, points: &[PointCloud]) -> bool {
+        match &self.channels {
+            Some(_) => points
+                .iter()
+                .enumerate()
+                .any(|(ch, cloud)| self.wants_channel(ch) && !cloud.points.is_empty()),
+            None => true,
+        }
+    }
+
+    /// 해당 채널을 구독 중인지 확인
+    fn wants_channel(&self, ch: usize) -> bool {
+        match &self.channels {
+            Some(channels) => channels.iter().any(|&channel| channel as usize == ch),
+            None => true,
+        }
+    }
+
+    /// 구독 설정에 맞게 포인트 클라우드 필터링 (채널, 관심 영역)
+    ///
+    /// 구독하지 않은 채널은 채널 번호가 유지되도록 빈 포인트 클라우드로 바꿉니다.
+    /// 프레임 통계/점유 격자 스트림에서 사용하며, 포인트는 LiDAR 좌표 그대로 유지합니다.
+    ///
+    /// # Arguments
+    /// * `points` - 채널별 포인트 클라우드
+    ///
+    /// # Returns
+    /// * `Option<Vec<PointCloud>>` - 필터링한 포인트 클라우드, 필터링이 필요 없으면 None
+    pub fn filter_points(&self, points: &[PointCloud]) -> Option<Vec<PointCloud>> {
+        if self.roi.is_none() && self.channels.is_none() {
+            return None;
+        }
+        Some(
+            points
+                .iter()
+                .enumerate()
+                .map(|(ch, cloud)| PointCloud {
+                    points: if self.wants_channel(ch) {
+                        cloud
+                            .points
+                            .iter()
+                            .filter(|point| self.roi.as_ref().is_none_or(|roi| roi.contains(point)))
+                            .cloned()
+                            .collect()
+                    } else {
+                        Vec::new()
+                    },
+                })
+                .collect(),
+        )
+    }
+
+    /// 구독 설정에 맞게 전송할 포인트 클라우드 준비
+    ///
+    /// 구독하지 않은 채널은 채널 번호가 유지되도록 빈 포인트 클라우드로 바꾸고,
+    /// 포인트 솎아내기, 움직임 왜곡 보정, 관심 영역 필터, 월드 좌표 변환, 출력 좌표계 변환 순서로 적용합니다.
+    ///
+    /// # Arguments
+    /// * `points` - 채널별 포인트 클라우드
+    /// * `deskew` - 포인트별 측정 시각 추정 설정
+    /// * `motion` - 프레임의 자기 속도와 스캔 시작/끝 자세
+    /// * `thinning` - 대역폭 제한으로 `decimation` 에 추가로 곱할 솎아내기 간격 (1 이면 추가 없음)
+    ///
+    /// # Returns
+    /// * `Option<FilteredPoints>` - 필터링/변환한 포인트 클라우드, 필요 없으면 None
+    ///
+    /// # 동작 설명
+    /// * 월드 좌표(`frame: "world"`)는 포인트별 측정 시각의 자세로 변환하므로 속도 기반 보정(`deskew`)은 적용하지 않음
+    /// * 월드 좌표인데 자세가 없으면 LiDAR 좌표 그대로 사용 (호출하는 쪽에서 전송하지 않도록 확인)
+    pub fn prepare_points(
+        &self,
+        points: &[PointCloud],
+        deskew: &DeskewConfig,
+        motion: &FrameMotion,
+        thinning: usize,
+    ) -> Option<FilteredPoints> {
+        if self.roi.is_none()
+            && self.channels.is_none()
+            && self.convention.is_none()
+            && !self.timing
+            && !self.deskew
+            && self.frame == OutputFrame::Lidar
+            && self.decimation.unwrap_or(1) <= 1
+            && thinning <= 1
+        {
+            return None;
+        }
+
+        let world = self.frame == OutputFrame::World;
+        let decimation =
+            (self.decimation.unwrap_or(1).max(1) as usize).saturating_mul(thinning.max(1));
+        let mut clouds = Vec::with_capacity(points.len());
+        let mut offsets = Vec::with_capacity(points.len());
+        for (ch, cloud) in points.iter().enumerate() {
+            let mut filtered = PointCloud { points: Vec::new() };
+            let mut channel_offsets = Vec::new();
+            if self.wants_channel(ch) {
+                let count = cloud.points.len();
+                for (index, point) in cloud.points.iter().enumerate().step_by(decimation) {
+                    let point = if self.deskew && !world {
+                        deskew.deskew(point, index, count, &motion.velocity)
+                    } else {
+                        point.clone()
+                    };
+                    if !self.roi.as_ref().is_none_or(|roi| roi.contains(&point)) {
+                        continue;
+                    }
+                    let point = match world
+                        .then(|| motion.pose(index as f32 / count as f32))
+                        .flatten()
+                    {
+                        Some(pose) => pose.apply(&point),
+                        None => point,
+                    };
+                    filtered.points.push(match &self.convention {
+                        Some(convention) => convention.apply(&point),
+                        None => point,
+                    });
+                    if self.timing {
+                        channel_offsets.push(deskew.offset_us(index, count));
+                    }
+                }
+            }
+            clouds.push(filtered);
+            offsets.push(channel_offsets);
+        }
+
+        Some(FilteredPoints {
+            points: clouds,
+            offsets_us: self.timing.then_some(offsets),
+        })
+    }
+}
+
+/// 관심 영역 (ROI) 열거형
+///
+/// 좌표는 포인트 클라우드와 같은 x, y 평면 좌표 (m) 입니다.
+///
+/// # Variants
+/// * `Box` - `min` ~ `max` 사이의 사각형 영역 (`[x, y]`)
+/// * `Polygon` - 꼭짓점 목록 (`[x, y]`, 3 개 이상) 으로 이루어진 다각형 영역
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Roi {
+    Box { min: [f32; 2], max: [f32; 2] },
+    Polygon { points: Vec<[f32; 2]> },
+}
+
+impl Roi {
+    /// 관심 영역 유효성 검사
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Roi::Box { min, max } => {
+                if !min.iter().chain(max.iter()).all(|v| v.is_finite()) {
+                    return Err("ROI box coordinates must be finite".to_string());
+                }
+                if min[0] > max[0] || min[1] > max[1] {
+                    return Err("ROI box min must not exceed max".to_string());
+                }
+            }
+            Roi::Polygon { points } => {
+                if points.len() < 3 {
+                    return Err("ROI polygon needs at least 3 points".to_string());
+                }
+                if !points.iter().flatten().all(|v| v.is_finite()) {
+                    return Err("ROI polygon coordinates must be finite".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 포인트가 관심 영역 안에 있는지 확인 (z 는 무시)
+    pub fn contains(&self, point: &Point) -> bool {
+        let (x, y) = (point.x, point.y);
+        match self {
+            Roi::Box { min, max } => x >= min[0] && x <= max[0] && y >= min[1] && y <= max[1],
+            Roi::Polygon { points } => {
+                // ray casting: x 축 방향 반직선과 변의 교차 횟수가 홀수이면 내부
+                let mut inside = false;
+                let mut j = points.len() - 1;
+                for i in 0..points.len() {
+                    let ([xi, yi], [xj, yj]) = (points[i], points[j]);
+                    if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+                        inside = !inside;
+                    }
+                    j = i;
+                }
+                inside
+            }
+        }
+    }
+}
+
+/// 연결된 WebSocket 클라이언트 상태 구조체
+///
+/// # Fields
+/// * `lanes` - 클라이언트로 메시지를 보내는 제어/데이터 전송 대기열
+/// * `protocol_version` - hello 로 협상된 프로토콜 버전 (기본값: v1)
+/// * `subscription` - LiDAR 데이터 구독 설정, None 이면 LiDAR 데이터를 보내지 않음
+/// * `quality` - 전송 지연에 따른 포인트 클라우드 전송 품질
+/// * `bandwidth` - 전송량 집계 및 대역폭 제한
+/// * `last_statistics` - LiDAR 별 마지막 통계 전송 시각
+/// * `last_frame` - LiDAR 별 마지막 포인트 클라우드 전송 시각 (`max_rate_hz` 구독 시)
+/// * `frames` - 점유 격자 생성을 위한 LiDAR 채널별 최신 포인트 클라우드
+/// * `last_occupancy` - LiDAR 별 (합친 격자는 None) 마지막 점유 격자 전송 시각
+/// * `remote_addr` - 클라이언트 주소
+/// * `connected_ms` - 연결 시각 (Unix time, ms)
+/// * `control` - 제어 전용 클라이언트 여부 (`control` 하위 프로토콜 또는 `/ws/control`), 데이터 프레임을 보내지 않음
+/// * `session` - `hello.session` 으로 받은 세션 토큰, 있으면 구독 변경을 세션 저장소에 저장
+/// * `_disconnect` - 연결 종료 신호, 상태가 제거되면 수신 태스크가 종료됨
+pub struct ClientState {
+    pub lanes: OutboundLanes,
+    pub protocol_version: ProtocolVersion,
+    pub subscription: Option<Subscription>,
+    pub quality: QualityController,
+    pub bandwidth: BandwidthController,
+    pub last_statistics: HashMap<LiDARKey, Instant>,
+    pub last_frame: HashMap<LiDARKey, Instant>,
+    pub frames: FrameAccumulator,
+    pub last_occupancy: HashMap<Option<LiDARKey>, Instant>,
+    pub remote_addr: SocketAddr,
+    pub connected_ms: u64,
+    pub control: bool,
+    pub session: Option<String>,
+    _disconnect: oneshot::Sender<()>,
+}
+
+impl ClientState {
+    pub fn new(
+        sender: SplitSink<WebSocket, Message>,
+        remote_addr: SocketAddr,
+        disconnect: oneshot::Sender<()>,
+        impairment: Option<Arc<Impairer>>,
+        control: bool,
+    ) -> Self {
+        Self {
+            lanes: OutboundLanes::spawn(sender, impairment),
+            protocol_version: ProtocolVersion::default(),
+            subscription: None,
+            quality: QualityController::new(),
+            bandwidth: BandwidthController::new(),
+            last_statistics: HashMap::new(),
+            last_frame: HashMap::new(),
+            frames: FrameAccumulator::default(),
+            last_occupancy: HashMap::new(),
+            remote_addr,
+            connected_ms: unix_time_ms(),
+            control,
+            session: None,
+            _disconnect: disconnect,
+        }
+    }
+
+    /// 응답, 에러, 이벤트 등 제어 메시지를 제어 대기열로 전송 (전송량 집계)
+    ///
+    /// 구독의 `framing` 이 `crc32` 이면 binary 메시지를 CRC32 봉투로 감싸서 전송
+    pub fn send(&mut self, message: Message) -> Result<(), String> {
+        let (message, size) = self.frame(message);
+        self.lanes.send_control(message)?;
+        self.bandwidth.record(size);
+        Ok(())
+    }
+
+    /// 포인트 클라우드, 통계, 점유 격자 등 데이터 메시지를 데이터 대기열로 전송 (전송량 집계)
+    ///
+    /// # Returns
+    /// * `Result<bool, String>` - 대기열에 추가하면 true, 대기열이 가득 차서 버렸으면 false (버린 프레임 수 집계),
+    ///   연결이 끊겼으면 에러 메시지
+    pub fn send_data(&mut self, message: Message) -> Result<bool, String> {
+        let (message, size) = self.frame(message);
+        if !self.lanes.send_data(message)? {
+            self.bandwidth.record_drop();
+            return Ok(false);
+        }
+        self.bandwidth.record(size);
+        Ok(true)
+    }
+
+    /// 구독의 `framing` 을 적용한 메시지와 크기
+    fn frame(&self, message: Message) -> (Message, usize) {
+        let message = match message {
+            Message::Binary(data) => match self.framing().wrap(&data) {
+                Some(frame) => Message::Binary(frame.into()),
+                None => Message::Binary(data),
+            },
+            message => message,
+        };
+        let size = match &message {
+            Message::Text(text) => text.len(),
+            Message::Binary(data) | Message::Ping(data) | Message::Pong(data) => data.len(),
+            Message::Close(_) => 0,
+        };
+        (message, size)
+    }
+
+    /// 구독의 최대 전송 주기(`max_rate_hz`)에 따라 해당 LiDAR 의 이번 포인트 클라우드를 전송할지 여부
+    pub fn within_rate(&mut self, key: &LiDARKey) -> bool {
+        let Some(rate_hz) = self
+            .subscription
+            .as_ref()
+            .and_then(|subscription| subscription.max_rate_hz)
+        else {
+            return true;
+        };
+        let interval = Duration::try_from_secs_f32(1.0 / rate_hz).unwrap_or(Duration::MAX);
+        if self
+            .last_frame
+            .get(key)
+            .is_some_and(|last| last.elapsed() < interval)
+        {
+            return false;
+        }
+        self.last_frame.insert(*key, Instant::now());
+        true
+    }
+
+    /// binary 메시지 무결성 봉투 (구독하지 않았으면 봉투 없음)
+    pub fn framing(&self) -> BinaryFraming {
+        self.subscription
+            .as_ref()
+            .map(|subscription| subscription.framing)
+            .unwrap_or_default()
+    }
+
+    /// 응답/이벤트 전송 형식 (구독하지 않았으면 JSON)
+    pub fn encoding(&self) -> WireEncoding {
+        self.subscription
+            .as_ref()
+            .map(|subscription| subscription.encoding)
+            .unwrap_or_default()
+    }
+
+    /// 응답/이벤트 전송 형식과 JSON 숫자 자릿수 (구독하지 않았으면 전체 자릿수 JSON)
+    pub fn format(&self) -> WireFormat {
+        self.subscription
+            .as_ref()
+            .map(|subscription| WireFormat {
+                encoding: subscription.encoding,
+                precision: subscription.precision,
+            })
+            .unwrap_or_default()
+    }
+
+    /// 관리용 클라이언트 정보
+    pub fn info(&self, id: Uuid) -> ClientInfo {
+        ClientInfo {
+            id,
+            remote_addr: self.remote_addr.to_string(),
+            protocol_version: self.protocol_version.as_u8(),
+            connected_ms: self.connected_ms,
+            control: self.control,
+            subscription: self.subscription.clone(),
+            quality_level: self.quality.level(),
+            send_latency_ms: self.quality.latency_ms(),
+            messages_sent: self.lanes.messages_sent(),
+            send_errors: self.lanes.send_errors(),
+            data_queued: self.lanes.data_queued(),
+            bytes_sent: self.bandwidth.bytes_sent(),
+            bytes_per_sec: self.bandwidth.bytes_per_sec(),
+            max_bytes_per_sec: self.bandwidth.cap(),
+            bandwidth_level: self.bandwidth.level(),
+            frames_dropped: self.bandwidth.frames_dropped(),
+        }
+    }
+}
+
+/// 관리용 클라이언트 정보 구조체
+///
+/// 메시지는 클라이언트별 제어/데이터 대기열을 거쳐 전송되며, 밀린 정도는 데이터 대기열 길이(`data_queued`),
+/// 전송 지연(`send_latency_ms`)과 적응형 전송 품질 단계(`quality_level`)로 확인합니다.
+///
+/// # Fields
+/// * `id` - 클라이언트 UUID
+/// * `remote_addr` - 클라이언트 주소
+/// * `protocol_version` - 협상된 프로토콜 버전
+/// * `connected_ms` - 연결 시각 (Unix time, ms)
+/// * `control` - 제어 전용 클라이언트 여부 (포인트 클라우드를 받지 않음)
+/// * `subscription` - 구독 설정, 구독하지 않았으면 null
+/// * `quality_level` - 적응형 전송 품질 단계 (0 이면 모든 프레임 전송)
+/// * `send_latency_ms` - 포인트 클라우드 전송 지연 추정값 (ms)
+/// * `messages_sent` - 전송한 메시지 수
+/// * `send_errors` - 전송 실패 수
+/// * `data_queued` - 데이터 대기열에서 전송을 기다리는 메시지 수
+/// * `bytes_sent` - 전송한 메시지 크기 합계 (bytes)
+/// * `bytes_per_sec` - 마지막 1초 구간의 전송량 (bytes/s)
+/// * `max_bytes_per_sec` - 적용 중인 최대 전송량 (bytes/s), 제한이 없으면 null
+/// * `bandwidth_level` - 대역폭 제한에 따른 솎아내기 단계 (0 이면 모든 포인트 전송)
+/// * `frames_dropped` - 대역폭 제한 또는 데이터 대기열이 가득 차서 버린 데이터 프레임 수
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct ClientInfo {
+    pub id: Uuid,
+    pub remote_addr: String,
+    pub protocol_version: u8,
+    pub connected_ms: u64,
+    pub control: bool,
+    #[schema(value_type = Option<Object>)]
+    pub subscription: Option<Subscription>,
+    pub quality_level: u8,
+    pub send_latency_ms: f64,
+    pub messages_sent: u64,
+    pub send_errors: u64,
+    pub data_queued: usize,
+    pub bytes_sent: u64,
+    pub bytes_per_sec: f64,
+    pub max_bytes_per_sec: Option<u64>,
+    pub bandwidth_level: u8,
+    pub frames_dropped: u64,
+}
@@ -0,0 +1,303 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::common::impairment::ImpairmentConfig;
+use crate::ws::client::Subscription;
+
+/// 응답 조각 최소 크기 (bytes)
+pub const MIN_CHUNK_SIZE: usize = 1024;
+
+/// WebSocket 서버 설정 구조체
+///
+/// # Fields
+/// * `start_port` - 바인딩을 시도할 시작 포트
+/// * `max_attempts` - 최대 시도 횟수, 시도 시 마다 start_port + 1 을 하여 시도
+/// * `max_message_size` - 클라이언트 메시지 최대 크기 (bytes), 초과 시 `message_too_large` 에러
+/// * `chunk_size` - v2 응답 메시지가 이 크기(bytes)를 넘으면 `chunk` 메시지로 나눠서 전송, None 이면 나누지 않음
+/// * `adaptive` - 전송 지연에 따른 클라이언트별 적응형 전송 설정
+/// * `diagnostics_timeout_ms` - LiDAR 진단 응답 대기 시간 (ms), 새 LiDAR 정보 조회 응답 대기에도 사용
+/// * `identify_new_lidars` - 새 LiDAR 를 처음 수신하면 버전/네트워크 소스 정보를 조회한 뒤 `lidar_discovered` 이벤트 전송
+/// * `config_timeout_ms` - 설정 명령의 ACK 와 설정 확인(읽기) 응답을 각각 기다리는 시간 (ms)
+/// * `max_pending_commands` - LiDAR 마다 진행 중인 설정 명령 외에 대기할 수 있는 요청 수, 초과 시 에러 (0 이면 진행 중일 때 바로 에러)
+/// * `teaching` - `teaching` 요청 진행 설정
+/// * `echo` - 클라이언트가 LiDAR 로 보낸 메시지를 다른 클라이언트에게 전달하는 방식
+/// * `presets` - 구독 시 이름(`preset`)으로 선택할 수 있는 구독 설정 목록
+/// * `bandwidth` - 클라이언트별 전송 대역폭 제한 설정
+/// * `impairment` - 클라이언트로 보내는 데이터 프레임(포인트 클라우드, 통계, 점유 격자)의 장애 주입 설정 (시험용)
+/// * `stream_tokens` - 한 LiDAR 데이터만 받는 일회용 스트림 URL(`/ws/stream/{token}`) 발급 설정
+/// * `sessions` - `hello.session` 토큰별 구독 설정 저장 (서버를 다시 시작해도 복원)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct WsServerConfig {
+    pub start_port: u16,
+    pub max_attempts: u16,
+    pub max_message_size: usize,
+    pub chunk_size: Option<usize>,
+    pub adaptive: AdaptiveConfig,
+    pub diagnostics_timeout_ms: u64,
+    pub identify_new_lidars: bool,
+    pub config_timeout_ms: u64,
+    pub max_pending_commands: usize,
+    pub teaching: TeachingConfig,
+    pub echo: EchoMode,
+    pub presets: Vec<StreamPreset>,
+    pub bandwidth: BandwidthConfig,
+    pub impairment: ImpairmentConfig,
+    pub stream_tokens: StreamTokenConfig,
+    pub sessions: SessionConfig,
+}
+
+impl Default for WsServerConfig {
+    fn default() -> Self {
+        Self {
+            start_port: 5555,
+            max_attempts: 10,
+            max_message_size: 64 * 1024,
+            chunk_size: None,
+            adaptive: AdaptiveConfig::default(),
+            diagnostics_timeout_ms: 1000,
+            identify_new_lidars: true,
+            config_timeout_ms: 1000,
+            max_pending_commands: 4,
+            teaching: TeachingConfig::default(),
+            echo: EchoMode::default(),
+            presets: Vec::new(),
+            bandwidth: BandwidthConfig::default(),
+            impairment: ImpairmentConfig::default(),
+            stream_tokens: StreamTokenConfig::default(),
+            sessions: SessionConfig::default(),
+        }
+    }
+}
+
+impl WsServerConfig {
+    /// 설정 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 조각 크기가 너무 작거나 구독 설정 이름이 중복되거나 구독 설정,
+    ///   스트림 토큰 유효 시간, 세션 수 또는 장애 주입 설정이 잘못되면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        if self.chunk_size.is_some_and(|size| size < MIN_CHUNK_SIZE) {
+            return Err(format!("chunk_size must be at least {}", MIN_CHUNK_SIZE));
+        }
+        for (index, preset) in self.presets.iter().enumerate() {
+            if self.presets[..index]
+                .iter()
+                .any(|other| other.name == preset.name)
+            {
+                return Err(format!("Duplicate preset name: {}", preset.name));
+            }
+            if preset.subscription.preset.is_some() {
+                return Err(format!("Preset {} must not refer to a preset", preset.name));
+            }
+            preset
+                .subscription
+                .validate()
+                .map_err(|e| format!("Preset {}: {}", preset.name, e))?;
+        }
+        if self.stream_tokens.ttl_ms == 0
+            || self.stream_tokens.ttl_ms > self.stream_tokens.max_ttl_ms
+        {
+            return Err("stream_tokens.ttl_ms must be within 1 ~ max_ttl_ms".to_string());
+        }
+        if self.sessions.enabled && self.sessions.max_sessions == 0 {
+            return Err("sessions.max_sessions must be at least 1".to_string());
+        }
+        self.impairment.validate()
+    }
+}
+
+/// 이름 있는 구독 설정 구조체
+///
+/// # Examples
+/// ```json
+/// { "name": "thumbnail", "subscription": { "max_rate_hz": 2.0, "decimation": 8 } }
+/// { "name": "full", "subscription": {} }
+/// ```
+///
+/// # Fields
+/// * `name` - 구독 설정 이름, 클라이언트가 `subscribe` 의 `preset` 으로 선택
+/// * `subscription` - 구독 설정 (`preset` 제외), 클라이언트가 함께 보낸 항목이 우선
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StreamPreset {
+    pub name: String,
+    pub subscription: Subscription,
+}
+
+/// 클라이언트별 전송 대역폭 제한 설정 구조체
+///
+/// # Examples
+/// ```json
+/// { "max_bytes_per_sec": 20000000, "roles": { "dashboard": 2000000 }, "max_level": 4 }
+/// ```
+///
+/// # Fields
+/// * `max_bytes_per_sec` - 모든 클라이언트에 적용할 최대 전송량 (bytes/s), None 이면 제한 없음
+/// * `roles` - 구독의 `role` 별 최대 전송량 (bytes/s)
+/// * `max_level` - 제한을 넘을 때 올리는 최대 솎아내기 단계, 단계 n 에서는 포인트 2^n 개 중 1 개만 전송
+///
+/// # 동작 설명
+/// * 클라이언트의 제한은 `max_bytes_per_sec`, 구독한 `role` 의 제한, 구독의 `max_bytes_per_sec` 중 가장 작은 값
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct BandwidthConfig {
+    pub max_bytes_per_sec: Option<u64>,
+    pub roles: HashMap<String, u64>,
+    pub max_level: u8,
+}
+
+impl Default for BandwidthConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes_per_sec: None,
+            roles: HashMap::new(),
+            max_level: 4,
+        }
+    }
+}
+
+impl BandwidthConfig {
+    /// 구독 설정에 적용할 최대 전송량
+    ///
+    /// # Arguments
+    /// * `subscription` - 클라이언트 구독 설정, None 이면 구독하지 않은 클라이언트
+    ///
+    /// # Returns
+    /// * `Result<Option<u64>, String>` - 성공 시 최대 전송량 (bytes/s, None 이면 제한 없음), 알 수 없는 `role` 이면 에러 메시지
+    pub fn cap(&self, subscription: Option<&Subscription>) -> Result<Option<u64>, String> {
+        let mut caps = vec![self.max_bytes_per_sec];
+        if let Some(subscription) = subscription {
+            if let Some(role) = &subscription.role {
+                let cap = self
+                    .roles
+                    .get(role)
+                    .ok_or_else(|| format!("Unknown role: {}", role))?;
+                caps.push(Some(*cap));
+            }
+            caps.push(subscription.max_bytes_per_sec);
+        }
+        Ok(caps.into_iter().flatten().min())
+    }
+}
+
+/// 스트림 URL 발급 설정 구조체
+///
+/// # Fields
+/// * `enabled` - `POST /streams` 로 스트림 URL 발급 허용 여부
+/// * `ttl_ms` - 요청에 유효 시간이 없을 때 사용하는 토큰 유효 시간 (ms)
+/// * `max_ttl_ms` - 요청할 수 있는 최대 유효 시간 (ms)
+/// * `max_pending` - 아직 사용하지 않은 토큰 최대 수, 초과 시 발급 거부
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct StreamTokenConfig {
+    pub enabled: bool,
+    pub ttl_ms: u64,
+    pub max_ttl_ms: u64,
+    pub max_pending: usize,
+}
+
+impl Default for StreamTokenConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl_ms: 30000,
+            max_ttl_ms: 600000,
+            max_pending: 256,
+        }
+    }
+}
+
+/// 클라이언트 세션 저장 설정 구조체
+///
+/// # Fields
+/// * `enabled` - `hello.session` 토큰별로 구독 설정을 저장하고 같은 토큰으로 다시 연결하면 복원할지 여부
+/// * `file` - 세션 저장 파일 (JSON)
+/// * `max_sessions` - 저장할 최대 세션 수, 초과 시 가장 오래전에 변경한 세션부터 삭제
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SessionConfig {
+    pub enabled: bool,
+    pub file: PathBuf,
+    pub max_sessions: usize,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            file: PathBuf::from("sessions.json"),
+            max_sessions: 1024,
+        }
+    }
+}
+
+/// 티칭 진행 설정 구조체
+///
+/// # Fields
+/// * `timeout_ms` - 티칭 모드 설정 후 설정된 티칭 영역을 받을 때까지 기다리는 시간 (ms)
+/// * `poll_interval_ms` - 티칭 영역 요청 간격 (ms), 티칭이 끝나지 않아 NAK 를 받아도 이 간격으로 재요청
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct TeachingConfig {
+    pub timeout_ms: u64,
+    pub poll_interval_ms: u64,
+}
+
+impl Default for TeachingConfig {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 30000,
+            poll_interval_ms: 1000,
+        }
+    }
+}
+
+/// 클라이언트 메시지 전달 방식 열거형
+///
+/// 클라이언트가 LiDAR 로 보낸 메시지(서버 명령이 아닌 메시지)를 보낸 클라이언트를 제외한
+/// 다른 클라이언트에게 binary 메시지로 전달할지 결정합니다.
+///
+/// # Variants
+/// * `None` - 전달하지 않음
+/// * `All` - 연결된 모든 클라이언트에게 전달
+/// * `Subscribers` - 메시지의 대상 LiDAR (제품 라인/ID) 를 구독 중인 클라이언트에게만 전달
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EchoMode {
+    None,
+    #[default]
+    All,
+    Subscribers,
+}
+
+/// 적응형 전송 설정 구조체
+///
+/// # Fields
+/// * `enabled` - 전송 지연이 커지면 해당 클라이언트의 프레임 전송 간격을 늘릴지 여부
+/// * `degrade_latency_ms` - 품질 단계를 올리는 평균 전송 지연 (ms)
+/// * `recover_latency_ms` - 품질 단계를 내리는 평균 전송 지연 (ms)
+/// * `max_level` - 최대 품질 단계, 단계 n 에서는 2^n 프레임 중 1 프레임만 전송
+/// * `interval_ms` - 품질 단계 변경 최소 간격 (ms)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct AdaptiveConfig {
+    pub enabled: bool,
+    pub degrade_latency_ms: u64,
+    pub recover_latency_ms: u64,
+    pub max_level: u8,
+    pub interval_ms: u64,
+}
+
+impl Default for AdaptiveConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            degrade_latency_ms: 50,
+            recover_latency_ms: 10,
+            max_level: 3,
+            interval_ms: 1000,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::lidar::Point;
+
+/// 출력 좌표 축 열거형 (LiDAR 좌표 축과 부호)
+///
+/// LiDAR 좌표계는 x 오른쪽, y 전방, z 위쪽입니다.
+///
+/// # Variants
+/// * `X`, `Y`, `Z` - LiDAR 좌표 축 값 그대로 사용 (`x`, `y`, `z`)
+/// * `NegX`, `NegY`, `NegZ` - LiDAR 좌표 축 값의 부호를 바꿔 사용 (`-x`, `-y`, `-z`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Axis {
+    #[serde(rename = "x")]
+    X,
+    #[serde(rename = "y")]
+    Y,
+    #[serde(rename = "z")]
+    Z,
+    #[serde(rename = "-x")]
+    NegX,
+    #[serde(rename = "-y")]
+    NegY,
+    #[serde(rename = "-z")]
+    NegZ,
+}
+
+impl Axis {
+    /// LiDAR 좌표에서 이 축의 값
+    fn value(&self, point: &Point) -> f32 {
+        match self {
+            Axis::X => point.x,
+            Axis::Y => point.y,
+            Axis::Z => point.z,
+            Axis::NegX => -point.x,
+            Axis::NegY => -point.y,
+            Axis::NegZ => -point.z,
+        }
+    }
+
+    /// 부호와 관계없는 LiDAR 좌표 축 번호 (x: 0, y: 1, z: 2)
+    fn index(&self) -> usize {
+        match self {
+            Axis::X | Axis::NegX => 0,
+            Axis::Y | Axis::NegY => 1,
+            Axis::Z | Axis::NegZ => 2,
+        }
+    }
+}
+
+/// 출력 길이 단위 열거형
+///
+/// # Variants
+/// * `Meters` - m (`m`, 기본값, LiDAR 좌표 단위)
+/// * `Centimeters` - cm (`cm`)
+/// * `Millimeters` - mm (`mm`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum LengthUnit {
+    #[default]
+    #[serde(rename = "m")]
+    Meters,
+    #[serde(rename = "cm")]
+    Centimeters,
+    #[serde(rename = "mm")]
+    Millimeters,
+}
+
+impl LengthUnit {
+    /// m 단위 값에 곱할 배율
+    pub fn scale(&self) -> f32 {
+        match self {
+            LengthUnit::Meters => 1.0,
+            LengthUnit::Centimeters => 100.0,
+            LengthUnit::Millimeters => 1000.0,
+        }
+    }
+}
+
+fn default_axes() -> [Axis; 3] {
+    [Axis::X, Axis::Y, Axis::Z]
+}
+
+/// 포인트 클라우드 출력 좌표계 설정 구조체
+///
+/// # Examples
+/// ```json
+/// { "axes": ["y", "-x", "z"], "unit": "m" }
+/// { "unit": "cm" }
+/// ```
+///
+/// # Fields
+/// * `axes` - 출력 x, y, z 에 사용할 LiDAR 좌표 축 (기본값 `["x", "y", "z"]`),
+///   ROS(REP-103, x 전방, y 왼쪽, z 위쪽)는 `["y", "-x", "z"]`
+/// * `unit` - 출력 길이 단위 (`m`, `cm`, `mm`, 기본값 `m`)
+///
+/// # 동작 설명
+/// * 관심 영역(ROI) 필터는 변환 전 LiDAR 좌표(m)로 적용
+/// * `axes` 는 x, y, z 를 한 번씩 사용해야 함 (부호는 자유)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OutputConvention {
+    #[serde(default = "default_axes")]
+    pub axes: [Axis; 3],
+    #[serde(default)]
+    pub unit: LengthUnit,
+}
+
+impl Default for OutputConvention {
+    fn default() -> Self {
+        Self {
+            axes: default_axes(),
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+impl OutputConvention {
+    /// 출력 좌표계 유효성 검사
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 같은 축을 두 번 이상 사용하면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        let mut used = [false; 3];
+        for axis in &self.axes {
+            if std::mem::replace(&mut used[axis.index()], true) {
+                return Err("Convention axes must use each of x, y, z exactly once".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// LiDAR 좌표(m)의 포인트를 출력 좌표계로 변환
+    pub fn apply(&self, point: &Point) -> Point {
+        let scale = self.unit.scale();
+        Point {
+            x: self.axes[0].value(point) * scale,
+            y: self.axes[1].value(point) * scale,
+            z: self.axes[2].value(point) * scale,
+        }
+    }
+}
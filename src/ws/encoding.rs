@@ -0,0 +1,185 @@
+use axum::extract::ws::Message;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::ser::{CompactFormatter, Formatter};
+use std::io;
+
+/// JSON 숫자 소수점 아래 최대 자릿수
+pub const MAX_PRECISION: u8 = 9;
+
+/// 클라이언트로 보내는 JSON 메시지(응답, 에러, 이벤트)의 전송 형식 열거형
+///
+/// # Variants
+/// * `Json` - JSON 텍스트 메시지 (기본값)
+/// * `Cbor` - CBOR binary 메시지 (`cbor` feature 필요)
+/// * `Msgpack` - 필드 이름을 포함한 MessagePack binary 메시지 (`msgpack` feature 필요)
+/// * `Protobuf` - `proto/lidar_server.proto` 의 `ServerMessage` binary 메시지 (`protobuf` feature 필요),
+///   포인트 클라우드도 `ScanFrame` 으로 전송하고 클라이언트의 binary 메시지는 `Command` 로 해석
+///
+/// # 동작 설명
+/// * 메시지 구조는 형식과 관계없이 JSON 과 동일
+/// * CBOR/MessagePack 메시지는 map 으로 시작하므로 첫 byte 가 0x80 이상이고,
+///   회사 구분값(0x80 미만)으로 시작하는 LiDAR 데이터 binary 프레임과 구분 가능
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WireEncoding {
+    #[default]
+    Json,
+    Cbor,
+    Msgpack,
+    Protobuf,
+}
+
+impl WireEncoding {
+    /// 이 빌드에서 사용할 수 있는 형식인지 확인
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 사용 가능하면 Ok(()), 필요한 feature 가 빠졌으면 에러 메시지
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            WireEncoding::Json => Ok(()),
+            WireEncoding::Cbor if cfg!(feature = "cbor") => Ok(()),
+            WireEncoding::Cbor => Err("CBOR encoding requires the `cbor` feature".to_string()),
+            WireEncoding::Msgpack if cfg!(feature = "msgpack") => Ok(()),
+            WireEncoding::Msgpack => {
+                Err("MessagePack encoding requires the `msgpack` feature".to_string())
+            }
+            WireEncoding::Protobuf if cfg!(feature = "protobuf") => Ok(()),
+            WireEncoding::Protobuf => {
+                Err("Protobuf encoding requires the `protobuf` feature".to_string())
+            }
+        }
+    }
+
+    /// 값을 WebSocket 메시지로 직렬화
+    ///
+    /// # Examples
+    /// ```
+    /// let message = WireEncoding::Cbor.encode(&envelope)?;
+    /// client.send(message).await?;
+    /// ```
+    ///
+    /// # Arguments
+    /// * `value` - 직렬화할 값
+    ///
+    /// # Returns
+    /// * `Result<Message, String>` - JSON 은 텍스트 메시지, 그 외는 binary 메시지, 실패 시 에러 메시지
+    ///   (Protobuf 는 메시지 종류별 변환이 필요하므로 `ws::protocol` 에서 처리)
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Message, String> {
+        match self {
+            WireEncoding::Json => serde_json::to_string(value)
+                .map(|json| Message::Text(json.into()))
+                .map_err(|e| e.to_string()),
+            #[cfg(feature = "cbor")]
+            WireEncoding::Cbor => {
+                let mut buffer = Vec::new();
+                ciborium::into_writer(value, &mut buffer).map_err(|e| e.to_string())?;
+                Ok(Message::Binary(buffer.into()))
+            }
+            #[cfg(feature = "msgpack")]
+            WireEncoding::Msgpack => rmp_serde::to_vec_named(value)
+                .map(|buffer| Message::Binary(buffer.into()))
+                .map_err(|e| e.to_string()),
+            #[cfg(feature = "protobuf")]
+            WireEncoding::Protobuf => Err("Protobuf requires a typed message".to_string()),
+            // 필요한 feature 가 빠진 형식
+            #[allow(unreachable_patterns)]
+            _ => Err(self.validate().err().unwrap_or_default()),
+        }
+    }
+}
+
+/// 클라이언트별 전송 형식 구조체
+///
+/// # Fields
+/// * `encoding` - 응답/이벤트 전송 형식
+/// * `precision` - JSON 실수를 반올림할 소수점 아래 자릿수, None 이면 전체 자릿수 (JSON 이 아닌 형식에는 적용하지 않음)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct WireFormat {
+    pub encoding: WireEncoding,
+    pub precision: Option<u8>,
+}
+
+impl From<WireEncoding> for WireFormat {
+    fn from(encoding: WireEncoding) -> Self {
+        Self {
+            encoding,
+            precision: None,
+        }
+    }
+}
+
+impl WireFormat {
+    /// 값을 WebSocket 메시지로 직렬화 (`WireEncoding::encode` 참고)
+    ///
+    /// # Returns
+    /// * `Result<Message, String>` - JSON 은 `precision` 자릿수로 반올림한 텍스트 메시지, 그 외는 binary 메시지, 실패 시 에러 메시지
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Message, String> {
+        match self.encoding {
+            WireEncoding::Json => to_json_string(value, self.precision)
+                .map(|json| Message::Text(json.into()))
+                .map_err(|e| e.to_string()),
+            encoding => encoding.encode(value),
+        }
+    }
+}
+
+/// 실수를 소수점 아래 자릿수로 반올림하여 JSON 문자열로 직렬화
+///
+/// # Examples
+/// ```
+/// let json = to_json_string(&[1.23456f32, 2.0], Some(3))?; // "[1.235,2.0]"
+/// ```
+///
+/// # Arguments
+/// * `value` - 직렬화할 값
+/// * `precision` - 소수점 아래 자릿수, None 이면 `serde_json::to_string` 과 같음
+///
+/// # Returns
+/// * `Result<String, serde_json::Error>` - 성공 시 JSON 문자열, 실패 시 직렬화 에러
+///
+/// # 동작 설명
+/// * 정수 값은 그대로 두고 f32/f64 값만 반올림 (좌표 하나가 `0.10000000149011612` 대신 `0.1`)
+/// * 반올림한 값이 정수이면 `2.0` 처럼 소수점을 붙여 실수임을 유지
+pub fn to_json_string<T: Serialize + ?Sized>(
+    value: &T,
+    precision: Option<u8>,
+) -> Result<String, serde_json::Error> {
+    let Some(precision) = precision else {
+        return serde_json::to_string(value);
+    };
+    let mut buffer = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(
+        &mut buffer,
+        RoundingFormatter {
+            scale: 10f64.powi(precision.min(MAX_PRECISION) as i32),
+        },
+    );
+    value.serialize(&mut serializer)?;
+    // serde_json 은 UTF-8 만 출력
+    Ok(String::from_utf8(buffer).unwrap_or_default())
+}
+
+/// 실수 반올림 JSON formatter
+struct RoundingFormatter {
+    scale: f64,
+}
+
+impl Formatter for RoundingFormatter {
+    fn write_f32<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f32) -> io::Result<()> {
+        self.write_f64(writer, value as f64)
+    }
+
+    fn write_f64<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f64) -> io::Result<()> {
+        let rounded = (value * self.scale).round() / self.scale;
+        if !rounded.is_finite() {
+            // 너무 커서 반올림할 수 없는 값
+            return CompactFormatter.write_f64(writer, value);
+        }
+        if rounded == rounded.trunc() {
+            write!(writer, "{:.1}", rounded)
+        } else {
+            write!(writer, "{}", rounded)
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// binary 메시지 봉투 시작 표시
+pub const MAGIC: [u8; 4] = *b"LWSF";
+
+/// binary 메시지 봉투 형식 버전
+pub const VERSION: u8 = 1;
+
+/// 봉투 헤더 크기: 시작 표시(4) + 버전(1) + 데이터 길이(u32) + CRC32(u32)
+pub const HEADER_LEN: usize = 13;
+
+/// 클라이언트로 보내는 binary 메시지의 무결성 봉투 열거형
+///
+/// # Variants
+/// * `None` - 봉투 없이 그대로 전송 (기본값)
+/// * `Crc32` - 모든 binary 메시지를 `[LWSF][버전(u8)][데이터 길이(u32)][CRC32(u32)][데이터]` 로 감싸서 전송
+///   (길이/CRC32 는 big endian, CRC32 는 데이터만 계산한 IEEE CRC-32)
+///
+/// # 동작 설명
+/// * 포인트 클라우드 binary 프레임, CBOR/MessagePack/Protobuf 메시지 등 모든 binary 메시지에 적용하고
+///   텍스트 메시지는 그대로 전송
+/// * 불안정한 링크를 거치며 손상되거나 잘린 메시지를 클라이언트가 디코딩하기 전에 걸러낼 수 있음
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryFraming {
+    #[default]
+    None,
+    Crc32,
+}
+
+impl BinaryFraming {
+    /// binary 메시지 데이터에 봉투 적용
+    ///
+    /// # Arguments
+    /// * `payload` - 보낼 binary 메시지 데이터
+    ///
+    /// # Returns
+    /// * `Option<Vec<u8>>` - 봉투를 씌운 데이터, 봉투를 사용하지 않으면 None
+    pub fn wrap(&self, payload: &[u8]) -> Option<Vec<u8>> {
+        match self {
+            BinaryFraming::None => None,
+            BinaryFraming::Crc32 => Some(wrap(payload)),
+        }
+    }
+}
+
+/// 데이터를 CRC32 봉투로 감싸기
+///
+/// # Examples
+/// ```
+/// let frame = wrap(&payload);
+/// assert_eq!(unwrap(&frame)?, &payload[..]);
+/// ```
+///
+/// # Arguments
+/// * `payload` - 감쌀 데이터
+///
+/// # Returns
+/// * `Vec<u8>` - 헤더 + 데이터
+pub fn wrap(payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(HEADER_LEN + payload.len());
+    frame.extend_from_slice(&MAGIC);
+    frame.push(VERSION);
+    frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&crc32fast::hash(payload).to_be_bytes());
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// CRC32 봉투를 확인하고 데이터 꺼내기 (Rust 클라이언트, 릴레이에서 사용)
+///
+/// # Arguments
+/// * `frame` - 받은 binary 메시지
+///
+/// # Returns
+/// * `Result<&[u8], String>` - 성공 시 데이터, 시작 표시/버전/길이/CRC32 가 맞지 않으면 에러 메시지
+pub fn unwrap(frame: &[u8]) -> Result<&[u8], String> {
+    if frame.len() < HEADER_LEN {
+        return Err(format!("Frame too short: {} bytes", frame.len()));
+    }
+    if frame[..4] != MAGIC {
+        return Err("Invalid frame magic".to_string());
+    }
+    if frame[4] != VERSION {
+        return Err(format!("Unsupported frame version: {}", frame[4]));
+    }
+    let len = u32::from_be_bytes([frame[5], frame[6], frame[7], frame[8]]) as usize;
+    let payload = &frame[HEADER_LEN..];
+    if payload.len() != len {
+        return Err(format!(
+            "Frame length mismatch: header {} bytes, received {} bytes",
+            len,
+            payload.len()
+        ));
+    }
+    let crc = u32::from_be_bytes([frame[9], frame[10], frame[11], frame[12]]);
+    if crc32fast::hash(payload) != crc {
+        return Err("Frame CRC32 mismatch".to_string());
+    }
+    Ok(payload)
+}
@@ -0,0 +1,148 @@
+use serde_json::Value;
+
+use crate::ws::message::RequestMessage;
+use crate::ws::protocol::{ErrorCode, ProtocolVersion};
+
+/// 요청 메시지 최상위에 허용되는 필드 목록
+const REQUEST_FIELDS: [&str; 3] = ["id", "command", "data"];
+
+/// 클라이언트 입력 검증 결과 열거형
+///
+/// # Variants
+/// * `Request` - 서버 명령 후보 요청 메시지
+/// * `Forward` - LiDAR 로 그대로 전달할 데이터
+/// * `Reject` - 에러 코드와 메시지 (요청 ID 를 알 수 있으면 함께 반환)
+#[derive(Debug)]
+pub enum Inbound {
+    Request(RequestMessage),
+    Forward(Vec<u8>),
+    Reject {
+        id: Option<Value>,
+        code: ErrorCode,
+        message: String,
+    },
+}
+
+impl Inbound {
+    fn reject(id: Option<Value>, code: ErrorCode, message: String) -> Self {
+        Self::Reject { id, code, message }
+    }
+}
+
+/// Text 메시지 검증
+///
+/// # Arguments
+/// * `text` - 수신한 Text 메시지
+/// * `version` - 클라이언트 프로토콜 버전
+/// * `max_message_size` - 허용하는 최대 메시지 크기 (bytes)
+///
+/// # Returns
+/// * `Inbound` - 검증 결과
+///
+/// # 동작 설명
+/// * 최대 크기를 넘으면 `message_too_large` 에러
+/// * v1: 요청 메시지 형식이 아니면 LiDAR 로 전달 (기존 동작 유지)
+/// * v2: 잘못된 JSON 은 `invalid_json`, 형식 오류/알 수 없는 필드는 `invalid_request` 에러
+pub fn validate_text(text: &str, version: ProtocolVersion, max_message_size: usize) -> Inbound {
+    if text.len() > max_message_size {
+        return Inbound::reject(
+            None,
+            ErrorCode::MessageTooLarge,
+            format!(
+                "Message size {} exceeds limit {}",
+                text.len(),
+                max_message_size
+            ),
+        );
+    }
+
+    let value = match serde_json::from_str::<Value>(text) {
+        Ok(value) => value,
+        Err(e) => {
+            return match version {
+                ProtocolVersion::V1 => Inbound::Forward(text.as_bytes().to_vec()),
+                ProtocolVersion::V2 => Inbound::reject(None, ErrorCode::InvalidJson, e.to_string()),
+            }
+        }
+    };
+
+    let id = value.get("id").cloned();
+    if version == ProtocolVersion::V2 {
+        if let Some(field) = value.as_object().and_then(|object| {
+            object
+                .keys()
+                .find(|key| !REQUEST_FIELDS.contains(&key.as_str()))
+        }) {
+            return Inbound::reject(
+                id,
+                ErrorCode::InvalidRequest,
+                format!("Unknown field: {}", field),
+            );
+        }
+    }
+
+    match serde_json::from_value::<RequestMessage>(value) {
+        Ok(request) => Inbound::Request(request),
+        Err(e) => match version {
+            ProtocolVersion::V1 => Inbound::Forward(text.as_bytes().to_vec()),
+            ProtocolVersion::V2 => Inbound::reject(id, ErrorCode::InvalidRequest, e.to_string()),
+        },
+    }
+}
+
+/// Binary 메시지 검증
+///
+/// # Arguments
+/// * `data` - 수신한 Binary 메시지
+/// * `max_message_size` - 허용하는 최대 메시지 크기 (bytes)
+///
+/// # Returns
+/// * `Inbound` - 최대 크기 이하이면 LiDAR 로 전달, 넘으면 `message_too_large` 에러
+///
+/// Binary 메시지는 UTF-8 이 아닐 수 있으므로 문자열로 변환하지 않고 그대로 전달
+pub fn validate_binary(data: &[u8], max_message_size: usize) -> Inbound {
+    if data.len() > max_message_size {
+        return Inbound::reject(
+            None,
+            ErrorCode::MessageTooLarge,
+            format!(
+                "Message size {} exceeds limit {}",
+                data.len(),
+                max_message_size
+            ),
+        );
+    }
+    Inbound::Forward(data.to_vec())
+}
+
+/// 스트림 토큰 연결(`/ws/stream/{token}`)에서 허용하는 입력만 남김
+///
+/// # Arguments
+/// * `inbound` - 입력 검증 결과
+///
+/// # Returns
+/// * `Inbound` - 구독 복원(`resume`, `session`) 없는 `hello` 와 에러는 그대로, 그 외 명령과 LiDAR 로 전달할 데이터는 `invalid_request` 에러
+///
+/// 스트림 연결은 토큰에 묶인 구독만 받으므로 구독 변경, 설정 명령, LiDAR 명령 전달을 허용하지 않음
+pub fn restrict_to_stream(inbound: Inbound) -> Inbound {
+    match inbound {
+        Inbound::Request(request)
+            if request.command == "hello"
+                && request.data.get("resume").is_none()
+                && request.data.get("session").is_none() =>
+        {
+            Inbound::Request(request)
+        }
+        Inbound::Request(request) => Inbound::reject(
+            request.id,
+            ErrorCode::InvalidRequest,
+            format!("{} is not allowed on a stream connection", request.command),
+        ),
+        Inbound::Forward(_) => Inbound::reject(
+            None,
+            ErrorCode::InvalidRequest,
+            "Forwarding to LiDAR is not allowed on a stream connection".to_string(),
+        ),
+        reject => reject,
+    }
+}
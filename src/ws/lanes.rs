@@ -0,0 +1,167 @@
+use axum::extract::ws::{Message, WebSocket};
+use futures::stream::SplitSink;
+use futures::SinkExt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tracing::*;
+
+use crate::common::impairment::Impairer;
+
+/// 데이터 대기열에 쌓아둘 수 있는 최대 메시지 수, 가득 차면 새 프레임은 버림
+pub const DATA_LANE_CAPACITY: usize = 4;
+
+/// 전송 태스크가 갱신하는 전송 통계
+#[derive(Debug, Default)]
+struct LaneStats {
+    sent: AtomicU64,
+    errors: AtomicU64,
+    data_latency_us: AtomicU64,
+}
+
+/// 클라이언트별 제어/데이터 전송 대기열 구조체
+///
+/// # Examples
+/// ```
+/// let lanes = OutboundLanes::spawn(sender, None);
+/// lanes.send_control(response)?;
+/// if !lanes.send_data(frame)? {
+///     // 데이터 대기열이 가득 차서 프레임을 버림
+/// }
+/// ```
+///
+/// # 동작 설명
+/// * 클라이언트마다 전송 태스크 하나가 WebSocket sink 를 가지고 두 대기열의 메시지를 전송
+/// * 제어 대기열(응답, 에러, 이벤트)은 항상 데이터 대기열(포인트 클라우드, 통계, 점유 격자)보다 먼저 전송하므로
+///   응답은 최대 전송 중인 프레임 하나만 기다림
+/// * 제어 대기열은 버리지 않고, 데이터 대기열은 `DATA_LANE_CAPACITY` 를 넘으면 새 프레임을 버림
+///   (호출자는 기다리지 않으므로 클라이언트 목록 잠금을 오래 잡지 않음)
+/// * 소켓 전송에 실패하거나 Close 메시지를 보내면 전송 태스크 종료
+/// * 장애 주입 사용 시 데이터 대기열 메시지만 버리거나 지연 후 보내거나 두 번 보냄
+///   (WebSocket 은 순서를 보장하므로 지연한 메시지 뒤의 메시지도 함께 늦어짐)
+pub struct OutboundLanes {
+    control: mpsc::UnboundedSender<Message>,
+    data: mpsc::Sender<Message>,
+    stats: Arc<LaneStats>,
+}
+
+impl OutboundLanes {
+    /// 전송 태스크를 시작하고 대기열 생성
+    ///
+    /// # Arguments
+    /// * `sender` - 클라이언트로 메시지를 보내는 WebSocket sink
+    /// * `impairment` - 데이터 메시지 장애 주입기 (미사용 시 None)
+    ///
+    /// # Returns
+    /// * `Self` - 대기열, 대기열이 drop 되면 남은 메시지를 보낸 뒤 전송 태스크 종료
+    pub fn spawn(sender: SplitSink<WebSocket, Message>, impairment: Option<Arc<Impairer>>) -> Self {
+        let (control, control_rx) = mpsc::unbounded_channel();
+        let (data, data_rx) = mpsc::channel(DATA_LANE_CAPACITY);
+        let stats = Arc::new(LaneStats::default());
+        tokio::spawn(write_loop(
+            sender,
+            control_rx,
+            data_rx,
+            stats.clone(),
+            impairment,
+        ));
+        Self {
+            control,
+            data,
+            stats,
+        }
+    }
+
+    /// 제어 대기열에 메시지 추가
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 전송 태스크가 종료되었으면 에러 메시지
+    pub fn send_control(&self, message: Message) -> Result<(), String> {
+        self.control.send(message).map_err(|_| {
+            self.stats.errors.fetch_add(1, Ordering::Relaxed);
+            "Connection closed".to_string()
+        })
+    }
+
+    /// 데이터 대기열에 메시지 추가
+    ///
+    /// # Returns
+    /// * `Result<bool, String>` - 추가하면 true, 대기열이 가득 차서 버렸으면 false,
+    ///   전송 태스크가 종료되었으면 에러 메시지
+    pub fn send_data(&self, message: Message) -> Result<bool, String> {
+        match self.data.try_send(message) {
+            Ok(()) => Ok(true),
+            Err(mpsc::error::TrySendError::Full(_)) => Ok(false),
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                self.stats.errors.fetch_add(1, Ordering::Relaxed);
+                Err("Connection closed".to_string())
+            }
+        }
+    }
+
+    /// 소켓으로 보낸 메시지 수
+    pub fn messages_sent(&self) -> u64 {
+        self.stats.sent.load(Ordering::Relaxed)
+    }
+
+    /// 전송 실패 수 (소켓 전송 실패, 연결 종료 후 추가 시도)
+    pub fn send_errors(&self) -> u64 {
+        self.stats.errors.load(Ordering::Relaxed)
+    }
+
+    /// 마지막 데이터 메시지의 소켓 전송 시간
+    pub fn data_latency(&self) -> Duration {
+        Duration::from_micros(self.stats.data_latency_us.load(Ordering::Relaxed))
+    }
+
+    /// 데이터 대기열에서 전송을 기다리는 메시지 수
+    pub fn data_queued(&self) -> usize {
+        DATA_LANE_CAPACITY - self.data.capacity()
+    }
+}
+
+/// 제어 대기열을 우선하여 대기열의 메시지를 소켓으로 전송
+async fn write_loop(
+    mut sender: SplitSink<WebSocket, Message>,
+    mut control_rx: mpsc::UnboundedReceiver<Message>,
+    mut data_rx: mpsc::Receiver<Message>,
+    stats: Arc<LaneStats>,
+    impairment: Option<Arc<Impairer>>,
+) {
+    'messages: loop {
+        let (message, data) = tokio::select! {
+            biased;
+            Some(message) = control_rx.recv() => (message, false),
+            Some(message) = data_rx.recv() => (message, true),
+            else => break,
+        };
+        let mut copies = 1;
+        if let Some(impairer) = impairment.as_ref().filter(|_| data) {
+            let impairment = impairer.decide();
+            tokio::time::sleep(impairment.delay).await;
+            copies = impairment.copies;
+            if copies == 0 {
+                continue;
+            }
+        }
+        let close = matches!(message, Message::Close(_));
+        let started = Instant::now();
+        for _ in 0..copies {
+            if let Err(e) = sender.send(message.clone()).await {
+                stats.errors.fetch_add(1, Ordering::Relaxed);
+                debug!("Stopping client writer: {}", e);
+                break 'messages;
+            }
+            stats.sent.fetch_add(1, Ordering::Relaxed);
+        }
+        if data {
+            stats
+                .data_latency_us
+                .store(started.elapsed().as_micros() as u64, Ordering::Relaxed);
+        }
+        if close {
+            break;
+        }
+    }
+}
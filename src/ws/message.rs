@@ -0,0 +1,632 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::alert::{Alert, EventQuery, ZoneEvent};
+use crate::lidar::kanavi_mobility::{
+    ConfigTransactionRequest, ConfigTransactionResult, DiagnosticsReport, KMConfigData,
+    SetConfigRequest, SetConfigResult, SetOutcome, TeachingProgress, TeachingRequest,
+    TeachingResult, WarningAreaRequest, WarningAreaResult,
+};
+use crate::lidar::{IdentityChange, LiDARInfo, LiDARKey, LiDARStatus, ParserStats, PointCloud};
+use crate::processing::{
+    CountRollup, FrameStatistics, InferenceResult, OccupancyEncoding, OdometrySample,
+    TrackedObjects,
+};
+use crate::record::format::{Marker, RecordingInfo};
+use crate::record::PlaybackStatus;
+use crate::site::SiteStatus;
+use crate::telemetry::TelemetrySample;
+use crate::ws::client::{ClientInfo, Subscription};
+use crate::ws::convention::OutputConvention;
+use crate::ws::queue::CommandQueueStatus;
+
+/// WebSocket 클라이언트 요청 메시지 (v1 호환 형식)
+///
+/// 수신한 JSON 을 먼저 이 형식으로 파싱한 뒤 `WsRequest::parse` 로 명령별 타입으로 변환합니다.
+///
+/// # Examples
+/// ```json
+/// { "command": "lidar_list" }
+/// { "id": 7, "command": "lidar_list" }
+/// ```
+///
+/// # Fields
+/// * `id` - 요청 ID, v2 에서 응답에 그대로 반환 (v1 에서는 무시)
+/// * `command` - 요청 명령 (예: `lidar_list`)
+/// * `data` - 명령별 추가 데이터
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RequestMessage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+    pub command: String,
+    #[serde(default)]
+    pub data: Value,
+}
+
+/// WebSocket 서버 응답 메시지 (v1 호환 형식)
+///
+/// # Examples
+/// ```json
+/// { "command": "lidar_list", "status": "ok", "data": [ ... ] }
+/// ```
+///
+/// # Fields
+/// * `command` - 요청 명령
+/// * `status` - 처리 결과 (`ok` 또는 `error`)
+/// * `data` - 응답 데이터, 실패 시 에러 메시지
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResponseMessage {
+    pub command: String,
+    pub status: String,
+    pub data: Value,
+}
+
+impl ResponseMessage {
+    pub fn ok(command: &str, data: Value) -> Self {
+        Self {
+            command: command.to_string(),
+            status: "ok".to_string(),
+            data,
+        }
+    }
+
+    pub fn error(command: &str, message: &str) -> Self {
+        Self {
+            command: command.to_string(),
+            status: "error".to_string(),
+            data: Value::String(message.to_string()),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+/// `point_cloud` 이벤트 데이터
+///
+/// # Fields
+/// * `key` - 데이터를 송신한 LiDAR 키
+/// * `points` - 포인트 클라우드 목록
+/// * `replay` - 녹화 파일 재생 데이터 여부
+/// * `time_ms` - 수신 시각 (Unix time, ms)
+/// * `convention` - 구독에서 선택한 출력 좌표계, 없으면 LiDAR 좌표(m)
+/// * `timing` - `timing` 구독 시 포인트별 측정 시각
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PointCloudData<'a> {
+    pub key: LiDARKey,
+    pub points: &'a [PointCloud],
+    pub replay: bool,
+    pub time_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub convention: Option<&'a OutputConvention>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<FrameTiming<'a>>,
+}
+
+/// 포인트별 측정 시각 구조체
+///
+/// 채널 데이터의 수신 시각을 스캔이 끝난 시각으로 보고, 포인트(열)가 `processing.deskew.scan_period_ms`
+/// 동안 순서대로 측정된 것으로 추정합니다.
+///
+/// # Fields
+/// * `scan_start_ms` - 스캔 시작 시각 (Unix time, ms)
+/// * `offsets_us` - `points` 와 같은 순서의 채널별, 포인트별 측정 시각 (스캔 시작 기준, µs)
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FrameTiming<'a> {
+    pub scan_start_ms: u64,
+    pub offsets_us: &'a [Vec<u32>],
+}
+
+/// `config` 이벤트 데이터
+///
+/// # Fields
+/// * `key` - 데이터를 송신한 LiDAR 키
+/// * `config` - LiDAR 설정 응답
+/// * `replay` - 녹화 파일 재생 데이터 여부
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ConfigData<'a> {
+    pub key: LiDARKey,
+    pub config: &'a KMConfigData,
+    pub replay: bool,
+}
+
+/// `statistics` 이벤트 데이터
+///
+/// # Fields
+/// * `key` - 데이터를 송신한 LiDAR 키
+/// * `statistics` - 구독 필터를 적용한 포인트 클라우드의 프레임 통계
+/// * `replay` - 녹화 파일 재생 데이터 여부
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct StatisticsData {
+    pub key: LiDARKey,
+    pub statistics: FrameStatistics,
+    pub replay: bool,
+}
+
+/// `occupancy_grid` 이벤트 데이터
+///
+/// # Fields
+/// * `key` - 격자를 만든 LiDAR 키, 여러 LiDAR 를 합친 격자이면 null
+/// * `resolution` - 격자 크기 (m)
+/// * `origin` - 격자 범위의 최소 좌표 `[x, y]` (m)
+/// * `width` - x 방향 격자 수
+/// * `height` - y 방향 격자 수
+/// * `encoding` - 격자 인코딩 (`png` 또는 `raw`)
+/// * `data` - base64 로 인코딩한 격자 데이터
+/// * `replay` - 녹화 파일 재생 데이터 여부
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct OccupancyGridData {
+    pub key: Option<LiDARKey>,
+    pub resolution: f32,
+    pub origin: [f32; 2],
+    pub width: usize,
+    pub height: usize,
+    pub encoding: OccupancyEncoding,
+    pub data: String,
+    pub replay: bool,
+}
+
+/// `spool_available` 이벤트 데이터
+///
+/// 구독 시작 시 클라이언트가 없는 동안 임시 저장된 세그먼트가 있으면 전송되며,
+/// 각 세그먼트는 `playback` 명령으로 재생할 수 있습니다.
+///
+/// # Fields
+/// * `recordings` - 임시 저장된 세그먼트 파일 목록 (오래된 순)
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SpoolData {
+    pub recordings: Vec<RecordingInfo>,
+}
+
+/// `quality` 이벤트 데이터
+///
+/// 전송 지연에 따라 클라이언트의 포인트 클라우드 전송 품질이 바뀌면 전송됩니다.
+///
+/// # Fields
+/// * `level` - 품질 단계 (0 이면 모든 프레임 전송)
+/// * `frame_interval` - LiDAR 별 전송 프레임 간격 (2^level 프레임 중 1 프레임 전송)
+/// * `latency_ms` - 평균 전송 지연 (ms)
+/// * `degraded` - 프레임을 건너뛰는 중인지 여부
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct QualityData {
+    pub level: u8,
+    pub frame_interval: u64,
+    pub latency_ms: f64,
+    pub degraded: bool,
+}
+
+/// `hello` 응답 데이터
+///
+/// # Fields
+/// * `protocol_version` - 협상된 프로토콜 버전
+/// * `supported_versions` - 서버가 지원하는 프로토콜 버전 목록
+/// * `server_version` - 서버 버전
+/// * `client_id` - 이 연결의 클라이언트 UUID (이중화 시 대기 서버에 다시 연결할 때 `resume` 으로 사용)
+/// * `resumed` - `resume` 으로 이전 구독을 복원했으면 true
+/// * `restored` - `session` 토큰으로 저장된 구독 설정을 복원했으면 true
+/// * `server_id` - 서버 ID (`relay.server_id`)
+/// * `relay_path` - 이 서버와 상위 서버들의 ID 경로 (하위 서버의 연결 고리 감지용)
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct HelloData {
+    pub protocol_version: u8,
+    pub supported_versions: Vec<u8>,
+    pub server_version: String,
+    pub client_id: Uuid,
+    pub resumed: bool,
+    pub restored: bool,
+    pub server_id: String,
+    pub relay_path: Vec<String>,
+}
+
+/// `hello` 요청 데이터
+///
+/// # Fields
+/// * `protocol_version` - 클라이언트가 요청하는 프로토콜 버전
+/// * `resume` - 이중화 주 서버에서 받은 이전 클라이언트 UUID, 복제된 구독이 있으면 복원
+/// * `session` - 클라이언트가 정한 세션 토큰 (`ws.sessions` 사용 시), 이후 구독 변경을 저장하고 저장된 구독 설정이 있으면 복원
+/// * `relay_path` - 릴레이 하위 서버의 ID 경로, 이 서버 ID 가 있으면 연결 고리로 거부
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HelloRequest {
+    pub protocol_version: u64,
+    #[serde(default)]
+    pub resume: Option<Uuid>,
+    #[serde(default)]
+    pub session: Option<String>,
+    #[serde(default)]
+    pub relay_path: Option<Vec<String>>,
+}
+
+fn default_playback_speed() -> f64 {
+    1.0
+}
+
+/// `playback` 요청 데이터 열거형
+///
+/// # Examples
+/// ```json
+/// { "command": "playback", "data": { "action": "start", "name": "session-1700000000000.lrec", "speed": 2.0 } }
+/// { "command": "playback", "data": { "action": "seek", "position_ms": 10000 } }
+/// ```
+///
+/// # Variants
+/// * `Start` - 녹화 파일 재생 시작 (`speed` 기본값 1.0)
+/// * `Pause` - 일시 정지
+/// * `Resume` - 일시 정지 해제
+/// * `Seek` - 녹화 시작 기준 위치(ms)로 이동
+/// * `Speed` - 재생 속도 배율 변경
+/// * `Stop` - 재생 정지
+/// * `Status` - 재생 상태 조회
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum PlaybackRequest {
+    Start {
+        name: String,
+        #[serde(default = "default_playback_speed")]
+        speed: f64,
+    },
+    Pause,
+    Resume,
+    Seek {
+        position_ms: u64,
+    },
+    Speed {
+        speed: f64,
+    },
+    Stop,
+    Status,
+}
+
+/// `annotate` 요청 데이터
+///
+/// # Examples
+/// ```json
+/// { "command": "annotate", "data": { "label": "pedestrian crossed" } }
+/// { "command": "annotate", "data": { "label": "pedestrian crossed", "key": { ... }, "time_ms": 1700000000000 } }
+/// ```
+///
+/// # Fields
+/// * `label` - 마커 내용 (비어 있으면 안 됨)
+/// * `key` - 마커와 관련된 LiDAR 키 (선택), 있으면 그 LiDAR 를 구독 중인 클라이언트에게만 이벤트 전송
+/// * `time_ms` - 마커 시각 (Unix time, ms), 없으면 서버 수신 시각
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AnnotateRequest {
+    pub label: String,
+    #[serde(default)]
+    pub key: Option<LiDARKey>,
+    #[serde(default)]
+    pub time_ms: Option<u64>,
+}
+
+/// `admin_lidar_enable` 요청 데이터
+///
+/// # Examples
+/// ```json
+/// { "command": "admin_lidar_enable", "data": { "lidar": { ... }, "enabled": false } }
+/// ```
+///
+/// # Fields
+/// * `lidar` - 대상 LiDAR 키
+/// * `enabled` - false 면 비활성화 (수신 데이터를 파싱/전달하지 않음), true 면 다시 활성화
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LidarEnableRequest {
+    pub lidar: LiDARKey,
+    pub enabled: bool,
+}
+
+/// 장치 검색 응답 기본 대기 시간 (ms)
+pub const DEFAULT_DISCOVER_TIMEOUT_MS: u64 = 2000;
+
+/// 장치 검색 응답 최대 대기 시간 (ms)
+pub const MAX_DISCOVER_TIMEOUT_MS: u64 = 30000;
+
+/// `admin_discover` 요청 데이터 (`POST /admin/discover` 본문)
+///
+/// # Examples
+/// ```json
+/// { "command": "admin_discover", "data": { "timeout_ms": 5000 } }
+/// ```
+///
+/// # Fields
+/// * `timeout_ms` - 검색 요청을 보낸 뒤 새 LiDAR 를 기다리는 시간 (ms), None 이면 `DEFAULT_DISCOVER_TIMEOUT_MS`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DiscoverRequest {
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+impl DiscoverRequest {
+    /// 응답 대기 시간
+    ///
+    /// # Returns
+    /// * `Result<u64, String>` - 성공 시 대기 시간 (ms), 1 ~ `MAX_DISCOVER_TIMEOUT_MS` 를 벗어나면 에러 메시지
+    pub fn timeout_ms(&self) -> Result<u64, String> {
+        let timeout_ms = self.timeout_ms.unwrap_or(DEFAULT_DISCOVER_TIMEOUT_MS);
+        if timeout_ms == 0 || timeout_ms > MAX_DISCOVER_TIMEOUT_MS {
+            return Err(format!(
+                "timeout_ms must be within 1 ~ {}",
+                MAX_DISCOVER_TIMEOUT_MS
+            ));
+        }
+        Ok(timeout_ms)
+    }
+}
+
+/// 장치 검색 결과
+///
+/// # Fields
+/// * `found` - 검색하는 동안 처음 등록된 LiDAR 장치 정보 (등록 순)
+/// * `total` - 검색 후 등록된 전체 LiDAR 수
+/// * `timeout_ms` - 응답을 기다린 시간 (ms)
+#[derive(Debug, Clone, Serialize, JsonSchema, ToSchema)]
+pub struct DiscoverResult {
+    pub found: Vec<LiDARInfo>,
+    pub total: usize,
+    pub timeout_ms: u64,
+}
+
+/// `annotate` 응답 및 `annotation` 이벤트 데이터
+///
+/// # Fields
+/// * `recording` - 마커를 기록한 녹화 파일 이름 (`playback` 명령으로 재생)
+/// * `marker` - 기록한 마커
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AnnotationData {
+    pub recording: String,
+    pub marker: Marker,
+}
+
+/// 요청 변환 에러 열거형
+///
+/// # Variants
+/// * `UnknownCommand` - 서버에서 처리하지 않는 명령
+/// * `Invalid` - 명령별 데이터 형식 오류
+#[derive(Debug, Clone)]
+pub enum RequestError {
+    UnknownCommand,
+    Invalid(String),
+}
+
+/// 서버에서 처리하는 WebSocket 요청 열거형
+///
+/// # Examples
+/// ```json
+/// { "command": "hello", "data": { "protocol_version": 2 } }
+/// { "command": "subscribe", "data": { "lidars": [ ... ] } }
+/// { "command": "odometry", "data": { "velocity": { "linear": [0.0, 5.0, 0.0] } } }
+/// ```
+///
+/// # Variants
+/// * `Hello` - 프로토콜 버전 협상
+/// * `LidarList` - 검색된 LiDAR 장치 목록 요청
+/// * `Subscribe` - LiDAR 데이터 구독, 데이터가 없으면 모든 LiDAR 구독
+/// * `Unsubscribe` - LiDAR 데이터 구독 해제
+/// * `GetSchema` - JSON Schema 요청
+/// * `RecordingList` - 녹화 파일 목록 요청
+/// * `Playback` - 녹화 파일 재생 제어
+/// * `Diagnostics` - LiDAR 진단 요청 (`data` 는 LiDAR 키)
+/// * `AdminClients` - 연결된 클라이언트 목록 요청
+/// * `AdminDisconnect` - 클라이언트 강제 연결 종료 (`data` 는 클라이언트 UUID)
+/// * `AdminLidarEnable` - LiDAR 비활성화/다시 활성화
+/// * `AdminDiscover` - 장치 검색 요청을 바로 보내고 새로 찾은 LiDAR 응답, 데이터가 없으면 기본 대기 시간
+/// * `SiteList` - 사이트 상태 목록 요청
+/// * `Teaching` - 티칭 모드 설정부터 티칭 영역 수신까지 진행
+/// * `SetWarningArea` - 위험/경고/주의 영역 거리(m) 설정 후 다시 읽어 확인
+/// * `SetConfig` - 설정 파라미터와 장치 형식 데이터로 설정 후 다시 읽어 확인
+/// * `ConfigTransaction` - 여러 설정을 순서대로 적용하고, 하나라도 실패하면 시작 전 값으로 되돌림
+/// * `ParserStats` - LiDAR 송신 주소별 파싱 통계 요청
+/// * `Annotate` - 진행 중인 녹화의 목록 파일(manifest)에 주석 마커 기록
+/// * `Odometry` - 자기 위치/속도 샘플 입력 (움직임 왜곡 보정, 월드 좌표 출력에 사용)
+/// * `ZoneEvents` - 저장된 영역 침입 이벤트 조회 (기간, LiDAR 주소, 영역 이름), 데이터가 없으면 최근 24시간
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "command", content = "data", rename_all = "snake_case")]
+pub enum WsRequest {
+    Hello(HelloRequest),
+    LidarList,
+    Subscribe(Option<Subscription>),
+    Unsubscribe,
+    GetSchema,
+    RecordingList,
+    Playback(PlaybackRequest),
+    Diagnostics(LiDARKey),
+    AdminClients,
+    AdminDisconnect(Uuid),
+    AdminLidarEnable(LidarEnableRequest),
+    AdminDiscover(Option<DiscoverRequest>),
+    SiteList,
+    Teaching(TeachingRequest),
+    SetWarningArea(WarningAreaRequest),
+    SetConfig(SetConfigRequest),
+    ConfigTransaction(ConfigTransactionRequest),
+    ParserStats,
+    Annotate(AnnotateRequest),
+    Odometry(OdometrySample),
+    ZoneEvents(Option<EventQuery>),
+}
+
+impl WsRequest {
+    /// 서버에서 처리하는 명령 목록
+    pub const COMMANDS: [&'static str; 21] = [
+        "hello",
+        "lidar_list",
+        "subscribe",
+        "unsubscribe",
+        "get_schema",
+        "recording_list",
+        "playback",
+        "diagnostics",
+        "admin_clients",
+        "admin_disconnect",
+        "site_list",
+        "teaching",
+        "set_warning_area",
+        "set_config",
+        "parser_stats",
+        "annotate",
+        "odometry",
+        "admin_lidar_enable",
+        "zone_events",
+        "admin_discover",
+        "config_transaction",
+    ];
+
+    /// v1 호환 요청 메시지를 명령별 요청으로 변환
+    ///
+    /// # Arguments
+    /// * `request` - 요청 메시지
+    ///
+    /// # Returns
+    /// * `Result<Self, RequestError>` - 성공 시 요청, 실패 시 알 수 없는 명령 또는 데이터 형식 오류
+    pub fn parse(request: &RequestMessage) -> Result<Self, RequestError> {
+        if !Self::COMMANDS.contains(&request.command.as_str()) {
+            return Err(RequestError::UnknownCommand);
+        }
+
+        let value = if request.data.is_null() {
+            json!({ "command": request.command })
+        } else {
+            json!({ "command": request.command, "data": request.data })
+        };
+        let request: Self =
+            serde_json::from_value(value).map_err(|e| RequestError::Invalid(e.to_string()))?;
+        if let WsRequest::Subscribe(Some(subscription)) = &request {
+            subscription.validate().map_err(RequestError::Invalid)?;
+        }
+        Ok(request)
+    }
+
+    /// 요청 대상 LiDAR (접근 로그 기록용)
+    ///
+    /// # Returns
+    /// * `Option<LiDARKey>` - 특정 LiDAR 를 대상으로 하는 명령이면 LiDAR 키, 아니면 None
+    pub fn target(&self) -> Option<LiDARKey> {
+        match self {
+            WsRequest::Diagnostics(key) => Some(*key),
+            WsRequest::Teaching(request) => Some(request.lidar),
+            WsRequest::SetWarningArea(request) => Some(request.lidar),
+            WsRequest::SetConfig(request) => Some(request.lidar),
+            WsRequest::ConfigTransaction(request) => Some(request.lidar),
+            WsRequest::Annotate(request) => request.key,
+            WsRequest::AdminLidarEnable(request) => Some(request.lidar),
+            _ => None,
+        }
+    }
+}
+
+/// 서버 응답 열거형
+///
+/// # Variants
+/// * `Hello` - 협상된 프로토콜 버전 정보
+/// * `LidarList` - 검색된 LiDAR 장치 목록 (처음/마지막 수신 시각, 수신 주기, 펌웨어 버전, 모터 속도, 온라인 여부 포함)
+/// * `Subscribe` - 적용된 구독 설정
+/// * `Unsubscribe` - 구독 해제 완료
+/// * `GetSchema` - JSON Schema
+/// * `RecordingList` - 녹화 파일 목록
+/// * `Playback` - 재생 상태 (재생 중이 아니면 null)
+/// * `Diagnostics` - LiDAR 진단 결과
+/// * `AdminClients` - 연결된 클라이언트 목록
+/// * `AdminDisconnect` - 연결을 끊은 클라이언트 UUID
+/// * `AdminLidarEnable` - 변경한 LiDAR 장치 정보
+/// * `AdminDiscover` - 새로 찾은 LiDAR 장치 정보와 전체 LiDAR 수
+/// * `SiteList` - 사이트 상태 목록
+/// * `Teaching` - 티칭 결과 (채널별 티칭 영역 다각형)
+/// * `SetWarningArea` - 설정 후 다시 읽은 경고 영역 거리와 설정 확인 결과 (`validate_only` 요청은 보냈을 명령 프레임)
+/// * `SetConfig` - 설정 후 다시 읽은 데이터와 설정 확인 결과 (`validate_only` 요청은 보냈을 명령 프레임)
+/// * `ConfigTransaction` - 적용 여부, 적용한 설정 결과, 실패 시 원인과 롤백 결과
+/// * `ParserStats` - LiDAR 송신 주소별 파싱 통계 목록
+/// * `Annotate` - 기록한 마커와 녹화 파일 이름
+/// * `Odometry` - 저장한 샘플 (시각 포함)
+/// * `ZoneEvents` - 조건에 맞는 영역 침입 이벤트 목록 (오래된 순)
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(tag = "command", content = "data", rename_all = "snake_case")]
+pub enum WsResponse {
+    Hello(HelloData),
+    LidarList(Vec<LiDARStatus>),
+    Subscribe(Subscription),
+    Unsubscribe,
+    GetSchema(Value),
+    RecordingList(Vec<RecordingInfo>),
+    Playback(Option<PlaybackStatus>),
+    Diagnostics(DiagnosticsReport),
+    AdminClients(Vec<ClientInfo>),
+    AdminDisconnect(Uuid),
+    AdminLidarEnable(LiDARInfo),
+    AdminDiscover(DiscoverResult),
+    SiteList(Vec<SiteStatus>),
+    Teaching(TeachingResult),
+    SetWarningArea(SetOutcome<WarningAreaResult>),
+    SetConfig(SetOutcome<SetConfigResult>),
+    ConfigTransaction(ConfigTransactionResult),
+    ParserStats(Vec<ParserStats>),
+    Annotate(AnnotationData),
+    Odometry(OdometrySample),
+    ZoneEvents(Vec<ZoneEvent>),
+}
+
+/// 서버 이벤트 열거형
+///
+/// # Variants
+/// * `PointCloud` - 구독 중인 LiDAR 포인트 클라우드
+/// * `Config` - LiDAR 설정 응답
+/// * `Statistics` - `statistics` 스트림을 구독 중인 LiDAR 의 프레임 통계
+/// * `OccupancyGrid` - `occupancy` 스트림을 구독 중인 LiDAR 의 2D 점유 격자
+/// * `Telemetry` - 구독 중인 LiDAR 의 상태 텔레메트리 샘플
+/// * `DeviceHealth` - LiDAR 진단에서 실패 항목이 발견된 경우의 진단 결과
+/// * `Alert` - 알림 규칙 발생/해제
+/// * `SpoolAvailable` - 구독자가 없는 동안 임시 저장된 세그먼트 목록
+/// * `Quality` - 전송 지연에 따른 전송 품질 변경
+/// * `SiteStatus` - 사이트 온라인/오프라인 변경
+/// * `LidarIdentityChanged` - IP 가 바뀐 LiDAR 의 키 변경 (구독 중인 LiDAR 목록은 서버에서 새 키로 변경)
+/// * `LidarDiscovered` - 새로 검색한 LiDAR 의 장치 정보 (버전/네트워크 소스 정보 조회 결과 포함)
+/// * `TeachingProgress` - `teaching` 요청한 클라이언트에게 보내는 티칭 진행 상황
+/// * `CommandQueued` - 같은 LiDAR 의 다른 설정 명령이 진행 중일 때 요청한 클라이언트에게 보내는 대기 순서
+/// * `Annotation` - 다른 클라이언트가 녹화에 기록한 주석 마커
+/// * `CountSummary` - `processing.counting.summary_interval_ms` 마다 보내는 오늘의 통과선/영역 집계
+/// * `Objects` - `objects` 스트림을 구독 중인 LiDAR 의 확정 물체 위치, 속력, 진행 방향
+/// * `Inference` - 구독 중인 LiDAR 프레임의 ONNX 모델 출력 (`processing.inference`)
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(tag = "command", content = "data", rename_all = "snake_case")]
+pub enum WsEvent<'a> {
+    PointCloud(PointCloudData<'a>),
+    Config(ConfigData<'a>),
+    Statistics(StatisticsData),
+    OccupancyGrid(OccupancyGridData),
+    Telemetry(TelemetrySample),
+    DeviceHealth(DiagnosticsReport),
+    Alert(Alert),
+    SpoolAvailable(SpoolData),
+    Quality(QualityData),
+    SiteStatus(SiteStatus),
+    LidarIdentityChanged(IdentityChange),
+    LidarDiscovered(LiDARInfo),
+    TeachingProgress(TeachingProgress),
+    CommandQueued(CommandQueueStatus),
+    Annotation(AnnotationData),
+    CountSummary(CountRollup),
+    Objects(TrackedObjects),
+    Inference(InferenceResult),
+}
+
+/// 명령으로 태그된 메시지를 명령 이름과 데이터로 분리
+///
+/// # Arguments
+/// * `message` - `command`/`data` 로 태그된 응답 또는 이벤트
+///
+/// # Returns
+/// * `Result<(String, Value), serde_json::Error>` - 성공 시 (명령, 데이터), 실패 시 직렬화 에러
+pub fn split_tagged<T: Serialize>(message: &T) -> Result<(String, Value), serde_json::Error> {
+    let mut value = serde_json::to_value(message)?;
+    let data = value.get_mut("data").map(Value::take).unwrap_or_default();
+    let command = match value.get("command").and_then(Value::as_str) {
+        Some(command) => command.to_string(),
+        None => return Err(serde::ser::Error::custom("Tagged message without command")),
+    };
+    Ok((command, data))
+}
@@ -1,3 +1,27 @@
+pub mod adaptive;
+pub mod bandwidth;
+pub mod chunk;
+pub mod client;
+pub mod config;
+pub mod convention;
+pub mod encoding;
+pub mod framing;
+pub mod input;
+pub mod lanes;
+pub mod message;
+pub mod port;
+pub mod protocol;
+pub mod queue;
+pub mod rest;
+pub mod routing;
+pub mod schema;
 pub mod server;
+pub mod session;
+pub mod stream_token;
+pub mod typescript;
 
+pub use config::{BandwidthConfig, SessionConfig, StreamPreset, StreamTokenConfig, WsServerConfig};
+pub use encoding::{to_json_string, WireEncoding, WireFormat};
+pub use framing::BinaryFraming;
+pub use port::{find_available_port, reserve_port};
 pub use server::WsServer;
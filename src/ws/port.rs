@@ -0,0 +1,56 @@
+use std::net::{SocketAddr, TcpListener};
+
+/// 바인딩을 시도할 포트 목록 (u16 범위를 넘지 않도록 제한)
+fn candidate_ports(start_port: u16, max_attempts: u16) -> impl Iterator<Item = u16> {
+    (start_port..=u16::MAX).take(max_attempts as usize)
+}
+
+/// 사용 가능한 포트를 찾아 바인딩한 채로 반환
+///
+/// # Examples
+/// ```
+/// let Some((port, listener)) = reserve_port(5555, 10) else {
+///     return;
+/// };
+/// ws_server.start(listener).await;
+/// ```
+///
+/// # Arguments
+/// * `start_port` - 시작 포트
+/// * `max_attempts` - 최대 시도 횟수, 시도 시 마다 start_port + 1 을 하여 시도
+///
+/// # Returns
+/// * `Option<(u16, TcpListener)>` - 바인딩한 포트와 리스너, 모든 포트가 사용 중이면 None
+///
+/// # 동작 설명
+/// * 찾은 포트의 리스너를 닫지 않고 그대로 서버에 넘기므로, 확인 후 다시 바인딩하는 사이에
+///   다른 프로세스가 포트를 가져가는 경합이 없음
+pub fn reserve_port(start_port: u16, max_attempts: u16) -> Option<(u16, TcpListener)> {
+    candidate_ports(start_port, max_attempts).find_map(|port| {
+        let addr = SocketAddr::from(([0, 0, 0, 0], port));
+        // 열린 포트 존재 시 다음 포트 시도
+        TcpListener::bind(addr)
+            .ok()
+            .map(|listener| (port, listener))
+    })
+}
+
+/// 사용 가능한 포트 찾기
+///
+/// # Examples
+/// ```
+/// let port: Option<u16> = find_available_port(5555, 10);
+/// ```
+///
+/// # Arguments
+/// * `start_port` - 시작 포트
+/// * `max_attempts` - 최대 시도 횟수, 시도 시 마다 start_port + 1 을 하여 시도
+///
+/// # Returns
+/// * `Option<u16>` - 사용 가능한 포트 번호, 모든 포트가 사용 중이면 None
+///
+/// 확인에 사용한 리스너는 바로 닫으므로 다시 바인딩하기 전에 다른 프로세스가 포트를 가져갈 수 있음.
+/// 서버를 시작할 때는 `reserve_port` 로 받은 리스너를 그대로 사용
+pub fn find_available_port(start_port: u16, max_attempts: u16) -> Option<u16> {
+    reserve_port(start_port, max_attempts).map(|(port, _)| port)
+}
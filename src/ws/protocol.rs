@@ -0,0 +1,392 @@
+use axum::extract::ws::Message;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::ws::chunk::ChunkBody;
+#[cfg(feature = "protobuf")]
+use crate::ws::encoding::WireEncoding;
+use crate::ws::encoding::WireFormat;
+use crate::ws::message::{
+    split_tagged, HelloData, RequestMessage, ResponseMessage, WsEvent, WsResponse,
+};
+
+/// 제어 전용 클라이언트 WebSocket 하위 프로토콜 이름 (`Sec-WebSocket-Protocol: control`)
+///
+/// 이 하위 프로토콜로 연결하거나 `/ws/control` 로 연결한 클라이언트에게는 포인트 클라우드와
+/// 포인트 클라우드에서 만든 데이터 프레임(통계, 점유 격자, 물체)을 보내지 않습니다.
+pub const CONTROL_SUBPROTOCOL: &str = "control";
+
+/// WebSocket 프로토콜 버전 열거형
+///
+/// # Variants
+/// * `V1` - JSON 요청/응답 (`command`, `status`, `data`), hello 없이 연결한 클라이언트의 기본값
+/// * `V2` - 타입이 있는 JSON 봉투 (`type`, `id`, 에러 코드) + LiDAR 데이터 binary 프레임
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProtocolVersion {
+    #[default]
+    V1 = 1,
+    V2 = 2,
+}
+
+impl ProtocolVersion {
+    /// 지원하는 프로토콜 버전 목록
+    pub const SUPPORTED: [u8; 2] = [1, 2];
+
+    pub fn from_u64(version: u64) -> Option<Self> {
+        match version {
+            1 => Some(Self::V1),
+            2 => Some(Self::V2),
+            _ => None,
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// 요청 처리 에러 코드 열거형
+///
+/// # Variants
+/// * `InvalidJson` - JSON 파싱 실패
+/// * `InvalidRequest` - 필수 항목 누락, 잘못된 값 또는 알 수 없는 필드
+/// * `MessageTooLarge` - 최대 메시지 크기 초과
+/// * `UnknownCommand` - 지원하지 않는 명령
+/// * `UnsupportedVersion` - 지원하지 않는 프로토콜 버전
+/// * `Internal` - 서버 내부 에러
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    InvalidJson,
+    InvalidRequest,
+    MessageTooLarge,
+    UnknownCommand,
+    UnsupportedVersion,
+    Internal,
+}
+
+impl ErrorCode {
+    /// 직렬화 이름과 같은 에러 코드 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::InvalidJson => "invalid_json",
+            ErrorCode::InvalidRequest => "invalid_request",
+            ErrorCode::MessageTooLarge => "message_too_large",
+            ErrorCode::UnknownCommand => "unknown_command",
+            ErrorCode::UnsupportedVersion => "unsupported_version",
+            ErrorCode::Internal => "internal",
+        }
+    }
+}
+
+/// v2 메시지 종류 열거형
+///
+/// # Variants
+/// * `Response` - 요청에 대한 정상 응답
+/// * `Error` - 요청에 대한 에러 응답
+/// * `Event` - 서버에서 먼저 보내는 메시지
+/// * `Chunk` - 크기 제한(`ws.chunk_size`)을 넘어 나눠 보낸 응답의 조각
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EnvelopeKind {
+    Response,
+    Error,
+    Event,
+    Chunk,
+}
+
+/// v2 에러 내용 구조체
+///
+/// # Fields
+/// * `code` - 에러 코드
+/// * `message` - 에러 메시지
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ErrorBody {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+/// v2 메시지 봉투 구조체
+///
+/// # Examples
+/// ```json
+/// { "type": "response", "id": 7, "command": "lidar_list", "data": [ ... ] }
+/// { "type": "error", "id": 8, "command": "foo", "error": { "code": "unknown_command", "message": "..." } }
+/// { "type": "event", "command": "config", "data": { ... } }
+/// { "type": "chunk", "id": 9, "command": "teaching", "chunk": { "part": 1, "parts": 3, ... } }
+/// ```
+///
+/// # Fields
+/// * `kind` - 메시지 종류
+/// * `id` - 요청 ID (요청에 포함된 값을 그대로 반환)
+/// * `command` - 명령
+/// * `data` - 응답/이벤트 데이터
+/// * `error` - 에러 내용
+/// * `chunk` - 나눠 보낸 응답의 조각 내용
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Envelope {
+    #[serde(rename = "type")]
+    pub kind: EnvelopeKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+    pub command: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorBody>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk: Option<ChunkBody>,
+}
+
+/// 요청 처리 결과 구조체
+///
+/// # Fields
+/// * `command` - 요청 명령
+/// * `id` - 요청 ID
+/// * `result` - 성공 시 응답, 실패 시 에러 코드와 메시지
+///
+/// # 주요 기능
+/// * 클라이언트의 프로토콜 버전에 맞는 JSON 으로 변환
+#[derive(Debug, Clone)]
+pub struct Reply {
+    pub command: String,
+    pub id: Option<Value>,
+    pub result: Result<WsResponse, (ErrorCode, String)>,
+}
+
+impl Reply {
+    pub fn ok(request: &RequestMessage, response: WsResponse) -> Self {
+        Self {
+            command: request.command.clone(),
+            id: request.id.clone(),
+            result: Ok(response),
+        }
+    }
+
+    pub fn error(request: &RequestMessage, code: ErrorCode, message: &str) -> Self {
+        Self {
+            command: request.command.clone(),
+            id: request.id.clone(),
+            result: Err((code, message.to_string())),
+        }
+    }
+
+    /// 프로토콜 버전에 맞는 JSON 문자열로 변환
+    ///
+    /// # Arguments
+    /// * `version` - 클라이언트 프로토콜 버전
+    ///
+    /// # Returns
+    /// * `Result<String, serde_json::Error>` - 성공 시 JSON 문자열, 실패 시 직렬화 에러
+    pub fn to_json(&self, version: ProtocolVersion) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.outbound(version)?)
+    }
+
+    /// 프로토콜 버전과 전송 형식에 맞는 WebSocket 메시지로 변환
+    ///
+    /// # Arguments
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `format` - 클라이언트 전송 형식 (JSON 숫자 자릿수 포함)
+    ///
+    /// # Returns
+    /// * `Result<Message, String>` - 성공 시 메시지, 실패 시 직렬화 에러 메시지
+    pub fn to_message(
+        &self,
+        version: ProtocolVersion,
+        format: WireFormat,
+    ) -> Result<Message, String> {
+        self.outbound(version)
+            .map_err(|e| e.to_string())?
+            .encode(format)
+    }
+
+    fn outbound(&self, version: ProtocolVersion) -> Result<Outbound, serde_json::Error> {
+        let result = match &self.result {
+            Ok(response) => Ok(split_tagged(response)?),
+            Err(error) => Err(error),
+        };
+
+        match version {
+            ProtocolVersion::V1 => Ok(Outbound::V1(match result {
+                Ok((command, data)) => ResponseMessage::ok(&command, data),
+                Err((_, message)) => ResponseMessage::error(&self.command, message),
+            })),
+            ProtocolVersion::V2 => {
+                let envelope = match result {
+                    Ok((command, data)) => Envelope {
+                        kind: EnvelopeKind::Response,
+                        id: self.id.clone(),
+                        command,
+                        data: Some(data),
+                        error: None,
+                        chunk: None,
+                    },
+                    Err((code, message)) => Envelope {
+                        kind: EnvelopeKind::Error,
+                        id: self.id.clone(),
+                        command: self.command.clone(),
+                        data: None,
+                        error: Some(ErrorBody {
+                            code: *code,
+                            message: message.clone(),
+                        }),
+                        chunk: None,
+                    },
+                };
+                Ok(Outbound::V2(envelope))
+            }
+        }
+    }
+}
+
+/// 프로토콜 버전별 전송 메시지
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Outbound {
+    V1(ResponseMessage),
+    V2(Envelope),
+}
+
+impl Outbound {
+    /// 전송 형식에 맞는 WebSocket 메시지로 변환
+    fn encode(&self, format: WireFormat) -> Result<Message, String> {
+        match format.encoding {
+            #[cfg(feature = "protobuf")]
+            WireEncoding::Protobuf => {
+                use prost::Message as _;
+                Ok(Message::Binary(self.to_proto().encode_to_vec().into()))
+            }
+            _ => format.encode(self),
+        }
+    }
+
+    /// Protobuf `ServerMessage` 로 변환 (v1 은 `status` 로 응답/에러 구분)
+    #[cfg(feature = "protobuf")]
+    fn to_proto(&self) -> crate::proto::ServerMessage {
+        use crate::proto::server_message;
+        match self {
+            Outbound::V1(response) if response.status == "ok" => server_message(
+                "response",
+                None,
+                &response.command,
+                Some(&response.data),
+                None,
+            ),
+            Outbound::V1(response) => server_message(
+                "error",
+                None,
+                &response.command,
+                None,
+                Some(("", response.data.as_str().unwrap_or_default())),
+            ),
+            Outbound::V2(envelope) => {
+                let kind = match envelope.kind {
+                    EnvelopeKind::Response => "response",
+                    EnvelopeKind::Error => "error",
+                    EnvelopeKind::Event => "event",
+                    EnvelopeKind::Chunk => "chunk",
+                };
+                let code = envelope.error.as_ref().map(|error| {
+                    serde_json::to_value(error.code)
+                        .ok()
+                        .and_then(|code| code.as_str().map(str::to_string))
+                        .unwrap_or_default()
+                });
+                server_message(
+                    kind,
+                    envelope.id.as_ref(),
+                    &envelope.command,
+                    envelope.data.as_ref(),
+                    envelope
+                        .error
+                        .as_ref()
+                        .zip(code.as_deref())
+                        .map(|(error, code)| (code, error.message.as_str())),
+                )
+            }
+        }
+    }
+}
+
+/// 서버 이벤트를 프로토콜 버전에 맞는 JSON 문자열로 변환
+///
+/// # Arguments
+/// * `version` - 클라이언트 프로토콜 버전
+/// * `event` - 서버 이벤트
+///
+/// # Returns
+/// * `Result<String, serde_json::Error>` - 성공 시 JSON 문자열, 실패 시 직렬화 에러
+pub fn event_json(version: ProtocolVersion, event: &WsEvent) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&event_outbound(version, event)?)
+}
+
+/// 서버 이벤트를 프로토콜 버전과 전송 형식에 맞는 WebSocket 메시지로 변환
+///
+/// # Arguments
+/// * `version` - 클라이언트 프로토콜 버전
+/// * `format` - 클라이언트 전송 형식 (JSON 숫자 자릿수 포함)
+/// * `event` - 서버 이벤트
+///
+/// # Returns
+/// * `Result<Message, String>` - 성공 시 메시지, 실패 시 직렬화 에러 메시지
+pub fn event_message(
+    version: ProtocolVersion,
+    format: WireFormat,
+    event: &WsEvent,
+) -> Result<Message, String> {
+    event_outbound(version, event)
+        .map_err(|e| e.to_string())?
+        .encode(format)
+}
+
+fn event_outbound(
+    version: ProtocolVersion,
+    event: &WsEvent,
+) -> Result<Outbound, serde_json::Error> {
+    let (command, data) = split_tagged(event)?;
+    Ok(match version {
+        ProtocolVersion::V1 => Outbound::V1(ResponseMessage::ok(&command, data)),
+        ProtocolVersion::V2 => Outbound::V2(Envelope {
+            kind: EnvelopeKind::Event,
+            id: None,
+            command,
+            data: Some(data),
+            error: None,
+            chunk: None,
+        }),
+    })
+}
+
+/// hello 응답 데이터 생성
+///
+/// # Arguments
+/// * `version` - 협상된 프로토콜 버전
+/// * `client_id` - 클라이언트 UUID
+/// * `resumed` - 이전 구독을 복원했는지 여부
+/// * `restored` - 세션에 저장된 구독 설정을 복원했는지 여부
+/// * `server_id` - 서버 ID
+/// * `relay_path` - 이 서버와 상위 서버들의 ID 경로
+///
+/// # Returns
+/// * `HelloData` - 협상된 버전, 지원 버전 목록, 서버 버전, 클라이언트 UUID, 구독/세션 복원 여부, 서버 ID, 릴레이 경로
+pub fn hello_data(
+    version: ProtocolVersion,
+    client_id: Uuid,
+    resumed: bool,
+    restored: bool,
+    server_id: &str,
+    relay_path: Vec<String>,
+) -> HelloData {
+    HelloData {
+        protocol_version: version.as_u8(),
+        supported_versions: ProtocolVersion::SUPPORTED.to_vec(),
+        server_version: env!("CARGO_PKG_VERSION").to_string(),
+        client_id,
+        resumed,
+        restored,
+        server_id: server_id.to_string(),
+        relay_path,
+    }
+}
@@ -0,0 +1,141 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::watch;
+
+use crate::lidar::LiDARKey;
+
+/// 명령 대기 상황 구조체 (`command_queued` 이벤트)
+///
+/// # Fields
+/// * `key` - 명령 대상 LiDAR 키
+/// * `command` - 대기 중인 명령 이름
+/// * `position` - 앞에 남은 명령 수, 0 이면 명령 시작
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct CommandQueueStatus {
+    pub key: LiDARKey,
+    pub command: String,
+    pub position: usize,
+}
+
+/// LiDAR 별 명령 대기열
+struct Lane {
+    tickets: VecDeque<u64>,
+    changed: watch::Sender<()>,
+}
+
+/// LiDAR 별 설정 명령 대기열
+///
+/// # Examples
+/// ```
+/// let queue = CommandQueue::new(4);
+/// let mut turn = queue.enter(key)?;
+/// while turn.position() > 0 {
+///     notify(turn.position());
+///     turn.changed().await;
+/// }
+/// // 설정 명령 송신 및 응답 대기
+/// drop(turn);
+/// ```
+///
+/// # 동작 설명
+/// * LiDAR 마다 한 번에 하나의 설정 명령만 진행하여 ACK/응답이 다른 요청과 섞이지 않도록 함
+/// * 먼저 들어온 요청부터 진행하고, 진행 중인 요청 외에 `max_pending` 개까지 대기
+/// * 차례(`CommandTurn`)를 drop 하면 다음 요청 진행 (대기 중 연결이 끊긴 요청도 대기열에서 제거)
+#[derive(Clone)]
+pub struct CommandQueue {
+    lanes: Arc<Mutex<HashMap<LiDARKey, Lane>>>,
+    next_ticket: Arc<AtomicU64>,
+    max_pending: usize,
+}
+
+impl CommandQueue {
+    pub fn new(max_pending: usize) -> Self {
+        Self {
+            lanes: Arc::new(Mutex::new(HashMap::new())),
+            next_ticket: Arc::new(AtomicU64::new(0)),
+            max_pending,
+        }
+    }
+
+    /// 대기열에 요청 추가
+    ///
+    /// # Arguments
+    /// * `key` - 명령 대상 LiDAR 키
+    ///
+    /// # Returns
+    /// * `Result<CommandTurn, String>` - 성공 시 차례, 대기 중인 요청이 `max_pending` 개면 에러 메시지
+    pub fn enter(&self, key: LiDARKey) -> Result<CommandTurn, String> {
+        let ticket = self.next_ticket.fetch_add(1, Ordering::Relaxed);
+        let mut lanes = self.lanes.lock().unwrap();
+        let lane = lanes.entry(key).or_insert_with(|| Lane {
+            tickets: VecDeque::new(),
+            changed: watch::channel(()).0,
+        });
+        let pending = lane.tickets.len().saturating_sub(1);
+        if !lane.tickets.is_empty() && pending >= self.max_pending {
+            return Err(format!(
+                "LiDAR {}:{} is busy ({} commands pending)",
+                key.ip, key.port, pending
+            ));
+        }
+        lane.tickets.push_back(ticket);
+        Ok(CommandTurn {
+            queue: self.clone(),
+            key,
+            ticket,
+            changed: lane.changed.subscribe(),
+        })
+    }
+
+    fn position(&self, key: &LiDARKey, ticket: u64) -> usize {
+        let lanes = self.lanes.lock().unwrap();
+        lanes
+            .get(key)
+            .and_then(|lane| lane.tickets.iter().position(|&t| t == ticket))
+            .unwrap_or(0)
+    }
+
+    fn leave(&self, key: &LiDARKey, ticket: u64) {
+        let mut lanes = self.lanes.lock().unwrap();
+        let Some(lane) = lanes.get_mut(key) else {
+            return;
+        };
+        lane.tickets.retain(|&t| t != ticket);
+        if lane.tickets.is_empty() {
+            lanes.remove(key);
+        } else {
+            lane.changed.send_replace(());
+        }
+    }
+}
+
+/// 설정 명령 대기열의 차례
+///
+/// drop 하면 대기열에서 제거되고 다음 요청이 진행됩니다.
+pub struct CommandTurn {
+    queue: CommandQueue,
+    key: LiDARKey,
+    ticket: u64,
+    changed: watch::Receiver<()>,
+}
+
+impl CommandTurn {
+    /// 앞에 남은 명령 수, 0 이면 명령을 보낼 차례
+    pub fn position(&self) -> usize {
+        self.queue.position(&self.key, self.ticket)
+    }
+
+    /// 대기열이 바뀔 때까지 대기
+    pub async fn changed(&mut self) {
+        _ = self.changed.changed().await;
+    }
+}
+
+impl Drop for CommandTurn {
+    fn drop(&mut self) {
+        self.queue.leave(&self.key, self.ticket);
+    }
+}
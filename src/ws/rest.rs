@@ -0,0 +1,1080 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::Json;
+use serde::Deserialize;
+use serde_json::Value;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::alert::{EventQuery, NotificationResult, ZoneEvent};
+use crate::common::time::unix_time_ms;
+use crate::health::HealthReport;
+use crate::lidar::kanavi_mobility::{
+    DiagnosticsReport, SetConfigRequest, SetConfigResult, SetOutcome,
+};
+use crate::lidar::{
+    CompanyInfo, LiDARInfo, LiDARKey, LiDARStatus, PointPoolStats, RejectionStats,
+    SenderMemoryStats,
+};
+use crate::logging::{LogFile, LogRotation};
+use crate::processing::{
+    AlignRequest, Alignment, CalibrationResult, CalibrationStore, CaptureRequest, CountRollup,
+    CountTotals, Extrinsic, OdometrySample, Pose, SnapshotInfo, TfFormat, TfFrame, TfTree,
+};
+use crate::record::format::{
+    list_recordings, manifest_path, read_manifest, recording_path, Manifest, Marker, RecordingInfo,
+};
+use crate::record::PlaybackStatus;
+use crate::site::{SiteMemberStatus, SiteStatus};
+use crate::telemetry::TelemetrySample;
+use crate::udp::socket::MulticastJoin;
+use crate::ws::client::ClientInfo;
+use crate::ws::message::{DiscoverRequest, DiscoverResult, PlaybackRequest};
+use crate::ws::protocol::ErrorCode;
+use crate::ws::schema::schemas;
+use crate::ws::server::AppState;
+use crate::ws::stream_token::{StreamGrant, StreamRequest};
+use crate::ws::typescript::type_definitions;
+
+/// REST API OpenAPI 문서
+///
+/// # 주요 기능
+/// * REST 엔드포인트, 파라미터, 응답 모델 정의
+/// * `/api-doc` 에서 OpenAPI JSON 으로 제공 (Swagger UI 등에서 사용)
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "LiDAR Server",
+        description = "LiDAR UDP/WebSocket relay REST API"
+    ),
+    paths(
+        get_healthz,
+        get_readyz,
+        get_schema,
+        get_types,
+        get_api_doc,
+        get_recordings,
+        get_recording_markers,
+        get_playback,
+        post_playback,
+        post_diagnostics,
+        get_telemetry,
+        get_metrics,
+        post_lidar_command,
+        post_test_notification,
+        get_events,
+        get_clients,
+        delete_client,
+        post_discover,
+        post_log_rotate,
+        post_stream,
+        get_sites,
+        get_lidars,
+        get_udp_rejected,
+        get_udp_memory,
+        get_counts,
+        post_calibration_snapshot,
+        post_calibration_align,
+        get_calibration_extrinsics,
+        put_calibration_extrinsic,
+        get_tf,
+        get_odometry,
+        post_odometry
+    ),
+    components(schemas(
+        HealthReport,
+        MulticastJoin,
+        RecordingInfo,
+        Manifest,
+        Marker,
+        PlaybackRequest,
+        PlaybackStatus,
+        LiDARKey,
+        DiagnosticsReport,
+        TelemetrySample,
+        LiDARCommand,
+        NotificationResult,
+        ZoneEvent,
+        ClientInfo,
+        DiscoverRequest,
+        DiscoverResult,
+        LogRotation,
+        StreamRequest,
+        StreamGrant,
+        SiteStatus,
+        SiteMemberStatus,
+        CompanyInfo,
+        LiDARInfo,
+        LiDARStatus,
+        RejectionStats,
+        SenderMemoryStats,
+        PointPoolStats,
+        CountRollup,
+        CountTotals,
+        CaptureRequest,
+        SnapshotInfo,
+        AlignRequest,
+        Alignment,
+        CalibrationResult,
+        Extrinsic,
+        TfFormat,
+        TfFrame,
+        TfTree,
+        OdometrySample,
+        Pose
+    ))
+)]
+pub struct ApiDoc;
+
+/// 활성 상태 엔드포인트(/healthz) 처리
+///
+/// # Returns
+/// * `Json<HealthReport>` - 서버 시작 상태 (응답하면 항상 200)
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    responses(
+        (status = 200, description = "Server is alive", body = HealthReport)
+    )
+)]
+pub async fn get_healthz(State(state): State<Arc<AppState>>) -> Json<HealthReport> {
+    Json(state.health.report())
+}
+
+/// 준비 상태 엔드포인트(/readyz) 처리
+///
+/// # Returns
+/// * `(StatusCode, Json<HealthReport>)` - 서버 시작 상태, 준비되지 않았으면 503
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    responses(
+        (status = 200, description = "UDP sockets bound, multicast joined, WebSocket accepting and config loaded", body = HealthReport),
+        (status = 503, description = "Server not ready", body = HealthReport)
+    )
+)]
+pub async fn get_readyz(State(state): State<Arc<AppState>>) -> (StatusCode, Json<HealthReport>) {
+    let report = state.health.report();
+    let status = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report))
+}
+
+/// JSON Schema 엔드포인트(/schema) 처리
+///
+/// # Returns
+/// * `Json<Value>` - WebSocket 메시지 및 설정 JSON Schema
+#[utoipa::path(
+    get,
+    path = "/schema",
+    responses(
+        (status = 200, description = "JSON Schemas of WebSocket messages, events and server config", body = Object)
+    )
+)]
+pub async fn get_schema() -> Json<Value> {
+    Json(schemas())
+}
+
+/// TypeScript 타입 정의 엔드포인트(/types.d.ts) 처리
+///
+/// # Returns
+/// * `([(header::HeaderName, &str); 1], String)` - WebSocket/REST 메시지 TypeScript 타입 정의
+#[utoipa::path(
+    get,
+    path = "/types.d.ts",
+    responses(
+        (status = 200, description = "TypeScript definitions of WebSocket/REST messages, generated from the JSON Schemas", body = String, content_type = "application/typescript")
+    )
+)]
+pub async fn get_types() -> ([(header::HeaderName, &'static str); 1], String) {
+    (
+        [(
+            header::CONTENT_TYPE,
+            "application/typescript; charset=utf-8",
+        )],
+        type_definitions(),
+    )
+}
+
+/// OpenAPI 문서 엔드포인트(/api-doc) 처리
+///
+/// # Returns
+/// * `Json<utoipa::openapi::OpenApi>` - REST API OpenAPI 문서
+#[utoipa::path(
+    get,
+    path = "/api-doc",
+    responses(
+        (status = 200, description = "OpenAPI document of the REST API", body = Object)
+    )
+)]
+pub async fn get_api_doc() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+/// 녹화 파일 목록 엔드포인트(/recordings) 처리
+///
+/// # Returns
+/// * `Result<Json<Vec<RecordingInfo>>, (StatusCode, String)>` - 성공 시 녹화 파일 목록, 실패 시 500 에러
+#[utoipa::path(
+    get,
+    path = "/recordings",
+    responses(
+        (status = 200, description = "Recordings available for playback", body = [RecordingInfo]),
+        (status = 500, description = "Failed to read the recording directory", body = String)
+    )
+)]
+pub async fn get_recordings(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<RecordingInfo>>, (StatusCode, String)> {
+    let dir = state.player.dir().to_path_buf();
+    match tokio::task::spawn_blocking(move || list_recordings(&dir)).await {
+        Ok(Ok(recordings)) => Ok(Json(recordings)),
+        Ok(Err(e)) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// 녹화 주석 마커 엔드포인트(/recordings/{name}/markers) 처리
+///
+/// # Arguments
+/// * `name` - 녹화 파일 이름
+///
+/// # Returns
+/// * `Result<Json<Manifest>, (StatusCode, String)>` - 성공 시 녹화 목록 파일(마커 목록 포함),
+///   잘못된 이름이면 400, 목록 파일이 없으면 404, 읽기 실패 시 500 에러
+#[utoipa::path(
+    get,
+    path = "/recordings/{name}/markers",
+    params(
+        ("name" = String, Path, description = "Recording file name")
+    ),
+    responses(
+        (status = 200, description = "Recording manifest with annotation markers", body = Manifest),
+        (status = 400, description = "Invalid recording name", body = String),
+        (status = 404, description = "Recording manifest not found", body = String),
+        (status = 500, description = "Failed to read the recording manifest", body = String)
+    )
+)]
+pub async fn get_recording_markers(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<Json<Manifest>, (StatusCode, String)> {
+    let path =
+        recording_path(state.player.dir(), &name).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    match tokio::task::spawn_blocking(move || read_manifest(&manifest_path(&path))).await {
+        Ok(Ok(manifest)) => Ok(Json(manifest)),
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            Err((StatusCode::NOT_FOUND, format!("No manifest for {}", name)))
+        }
+        Ok(Err(e)) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// 재생 상태 엔드포인트(GET /playback) 처리
+///
+/// # Returns
+/// * `Result<Json<Option<PlaybackStatus>>, (StatusCode, String)>` - 성공 시 재생 상태 (재생한 적이 없으면 null), 실패 시 500 에러
+#[utoipa::path(
+    get,
+    path = "/playback",
+    responses(
+        (status = 200, description = "Playback status, or null if nothing has been played", body = PlaybackStatus),
+        (status = 500, description = "Playback task failed", body = String)
+    )
+)]
+pub async fn get_playback(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Option<PlaybackStatus>>, (StatusCode, String)> {
+    playback_response(state.control_playback(PlaybackRequest::Status).await)
+}
+
+/// 재생 제어 엔드포인트(POST /playback) 처리
+///
+/// # Arguments
+/// * `playback` - 재생 제어 요청 (JSON 본문, WebSocket `playback` 명령의 `data` 와 같은 형식)
+///
+/// # Returns
+/// * `Result<Json<Option<PlaybackStatus>>, (StatusCode, String)>` - 성공 시 재생 상태,
+///   없는 녹화 파일, 재생 중이 아닐 때의 제어, 잘못된 속도 등 실패 시 400 에러
+///
+/// WebSocket `playback` 명령과 같은 재생기를 제어 (`AppState::control_playback`)
+#[utoipa::path(
+    post,
+    path = "/playback",
+    request_body = PlaybackRequest,
+    responses(
+        (status = 200, description = "Playback status after the action", body = PlaybackStatus),
+        (status = 400, description = "Unknown recording, nothing playing or invalid speed", body = String),
+        (status = 500, description = "Playback task failed", body = String)
+    )
+)]
+pub async fn post_playback(
+    State(state): State<Arc<AppState>>,
+    Json(playback): Json<PlaybackRequest>,
+) -> Result<Json<Option<PlaybackStatus>>, (StatusCode, String)> {
+    playback_response(state.control_playback(playback).await)
+}
+
+fn playback_response(
+    result: Result<Option<PlaybackStatus>, (ErrorCode, String)>,
+) -> Result<Json<Option<PlaybackStatus>>, (StatusCode, String)> {
+    result.map(Json).map_err(|(code, e)| match code {
+        ErrorCode::Internal => (StatusCode::INTERNAL_SERVER_ERROR, e),
+        _ => (StatusCode::BAD_REQUEST, e),
+    })
+}
+
+/// LiDAR 진단 엔드포인트(/diagnostics) 처리
+///
+/// # Arguments
+/// * `key` - 진단할 LiDAR 키 (JSON 본문)
+///
+/// # Returns
+/// * `Result<Json<DiagnosticsReport>, (StatusCode, String)>` - 성공 시 진단 결과, 등록되지 않은 LiDAR 등 실패 시 400 에러
+#[utoipa::path(
+    post,
+    path = "/diagnostics",
+    request_body = LiDARKey,
+    responses(
+        (status = 200, description = "Diagnostics report of the LiDAR", body = DiagnosticsReport),
+        (status = 400, description = "Unknown or unsupported LiDAR", body = String)
+    )
+)]
+pub async fn post_diagnostics(
+    State(state): State<Arc<AppState>>,
+    Json(key): Json<LiDARKey>,
+) -> Result<Json<DiagnosticsReport>, (StatusCode, String)> {
+    state
+        .diagnose(key)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
+
+/// LiDAR 설정 명령 요청 본문
+///
+/// # Examples
+/// ```json
+/// { "param": 160, "data": [3] }
+/// ```
+///
+/// # Fields
+/// * `param` - 설정 파라미터 (`SET_PARAMS` 중 하나)
+/// * `data` - 설정 데이터 (byte)
+/// * `validate_only` - true 면 검증과 프레임 생성만 하고 LiDAR 로 보내지 않음 (기본값 false)
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LiDARCommand {
+    pub param: u8,
+    pub data: Vec<u8>,
+    #[serde(default)]
+    pub validate_only: bool,
+}
+
+/// LiDAR 설정 명령 엔드포인트(/lidars/{ip}/{port}/{product_line}/{lidar_id}/command) 처리
+///
+/// # Arguments
+/// * `ip`, `port`, `product_line`, `lidar_id` - LiDAR 키
+/// * `command` - 설정 파라미터와 데이터 (JSON 본문)
+///
+/// # Returns
+/// * `Result<Json<SetOutcome<SetConfigResult>>, (StatusCode, String)>` - 성공 시 WebSocket `set_config` 응답과 같은 결과,
+///   지원하지 않는 파라미터, 잘못된 데이터 길이, 등록되지 않은 LiDAR, 대기열 초과, NAK 또는 제한 시간 초과 시 400 에러
+///
+/// WebSocket `set_config` 명령과 같은 LiDAR 별 명령 대기열과 ACK/설정 확인 처리를 사용 (`AppState::lidar_command`)
+#[utoipa::path(
+    post,
+    path = "/lidars/{ip}/{port}/{product_line}/{lidar_id}/command",
+    params(
+        ("ip" = String, Path, description = "LiDAR key IP address"),
+        ("port" = u16, Path, description = "LiDAR key port"),
+        ("product_line" = u8, Path, description = "LiDAR product line"),
+        ("lidar_id" = u8, Path, description = "LiDAR ID")
+    ),
+    request_body = LiDARCommand,
+    responses(
+        (status = 200, description = "Readback and verification of the applied setting, or the frame that would be sent (validate_only)", body = Object),
+        (status = 400, description = "Invalid command, unknown LiDAR, busy queue, NAK or timeout", body = String)
+    )
+)]
+pub async fn post_lidar_command(
+    State(state): State<Arc<AppState>>,
+    Path((ip, port, product_line, lidar_id)): Path<(Ipv4Addr, u16, u8, u8)>,
+    Json(command): Json<LiDARCommand>,
+) -> Result<Json<SetOutcome<SetConfigResult>>, (StatusCode, String)> {
+    let request = SetConfigRequest {
+        lidar: LiDARKey::new(ip, port, product_line, lidar_id),
+        param: command.param,
+        data: command.data,
+        validate_only: command.validate_only,
+    };
+    state
+        .lidar_command(&request)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
+
+/// 텔레메트리 조회 파라미터
+///
+/// # Fields
+/// * `last` - 최대 샘플 수 (기본값: 100)
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct TelemetryQuery {
+    pub last: Option<usize>,
+}
+
+/// LiDAR 텔레메트리 엔드포인트(/lidars/{ip}/{port}/{product_line}/{lidar_id}/telemetry) 처리
+///
+/// # Arguments
+/// * `ip`, `port`, `product_line`, `lidar_id` - LiDAR 키
+/// * `last` - 최대 샘플 수 (기본값: 100)
+///
+/// # Returns
+/// * `Result<Json<Vec<TelemetrySample>>, (StatusCode, String)>` - 성공 시 오래된 순 샘플 목록, 샘플이 없으면 404 에러
+#[utoipa::path(
+    get,
+    path = "/lidars/{ip}/{port}/{product_line}/{lidar_id}/telemetry",
+    params(
+        ("ip" = String, Path, description = "LiDAR key IP address"),
+        ("port" = u16, Path, description = "LiDAR key port"),
+        ("product_line" = u8, Path, description = "LiDAR product line"),
+        ("lidar_id" = u8, Path, description = "LiDAR ID"),
+        TelemetryQuery
+    ),
+    responses(
+        (status = 200, description = "Recent telemetry samples, oldest first", body = [TelemetrySample]),
+        (status = 404, description = "No telemetry for the LiDAR", body = String)
+    )
+)]
+pub async fn get_telemetry(
+    State(state): State<Arc<AppState>>,
+    Path((ip, port, product_line, lidar_id)): Path<(Ipv4Addr, u16, u8, u8)>,
+    Query(query): Query<TelemetryQuery>,
+) -> Result<Json<Vec<TelemetrySample>>, (StatusCode, String)> {
+    let key = LiDARKey::new(ip, port, product_line, lidar_id);
+    state
+        .telemetry
+        .history(&key, query.last.unwrap_or(100))
+        .await
+        .map(Json)
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                format!("No telemetry for {}:{}", key.ip, key.port),
+            )
+        })
+}
+
+/// 메트릭 이력 조회 파라미터
+///
+/// # Fields
+/// * `from` - 조회 시작 시각 (Unix time, ms, 기본값: `to` 의 24시간 전)
+/// * `to` - 조회 종료 시각 (Unix time, ms, 기본값: 현재)
+/// * `step_ms` - 집계 간격 (ms, 없으면 저장된 샘플 그대로 반환)
+/// * `limit` - 최대 샘플 수 (기본값: 10000, 넘으면 최근 샘플만 반환)
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct MetricsQuery {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub step_ms: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+/// LiDAR 메트릭 이력 엔드포인트(/lidars/{ip}/{port}/{product_line}/{lidar_id}/metrics) 처리
+///
+/// # Arguments
+/// * `ip`, `port`, `product_line`, `lidar_id` - LiDAR 키
+/// * `from`, `to`, `step_ms`, `limit` - 조회 기간, 집계 간격, 최대 샘플 수
+///
+/// # Returns
+/// * `Result<Json<Vec<TelemetrySample>>, (StatusCode, String)>` - 성공 시 오래된 순 샘플 목록, 메트릭 보관 미사용이면 404, 읽기 실패 시 500 에러
+#[utoipa::path(
+    get,
+    path = "/lidars/{ip}/{port}/{product_line}/{lidar_id}/metrics",
+    params(
+        ("ip" = String, Path, description = "LiDAR key IP address"),
+        ("port" = u16, Path, description = "LiDAR key port"),
+        ("product_line" = u8, Path, description = "LiDAR product line"),
+        ("lidar_id" = u8, Path, description = "LiDAR ID"),
+        MetricsQuery
+    ),
+    responses(
+        (status = 200, description = "Stored metric samples in the range, oldest first", body = [TelemetrySample]),
+        (status = 400, description = "Invalid range", body = String),
+        (status = 404, description = "Metric history disabled", body = String),
+        (status = 500, description = "Failed to read metric files", body = String)
+    )
+)]
+pub async fn get_metrics(
+    State(state): State<Arc<AppState>>,
+    Path((ip, port, product_line, lidar_id)): Path<(Ipv4Addr, u16, u8, u8)>,
+    Query(query): Query<MetricsQuery>,
+) -> Result<Json<Vec<TelemetrySample>>, (StatusCode, String)> {
+    let Some(metrics) = &state.metrics else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Metric history is disabled".to_string(),
+        ));
+    };
+    let to = query.to.unwrap_or_else(unix_time_ms);
+    let from = query.from.unwrap_or(to.saturating_sub(24 * 60 * 60 * 1000));
+    if from > to {
+        return Err((StatusCode::BAD_REQUEST, "from is after to".to_string()));
+    }
+    let key = LiDARKey::new(ip, port, product_line, lidar_id);
+    metrics
+        .query(key, from, to, query.step_ms, query.limit.unwrap_or(10_000))
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))
+}
+
+/// 테스트 알림 파라미터
+///
+/// # Fields
+/// * `sink` - 전송할 알림 채널 이름 (없으면 모든 알림 채널)
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct TestNotificationQuery {
+    pub sink: Option<String>,
+}
+
+/// 테스트 알림 엔드포인트(/alerts/test) 처리
+///
+/// # Arguments
+/// * `sink` - 전송할 알림 채널 이름 (없으면 모든 알림 채널)
+///
+/// # Returns
+/// * `Result<Json<Vec<NotificationResult>>, (StatusCode, String)>` - 성공 시 채널별 전송 결과, 알림 미사용 또는 알 수 없는 채널이면 400 에러
+#[utoipa::path(
+    post,
+    path = "/alerts/test",
+    params(TestNotificationQuery),
+    responses(
+        (status = 200, description = "Delivery result of the test notification per sink", body = [NotificationResult]),
+        (status = 400, description = "Alerting disabled or unknown sink", body = String)
+    )
+)]
+pub async fn post_test_notification(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<TestNotificationQuery>,
+) -> Result<Json<Vec<NotificationResult>>, (StatusCode, String)> {
+    let Some(alerts) = &state.alerts else {
+        return Err((StatusCode::BAD_REQUEST, "Alerting is disabled".to_string()));
+    };
+    alerts
+        .test_notification(query.sink.as_deref())
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
+
+/// 영역 이벤트 이력 엔드포인트(/events) 처리
+///
+/// # Arguments
+/// * `from`, `to` - 조회 기간 (Unix time, ms)
+/// * `sensor` - LiDAR 주소 (`ip` 또는 `ip:port`)
+/// * `zone` - 영역 이름 (`zone_intrusion` 규칙 이름)
+/// * `limit` - 최대 이벤트 수
+///
+/// # Returns
+/// * `Result<Json<Vec<ZoneEvent>>, (StatusCode, String)>` - 성공 시 오래된 순 이벤트 목록, 조건이 잘못되면 400,
+///   이벤트 보관 미사용이면 404, 읽기 실패 시 500 에러
+#[utoipa::path(
+    get,
+    path = "/events",
+    params(EventQuery),
+    responses(
+        (status = 200, description = "Stored zone intrusion events in the range, oldest first", body = [ZoneEvent]),
+        (status = 400, description = "Invalid range or sensor", body = String),
+        (status = 404, description = "Zone event history disabled", body = String),
+        (status = 500, description = "Failed to read event files", body = String)
+    )
+)]
+pub async fn get_events(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<EventQuery>,
+) -> Result<Json<Vec<ZoneEvent>>, (StatusCode, String)> {
+    let Some(events) = &state.events else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Zone event history is disabled".to_string(),
+        ));
+    };
+    query
+        .range()
+        .and_then(|_| query.sensor_filter())
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    events
+        .query(&query)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))
+}
+
+/// 클라이언트 목록 엔드포인트(/admin/clients) 처리
+///
+/// # Returns
+/// * `Json<Vec<ClientInfo>>` - 연결 시각 순 클라이언트 정보
+#[utoipa::path(
+    get,
+    path = "/admin/clients",
+    responses(
+        (status = 200, description = "Connected WebSocket clients", body = [ClientInfo])
+    )
+)]
+pub async fn get_clients(State(state): State<Arc<AppState>>) -> Json<Vec<ClientInfo>> {
+    Json(state.client_list().await)
+}
+
+/// 클라이언트 강제 연결 종료 엔드포인트(/admin/clients/{id}) 처리
+///
+/// # Arguments
+/// * `id` - 연결을 끊을 클라이언트 UUID
+///
+/// # Returns
+/// * `Result<StatusCode, (StatusCode, String)>` - 성공 시 204, 연결되지 않은 클라이언트이면 404 에러
+#[utoipa::path(
+    delete,
+    path = "/admin/clients/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Client UUID")
+    ),
+    responses(
+        (status = 204, description = "Client disconnected"),
+        (status = 404, description = "Client not found", body = String)
+    )
+)]
+pub async fn delete_client(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    state
+        .disconnect_client(id)
+        .await
+        .map(|()| StatusCode::NO_CONTENT)
+        .map_err(|e| (StatusCode::NOT_FOUND, e))
+}
+
+/// 장치 검색 엔드포인트(/admin/discover) 처리
+///
+/// # Arguments
+/// * `request` - 검색 요청 (JSON 본문: 응답 대기 시간, 생략 가능)
+///
+/// # Returns
+/// * `Result<Json<DiscoverResult>, (StatusCode, String)>` - 성공 시 대기 시간 동안 새로 찾은 LiDAR 와 전체 LiDAR 수,
+///   대기 시간이 잘못되었으면 400 에러
+#[utoipa::path(
+    post,
+    path = "/admin/discover",
+    request_body = DiscoverRequest,
+    responses(
+        (status = 200, description = "LiDARs found by an immediate discovery sweep", body = DiscoverResult),
+        (status = 400, description = "Invalid timeout", body = String)
+    )
+)]
+pub async fn post_discover(
+    State(state): State<Arc<AppState>>,
+    request: Option<Json<DiscoverRequest>>,
+) -> Result<Json<DiscoverResult>, (StatusCode, String)> {
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+    state
+        .discover(&request)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
+
+/// 로그 파일 회전 엔드포인트(/admin/logs/rotate) 처리
+///
+/// # Returns
+/// * `Result<Json<LogRotation>, (StatusCode, String)>` - 성공 시 회전 결과, 파일 로그를 사용하지 않으면 404 에러,
+///   동기화/회전에 실패하면 500 에러
+///
+/// # 동작 설명
+/// * 현재 로그 파일을 디스크에 동기화(fsync)한 뒤 보관 파일로 회전하고, 보관 개수/전체 크기 제한을 넘는 오래된 파일 삭제
+#[utoipa::path(
+    post,
+    path = "/admin/logs/rotate",
+    responses(
+        (status = 200, description = "Log file flushed and rotated", body = LogRotation),
+        (status = 404, description = "File logging disabled", body = String),
+        (status = 500, description = "Failed to flush or rotate the log file", body = String)
+    )
+)]
+pub async fn post_log_rotate() -> Result<Json<LogRotation>, (StatusCode, String)> {
+    let log_file = LogFile::global().ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            "File logging is disabled".to_string(),
+        )
+    })?;
+    // 파일 동기화와 이름 변경은 블로킹 작업
+    let rotation = tokio::task::spawn_blocking(|| log_file.rotate())
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+    tracing::info!(
+        "Log rotated on request: {:?} ({} old files removed)",
+        rotation.rotated,
+        rotation.removed.len()
+    );
+    Ok(Json(rotation))
+}
+
+/// 사이트 상태 엔드포인트(/sites) 처리
+///
+/// # Returns
+/// * `Json<Vec<SiteStatus>>` - 설정 순서대로 사이트 상태
+#[utoipa::path(
+    get,
+    path = "/sites",
+    responses(
+        (status = 200, description = "Aggregate status of configured sites", body = [SiteStatus])
+    )
+)]
+pub async fn get_sites(State(state): State<Arc<AppState>>) -> Json<Vec<SiteStatus>> {
+    let lidars = state.registry.list().await;
+    Json(state.sites.status(&lidars))
+}
+
+/// 스트림 URL 발급 엔드포인트(/streams) 처리
+///
+/// # Arguments
+/// * `request` - 발급 요청 (JSON 본문: LiDAR 키, 구독 설정 이름, 유효 시간)
+///
+/// # Returns
+/// * `Result<Json<StreamGrant>, (StatusCode, String)>` - 성공 시 한 번만 연결할 수 있는 `/ws/stream/{token}` URL,
+///   발급 미사용 시 404 에러, 등록되지 않은 LiDAR 등 잘못된 요청이면 400 에러
+#[utoipa::path(
+    post,
+    path = "/streams",
+    request_body = StreamRequest,
+    responses(
+        (status = 200, description = "One-shot stream URL bound to the LiDAR and preset", body = StreamGrant),
+        (status = 400, description = "Unknown LiDAR or preset, invalid ttl or too many pending tokens", body = String),
+        (status = 404, description = "Stream URLs disabled", body = String)
+    )
+)]
+pub async fn post_stream(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<StreamRequest>,
+) -> Result<Json<StreamGrant>, (StatusCode, String)> {
+    let Some(tokens) = &state.stream_tokens else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Stream URLs are disabled".to_string(),
+        ));
+    };
+    state
+        .mint_stream(tokens, request)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
+
+/// LiDAR 장치 목록 엔드포인트(/lidars) 처리
+///
+/// # Returns
+/// * `Json<Vec<LiDARStatus>>` - `lidar_list` 응답과 같은 장치 정보와 현재 동작 상태 목록
+#[utoipa::path(
+    get,
+    path = "/lidars",
+    responses(
+        (status = 200, description = "Discovered LiDARs with first/last seen time, frame rate, firmware version, motor speed and online state", body = [LiDARStatus])
+    )
+)]
+pub async fn get_lidars(State(state): State<Arc<AppState>>) -> Json<Vec<LiDARStatus>> {
+    Json(state.lidar_status().await)
+}
+
+/// 송신 주소 필터 거부 통계 엔드포인트(/udp/rejected) 처리
+///
+/// # Returns
+/// * `Json<RejectionStats>` - `udp.source_filter` 로 버린 데이터그램 수 (거부 이유별)
+#[utoipa::path(
+    get,
+    path = "/udp/rejected",
+    responses(
+        (status = 200, description = "Datagrams rejected by the UDP source filter", body = RejectionStats)
+    )
+)]
+pub async fn get_udp_rejected(State(state): State<Arc<AppState>>) -> Json<RejectionStats> {
+    Json(state.parse_stats.rejected())
+}
+
+/// 송신 주소별 상태 메모리 엔드포인트(/udp/memory) 처리
+///
+/// # Returns
+/// * `Json<SenderMemoryStats>` - 파싱 통계, 중복 제거 기록, 파서 버퍼의 메모리 사용량과 정리 횟수
+#[utoipa::path(
+    get,
+    path = "/udp/memory",
+    responses(
+        (status = 200, description = "Memory held by per-sender UDP ingest state", body = SenderMemoryStats)
+    )
+)]
+pub async fn get_udp_memory(State(state): State<Arc<AppState>>) -> Json<SenderMemoryStats> {
+    Json(state.parse_stats.memory().await)
+}
+
+/// 통과 횟수 조회 파라미터
+///
+/// # Fields
+/// * `day` - 날짜 (Unix time 기준 일 수, UTC, 없으면 오늘)
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct CountsQuery {
+    pub day: Option<u64>,
+}
+
+/// 통과 횟수 엔드포인트(/counts) 처리
+///
+/// # Arguments
+/// * `day` - 날짜 (Unix time 기준 일 수, UTC, 없으면 오늘)
+///
+/// # Returns
+/// * `Result<Json<CountRollup>, (StatusCode, String)>` - 성공 시 그날 통과선/영역 집계, 집계 미사용이거나
+///   저장된 집계가 없으면 404, 읽기 실패 시 500 에러
+#[utoipa::path(
+    get,
+    path = "/counts",
+    params(CountsQuery),
+    responses(
+        (status = 200, description = "Tripwire/zone counts per size bucket for the day", body = CountRollup),
+        (status = 404, description = "Counting disabled or no counts stored for the day", body = String),
+        (status = 500, description = "Failed to read count files", body = String)
+    )
+)]
+pub async fn get_counts(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<CountsQuery>,
+) -> Result<Json<CountRollup>, (StatusCode, String)> {
+    let Some(counts) = &state.counts else {
+        return Err((StatusCode::NOT_FOUND, "Counting is disabled".to_string()));
+    };
+    match counts.query(query.day).await {
+        Ok(Some(rollup)) => Ok(Json(rollup)),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            "No counts stored for the day".to_string(),
+        )),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e)),
+    }
+}
+
+/// 캘리브레이션 사용 여부 확인
+fn calibration(state: &AppState) -> Result<&CalibrationStore, (StatusCode, String)> {
+    state
+        .calibration
+        .as_deref()
+        .ok_or((StatusCode::NOT_FOUND, "Calibration is disabled".to_string()))
+}
+
+/// 캘리브레이션 스냅샷 수집 엔드포인트(/calibration/snapshots) 처리
+///
+/// # Arguments
+/// * `request` - 설치 자세를 추정할 LiDAR(`source`)와 기준 LiDAR(`target`) (JSON 본문)
+///
+/// # Returns
+/// * `Result<Json<SnapshotInfo>, (StatusCode, String)>` - 성공 시 `capture_ms` 동안 수집한 스냅샷 정보,
+///   캘리브레이션 미사용이면 404, 같은 LiDAR 이거나 포인트가 없으면 400 에러
+#[utoipa::path(
+    post,
+    path = "/calibration/snapshots",
+    request_body = CaptureRequest,
+    responses(
+        (status = 200, description = "Snapshot captured from both LiDARs over the same interval", body = SnapshotInfo),
+        (status = 400, description = "Same LiDAR or no points received", body = String),
+        (status = 404, description = "Calibration disabled", body = String)
+    )
+)]
+pub async fn post_calibration_snapshot(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CaptureRequest>,
+) -> Result<Json<SnapshotInfo>, (StatusCode, String)> {
+    calibration(&state)?
+        .capture(&request)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
+
+/// 캘리브레이션 정합 엔드포인트(/calibration/align) 처리
+///
+/// # Arguments
+/// * `request` - 스냅샷 ID, 초기 변환, ICP 파라미터 (JSON 본문)
+///
+/// # Returns
+/// * `Result<Json<CalibrationResult>, (StatusCode, String)>` - 성공 시 추정한 변환, 정합 지표, 저장할 설치 자세,
+///   캘리브레이션 미사용이면 404, 스냅샷이 없거나 파라미터가 잘못되면 400 에러
+#[utoipa::path(
+    post,
+    path = "/calibration/align",
+    request_body = AlignRequest,
+    responses(
+        (status = 200, description = "Estimated source to target transform with fitness metrics", body = CalibrationResult),
+        (status = 400, description = "Unknown snapshot or invalid parameters", body = String),
+        (status = 404, description = "Calibration disabled", body = String)
+    )
+)]
+pub async fn post_calibration_align(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<AlignRequest>,
+) -> Result<Json<CalibrationResult>, (StatusCode, String)> {
+    calibration(&state)?
+        .align(&request)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
+
+/// 설치 자세 조회 엔드포인트(/calibration/extrinsics) 처리
+///
+/// # Returns
+/// * `Result<Json<Vec<Extrinsic>>, (StatusCode, String)>` - 성공 시 사용 중인 설치 자세 목록 (설정 + 저장된 값),
+///   캘리브레이션 미사용이면 404 에러
+#[utoipa::path(
+    get,
+    path = "/calibration/extrinsics",
+    responses(
+        (status = 200, description = "Extrinsics in use (configured and saved)", body = [Extrinsic]),
+        (status = 404, description = "Calibration disabled", body = String)
+    )
+)]
+pub async fn get_calibration_extrinsics(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<Extrinsic>>, (StatusCode, String)> {
+    Ok(Json(calibration(&state)?.extrinsics()))
+}
+
+/// 설치 자세 저장 엔드포인트(/calibration/extrinsics) 처리
+///
+/// # Arguments
+/// * `extrinsic` - 저장할 설치 자세 (JSON 본문, 정합 결과의 `extrinsic`)
+///
+/// # Returns
+/// * `Result<Json<Vec<Extrinsic>>, (StatusCode, String)>` - 성공 시 저장된 설치 자세 목록, 캘리브레이션 미사용이면 404,
+///   자세가 잘못되었거나 파일 쓰기 실패 시 400 에러
+///
+/// # 동작 설명
+/// * 물체 추적 사용 시 다음 프레임부터 새 설치 자세로 속도와 방향 계산
+#[utoipa::path(
+    put,
+    path = "/calibration/extrinsics",
+    request_body = Extrinsic,
+    responses(
+        (status = 200, description = "Saved extrinsics", body = [Extrinsic]),
+        (status = 400, description = "Invalid pose or failed to write file", body = String),
+        (status = 404, description = "Calibration disabled", body = String)
+    )
+)]
+pub async fn put_calibration_extrinsic(
+    State(state): State<Arc<AppState>>,
+    Json(extrinsic): Json<Extrinsic>,
+) -> Result<Json<Vec<Extrinsic>>, (StatusCode, String)> {
+    let saved = calibration(&state)?
+        .save(&extrinsic)
+        .await
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    if let Some(tracker) = &state.tracker {
+        tracker.set_extrinsic(&extrinsic);
+    }
+    Ok(Json(saved))
+}
+
+/// TF 트리 내보내기 파라미터
+///
+/// # Fields
+/// * `format` - 파일 형식 (`json` 또는 `yaml`, 기본값 `json`)
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct TfQuery {
+    pub format: Option<TfFormat>,
+}
+
+/// TF 트리 내보내기 엔드포인트(/tf) 처리
+///
+/// # Returns
+/// * `Result<([(header::HeaderName, &str); 1], String), (StatusCode, String)>` - 성공 시 사용 중인 설치 자세의 TF 트리,
+///   이 빌드에서 사용할 수 없는 형식이면 400 에러
+///
+/// # 동작 설명
+/// * 캘리브레이션 사용 시 저장된 값을 포함한 설치 자세, 미사용 시 설정의 설치 자세(`tf_tree` 반영)를 내보냄
+/// * 모든 LiDAR 좌표계의 부모는 `world`, 이름은 사이트 설정으로 정함 (`<사이트 이름>/lidar_<순서>`)
+#[utoipa::path(
+    get,
+    path = "/tf",
+    params(TfQuery),
+    responses(
+        (status = 200, description = "Static TF tree of the sensor extrinsics in use", body = TfTree),
+        (status = 400, description = "Format not available in this build", body = String)
+    )
+)]
+pub async fn get_tf(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<TfQuery>,
+) -> Result<([(header::HeaderName, &'static str); 1], String), (StatusCode, String)> {
+    let format = query.format.unwrap_or_default();
+    let extrinsics = match &state.calibration {
+        Some(calibration) => calibration.extrinsics(),
+        None => state.processing.tracking.extrinsics.clone(),
+    };
+    let text = TfTree::from_extrinsics(&extrinsics, state.sites.config())
+        .encode(format)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    Ok(([(header::CONTENT_TYPE, format.content_type())], text))
+}
+
+/// 최근 odometry 조회 엔드포인트(/odometry) 처리
+///
+/// # Returns
+/// * `Result<Json<OdometrySample>, (StatusCode, String)>` - 성공 시 가장 최근 샘플, 받은 샘플이 없으면 404 에러
+#[utoipa::path(
+    get,
+    path = "/odometry",
+    responses(
+        (status = 200, description = "Latest ego pose/velocity sample", body = OdometrySample),
+        (status = 404, description = "No odometry received", body = String)
+    )
+)]
+pub async fn get_odometry(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<OdometrySample>, (StatusCode, String)> {
+    state
+        .odometry
+        .latest()
+        .map(Json)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "No odometry received".to_string()))
+}
+
+/// odometry 입력 엔드포인트(/odometry) 처리
+///
+/// # Arguments
+/// * `sample` - 자기 위치/속도 샘플 (JSON 본문)
+///
+/// # Returns
+/// * `Result<Json<OdometrySample>, (StatusCode, String)>` - 성공 시 저장한 샘플 (시각 포함), 잘못된 샘플이면 400 에러
+#[utoipa::path(
+    post,
+    path = "/odometry",
+    request_body = OdometrySample,
+    responses(
+        (status = 200, description = "Stored ego pose/velocity sample", body = OdometrySample),
+        (status = 400, description = "Invalid odometry sample", body = String)
+    )
+)]
+pub async fn post_odometry(
+    State(state): State<Arc<AppState>>,
+    Json(sample): Json<OdometrySample>,
+) -> Result<Json<OdometrySample>, (StatusCode, String)> {
+    state
+        .odometry
+        .update(sample)
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))
+}
@@ -0,0 +1,159 @@
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use uuid::Uuid;
+
+use crate::lidar::kanavi_mobility::codec::decode_frame;
+use crate::lidar::kanavi_mobility::command::{
+    command_target, BROADCAST_LIDAR_ID, BROADCAST_PRODUCT_LINE,
+};
+use crate::lidar::{CompanyInfo, LiDARInfo, LiDARKey, LiDARStatus, ParseOutcome};
+use crate::ws::client::Subscription;
+
+/// LiDAR 데이터 라우팅 테이블
+///
+/// # Examples
+/// ```
+/// let mut routes = RoutingTable::default();
+/// routes.update(client_id, Some(&subscription));
+/// let targets = routes.subscribers(&key);
+/// routes.remove(client_id);
+/// ```
+///
+/// # 주요 기능
+/// * 구독 설정을 LiDAR 키 별 구독 클라이언트 집합과 모든 LiDAR 를 구독하는 클라이언트 집합으로 관리
+/// * 수신한 LiDAR 데이터마다 전체 클라이언트를 확인하지 않고 구독 클라이언트만 조회
+#[derive(Debug, Default)]
+pub struct RoutingTable {
+    by_key: HashMap<LiDARKey, HashSet<Uuid>>,
+    all: HashSet<Uuid>,
+}
+
+impl RoutingTable {
+    /// 클라이언트 구독 설정 반영
+    ///
+    /// # Arguments
+    /// * `client_id` - 클라이언트 ID
+    /// * `subscription` - 구독 설정, None 이면 구독 해제
+    pub fn update(&mut self, client_id: Uuid, subscription: Option<&Subscription>) {
+        self.remove(client_id);
+        match subscription.map(|subscription| &subscription.lidars) {
+            Some(Some(lidars)) => {
+                for key in lidars {
+                    self.by_key.entry(*key).or_default().insert(client_id);
+                }
+            }
+            Some(None) => {
+                self.all.insert(client_id);
+            }
+            None => {}
+        }
+    }
+
+    /// 클라이언트 제거
+    pub fn remove(&mut self, client_id: Uuid) {
+        self.all.remove(&client_id);
+        self.by_key.retain(|_, clients| {
+            clients.remove(&client_id);
+            !clients.is_empty()
+        });
+    }
+
+    /// LiDAR 를 구독 중인 클라이언트 목록
+    pub fn subscribers(&self, key: &LiDARKey) -> Vec<Uuid> {
+        let mut clients: Vec<Uuid> = self.all.iter().copied().collect();
+        if let Some(subscribed) = self.by_key.get(key) {
+            clients.extend(subscribed.difference(&self.all).copied());
+        }
+        clients
+    }
+
+    /// LiDAR 를 구독 중인 클라이언트가 있는지 확인
+    pub fn has_subscriber(&self, key: &LiDARKey) -> bool {
+        !self.all.is_empty() || self.by_key.contains_key(key)
+    }
+
+    /// 명령 프레임의 대상 LiDAR 를 구독 중인 클라이언트 목록
+    ///
+    /// # Arguments
+    /// * `frame` - 클라이언트가 LiDAR 로 보낸 명령 프레임
+    /// * `lidars` - 등록된 LiDAR 장치 목록
+    ///
+    /// # Returns
+    /// * `Vec<Uuid>` - 대상 LiDAR 중 하나라도 구독 중인 클라이언트 목록, 명령 프레임이 아니면 빈 목록
+    pub fn command_subscribers(&self, frame: &[u8], lidars: &[LiDARInfo]) -> Vec<Uuid> {
+        let Some((product_line, lidar_id)) = command_target(frame) else {
+            return Vec::new();
+        };
+        let mut clients = HashSet::new();
+        for info in lidars.iter().filter(|info| {
+            (product_line == BROADCAST_PRODUCT_LINE || info.product_line == product_line)
+                && (lidar_id == BROADCAST_LIDAR_ID || info.lidar_id == lidar_id)
+        }) {
+            clients.extend(self.subscribers(&info.key));
+        }
+        clients.into_iter().collect()
+    }
+}
+
+/// 클라이언트가 LiDAR 로 보낸 명령 프레임의 송신 대상
+///
+/// # Arguments
+/// * `frame` - 클라이언트가 보낸 명령 프레임
+/// * `lidars` - 등록된 LiDAR 장치 목록과 온라인 여부
+///
+/// # Returns
+/// * `Result<Vec<(LiDARKey, SocketAddr)>, String>` - 성공 시 대상 LiDAR 키와 현재 송신 주소, 실패 시 에러 메시지
+///
+/// # 동작 설명
+/// * 헤더/길이/체크섬이 맞는 Kanavi Mobility 프레임 하나가 아니면 에러
+/// * 프레임의 제품 라인/ID 와 같은 Kanavi Mobility LiDAR 를 장치 저장소에서 찾음 (0xFF 는 해당 항목 전체 대상)
+/// * 같은 제품 라인/ID 의 LiDAR 가 여러 주소에 있으면 모두 대상 (클라이언트가 보낸 주소가 아닌 장치 저장소의 현재 송신 주소 사용)
+/// * 해당하는 LiDAR 가 없으면 `Unknown LiDAR`, 모두 비활성화되었거나 오프라인이면 `LiDAR offline` 에러
+pub fn forward_targets(
+    frame: &[u8],
+    lidars: &[LiDARStatus],
+) -> Result<Vec<(LiDARKey, SocketAddr)>, String> {
+    let header = match decode_frame(frame) {
+        ParseOutcome::Frame(header) if header.frame_len() == frame.len() => header,
+        ParseOutcome::Frame(_) => {
+            return Err("Invalid LiDAR command frame: unexpected trailing data".to_string())
+        }
+        ParseOutcome::Error(e) => return Err(format!("Invalid LiDAR command frame: {}", e)),
+        _ => return Err("Invalid LiDAR command frame: truncated frame".to_string()),
+    };
+    let (product_line, lidar_id) = (header.product_line, header.lidar_id);
+
+    let matched: Vec<&LiDARStatus> = lidars
+        .iter()
+        .filter(|status| {
+            let info = &status.info;
+            info.company == CompanyInfo::KanaviMobility
+                && (product_line == BROADCAST_PRODUCT_LINE || info.product_line == product_line)
+                && (lidar_id == BROADCAST_LIDAR_ID || info.lidar_id == lidar_id)
+        })
+        .collect();
+    if matched.is_empty() {
+        return Err(format!(
+            "Unknown LiDAR: product line {}, ID {}",
+            product_line, lidar_id
+        ));
+    }
+
+    let targets: Vec<(LiDARKey, SocketAddr)> = matched
+        .into_iter()
+        .filter(|status| status.online && !status.info.disabled)
+        .map(|status| {
+            (
+                status.info.key,
+                SocketAddr::from((status.info.ip, status.info.port)),
+            )
+        })
+        .collect();
+    if targets.is_empty() {
+        return Err(format!(
+            "LiDAR offline: product line {}, ID {}",
+            product_line, lidar_id
+        ));
+    }
+    Ok(targets)
+}
@@ -0,0 +1,32 @@
+use schemars::schema_for;
+use serde_json::{json, Value};
+
+use crate::config::ServerConfig;
+use crate::ws::message::{RequestMessage, ResponseMessage, WsEvent, WsRequest, WsResponse};
+use crate::ws::protocol::Envelope;
+
+/// WebSocket 메시지 및 설정 JSON Schema 생성
+///
+/// # Returns
+/// * `Value` - 메시지 종류별 JSON Schema
+///
+/// # 동작 설명
+/// * `messages` - v1 요청/응답 메시지, v2 메시지 봉투
+/// * `commands` - 명령별 요청/응답 (`command` 로 태그된 `data`)
+/// * `events` - 서버 이벤트 (`point_cloud`, `config`)
+/// * `config` - 서버 설정 파일
+pub fn schemas() -> Value {
+    json!({
+        "messages": {
+            "request": schema_for!(RequestMessage),
+            "response": schema_for!(ResponseMessage),
+            "envelope": schema_for!(Envelope),
+        },
+        "commands": {
+            "request": schema_for!(WsRequest),
+            "response": schema_for!(WsResponse),
+        },
+        "events": schema_for!(WsEvent),
+        "config": schema_for!(ServerConfig),
+    })
+}
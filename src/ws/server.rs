@@ -1,38 +1,112 @@
 use axum::{
-    extract::{ws::Message, ws::WebSocket, ws::WebSocketUpgrade, State},
-    response::Response,
-    routing::get,
+    extract::{
+        ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade},
+        ConnectInfo, Path, State,
+    },
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
     Router,
 };
 use bincode::config::standard;
-use bincode::decode_from_slice;
+use bincode::{decode_from_slice, encode_to_vec};
 use bytes::Bytes;
-use futures::{stream::StreamExt, SinkExt};
+use futures::stream::StreamExt;
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::Mutex;
 use tracing::*;
 use uuid::Uuid;
 
+use crate::alert::{Alert, AlertConfig, AlertEngine, EventHistory};
+use crate::bus::BusConfig;
+#[cfg(feature = "nats")]
+use crate::bus::BusPublisher;
+use crate::common::data::{LiDARPacket, OutboundMessage};
+use crate::common::impairment::Impairer;
+use crate::common::time::unix_time_ms;
+use crate::health::HealthState;
+use crate::influx::InfluxConfig;
+#[cfg(feature = "influx")]
+use crate::influx::InfluxWriter;
+#[cfg(feature = "shm")]
+use crate::ipc::ShmOutput;
+use crate::ipc::{IpcConfig, IpcOutput};
 use crate::lidar::{
-    kanavi_mobility::{KMConfigData, KanaviMobilityData},
-    CompanyInfo, LiDARData,
+    kanavi_mobility::{
+        codec::frame_data,
+        command::{
+            diagnostics_requests, identify_requests, network_source_change, PARAM_WARNING_AREA_SET,
+        },
+        readback::set_param,
+        ConfigTransactionRequest, ConfigTransactionResult, ConfigVerification,
+        DiagnosticsCollector, DiagnosticsReport, DryRun, KMConfigData, KanaviFrameBuilder,
+        KanaviMobilityData, RollbackStep, SetConfigRequest, SetConfigResult, SetOutcome, SetParam,
+        TeachingRequest, TeachingResult, TeachingSession, WarningAreaRequest, WarningAreaResult,
+    },
+    CompanyInfo, IdentityChange, LiDARData, LiDARInfo, LiDARKey, LiDARRegistry, LiDARStatus,
+    ParseStatsStore, ParserStats, PointCloud,
 };
+use crate::logging::TraceId;
+#[cfg(feature = "onnx")]
+use crate::processing::InferenceEngine;
+use crate::processing::{
+    CalibrationStore, CountStore, FrameStatistics, ObjectTracker, OdometryStore, ProcessingConfig,
+    ReorderBuffer, TrackedObjects, Voxelizer,
+};
+use crate::record::format::{list_recordings, Marker};
+use crate::record::{PlaybackStatus, Player, Recorder, RecorderConfig, Spool};
+use crate::relay::RelayState;
+use crate::replication::SubscriptionStore;
+use crate::site::{SiteConfig, SiteMonitor};
+use crate::supervisor::{supervise, SupervisorConfig};
+use crate::telemetry::{MetricHistory, TelemetrySample, TelemetryStore};
+use crate::ws::chunk;
+use crate::ws::client::{ClientInfo, ClientState, OutputFrame, StreamType, Subscription};
+use crate::ws::config::{
+    AdaptiveConfig, BandwidthConfig, EchoMode, StreamPreset, TeachingConfig, WsServerConfig,
+};
+use crate::ws::encoding::{WireEncoding, WireFormat};
+use crate::ws::input::{restrict_to_stream, validate_binary, validate_text, Inbound};
+use crate::ws::message::{
+    AnnotateRequest, AnnotationData, ConfigData, DiscoverRequest, DiscoverResult, FrameTiming,
+    HelloRequest, OccupancyGridData, PlaybackRequest, PointCloudData, RequestError, RequestMessage,
+    SpoolData, StatisticsData, WsEvent, WsRequest, WsResponse,
+};
+use crate::ws::protocol::{
+    event_message, hello_data, ErrorCode, ProtocolVersion, Reply, CONTROL_SUBPROTOCOL,
+};
+use crate::ws::queue::{CommandQueue, CommandQueueStatus, CommandTurn};
+use crate::ws::rest;
+use crate::ws::routing::{forward_targets, RoutingTable};
+use crate::ws::schema::schemas;
+use crate::ws::session::{validate_session_token, SessionStore};
+use crate::ws::stream_token::{StreamGrant, StreamRequest, StreamTokens};
 
 /// WebSocket 서버 구조체
 ///
 /// # Examples
 /// ```
-/// let ws_addr: SocketAddr = format!("0.0.0.0:{}", 5555).parse().unwrap();
-/// let ws_server = WsServer::new(ws_to_udp_tx, udp_to_ws_rx);
-/// ws_server.start(ws_addr).await;
+/// let (_, ws_listener) = reserve_port(5555, 10).unwrap();
+/// let ws_server = WsServer::new(config.ws, config.record, ws_to_udp_tx, udp_to_ws_rx, registry);
+/// ws_server.start(ws_listener).await;
 /// ```
 ///
 /// # Arguments
+/// * `config` - WebSocket 서버 설정
+/// * `record` - 녹화/재생 설정
 /// * `ws_to_udp_tx` - WebSocket에서 UDP로 메시지를 전송하는 mpsc 채널 송신자
 /// * `udp_to_ws_rx` - UDP에서 WebSocket으로 메시지를 수신하는 mpsc 채널 수신자
 /// * `clients` - 연결된 WebSocket 클라이언트들의 HashMap
+/// * `registry` - 검색된 LiDAR 장치 저장소
+/// * `alert` - 알림 규칙 설정
+/// * `ipc` - 같은 호스트 소비자용 IPC 출력 설정
+/// * `bus` - 메시지 버스(NATS) 발행 설정
+/// * `health` - 서버 시작 상태 저장소
+/// * `supervisor` - 내부 태스크 감시 설정
 ///
 /// # 주요 기능
 /// * WebSocket 클라이언트 연결 관리
@@ -40,9 +114,24 @@ use crate::lidar::{
 /// * LiDAR 데이터 파싱 및 처리
 /// * 클라이언트 간 메시지 브로드캐스트
 pub struct WsServer {
-    ws_to_udp_tx: tokio::sync::mpsc::Sender<Vec<u8>>,
-    udp_to_ws_rx: Option<tokio::sync::mpsc::Receiver<Vec<u8>>>,
-    clients: Arc<Mutex<HashMap<Uuid, futures::stream::SplitSink<WebSocket, Message>>>>,
+    config: WsServerConfig,
+    record: RecorderConfig,
+    processing: ProcessingConfig,
+    ws_to_udp_tx: tokio::sync::mpsc::Sender<OutboundMessage>,
+    udp_to_ws_rx: Option<tokio::sync::mpsc::Receiver<LiDARPacket>>,
+    clients: Arc<Mutex<HashMap<Uuid, ClientState>>>,
+    registry: LiDARRegistry,
+    telemetry: TelemetryStore,
+    parse_stats: ParseStatsStore,
+    alert: AlertConfig,
+    ipc: IpcConfig,
+    bus: BusConfig,
+    influx: InfluxConfig,
+    sites: SiteConfig,
+    subscriptions: SubscriptionStore,
+    relay: RelayState,
+    health: HealthState,
+    supervisor: SupervisorConfig,
 }
 
 impl WsServer {
@@ -50,23 +139,87 @@ impl WsServer {
     ///
     /// # Examples
     /// ```
-    /// let server = WsServer::new(tx, rx);
+    /// let server = WsServer::new(
+    ///     WsServerConfig::default(),
+    ///     RecorderConfig::default(),
+    ///     ProcessingConfig::default(),
+    ///     tx,
+    ///     rx,
+    ///     registry,
+    ///     telemetry,
+    ///     ParseStatsStore::default(),
+    ///     AlertConfig::default(),
+    ///     IpcConfig::default(),
+    ///     BusConfig::default(),
+    ///     InfluxConfig::default(),
+    ///     SiteConfig::default(),
+    ///     SubscriptionStore::default(),
+    ///     RelayState::new(None),
+    ///     HealthState::new(),
+    ///     SupervisorConfig::default(),
+    /// );
     /// ```
     ///
     /// # Arguments
+    /// * `config` - WebSocket 서버 설정
+    /// * `record` - 녹화/재생 설정
+    /// * `processing` - 포인트 클라우드 처리 설정
     /// * `ws_to_udp_tx` - WebSocket에서 UDP로의 송신 채널
     /// * `udp_to_ws_rx` - UDP에서 WebSocket으로의 수신 채널
+    /// * `registry` - 검색된 LiDAR 장치 저장소
+    /// * `telemetry` - LiDAR 상태 텔레메트리 저장소
+    /// * `parse_stats` - 송신 주소별 파싱 통계 저장소
+    /// * `alert` - 알림 규칙 설정
+    /// * `ipc` - IPC 출력 설정
+    /// * `bus` - 메시지 버스 발행 설정
+    /// * `influx` - 시계열 데이터베이스 기록 설정
+    /// * `sites` - LiDAR 그룹(사이트) 설정
+    /// * `subscriptions` - 이중화용 클라이언트 구독 저장소
+    /// * `relay` - 릴레이 연결 고리 상태 (서버 ID, 상위 서버 경로)
+    /// * `health` - 서버 시작 상태 저장소 (WebSocket 연결 수락 시작 기록, `/healthz`, `/readyz` 응답)
+    /// * `supervisor` - 내부 태스크 감시 설정
     ///
     /// # Returns
     /// * `Self` - 새로운 WsServer 인스턴스
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        ws_to_udp_tx: tokio::sync::mpsc::Sender<Vec<u8>>,
-        udp_to_ws_rx: tokio::sync::mpsc::Receiver<Vec<u8>>,
+        config: WsServerConfig,
+        record: RecorderConfig,
+        processing: ProcessingConfig,
+        ws_to_udp_tx: tokio::sync::mpsc::Sender<OutboundMessage>,
+        udp_to_ws_rx: tokio::sync::mpsc::Receiver<LiDARPacket>,
+        registry: LiDARRegistry,
+        telemetry: TelemetryStore,
+        parse_stats: ParseStatsStore,
+        alert: AlertConfig,
+        ipc: IpcConfig,
+        bus: BusConfig,
+        influx: InfluxConfig,
+        sites: SiteConfig,
+        subscriptions: SubscriptionStore,
+        relay: RelayState,
+        health: HealthState,
+        supervisor: SupervisorConfig,
     ) -> Self {
         Self {
+            config,
+            record,
+            processing,
             ws_to_udp_tx,
             udp_to_ws_rx: Some(udp_to_ws_rx),
             clients: Arc::new(Mutex::new(HashMap::new())),
+            registry,
+            telemetry,
+            parse_stats,
+            alert,
+            ipc,
+            bus,
+            influx,
+            sites,
+            subscriptions,
+            relay,
+            health,
+            supervisor,
         }
     }
 
@@ -74,92 +227,627 @@ impl WsServer {
     ///
     /// # Examples
     /// ```
-    /// let ws_addr: SocketAddr = format!("0.0.0.0:{}", 5555).parse().unwrap();
-    /// server.start(ws_addr).await;
+    /// let (_, ws_listener) = reserve_port(5555, 10).unwrap();
+    /// server.start(ws_listener).await;
     /// ```
     ///
     /// # Arguments
-    /// * `addr` - 서버를 바인딩할 소켓 주소
+    /// * `listener` - 바인딩해 둔 TCP 리스너 (`reserve_port` 참고), WebSocket 과 REST 엔드포인트가 함께 사용
     ///
     /// # Returns
     /// 없음
     ///
     /// # 동작 설명
-    /// * WebSocket 엔드포인트(/ws) 설정
-    /// * UDP 메시지 수신 및 처리
+    /// * WebSocket 엔드포인트(/ws, 제어 전용 /ws/control, 스트림 토큰 /ws/stream/{token}), REST 엔드포인트(/healthz, /readyz, /schema, /api-doc, /recordings, /recordings/../markers, /playback, /diagnostics, /lidars/../telemetry, /lidars/../metrics, /alerts/test, /events, /admin/clients, /admin/discover, /admin/logs/rotate, /streams, /sites, /lidars, /udp/rejected, /udp/memory, /odometry, /counts, /calibration/.., /tf) 설정
+    /// * 받은 리스너로 바로 연결을 수락하고 시작 상태 저장소에 WebSocket 연결 수락 시작 기록
+    /// * 텔레메트리 사용 시 `sample_interval_ms` 마다 LiDAR 별 샘플 생성 후 구독 중인 클라이언트에게 `telemetry` 이벤트 전송
+    ///   (메트릭 보관 사용 시 디스크에도 저장)
+    /// * 알림 사용 시 `check_interval_ms` 마다 장치 오프라인 규칙 평가 및 해제된 알림 확인
+    /// * 사이트가 설정되어 있으면 `sites.check_interval_ms` 마다 사이트 상태를 확인하고 바뀐 사이트를 `site_status` 이벤트로 전송
+    /// * 통과 횟수 집계 사용 시 `summary_interval_ms` 마다 오늘 집계를 `count_summary` 이벤트로 전송
+    ///   (집계 보관 사용 시 `persist.interval_ms` 마다 디스크에도 저장)
+    /// * LiDAR IP 변경으로 키가 바뀌면 텔레메트리 이력과 구독 LiDAR 목록을 새 키로 변경하고 `lidar_identity_changed` 이벤트 전송
+    /// * 새 LiDAR 정보 조회 사용 시 처음 수신한 LiDAR 의 버전/네트워크 소스 정보를 조회하고 `lidar_discovered` 이벤트 전송
+    /// * 추론 사용 시 추론 결과를 해당 LiDAR 를 구독 중인 클라이언트에게 `inference` 이벤트로 전송
+    /// * UDP 에서 수신한 LiDAR 데이터와 녹화 재생 데이터를 구독 중인 클라이언트에게 전달
+    /// * 프레임 정렬 사용 시 UDP(릴레이 포함) 에서 수신한 데이터를 `reorder.max_delay_ms` 동안 모아 수신 시각 순으로 처리
+    ///   (재정렬 깊이와 늦게 도착해 버린 프레임 수는 텔레메트리 샘플에 기록)
+    /// * 녹화 사용 시 UDP 에서 수신한 LiDAR 데이터 녹화
+    /// * 임시 저장 사용 시 구독자가 없는 LiDAR 데이터를 디스크 링 버퍼에 저장
+    /// * IPC 출력 사용 시 UDP 에서 수신한 LiDAR 데이터를 연결된 소비자에게 전달
+    /// * 공유 메모리 출력 사용 시 LiDAR/채널별 최신 포인트 클라우드를 공유 메모리에 기록
+    /// * 메시지 버스 사용 시 UDP 에서 수신한 LiDAR 데이터와 장치 이벤트(`config`, `telemetry`, `device_health`, `alert`, `site_status`, `lidar_identity_changed`, `lidar_discovered`) 발행
+    /// * 시계열 데이터베이스 사용 시 텔레메트리 샘플과 알림(영역 침입 포함)을 InfluxDB 에 기록
+    /// * 데이터 전달, 텔레메트리, 알림, 사이트, 통과 횟수 집계, 장치 식별 변경, 새 LiDAR 정보 조회 태스크는 감시 태스크로 실행하여 패닉하면 다시 시작
     /// * 클라이언트 연결 관리
-    pub async fn start(&mut self, addr: SocketAddr) {
+    pub async fn start(&mut self, listener: std::net::TcpListener) {
+        let (replay_tx, replay_rx) = tokio::sync::mpsc::channel(16);
+        let recorder = if self.record.enabled {
+            match Recorder::start(&self.record) {
+                Ok(recorder) => Some(Arc::new(recorder)),
+                Err(e) => {
+                    error!("Failed to start recorder: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let calibration = self.processing.calibration.enabled.then(|| {
+            Arc::new(CalibrationStore::new(
+                self.processing.calibration.clone(),
+                &self.processing.tracking.extrinsics,
+            ))
+        });
+        let voxelizer = if self.processing.voxel.enabled {
+            Some(Arc::new(Voxelizer::new(&self.processing.voxel).await))
+        } else {
+            None
+        };
+        // 저장된 설치 자세가 있으면 물체 추적에도 사용
+        let mut tracking = self.processing.tracking.clone();
+        if let Some(calibration) = &calibration {
+            tracking.extrinsics = calibration.extrinsics();
+        }
+
         let state = Arc::new(AppState {
             ws_to_udp_tx: self.ws_to_udp_tx.clone(),
             clients: self.clients.clone(),
+            routes: Arc::new(Mutex::new(RoutingTable::default())),
+            registry: self.registry.clone(),
+            telemetry: self.telemetry.clone(),
+            parse_stats: self.parse_stats.clone(),
+            max_message_size: self.config.max_message_size,
+            chunk_size: self.config.chunk_size,
+            adaptive: self.config.adaptive.clone(),
+            processing: self.processing.clone(),
+            odometry: OdometryStore::new(self.processing.odometry.clone()),
+            diagnostics_timeout_ms: self.config.diagnostics_timeout_ms,
+            config_timeout_ms: self.config.config_timeout_ms,
+            commands: CommandQueue::new(self.config.max_pending_commands),
+            teaching: self.config.teaching.clone(),
+            presets: self.config.presets.clone(),
+            bandwidth: self.config.bandwidth.clone(),
+            echo: self.config.echo,
+            config_tx: tokio::sync::broadcast::channel(16).0,
+            player: Arc::new(Player::new(self.record.dir.clone(), replay_tx)),
+            recorder,
+            spool: if self.record.spool.enabled {
+                match Spool::start(&self.record.dir, &self.record.spool) {
+                    Ok(spool) => Some(Arc::new(spool)),
+                    Err(e) => {
+                        error!("Failed to start spool: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            },
+            metrics: if self.telemetry.config().persist.enabled {
+                match MetricHistory::start(&self.telemetry.config().persist) {
+                    Ok(metrics) => Some(Arc::new(metrics)),
+                    Err(e) => {
+                        error!("Failed to start metric history: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            },
+            alerts: self
+                .alert
+                .enabled
+                .then(|| Arc::new(AlertEngine::new(self.alert.clone()))),
+            events: if self.alert.enabled && self.alert.history.enabled {
+                match EventHistory::start(&self.alert.history) {
+                    Ok(events) => Some(Arc::new(events)),
+                    Err(e) => {
+                        error!("Failed to start zone event history: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            },
+            tracker: (self.processing.tracking.enabled
+                || self.processing.counting.is_enabled()
+                || self.alert.uses_tracking())
+            .then(|| Arc::new(ObjectTracker::new(tracking))),
+            counts: if self.processing.counting.is_enabled() {
+                match CountStore::start(&self.processing.counting) {
+                    Ok(counts) => Some(Arc::new(counts)),
+                    Err(e) => {
+                        error!("Failed to start counting: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            },
+            calibration,
+            voxelizer,
+            impairment: self.config.impairment.enabled.then(|| {
+                warn!(
+                    "Injecting impairment into outbound data frames: {:?}",
+                    self.config.impairment
+                );
+                Arc::new(Impairer::new(&self.config.impairment))
+            }),
+            stream_tokens: self
+                .config
+                .stream_tokens
+                .enabled
+                .then(|| Arc::new(StreamTokens::new(self.config.stream_tokens.clone()))),
+            sessions: if self.config.sessions.enabled {
+                match SessionStore::load(self.config.sessions.clone()) {
+                    Ok(sessions) => Some(Arc::new(sessions)),
+                    Err(e) => {
+                        error!("Failed to load client sessions: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            },
+            sites: Arc::new(SiteMonitor::new(self.sites.clone())),
+            subscriptions: self.subscriptions.clone(),
+            relay: self.relay.clone(),
+            health: self.health.clone(),
+            #[cfg(feature = "shm")]
+            shm: if self.ipc.shm.enabled {
+                match ShmOutput::start(&self.ipc.shm) {
+                    Ok(shm) => Some(Arc::new(shm)),
+                    Err(e) => {
+                        error!("Failed to start shared memory output: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            },
+            #[cfg(feature = "nats")]
+            bus: self
+                .bus
+                .enabled
+                .then(|| Arc::new(BusPublisher::start(&self.bus))),
+            #[cfg(feature = "influx")]
+            influx: self
+                .influx
+                .enabled
+                .then(|| Arc::new(InfluxWriter::start(&self.influx))),
+            #[cfg(feature = "onnx")]
+            inference: if self.processing.inference.enabled {
+                match InferenceEngine::load(&self.processing.inference, &self.processing.occupancy)
+                {
+                    Ok(engine) => Some(Arc::new(engine)),
+                    Err(e) => {
+                        error!("Failed to start inference: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            },
         });
+        #[cfg(not(feature = "influx"))]
+        if self.influx.enabled {
+            error!("InfluxDB writing requires the `influx` feature");
+        }
+        #[cfg(not(feature = "nats"))]
+        if self.bus.enabled {
+            error!("Message bus publishing requires the `nats` feature");
+        }
+        #[cfg(not(feature = "shm"))]
+        if self.ipc.shm.enabled {
+            error!("Shared memory output requires the `shm` feature");
+        }
+        #[cfg(not(feature = "onnx"))]
+        if self.processing.inference.enabled {
+            error!("Inference requires the `onnx` feature");
+        }
 
-        let state_clone = state.clone();
-        let mut rx = self.udp_to_ws_rx.take().unwrap();
-        let handle = tokio::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Some(data) => {
-                        match CompanyInfo::try_from(data[0]) {
-                            Ok(company) => {
-                                match company {
-                                    CompanyInfo::KanaviMobility => {
-                                        let lidar_data: KanaviMobilityData =
-                                            decode_from_slice(&data[1..], standard()).unwrap().0;
-
-                                        if lidar_data.get_points().len() > 0 {
-                                            // point cloud data
-                                            // debug!(
-                                            //     "point cloud data: {:?}",
-                                            //     lidar_data.get_points()
-                                            // );
-                                        } else {
-                                            // config data
-                                            if let Some(config_data) =
-                                                lidar_data.get_data().and_then(|data| {
-                                                    data.downcast_ref::<KMConfigData>()
-                                                })
-                                            {
-                                                debug!("config_data: {:?}", config_data);
-                                            }
-                                        }
-                                    }
-                                    _ => {
-                                        error!("Unknown company");
-                                    }
-                                }
-                            }
-                            Err(_) => {
-                                error!("Failed to convert company info");
+        let ipc = if self.ipc.enabled {
+            match IpcOutput::start(&self.ipc) {
+                Ok(output) => Some(output),
+                Err(e) => {
+                    error!("Failed to start IPC output: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let rx = Arc::new(Mutex::new(self.udp_to_ws_rx.take().unwrap()));
+        let replay_rx = Arc::new(Mutex::new(replay_rx));
+        let ipc = Arc::new(ipc);
+        let handle = {
+            let state_clone = state.clone();
+            let reorder = self.processing.reorder.clone();
+            supervise("ws-forward", &self.supervisor, move || {
+                Self::forward_loop(
+                    state_clone.clone(),
+                    rx.clone(),
+                    replay_rx.clone(),
+                    reorder.enabled.then(|| ReorderBuffer::new(&reorder)),
+                    ipc.clone(),
+                )
+            })
+        };
+
+        let telemetry_handle = if self.telemetry.config().enabled {
+            let state = state.clone();
+            Some(supervise("telemetry", &self.supervisor, move || {
+                let state_clone = state.clone();
+                async move {
+                    let period = Duration::from_millis(
+                        state_clone.telemetry.config().sample_interval_ms.max(1),
+                    );
+                    let mut interval = tokio::time::interval(period);
+                    interval.tick().await;
+                    loop {
+                        interval.tick().await;
+                        let samples = state_clone.telemetry.sample().await;
+                        for sample in &samples {
+                            state_clone.send_telemetry(sample).await;
+                        }
+                        if let Some(metrics) = &state_clone.metrics {
+                            metrics.store(&samples);
+                        }
+                        #[cfg(feature = "influx")]
+                        if let Some(influx) = &state_clone.influx {
+                            influx.write_telemetry(&samples);
+                        }
+                        if let Some(alerts) = &state_clone.alerts {
+                            let raised = alerts
+                                .observe_samples(
+                                    &samples,
+                                    state_clone.telemetry.config().sample_interval_ms,
+                                )
+                                .await;
+                            state_clone.publish_alerts(raised).await;
+                        }
+                    }
+                }
+            }))
+        } else {
+            None
+        };
+
+        let alert_handle = state.alerts.clone().map(|alerts| {
+            let state = state.clone();
+            supervise("alert", &self.supervisor, move || {
+                let state_clone = state.clone();
+                let alerts = alerts.clone();
+                async move {
+                    let period = Duration::from_millis(alerts.config().check_interval_ms.max(1));
+                    let mut interval = tokio::time::interval(period);
+                    loop {
+                        interval.tick().await;
+                        let lidars = state_clone.registry.list().await;
+                        let quarantined =
+                            quarantined_lidars(&state_clone.parse_stats.list().await, &lidars);
+                        let mut raised = alerts.observe_quarantines(&quarantined).await;
+                        raised.extend(alerts.check(&lidars).await);
+                        state_clone.publish_alerts(raised).await;
+                    }
+                }
+            })
+        });
+
+        let site_handle = (!self.sites.sites.is_empty()).then(|| {
+            let state = state.clone();
+            supervise("site", &self.supervisor, move || {
+                let state_clone = state.clone();
+                async move {
+                    let period =
+                        Duration::from_millis(state_clone.sites.config().check_interval_ms.max(1));
+                    let mut interval = tokio::time::interval(period);
+                    loop {
+                        interval.tick().await;
+                        let lidars = state_clone.registry.list().await;
+                        for status in state_clone.sites.check(&lidars).await {
+                            state_clone
+                                .broadcast_event(&WsEvent::SiteStatus(status))
+                                .await;
+                        }
+                    }
+                }
+            })
+        });
+
+        let counting_handle = state.counts.clone().map(|counts| {
+            let state = state.clone();
+            supervise("counting", &self.supervisor, move || {
+                let state_clone = state.clone();
+                let counts = counts.clone();
+                async move {
+                    let config = counts.config();
+                    let period = Duration::from_millis(config.summary_interval_ms.max(1));
+                    let persist_period = Duration::from_millis(config.persist.interval_ms.max(1));
+                    let mut interval = tokio::time::interval(period);
+                    let mut persisted = tokio::time::Instant::now();
+                    interval.tick().await;
+                    loop {
+                        interval.tick().await;
+                        let rollup = counts.summary().await;
+                        state_clone
+                            .broadcast_event(&WsEvent::CountSummary(rollup))
+                            .await;
+                        if persisted.elapsed() >= persist_period {
+                            persisted = tokio::time::Instant::now();
+                            counts.persist().await;
+                        }
+                    }
+                }
+            })
+        });
+
+        let identity_handle = {
+            let state = state.clone();
+            let registry = self.registry.clone();
+            supervise("identity", &self.supervisor, move || {
+                let state_clone = state.clone();
+                let mut changes = registry.subscribe_changes();
+                async move {
+                    loop {
+                        match changes.recv().await {
+                            Ok(change) => state_clone.apply_identity_change(change).await,
+                            Err(RecvError::Lagged(count)) => {
+                                warn!("Missed {} LiDAR identity changes", count);
                             }
+                            Err(RecvError::Closed) => break,
                         }
+                    }
+                }
+            })
+        };
 
-                        // response
-                        // if let Err(e) = state_clone.broadcast_message(data.clone()).await {
-                        //     error!("Failed to broadcast message: {}", e);
-                        // }
+        let identify_handle = if self.config.identify_new_lidars {
+            let state = state.clone();
+            let registry = self.registry.clone();
+            Some(supervise("identify", &self.supervisor, move || {
+                let state_clone = state.clone();
+                let mut discoveries = registry.subscribe_discoveries();
+                async move {
+                    loop {
+                        match discoveries.recv().await {
+                            Ok(key) => {
+                                // 응답을 기다리는 동안 다른 LiDAR 의 조회가 밀리지 않도록 LiDAR 마다 따로 실행
+                                let state = state_clone.clone();
+                                tokio::spawn(async move { state.identify(key).await });
+                            }
+                            Err(RecvError::Lagged(count)) => {
+                                warn!("Missed {} new LiDAR notifications", count);
+                            }
+                            Err(RecvError::Closed) => break,
+                        }
                     }
-                    None => {
-                        error!("Failed to receive from UDP channel");
+                }
+            }))
+        } else {
+            None
+        };
+
+        #[cfg(feature = "onnx")]
+        let inference_handle = state.inference.clone().map(|engine| {
+            let state = state.clone();
+            supervise("inference", &self.supervisor, move || {
+                let state_clone = state.clone();
+                let mut results = engine.subscribe();
+                async move {
+                    loop {
+                        match results.recv().await {
+                            Ok(result) => {
+                                state_clone
+                                    .send_to_subscribers(
+                                        &result.key,
+                                        &WsEvent::Inference(result.clone()),
+                                    )
+                                    .await;
+                            }
+                            Err(RecvError::Lagged(count)) => {
+                                warn!("Missed {} inference results", count);
+                            }
+                            Err(RecvError::Closed) => break,
+                        }
                     }
                 }
-            }
+            })
         });
 
         let app = Router::new()
             .route("/ws", get(Self::handle_upgrade))
+            .route("/ws/control", get(Self::handle_control_upgrade))
+            .route("/ws/stream/{token}", get(Self::handle_stream_upgrade))
+            .route("/healthz", get(rest::get_healthz))
+            .route("/readyz", get(rest::get_readyz))
+            .route("/schema", get(rest::get_schema))
+            .route("/types.d.ts", get(rest::get_types))
+            .route("/api-doc", get(rest::get_api_doc))
+            .route("/recordings", get(rest::get_recordings))
+            .route(
+                "/recordings/{name}/markers",
+                get(rest::get_recording_markers),
+            )
+            .route(
+                "/playback",
+                get(rest::get_playback).post(rest::post_playback),
+            )
+            .route("/diagnostics", post(rest::post_diagnostics))
+            .route(
+                "/lidars/{ip}/{port}/{product_line}/{lidar_id}/telemetry",
+                get(rest::get_telemetry),
+            )
+            .route(
+                "/lidars/{ip}/{port}/{product_line}/{lidar_id}/metrics",
+                get(rest::get_metrics),
+            )
+            .route(
+                "/lidars/{ip}/{port}/{product_line}/{lidar_id}/command",
+                post(rest::post_lidar_command),
+            )
+            .route("/alerts/test", post(rest::post_test_notification))
+            .route("/events", get(rest::get_events))
+            .route("/admin/clients", get(rest::get_clients))
+            .route("/admin/clients/{id}", delete(rest::delete_client))
+            .route("/admin/discover", post(rest::post_discover))
+            .route("/admin/logs/rotate", post(rest::post_log_rotate))
+            .route("/streams", post(rest::post_stream))
+            .route("/sites", get(rest::get_sites))
+            .route("/lidars", get(rest::get_lidars))
+            .route("/udp/rejected", get(rest::get_udp_rejected))
+            .route("/udp/memory", get(rest::get_udp_memory))
+            .route("/counts", get(rest::get_counts))
+            .route(
+                "/calibration/snapshots",
+                post(rest::post_calibration_snapshot),
+            )
+            .route("/calibration/align", post(rest::post_calibration_align))
+            .route(
+                "/calibration/extrinsics",
+                get(rest::get_calibration_extrinsics).put(rest::put_calibration_extrinsic),
+            )
+            .route("/tf", get(rest::get_tf))
+            .route(
+                "/odometry",
+                get(rest::get_odometry).post(rest::post_odometry),
+            )
             .with_state(state.clone());
 
-        let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-        axum::serve(listener, app).await.unwrap();
+        let listener = match listener
+            .set_nonblocking(true)
+            .and_then(|_| tokio::net::TcpListener::from_std(listener))
+        {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!("Failed to use WebSocket listener: {}", e);
+                return;
+            }
+        };
+        match listener.local_addr() {
+            Ok(addr) => self.health.set_ws_accepting(addr),
+            Err(e) => error!("Failed to get WebSocket listener address: {}", e),
+        }
+        if let Err(e) = axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        {
+            error!("WebSocket server stopped: {}", e);
+        }
 
         handle.abort();
+        if let Some(handle) = telemetry_handle {
+            handle.abort();
+        }
+        if let Some(handle) = alert_handle {
+            handle.abort();
+        }
+        if let Some(handle) = site_handle {
+            handle.abort();
+        }
+        if let Some(handle) = counting_handle {
+            handle.abort();
+        }
+        if let Some(handle) = identify_handle {
+            handle.abort();
+        }
+        #[cfg(feature = "onnx")]
+        if let Some(handle) = inference_handle {
+            handle.abort();
+        }
+        identity_handle.abort();
+    }
+
+    /// UDP 수신 데이터와 녹화 재생 데이터를 구독 중인 클라이언트에게 전달하는 루프
+    ///
+    /// # Arguments
+    /// * `state` - 서버 공유 상태
+    /// * `rx` - UDP에서 WebSocket으로 메시지를 수신하는 mpsc 채널 수신자
+    /// * `replay_rx` - 녹화 재생 데이터 수신자
+    /// * `reorder` - 프레임 정렬 버퍼 (미사용 시 None)
+    /// * `ipc` - IPC 출력 (미사용 시 None)
+    ///
+    /// # 동작 설명
+    /// * 수신자는 감시 태스크가 다시 시작할 때 재사용할 수 있도록 공유하고, 실행 중에는 잠금 유지
+    /// * 패킷마다 `ws_frame` tracing span 안에서 처리 (UDP 수신 span 과 같은 `trace_id`)
+    /// * UDP 채널이 닫히면 종료
+    async fn forward_loop(
+        state: Arc<AppState>,
+        rx: Arc<Mutex<tokio::sync::mpsc::Receiver<LiDARPacket>>>,
+        replay_rx: Arc<Mutex<tokio::sync::mpsc::Receiver<LiDARPacket>>>,
+        mut reorder: Option<ReorderBuffer>,
+        ipc: Arc<Option<IpcOutput>>,
+    ) {
+        let mut rx = rx.lock().await;
+        let mut replay_rx = replay_rx.lock().await;
+        loop {
+            let wait = reorder
+                .as_ref()
+                .and_then(|reorder| reorder.next_release_ms())
+                .map(|release_ms| Duration::from_millis(release_ms.saturating_sub(unix_time_ms())));
+            let packets = tokio::select! {
+                packet = rx.recv() => match (packet, reorder.as_mut()) {
+                    (Some(packet), Some(reorder)) => {
+                        let key = packet.key;
+                        let result = reorder.push(packet);
+                        state.telemetry.observe_reorder(key, result).await;
+                        reorder.release(unix_time_ms())
+                    }
+                    (Some(packet), None) => vec![packet],
+                    (None, _) => {
+                        error!("Failed to receive from UDP channel");
+                        break;
+                    }
+                },
+                Some(packet) = replay_rx.recv() => vec![packet],
+                _ = tokio::time::sleep(wait.unwrap_or_default()), if wait.is_some() => {
+                    reorder
+                        .as_mut()
+                        .map(|reorder| reorder.release(unix_time_ms()))
+                        .unwrap_or_default()
+                }
+            };
+
+            for packet in packets {
+                let span = info_span!(
+                    "ws_frame",
+                    trace_id = %packet.trace_id,
+                    key = ?packet.key,
+                    replay = packet.replay
+                );
+                async {
+                    if let Some(recorder) = &state.recorder {
+                        recorder.record(&packet);
+                    }
+                    if let Some(ipc) = ipc.as_ref() {
+                        ipc.send(&packet);
+                    }
+                    #[cfg(feature = "nats")]
+                    if let Some(bus) = &state.bus {
+                        bus.publish_frame(&packet);
+                    }
+                    if let Some(spool) = &state.spool {
+                        if !packet.replay && !state.has_subscriber(&packet.key).await {
+                            spool.store(&packet);
+                        }
+                    }
+                    state.dispatch_packet(packet).await;
+                }
+                .instrument(span)
+                .await;
+            }
+        }
     }
 
     /// WebSocket 엔드포인트(/ws) 업그레이드 처리
     ///
     /// # Arguments
     /// * `ws` - WebSocketUpgrade 타입의 인자
+    /// * `headers` - 업그레이드 요청 헤더
+    /// * `remote_addr` - 클라이언트 주소
     /// * `state` - AppState 타입의 인자
     ///
     /// # Returns
@@ -167,6 +855,7 @@ impl WsServer {
     ///
     /// # 동작 설명
     /// * WebSocket 연결 업그레이드
+    /// * `Sec-WebSocket-Protocol` 에 `control` 이 있으면 하위 프로토콜을 수락하고 제어 전용 클라이언트로 처리
     /// * 연결 처리 위임
     ///
     /// 참고: 이 함수는 Axum 라우터에 의해 자동으로 호출되며, 직접 호출하지 않습니다.
@@ -175,66 +864,222 @@ impl WsServer {
     ///     .route("/ws", get(Self::handle_upgrade))
     ///     .with_state(state);
     /// ```
-    async fn handle_upgrade(ws: WebSocketUpgrade, State(state): State<Arc<AppState>>) -> Response {
-        ws.on_upgrade(|socket| async move { Self::handle_socket(socket, state).await })
+    async fn handle_upgrade(
+        ws: WebSocketUpgrade,
+        headers: HeaderMap,
+        ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+        State(state): State<Arc<AppState>>,
+    ) -> Response {
+        let control = requests_control(&headers);
+        ws.protocols([CONTROL_SUBPROTOCOL])
+            .on_upgrade(move |socket| async move {
+                Self::handle_socket(socket, remote_addr, state, control, None).await
+            })
+    }
+
+    /// 제어 전용 WebSocket 엔드포인트(/ws/control) 업그레이드 처리
+    ///
+    /// # Arguments
+    /// * `ws` - WebSocketUpgrade 타입의 인자
+    /// * `remote_addr` - 클라이언트 주소
+    /// * `state` - AppState 타입의 인자
+    ///
+    /// # Returns
+    /// * `Response` - 업그레이드된 WebSocket 연결
+    ///
+    /// # 동작 설명
+    /// * 하위 프로토콜과 관계없이 제어 전용 클라이언트로 처리 (`control` 하위 프로토콜을 요청하면 수락)
+    /// * 장치 목록, 이벤트, 명령 응답만 받고 포인트 클라우드와 데이터 프레임(통계, 점유 격자, 물체)은 받지 않음
+    async fn handle_control_upgrade(
+        ws: WebSocketUpgrade,
+        ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+        State(state): State<Arc<AppState>>,
+    ) -> Response {
+        ws.protocols([CONTROL_SUBPROTOCOL])
+            .on_upgrade(move |socket| async move {
+                Self::handle_socket(socket, remote_addr, state, true, None).await
+            })
+    }
+
+    /// 스트림 토큰 WebSocket 엔드포인트(/ws/stream/{token}) 업그레이드 처리
+    ///
+    /// # Arguments
+    /// * `ws` - WebSocketUpgrade 타입의 인자
+    /// * `token` - `POST /streams` 로 발급한 토큰
+    /// * `remote_addr` - 클라이언트 주소
+    /// * `state` - AppState 타입의 인자
+    ///
+    /// # Returns
+    /// * `Response` - 업그레이드된 WebSocket 연결, 알 수 없거나 이미 사용했거나 만료된 토큰이면 404 응답
+    ///
+    /// # 동작 설명
+    /// * 업그레이드 전에 토큰을 사용 처리 (같은 URL 로 다시 연결할 수 없음)
+    /// * 연결되면 토큰에 묶인 구독 설정을 바로 적용하고, `hello` 외의 명령과 LiDAR 명령 전달은 거부
+    async fn handle_stream_upgrade(
+        ws: WebSocketUpgrade,
+        Path(token): Path<String>,
+        ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+        State(state): State<Arc<AppState>>,
+    ) -> Response {
+        let subscription = match &state.stream_tokens {
+            Some(tokens) => tokens.redeem(&token).await,
+            None => None,
+        };
+        let Some(subscription) = subscription else {
+            return (StatusCode::NOT_FOUND, "Unknown or expired stream token").into_response();
+        };
+        ws.on_upgrade(move |socket| async move {
+            Self::handle_socket(socket, remote_addr, state, false, Some(subscription)).await
+        })
     }
 
     /// WebSocket 연결을 처리하는 비동기 함수
     ///
     /// # Arguments
     /// * `socket` - 업그레이드된 WebSocket 연결
+    /// * `remote_addr` - 클라이언트 주소
     /// * `state` - 애플리케이션 상태를 포함하는 Arc<AppState>
+    /// * `control` - 제어 전용 클라이언트 여부
+    /// * `stream` - 스트림 토큰에 묶인 구독 설정 (스트림 토큰 연결이 아니면 None)
     ///
     /// # 동작 설명
     /// * 클라이언트 연결 시 고유 UUID 할당
     /// * WebSocket 스트림을 sender와 receiver로 분리
     /// * 클라이언트의 sender를 상태에 저장
+    /// * 스트림 토큰 연결이면 묶인 구독 설정을 적용하고, 이후 `hello` 외의 입력은 `invalid_request` 에러
     /// * 메시지 수신 처리:
-    ///   - Text 메시지: UDP로 전달 및 모든 클라이언트에게 브로드캐스트
-    ///   - Binary 메시지: UDP로 전달 및 모든 클라이언트에게 브로드캐스트
+    ///   - Text 메시지: 서버 명령(`hello`, `lidar_list` 등)은 서버에서 처리하여 요청한 클라이언트에게 응답,
+    ///     v1 클라이언트의 그 외 메시지는 UDP로 전달 및 `echo` 설정에 따라 다른 클라이언트에게 전달,
+    ///     v2 클라이언트의 잘못된 JSON/알 수 없는 명령은 에러 응답
+    ///   - Binary 메시지: UDP로 전달 및 `echo` 설정에 따라 다른 클라이언트에게 전달
     ///   - Close 메시지: 연결 종료
+    /// * 관리자가 연결을 끊으면(클라이언트 상태 제거) 수신 종료
     /// * 연결 종료 시 클라이언트 정리
     ///
     /// 참고: 이 함수는 handle_upgrade 함수에 의해 호출되며, WebSocket 연결의 전체 생명주기를 관리합니다.
-    async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
+    async fn handle_socket(
+        socket: WebSocket,
+        remote_addr: SocketAddr,
+        state: Arc<AppState>,
+        control: bool,
+        stream: Option<Subscription>,
+    ) {
         let (sender, mut receiver) = socket.split();
+        let restricted = stream.is_some();
         let client_id = Uuid::new_v4();
+        let (disconnect_tx, mut disconnect_rx) = tokio::sync::oneshot::channel();
 
         // sender 저장
         {
             let mut clients = state.clients.lock().await;
-            clients.insert(client_id, sender);
-            info!("Client connected: {}", client_id);
+            clients.insert(
+                client_id,
+                ClientState::new(
+                    sender,
+                    remote_addr,
+                    disconnect_tx,
+                    state.impairment.clone(),
+                    control,
+                ),
+            );
+            info!(
+                "Client connected: {} ({}{})",
+                client_id,
+                remote_addr,
+                if control {
+                    ", control"
+                } else if restricted {
+                    ", stream"
+                } else {
+                    ""
+                }
+            );
+        }
+        if let Some(subscription) = stream {
+            state.set_subscription(client_id, Some(subscription)).await;
         }
 
         let state_clone = state.clone();
         let ws_to_udp_task = tokio::spawn(async move {
-            while let Some(Ok(msg)) = receiver.next().await {
-                match msg {
+            loop {
+                let msg = tokio::select! {
+                    msg = receiver.next() => match msg {
+                        Some(Ok(msg)) => msg,
+                        Some(Err(e)) => {
+                            warn!("Failed to receive message from {}: {}", client_id, e);
+                            break;
+                        }
+                        None => break,
+                    },
+                    // 클라이언트 상태가 제거되면 (관리자 연결 끊기) 신호 송신자가 drop 됨
+                    _ = &mut disconnect_rx => break,
+                };
+
+                let version = state_clone.protocol_version(client_id).await;
+                let max_message_size = state_clone.max_message_size;
+                let forward = match msg {
                     Message::Text(text) => {
                         info!("Text message received: {:?}", text);
-                        _ = state_clone.ws_to_udp_tx.send(text.as_bytes().to_vec());
-
-                        // response to all clients
-                        _ = state_clone.broadcast_message(text.as_bytes().to_vec());
+                        let mut inbound = validate_text(&text, version, max_message_size);
+                        if restricted {
+                            inbound = restrict_to_stream(inbound);
+                        }
+                        state_clone
+                            .process_inbound(client_id, version, inbound, text.as_bytes())
+                            .await
                     }
                     Message::Binary(data) => {
                         info!("Binary message received: {:?}", data);
-                        _ = state_clone.ws_to_udp_tx.send(data.to_vec());
-
-                        // response to all clients
-                        _ = state_clone.broadcast_message(data.to_vec());
+                        let inbound = match state_clone.encoding(client_id).await {
+                            #[cfg(feature = "protobuf")]
+                            WireEncoding::Protobuf => match crate::proto::command_frame(&data) {
+                                Ok(frame) => validate_binary(&frame, max_message_size),
+                                Err(message) => Inbound::Reject {
+                                    id: None,
+                                    code: ErrorCode::InvalidRequest,
+                                    message,
+                                },
+                            },
+                            _ => validate_binary(&data, max_message_size),
+                        };
+                        let inbound = if restricted {
+                            restrict_to_stream(inbound)
+                        } else {
+                            inbound
+                        };
+                        state_clone
+                            .process_inbound(client_id, version, inbound, &data)
+                            .await
                     }
                     Message::Close(_) => break,
-                    _ => {}
+                    _ => continue,
+                };
+
+                if let Some((data, targets)) = forward {
+                    if let Some((product_line, lidar_id, ip)) = network_source_change(&data) {
+                        state_clone
+                            .registry
+                            .expect_ip_change(product_line, lidar_id, ip)
+                            .await;
+                    }
+                    for target in targets {
+                        _ = state_clone
+                            .ws_to_udp_tx
+                            .send(OutboundMessage::command(target, data.clone()))
+                            .await;
+                    }
+
+                    state_clone.echo_message(client_id, data).await;
                 }
             }
         });
 
         _ = tokio::join!(ws_to_udp_task);
 
-        // 연결이 종료되면 sender 제거
+        // 연결이 종료되면 sender 및 라우팅 제거
         {
+            state.routes.lock().await.remove(client_id);
+            state.subscriptions.set(client_id, None).await;
             let mut clients = state.clients.lock().await;
             clients.remove(&client_id);
             info!("Client disconnected: {}", client_id);
@@ -242,56 +1087,2575 @@ impl WsServer {
     }
 }
 
+/// REST 요청의 클라이언트 ID
+///
+/// REST 요청은 WebSocket 연결이 없으므로 nil UUID 로 WebSocket 요청과 같은 명령 처리 경로를 사용하고,
+/// 명령 대기 상황(`command_queued`) 이벤트는 보내지 않음
+pub const REST_CLIENT_ID: Uuid = Uuid::nil();
+
 /// 애플리케이션 상태 구조체
 ///
 /// # Examples
 /// ```
 /// let state = AppState {
 ///     ws_to_udp_tx: tx,
-///     clients: Arc::new(Mutex::new(HashMap::new()))
+///     clients: Arc::new(Mutex::new(HashMap::new())),
+///     routes: Arc::new(Mutex::new(RoutingTable::default())),
+///     registry: LiDARRegistry::new(IdentityConfig::default()),
+///     telemetry: TelemetryStore::new(TelemetryConfig::default()),
+///     parse_stats: ParseStatsStore::default(),
+///     max_message_size: 64 * 1024,
+///     chunk_size: None,
+///     adaptive: AdaptiveConfig::default(),
+///     processing: ProcessingConfig::default(),
+///     odometry: OdometryStore::new(OdometryConfig::default()),
+///     diagnostics_timeout_ms: 1000,
+///     config_timeout_ms: 1000,
+///     commands: CommandQueue::new(4),
+///     teaching: TeachingConfig::default(),
+///     presets: Vec::new(),
+///     bandwidth: BandwidthConfig::default(),
+///     echo: EchoMode::All,
+///     config_tx: tokio::sync::broadcast::channel(16).0,
+///     player: Arc::new(Player::new(PathBuf::from("recordings"), replay_tx)),
+///     spool: None,
+///     metrics: None,
+///     alerts: None,
+///     events: None,
+///     tracker: None,
+///     counts: None,
+///     calibration: None,
+///     voxelizer: None,
+///     impairment: None,
+///     stream_tokens: None,
+///     sessions: None,
+///     sites: Arc::new(SiteMonitor::new(SiteConfig::default())),
+///     subscriptions: SubscriptionStore::default(),
+///     relay: RelayState::new(None),
+///     health: HealthState::new(),
+///     shm: None,
+///     bus: None,
+///     influx: None,
+///     inference: None,
 /// };
 /// ```
 ///
 /// # Arguments
 /// * `ws_to_udp_tx` - WebSocket에서 UDP로의 mpsc 송신 채널
 /// * `clients` - 연결된 클라이언트들의 HashMap
+/// * `routes` - LiDAR 키 별 구독 클라이언트 라우팅 테이블
+/// * `registry` - 검색된 LiDAR 장치 저장소
+/// * `telemetry` - LiDAR 상태 텔레메트리 저장소
+/// * `parse_stats` - 송신 주소별 파싱 통계 저장소
+/// * `max_message_size` - 클라이언트 메시지 최대 크기 (bytes)
+/// * `chunk_size` - v2 응답을 조각으로 나누는 크기 (bytes), None 이면 나누지 않음
+/// * `adaptive` - 적응형 전송 설정
+/// * `processing` - 포인트 클라우드 처리 설정
+/// * `odometry` - 클라이언트가 보낸 자기 위치/속도(odometry) 저장소
+/// * `diagnostics_timeout_ms` - LiDAR 진단 응답 대기 시간 (ms)
+/// * `config_timeout_ms` - 설정 명령 ACK 및 설정 확인 응답 대기 시간 (ms)
+/// * `commands` - LiDAR 별 설정 명령 대기열 (한 LiDAR 에 한 번에 하나의 설정 명령만 진행)
+/// * `teaching` - 티칭 진행 설정
+/// * `presets` - 이름 있는 구독 설정 목록
+/// * `bandwidth` - 클라이언트별 전송 대역폭 제한 설정
+/// * `echo` - 클라이언트 메시지 전달 방식
+/// * `config_tx` - LiDAR 설정 응답 broadcast 채널 (진단/티칭 응답 수집에 사용)
+/// * `player` - 녹화 파일 재생기
+/// * `recorder` - 녹화기 (녹화 미사용 시 None)
+/// * `spool` - 구독자가 없을 때 사용하는 디스크 임시 저장소
+/// * `metrics` - 텔레메트리 샘플 디스크 보관소 (메트릭 보관 미사용 시 None)
+/// * `alerts` - 알림 규칙 엔진 (알림 미사용 시 None)
+/// * `events` - 영역 침입 이벤트 디스크 보관소 (알림 또는 이벤트 보관 미사용 시 None)
+/// * `tracker` - 물체 추적기 (물체 추적과 통과 횟수 집계 모두 미사용 시 None)
+/// * `counts` - 통과선/영역 통과 횟수 집계기 (미사용 시 None)
+/// * `calibration` - 두 LiDAR 사이 설치 자세 추정 도우미 (미사용 시 None)
+/// * `voxelizer` - 여러 LiDAR 점유 격자를 합칠 때 사용하는 복셀 누적기 (미사용 시 None)
+/// * `impairment` - 클라이언트로 보내는 데이터 프레임 장애 주입기 (미사용 시 None, 모든 클라이언트가 공유)
+/// * `stream_tokens` - 일회용 스트림 URL 토큰 저장소 (발급 미사용 시 None)
+/// * `sessions` - 세션 토큰별 구독 설정 저장소 (세션 저장 미사용 시 None)
+/// * `sites` - 사이트 상태 감시기
+/// * `subscriptions` - 이중화용 클라이언트 구독 저장소 (주 서버는 대기 서버로 전송, 대기 서버는 복제)
+/// * `relay` - 릴레이 연결 고리 상태 (서버 ID, 상위 서버 경로)
+/// * `health` - 서버 시작 상태 저장소
+/// * `shm` - 공유 메모리 출력 (미사용 시 None, `shm` feature 필요)
+/// * `bus` - 메시지 버스 발행자 (미사용 시 None, `nats` feature 필요)
+/// * `influx` - 시계열 데이터베이스 기록기 (미사용 시 None, `influx` feature 필요)
+/// * `inference` - 프레임별 ONNX 모델 추론기 (미사용 시 None, `onnx` feature 필요)
 ///
 /// # 주요 기능
 /// * 클라이언트 상태 관리
+/// * 서버 명령 처리
 /// * 메시지 브로드캐스트
 #[derive(Clone)]
 pub struct AppState {
-    pub ws_to_udp_tx: tokio::sync::mpsc::Sender<Vec<u8>>,
-    pub clients: Arc<Mutex<HashMap<Uuid, futures::stream::SplitSink<WebSocket, Message>>>>,
+    pub ws_to_udp_tx: tokio::sync::mpsc::Sender<OutboundMessage>,
+    pub clients: Arc<Mutex<HashMap<Uuid, ClientState>>>,
+    pub routes: Arc<Mutex<RoutingTable>>,
+    pub registry: LiDARRegistry,
+    pub telemetry: TelemetryStore,
+    pub parse_stats: ParseStatsStore,
+    pub max_message_size: usize,
+    pub chunk_size: Option<usize>,
+    pub adaptive: AdaptiveConfig,
+    pub processing: ProcessingConfig,
+    pub odometry: OdometryStore,
+    pub diagnostics_timeout_ms: u64,
+    pub config_timeout_ms: u64,
+    pub commands: CommandQueue,
+    pub teaching: TeachingConfig,
+    pub presets: Vec<StreamPreset>,
+    pub bandwidth: BandwidthConfig,
+    pub echo: EchoMode,
+    pub config_tx: tokio::sync::broadcast::Sender<LiDARPacket>,
+    pub player: Arc<Player>,
+    pub recorder: Option<Arc<Recorder>>,
+    pub spool: Option<Arc<Spool>>,
+    pub metrics: Option<Arc<MetricHistory>>,
+    pub alerts: Option<Arc<AlertEngine>>,
+    pub events: Option<Arc<EventHistory>>,
+    pub tracker: Option<Arc<ObjectTracker>>,
+    pub counts: Option<Arc<CountStore>>,
+    pub calibration: Option<Arc<CalibrationStore>>,
+    pub voxelizer: Option<Arc<Voxelizer>>,
+    pub impairment: Option<Arc<Impairer>>,
+    pub stream_tokens: Option<Arc<StreamTokens>>,
+    pub sessions: Option<Arc<SessionStore>>,
+    pub sites: Arc<SiteMonitor>,
+    pub subscriptions: SubscriptionStore,
+    pub relay: RelayState,
+    pub health: HealthState,
+    #[cfg(feature = "shm")]
+    pub shm: Option<Arc<ShmOutput>>,
+    #[cfg(feature = "nats")]
+    pub bus: Option<Arc<BusPublisher>>,
+    #[cfg(feature = "influx")]
+    pub influx: Option<Arc<InfluxWriter>>,
+    #[cfg(feature = "onnx")]
+    pub inference: Option<Arc<InferenceEngine>>,
 }
 
 impl AppState {
-    /// 모든 연결된 클라이언트에게 메시지 브로드캐스트
+    /// 서버에서 처리하는 요청 메시지 처리
     ///
-    /// # Examples
-    /// ```
-    /// state.broadcast_message(message).await?;
-    /// ```
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID
+    /// * `request` - 요청 메시지
+    ///
+    /// # Returns
+    /// * `bool` - 서버에서 처리한 명령이면 true, LiDAR 로 전달해야 하는 메시지이면 false
+    ///
+    /// # 지원하는 명령
+    /// * `hello`: 프로토콜 버전 협상 (`data.protocol_version`), `data.resume` 이 있으면 복제된 구독 복원,
+    ///   `data.session` 이 있으면 세션에 저장된 구독 설정 복원
+    /// * `lidar_list`: 검색된 LiDAR 장치 목록 응답
+    /// * `get_schema`: WebSocket 메시지 및 설정 JSON Schema 응답
+    /// * `subscribe`: LiDAR 데이터 구독 (`data.lidars`, `data.sites` 가 없으면 모든 LiDAR, `data.preset` 이 있으면 이름 있는 구독 설정 적용)
+    /// * `unsubscribe`: LiDAR 데이터 구독 해제
+    /// * `recording_list`: 녹화 파일 목록 응답
+    /// * `playback`: 녹화 파일 재생/일시 정지/탐색/속도 변경/정지/상태 조회
+    /// * `diagnostics`: LiDAR 진단 (`data` 는 LiDAR 키)
+    /// * `admin_clients`: 연결된 클라이언트 목록 응답
+    /// * `admin_disconnect`: 클라이언트 강제 연결 종료 (`data` 는 클라이언트 UUID)
+    /// * `admin_lidar_enable`: LiDAR 비활성화 (수신 데이터를 파싱/전달하지 않고 장치 목록에 `disabled` 로 표시) 또는 다시 활성화
+    /// * `admin_discover`: 장치 검색 요청을 바로 보내고 `data.timeout_ms` 동안 새로 등록된 LiDAR 응답
+    /// * `site_list`: 사이트 상태 목록 응답
+    /// * `teaching`: 티칭 모드 설정 후 티칭 영역을 받아 채널별 다각형으로 응답 (진행 중 `teaching_progress` 이벤트 전송)
+    /// * `annotate`: 진행 중인 녹화의 목록 파일에 주석 마커를 기록하고 구독 중인 다른 클라이언트에게 `annotation` 이벤트 전송
+    /// * `odometry`: 자기 위치/속도 샘플 저장 (움직임 왜곡 보정, 월드 좌표 출력에 사용)
+    /// * `config_transaction`: 여러 설정을 순서대로 적용하고 하나라도 실패하면 시작 전 값으로 롤백
+    ///
+    /// v2 클라이언트의 알 수 없는 명령은 LiDAR 로 전달하지 않고 `unknown_command` 에러로 응답
+    ///
+    /// # 동작 설명
+    /// * 요청마다 `ws_request` tracing span (`trace_id`, `request_id`) 을 만들어 처리 중 보낸 LiDAR 명령 프레임과 ACK 로그를 요청에 연결
+    /// * 처리가 끝나면 `access` target 으로 클라이언트 ID, 명령, 처리 종류, 대상 LiDAR, 처리 시간, 결과를 기록
+    pub async fn handle_request(&self, client_id: Uuid, request: &RequestMessage) -> bool {
+        let span = info_span!(
+            "ws_request",
+            trace_id = %TraceId::next(),
+            request_id = %Uuid::new_v4(),
+            %client_id,
+            command = %request.command
+        );
+        async {
+            let started = Instant::now();
+            let parsed = WsRequest::parse(request);
+            let target = parsed.as_ref().ok().and_then(WsRequest::target);
+            let Some((reply, version)) = self.execute_request(client_id, request, parsed).await
+            else {
+                // 전달 대상 확인 후 `route_forward` 에서 접근 로그 기록
+                return false;
+            };
+            self.reply(client_id, version, &reply).await;
+            if matches!(reply.result, Ok(WsResponse::Subscribe(_))) {
+                self.notify_spool(client_id, version).await;
+            }
+            let status = match &reply.result {
+                Ok(_) => "ok",
+                Err((code, _)) => code.as_str(),
+            };
+            access_log(
+                client_id,
+                &request.command,
+                "request",
+                target,
+                started,
+                status,
+            );
+            true
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// 요청 메시지 처리 후 응답 생성
     ///
     /// # Arguments
-    /// * `message` - 브로드캐스트할 바이너리 메시지
+    /// * `client_id` - 요청한 클라이언트 ID
+    /// * `request` - 요청 메시지
+    /// * `parsed` - 명령별 요청으로 변환한 결과
     ///
     /// # Returns
-    /// * `Result<(), String>` - 성공 시 Ok(()), 실패 시 에러 메시지
+    /// * `Option<(Reply, ProtocolVersion)>` - 응답과 응답에 사용할 프로토콜 버전, LiDAR 로 전달해야 하는 메시지이면 None
+    async fn execute_request(
+        &self,
+        client_id: Uuid,
+        request: &RequestMessage,
+        parsed: Result<WsRequest, RequestError>,
+    ) -> Option<(Reply, ProtocolVersion)> {
+        let version = self.protocol_version(client_id).await;
+        let result = match parsed {
+            Ok(WsRequest::Hello(hello)) => self.hello(client_id, version, request, &hello).await,
+            Ok(WsRequest::LidarList) => {
+                let lidars = self.lidar_status().await;
+                (Reply::ok(request, WsResponse::LidarList(lidars)), version)
+            }
+            Ok(WsRequest::Subscribe(subscription)) => {
+                let mut subscription = match subscription
+                    .unwrap_or_default()
+                    .resolve_preset(&self.presets, &request.data)
+                {
+                    Ok(subscription) => subscription,
+                    Err(e) => {
+                        return Some((
+                            Reply::error(request, ErrorCode::InvalidRequest, &e),
+                            version,
+                        ));
+                    }
+                };
+                if let Err(e) = subscription
+                    .resolve_sites(self.sites.config())
+                    .and_then(|_| self.bandwidth.cap(Some(&subscription)).map(|_| ()))
+                {
+                    return Some((
+                        Reply::error(request, ErrorCode::InvalidRequest, &e),
+                        version,
+                    ));
+                }
+                self.set_subscription(client_id, Some(subscription.clone()))
+                    .await;
+                (
+                    Reply::ok(request, WsResponse::Subscribe(subscription)),
+                    version,
+                )
+            }
+            Ok(WsRequest::Unsubscribe) => {
+                self.set_subscription(client_id, None).await;
+                (Reply::ok(request, WsResponse::Unsubscribe), version)
+            }
+            Ok(WsRequest::GetSchema) => (
+                Reply::ok(request, WsResponse::GetSchema(schemas())),
+                version,
+            ),
+            Ok(WsRequest::RecordingList) => {
+                let dir = self.player.dir().to_path_buf();
+                let reply = match tokio::task::spawn_blocking(move || list_recordings(&dir)).await {
+                    Ok(Ok(recordings)) => Reply::ok(request, WsResponse::RecordingList(recordings)),
+                    Ok(Err(e)) => Reply::error(request, ErrorCode::Internal, &e.to_string()),
+                    Err(e) => Reply::error(request, ErrorCode::Internal, &e.to_string()),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::Playback(playback)) => (self.playback(request, playback).await, version),
+            Ok(WsRequest::Annotate(annotate)) => {
+                (self.annotate(client_id, request, annotate).await, version)
+            }
+            Ok(WsRequest::Odometry(sample)) => {
+                let reply = match self.odometry.update(sample) {
+                    Ok(sample) => Reply::ok(request, WsResponse::Odometry(sample)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::AdminClients) => (
+                Reply::ok(request, WsResponse::AdminClients(self.client_list().await)),
+                version,
+            ),
+            Ok(WsRequest::AdminDisconnect(id)) => {
+                let reply = match self.disconnect_client(id).await {
+                    Ok(()) => Reply::ok(request, WsResponse::AdminDisconnect(id)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::AdminLidarEnable(enable)) => {
+                let reply = match self
+                    .registry
+                    .set_enabled(&enable.lidar, enable.enabled)
+                    .await
+                {
+                    Ok(info) => Reply::ok(request, WsResponse::AdminLidarEnable(info)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::AdminDiscover(discover)) => {
+                let reply = match self.discover(&discover.unwrap_or_default()).await {
+                    Ok(result) => Reply::ok(request, WsResponse::AdminDiscover(result)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::SiteList) => {
+                let lidars = self.registry.list().await;
+                (
+                    Reply::ok(request, WsResponse::SiteList(self.sites.status(&lidars))),
+                    version,
+                )
+            }
+            Ok(WsRequest::Diagnostics(key)) => {
+                let reply = match self.diagnose(key).await {
+                    Ok(report) => Reply::ok(request, WsResponse::Diagnostics(report)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::Teaching(teaching)) => {
+                let reply = match self.teach(client_id, version, &teaching).await {
+                    Ok(result) => Reply::ok(request, WsResponse::Teaching(result)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::ZoneEvents(query)) => {
+                let reply = match &self.events {
+                    Some(events) => match events.query(&query.unwrap_or_default()).await {
+                        Ok(events) => Reply::ok(request, WsResponse::ZoneEvents(events)),
+                        Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                    },
+                    None => Reply::error(
+                        request,
+                        ErrorCode::InvalidRequest,
+                        "Zone event history is disabled",
+                    ),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::ParserStats) => (
+                Reply::ok(
+                    request,
+                    WsResponse::ParserStats(self.parse_stats.list().await),
+                ),
+                version,
+            ),
+            Ok(WsRequest::SetConfig(config)) => {
+                let reply = match self.set_config(client_id, version, &config).await {
+                    Ok(result) => Reply::ok(request, WsResponse::SetConfig(result)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::ConfigTransaction(transaction)) => {
+                let reply = match self
+                    .config_transaction(client_id, version, &transaction)
+                    .await
+                {
+                    Ok(result) => Reply::ok(request, WsResponse::ConfigTransaction(result)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Ok(WsRequest::SetWarningArea(area)) => {
+                let reply = match self.set_warning_area(client_id, version, &area).await {
+                    Ok(result) => Reply::ok(request, WsResponse::SetWarningArea(result)),
+                    Err(e) => Reply::error(request, ErrorCode::InvalidRequest, &e),
+                };
+                (reply, version)
+            }
+            Err(RequestError::Invalid(message)) => (
+                Reply::error(request, ErrorCode::InvalidRequest, &message),
+                version,
+            ),
+            Err(RequestError::UnknownCommand) => match version {
+                ProtocolVersion::V1 => return None,
+                ProtocolVersion::V2 => (
+                    Reply::error(
+                        request,
+                        ErrorCode::UnknownCommand,
+                        &format!("Unknown command: {}", request.command),
+                    ),
+                    version,
+                ),
+            },
+        };
+        Some(result)
+    }
+
+    /// 연결된 클라이언트 목록
+    ///
+    /// # Returns
+    /// * `Vec<ClientInfo>` - 연결 시각 순 클라이언트 정보
+    pub async fn client_list(&self) -> Vec<ClientInfo> {
+        let clients = self.clients.lock().await;
+        let mut infos: Vec<_> = clients
+            .iter()
+            .map(|(client_id, client)| client.info(*client_id))
+            .collect();
+        infos.sort_by_key(|info| info.connected_ms);
+        infos
+    }
+
+    /// 클라이언트 강제 연결 종료
+    ///
+    /// # Arguments
+    /// * `client_id` - 연결을 끊을 클라이언트 ID
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 연결되지 않은 클라이언트이면 에러 메시지
     ///
     /// # 동작 설명
-    /// * 모든 클라이언트에게 동일한 메시지 전송
-    /// * 전송 실패 시 에러 로깅
-    pub async fn broadcast_message(&self, message: Vec<u8>) -> Result<(), String> {
-        let mut clients = self.clients.lock().await;
-        for (_, sender) in clients.iter_mut() {
-            if let Err(e) = sender
-                .send(Message::Binary(Bytes::from(message.clone())))
+    /// * 클라이언트 상태를 제거하고 Close 프레임(1008 policy violation) 전송
+    /// * 상태가 제거되면 해당 클라이언트의 수신 태스크 종료
+    pub async fn disconnect_client(&self, client_id: Uuid) -> Result<(), String> {
+        self.routes.lock().await.remove(client_id);
+        self.subscriptions.set(client_id, None).await;
+        let mut client = self
+            .clients
+            .lock()
+            .await
+            .remove(&client_id)
+            .ok_or_else(|| format!("Client not found: {}", client_id))?;
+        let frame = CloseFrame {
+            code: close_code::POLICY,
+            reason: "Disconnected by administrator".into(),
+        };
+        if let Err(e) = client.send(Message::Close(Some(frame))) {
+            warn!("Failed to send close frame to {}: {}", client_id, e);
+        }
+        info!(
+            "Client {} ({}) disconnected by administrator",
+            client_id, client.remote_addr
+        );
+        Ok(())
+    }
+
+    /// 스트림 URL 발급
+    ///
+    /// # Arguments
+    /// * `tokens` - 스트림 토큰 저장소
+    /// * `request` - 발급 요청 (LiDAR 키, 구독 설정 이름, 유효 시간)
+    ///
+    /// # Returns
+    /// * `Result<StreamGrant, String>` - 성공 시 발급한 URL, 등록되지 않은 LiDAR, 알 수 없는 구독 설정 이름,
+    ///   잘못된 유효 시간 등 실패 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * `subscribe` 와 같이 이름 있는 구독 설정을 적용하고 대역폭 제한 `role` 을 확인
+    /// * 구독 LiDAR 는 요청한 LiDAR 하나로 고정 (구독 설정의 `lidars`, `sites` 는 무시)
+    pub async fn mint_stream(
+        &self,
+        tokens: &StreamTokens,
+        request: StreamRequest,
+    ) -> Result<StreamGrant, String> {
+        if self.registry.get(&request.lidar).await.is_none() {
+            return Err(format!(
+                "Unknown LiDAR: {}:{}",
+                request.lidar.ip, request.lidar.port
+            ));
+        }
+        let data = serde_json::json!({ "lidars": [request.lidar] });
+        let mut subscription = Subscription {
+            preset: request.preset.clone(),
+            ..Default::default()
+        }
+        .resolve_preset(&self.presets, &data)?;
+        subscription.lidars = Some(vec![request.lidar]);
+        subscription.sites = None;
+        self.bandwidth.cap(Some(&subscription))?;
+
+        let grant = tokens
+            .mint(request.lidar, request.preset, subscription, request.ttl_ms)
+            .await?;
+        info!(
+            "Stream URL issued for {}:{} (expires at {})",
+            grant.lidar.ip, grant.lidar.port, grant.expires_ms
+        );
+        Ok(grant)
+    }
+
+    /// LiDAR 진단
+    ///
+    /// # Arguments
+    /// * `key` - 진단할 LiDAR 키
+    ///
+    /// # Returns
+    /// * `Result<DiagnosticsReport, String>` - 성공 시 진단 결과, 등록되지 않은 LiDAR 등 실패 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 등록된 LiDAR 의 현재 송신 주소로 버전 정보/기본 설정 요청 송신
+    /// * `diagnostics_timeout_ms` 동안 해당 LiDAR 의 설정 응답을 수집
+    /// * 실패 항목이 있으면 모든 클라이언트에게 `device_health` 이벤트 전송
+    pub async fn diagnose(&self, key: LiDARKey) -> Result<DiagnosticsReport, String> {
+        let target = self.command_target(&key, "Diagnostics").await?;
+        let mut config_rx = self.config_tx.subscribe();
+        for frame in diagnostics_requests(key.product_line, key.lidar_id) {
+            self.ws_to_udp_tx
+                .send(OutboundMessage::command(target, frame))
                 .await
-            {
-                error!("Failed to send message: {}", e);
+                .map_err(|e| format!("Failed to send diagnostics request: {}", e))?;
+        }
+
+        let mut collector = DiagnosticsCollector::new(key);
+        let deadline =
+            tokio::time::Instant::now() + Duration::from_millis(self.diagnostics_timeout_ms);
+        while !collector.is_complete() {
+            let Some(lidar_data) = next_config(&mut config_rx, &key, deadline).await else {
+                break;
+            };
+            if let Some(config_data) = lidar_data.get_config_data() {
+                collector.observe(config_data);
             }
         }
-        Ok(())
+
+        let report = collector.finish();
+        if !report.healthy {
+            warn!(
+                "LiDAR {}:{} diagnostics failed: {:?}",
+                key.ip, key.port, report.failures
+            );
+            self.broadcast_event(&WsEvent::DeviceHealth(report.clone()))
+                .await;
+        }
+        Ok(report)
+    }
+
+    /// 장치 검색 요청을 바로 보내고 새로 찾은 LiDAR 수집
+    ///
+    /// # Arguments
+    /// * `request` - 검색 요청 (응답 대기 시간)
+    ///
+    /// # Returns
+    /// * `Result<DiscoverResult, String>` - 성공 시 새로 찾은 LiDAR 와 전체 LiDAR 수, 대기 시간이 잘못되었거나 UDP 리스너로 보내지 못하면 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 주기적 검색(`udp.discovery.interval_ms`)을 기다리지 않고 모든 검색 대상 주소로 검색 요청 송신
+    /// * `timeout_ms` 동안 장치 저장소에 처음 등록된 LiDAR 를 모아 응답 (이미 알고 있는 LiDAR 는 `total` 에만 포함)
+    /// * 새로 찾은 LiDAR 의 정보 조회와 `lidar_discovered` 이벤트는 평소처럼 별도로 진행
+    pub async fn discover(&self, request: &DiscoverRequest) -> Result<DiscoverResult, String> {
+        let timeout_ms = request.timeout_ms()?;
+        let mut discoveries = self.registry.subscribe_discoveries();
+        self.ws_to_udp_tx
+            .send(OutboundMessage::discover())
+            .await
+            .map_err(|e| format!("Failed to send discovery request: {}", e))?;
+
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(timeout_ms);
+        let mut keys = Vec::new();
+        loop {
+            match tokio::time::timeout_at(deadline, discoveries.recv()).await {
+                Ok(Ok(key)) => {
+                    if !keys.contains(&key) {
+                        keys.push(key);
+                    }
+                }
+                Ok(Err(RecvError::Lagged(skipped))) => {
+                    warn!("Missed {} discoveries during sweep", skipped);
+                }
+                Ok(Err(RecvError::Closed)) | Err(_) => break,
+            }
+        }
+
+        let lidars = self.registry.list().await;
+        let found: Vec<_> = keys
+            .iter()
+            .filter_map(|key| lidars.iter().find(|info| info.key == *key).cloned())
+            .collect();
+        info!(
+            "Discovery sweep found {} new LiDARs ({} total)",
+            found.len(),
+            lidars.len()
+        );
+        Ok(DiscoverResult {
+            found,
+            total: lidars.len(),
+            timeout_ms,
+        })
+    }
+
+    /// 새로 검색한 LiDAR 정보 조회
+    ///
+    /// # Arguments
+    /// * `key` - 처음 수신한 LiDAR 키
+    ///
+    /// # 동작 설명
+    /// * Kanavi Mobility 장치이면 버전 정보/네트워크 소스 정보 요청을 보내고 `diagnostics_timeout_ms` 동안 응답 대기
+    /// * 받은 펌웨어 버전과 보고된 IP/MAC 주소는 장치 저장소에 기록 (응답이 없어도 이벤트는 전송)
+    /// * 조회 중 보고된 IP 로 키가 바뀌면 새 키의 장치 정보 사용
+    /// * 모든 클라이언트에게 `lidar_discovered` 이벤트 전송 (메시지 버스 사용 시 함께 발행)
+    pub async fn identify(&self, mut key: LiDARKey) {
+        let mut changes = self.registry.subscribe_changes();
+        if let Ok(target) = self.command_target(&key, "Identify").await {
+            let mut config_rx = self.config_tx.subscribe();
+            for frame in identify_requests(key.product_line, key.lidar_id) {
+                if let Err(e) = self
+                    .ws_to_udp_tx
+                    .send(OutboundMessage::command(target, frame))
+                    .await
+                {
+                    warn!("Failed to send identify request: {}", e);
+                    return;
+                }
+            }
+
+            let deadline =
+                tokio::time::Instant::now() + Duration::from_millis(self.diagnostics_timeout_ms);
+            let (mut version, mut network) = (false, false);
+            while !(version && network) {
+                let Some(lidar_data) = next_config(&mut config_rx, &key, deadline).await else {
+                    break;
+                };
+                match lidar_data.get_config_data() {
+                    Some(KMConfigData::VersionInfo(info)) => {
+                        self.registry
+                            .set_firmware_version(&key, info.firmware_version_text())
+                            .await;
+                        version = true;
+                    }
+                    Some(KMConfigData::NetworkSourceInfo(_)) => network = true,
+                    _ => {}
+                }
+            }
+            if !version {
+                debug!("LiDAR {}:{} did not report version info", key.ip, key.port);
+            }
+        }
+
+        while let Ok(change) = changes.try_recv() {
+            if change.previous == key {
+                key = change.key;
+            }
+        }
+        let Some(info) = self.registry.get(&key).await else {
+            return;
+        };
+        info!(
+            "LiDAR {}:{} (product line: {}, id: {}) identified, firmware: {}",
+            key.ip,
+            key.port,
+            key.product_line,
+            key.lidar_id,
+            info.firmware_version.as_deref().unwrap_or("unknown")
+        );
+        self.broadcast_event(&WsEvent::LidarDiscovered(info)).await;
+    }
+
+    /// 설정 명령을 보낼 LiDAR 의 현재 송신 주소
+    ///
+    /// # Arguments
+    /// * `key` - 대상 LiDAR 키
+    /// * `action` - 에러 메시지에 사용할 작업 이름
+    ///
+    /// # Returns
+    /// * `Result<SocketAddr, String>` - 성공 시 송신 주소, 등록되지 않았거나 Kanavi Mobility 장치가 아니면 에러 메시지
+    async fn command_target(&self, key: &LiDARKey, action: &str) -> Result<SocketAddr, String> {
+        let info = self
+            .registry
+            .get(key)
+            .await
+            .ok_or_else(|| format!("Unknown LiDAR: {}:{}", key.ip, key.port))?;
+        if info.company != CompanyInfo::KanaviMobility {
+            return Err(format!("{} not supported for {:?}", action, info.company));
+        }
+        Ok(SocketAddr::from((info.ip, info.port)))
     }
+
+    /// LiDAR 티칭
+    ///
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID (진행 상황 이벤트 수신)
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `request` - 티칭 요청
+    ///
+    /// # Returns
+    /// * `Result<TeachingResult, String>` - 성공 시 채널별 티칭 영역 다각형, 등록되지 않은 LiDAR 또는 제한 시간 초과 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 대상 LiDAR 의 다른 설정 명령이 끝날 때까지 대기 (`command_turn`)
+    /// * 티칭 모드 설정(범위, 마진) 명령 송신 후 `teaching.poll_interval_ms` 마다 티칭 영역 요청
+    /// * 티칭이 끝나기 전의 NAK 와 설정되지 않은 티칭 영역 응답은 대기 중으로 보고 재요청
+    /// * 진행 단계나 NAK 수가 바뀔 때마다 요청한 클라이언트에게 `teaching_progress` 이벤트 전송
+    /// * `teaching.timeout_ms` 안에 설정된 티칭 영역을 받지 못하면 `failed` 이벤트 전송 후 에러
+    pub async fn teach(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        request: &TeachingRequest,
+    ) -> Result<TeachingResult, String> {
+        let key = request.lidar;
+        let target = self.command_target(&key, "Teaching").await?;
+        let _turn = self
+            .command_turn(client_id, version, &key, "teaching")
+            .await?;
+        let mut config_rx = self.config_tx.subscribe();
+        let mut session = TeachingSession::new(request);
+        self.ws_to_udp_tx
+            .send(OutboundMessage::command(target, session.start_frame()))
+            .await
+            .map_err(|e| format!("Failed to send teaching command: {}", e))?;
+        info!(
+            "Teaching started for {}:{} (range: {}, margin: {})",
+            key.ip, key.port, request.range, request.margin
+        );
+        self.send_teaching_progress(client_id, version, &session)
+            .await;
+
+        let deadline =
+            tokio::time::Instant::now() + Duration::from_millis(self.teaching.timeout_ms);
+        let mut poll = tokio::time::interval_at(
+            tokio::time::Instant::now() + Duration::from_millis(self.teaching.poll_interval_ms),
+            Duration::from_millis(self.teaching.poll_interval_ms.max(1)),
+        );
+        while !session.is_complete() {
+            tokio::select! {
+                _ = tokio::time::sleep_until(deadline) => break,
+                _ = poll.tick() => {
+                    let frame = session.area_request();
+                    if let Err(e) = self
+                        .ws_to_udp_tx
+                        .send(OutboundMessage::command(target, frame))
+                        .await
+                    {
+                        return Err(format!("Failed to send teaching area request: {}", e));
+                    }
+                }
+                packet = config_rx.recv() => {
+                    let packet = match packet {
+                        Ok(packet) => packet,
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => break,
+                    };
+                    if packet.key != key || packet.replay {
+                        continue;
+                    }
+                    let Some((_, encoded)) = packet.payload.split_first() else {
+                        continue;
+                    };
+                    if let Ok((lidar_data, _)) =
+                        decode_from_slice::<KanaviMobilityData, _>(encoded, standard())
+                    {
+                        if session.observe(&lidar_data) {
+                            self.send_teaching_progress(client_id, version, &session)
+                                .await;
+                        }
+                    }
+                }
+            }
+        }
+
+        match session.finish() {
+            Ok(result) => {
+                info!(
+                    "Teaching completed for {}:{} ({} zones, {} attempts)",
+                    key.ip,
+                    key.port,
+                    result.zones.len(),
+                    result.attempts
+                );
+                Ok(result)
+            }
+            Err(progress) => {
+                warn!(
+                    "Teaching timed out for {}:{} ({} attempts, {} NAK)",
+                    key.ip, key.port, progress.attempts, progress.nak_count
+                );
+                let message = format!(
+                    "Teaching area not received within {} ms ({} attempts, {} NAK)",
+                    self.teaching.timeout_ms, progress.attempts, progress.nak_count
+                );
+                self.send_event(client_id, version, &WsEvent::TeachingProgress(progress))
+                    .await;
+                Err(message)
+            }
+        }
+    }
+
+    /// LiDAR 경고 영역 설정
+    ///
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID (대기 상황 이벤트 수신)
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `request` - 경고 영역 설정 요청 (거리 단위 m)
+    ///
+    /// # Returns
+    /// * `Result<SetOutcome<WarningAreaResult>, String>` - 성공 시 설정 후 다시 읽은 경고 영역과 설정 확인 결과
+    ///   (`validate_only` 요청은 보냈을 명령 프레임), 잘못된 거리, 등록되지 않은 LiDAR, NAK 또는 제한 시간 초과 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 거리를 검증(위험 ≤ 경고 ≤ 주의 ≤ 제품 최대 감지 거리)하고 cm 단위 장치 형식으로 변환
+    /// * `apply_config` 로 설정 후 다시 읽은 값 확인
+    pub async fn set_warning_area(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        request: &WarningAreaRequest,
+    ) -> Result<SetOutcome<WarningAreaResult>, String> {
+        let key = request.lidar;
+        let area = request.to_area()?;
+        let param = set_param(PARAM_WARNING_AREA_SET)
+            .ok_or_else(|| "Warning area set param not supported".to_string())?;
+        if request.validate_only {
+            return self
+                .dry_run(&key, param, &area.to_bytes())
+                .await
+                .map(SetOutcome::Validated);
+        }
+        let (readback, verification) = self
+            .apply_config(client_id, version, &key, param, &area.to_bytes())
+            .await?;
+        match readback.get_config_data() {
+            Some(KMConfigData::WarningArea(readback)) => {
+                let result = WarningAreaResult::new(key, readback, verification);
+                info!(
+                    "Warning area set for {}:{} (danger: {} m, warning: {} m, caution: {} m)",
+                    key.ip, key.port, result.danger, result.warning, result.caution
+                );
+                Ok(SetOutcome::Applied(result))
+            }
+            _ => Err("Invalid warning area readback".to_string()),
+        }
+    }
+
+    /// LiDAR 설정
+    ///
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID (대기 상황 이벤트 수신)
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `request` - 설정 요청 (설정 파라미터와 장치 형식 데이터)
+    ///
+    /// # Returns
+    /// * `Result<SetOutcome<SetConfigResult>, String>` - 성공 시 다시 읽은 데이터와 설정 확인 결과
+    ///   (`validate_only` 요청은 보냈을 명령 프레임), 지원하지 않는 파라미터, 잘못된 데이터 길이,
+    ///   등록되지 않은 LiDAR, NAK 또는 제한 시간 초과 시 에러 메시지
+    pub async fn set_config(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        request: &SetConfigRequest,
+    ) -> Result<SetOutcome<SetConfigResult>, String> {
+        let param = request.validate()?;
+        if request.validate_only {
+            return self
+                .dry_run(&request.lidar, param, &request.data)
+                .await
+                .map(SetOutcome::Validated);
+        }
+        let (readback, verification) = self
+            .apply_config(client_id, version, &request.lidar, param, &request.data)
+            .await?;
+        Ok(SetOutcome::Applied(SetConfigResult {
+            key: request.lidar,
+            param: request.param,
+            reported: frame_data(readback.get_raw_data()).to_vec(),
+            verification,
+        }))
+    }
+
+    /// REST 설정 명령 (`POST /lidars/{ip}/{port}/{product_line}/{lidar_id}/command`)
+    ///
+    /// # Arguments
+    /// * `request` - 설정 요청 (LiDAR 키는 경로의 값)
+    ///
+    /// # Returns
+    /// * `Result<SetOutcome<SetConfigResult>, String>` - WebSocket `set_config` 명령과 같은 결과
+    ///
+    /// # 동작 설명
+    /// * WebSocket `set_config` 명령과 같은 `set_config` 경로를 사용하여 같은 LiDAR 별 명령 대기열, ACK/설정 확인 응답 대기를 거침
+    /// * 요청마다 `rest_request` tracing span (`trace_id`, `request_id`) 을 만들어 LiDAR 명령 프레임과 ACK 로그를 요청에 연결
+    /// * 처리가 끝나면 `access` target 으로 WebSocket 요청과 같은 형식의 접근 로그 기록 (클라이언트 ID 는 `REST_CLIENT_ID`)
+    pub async fn lidar_command(
+        &self,
+        request: &SetConfigRequest,
+    ) -> Result<SetOutcome<SetConfigResult>, String> {
+        let span = info_span!(
+            "rest_request",
+            trace_id = %TraceId::next(),
+            request_id = %Uuid::new_v4(),
+            command = "set_config"
+        );
+        async {
+            let started = Instant::now();
+            let result = self
+                .set_config(REST_CLIENT_ID, ProtocolVersion::V2, request)
+                .await;
+            let status = match &result {
+                Ok(_) => "ok",
+                Err(_) => ErrorCode::InvalidRequest.as_str(),
+            };
+            access_log(
+                REST_CLIENT_ID,
+                "set_config",
+                "request",
+                Some(request.lidar),
+                started,
+                status,
+            );
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// LiDAR 설정 명령 대기열에서 차례를 기다림
+    ///
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `key` - 대상 LiDAR 키
+    /// * `command` - 이벤트에 표시할 명령 이름
+    ///
+    /// # Returns
+    /// * `Result<CommandTurn, String>` - 성공 시 차례 (drop 하면 다음 요청 진행), 대기열이 가득 차면 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 앞에 다른 요청이 있으면 대기 순서가 바뀔 때마다 `command_queued` 이벤트 전송 (`position` 0 은 명령 시작)
+    /// * REST 요청(`REST_CLIENT_ID`)은 이벤트 없이 차례만 기다림
+    async fn command_turn(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        key: &LiDARKey,
+        command: &str,
+    ) -> Result<CommandTurn, String> {
+        let mut turn = self.commands.enter(*key)?;
+        let mut position = turn.position();
+        if position == 0 {
+            return Ok(turn);
+        }
+        info!(
+            "Command {} for {}:{} queued at position {}",
+            command, key.ip, key.port, position
+        );
+        loop {
+            let status = CommandQueueStatus {
+                key: *key,
+                command: command.to_string(),
+                position,
+            };
+            if client_id != REST_CLIENT_ID {
+                self.send_event(client_id, version, &WsEvent::CommandQueued(status))
+                    .await;
+            }
+            if position == 0 {
+                return Ok(turn);
+            }
+            while turn.position() == position {
+                turn.changed().await;
+            }
+            position = turn.position();
+        }
+    }
+
+    /// 설정 명령을 보내지 않고 대상 LiDAR 확인과 프레임 생성만 수행
+    ///
+    /// # Arguments
+    /// * `key` - 대상 LiDAR 키
+    /// * `param` - 설정 파라미터
+    /// * `data` - 검증을 마친 설정 데이터
+    ///
+    /// # Returns
+    /// * `Result<DryRun, String>` - 성공 시 보냈을 명령 프레임, 등록되지 않았거나 Kanavi Mobility 장치가 아니면 에러 메시지
+    async fn dry_run(
+        &self,
+        key: &LiDARKey,
+        param: &SetParam,
+        data: &[u8],
+    ) -> Result<DryRun, String> {
+        self.command_target(key, "Set config").await?;
+        let frame = KanaviFrameBuilder::for_lidar(key)
+            .config_set(param, data)
+            .build();
+        Ok(DryRun::new(*key, &frame))
+    }
+
+    /// 설정 명령 송신 후 같은 설정을 다시 읽어 확인
+    ///
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID (대기 상황 이벤트 수신)
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `key` - 대상 LiDAR 키
+    /// * `param` - 설정 파라미터
+    /// * `data` - 설정 데이터
+    ///
+    /// # Returns
+    /// * `Result<(KanaviMobilityData, ConfigVerification), String>` - 성공 시 설정 확인 응답과 비교 결과,
+    ///   등록되지 않은 LiDAR, NAK 또는 제한 시간 초과 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 대상 LiDAR 의 다른 설정 명령이 끝날 때까지 대기 (`command_turn`)
+    /// * `write_config` 로 설정 후 다시 읽은 값 확인
+    async fn apply_config(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        key: &LiDARKey,
+        param: &SetParam,
+        data: &[u8],
+    ) -> Result<(KanaviMobilityData, ConfigVerification), String> {
+        let target = self.command_target(key, "Set config").await?;
+        let _turn = self
+            .command_turn(client_id, version, key, &format!("set 0x{:02X}", param.set))
+            .await?;
+        self.write_config(target, key, param, data).await
+    }
+
+    /// 설정 명령 송신 후 같은 설정을 다시 읽어 확인 (명령 대기열 차례를 가진 상태에서 호출)
+    ///
+    /// # Arguments
+    /// * `target` - LiDAR 의 현재 송신 주소
+    /// * `key` - 대상 LiDAR 키
+    /// * `param` - 설정 파라미터
+    /// * `data` - 설정 데이터
+    ///
+    /// # Returns
+    /// * `Result<(KanaviMobilityData, ConfigVerification), String>` - 성공 시 설정 확인 응답과 비교 결과,
+    ///   NAK 또는 제한 시간 초과 시 에러 메시지
+    ///
+    /// # 동작 설명
+    /// * 설정 명령 송신 후 `config_timeout_ms` 안에 ACK(`set + 1`)를 기다림
+    /// * 설정 확인 요청 송신 후 `config_timeout_ms` 안에 받은 응답(`request + 1`)을 설정 데이터와 필드별로 비교
+    /// * 장치가 ACK 후에도 값을 반영하지 않은 경우 `verified: false` 와 다른 필드 목록으로 보고
+    async fn write_config(
+        &self,
+        target: SocketAddr,
+        key: &LiDARKey,
+        param: &SetParam,
+        data: &[u8],
+    ) -> Result<(KanaviMobilityData, ConfigVerification), String> {
+        let mut config_rx = self.config_tx.subscribe();
+        let timeout = Duration::from_millis(self.config_timeout_ms);
+        self.ws_to_udp_tx
+            .send(OutboundMessage::command(
+                target,
+                KanaviFrameBuilder::for_lidar(key)
+                    .config_set(param, data)
+                    .build(),
+            ))
+            .await
+            .map_err(|e| format!("Failed to send set command: {}", e))?;
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let lidar_data = next_config(&mut config_rx, key, deadline)
+                .await
+                .ok_or_else(|| {
+                    format!(
+                        "Set param 0x{:02X} not acknowledged within {} ms",
+                        param.set, self.config_timeout_ms
+                    )
+                })?;
+            match lidar_data.get_config_data() {
+                Some(KMConfigData::Ack(_)) if lidar_data.get_param() == param.set + 1 => {
+                    debug!(
+                        "LiDAR {}:{} acknowledged set param 0x{:02X}",
+                        key.ip, key.port, param.set
+                    );
+                    break;
+                }
+                Some(KMConfigData::Nak(_)) => {
+                    debug!(
+                        "LiDAR {}:{} rejected set param 0x{:02X}",
+                        key.ip, key.port, param.set
+                    );
+                    return Err(format!(
+                        "Set param 0x{:02X} rejected by LiDAR (NAK)",
+                        param.set
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        let lidar_data = self.read_config(target, key, param, &mut config_rx).await?;
+        let verification =
+            ConfigVerification::compare(param, data, frame_data(lidar_data.get_raw_data()));
+        if !verification.verified {
+            warn!(
+                "LiDAR {}:{} ignored set param 0x{:02X}: {:?}",
+                key.ip, key.port, param.set, verification.differences
+            );
+        }
+        Ok((lidar_data, verification))
+    }
+
+    /// 설정 확인 요청 송신 후 응답 수신
+    ///
+    /// # Arguments
+    /// * `target` - LiDAR 의 현재 송신 주소
+    /// * `key` - 대상 LiDAR 키
+    /// * `param` - 설정 파라미터
+    /// * `config_rx` - 요청 송신 전에 구독한 설정 응답 수신기
+    ///
+    /// # Returns
+    /// * `Result<KanaviMobilityData, String>` - 성공 시 설정 확인 응답(`request + 1`), 제한 시간 초과 시 에러 메시지
+    async fn read_config(
+        &self,
+        target: SocketAddr,
+        key: &LiDARKey,
+        param: &SetParam,
+        config_rx: &mut tokio::sync::broadcast::Receiver<LiDARPacket>,
+    ) -> Result<KanaviMobilityData, String> {
+        self.ws_to_udp_tx
+            .send(OutboundMessage::command(
+                target,
+                KanaviFrameBuilder::for_lidar(key)
+                    .config_request(param)
+                    .build(),
+            ))
+            .await
+            .map_err(|e| format!("Failed to send readback request: {}", e))?;
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(self.config_timeout_ms);
+        loop {
+            let lidar_data = next_config(config_rx, key, deadline).await.ok_or_else(|| {
+                format!(
+                    "Set param 0x{:02X} not read back within {} ms",
+                    param.set, self.config_timeout_ms
+                )
+            })?;
+            if lidar_data.get_param() != param.request + 1 || lidar_data.get_config_data().is_none()
+            {
+                continue;
+            }
+            debug!(
+                "LiDAR {}:{} read back param 0x{:02X}",
+                key.ip, key.port, param.request
+            );
+            return Ok(lidar_data);
+        }
+    }
+
+    /// 설정 트랜잭션
+    ///
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID (대기 상황 이벤트 수신)
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `request` - 트랜잭션 요청 (LiDAR 키와 순서대로 적용할 설정 목록)
+    ///
+    /// # Returns
+    /// * `Result<ConfigTransactionResult, String>` - 설정을 보내기 시작했으면 적용 여부와 롤백 결과,
+    ///   잘못된 설정 목록, 등록되지 않은 LiDAR, 대기열 초과, 시작 전 값 읽기 실패 시 에러 메시지 (LiDAR 설정은 바뀌지 않음)
+    ///
+    /// # 동작 설명
+    /// * 트랜잭션이 끝날 때까지 대상 LiDAR 의 명령 대기열 차례를 유지하여 다른 설정 명령이 끼어들지 않도록 함
+    /// * 모든 설정의 현재 값을 먼저 읽어 둔 뒤(snapshot) 순서대로 설정하고 다시 읽어 확인
+    /// * NAK, 제한 시간 초과, 다시 읽은 값이 다른 설정이 있으면 그 설정부터 역순으로 시작 전 값을 다시 설정하고 확인 (롤백)
+    /// * 롤백 중 실패한 설정이 있어도 나머지 설정은 계속 되돌리고 단계별 결과로 보고
+    pub async fn config_transaction(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        request: &ConfigTransactionRequest,
+    ) -> Result<ConfigTransactionResult, String> {
+        let params = request.validate()?;
+        let key = &request.lidar;
+        let target = self.command_target(key, "Config transaction").await?;
+        let _turn = self
+            .command_turn(client_id, version, key, "config_transaction")
+            .await?;
+
+        let mut snapshot = Vec::with_capacity(params.len());
+        for param in &params {
+            let mut config_rx = self.config_tx.subscribe();
+            let current = self
+                .read_config(target, key, param, &mut config_rx)
+                .await
+                .map_err(|e| format!("Failed to read current value: {}", e))?;
+            let data = frame_data(current.get_raw_data())
+                .get(..param.data_len())
+                .ok_or_else(|| format!("Set param 0x{:02X} read back too short", param.set))?;
+            snapshot.push(data.to_vec());
+        }
+
+        let mut applied = Vec::with_capacity(params.len());
+        let mut error = None;
+        for (param, change) in params.iter().zip(&request.changes) {
+            match self.write_config(target, key, param, &change.data).await {
+                Ok((readback, verification)) if verification.verified => {
+                    applied.push(SetConfigResult {
+                        key: *key,
+                        param: change.param,
+                        reported: frame_data(readback.get_raw_data()).to_vec(),
+                        verification,
+                    });
+                }
+                Ok((_, verification)) => {
+                    let fields: Vec<&str> = verification
+                        .differences
+                        .iter()
+                        .map(|difference| difference.field.as_str())
+                        .collect();
+                    error = Some(format!(
+                        "Set param 0x{:02X} not applied by LiDAR ({})",
+                        param.set,
+                        fields.join(", ")
+                    ));
+                    break;
+                }
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        let Some(error) = error else {
+            info!(
+                "Config transaction committed for {}:{} ({} changes)",
+                key.ip,
+                key.port,
+                applied.len()
+            );
+            return Ok(ConfigTransactionResult {
+                key: *key,
+                committed: true,
+                applied,
+                error: None,
+                rollback: Vec::new(),
+                rolled_back: false,
+            });
+        };
+
+        // 실패한 설정도 일부 반영되었을 수 있으므로 함께 되돌림
+        warn!(
+            "Config transaction failed for {}:{}, rolling back: {}",
+            key.ip, key.port, error
+        );
+        let attempted = applied.len() + 1;
+        let mut rollback = Vec::with_capacity(attempted);
+        for (param, data) in params[..attempted].iter().zip(&snapshot).rev() {
+            let step = match self.write_config(target, key, param, data).await {
+                Ok((_, verification)) => RollbackStep {
+                    param: param.set,
+                    restored: data.clone(),
+                    verified: verification.verified,
+                    error: None,
+                },
+                Err(e) => RollbackStep {
+                    param: param.set,
+                    restored: data.clone(),
+                    verified: false,
+                    error: Some(e),
+                },
+            };
+            rollback.push(step);
+        }
+        let rolled_back = rollback.iter().all(|step| step.verified);
+        if !rolled_back {
+            error!(
+                "Config transaction rollback incomplete for {}:{}",
+                key.ip, key.port
+            );
+        }
+        Ok(ConfigTransactionResult {
+            key: *key,
+            committed: false,
+            applied,
+            error: Some(error),
+            rollback,
+            rolled_back,
+        })
+    }
+
+    /// 티칭을 요청한 클라이언트에게 `teaching_progress` 이벤트 전송
+    async fn send_teaching_progress(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        session: &TeachingSession,
+    ) {
+        let event = WsEvent::TeachingProgress(session.progress().clone());
+        self.send_event(client_id, version, &event).await;
+    }
+
+    /// 특정 클라이언트에게 프로토콜 버전과 전송 형식에 맞게 이벤트 전송
+    async fn send_event(&self, client_id: Uuid, version: ProtocolVersion, event: &WsEvent<'_>) {
+        let format = self.format(client_id).await;
+        match event_message(version, format, event) {
+            Ok(message) => {
+                if let Err(e) = self.send_to(client_id, message).await {
+                    error!("{}", e);
+                }
+            }
+            Err(e) => error!("Failed to serialize event: {}", e),
+        }
+    }
+
+    /// 등록된 LiDAR 장치 목록과 현재 동작 상태 (`lidar_list` 응답, `GET /lidars`)
+    ///
+    /// # Returns
+    /// * `Vec<LiDARStatus>` - IP, 포트 순으로 정렬된 장치 목록
+    ///
+    /// # 동작 설명
+    /// * 처음/마지막 수신 시각과 펌웨어 버전은 장치 저장소, 수신 주기와 모터 속도는 마지막 텔레메트리 샘플 사용
+    /// * 온라인 여부는 LiDAR 가 속한 사이트의 `offline_ms` 기준 (속한 사이트가 없으면 5000 ms)
+    /// * 오프라인 LiDAR 의 수신 주기는 마지막 샘플 값 대신 null
+    pub async fn lidar_status(&self) -> Vec<LiDARStatus> {
+        let now_ms = unix_time_ms();
+        let mut lidars = Vec::new();
+        for info in self.registry.list().await {
+            let online =
+                now_ms.saturating_sub(info.last_seen) <= self.sites.config().offline_ms(&info.key);
+            let sample = self
+                .telemetry
+                .history(&info.key, 1)
+                .await
+                .and_then(|mut history| history.pop());
+            lidars.push(LiDARStatus {
+                online,
+                frame_rate: sample
+                    .as_ref()
+                    .filter(|_| online)
+                    .map(|sample| sample.frame_rate),
+                motor_speed: sample.and_then(|sample| sample.motor_speed),
+                info,
+            });
+        }
+        lidars
+    }
+
+    /// 해당 LiDAR 를 구독 중인 클라이언트에게 `telemetry` 이벤트 전송
+    pub async fn send_telemetry(&self, sample: &TelemetrySample) {
+        self.send_to_subscribers(&sample.key, &WsEvent::Telemetry(sample.clone()))
+            .await;
+    }
+
+    /// 해당 LiDAR 를 구독 중인 클라이언트에게 이벤트 전송 (메시지 버스에도 발행)
+    ///
+    /// # Arguments
+    /// * `key` - 이벤트 대상 LiDAR 키
+    /// * `event` - 보낼 이벤트
+    pub async fn send_to_subscribers(&self, key: &LiDARKey, event: &WsEvent<'_>) {
+        self.publish_bus_event(event);
+        let targets = self.routes.lock().await.subscribers(key);
+        let mut clients = self.clients.lock().await;
+        for client_id in &targets {
+            let Some(client) = clients.get_mut(client_id) else {
+                continue;
+            };
+            let message = match event_message(client.protocol_version, client.format(), event) {
+                Ok(message) => message,
+                Err(e) => {
+                    error!("Failed to serialize event: {}", e);
+                    continue;
+                }
+            };
+            if let Err(e) = client.send(message) {
+                error!("Failed to send message to {}: {}", client_id, e);
+            }
+        }
+    }
+
+    /// 알림을 모든 클라이언트(`alert` 이벤트)와 webhook 으로 전송 (시계열 데이터베이스 사용 시 기록)
+    pub async fn publish_alerts(&self, alerts: Vec<Alert>) {
+        let Some(engine) = &self.alerts else {
+            return;
+        };
+        for alert in alerts {
+            info!(
+                "Alert {} {:?} for {}:{}: {}",
+                alert.rule, alert.state, alert.key.ip, alert.key.port, alert.message
+            );
+            engine.notify(&alert);
+            if let Some(events) = &self.events {
+                events.store(&alert);
+            }
+            #[cfg(feature = "influx")]
+            if let Some(influx) = &self.influx {
+                influx.write_alert(&alert);
+            }
+            self.broadcast_event(&WsEvent::Alert(alert)).await;
+        }
+    }
+
+    /// LiDAR 키 변경 반영
+    ///
+    /// # Arguments
+    /// * `change` - 장치 저장소에서 알린 키 변경 정보
+    ///
+    /// # 동작 설명
+    /// * 텔레메트리 샘플 이력과 추적 중인 물체를 새 키로 이동
+    /// * 이전 키를 구독 중인 클라이언트의 구독 LiDAR 목록을 새 키로 변경 (라우팅 테이블, 이중화용 구독 저장소 포함)
+    /// * 모든 클라이언트에게 `lidar_identity_changed` 이벤트 전송
+    pub async fn apply_identity_change(&self, change: IdentityChange) {
+        self.telemetry.rename(&change.previous, change.key).await;
+        if let Some(tracker) = &self.tracker {
+            tracker.rename(&change.previous, change.key).await;
+        }
+        {
+            let mut routes = self.routes.lock().await;
+            let mut clients = self.clients.lock().await;
+            for (client_id, client) in clients.iter_mut() {
+                let Some(subscription) = client.subscription.as_mut() else {
+                    continue;
+                };
+                let Some(lidars) = subscription.lidars.as_mut() else {
+                    continue;
+                };
+                if !lidars.contains(&change.previous) {
+                    continue;
+                }
+                lidars.retain(|key| *key != change.previous && *key != change.key);
+                lidars.push(change.key);
+                routes.update(*client_id, Some(subscription));
+                self.subscriptions
+                    .set(*client_id, Some(subscription.clone()))
+                    .await;
+            }
+        }
+        self.broadcast_event(&WsEvent::LidarIdentityChanged(change))
+            .await;
+    }
+
+    /// 메시지 버스 사용 시 장치 이벤트 발행
+    fn publish_bus_event(&self, event: &WsEvent<'_>) {
+        #[cfg(feature = "nats")]
+        if let Some(bus) = &self.bus {
+            bus.publish_event(event);
+        }
+        #[cfg(not(feature = "nats"))]
+        let _ = event;
+    }
+
+    /// 모든 클라이언트에게 프로토콜 버전에 맞게 이벤트 전송
+    pub async fn broadcast_event(&self, event: &WsEvent<'_>) {
+        self.publish_bus_event(event);
+        let mut clients = self.clients.lock().await;
+        for (client_id, client) in clients.iter_mut() {
+            let message = match event_message(client.protocol_version, client.format(), event) {
+                Ok(message) => message,
+                Err(e) => {
+                    error!("Failed to serialize event: {}", e);
+                    continue;
+                }
+            };
+            if let Err(e) = client.send(message) {
+                error!("Failed to send message to {}: {}", client_id, e);
+            }
+        }
+    }
+
+    /// annotate 요청 처리 (녹화 주석 마커 기록)
+    ///
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID
+    /// * `request` - 요청 메시지
+    /// * `annotate` - 마커 내용, 관련 LiDAR, 시각
+    ///
+    /// # Returns
+    /// * `Reply` - 성공 시 기록한 마커, 녹화 중이 아니거나 내용이 비어 있으면 `invalid_request` 에러,
+    ///   목록 파일 쓰기에 실패하면 `internal` 에러
+    ///
+    /// # 동작 설명
+    /// * 목록 파일 쓰기는 blocking 스레드에서 처리
+    /// * 요청한 클라이언트를 제외하고 구독 중인 클라이언트에게 `annotation` 이벤트 전송
+    ///   (`key` 가 있으면 그 LiDAR 를 구독 중인 클라이언트에게만)
+    async fn annotate(
+        &self,
+        client_id: Uuid,
+        request: &RequestMessage,
+        annotate: AnnotateRequest,
+    ) -> Reply {
+        let Some(recorder) = self.recorder.clone() else {
+            return Reply::error(
+                request,
+                ErrorCode::InvalidRequest,
+                "Recording is not enabled",
+            );
+        };
+        let label = annotate.label.trim();
+        if label.is_empty() {
+            return Reply::error(request, ErrorCode::InvalidRequest, "Empty annotation label");
+        }
+
+        let data = AnnotationData {
+            recording: recorder.name(),
+            marker: Marker {
+                time_ms: annotate.time_ms.unwrap_or_else(unix_time_ms),
+                label: label.to_string(),
+                key: annotate.key,
+                client_id,
+            },
+        };
+        let marker = data.marker.clone();
+        match tokio::task::spawn_blocking(move || recorder.annotate(marker)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => return Reply::error(request, ErrorCode::Internal, &e),
+            Err(e) => return Reply::error(request, ErrorCode::Internal, &e.to_string()),
+        }
+        info!(
+            "Annotation from {} in {}: {}",
+            client_id, data.recording, data.marker.label
+        );
+
+        let event = WsEvent::Annotation(data.clone());
+        self.publish_bus_event(&event);
+        let targets = match &data.marker.key {
+            Some(key) => self.routes.lock().await.subscribers(key),
+            None => self
+                .clients
+                .lock()
+                .await
+                .iter()
+                .filter(|(_, client)| client.subscription.is_some())
+                .map(|(id, _)| *id)
+                .collect(),
+        };
+        let mut clients = self.clients.lock().await;
+        for target in targets.iter().filter(|target| **target != client_id) {
+            let Some(client) = clients.get_mut(target) else {
+                continue;
+            };
+            let message = match event_message(client.protocol_version, client.format(), &event) {
+                Ok(message) => message,
+                Err(e) => {
+                    error!("Failed to serialize annotation: {}", e);
+                    continue;
+                }
+            };
+            if let Err(e) = client.send(message) {
+                error!("Failed to send message to {}: {}", target, e);
+            }
+        }
+        drop(clients);
+
+        Reply::ok(request, WsResponse::Annotate(data))
+    }
+
+    /// playback 요청 처리 (녹화 파일 재생 제어)
+    ///
+    /// # Returns
+    /// * `Reply` - 성공 시 재생 상태, 실패 시 `invalid_request` 에러
+    async fn playback(&self, request: &RequestMessage, playback: PlaybackRequest) -> Reply {
+        match self.control_playback(playback).await {
+            Ok(status) => Reply::ok(request, WsResponse::Playback(status)),
+            Err((code, e)) => Reply::error(request, code, &e),
+        }
+    }
+
+    /// 녹화 파일 재생기에 재생 제어 요청 적용 (WebSocket `playback` 명령과 REST `/playback` 공용)
+    ///
+    /// # Arguments
+    /// * `playback` - 재생 제어 요청
+    ///
+    /// # Returns
+    /// * `Result<Option<PlaybackStatus>, (ErrorCode, String)>` - 성공 시 재생 상태 (재생 중이 아니면 `Status` 요청에 None),
+    ///   잘못된 요청이면 `invalid_request`, 처리 스레드 실패 시 `internal` 에러
+    ///
+    /// 녹화 파일 색인 생성 등 파일 입출력이 있으므로 blocking 스레드에서 처리
+    pub async fn control_playback(
+        &self,
+        playback: PlaybackRequest,
+    ) -> Result<Option<PlaybackStatus>, (ErrorCode, String)> {
+        let player = self.player.clone();
+        let result = tokio::task::spawn_blocking(move || match playback {
+            PlaybackRequest::Start { name, speed } => player.start(&name, speed).map(Some),
+            PlaybackRequest::Pause => player.pause().map(Some),
+            PlaybackRequest::Resume => player.resume().map(Some),
+            PlaybackRequest::Seek { position_ms } => player.seek(position_ms).map(Some),
+            PlaybackRequest::Speed { speed } => player.set_speed(speed).map(Some),
+            PlaybackRequest::Stop => player.stop().map(Some),
+            PlaybackRequest::Status => Ok(player.status()),
+        })
+        .await;
+
+        match result {
+            Ok(Ok(status)) => Ok(status),
+            Ok(Err(e)) => Err((ErrorCode::InvalidRequest, e)),
+            Err(e) => Err((ErrorCode::Internal, e.to_string())),
+        }
+    }
+
+    /// hello 요청 처리 (프로토콜 버전 협상)
+    ///
+    /// # Returns
+    /// * `(Reply, ProtocolVersion)` - 응답과 응답에 사용할 프로토콜 버전
+    ///
+    /// # 동작 설명
+    /// * 지원하는 버전이면 클라이언트 버전을 변경하고 변경된 버전으로 응답
+    /// * 지원하지 않는 버전이면 기존 버전을 유지하고 지원 버전 목록과 함께 에러 응답
+    async fn hello(
+        &self,
+        client_id: Uuid,
+        current: ProtocolVersion,
+        request: &RequestMessage,
+        hello: &HelloRequest,
+    ) -> (Reply, ProtocolVersion) {
+        let requested = hello.protocol_version;
+        if let Some(path) = &hello.relay_path {
+            if self.relay.is_loop(path) {
+                warn!("Client {} rejected: relay loop {:?}", client_id, path);
+                return (
+                    Reply::error(
+                        request,
+                        ErrorCode::InvalidRequest,
+                        &format!("Relay loop detected: {:?}", path),
+                    ),
+                    current,
+                );
+            }
+        }
+        if let Some(Err(e)) = hello.session.as_deref().map(validate_session_token) {
+            return (
+                Reply::error(request, ErrorCode::InvalidRequest, &e),
+                current,
+            );
+        }
+        // 세션 저장을 사용하지 않으면 세션 토큰은 무시
+        let session = self.sessions.as_ref().and(hello.session.clone());
+        match ProtocolVersion::from_u64(requested) {
+            Some(version) => {
+                if let Some(client) = self.clients.lock().await.get_mut(&client_id) {
+                    client.protocol_version = version;
+                    client.session = session.clone();
+                }
+                info!(
+                    "Client {} negotiated protocol version {}",
+                    client_id,
+                    version.as_u8()
+                );
+                let mut resumed = false;
+                if let Some(previous) = hello.resume {
+                    if let Some(subscription) = self.subscriptions.take_mirrored(&previous).await {
+                        info!("Client {} resumed subscription of {}", client_id, previous);
+                        self.set_subscription(client_id, Some(subscription)).await;
+                        resumed = true;
+                    }
+                }
+                let mut restored = false;
+                if let (false, Some(sessions), Some(token)) = (resumed, &self.sessions, &session) {
+                    if let Some(subscription) = sessions.get(token).await {
+                        // 저장 후 설정에서 역할이 빠졌을 수 있으므로 다시 확인
+                        match self.bandwidth.cap(Some(&subscription)) {
+                            Ok(_) => {
+                                info!("Client {} restored session {}", client_id, token);
+                                self.set_subscription(client_id, Some(subscription)).await;
+                                restored = true;
+                            }
+                            Err(e) => {
+                                warn!(
+                                    "Client {} failed to restore session {}: {}",
+                                    client_id, token, e
+                                )
+                            }
+                        }
+                    }
+                }
+                (
+                    Reply::ok(
+                        request,
+                        WsResponse::Hello(hello_data(
+                            version,
+                            client_id,
+                            resumed,
+                            restored,
+                            self.relay.server_id(),
+                            self.relay.path(),
+                        )),
+                    ),
+                    version,
+                )
+            }
+            None => {
+                warn!(
+                    "Client {} requested unsupported protocol version {}",
+                    client_id, requested
+                );
+                (
+                    Reply::error(
+                        request,
+                        ErrorCode::UnsupportedVersion,
+                        &format!(
+                            "Unsupported protocol version {}, supported versions: {:?}",
+                            requested,
+                            ProtocolVersion::SUPPORTED
+                        ),
+                    ),
+                    current,
+                )
+            }
+        }
+    }
+
+    /// 클라이언트의 프로토콜 버전 조회 (연결되지 않은 클라이언트는 기본값)
+    pub async fn protocol_version(&self, client_id: Uuid) -> ProtocolVersion {
+        self.clients
+            .lock()
+            .await
+            .get(&client_id)
+            .map(|client| client.protocol_version)
+            .unwrap_or_default()
+    }
+
+    /// 클라이언트의 응답/이벤트 전송 형식 조회 (연결되지 않은 클라이언트는 JSON)
+    pub async fn encoding(&self, client_id: Uuid) -> WireEncoding {
+        self.clients
+            .lock()
+            .await
+            .get(&client_id)
+            .map(|client| client.encoding())
+            .unwrap_or_default()
+    }
+
+    /// 클라이언트의 응답/이벤트 전송 형식과 JSON 숫자 자릿수 조회 (연결되지 않은 클라이언트는 전체 자릿수 JSON)
+    pub async fn format(&self, client_id: Uuid) -> WireFormat {
+        self.clients
+            .lock()
+            .await
+            .get(&client_id)
+            .map(|client| client.format())
+            .unwrap_or_default()
+    }
+
+    /// LiDAR 데이터를 구독 중인 클라이언트가 있는지 확인
+    pub async fn has_subscriber(&self, key: &LiDARKey) -> bool {
+        self.routes.lock().await.has_subscriber(key)
+    }
+
+    /// 임시 저장된 세그먼트가 있으면 `spool_available` 이벤트 전송
+    ///
+    /// 현재 쓰고 있는 세그먼트를 닫아 클라이언트가 바로 재생할 수 있도록 함
+    async fn notify_spool(&self, client_id: Uuid, version: ProtocolVersion) {
+        let Some(spool) = &self.spool else {
+            return;
+        };
+        let recordings = match spool.rotate().await {
+            Ok(recordings) if !recordings.is_empty() => recordings,
+            Ok(_) => return,
+            Err(e) => {
+                error!("Failed to list spool segments: {}", e);
+                return;
+            }
+        };
+        let event = WsEvent::SpoolAvailable(SpoolData { recordings });
+        self.send_event(client_id, version, &event).await;
+    }
+
+    /// 클라이언트의 LiDAR 데이터 구독 설정 변경 (구독에 맞는 전송 대역폭 제한도 함께 적용)
+    ///
+    /// 클라이언트가 세션 토큰을 보냈으면 변경한 구독을 세션 저장소에도 저장 (구독 해제 시 세션 삭제)
+    async fn set_subscription(&self, client_id: Uuid, subscription: Option<Subscription>) {
+        let session = {
+            let mut routes = self.routes.lock().await;
+            let mut clients = self.clients.lock().await;
+            let Some(client) = clients.get_mut(&client_id) else {
+                return;
+            };
+            routes.update(client_id, subscription.as_ref());
+            let cap = self
+                .bandwidth
+                .cap(subscription.as_ref())
+                .unwrap_or(self.bandwidth.max_bytes_per_sec);
+            client.bandwidth.set_cap(cap, self.bandwidth.max_level);
+            self.subscriptions
+                .set(client_id, subscription.clone())
+                .await;
+            client.subscription = subscription.clone();
+            client.session.clone()
+        };
+        if let (Some(sessions), Some(token)) = (&self.sessions, session) {
+            if let Err(e) = sessions.save(&token, subscription).await {
+                error!("Failed to save session {}: {}", token, e);
+            }
+        }
+    }
+
+    /// 검증된 클라이언트 입력 처리
+    ///
+    /// # Arguments
+    /// * `client_id` - 요청한 클라이언트 ID
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `inbound` - 입력 검증 결과
+    /// * `raw` - 수신한 원본 메시지
+    ///
+    /// # Returns
+    /// * `Option<(Vec<u8>, Vec<SocketAddr>)>` - LiDAR 로 전달할 데이터와 송신 주소,
+    ///   서버에서 처리했거나 거부한 경우 None
+    pub async fn process_inbound(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        inbound: Inbound,
+        raw: &[u8],
+    ) -> Option<(Vec<u8>, Vec<SocketAddr>)> {
+        match inbound {
+            Inbound::Request(request) => {
+                if self.handle_request(client_id, &request).await {
+                    None
+                } else {
+                    let command = request.command.clone();
+                    self.route_forward(client_id, version, request.id, &command, raw.to_vec())
+                        .await
+                }
+            }
+            Inbound::Forward(data) => self.route_forward(client_id, version, None, "", data).await,
+            Inbound::Reject { id, code, message } => {
+                warn!("Rejected message from {}: {}", client_id, message);
+                access_log(client_id, "", "reject", None, Instant::now(), code.as_str());
+                let reply = Reply {
+                    command: String::new(),
+                    id,
+                    result: Err((code, message)),
+                };
+                self.reply(client_id, version, &reply).await;
+                None
+            }
+        }
+    }
+
+    /// LiDAR 로 전달할 클라이언트 메시지의 송신 대상 확인
+    ///
+    /// # Arguments
+    /// * `client_id` - 보낸 클라이언트 ID
+    /// * `version` - 클라이언트 프로토콜 버전
+    /// * `id` - 요청 ID (JSON 요청이 아니면 None)
+    /// * `command` - 요청 명령 (JSON 요청이 아니면 빈 문자열)
+    /// * `data` - LiDAR 로 전달할 데이터
+    ///
+    /// # Returns
+    /// * `Option<(Vec<u8>, Vec<SocketAddr>)>` - 전달할 데이터와 대상 LiDAR 의 현재 송신 주소,
+    ///   대상을 확인할 수 없으면 `invalid_request` 에러로 응답하고 None
+    ///
+    /// # 동작 설명
+    /// * 명령 프레임의 제품 라인/ID 로 장치 저장소에서 온라인 LiDAR 를 찾음 (`routing::forward_targets`)
+    /// * 명령 프레임이 아니거나, 등록되지 않았거나, 오프라인/비활성화된 LiDAR 대상이면 전달하지 않음
+    async fn route_forward(
+        &self,
+        client_id: Uuid,
+        version: ProtocolVersion,
+        id: Option<serde_json::Value>,
+        command: &str,
+        data: Vec<u8>,
+    ) -> Option<(Vec<u8>, Vec<SocketAddr>)> {
+        let started = Instant::now();
+        let lidars = self.lidar_status().await;
+        match forward_targets(&data, &lidars) {
+            Ok(targets) => {
+                for (key, target) in &targets {
+                    debug!("Forwarding {} bytes to {} ({:?})", data.len(), target, key);
+                }
+                access_log(
+                    client_id,
+                    command,
+                    "forward",
+                    targets.first().map(|(key, _)| *key),
+                    started,
+                    "forwarded",
+                );
+                Some((
+                    data,
+                    targets.into_iter().map(|(_, target)| target).collect(),
+                ))
+            }
+            Err(message) => {
+                warn!("Rejected forward from {}: {}", client_id, message);
+                let code = ErrorCode::InvalidRequest;
+                access_log(client_id, command, "reject", None, started, code.as_str());
+                let reply = Reply {
+                    command: command.to_string(),
+                    id,
+                    result: Err((code, message)),
+                };
+                self.reply(client_id, version, &reply).await;
+                None
+            }
+        }
+    }
+
+    /// 요청 처리 결과를 클라이언트의 프로토콜 버전과 전송 형식에 맞게 전송
+    ///
+    /// # Arguments
+    /// * `client_id` - 대상 클라이언트 ID
+    /// * `version` - 응답에 사용할 프로토콜 버전
+    /// * `reply` - 요청 처리 결과
+    ///
+    /// v2 응답이 `chunk_size` 를 넘으면 `chunk` 메시지로 나눠서 다른 메시지가 끼지 않도록 이어서 전송
+    pub async fn reply(&self, client_id: Uuid, version: ProtocolVersion, reply: &Reply) {
+        let format = self.format(client_id).await;
+        let message = match reply.to_message(version, format) {
+            Ok(message) => message,
+            Err(e) => {
+                error!("Failed to serialize response: {}", e);
+                return;
+            }
+        };
+        let messages = match (version, self.chunk_size) {
+            (ProtocolVersion::V2, Some(chunk_size)) => {
+                chunk::split(message, chunk_size, reply.id.as_ref(), &reply.command)
+            }
+            _ => vec![message],
+        };
+
+        let mut clients = self.clients.lock().await;
+        let Some(client) = clients.get_mut(&client_id) else {
+            error!("Client not found: {}", client_id);
+            return;
+        };
+        for message in messages {
+            if let Err(e) = client.send(message) {
+                error!("Failed to send message to {}: {}", client_id, e);
+                return;
+            }
+        }
+    }
+
+    /// 특정 클라이언트에게 메시지 전송
+    ///
+    /// # Arguments
+    /// * `client_id` - 대상 클라이언트 ID
+    /// * `message` - 전송할 메시지
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 실패 시 에러 메시지
+    pub async fn send_to(&self, client_id: Uuid, message: Message) -> Result<(), String> {
+        let mut clients = self.clients.lock().await;
+        match clients.get_mut(&client_id) {
+            Some(client) => client
+                .send(message)
+                .map_err(|e| format!("Failed to send message to {}: {}", client_id, e)),
+            None => Err(format!("Client not found: {}", client_id)),
+        }
+    }
+
+    /// `statistics` 스트림 구독 클라이언트에게 프레임 통계 전송
+    ///
+    /// # Arguments
+    /// * `client_id` - 대상 클라이언트 ID
+    /// * `client` - 대상 클라이언트 상태
+    /// * `packet` - 수신한 LiDAR 데이터
+    /// * `points` - 채널별 포인트 클라우드
+    ///
+    /// 클라이언트별, LiDAR 별로 `statistics.interval_ms` 에 한 번만 전송
+    async fn send_statistics(
+        &self,
+        client_id: &Uuid,
+        client: &mut ClientState,
+        packet: &LiDARPacket,
+        points: &[PointCloud],
+    ) {
+        let interval = Duration::from_millis(self.processing.statistics.interval_ms);
+        if client
+            .last_statistics
+            .get(&packet.key)
+            .is_some_and(|last| last.elapsed() < interval)
+        {
+            return;
+        }
+        client.last_statistics.insert(packet.key, Instant::now());
+
+        let filtered = client
+            .subscription
+            .as_ref()
+            .and_then(|subscription| subscription.filter_points(points));
+        let statistics = FrameStatistics::compute(
+            filtered.as_deref().unwrap_or(points),
+            &self.processing.statistics,
+        );
+        let event = WsEvent::Statistics(StatisticsData {
+            key: packet.key,
+            statistics,
+            replay: packet.replay,
+        });
+        match event_message(client.protocol_version, client.format(), &event) {
+            Ok(message) => {
+                if let Err(e) = client.send_data(message) {
+                    error!("Failed to send message to {}: {}", client_id, e);
+                }
+            }
+            Err(e) => error!("Failed to serialize statistics: {}", e),
+        }
+    }
+
+    /// `objects` 스트림 구독 클라이언트에게 확정 물체 목록 전송
+    ///
+    /// # Arguments
+    /// * `client_id` - 대상 클라이언트 ID
+    /// * `client` - 대상 클라이언트 상태
+    /// * `objects` - 이번 프레임의 확정 물체 목록
+    fn send_objects(client_id: &Uuid, client: &mut ClientState, objects: &TrackedObjects) {
+        let event = WsEvent::Objects(objects.clone());
+        match event_message(client.protocol_version, client.format(), &event) {
+            Ok(message) => {
+                if let Err(e) = client.send_data(message) {
+                    error!("Failed to send message to {}: {}", client_id, e);
+                }
+            }
+            Err(e) => error!("Failed to serialize objects: {}", e),
+        }
+    }
+
+    /// `occupancy` 스트림 구독 클라이언트에게 2D 점유 격자 전송
+    ///
+    /// # Arguments
+    /// * `client_id` - 대상 클라이언트 ID
+    /// * `client` - 대상 클라이언트 상태
+    /// * `packet` - 수신한 LiDAR 데이터
+    /// * `points` - 채널별 포인트 클라우드
+    ///
+    /// # 동작 설명
+    /// * 구독 필터를 적용한 포인트 클라우드를 LiDAR 채널별 최신 데이터로 저장
+    /// * `occupancy.fuse` 이면 저장된 모든 LiDAR 데이터를, 아니면 해당 LiDAR 데이터만 격자로 변환
+    ///   (복셀 누적 사용 시 합친 데이터를 복셀마다 포인트 하나로 줄인 뒤 변환)
+    /// * 격자별로 `occupancy.interval_ms` 에 한 번만 전송
+    async fn send_occupancy(
+        &self,
+        client_id: &Uuid,
+        client: &mut ClientState,
+        packet: &LiDARPacket,
+        points: &[PointCloud],
+    ) {
+        let config = &self.processing.occupancy;
+        let filtered = client
+            .subscription
+            .as_ref()
+            .and_then(|subscription| subscription.filter_points(points));
+        client
+            .frames
+            .update(packet.key, filtered.as_deref().unwrap_or(points));
+
+        let grid_key = (!config.fuse).then_some(packet.key);
+        let interval = Duration::from_millis(config.interval_ms);
+        if client
+            .last_occupancy
+            .get(&grid_key)
+            .is_some_and(|last| last.elapsed() < interval)
+        {
+            return;
+        }
+        client.last_occupancy.insert(grid_key, Instant::now());
+
+        let grid = match (&grid_key, &self.voxelizer) {
+            (None, Some(voxelizer)) => client.frames.voxel_grid(config, voxelizer),
+            _ => client.frames.grid(grid_key.as_ref(), config),
+        };
+        let data = match grid.encode_base64(config.encoding) {
+            Ok(data) => data,
+            Err(e) => {
+                error!("Failed to encode occupancy grid: {}", e);
+                return;
+            }
+        };
+        let event = WsEvent::OccupancyGrid(OccupancyGridData {
+            key: grid_key,
+            resolution: grid.resolution(),
+            origin: grid.origin(),
+            width: grid.width(),
+            height: grid.height(),
+            encoding: config.encoding,
+            data,
+            replay: packet.replay,
+        });
+        match event_message(client.protocol_version, client.format(), &event) {
+            Ok(message) => {
+                if let Err(e) = client.send_data(message) {
+                    error!("Failed to send message to {}: {}", client_id, e);
+                }
+            }
+            Err(e) => error!("Failed to serialize occupancy grid: {}", e),
+        }
+    }
+
+    /// UDP 에서 수신한 LiDAR 데이터를 클라이언트에게 전달
+    ///
+    /// # Arguments
+    /// * `packet` - LiDAR 키와 회사 구분값 + bincode 인코딩 데이터
+    ///
+    /// # 동작 설명
+    /// * 포인트 클라우드: 구독 중인 클라이언트에게만 전달
+    ///   - 구독에 관심 영역(`roi`)이 있으면 영역 안의 포인트만 전송
+    ///   - 구독에 채널 목록(`channels`)이 있으면 해당 채널의 포인트만 전송, 구독 채널의 포인트가 없으면 전송하지 않음
+    ///   - 구독에 최대 전송 주기(`max_rate_hz`)가 있으면 LiDAR 별로 주기를 넘는 프레임은 건너뛰고, `decimation` 이 있으면 포인트를 솎아 전송
+    ///   - 월드 좌표(`frame: "world"`) 구독은 odometry 자세로 변환하며, 프레임 시각의 자세가 없으면 전송하지 않음
+    ///   - `statistics` 스트림 구독 클라이언트는 포인트 대신 `statistics.interval_ms` 마다 `statistics` 이벤트 전송
+    ///   - `occupancy` 스트림 구독 클라이언트는 포인트 대신 `occupancy.interval_ms` 마다 `occupancy_grid` 이벤트 전송
+    ///   - `objects` 스트림 구독 클라이언트는 포인트 대신 물체 추적 결과(`objects` 이벤트) 전송 (녹화 재생 데이터 제외)
+    ///   - 전송 지연이 커진 클라이언트는 품질 단계에 따라 프레임을 건너뛰고 `quality` 이벤트로 알림
+    ///   - 전송량이 대역폭 제한을 넘은 클라이언트는 포인트를 더 솎아 전송하고, 1초 구간의 제한을 다 쓰면 프레임을 건너뜀
+    ///   - v1: `point_cloud` JSON 응답 (`key`, `points`, `replay`)
+    ///   - v2: 회사 구분값 + bincode 인코딩 데이터를 그대로 binary 프레임으로 전송,
+    ///     녹화 재생 데이터는 `point_cloud` JSON 이벤트 (`replay: true`)
+    /// * 설정 응답: 모든 클라이언트에게 `config` 이벤트 (`key`, `config`) 로 전달, 진단 응답 수집을 위해 `config_tx` 로도 전달
+    pub async fn dispatch_packet(&self, packet: LiDARPacket) {
+        let Some((&company, encoded)) = packet.payload.split_first() else {
+            return;
+        };
+        match CompanyInfo::try_from(company) {
+            Ok(CompanyInfo::KanaviMobility) => {}
+            Ok(_) => {
+                error!("Unknown company");
+                return;
+            }
+            Err(_) => {
+                error!("Failed to convert company info");
+                return;
+            }
+        }
+
+        let lidar_data: KanaviMobilityData = match decode_from_slice(encoded, standard()) {
+            Ok((data, _)) => data,
+            Err(e) => {
+                error!("Failed to decode LiDAR data: {}", e);
+                return;
+            }
+        };
+        let mut objects = None;
+        if !packet.replay {
+            self.telemetry.observe(packet.key, &lidar_data).await;
+            if let Some(KMConfigData::VersionInfo(version)) = lidar_data.get_config_data() {
+                self.registry
+                    .set_firmware_version(&packet.key, version.firmware_version_text())
+                    .await;
+            }
+            #[cfg(feature = "shm")]
+            if let Some(shm) = &self.shm {
+                for (channel, cloud) in lidar_data.get_points().iter().enumerate() {
+                    if !cloud.points.is_empty() {
+                        shm.publish(packet.key, channel as u8, &cloud.points).await;
+                    }
+                }
+            }
+            if let Some(alerts) = &self.alerts {
+                if !lidar_data.get_points().is_empty() {
+                    let raised = alerts
+                        .observe_frame(packet.key, lidar_data.get_points())
+                        .await;
+                    self.publish_alerts(raised).await;
+                }
+            }
+            if let Some(calibration) = &self.calibration {
+                calibration
+                    .observe(packet.key, lidar_data.get_points())
+                    .await;
+            }
+            #[cfg(feature = "onnx")]
+            if let Some(inference) = &self.inference {
+                if !lidar_data.get_points().is_empty() {
+                    inference
+                        .observe(packet.key, packet.time_ms, lidar_data.get_points())
+                        .await;
+                }
+            }
+            if let Some(tracker) = &self.tracker {
+                if !lidar_data.get_points().is_empty() {
+                    let steps = tracker
+                        .observe(packet.key, packet.time_ms, lidar_data.get_points())
+                        .await;
+                    if let Some(counts) = &self.counts {
+                        counts.observe(&steps).await;
+                    }
+                    let tracked = tracker.objects(packet.key, packet.time_ms, &steps);
+                    #[cfg(feature = "influx")]
+                    if let Some(influx) = &self.influx {
+                        influx.write_tracks(&tracked);
+                    }
+                    if let Some(alerts) = &self.alerts {
+                        let raised = alerts.observe_objects(&tracked).await;
+                        self.publish_alerts(raised).await;
+                    }
+                    objects = Some(tracked);
+                }
+            }
+        }
+
+        let targets = self.routes.lock().await.subscribers(&packet.key);
+        let mut clients = self.clients.lock().await;
+        if !lidar_data.get_points().is_empty() {
+            let motion = self
+                .odometry
+                .frame_motion(packet.time_ms, &self.processing.deskew);
+            let event = WsEvent::PointCloud(PointCloudData {
+                key: packet.key,
+                points: lidar_data.get_points(),
+                replay: packet.replay,
+                time_ms: packet.time_ms,
+                convention: None,
+                timing: None,
+            });
+            let mut cached = HashMap::new();
+            for client_id in &targets {
+                let Some(client) = clients.get_mut(client_id) else {
+                    continue;
+                };
+                // 제어 전용 클라이언트에게는 포인트 클라우드와 데이터 프레임을 보내지 않음
+                if client.control {
+                    continue;
+                }
+                if !client.subscription.as_ref().is_some_and(|subscription| {
+                    subscription.matches_channels(lidar_data.get_points())
+                }) {
+                    continue;
+                }
+                match client
+                    .subscription
+                    .as_ref()
+                    .map(|subscription| subscription.stream)
+                {
+                    Some(StreamType::Statistics) => {
+                        self.send_statistics(client_id, client, &packet, lidar_data.get_points())
+                            .await;
+                        continue;
+                    }
+                    Some(StreamType::Occupancy) => {
+                        self.send_occupancy(client_id, client, &packet, lidar_data.get_points())
+                            .await;
+                        continue;
+                    }
+                    Some(StreamType::Objects) => {
+                        if let Some(objects) = &objects {
+                            Self::send_objects(client_id, client, objects);
+                        }
+                        continue;
+                    }
+                    _ => {}
+                }
+                if !client.within_rate(&packet.key)
+                    || !client.quality.should_send(&packet.key)
+                    || !client.bandwidth.should_send()
+                {
+                    continue;
+                }
+                // 월드 좌표 구독은 프레임 시각의 자세가 없으면 LiDAR 좌표로 보내지 않고 건너뜀
+                if motion.poses.is_none()
+                    && client
+                        .subscription
+                        .as_ref()
+                        .is_some_and(|subscription| subscription.frame == OutputFrame::World)
+                {
+                    debug!(
+                        "No odometry pose at {} for world frame client {}",
+                        packet.time_ms, client_id
+                    );
+                    continue;
+                }
+
+                // 관심 영역, 채널 등 구독 필터나 출력 좌표계가 있으면 클라이언트별로 필터링/변환한 데이터 전송
+                let thinning = client.bandwidth.decimation();
+                let filtered = client.subscription.as_ref().and_then(|subscription| {
+                    subscription.prepare_points(
+                        lidar_data.get_points(),
+                        &self.processing.deskew,
+                        &motion,
+                        thinning,
+                    )
+                });
+
+                // v2 실시간 데이터는 binary, 재생 데이터는 replay 표시를 위해 JSON 이벤트로 전송
+                // (Protobuf 전송 형식은 항상 `ScanFrame` 이벤트, 포인트별 측정 시각은 binary 프레임에 담을 수 없으므로 이벤트)
+                let bincode_frame = !packet.replay
+                    && client.encoding() != WireEncoding::Protobuf
+                    && filtered
+                        .as_ref()
+                        .is_none_or(|filtered| filtered.offsets_us.is_none());
+                let message = match (client.protocol_version, filtered) {
+                    (ProtocolVersion::V2, None) if bincode_frame => {
+                        Message::Binary(Bytes::copy_from_slice(&packet.payload))
+                    }
+                    (ProtocolVersion::V2, Some(filtered)) if bincode_frame => {
+                        match encode_points(company, encoded, filtered.points) {
+                            Ok(payload) => Message::Binary(Bytes::from(payload)),
+                            Err(e) => {
+                                error!("Failed to encode filtered point cloud: {}", e);
+                                continue;
+                            }
+                        }
+                    }
+                    (version, None) => {
+                        let format = client.format();
+                        match cached
+                            .entry((version, format))
+                            .or_insert_with(|| event_message(version, format, &event))
+                        {
+                            Ok(message) => message.clone(),
+                            Err(e) => {
+                                error!("Failed to serialize point cloud: {}", e);
+                                continue;
+                            }
+                        }
+                    }
+                    (version, Some(filtered)) => {
+                        let event = WsEvent::PointCloud(PointCloudData {
+                            key: packet.key,
+                            points: &filtered.points,
+                            replay: packet.replay,
+                            time_ms: packet.time_ms,
+                            convention: client
+                                .subscription
+                                .as_ref()
+                                .and_then(|subscription| subscription.convention.as_ref()),
+                            timing: filtered
+                                .offsets_us
+                                .as_deref()
+                                .map(|offsets_us| FrameTiming {
+                                    scan_start_ms: self
+                                        .processing
+                                        .deskew
+                                        .scan_start_ms(packet.time_ms),
+                                    offsets_us,
+                                }),
+                        });
+                        match event_message(version, client.format(), &event) {
+                            Ok(message) => message,
+                            Err(e) => {
+                                error!("Failed to serialize point cloud: {}", e);
+                                continue;
+                            }
+                        }
+                    }
+                };
+                // 데이터 대기열이 가득 차서 버린 프레임도 마지막 전송 시간으로 품질 단계 조정
+                match client.send_data(message) {
+                    Ok(true) => {}
+                    Ok(false) => debug!("Data lane full for {}, frame dropped", client_id),
+                    Err(e) => {
+                        error!("Failed to send message to {}: {}", client_id, e);
+                        continue;
+                    }
+                }
+                let Some(quality) = client
+                    .quality
+                    .observe(client.lanes.data_latency(), &self.adaptive)
+                else {
+                    continue;
+                };
+                info!(
+                    "Client {} quality level {} ({:.1} ms)",
+                    client_id, quality.level, quality.latency_ms
+                );
+                match event_message(
+                    client.protocol_version,
+                    client.format(),
+                    &WsEvent::Quality(quality),
+                ) {
+                    Ok(message) => {
+                        if let Err(e) = client.send(message) {
+                            error!("Failed to send message to {}: {}", client_id, e);
+                        }
+                    }
+                    Err(e) => error!("Failed to serialize quality event: {}", e),
+                }
+            }
+        } else if let Some(config_data) = lidar_data
+            .get_data()
+            .and_then(|data| data.downcast_ref::<KMConfigData>())
+        {
+            debug!("config_data: {:?}", config_data);
+            if !packet.replay {
+                if let Some(alerts) = &self.alerts {
+                    alerts.observe_config(packet.key, config_data).await;
+                }
+            }
+            // 진단 대기 중인 요청이 없으면 수신자가 없어 전송 실패하므로 결과는 무시
+            let _ = self.config_tx.send(packet.clone());
+            let event = WsEvent::Config(ConfigData {
+                key: packet.key,
+                config: config_data,
+                replay: packet.replay,
+            });
+            if !packet.replay {
+                self.publish_bus_event(&event);
+            }
+            for (client_id, client) in clients.iter_mut() {
+                let message = match event_message(client.protocol_version, client.format(), &event)
+                {
+                    Ok(message) => message,
+                    Err(e) => {
+                        error!("Failed to serialize config data: {}", e);
+                        continue;
+                    }
+                };
+                if let Err(e) = client.send(message) {
+                    error!("Failed to send message to {}: {}", client_id, e);
+                }
+            }
+        }
+    }
+
+    /// 클라이언트가 LiDAR 로 보낸 메시지를 `echo` 설정에 따라 다른 클라이언트에게 전달
+    ///
+    /// # Arguments
+    /// * `sender_id` - 메시지를 보낸 클라이언트 ID (전달 대상에서 제외)
+    /// * `message` - 전달할 메시지
+    ///
+    /// # 동작 설명
+    /// * `none`: 전달하지 않음
+    /// * `all`: 연결된 모든 클라이언트에게 binary 메시지로 전달
+    /// * `subscribers`: 명령 프레임의 대상 LiDAR 를 구독 중인 클라이언트에게만 전달
+    /// * 제어 전용 클라이언트에게는 전달하지 않음
+    /// * 전송 실패 시 에러 로깅
+    pub async fn echo_message(&self, sender_id: Uuid, message: Vec<u8>) {
+        let targets: Vec<Uuid> = match self.echo {
+            EchoMode::None => return,
+            EchoMode::All => self.clients.lock().await.keys().copied().collect(),
+            EchoMode::Subscribers => {
+                let lidars = self.registry.list().await;
+                self.routes
+                    .lock()
+                    .await
+                    .command_subscribers(&message, &lidars)
+            }
+        };
+
+        let message = Bytes::from(message);
+        let mut clients = self.clients.lock().await;
+        for client_id in targets.iter().filter(|&&client_id| client_id != sender_id) {
+            let Some(client) = clients.get_mut(client_id) else {
+                continue;
+            };
+            if client.control {
+                continue;
+            }
+            if let Err(e) = client.send(Message::Binary(message.clone())) {
+                error!("Failed to send message to {}: {}", client_id, e);
+            }
+        }
+    }
+}
+
+/// 격리 중인 송신 주소를 LiDAR 키와 알림 메시지로 변환 (`quarantine` 알림 규칙)
+///
+/// # Arguments
+/// * `stats` - 송신 주소별 파싱 통계
+/// * `lidars` - 등록된 LiDAR 장치 목록
+///
+/// # Returns
+/// * `Vec<(LiDARKey, String)>` - 격리 중인 LiDAR 키와 메시지
+///   (등록되지 않은 송신 주소는 제품 라인/ID 0 인 키)
+fn quarantined_lidars(stats: &[ParserStats], lidars: &[LiDARInfo]) -> Vec<(LiDARKey, String)> {
+    let now_ms = unix_time_ms();
+    stats
+        .iter()
+        .filter_map(|stats| {
+            let until_ms = stats.quarantined_until_ms?;
+            let std::net::IpAddr::V4(ip) = stats.ip else {
+                return None;
+            };
+            let key = lidars
+                .iter()
+                .find(|info| info.ip == ip && info.port == stats.port)
+                .map_or_else(|| LiDARKey::new(ip, stats.port, 0, 0), |info| info.key);
+            let message = format!(
+                "Quarantined after repeated parse errors ({}), retry in {} s",
+                stats.last_error.as_deref().unwrap_or("unknown"),
+                until_ms.saturating_sub(now_ms).div_ceil(1000)
+            );
+            Some((key, message))
+        })
+        .collect()
+}
+
+/// 업그레이드 요청이 제어 전용 하위 프로토콜(`control`)을 요청했는지 확인
+fn requests_control(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|protocol| protocol.trim() == CONTROL_SUBPROTOCOL)
+}
+
+/// 설정 응답 채널에서 해당 LiDAR 의 다음 설정 응답 수신
+///
+/// # Arguments
+/// * `config_rx` - 설정 응답 broadcast 수신기
+/// * `key` - 대상 LiDAR 키
+/// * `deadline` - 기다릴 시각
+///
+/// # Returns
+/// * `Option<KanaviMobilityData>` - 수신한 설정 응답, 제한 시간 초과 또는 채널 종료 시 None
+///   (다른 LiDAR 의 응답과 재생 중인 녹화 데이터는 무시)
+async fn next_config(
+    config_rx: &mut tokio::sync::broadcast::Receiver<LiDARPacket>,
+    key: &LiDARKey,
+    deadline: tokio::time::Instant,
+) -> Option<KanaviMobilityData> {
+    loop {
+        let packet = match tokio::time::timeout_at(deadline, config_rx.recv()).await {
+            Ok(Ok(packet)) => packet,
+            Ok(Err(RecvError::Lagged(_))) => continue,
+            Ok(Err(RecvError::Closed)) | Err(_) => return None,
+        };
+        if packet.key != *key || packet.replay {
+            continue;
+        }
+        let Some((_, encoded)) = packet.payload.split_first() else {
+            continue;
+        };
+        if let Ok((lidar_data, _)) = decode_from_slice::<KanaviMobilityData, _>(encoded, standard())
+        {
+            return Some(lidar_data);
+        }
+    }
+}
+
+/// 포인트 클라우드를 필터링한 결과로 바꾼 v2 binary 데이터 생성
+///
+/// # Arguments
+/// * `company` - 회사 구분값
+/// * `encoded` - 원본 bincode 인코딩 데이터
+/// * `points` - 필터링한 채널별 포인트 클라우드
+///
+/// # Returns
+/// * `Result<Vec<u8>, String>` - 성공 시 회사 구분값 + bincode 인코딩 데이터, 실패 시 에러 메시지
+fn encode_points(company: u8, encoded: &[u8], points: Vec<PointCloud>) -> Result<Vec<u8>, String> {
+    let (mut lidar_data, _): (KanaviMobilityData, _) =
+        decode_from_slice(encoded, standard()).map_err(|e| e.to_string())?;
+    lidar_data.replace_points(points);
+
+    let mut payload = vec![company];
+    payload.extend(encode_to_vec(&lidar_data, standard()).map_err(|e| e.to_string())?);
+    Ok(payload)
+}
+
+/// WebSocket 요청 접근 로그 기록 (`access` target)
+///
+/// # Arguments
+/// * `client_id` - 요청한 클라이언트 ID
+/// * `command` - 요청 명령 (JSON 요청이 아니면 빈 문자열)
+/// * `kind` - 처리 종류 (`request`: 서버에서 처리, `forward`: LiDAR 로 전달, `reject`: 입력 검증 실패)
+/// * `target` - 대상 LiDAR 키
+/// * `started` - 요청 처리 시작 시각
+/// * `status` - 처리 결과 (`ok`, `forwarded` 또는 에러 코드)
+fn access_log(
+    client_id: Uuid,
+    command: &str,
+    kind: &str,
+    target: Option<LiDARKey>,
+    started: Instant,
+    status: &str,
+) {
+    let lidar = target
+        .map(|key| {
+            format!(
+                "{}:{}/{}/{}",
+                key.ip, key.port, key.product_line, key.lidar_id
+            )
+        })
+        .unwrap_or_default();
+    info!(
+        target: "access",
+        %client_id,
+        command,
+        kind,
+        lidar,
+        latency_ms = started.elapsed().as_secs_f64() * 1000.0,
+        status,
+        "WS request"
+    );
 }
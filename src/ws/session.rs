@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::sync::Mutex;
+use tracing::*;
+
+use crate::common::time::unix_time_ms;
+use crate::ws::client::Subscription;
+use crate::ws::config::SessionConfig;
+
+/// 세션 토큰 최대 길이
+pub const MAX_SESSION_TOKEN_LEN: usize = 128;
+
+/// 세션별로 저장한 클라이언트 설정
+///
+/// # Fields
+/// * `subscription` - 마지막 구독 설정 (전송 형식, 구독 설정 이름, 관심 영역 등을 모두 포함한 적용된 설정)
+/// * `updated_ms` - 마지막 변경 시각 (ms, UNIX epoch), 세션이 너무 많으면 오래된 세션부터 삭제
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionPreferences {
+    pub subscription: Subscription,
+    pub updated_ms: u64,
+}
+
+/// 세션 토큰 검사
+///
+/// # Returns
+/// * `Result<(), String>` - 성공 시 Ok(()), 비어 있거나 `MAX_SESSION_TOKEN_LEN` 보다 길면 에러 메시지
+pub fn validate_session_token(token: &str) -> Result<(), String> {
+    if token.is_empty() || token.len() > MAX_SESSION_TOKEN_LEN {
+        return Err(format!(
+            "session must be 1 ~ {} bytes",
+            MAX_SESSION_TOKEN_LEN
+        ));
+    }
+    Ok(())
+}
+
+/// 클라이언트 세션 설정 저장소
+///
+/// # Examples
+/// ```
+/// let sessions = SessionStore::load(config.ws.sessions.clone())?;
+/// sessions.save("dashboard-1", Some(subscription)).await?;
+/// let subscription = sessions.get("dashboard-1").await; // 서버를 다시 시작해도 복원
+/// ```
+///
+/// # 동작 설명
+/// * 클라이언트가 `hello.session` 으로 보낸 토큰을 키로 마지막 구독 설정을 `file` 에 JSON 으로 저장
+/// * 구독을 바꿀 때마다 임시 파일에 쓴 뒤 이름을 바꿔 저장하고, 구독을 해제하면 세션을 삭제
+/// * 연결이 끊겨도 세션은 남아 같은 토큰으로 다시 연결한 대시보드가 이전 구독을 그대로 이어받음
+/// * 세션이 `max_sessions` 개를 넘으면 가장 오래전에 변경한 세션부터 삭제
+pub struct SessionStore {
+    config: SessionConfig,
+    sessions: Mutex<HashMap<String, SessionPreferences>>,
+}
+
+impl SessionStore {
+    /// 저장된 세션 읽기
+    ///
+    /// # Arguments
+    /// * `config` - 세션 저장 설정
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - 성공 시 세션 저장소 (파일이 없으면 빈 저장소), 파일을 읽거나 해석하지 못하면 에러 메시지
+    pub fn load(config: SessionConfig) -> Result<Self, String> {
+        let sessions = load_sessions(&config.file)?;
+        info!(
+            "Loaded {} client sessions from {}",
+            sessions.len(),
+            config.file.display()
+        );
+        Ok(Self {
+            config,
+            sessions: Mutex::new(sessions),
+        })
+    }
+
+    /// 세션의 마지막 구독 설정 조회
+    pub async fn get(&self, token: &str) -> Option<Subscription> {
+        self.sessions
+            .lock()
+            .await
+            .get(token)
+            .map(|preferences| preferences.subscription.clone())
+    }
+
+    /// 세션 구독 설정 저장
+    ///
+    /// # Arguments
+    /// * `token` - 세션 토큰
+    /// * `subscription` - 저장할 구독 설정, None 이면 세션 삭제
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - 성공 시 Ok(()), 파일 쓰기 실패 시 에러 메시지 (메모리의 세션은 변경하지 않음)
+    pub async fn save(
+        &self,
+        token: &str,
+        subscription: Option<Subscription>,
+    ) -> Result<(), String> {
+        let mut sessions = self.sessions.lock().await;
+        let mut updated = sessions.clone();
+        match subscription {
+            Some(subscription) => {
+                updated.insert(
+                    token.to_string(),
+                    SessionPreferences {
+                        subscription,
+                        updated_ms: unix_time_ms(),
+                    },
+                );
+            }
+            None => {
+                if updated.remove(token).is_none() {
+                    return Ok(());
+                }
+            }
+        }
+        while updated.len() > self.config.max_sessions.max(1) {
+            let Some(oldest) = updated
+                .iter()
+                .min_by_key(|(_, preferences)| preferences.updated_ms)
+                .map(|(token, _)| token.clone())
+            else {
+                break;
+            };
+            updated.remove(&oldest);
+        }
+
+        let path = self.config.file.clone();
+        let text = serde_json::to_vec_pretty(&updated).map_err(|e| e.to_string())?;
+        tokio::task::spawn_blocking(move || {
+            let tmp = path.with_extension("json.tmp");
+            std::fs::write(&tmp, text)
+                .and_then(|_| std::fs::rename(&tmp, &path))
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+
+        *sessions = updated;
+        Ok(())
+    }
+
+    /// 저장된 세션 수
+    pub async fn len(&self) -> usize {
+        self.sessions.lock().await.len()
+    }
+
+    /// 저장된 세션이 없는지 확인
+    pub async fn is_empty(&self) -> bool {
+        self.sessions.lock().await.is_empty()
+    }
+}
+
+/// 저장된 세션 파일 읽기 (파일이 없으면 빈 목록)
+pub fn load_sessions(path: &Path) -> Result<HashMap<String, SessionPreferences>, String> {
+    let text = match std::fs::read(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+    serde_json::from_slice(&text).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
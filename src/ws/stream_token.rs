@@ -0,0 +1,154 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::common::time::unix_time_ms;
+use crate::lidar::LiDARKey;
+use crate::ws::client::Subscription;
+use crate::ws::config::StreamTokenConfig;
+
+/// 스트림 URL 발급 요청 구조체 (`POST /streams` 본문)
+///
+/// # Examples
+/// ```json
+/// { "lidar": { "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 }, "preset": "viewer", "ttl_ms": 10000 }
+/// ```
+///
+/// # Fields
+/// * `lidar` - 스트림으로 받을 LiDAR 키
+/// * `preset` - 적용할 이름 있는 구독 설정 (`ws.presets`), None 이면 기본 구독 설정
+/// * `ttl_ms` - URL 유효 시간 (ms), None 이면 `ws.stream_tokens.ttl_ms`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct StreamRequest {
+    pub lidar: LiDARKey,
+    #[serde(default)]
+    pub preset: Option<String>,
+    #[serde(default)]
+    pub ttl_ms: Option<u64>,
+}
+
+/// 발급한 스트림 URL 구조체
+///
+/// # Fields
+/// * `token` - 한 번만 사용할 수 있는 토큰
+/// * `url` - 연결할 WebSocket 경로 (`/ws/stream/{token}`), 서버 주소는 요청한 쪽에서 붙임
+/// * `lidar` - 토큰에 묶인 LiDAR 키
+/// * `preset` - 토큰에 묶인 구독 설정 이름
+/// * `expires_ms` - 토큰 만료 시각 (ms, UNIX epoch), 이 시각까지 연결하지 않으면 사용할 수 없음
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ToSchema)]
+pub struct StreamGrant {
+    pub token: String,
+    pub url: String,
+    pub lidar: LiDARKey,
+    pub preset: Option<String>,
+    pub expires_ms: u64,
+}
+
+/// 아직 사용하지 않은 토큰
+struct PendingStream {
+    subscription: Subscription,
+    expires_ms: u64,
+}
+
+/// 스트림 토큰 저장소
+///
+/// # Examples
+/// ```
+/// let tokens = StreamTokens::new(config.ws.stream_tokens.clone());
+/// let grant = tokens.mint(key, None, subscription, None).await?;
+/// let subscription = tokens.redeem(&grant.token).await; // 두 번째 사용은 None
+/// ```
+///
+/// # 동작 설명
+/// * 토큰은 특정 LiDAR 와 구독 설정에 묶인 임의의 UUID 이며, 연결할 때 한 번 사용하면 삭제
+/// * 만료된 토큰은 발급할 때 정리하고, 사용하지 않은 토큰이 `max_pending` 개이면 발급 거부
+pub struct StreamTokens {
+    config: StreamTokenConfig,
+    pending: Mutex<HashMap<String, PendingStream>>,
+}
+
+impl StreamTokens {
+    pub fn new(config: StreamTokenConfig) -> Self {
+        Self {
+            config,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &StreamTokenConfig {
+        &self.config
+    }
+
+    /// 스트림 토큰 발급
+    ///
+    /// # Arguments
+    /// * `lidar` - 토큰에 묶을 LiDAR 키
+    /// * `preset` - 토큰에 묶을 구독 설정 이름 (응답 표시용)
+    /// * `subscription` - 연결 시 적용할 구독 설정 (`lidar` 만 구독하도록 정리된 설정)
+    /// * `ttl_ms` - 유효 시간 (ms), None 이면 기본 유효 시간
+    ///
+    /// # Returns
+    /// * `Result<StreamGrant, String>` - 성공 시 발급한 URL, 유효 시간이 범위를 벗어나거나 대기 토큰이 너무 많으면 에러 메시지
+    pub async fn mint(
+        &self,
+        lidar: LiDARKey,
+        preset: Option<String>,
+        subscription: Subscription,
+        ttl_ms: Option<u64>,
+    ) -> Result<StreamGrant, String> {
+        let ttl_ms = ttl_ms.unwrap_or(self.config.ttl_ms);
+        if ttl_ms == 0 || ttl_ms > self.config.max_ttl_ms {
+            return Err(format!(
+                "ttl_ms must be within 1 ~ {}",
+                self.config.max_ttl_ms
+            ));
+        }
+
+        let now = unix_time_ms();
+        let mut pending = self.pending.lock().await;
+        pending.retain(|_, stream| stream.expires_ms >= now);
+        if pending.len() >= self.config.max_pending {
+            return Err(format!(
+                "Too many pending stream tokens (max {})",
+                self.config.max_pending
+            ));
+        }
+
+        let token = Uuid::new_v4().simple().to_string();
+        let expires_ms = now + ttl_ms;
+        pending.insert(
+            token.clone(),
+            PendingStream {
+                subscription,
+                expires_ms,
+            },
+        );
+        Ok(StreamGrant {
+            url: format!("/ws/stream/{}", token),
+            token,
+            lidar,
+            preset,
+            expires_ms,
+        })
+    }
+
+    /// 스트림 토큰 사용
+    ///
+    /// # Arguments
+    /// * `token` - 연결 URL 의 토큰
+    ///
+    /// # Returns
+    /// * `Option<Subscription>` - 토큰에 묶인 구독 설정, 알 수 없거나 이미 사용했거나 만료된 토큰이면 None
+    pub async fn redeem(&self, token: &str) -> Option<Subscription> {
+        let stream = self.pending.lock().await.remove(token)?;
+        (stream.expires_ms >= unix_time_ms()).then_some(stream.subscription)
+    }
+
+    /// 사용하지 않은 토큰 수 (만료된 토큰 포함)
+    pub async fn pending(&self) -> usize {
+        self.pending.lock().await.len()
+    }
+}
@@ -0,0 +1,356 @@
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashSet};
+use utoipa::OpenApi;
+
+use crate::ws::rest::ApiDoc;
+use crate::ws::schema::schemas;
+
+/// 들여쓰기 단위
+const INDENT: &str = "  ";
+
+/// WebSocket/REST 메시지 TypeScript 타입 정의 생성
+///
+/// # Examples
+/// ```
+/// std::fs::write("web/src/lidar_server.d.ts", type_definitions())?;
+/// ```
+///
+/// # Returns
+/// * `String` - `.d.ts` 파일 내용
+///
+/// # 동작 설명
+/// * `/schema` 의 JSON Schema(WebSocket 메시지, 명령, 이벤트, 서버 설정)와 `/api-doc` 의
+///   REST 모델에서 변환하므로, Rust 구조체를 바꾸면 프런트엔드/릴레이 타입도 함께 바뀜
+/// * 최상위 메시지는 `RequestMessage`, `ResponseMessage`, `Envelope`, `WsRequest`, `WsResponse`,
+///   `WsEvent`, `ServerConfig` 이름으로 생성하고, 참조하는 타입은 Rust 타입 이름 그대로 생성
+/// * 같은 이름의 타입은 WebSocket 스키마를 우선하며, 속성만 있는 객체는 `interface`,
+///   그 외(열거형, 태그된 공용체 등)는 `type` 으로 생성
+pub fn type_definitions() -> String {
+    let schemas = schemas();
+    let roots = [
+        ("RequestMessage", "/messages/request"),
+        ("ResponseMessage", "/messages/response"),
+        ("Envelope", "/messages/envelope"),
+        ("WsRequest", "/commands/request"),
+        ("WsResponse", "/commands/response"),
+        ("WsEvent", "/events"),
+        ("ServerConfig", "/config"),
+    ];
+
+    let mut declarations = BTreeMap::new();
+    for (name, pointer) in roots {
+        let Some(Value::Object(root)) = schemas.pointer(pointer) else {
+            continue;
+        };
+        let mut root = root.clone();
+        if let Some(Value::Object(definitions)) = root.remove("definitions") {
+            for (name, schema) in definitions {
+                declarations.entry(name).or_insert(schema);
+            }
+        }
+        root.remove("$schema");
+        root.remove("title");
+        declarations.insert(name.to_string(), Value::Object(root));
+    }
+
+    let openapi = serde_json::to_value(ApiDoc::openapi()).unwrap_or_default();
+    if let Some(Value::Object(components)) = openapi.pointer("/components/schemas") {
+        for (name, schema) in components {
+            declarations
+                .entry(name.clone())
+                .or_insert_with(|| schema.clone());
+        }
+    }
+
+    let mut output = format!(
+        "// LiDAR Server {} WebSocket/REST message types\n\
+         // Generated from GET /types.d.ts, do not edit by hand.\n\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    for (name, schema) in &declarations {
+        write_declaration(&mut output, name, schema);
+    }
+    output
+}
+
+/// 타입 하나의 선언 (`export interface` 또는 `export type`)
+fn write_declaration(output: &mut String, name: &str, schema: &Value) {
+    write_doc(output, schema, "");
+    let name = identifier(name);
+    if is_interface(schema) {
+        output.push_str(&format!(
+            "export interface {} {}\n\n",
+            name,
+            object_body(schema, 0)
+        ));
+    } else {
+        output.push_str(&format!(
+            "export type {} = {};\n\n",
+            name,
+            ts_type(schema, 0)
+        ));
+    }
+}
+
+/// 스키마의 `description` 을 JSDoc 주석으로 출력
+fn write_doc(output: &mut String, schema: &Value, pad: &str) {
+    let Some(description) = schema.get("description").and_then(Value::as_str) else {
+        return;
+    };
+    let description = description.trim().replace("*/", "*\\/");
+    if description.is_empty() {
+        return;
+    }
+    if !description.contains('\n') {
+        output.push_str(&format!("{}/** {} */\n", pad, description));
+        return;
+    }
+    output.push_str(&format!("{}/**\n", pad));
+    for line in description.lines() {
+        match line.trim_end() {
+            "" => output.push_str(&format!("{} *\n", pad)),
+            line => output.push_str(&format!("{} * {}\n", pad, line)),
+        }
+    }
+    output.push_str(&format!("{} */\n", pad));
+}
+
+/// 속성만 있는 객체 스키마인지 확인 (`interface` 로 선언 가능)
+fn is_interface(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("object")
+        && schema.get("properties").is_some()
+        && ["allOf", "anyOf", "oneOf", "enum", "const"]
+            .iter()
+            .all(|key| schema.get(key).is_none())
+}
+
+/// JSON Schema 를 TypeScript 타입 식으로 변환
+///
+/// # Arguments
+/// * `schema` - JSON Schema (schemars 의 `definitions` 참조, OpenAPI 의 `components` 참조 모두 지원)
+/// * `depth` - 객체 속성 들여쓰기 단계
+///
+/// # Returns
+/// * `String` - TypeScript 타입 식, 표현할 수 없는 스키마는 `unknown`
+fn ts_type(schema: &Value, depth: usize) -> String {
+    let Value::Object(schema) = schema else {
+        return match schema {
+            Value::Bool(false) => "never",
+            _ => "unknown",
+        }
+        .to_string();
+    };
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return identifier(reference.rsplit('/').next().unwrap_or(reference));
+    }
+    if let Some(value) = schema.get("const") {
+        return value.to_string();
+    }
+    if let Some(Value::Array(values)) = schema.get("enum") {
+        return union(values.iter().map(Value::to_string));
+    }
+
+    let combined = ["allOf", "anyOf", "oneOf"]
+        .iter()
+        .any(|key| schema.contains_key(*key));
+    let mut parts = Vec::new();
+    if let Some(types) = schema.get("type") {
+        // 조합 스키마에 붙은 속성 없는 `object` 는 조합 결과로 충분
+        let bare_object = types.as_str() == Some("object")
+            && !schema.contains_key("properties")
+            && !schema.contains_key("additionalProperties");
+        if !(combined && bare_object) {
+            parts.push(typed(schema, types, depth));
+        }
+    }
+    for key in ["anyOf", "oneOf"] {
+        if let Some(Value::Array(variants)) = schema.get(key) {
+            parts.push(union(
+                variants.iter().map(|variant| ts_type(variant, depth)),
+            ));
+        }
+    }
+    if let Some(Value::Array(all)) = schema.get("allOf") {
+        parts.extend(all.iter().map(|schema| ts_type(schema, depth)));
+    }
+
+    let ty = match parts.len() {
+        0 => "unknown".to_string(),
+        1 => parts.remove(0),
+        _ => parts
+            .iter()
+            .map(|part| group(part))
+            .collect::<Vec<_>>()
+            .join(" & "),
+    };
+    if schema.get("nullable") == Some(&Value::Bool(true)) {
+        union([ty, "null".to_string()])
+    } else {
+        ty
+    }
+}
+
+/// `type` 키워드(단일 또는 목록)로 지정한 타입
+fn typed(schema: &Map<String, Value>, types: &Value, depth: usize) -> String {
+    let types: Vec<&str> = match types {
+        Value::String(ty) => vec![ty.as_str()],
+        Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+    };
+    union(types.into_iter().map(|ty| match ty {
+        "string" => "string".to_string(),
+        "integer" | "number" => "number".to_string(),
+        "boolean" => "boolean".to_string(),
+        "null" => "null".to_string(),
+        "array" => array_type(schema, depth),
+        "object" => object_type(schema, depth),
+        _ => "unknown".to_string(),
+    }))
+}
+
+/// 배열 타입 (`items` 가 목록이거나 `prefixItems` 이면 튜플)
+fn array_type(schema: &Map<String, Value>, depth: usize) -> String {
+    match (schema.get("prefixItems"), schema.get("items")) {
+        (Some(Value::Array(items)), _) | (None, Some(Value::Array(items))) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(|item| ts_type(item, depth))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        (_, Some(item)) => format!("{}[]", group(&ts_type(item, depth))),
+        _ => "unknown[]".to_string(),
+    }
+}
+
+/// 객체 타입 (속성이 없으면 `Record`)
+fn object_type(schema: &Map<String, Value>, depth: usize) -> String {
+    if schema.contains_key("properties") {
+        return object_body(&Value::Object(schema.clone()), depth);
+    }
+    match schema.get("additionalProperties") {
+        Some(Value::Bool(false)) => "Record<string, never>".to_string(),
+        Some(value @ Value::Object(_)) => format!("Record<string, {}>", ts_type(value, depth)),
+        _ => "Record<string, unknown>".to_string(),
+    }
+}
+
+/// 객체 속성 목록 (`{ name: T; optional?: T; }`)
+fn object_body(schema: &Value, depth: usize) -> String {
+    let empty = Map::new();
+    let properties = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let required: HashSet<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let pad = INDENT.repeat(depth + 1);
+
+    let mut body = String::from("{\n");
+    for (name, property) in properties {
+        write_doc(&mut body, property, &pad);
+        body.push_str(&format!(
+            "{}{}{}: {};\n",
+            pad,
+            property_name(name),
+            if required.contains(name.as_str()) {
+                ""
+            } else {
+                "?"
+            },
+            ts_type(property, depth + 1)
+        ));
+    }
+    // 다른 속성과 호환되도록 추가 속성 값은 unknown
+    if schema
+        .get("additionalProperties")
+        .is_some_and(|additional| additional != &Value::Bool(false))
+    {
+        body.push_str(&format!("{}[key: string]: unknown;\n", pad));
+    }
+    body.push_str(&format!("{}}}", INDENT.repeat(depth)));
+    body
+}
+
+/// 중복을 제거한 공용체 타입
+fn union(types: impl IntoIterator<Item = String>) -> String {
+    let mut variants: Vec<String> = Vec::new();
+    for ty in types {
+        if !variants.contains(&ty) {
+            variants.push(ty);
+        }
+    }
+    match variants.len() {
+        0 => "never".to_string(),
+        1 => variants.remove(0),
+        _ => variants
+            .iter()
+            .map(|variant| group(variant))
+            .collect::<Vec<_>>()
+            .join(" | "),
+    }
+}
+
+/// 최상위에 `|`, `&` 가 있는 타입 식은 괄호로 묶기
+fn group(ty: &str) -> String {
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in ty.chars() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '[' | '(' | '<' => depth += 1,
+            '}' | ']' | ')' | '>' => depth -= 1,
+            '|' | '&' if depth == 0 => return format!("({})", ty),
+            _ => {}
+        }
+    }
+    ty.to_string()
+}
+
+/// 타입 이름을 TypeScript 식별자로 변환
+fn identifier(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// 속성 이름 (식별자로 쓸 수 없으면 따옴표로 묶기)
+fn property_name(name: &str) -> String {
+    let valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if valid {
+        name.to_string()
+    } else {
+        Value::String(name.to_string()).to_string()
+    }
+}
@@ -0,0 +1,79 @@
+//! 창 오염 알림 테스트
+//!
+//! 반사가 없는 프레임이 계속되거나 짧은 오염 구간이 반복되면 알림이 발생하고,
+//! 설정 응답으로 받은 안개 필터 상태가 권장 정비 조치에 반영되는지 확인합니다.
+
+use lidar_server::alert::{AlertConfig, AlertEngine, AlertState};
+use lidar_server::lidar::kanavi_mobility::{FogFilter, KMConfigData};
+use lidar_server::lidar::{LiDARKey, Point, PointCloud};
+use serde_json::json;
+use std::net::Ipv4Addr;
+
+fn engine(frames: u32, recur_count: u32) -> AlertEngine {
+    let config: AlertConfig = serde_json::from_value(json!({
+        "enabled": true,
+        "rules": [{
+            "name": "window",
+            "type": "window_contamination",
+            "frames": frames,
+            "recur_count": recur_count
+        }]
+    }))
+    .unwrap();
+    AlertEngine::new(config)
+}
+
+fn frame(contaminated: bool) -> Vec<PointCloud> {
+    let point = if contaminated {
+        Point {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    } else {
+        Point {
+            x: 5.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    };
+    vec![PointCloud {
+        points: vec![point; 10],
+    }]
+}
+
+fn key() -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, 200), 5000, 1, 0)
+}
+
+#[tokio::test]
+async fn persistent_contamination_recommends_cleaning() {
+    let engine = engine(3, 0);
+    assert!(engine.observe_frame(key(), &frame(true)).await.is_empty());
+    assert!(engine.observe_frame(key(), &frame(true)).await.is_empty());
+    let alerts = engine.observe_frame(key(), &frame(true)).await;
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].state, AlertState::Firing);
+    assert_eq!(
+        alerts[0].maintenance.as_deref(),
+        Some("Clean the sensor window")
+    );
+}
+
+#[tokio::test]
+async fn recurring_contamination_raises_alert() {
+    let engine = engine(100, 3);
+    engine
+        .observe_config(key(), &KMConfigData::FogFilter(FogFilter::new(2)))
+        .await;
+    for _ in 0..2 {
+        assert!(engine.observe_frame(key(), &frame(true)).await.is_empty());
+        assert!(engine.observe_frame(key(), &frame(false)).await.is_empty());
+    }
+    let alerts = engine.observe_frame(key(), &frame(true)).await;
+    assert_eq!(alerts.len(), 1);
+    assert!(alerts[0].message.contains("recurred 3 times"));
+    let maintenance = alerts[0].maintenance.as_deref().unwrap();
+    assert!(maintenance.starts_with("Fog filter is on"));
+    assert!(maintenance.contains("recurring source"));
+}
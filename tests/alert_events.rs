@@ -0,0 +1,94 @@
+//! 영역 이벤트 이력 테스트
+//!
+//! 영역 침입 알림만 이벤트로 저장되고, 기간/LiDAR 주소/영역 이름 조건으로 조회되는지 확인합니다.
+
+use lidar_server::alert::{
+    Alert, AlertState, EventHistory, EventHistoryConfig, EventQuery, ZoneEvent,
+};
+use lidar_server::common::time::unix_time_ms;
+use lidar_server::lidar::LiDARKey;
+use std::net::Ipv4Addr;
+use std::time::Duration;
+
+fn alert(kind: &str, rule: &str, port: u16) -> Alert {
+    Alert {
+        rule: rule.to_string(),
+        kind: kind.to_string(),
+        key: LiDARKey::new(Ipv4Addr::new(192, 168, 123, 200), port, 1, 0),
+        state: AlertState::Firing,
+        message: "3 point(s) inside zone".to_string(),
+        maintenance: None,
+        time_ms: unix_time_ms(),
+    }
+}
+
+#[test]
+fn only_zone_intrusion_alerts_become_events() {
+    assert!(ZoneEvent::from_alert(&alert("zone_intrusion", "lane-1", 5000)).is_some());
+    assert!(ZoneEvent::from_alert(&alert("device_offline", "offline", 5000)).is_none());
+}
+
+#[test]
+fn query_filters_by_sensor_and_zone() {
+    let event = ZoneEvent::from_alert(&alert("zone_intrusion", "lane-1", 5000)).unwrap();
+    let query = |sensor: &str, zone: Option<&str>| EventQuery {
+        sensor: Some(sensor.to_string()),
+        zone: zone.map(str::to_string),
+        ..Default::default()
+    };
+
+    for (query, expected) in [
+        (query("192.168.123.200", None), true),
+        (query("192.168.123.200:5000", Some("lane-1")), true),
+        (query("192.168.123.200:5001", None), false),
+        (query("192.168.123.201", None), false),
+        (query("192.168.123.200", Some("lane-2")), false),
+    ] {
+        let sensor = query.sensor_filter().unwrap();
+        assert_eq!(query.matches(&event, sensor), expected, "{:?}", query);
+    }
+    assert!(query("lidar-1", None).sensor_filter().is_err());
+}
+
+#[test]
+fn inverted_range_is_rejected() {
+    let query = EventQuery {
+        from: Some(2000),
+        to: Some(1000),
+        ..Default::default()
+    };
+    assert!(query.range().is_err());
+}
+
+#[tokio::test]
+async fn stored_events_can_be_queried() {
+    let dir = std::env::temp_dir().join(format!("lidar-events-{}", std::process::id()));
+    let history = EventHistory::start(&EventHistoryConfig {
+        enabled: true,
+        dir: dir.clone(),
+        ..Default::default()
+    })
+    .unwrap();
+    history.store(&alert("zone_intrusion", "lane-1", 5000));
+    history.store(&alert("zone_intrusion", "lane-2", 5000));
+    history.store(&alert("device_offline", "offline", 5000));
+
+    let query = EventQuery {
+        zone: Some("lane-1".to_string()),
+        ..Default::default()
+    };
+    // 저장은 별도 스레드에서 진행되므로 기록될 때까지 대기
+    let mut events = Vec::new();
+    for _ in 0..50 {
+        events = history.query(&query).await.unwrap();
+        if !events.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].zone, "lane-1");
+    assert_eq!(events[0].state, AlertState::Firing);
+}
@@ -0,0 +1,69 @@
+//! 네트워크 장애 주입 테스트
+//!
+//! 설정한 비율로 패킷을 버리거나 지연/중복시키고, 같은 시드는 같은 순서로 장애가 발생하는지 확인합니다.
+
+use lidar_server::common::impairment::{Impairer, ImpairmentConfig};
+use std::time::Duration;
+
+fn config(drop: f64, delay: f64, duplicate: f64) -> ImpairmentConfig {
+    ImpairmentConfig {
+        enabled: true,
+        drop_percent: drop,
+        delay_percent: delay,
+        delay_ms: [10, 20],
+        duplicate_percent: duplicate,
+        seed: Some(42),
+    }
+}
+
+#[test]
+fn rates_follow_configured_percentages() {
+    let impairer = Impairer::new(&config(10.0, 20.0, 5.0));
+    for _ in 0..20_000 {
+        let impairment = impairer.decide();
+        if impairment.copies == 0 {
+            assert_eq!(impairment.delay, Duration::ZERO);
+        } else if !impairment.delay.is_zero() {
+            assert!((10..=20).contains(&(impairment.delay.as_millis() as u64)));
+        }
+    }
+
+    let stats = impairer.stats();
+    assert_eq!(stats.total, 20_000);
+    // 버린 패킷은 지연/중복하지 않으므로 나머지 90% 중 비율
+    assert!((1_800..2_200).contains(&stats.dropped), "{:?}", stats);
+    assert!((3_300..3_900).contains(&stats.delayed), "{:?}", stats);
+    assert!((700..1_100).contains(&stats.duplicated), "{:?}", stats);
+}
+
+#[test]
+fn same_seed_gives_same_sequence() {
+    let a = Impairer::new(&config(30.0, 30.0, 30.0));
+    let b = Impairer::new(&config(30.0, 30.0, 30.0));
+    for _ in 0..1000 {
+        assert_eq!(a.decide(), b.decide());
+    }
+}
+
+#[test]
+fn disabled_rates_pass_everything() {
+    let impairer = Impairer::new(&config(0.0, 0.0, 0.0));
+    for _ in 0..1000 {
+        let impairment = impairer.decide();
+        assert_eq!(impairment.copies, 1);
+        assert!(impairment.delay.is_zero());
+    }
+}
+
+#[test]
+fn validate_rejects_bad_ranges() {
+    assert!(config(101.0, 0.0, 0.0).validate().is_err());
+    assert!(config(0.0, -1.0, 0.0).validate().is_err());
+    assert!(ImpairmentConfig {
+        delay_ms: [50, 10],
+        ..config(0.0, 0.0, 0.0)
+    }
+    .validate()
+    .is_err());
+    assert!(config(100.0, 50.0, 0.0).validate().is_ok());
+}
@@ -0,0 +1,102 @@
+//! 설정 파일 엄격 검사 테스트
+//!
+//! 알 수 없는 키, 포트 충돌, 잘못된 멀티캐스트 주소를 줄/열 번호와 함께 찾는지 확인합니다.
+
+use lidar_server::config::check::{check, path_at};
+use lidar_server::config::{ConfigIssue, ServerConfig};
+
+fn issues(text: &str) -> Vec<ConfigIssue> {
+    let config: ServerConfig = serde_json::from_str(text).unwrap();
+    check(text, &config)
+}
+
+#[test]
+fn valid_config_has_no_issues() {
+    let text = r#"{
+    "udp": { "addr": "0.0.0.0:5000", "multicast_addr": "224.0.0.5", "dedup": { "enabled": true } },
+    "ws": { "start_port": 5555 },
+    "alert": { "rules": [ { "name": "slow", "type": "over_speed", "max_speed": 8.0 } ] }
+}"#;
+    assert_eq!(issues(text), Vec::new());
+}
+
+#[test]
+fn unknown_keys_report_line_and_column() {
+    let text = r#"{
+    "udp": {
+        "dedup": { "enabled": true, "windw_ms": 20 }
+    },
+    "wss": {}
+}"#;
+    let issues = issues(text);
+
+    assert_eq!(issues.len(), 2, "{:?}", issues);
+    assert_eq!(issues[0].path, "udp.dedup.windw_ms");
+    assert_eq!((issues[0].line, issues[0].column), (Some(3), Some(37)));
+    assert_eq!(issues[0].message, "unknown key");
+    assert_eq!(issues[1].path, "wss");
+    assert_eq!((issues[1].line, issues[1].column), (Some(5), Some(5)));
+}
+
+#[test]
+fn port_conflicts_and_multicast_address() {
+    let text = r#"{
+    "udp": {
+        "addr": "0.0.0.0:5000",
+        "multicast_addr": "192.168.0.5",
+        "connected": [
+            { "sensor": "192.168.123.200:5000", "local_port": 5001 },
+            { "sensor": "192.168.123.201:5000", "local_port": 5001 },
+            { "sensor": "192.168.123.202:5000", "local_port": 5000 }
+        ]
+    },
+    "ws": { "start_port": 5555, "max_attempts": 10 },
+    "replication": { "role": "primary", "listen": "0.0.0.0:5560" }
+}"#;
+    let paths: Vec<String> = issues(text).into_iter().map(|issue| issue.path).collect();
+
+    assert_eq!(
+        paths,
+        vec![
+            "udp.multicast_addr",
+            "udp.connected[1].local_port",
+            "udp.connected[2].local_port",
+            "replication.listen",
+        ]
+    );
+}
+
+#[test]
+fn parse_error_path() {
+    let text = "{\n    \"udp\": {\n        \"recv_batch_size\": \"many\"\n    }\n}";
+    let error = serde_json::from_str::<ServerConfig>(text).unwrap_err();
+
+    assert_eq!(
+        path_at(text, error.line(), error.column()).as_deref(),
+        Some("udp.recv_batch_size")
+    );
+}
+
+#[test]
+fn load_reports_every_issue() {
+    let path = std::env::temp_dir().join(format!("lidar-config-check-{}.json", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"{ "udp": { "multicast_addr": "10.0.0.1" }, "typo": 1 }"#,
+    )
+    .unwrap();
+    let result = ServerConfig::load(&path);
+    std::fs::remove_file(&path).ok();
+
+    let error = result.unwrap_err();
+    assert!(
+        error.contains("udp.multicast_addr (line 1, column 12)"),
+        "{}",
+        error
+    );
+    assert!(
+        error.contains("typo (line 1, column 44): unknown key"),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,65 @@
+//! InfluxDB 추적 물체 line protocol 테스트
+//!
+//! 한 프레임의 확정 물체가 물체마다 LiDAR 키/물체 ID/좌표계 태그와 위치/속도 필드를 가진
+//! `<prefix>track` 줄로 변환되는지 확인합니다.
+
+use lidar_server::influx::line::track_lines;
+use lidar_server::lidar::LiDARKey;
+use lidar_server::processing::{TrackedObject, TrackedObjects};
+use lidar_server::ws::client::OutputFrame;
+use std::net::Ipv4Addr;
+
+fn object(id: u64, position: [f32; 2], velocity: [f32; 2]) -> TrackedObject {
+    TrackedObject {
+        id,
+        position,
+        velocity,
+        speed: (velocity[0].powi(2) + velocity[1].powi(2)).sqrt(),
+        heading: 0.0,
+        size: 4.5,
+    }
+}
+
+#[test]
+fn one_line_per_tracked_object() {
+    let objects = TrackedObjects {
+        key: LiDARKey::new(Ipv4Addr::new(192, 168, 123, 200), 5000, 1, 0),
+        time_ms: 1_700_000_000_000,
+        frame: OutputFrame::World,
+        objects: vec![
+            object(7, [12.5, -1.5], [3.0, 4.0]),
+            object(8, [0.0, 2.0], [0.0, 0.0]),
+        ],
+    };
+
+    let lines = track_lines("lidar_", &objects);
+    assert_eq!(
+        lines,
+        vec![
+            "lidar_track,ip=192.168.123.200,port=5000,product_line=1,lidar_id=0,track_id=7,frame=world \
+             x=12.5,y=-1.5,vx=3,vy=4,speed=5,heading=0,size=4.5 1700000000000",
+            "lidar_track,ip=192.168.123.200,port=5000,product_line=1,lidar_id=0,track_id=8,frame=world \
+             x=0,y=2,vx=0,vy=0,speed=0,heading=0,size=4.5 1700000000000",
+        ]
+    );
+}
+
+#[test]
+fn empty_frame_and_escaped_prefix() {
+    let objects = TrackedObjects {
+        key: LiDARKey::new(Ipv4Addr::new(192, 168, 123, 200), 5000, 1, 0),
+        time_ms: 1_700_000_000_000,
+        frame: OutputFrame::Lidar,
+        objects: Vec::new(),
+    };
+    assert!(track_lines("lidar_", &objects).is_empty());
+
+    let objects = TrackedObjects {
+        objects: vec![object(1, [1.0, 1.0], [1.0, 0.0])],
+        ..objects
+    };
+    let lines = track_lines("my prefix,", &objects);
+    // 접두어의 공백/쉼표는 escape, 좌표계 태그는 lidar
+    assert!(lines[0].starts_with("my\\ prefix\\,track,"), "{}", lines[0]);
+    assert!(lines[0].contains(",frame=lidar "), "{}", lines[0]);
+}
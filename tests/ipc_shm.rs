@@ -0,0 +1,123 @@
+//! 공유 메모리 출력 테스트
+//!
+//! `ShmOutput` 으로 기록한 포인트 클라우드를 `ShmReader` 로 다시 읽어 LiDAR 키/채널/포인트와
+//! `used_slots` 가 맞는지, `max_points` 를 넘는 포인트는 잘리는지, 기록되지 않은 슬롯은 None 인지 확인합니다.
+#![cfg(feature = "shm")]
+
+use lidar_server::ipc::{ShmConfig, ShmOutput, ShmReader};
+use lidar_server::lidar::{LiDARKey, Point};
+use std::net::Ipv4Addr;
+use std::path::{Path, PathBuf};
+
+fn key(last: u8) -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, last), 5000, 1, 0)
+}
+
+fn points(count: usize) -> Vec<Point> {
+    (0..count)
+        .map(|i| Point {
+            x: i as f32,
+            y: i as f32 * 0.5,
+            z: -(i as f32),
+        })
+        .collect()
+}
+
+fn coords(points: &[Point]) -> Vec<(f32, f32, f32)> {
+    points.iter().map(|p| (p.x, p.y, p.z)).collect()
+}
+
+/// 테스트마다 다른 파일 경로
+fn shm_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("lidar_server-{}-{}", name, std::process::id()))
+}
+
+fn start(path: &Path, slots: usize, max_points: usize) -> ShmOutput {
+    ShmOutput::start(&ShmConfig {
+        enabled: true,
+        path: path.to_path_buf(),
+        slots,
+        max_points,
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn frames_round_trip_through_shared_memory() {
+    let path = shm_path("round-trip");
+    let output = start(&path, 2, 4);
+    let reader = ShmReader::open(&path).unwrap();
+    assert_eq!(reader.used_slots(), 0);
+    assert!(reader.read(0).is_none());
+
+    output.publish(key(200), 0, &points(3)).await;
+    assert_eq!(reader.used_slots(), 1);
+    let frame = reader.read(0).expect("slot 0 written");
+    assert_eq!(frame.key, key(200));
+    assert_eq!(frame.channel, 0);
+    assert_eq!(coords(&frame.points), coords(&points(3)));
+    assert!(
+        frame.seq > 0 && frame.seq.is_multiple_of(2),
+        "{}",
+        frame.seq
+    );
+    assert!(frame.time_ms > 0);
+
+    // 같은 LiDAR/채널은 같은 슬롯을 덮어쓰고 갱신 번호만 증가
+    output.publish(key(200), 0, &points(2)).await;
+    let updated = reader.read(0).unwrap();
+    assert_eq!(updated.seq, frame.seq + 2);
+    assert_eq!(coords(&updated.points), coords(&points(2)));
+    assert_eq!(reader.used_slots(), 1);
+
+    // 아직 기록되지 않은 슬롯과 범위를 벗어난 슬롯은 None
+    assert!(reader.read(1).is_none());
+    assert!(reader.read(2).is_none());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn points_are_truncated_at_max_points() {
+    let path = shm_path("truncate");
+    let output = start(&path, 2, 4);
+    let reader = ShmReader::open(&path).unwrap();
+
+    output.publish(key(200), 1, &points(6)).await;
+    let frame = reader.read(0).unwrap();
+    assert_eq!(frame.channel, 1);
+    assert_eq!(coords(&frame.points), coords(&points(4)));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn new_lidars_are_ignored_when_slots_are_full() {
+    let path = shm_path("full");
+    let output = start(&path, 2, 4);
+    let reader = ShmReader::open(&path).unwrap();
+
+    output.publish(key(200), 0, &points(1)).await;
+    // 같은 LiDAR 라도 채널이 다르면 다른 슬롯
+    output.publish(key(200), 1, &points(2)).await;
+    assert_eq!(reader.used_slots(), 2);
+    output.publish(key(201), 0, &points(3)).await;
+    assert_eq!(reader.used_slots(), 2);
+
+    let slots: Vec<_> = (0..reader.used_slots())
+        .map(|slot| reader.read(slot).unwrap())
+        .map(|frame| (frame.key, frame.channel, frame.points.len()))
+        .collect();
+    assert_eq!(slots, vec![(key(200), 0, 1), (key(200), 1, 2)]);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn open_rejects_other_files() {
+    let path = shm_path("invalid");
+    std::fs::write(&path, [0u8; 128]).unwrap();
+    assert!(ShmReader::open(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+    assert!(ShmReader::open(&path).is_err());
+}
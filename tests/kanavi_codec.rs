@@ -0,0 +1,181 @@
+//! Kanavi Mobility 프레임 codec 테스트
+//!
+//! `codec::encode` 로 만든 프레임을 `codec::decode_frame` 이 같은 헤더로 읽고, 프레임이 아직 다 오지 않았으면
+//! `NeedMoreData`, 설정 응답 데이터가 짧으면 패닉 없이 `Truncated` 를 반환하는지 확인합니다.
+
+use lidar_server::lidar::kanavi_mobility::codec::{
+    decode_config, decode_frame, encode, frame_data, FrameHeader, MODE_CONFIG, MODE_POINT_CLOUD,
+};
+use lidar_server::lidar::kanavi_mobility::{KanaviMobilityParser, TeachingArea};
+use lidar_server::lidar::{LiDARParser, ParseError, ParseOutcome};
+use serde_json::json;
+use std::net::Ipv4Addr;
+
+/// 지원하는 설정 응답 파라미터와 최소 데이터 길이
+const CONFIG_PARAMS: [(u8, usize); 13] = [
+    (0x11, 14),
+    (0x71, 7),
+    (0xD1, 20),
+    (0xF1, 1),
+    (0x43, 4),
+    (0x63, 1),
+    (0x83, 6),
+    (0xA3, 1),
+    (0xC3, 1),
+    (0xE3, 1),
+    (0x05, 1),
+    (0x15, 2),
+    (0x35, 1),
+];
+
+#[test]
+fn encoded_frame_decodes_to_same_header() {
+    let data = [1, 2, 3, 4, 5];
+    let frame = encode(3, 1, MODE_POINT_CLOUD, 0xF1, &data);
+    assert_eq!(frame.len(), 8 + data.len());
+
+    let ParseOutcome::Frame(header) = decode_frame(&frame) else {
+        panic!("complete frame");
+    };
+    assert_eq!(
+        header,
+        FrameHeader {
+            product_line: 3,
+            lidar_id: 1,
+            mode: MODE_POINT_CLOUD,
+            param: 0xF1,
+            data_len: 5,
+        }
+    );
+    assert_eq!(header.channel(), 1);
+    assert_eq!(header.frame_len(), frame.len());
+    assert_eq!(frame_data(&frame), data);
+
+    // 뒤에 다른 데이터가 있어도 앞 프레임만 검증
+    let mut followed = frame.clone();
+    followed.extend_from_slice(&[0xFA, 0x01]);
+    assert_eq!(decode_frame(&followed).frame(), Some(header));
+}
+
+#[test]
+fn prefixes_need_more_data() {
+    let frame = encode(1, 0, MODE_CONFIG, 0x83, &[2, 0, 4, 0, 6, 0]);
+    for len in 0..frame.len() {
+        assert!(
+            matches!(decode_frame(&frame[..len]), ParseOutcome::NeedMoreData),
+            "prefix of {} bytes",
+            len
+        );
+    }
+}
+
+#[test]
+fn invalid_header_and_checksum() {
+    let mut frame = encode(1, 0, MODE_CONFIG, 0x83, &[2, 0, 4, 0, 6, 0]);
+    let last = frame.len() - 1;
+    frame[last] ^= 0xFF;
+    assert_eq!(decode_frame(&frame).error(), Some(&ParseError::Checksum));
+
+    frame[0] = 0xAB;
+    assert_eq!(
+        decode_frame(&frame).error(),
+        Some(&ParseError::InvalidHeader)
+    );
+    assert_eq!(
+        decode_frame(&[0xAB]).error(),
+        Some(&ParseError::InvalidHeader)
+    );
+}
+
+#[test]
+fn short_config_data_is_truncated() {
+    for (param, min_len) in CONFIG_PARAMS {
+        let data = vec![0u8; min_len];
+        assert!(
+            decode_config(1, param, &data).is_ok(),
+            "param 0x{:02X}",
+            param
+        );
+        assert_eq!(
+            decode_config(1, param, &data[..min_len - 1]).err(),
+            Some(ParseError::Truncated),
+            "param 0x{:02X}",
+            param
+        );
+    }
+    assert_eq!(
+        decode_config(1, 0x21, &[]).err(),
+        Some(ParseError::Truncated)
+    );
+    assert_eq!(
+        decode_config(1, 0x99, &[0]).err(),
+        Some(ParseError::UnknownParam(0x99))
+    );
+}
+
+#[test]
+fn basic_config_user_areas() {
+    let mut data = vec![1, 1, 0, 0, 0, 0, 0x00, 0x2D, 0x01, 0x0E, 1, 30, 5, 1];
+    // 사용자 영역 하나, 포인트 두 개 (1.50, -2.25) (3.00, 0.10)
+    data.extend_from_slice(&[2, 1, 50, 0xFE, 0xE7, 3, 0, 0, 10]);
+    let config = decode_config(1, 0x11, &data).unwrap();
+    let config = serde_json::to_value(&config).unwrap();
+    assert_eq!(config["BasicConfig"]["start_angle"], json!(45));
+    assert_eq!(config["BasicConfig"]["finish_angle"], json!(270));
+    assert_eq!(config["BasicConfig"]["area_count"], json!(1));
+    assert_eq!(
+        config["BasicConfig"]["areas"][0]["points"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+
+    // 영역 개수만큼의 포인트가 없으면 패닉 없이 실패
+    assert_eq!(
+        decode_config(1, 0x11, &data[..data.len() - 1]).err(),
+        Some(ParseError::Truncated)
+    );
+    data[13] = 2;
+    assert_eq!(
+        decode_config(1, 0x11, &data).err(),
+        Some(ParseError::Truncated)
+    );
+}
+
+#[test]
+fn short_teaching_area_is_truncated() {
+    // 설정된 티칭 영역인데 거리 데이터가 없거나 2 byte 단위가 아닌 응답
+    for data in [&[1u8][..], &[1, 0]] {
+        let mut parser = KanaviMobilityParser::new();
+        let frame = encode(3, 0, MODE_CONFIG, 0xF1, data);
+        assert_eq!(
+            parser.parse(Ipv4Addr::LOCALHOST, &frame).error(),
+            Some(&ParseError::Truncated),
+            "{} data bytes",
+            data.len()
+        );
+    }
+
+    for product_line in [1, 3, 7] {
+        let distances = TeachingArea::point_count(product_line);
+        let mut data = vec![1];
+        data.resize(1 + 2 * distances, 0);
+        assert!(decode_config(product_line, 0xF1, &data).is_ok());
+        assert_eq!(
+            decode_config(product_line, 0xF1, &data[..data.len() - 1]).err(),
+            Some(ParseError::Truncated)
+        );
+        assert_eq!(
+            decode_config(product_line, 0xF1, &data[..data.len() - 2]).err(),
+            Some(ParseError::Truncated)
+        );
+        data.push(0);
+        assert_eq!(
+            decode_config(product_line, 0xF1, &data).err(),
+            Some(ParseError::Truncated)
+        );
+    }
+    // 설정되지 않은 티칭 영역은 설정 여부만 있으면 됨
+    assert!(decode_config(3, 0xF1, &[0]).is_ok());
+}
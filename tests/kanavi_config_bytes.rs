@@ -0,0 +1,149 @@
+//! Kanavi Mobility 설정 데이터 직렬화 테스트
+//!
+//! 설정 타입의 `to_bytes` 가 장치 데이터 형식과 같아, 응답 데이터를 `codec::decode_config` 로 읽은 뒤
+//! 다시 `to_bytes` 로 만들면 원래 데이터가 되는지(고정 seed 무작위 입력) 확인합니다.
+//! 설정 명령 생성기가 같은 데이터로 설정 명령을 만드는지도 확인합니다.
+
+use lidar_server::lidar::kanavi_mobility::codec::{decode_config, frame_data, MODE_CONFIG};
+use lidar_server::lidar::kanavi_mobility::command::*;
+use lidar_server::lidar::kanavi_mobility::{KMConfigData, KanaviFrameBuilder};
+use lidar_server::sim::synth::SimRng;
+
+/// 무작위 입력 반복 횟수
+const CASES: usize = 200;
+
+fn byte(rng: &mut SimRng) -> u8 {
+    rng.next_u64() as u8
+}
+
+fn bytes(rng: &mut SimRng, len: usize) -> Vec<u8> {
+    (0..len).map(|_| byte(rng)).collect()
+}
+
+/// 거리 (정수부 m, 소수부 cm)
+fn distance(rng: &mut SimRng) -> [u8; 2] {
+    [byte(rng), byte(rng) % 100]
+}
+
+/// 사용자 영역 좌표 (부호 있는 정수부 m, 같은 부호의 소수부 cm)
+fn coordinate(rng: &mut SimRng) -> [u8; 2] {
+    let centimeters = (rng.next_u64() % 25599) as i32 - 12799;
+    [
+        (centimeters / 100) as i8 as u8,
+        (centimeters % 100) as i8 as u8,
+    ]
+}
+
+fn round_trip(product_line: u8, param: u8, data: &[u8]) -> KMConfigData {
+    let config = decode_config(product_line, param, data)
+        .unwrap_or_else(|e| panic!("param 0x{:02X}: {}", param, e));
+    assert_eq!(config.to_bytes(), data, "param 0x{:02X}", param);
+    config
+}
+
+#[test]
+fn fixed_size_configs_round_trip() {
+    let mut rng = SimRng::new(4188);
+    for _ in 0..CASES {
+        round_trip(1, PARAM_VERSION_INFO_REQUEST + 1, &bytes(&mut rng, 7));
+        round_trip(
+            1,
+            PARAM_NETWORK_SOURCE_INFO_REQUEST + 1,
+            &bytes(&mut rng, 20),
+        );
+        round_trip(
+            1,
+            PARAM_NETWORK_DESTINATION_IP_REQUEST + 1,
+            &bytes(&mut rng, 4),
+        );
+        round_trip(1, PARAM_MOTOR_SPEED_REQUEST + 1, &bytes(&mut rng, 1));
+        let areas = [distance(&mut rng), distance(&mut rng), distance(&mut rng)].concat();
+        round_trip(1, PARAM_WARNING_AREA_REQUEST + 1, &areas);
+        round_trip(1, PARAM_FOG_FILTER_REQUEST + 1, &bytes(&mut rng, 1));
+        round_trip(1, PARAM_RADIUS_FILTER_REQUEST + 1, &bytes(&mut rng, 1));
+        round_trip(
+            1,
+            PARAM_RADIUS_FILTER_MAX_DISTANCE_REQUEST + 1,
+            &bytes(&mut rng, 1),
+        );
+        round_trip(
+            1,
+            PARAM_RADIUS_FILTER_MIN_DISTANCE_REQUEST + 1,
+            &bytes(&mut rng, 1),
+        );
+        round_trip(
+            1,
+            PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_REQUEST + 1,
+            &bytes(&mut rng, 1),
+        );
+        round_trip(1, PARAM_TEACHING_MODE_REQUEST + 1, &bytes(&mut rng, 2));
+        // ACK 응답 코드
+        round_trip(1, PARAM_MOTOR_SPEED_SET + 1, &bytes(&mut rng, 1));
+    }
+}
+
+#[test]
+fn basic_config_round_trip() {
+    let mut rng = SimRng::new(11);
+    for _ in 0..CASES {
+        let mut data = bytes(&mut rng, 13);
+        let area_count = byte(&mut rng) % 4;
+        data.push(area_count);
+        for _ in 0..area_count {
+            let point_count = byte(&mut rng) % 8;
+            data.push(point_count);
+            for _ in 0..point_count * 2 {
+                data.extend_from_slice(&coordinate(&mut rng));
+            }
+        }
+        round_trip(1, PARAM_BASIC_CONFIG_REQUEST + 1, &data);
+    }
+}
+
+#[test]
+fn teaching_area_round_trip() {
+    let mut rng = SimRng::new(12);
+    // 제품 라인별 (채널 수, 채널당 거리 수)
+    for (product_line, channels, columns) in [(1, 4, 400), (3, 2, 480), (7, 1, 1080)] {
+        let mut data = vec![1];
+        for _ in 0..channels * columns {
+            data.extend_from_slice(&distance(&mut rng));
+        }
+        round_trip(product_line, PARAM_TEACHING_AREA_REQUEST + 1, &data);
+    }
+    // 설정되지 않은 티칭 영역은 설정 여부만
+    round_trip(1, PARAM_TEACHING_AREA_REQUEST + 1, &[0]);
+}
+
+#[test]
+fn set_command_uses_config_bytes() {
+    let mut rng = SimRng::new(13);
+    let builder = KanaviFrameBuilder::new(3, 0);
+    for _ in 0..CASES {
+        let (danger, warning, caution) =
+            (distance(&mut rng), distance(&mut rng), distance(&mut rng));
+        let config = round_trip(
+            3,
+            PARAM_WARNING_AREA_REQUEST + 1,
+            &[danger, warning, caution].concat(),
+        );
+        assert_eq!(config.set_param(), Some(PARAM_WARNING_AREA_SET));
+        let typed = builder.clone().warning_area_set(danger, warning, caution);
+        assert_eq!(builder.clone().set_config(&config).unwrap(), typed);
+        let frame = typed.build();
+        assert_eq!(frame[3], MODE_CONFIG);
+        assert_eq!(frame_data(&frame), config.to_bytes());
+
+        let speed = byte(&mut rng);
+        let config = round_trip(3, PARAM_MOTOR_SPEED_REQUEST + 1, &[speed]);
+        assert_eq!(
+            builder.clone().set_config(&config).unwrap(),
+            builder.clone().motor_speed_set(speed)
+        );
+    }
+
+    // 읽기 전용 설정은 설정 명령을 만들지 않음
+    let version = round_trip(3, PARAM_VERSION_INFO_REQUEST + 1, &[1, 2, 3, 4, 5, 6, 0]);
+    assert_eq!(version.set_param(), None);
+    assert!(builder.set_config(&version).is_err());
+}
@@ -0,0 +1,78 @@
+//! 설정 트랜잭션 요청 테스트
+//!
+//! `config_transaction` 요청이 명령별 요청으로 변환되고, 설정을 보내기 전에 빈 목록, 중복 파라미터,
+//! 잘못된 파라미터/데이터 길이를 거부하는지 확인합니다.
+
+use lidar_server::lidar::kanavi_mobility::command::{
+    PARAM_FOG_FILTER_SET, PARAM_MOTOR_SPEED_SET, PARAM_RADIUS_FILTER_SET,
+};
+use lidar_server::lidar::kanavi_mobility::ConfigTransactionRequest;
+use lidar_server::ws::message::{RequestMessage, WsRequest};
+use serde_json::{json, Value};
+
+const LIDAR: &str =
+    r#"{ "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 }"#;
+
+fn transaction(changes: Value) -> ConfigTransactionRequest {
+    let lidar: Value = serde_json::from_str(LIDAR).unwrap();
+    serde_json::from_value(json!({ "lidar": lidar, "changes": changes })).unwrap()
+}
+
+#[test]
+fn parses_as_ws_request() {
+    let lidar: Value = serde_json::from_str(LIDAR).unwrap();
+    let request: RequestMessage = serde_json::from_value(json!({
+        "command": "config_transaction",
+        "data": {
+            "lidar": lidar,
+            "changes": [
+                { "param": PARAM_FOG_FILTER_SET, "data": [3] },
+                { "param": PARAM_RADIUS_FILTER_SET, "data": [1] }
+            ]
+        }
+    }))
+    .unwrap();
+    let Ok(WsRequest::ConfigTransaction(transaction)) = WsRequest::parse(&request) else {
+        panic!("config_transaction request");
+    };
+    assert_eq!(transaction.changes.len(), 2);
+    assert_eq!(
+        WsRequest::ConfigTransaction(transaction).target(),
+        Some(serde_json::from_str(LIDAR).unwrap())
+    );
+}
+
+#[test]
+fn validates_changes_in_order() {
+    let params = transaction(json!([
+        { "param": PARAM_MOTOR_SPEED_SET, "data": [2] },
+        { "param": PARAM_FOG_FILTER_SET, "data": [3] }
+    ]))
+    .validate()
+    .unwrap();
+    let sets: Vec<u8> = params.iter().map(|param| param.set).collect();
+    assert_eq!(sets, vec![PARAM_MOTOR_SPEED_SET, PARAM_FOG_FILTER_SET]);
+}
+
+#[test]
+fn rejects_invalid_transactions() {
+    assert!(transaction(json!([])).validate().is_err());
+    // 같은 파라미터 두 번
+    assert!(transaction(json!([
+        { "param": PARAM_FOG_FILTER_SET, "data": [3] },
+        { "param": PARAM_FOG_FILTER_SET, "data": [4] }
+    ]))
+    .validate()
+    .is_err());
+    // 데이터 길이
+    assert!(transaction(json!([
+        { "param": PARAM_MOTOR_SPEED_SET, "data": [2] },
+        { "param": PARAM_FOG_FILTER_SET, "data": [3, 4] }
+    ]))
+    .validate()
+    .is_err());
+    // 설정 확인을 지원하지 않는 파라미터
+    assert!(transaction(json!([{ "param": 0x99, "data": [1] }]))
+        .validate()
+        .is_err());
+}
@@ -0,0 +1,166 @@
+//! Kanavi Mobility 프레임 golden fixture 테스트
+//!
+//! `tests/fixtures/kanavi/*.hex` 의 프레임을 `KanaviMobilityParser` 로 파싱한 결과를
+//! 같은 이름의 `.json` 과 비교하여 파서 리팩터링 시 프로토콜 회귀를 확인합니다.
+//!
+//! * `.hex` - `#` 으로 시작하는 줄은 주석, 빈 줄로 데이터그램 구분 (한 데이터그램이 여러 줄이어도 됨)
+//! * `.json` - 데이터그램별 결과 목록. 포인트 클라우드는 채널별 포인트 수와 처음/가운데/마지막 포인트만 비교
+//! * `UPDATE_FIXTURES=1 cargo test --test kanavi_fixtures` 로 실행하면 현재 결과로 `.json` 을 다시 생성
+
+use std::fs;
+use std::net::Ipv4Addr;
+use std::path::{Path, PathBuf};
+
+use lidar_server::lidar::kanavi_mobility::{KanaviMobilityData, KanaviMobilityParser};
+use lidar_server::lidar::{LiDARData, LiDARParser, ParseOutcome, PointCloud};
+use serde_json::{json, Value};
+
+const LIDAR_IP: Ipv4Addr = Ipv4Addr::new(192, 168, 123, 200);
+
+/// 좌표 비교 허용 오차 (m)
+const TOLERANCE: f64 = 1e-3;
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/kanavi")
+}
+
+/// hex fixture 를 데이터그램 목록으로 변환
+fn read_datagrams(path: &Path) -> Vec<Vec<u8>> {
+    let text = fs::read_to_string(path).unwrap();
+    let mut datagrams = Vec::new();
+    let mut current = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.is_empty() {
+            if !current.is_empty() {
+                datagrams.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        for byte in line.split_whitespace() {
+            let byte = u8::from_str_radix(byte, 16).unwrap_or_else(|e| {
+                panic!("{}: invalid hex byte {:?}: {}", path.display(), byte, e)
+            });
+            current.push(byte);
+        }
+    }
+    if !current.is_empty() {
+        datagrams.push(current);
+    }
+    datagrams
+}
+
+/// 채널별 포인트 수와 처음/가운데/마지막 포인트
+fn summarize_points(ch: usize, cloud: &PointCloud) -> Value {
+    let points = &cloud.points;
+    let mut indices = vec![];
+    if !points.is_empty() {
+        indices = vec![0, points.len() / 2, points.len() - 1];
+        indices.dedup();
+    }
+    let samples: Vec<_> = indices
+        .into_iter()
+        .map(|i| json!([points[i].x, points[i].y, points[i].z]))
+        .collect();
+    json!({ "channel": ch, "count": points.len(), "samples": samples })
+}
+
+/// 데이터그램 하나의 파싱 결과
+fn summarize(parser: &mut KanaviMobilityParser, datagram: &[u8]) -> Value {
+    let data = match parser.parse(LIDAR_IP, datagram) {
+        ParseOutcome::Frame(data) => data,
+        ParseOutcome::NeedMoreData => return json!({ "need_more_data": true }),
+        ParseOutcome::Skipped { reason } => return json!({ "skipped": reason }),
+        ParseOutcome::Error(e) => return json!({ "error": e.to_string() }),
+    };
+    let data = data
+        .as_any()
+        .downcast_ref::<KanaviMobilityData>()
+        .expect("Kanavi data");
+    let channels: Vec<_> = data
+        .get_points()
+        .iter()
+        .enumerate()
+        .map(|(ch, cloud)| summarize_points(ch, cloud))
+        .collect();
+    json!({
+        "product_line": data.get_product_line(),
+        "lidar_id": data.get_lidar_id(),
+        "mode": data.get_mode(),
+        "param": data.get_param(),
+        "data": serde_json::to_value(data.get_config_data()).unwrap(),
+        "channels": channels,
+    })
+}
+
+/// 숫자는 허용 오차 안에서 같으면 같은 값으로 비교
+fn matches(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Number(a), Value::Number(b)) => {
+            (a.as_f64().unwrap() - b.as_f64().unwrap()).abs() <= TOLERANCE
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| matches(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| matches(a, b)))
+        }
+        _ => actual == expected,
+    }
+}
+
+#[test]
+fn kanavi_fixtures_match_golden_output() {
+    let update = std::env::var_os("UPDATE_FIXTURES").is_some();
+    let mut fixtures: Vec<_> = fs::read_dir(fixture_dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "hex"))
+        .collect();
+    fixtures.sort();
+    assert!(
+        !fixtures.is_empty(),
+        "no fixtures in {}",
+        fixture_dir().display()
+    );
+
+    let mut failures = Vec::new();
+    for hex in &fixtures {
+        let mut parser = KanaviMobilityParser::new();
+        let actual: Vec<_> = read_datagrams(hex)
+            .iter()
+            .map(|datagram| summarize(&mut parser, datagram))
+            .collect();
+        let actual = Value::Array(actual);
+
+        let golden = hex.with_extension("json");
+        if update {
+            let text = serde_json::to_string_pretty(&actual).unwrap();
+            fs::write(&golden, text + "\n").unwrap();
+            continue;
+        }
+        let expected: Value = match fs::read_to_string(&golden) {
+            Ok(text) => serde_json::from_str(&text).unwrap(),
+            Err(e) => {
+                failures.push(format!("{}: {}", golden.display(), e));
+                continue;
+            }
+        };
+        if !matches(&actual, &expected) {
+            failures.push(format!(
+                "{} differs from golden output:\n{}",
+                hex.display(),
+                serde_json::to_string_pretty(&actual).unwrap()
+            ));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{}\n(run with UPDATE_FIXTURES=1 to regenerate golden files)",
+        failures.join("\n")
+    );
+}
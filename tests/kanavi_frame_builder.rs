@@ -0,0 +1,298 @@
+//! Kanavi Mobility 프레임 생성기 테스트
+//!
+//! `KanaviFrameBuilder` 로 만든 프레임을 무작위 입력(고정 seed)으로 생성하여 `codec::decode_frame` 과
+//! `KanaviMobilityParser` 가 같은 헤더/데이터로 읽는지, 설정 명령의 데이터가 같은 설정의 응답 형식과
+//! 같아 디코더로 다시 읽으면 같은 값이 되는지 확인합니다.
+
+use lidar_server::lidar::kanavi_mobility::codec::{
+    decode_config, decode_frame, encode, frame_data, MODE_CONFIG, MODE_NAK, MODE_POINT_CLOUD,
+};
+use lidar_server::lidar::kanavi_mobility::command::*;
+use lidar_server::lidar::kanavi_mobility::readback::SET_PARAMS;
+use lidar_server::lidar::kanavi_mobility::{KanaviFrameBuilder, KanaviMobilityParser};
+use lidar_server::lidar::{LiDARKey, LiDARParser, ParseError, ParseOutcome};
+use lidar_server::sim::synth::SimRng;
+use serde_json::{json, Value};
+use std::net::Ipv4Addr;
+
+const LIDAR_IP: Ipv4Addr = Ipv4Addr::new(192, 168, 123, 200);
+
+/// 무작위 입력 반복 횟수
+const CASES: usize = 500;
+
+fn byte(rng: &mut SimRng) -> u8 {
+    rng.next_u64() as u8
+}
+
+fn bytes(rng: &mut SimRng, max_len: usize) -> Vec<u8> {
+    let len = rng.next_u64() as usize % (max_len + 1);
+    (0..len).map(|_| byte(rng)).collect()
+}
+
+/// 설정 응답 데이터로 디코딩한 값 (비교용 JSON)
+fn decoded(product_line: u8, param: u8, data: &[u8]) -> Value {
+    serde_json::to_value(decode_config(product_line, param, data).expect("valid response data"))
+        .unwrap()
+}
+
+#[test]
+fn random_frames_round_trip() {
+    let mut rng = SimRng::new(4187);
+    for _ in 0..CASES {
+        let (product_line, lidar_id, param) = (byte(&mut rng), byte(&mut rng), byte(&mut rng));
+        // 설정 모드는 파라미터별 데이터 형식이 있으므로 아래 설정 명령 테스트에서 확인
+        let mode = match byte(&mut rng) {
+            MODE_CONFIG => MODE_POINT_CLOUD,
+            mode => mode,
+        };
+        let payload = bytes(&mut rng, 2048);
+        let frame = KanaviFrameBuilder::new(product_line, lidar_id)
+            .mode(mode)
+            .param(param)
+            .payload(&payload)
+            .build();
+        assert_eq!(frame, encode(product_line, lidar_id, mode, param, &payload));
+
+        let header = decode_frame(&frame).frame().expect("complete frame");
+        assert_eq!(
+            (
+                header.product_line,
+                header.lidar_id,
+                header.mode,
+                header.param
+            ),
+            (product_line, lidar_id, mode, param)
+        );
+        assert_eq!(header.frame_len(), frame.len());
+        assert_eq!(frame_data(&frame), payload);
+
+        // 데이터나 체크섬이 바뀌면 체크섬 불일치
+        let mut corrupted = frame.clone();
+        corrupted[7 + rng.next_u64() as usize % (frame.len() - 7)] ^= 0x01;
+        assert_eq!(
+            decode_frame(&corrupted).error(),
+            Some(&ParseError::Checksum)
+        );
+
+        // 임의 위치에서 나눠 받아도 한 프레임으로 합쳐짐
+        let split = rng.next_u64() as usize % frame.len();
+        let (head, tail) = frame.split_at(split);
+        let mut parser = KanaviMobilityParser::new();
+        if !head.is_empty() {
+            assert!(matches!(
+                parser.parse(LIDAR_IP, head),
+                ParseOutcome::NeedMoreData
+            ));
+        }
+        let outcome = parser.parse(LIDAR_IP, tail);
+        if matches!(mode, MODE_NAK | MODE_POINT_CLOUD) {
+            let data = outcome.frame().expect("reassembled frame");
+            assert_eq!(data.get_raw_data(), frame);
+        } else {
+            // 지원하지 않는 모드는 합친 뒤 건너뜀
+            assert!(matches!(outcome, ParseOutcome::Skipped { .. }));
+        }
+        assert_eq!(parser.buffered_len(), 0);
+    }
+}
+
+#[test]
+fn requests_have_no_payload() {
+    let key = LiDARKey::new(LIDAR_IP, 5000, 3, 1);
+    let builder = KanaviFrameBuilder::for_lidar(&key);
+    let requests = [
+        (
+            builder.clone().basic_config_request(),
+            PARAM_BASIC_CONFIG_REQUEST,
+        ),
+        (
+            builder.clone().version_info_request(),
+            PARAM_VERSION_INFO_REQUEST,
+        ),
+        (
+            builder.clone().network_source_info_request(),
+            PARAM_NETWORK_SOURCE_INFO_REQUEST,
+        ),
+        (
+            builder.clone().network_destination_ip_request(),
+            PARAM_NETWORK_DESTINATION_IP_REQUEST,
+        ),
+        (
+            builder.clone().motor_speed_request(),
+            PARAM_MOTOR_SPEED_REQUEST,
+        ),
+        (
+            builder.clone().warning_area_request(),
+            PARAM_WARNING_AREA_REQUEST,
+        ),
+        (
+            builder.clone().fog_filter_request(),
+            PARAM_FOG_FILTER_REQUEST,
+        ),
+        (
+            builder.clone().radius_filter_request(),
+            PARAM_RADIUS_FILTER_REQUEST,
+        ),
+        (
+            builder.clone().radius_filter_max_distance_request(),
+            PARAM_RADIUS_FILTER_MAX_DISTANCE_REQUEST,
+        ),
+        (
+            builder.clone().radius_filter_min_distance_request(),
+            PARAM_RADIUS_FILTER_MIN_DISTANCE_REQUEST,
+        ),
+        (
+            builder
+                .clone()
+                .window_contamination_detection_mode_request(),
+            PARAM_WINDOW_CONTAMINATION_DETECTION_MODE_REQUEST,
+        ),
+        (
+            builder.clone().teaching_mode_request(),
+            PARAM_TEACHING_MODE_REQUEST,
+        ),
+        (
+            builder.clone().teaching_area_request(),
+            PARAM_TEACHING_AREA_REQUEST,
+        ),
+    ];
+    for (request, param) in requests {
+        let frame = request.build();
+        assert_eq!(frame, encode(3, 1, MODE_CONFIG, param, &[]));
+        assert_eq!(command_target(&frame), Some((3, 1)));
+    }
+
+    // 설정 확인 파라미터의 요청 명령은 typed 생성자와 같은 프레임
+    let warning_area = SET_PARAMS
+        .iter()
+        .find(|param| param.set == PARAM_WARNING_AREA_SET)
+        .unwrap();
+    assert_eq!(
+        builder.clone().config_request(warning_area),
+        builder.warning_area_request()
+    );
+}
+
+#[test]
+fn set_payloads_decode_as_readback_responses() {
+    let mut rng = SimRng::new(4188);
+    for _ in 0..CASES {
+        let product_line = 1 + byte(&mut rng) % 7;
+        let builder = KanaviFrameBuilder::new(product_line, byte(&mut rng));
+        let value = byte(&mut rng);
+        let areas = [
+            [byte(&mut rng), byte(&mut rng) % 100],
+            [byte(&mut rng), byte(&mut rng) % 100],
+            [byte(&mut rng), byte(&mut rng) % 100],
+        ];
+        let ip = Ipv4Addr::from(rng.next_u64() as u32);
+        let (range, margin) = (byte(&mut rng), byte(&mut rng));
+
+        let cases = [
+            (
+                builder.clone().network_destination_ip_set(ip),
+                json!({ "NetworkDestinationIP": { "ip_address": ip.octets() } }),
+            ),
+            (
+                builder.clone().motor_speed_set(value),
+                json!({ "MotorSpeed": { "speed": value } }),
+            ),
+            (
+                builder
+                    .clone()
+                    .warning_area_set(areas[0], areas[1], areas[2]),
+                json!({ "WarningArea": {
+                    "danger_area": areas[0],
+                    "warning_area": areas[1],
+                    "caution_area": areas[2],
+                } }),
+            ),
+            (
+                builder.clone().fog_filter_set(value),
+                json!({ "FogFilter": { "filter_value": value } }),
+            ),
+            (
+                builder.clone().radius_filter_set(value),
+                json!({ "RadiusFilter": { "filter_value": value } }),
+            ),
+            (
+                builder.clone().radius_filter_max_distance_set(value),
+                json!({ "RadiusFilterMaxDistance": { "max_distance": value } }),
+            ),
+            (
+                builder.clone().radius_filter_min_distance_set(value),
+                json!({ "RadiusFilterMinDistance": { "min_distance": value } }),
+            ),
+            (
+                builder
+                    .clone()
+                    .window_contamination_detection_mode_set(value),
+                json!({ "WindowContaminationDetectionMode": { "mode": value } }),
+            ),
+            (
+                builder.clone().teaching_mode_set(range, margin),
+                json!({ "TeachingMode": { "range": range, "margin": margin } }),
+            ),
+        ];
+        for (set, expected) in cases {
+            let frame = set.build();
+            let header = decode_frame(&frame).frame().expect("complete frame");
+            let param = SET_PARAMS
+                .iter()
+                .find(|param| param.set == header.param)
+                .expect("set param with readback");
+            let data = frame_data(&frame);
+            assert_eq!(data.len(), param.data_len());
+            assert_eq!(
+                decoded(product_line, param.request + 1, data),
+                expected,
+                "set 0x{:02X}",
+                param.set
+            );
+        }
+    }
+}
+
+#[test]
+fn network_source_set_matches_response_layout() {
+    let frame = KanaviFrameBuilder::new(1, 0)
+        .network_source_info_set(
+            Ipv4Addr::new(192, 168, 123, 201),
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            Ipv4Addr::new(255, 255, 255, 0),
+            Ipv4Addr::new(192, 168, 123, 1),
+            5000,
+        )
+        .build();
+    assert_eq!(
+        network_source_change(&frame),
+        Some((1, 0, Ipv4Addr::new(192, 168, 123, 201)))
+    );
+    let data = frame_data(&frame);
+    assert_eq!(
+        decoded(1, PARAM_NETWORK_SOURCE_INFO_REQUEST + 1, data),
+        json!({ "NetworkSourceInfo": {
+            "ip_address": [192, 168, 123, 201],
+            "mac_address": [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            "subnet_mask": [255, 255, 255, 0],
+            "gateway": [192, 168, 123, 1],
+            "port": 5000,
+        } })
+    );
+}
+
+#[test]
+fn point_cloud_frame() {
+    let distances = [1, 50, 2, 0, 0, 0];
+    let frame = KanaviFrameBuilder::broadcast()
+        .point_cloud(0x12, &distances)
+        .build();
+    let header = decode_frame(&frame).frame().unwrap();
+    assert_eq!(header.mode, MODE_POINT_CLOUD);
+    assert_eq!(header.channel(), 2);
+    assert_eq!(
+        (header.product_line, header.lidar_id),
+        (BROADCAST_PRODUCT_LINE, BROADCAST_LIDAR_ID)
+    );
+    assert_eq!(frame_data(&frame), distances);
+}
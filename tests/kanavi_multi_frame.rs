@@ -0,0 +1,126 @@
+//! Kanavi Mobility 다중 프레임 데이터그램 테스트
+//!
+//! 한 데이터그램에 프레임이 여러 개 들어 있으면 프레임마다 따로 파싱하고,
+//! 남은 데이터가 프레임 앞부분이면 다음 데이터그램과 이어 붙이는지 확인합니다.
+//! 프레임이 아직 다 오지 않았으면 실패가 아니라 `NeedMoreData`, 지원하지 않는 모드면 `Skipped` 를 반환합니다.
+
+use lidar_server::lidar::kanavi_mobility::codec::encode;
+use lidar_server::lidar::kanavi_mobility::{KanaviMobilityData, KanaviMobilityParser};
+use lidar_server::lidar::{LiDARParser, ParseError, ParseOutcome};
+use std::net::Ipv4Addr;
+
+const LIDAR_IP: Ipv4Addr = Ipv4Addr::new(192, 168, 123, 200);
+
+/// 경고 영역 응답 (제품 라인 1)
+const WARNING_AREA: [u8; 14] = [
+    0xFA, 0x01, 0x00, 0xCF, 0x83, 0x00, 0x06, 0x02, 0x00, 0x04, 0x00, 0x06, 0x00, 0xB1,
+];
+
+/// NAK 응답 (제품 라인 1)
+const NAK: [u8; 8] = [0xFA, 0x01, 0x00, 0xF0, 0x00, 0x00, 0x00, 0x0B];
+
+fn mode(parser: &mut KanaviMobilityParser, data: &[u8]) -> u8 {
+    let data = parser.parse(LIDAR_IP, data).frame().expect("frame");
+    data.as_any()
+        .downcast_ref::<KanaviMobilityData>()
+        .expect("Kanavi data")
+        .get_mode()
+}
+
+#[test]
+fn two_frames_in_one_datagram() {
+    let mut parser = KanaviMobilityParser::new();
+    let datagram = [WARNING_AREA.as_slice(), NAK.as_slice()].concat();
+
+    let first = parser.parse(LIDAR_IP, &datagram).frame().unwrap();
+    assert_eq!(first.get_raw_data(), WARNING_AREA);
+    assert!(parser.has_frame());
+
+    assert_eq!(mode(&mut parser, &[]), 0xF0);
+    assert!(!parser.has_frame());
+    assert_eq!(parser.buffered_len(), 0);
+}
+
+#[test]
+fn trailing_garbage_is_discarded() {
+    let mut parser = KanaviMobilityParser::new();
+    let datagram = [WARNING_AREA.as_slice(), &[0x00, 0x01]].concat();
+
+    assert_eq!(mode(&mut parser, &datagram), 0xCF);
+    assert!(!parser.has_frame());
+    assert_eq!(parser.buffered_len(), 0);
+
+    // 다음 데이터그램은 헤더 불일치 없이 파싱
+    assert_eq!(mode(&mut parser, &NAK), 0xF0);
+}
+
+#[test]
+fn partial_second_frame_waits_for_next_datagram() {
+    let mut parser = KanaviMobilityParser::new();
+    let datagram = [WARNING_AREA.as_slice(), &WARNING_AREA[..9]].concat();
+
+    assert_eq!(mode(&mut parser, &datagram), 0xCF);
+    assert!(!parser.has_frame());
+    assert_eq!(parser.buffered_len(), 9);
+
+    assert_eq!(mode(&mut parser, &WARNING_AREA[9..]), 0xCF);
+    assert_eq!(parser.buffered_len(), 0);
+}
+
+#[test]
+fn failed_frame_does_not_drop_the_next() {
+    let mut parser = KanaviMobilityParser::new();
+    let unknown_param = [0xFA, 0x01, 0x00, 0xCF, 0x99, 0x00, 0x01, 0x00, 0xAC];
+    let datagram = [unknown_param.as_slice(), NAK.as_slice()].concat();
+
+    assert_eq!(
+        parser.parse(LIDAR_IP, &datagram).error(),
+        Some(&ParseError::UnknownParam(0x99))
+    );
+    assert!(parser.has_frame());
+    assert_eq!(mode(&mut parser, &[]), 0xF0);
+}
+
+#[test]
+fn unknown_mode_is_skipped() {
+    let mut parser = KanaviMobilityParser::new();
+    let unknown_mode = encode(1, 0, 0x11, 0x00, &[]);
+    let datagram = [unknown_mode.as_slice(), NAK.as_slice()].concat();
+
+    match parser.parse(LIDAR_IP, &datagram) {
+        ParseOutcome::Skipped { reason } => assert!(reason.contains("0x11"), "{}", reason),
+        _ => panic!("unknown mode should be skipped"),
+    }
+    assert!(parser.has_frame());
+    assert_eq!(mode(&mut parser, &[]), 0xF0);
+    assert_eq!(parser.buffered_len(), 0);
+}
+
+#[test]
+fn frame_split_byte_by_byte_needs_more_data() {
+    let mut parser = KanaviMobilityParser::new();
+    assert!(matches!(
+        parser.parse(LIDAR_IP, &[]),
+        ParseOutcome::NeedMoreData
+    ));
+    for (i, byte) in WARNING_AREA[..WARNING_AREA.len() - 1].iter().enumerate() {
+        assert!(matches!(
+            parser.parse(LIDAR_IP, &[*byte]),
+            ParseOutcome::NeedMoreData
+        ));
+        assert_eq!(parser.buffered_len(), i + 1);
+    }
+    assert_eq!(
+        mode(&mut parser, &WARNING_AREA[WARNING_AREA.len() - 1..]),
+        0xCF
+    );
+    assert_eq!(parser.buffered_len(), 0);
+}
+
+#[test]
+fn invalid_header_is_an_error() {
+    let mut parser = KanaviMobilityParser::new();
+    let outcome = parser.parse(LIDAR_IP, &[0xAB, 0x01]);
+    assert_eq!(outcome.error(), Some(&ParseError::InvalidHeader));
+    assert_eq!(parser.buffered_len(), 0);
+}
@@ -0,0 +1,50 @@
+//! Kanavi Mobility 투영 표 테스트
+//!
+//! 투영 표 + SIMD 변환이 포인트별 삼각함수 계산과 같은 좌표를 만드는지
+//! 제품 라인과 채널, 8 의 배수가 아닌 길이와 FOV 를 넘는 길이에서 확인합니다.
+
+use lidar_server::lidar::kanavi_mobility::{
+    project_points, project_points_scalar, ProjectionTable,
+};
+
+fn distances(points: usize) -> Vec<u8> {
+    (0..points)
+        .flat_map(|i| [(i % 60) as u8, (i * 7 % 100) as u8])
+        .collect()
+}
+
+#[test]
+fn table_matches_scalar_projection() {
+    for product_line in [1u8, 3, 7] {
+        let table = ProjectionTable::for_product_line(product_line);
+        for len in [0, 1, 13, table.columns(), table.columns() + 5] {
+            // 홀수 바이트는 마지막 바이트를 무시
+            let mut data = distances(len);
+            data.push(0xFF);
+            for ch in 0..table.channels() as u8 {
+                let expected = project_points_scalar(product_line, ch, &data);
+                let actual = project_points(product_line, ch, &data);
+                assert_eq!(actual.points.len(), len.min(table.columns()));
+                assert_eq!(actual.points.len(), expected.points.len());
+                for (a, e) in actual.points.iter().zip(&expected.points) {
+                    assert!(
+                        (a.x - e.x).abs() < 1e-3
+                            && (a.y - e.y).abs() < 1e-3
+                            && (a.z - e.z).abs() < 1e-3,
+                        "product line {} ch {}: {:?} != {:?}",
+                        product_line,
+                        ch,
+                        a,
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn unknown_channel_is_empty() {
+    let table = ProjectionTable::for_product_line(7);
+    assert!(table.project(1, &distances(8)).points.is_empty());
+}
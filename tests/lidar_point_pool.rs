@@ -0,0 +1,83 @@
+//! 포인트 버퍼 풀 테스트
+//!
+//! 풀이 같은 용량의 버퍼를 비워서 다시 내주는지, 가득 차면 반환한 버퍼를 해제하는지 확인하고,
+//! LiDAR 10 대 부하에서 파싱 후 버퍼를 반환하면 새로 할당하는 버퍼 수가 줄어드는지 측정합니다.
+
+use lidar_server::lidar::kanavi_mobility::codec::encode;
+use lidar_server::lidar::kanavi_mobility::KanaviMobilityParser;
+use lidar_server::lidar::{LiDARParser, Point, PointPool};
+use std::net::Ipv4Addr;
+
+#[test]
+fn pool_reuses_buffers_by_capacity() {
+    let pool = PointPool::new(1);
+    let mut buffer = pool.take(400);
+    buffer.push(Point {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    });
+    pool.give(buffer);
+    pool.give(Vec::with_capacity(400));
+
+    let reused = pool.take(400);
+    assert!(reused.is_empty());
+    assert_eq!(reused.capacity(), 400);
+    let other = pool.take(1080);
+    assert_eq!(other.capacity(), 1080);
+
+    let stats = pool.stats();
+    assert_eq!((stats.allocated, stats.reused, stats.discarded), (2, 1, 1));
+    assert_eq!(stats.pooled, 0);
+}
+
+#[test]
+fn recycling_cuts_allocations_under_load() {
+    const SENSORS: u8 = 10;
+    const FRAMES: usize = 50;
+    let packets: Vec<(Ipv4Addr, Vec<u8>)> = (0..SENSORS)
+        .flat_map(|sensor| {
+            (0..4u8).map(move |ch| {
+                let distances: Vec<u8> = (0..400).flat_map(|i| [(i % 50) as u8 + 1, 0]).collect();
+                (
+                    Ipv4Addr::new(192, 168, 123, 200 + sensor),
+                    encode(1, 0, 0xDD, 0xF0 | ch, &distances),
+                )
+            })
+        })
+        .collect();
+
+    let pool = PointPool::global();
+    let before = pool.stats();
+    let mut parser = KanaviMobilityParser::new();
+    for _ in 0..FRAMES {
+        for (ip, packet) in &packets {
+            let mut data = parser.parse(*ip, packet).frame().unwrap();
+            assert_eq!(
+                data.get_points()
+                    .iter()
+                    .map(|c| c.points.len())
+                    .sum::<usize>(),
+                400
+            );
+            pool.recycle(data.take_points());
+        }
+    }
+    let after = pool.stats();
+
+    let buffers = (FRAMES * packets.len()) as u64;
+    let allocated = after.allocated - before.allocated;
+    let reused = after.reused - before.reused;
+    println!(
+        "{} sensors: {} point buffers, {} allocated, {} reused",
+        SENSORS, buffers, allocated, reused
+    );
+    assert_eq!(allocated + reused, buffers);
+    // 파싱과 반환을 번갈아 하므로 처음 한 번만 할당
+    assert!(
+        allocated * 100 <= buffers,
+        "allocated {} of {}",
+        allocated,
+        buffers
+    );
+}
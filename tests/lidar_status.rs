@@ -0,0 +1,125 @@
+//! LiDAR 장치 목록 동작 상태 테스트
+//!
+//! 장치 저장소가 처음 수신 시각과 보고된 펌웨어 버전을 기록하고, `lidar_list` 응답 항목이
+//! 기존 장치 정보 형식을 유지한 채 동작 상태를 덧붙이는지 확인합니다.
+//! 새 LiDAR 를 등록하면 한 번만 알리고, 정보 조회 요청 프레임이 해당 LiDAR 를 대상으로 하는지도 확인합니다.
+
+use lidar_server::lidar::kanavi_mobility::codec::{checksum, MODE_CONFIG};
+use lidar_server::lidar::kanavi_mobility::command::{
+    command_target, identify_requests, PARAM_NETWORK_SOURCE_INFO_REQUEST,
+    PARAM_VERSION_INFO_REQUEST,
+};
+use lidar_server::lidar::kanavi_mobility::VersionInfo;
+use lidar_server::lidar::{CompanyInfo, LiDARInfo, LiDARKey, LiDARRegistry, LiDARStatus};
+use lidar_server::site::{SiteConfig, SiteDefinition};
+use std::net::{Ipv4Addr, SocketAddrV4};
+
+fn source(last: u8) -> SocketAddrV4 {
+    SocketAddrV4::new(Ipv4Addr::new(192, 168, 123, last), 5000)
+}
+
+#[tokio::test]
+async fn registry_keeps_first_seen_and_firmware() {
+    let registry = LiDARRegistry::default();
+    let key = registry
+        .update(CompanyInfo::KanaviMobility, source(200), 3, 0, None, None)
+        .await;
+    let first = registry.get(&key).await.unwrap();
+    assert_eq!(first.first_seen, first.last_seen);
+    assert_eq!(first.firmware_version, None);
+
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    registry
+        .update(CompanyInfo::KanaviMobility, source(200), 3, 0, None, None)
+        .await;
+    let version = VersionInfo::new([1, 2, 3], [4, 5, 6], 0);
+    registry
+        .set_firmware_version(&key, version.firmware_version_text())
+        .await;
+
+    let info = registry.get(&key).await.unwrap();
+    assert_eq!(info.first_seen, first.first_seen);
+    assert!(info.last_seen > info.first_seen);
+    assert_eq!(info.firmware_version.as_deref(), Some("1.2.3"));
+}
+
+#[test]
+fn offline_threshold_follows_sites() {
+    let key = |last: u8| LiDARKey::new(Ipv4Addr::new(192, 168, 123, last), 5000, 3, 0);
+    let site = |name: &str, offline_ms: u64| SiteDefinition {
+        name: name.to_string(),
+        lidars: vec![key(200)],
+        quorum: None,
+        offline_ms,
+    };
+    let config = SiteConfig {
+        sites: vec![site("a", 3000), site("b", 1000)],
+        ..Default::default()
+    };
+
+    assert_eq!(config.offline_ms(&key(200)), 1000);
+    assert_eq!(config.offline_ms(&key(201)), 5000);
+}
+
+#[tokio::test]
+async fn status_flattens_device_info() {
+    let registry = LiDARRegistry::default();
+    let key = registry
+        .update(CompanyInfo::KanaviMobility, source(200), 3, 0, None, None)
+        .await;
+    let status = LiDARStatus {
+        info: registry.get(&key).await.unwrap(),
+        online: true,
+        frame_rate: Some(25.0),
+        motor_speed: Some(2),
+    };
+
+    let value = serde_json::to_value(&status).unwrap();
+    assert_eq!(value["ip"], "192.168.123.200");
+    assert_eq!(value["online"], true);
+    assert_eq!(value["frame_rate"], 25.0);
+    assert_eq!(value["motor_speed"], 2);
+    assert!(value["firmware_version"].is_null());
+
+    // 장치 정보만 읽는 클라이언트(릴레이 등)는 기존 형식으로 읽을 수 있어야 함
+    let info: LiDARInfo = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(info.key, key);
+    let parsed: LiDARStatus = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed.motor_speed, Some(2));
+}
+
+#[tokio::test]
+async fn new_lidar_is_announced_once() {
+    let registry = LiDARRegistry::default();
+    let mut discoveries = registry.subscribe_discoveries();
+
+    let key = registry
+        .update(CompanyInfo::KanaviMobility, source(200), 3, 0, None, None)
+        .await;
+    registry
+        .update(CompanyInfo::KanaviMobility, source(200), 3, 0, None, None)
+        .await;
+
+    assert_eq!(discoveries.try_recv().unwrap(), key);
+    assert!(discoveries.try_recv().is_err());
+}
+
+#[test]
+fn identify_requests_target_the_lidar() {
+    let frames = identify_requests(3, 1);
+    let params: Vec<u8> = frames.iter().map(|frame| frame[4]).collect();
+
+    assert_eq!(
+        params,
+        vec![
+            PARAM_VERSION_INFO_REQUEST,
+            PARAM_NETWORK_SOURCE_INFO_REQUEST
+        ]
+    );
+    for frame in &frames {
+        assert_eq!(command_target(frame), Some((3, 1)));
+        assert_eq!(frame[3], MODE_CONFIG);
+        assert_eq!(frame.len(), 8);
+        assert_eq!(checksum(&frame[..7]), frame[7]);
+    }
+}
@@ -0,0 +1,101 @@
+//! JSON 로그 형식 테스트
+//!
+//! 로그 한 줄이 고정된 필드 이름의 JSON 객체로 출력되고, span 필드와 `trace_id` 가
+//! 안쪽 span 기준으로 합쳐지는지 확인합니다.
+
+use lidar_server::logging::{JsonFields, JsonFormat, TraceId};
+use serde_json::Value;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing::{info, info_span, warn};
+
+/// 출력한 로그를 보관하는 writer
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn capture(f: impl FnOnce()) -> Vec<Value> {
+    let captured = Captured::default();
+    let writer = captured.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .fmt_fields(JsonFields)
+        .event_format(JsonFormat)
+        .finish();
+    tracing::subscriber::with_default(subscriber, f);
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("one JSON object per line"))
+        .collect()
+}
+
+#[test]
+fn event_fields_are_stable() {
+    let lines = capture(|| info!(frames = 3u64, ok = true, "Parsed {} frames", 3));
+    assert_eq!(lines.len(), 1);
+    let line = &lines[0];
+    assert_eq!(line["level"], "INFO");
+    assert_eq!(line["message"], "Parsed 3 frames");
+    assert_eq!(line["target"], "logging_json");
+    assert_eq!(line["file"], "tests/logging_json.rs");
+    assert!(line["line"].is_u64());
+    assert!(line["time_ms"].as_u64().unwrap() > 1_600_000_000_000);
+    assert!(line["thread"].is_string());
+    assert_eq!(line["trace_id"], Value::Null);
+    assert_eq!(line["spans"], serde_json::json!([]));
+    assert_eq!(
+        line["fields"],
+        serde_json::json!({ "frames": 3, "ok": true })
+    );
+}
+
+#[test]
+fn span_context_and_trace_id() {
+    let outer = TraceId(0x1234);
+    let inner = TraceId(0xabcd);
+    let lines = capture(|| {
+        let udp = info_span!("udp_frame", trace_id = %outer, src = "192.168.123.200:5000");
+        let _udp = udp.enter();
+        warn!("outer");
+        let ws = info_span!("ws_frame", trace_id = %inner, replay = false);
+        let _ws = ws.enter();
+        ws.record("replay", true);
+        info!("inner");
+    });
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["trace_id"], "0000000000001234");
+    assert_eq!(lines[0]["spans"], serde_json::json!(["udp_frame"]));
+    assert_eq!(
+        lines[0]["context"],
+        serde_json::json!({ "src": "192.168.123.200:5000" })
+    );
+
+    assert_eq!(lines[1]["trace_id"], "000000000000abcd");
+    assert_eq!(
+        lines[1]["spans"],
+        serde_json::json!(["udp_frame", "ws_frame"])
+    );
+    assert_eq!(
+        lines[1]["context"],
+        serde_json::json!({ "src": "192.168.123.200:5000", "replay": true })
+    );
+}
+
+#[test]
+fn trace_ids_are_unique() {
+    let ids: Vec<TraceId> = (0..1000).map(|_| TraceId::next()).collect();
+    let unique: std::collections::HashSet<_> = ids.iter().collect();
+    assert_eq!(unique.len(), ids.len());
+    assert_eq!(ids[0].to_string().len(), 16);
+}
@@ -0,0 +1,104 @@
+//! 로그 파일 회전/보관 테스트
+//!
+//! 크기를 넘으면 보관 파일로 회전하고, 보관 개수와 전체 크기 제한을 넘는 오래된 보관 파일을
+//! 삭제하며, 요청 시 바로 회전하는지 확인합니다.
+
+use lidar_server::logging::{LogConfig, LogFile};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("lidar-log-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn config(dir: &Path) -> LogConfig {
+    LogConfig {
+        dir: dir.to_path_buf(),
+        max_file_bytes: 100,
+        daily: false,
+        max_files: 3,
+        max_total_bytes: 1000,
+        ..LogConfig::default()
+    }
+}
+
+fn write_lines(log_file: &LogFile, lines: usize) {
+    let mut writer = log_file;
+    for _ in 0..lines {
+        // 한 줄 40 byte, 파일 하나에 두 줄
+        writer.write_all(&[b'x'; 39]).unwrap();
+        writer.write_all(b"\n").unwrap();
+    }
+}
+
+#[test]
+fn rotates_by_size_and_keeps_max_files() {
+    let dir = temp_dir("size");
+    let log_file = LogFile::open(&config(&dir)).unwrap();
+    write_lines(&log_file, 2);
+    assert!(log_file.archives().is_empty());
+
+    write_lines(&log_file, 1);
+    let archives = log_file.archives();
+    assert_eq!(archives.len(), 1);
+    assert_eq!(archives[0].1, 80);
+    let name = archives[0].0.file_name().unwrap().to_str().unwrap();
+    assert!(name.starts_with("lidar-server.") && name.ends_with(".log"));
+    assert_eq!(std::fs::metadata(log_file.path()).unwrap().len(), 40);
+
+    // 보관 파일은 최대 3 개, 가장 오래된 것부터 삭제
+    write_lines(&log_file, 10);
+    let archives = log_file.archives();
+    assert_eq!(archives.len(), 3);
+    assert!(!archives.iter().any(|(path, _)| *path == log_file.path()));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn total_size_limit() {
+    let dir = temp_dir("total");
+    let log_file = LogFile::open(&LogConfig {
+        max_files: 100,
+        max_total_bytes: 200,
+        ..config(&dir)
+    })
+    .unwrap();
+    write_lines(&log_file, 12);
+    let archived: u64 = log_file.archives().iter().map(|(_, bytes)| bytes).sum();
+    let current = std::fs::metadata(log_file.path()).unwrap().len();
+    assert!(archived + current <= 200, "{} + {}", archived, current);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn rotate_on_request() {
+    let dir = temp_dir("request");
+    let log_file = LogFile::open(&config(&dir)).unwrap();
+    // 비어 있으면 회전하지 않음
+    let rotation = log_file.rotate().unwrap();
+    assert_eq!(rotation.rotated, None);
+    assert_eq!(rotation.files, 1);
+
+    write_lines(&log_file, 1);
+    let rotation = log_file.rotate().unwrap();
+    assert!(rotation.rotated.is_some());
+    assert_eq!(rotation.files, 2);
+    assert_eq!(rotation.total_bytes, 40);
+    assert_eq!(std::fs::metadata(log_file.path()).unwrap().len(), 0);
+
+    // 다시 열면 기존 보관 파일에도 제한 적용
+    for _ in 0..4 {
+        write_lines(&log_file, 1);
+        log_file.rotate().unwrap();
+    }
+    drop(log_file);
+    let reopened = LogFile::open(&LogConfig {
+        max_files: 2,
+        ..config(&dir)
+    })
+    .unwrap();
+    assert_eq!(reopened.archives().len(), 2);
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,84 @@
+//! 물체 추적 및 통과 횟수 집계 테스트
+//!
+//! 포인트 묶음이 물체로 추적되고, 통과선을 지난 방향과 크기 구분별로 한 번만 세어지는지 확인합니다.
+
+use lidar_server::lidar::{LiDARKey, Point, PointCloud};
+use lidar_server::processing::{
+    cluster, crossing, CountStore, CountingConfig, ObjectTracker, TrackingConfig, Tripwire,
+};
+use std::net::Ipv4Addr;
+
+fn key() -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, 200), 5000, 1, 0)
+}
+
+/// 중심이 `(x, y)` 인 0.4 m 크기 포인트 묶음
+fn blob(x: f32, y: f32) -> Vec<PointCloud> {
+    let mut cloud = PointCloud::new();
+    for i in 0..5 {
+        for j in 0..5 {
+            cloud.add_point(Point {
+                x: x - 0.2 + i as f32 * 0.1,
+                y: y - 0.2 + j as f32 * 0.1,
+                z: 1.0,
+            });
+        }
+    }
+    vec![cloud]
+}
+
+#[test]
+fn separate_blobs_become_separate_clusters() {
+    let mut points = blob(0.0, 0.0);
+    points.extend(blob(5.0, 5.0));
+    let mut clusters = cluster(&points, &TrackingConfig::default());
+    clusters.sort_by(|a, b| a.center[0].total_cmp(&b.center[0]));
+
+    assert_eq!(clusters.len(), 2);
+    assert!(clusters[0].center[0].abs() < 0.01);
+    assert!((clusters[1].center[0] - 5.0).abs() < 0.01);
+    assert_eq!(clusters[0].points, 25);
+}
+
+#[test]
+fn crossing_reports_direction_within_segment() {
+    let (from, to) = ([0.0, -1.0], [0.0, 1.0]);
+    // from → to (+y) 를 바라볼 때 왼쪽은 -x
+    assert_eq!(crossing(from, to, [-0.5, 0.0], [0.5, 0.0]), Some(true));
+    assert_eq!(crossing(from, to, [0.5, 0.0], [-0.5, 0.0]), Some(false));
+    assert_eq!(crossing(from, to, [-0.5, 2.0], [0.5, 2.0]), None);
+    assert_eq!(crossing(from, to, [0.5, 0.0], [0.6, 0.0]), None);
+}
+
+#[tokio::test]
+async fn tracked_object_is_counted_once_per_tripwire() {
+    let tracker = ObjectTracker::new(TrackingConfig {
+        min_hits: 2,
+        ..Default::default()
+    });
+    let counts = CountStore::start(&CountingConfig {
+        tripwires: vec![Tripwire {
+            name: "gate".to_string(),
+            lidar: None,
+            from: [0.0, -5.0],
+            to: [0.0, 5.0],
+        }],
+        ..Default::default()
+    })
+    .unwrap();
+
+    // 0.5 m 씩 +x 방향으로 이동하며 통과선을 지난 뒤 되돌아와 다시 지남
+    for (frame, x) in [-1.5f32, -1.0, -0.5, 0.5, 1.0, 0.5, -0.5].iter().enumerate() {
+        let steps = tracker
+            .observe(key(), 1000 + frame as u64 * 100, &blob(*x, 0.0))
+            .await;
+        counts.observe(&steps).await;
+    }
+
+    let rollup = counts.summary().await;
+    assert_eq!(rollup.counters.len(), 1);
+    let totals = &rollup.counters[0];
+    assert_eq!(totals.counter, "gate");
+    assert_eq!(totals.forward.get("person"), Some(&1));
+    assert!(totals.backward.is_empty());
+}
@@ -0,0 +1,119 @@
+//! 점-점 ICP 정합 테스트
+//!
+//! 알려진 변환으로 옮긴 포인트 집합을 정합하여 변환과 정합 지표가 복원되는지 확인합니다.
+
+use lidar_server::lidar::Point;
+use lidar_server::processing::{align, IcpParams, Pose};
+
+/// 바닥과 두 벽으로 이루어진 모서리 (세 방향 모두 구속되는 장면)
+fn corner() -> Vec<Point> {
+    let mut points = Vec::new();
+    for i in 0..20 {
+        for j in 0..20 {
+            let (a, b) = (i as f32 * 0.25, j as f32 * 0.25);
+            points.push(Point { x: a, y: b, z: 0.0 });
+            points.push(Point { x: a, y: 0.0, z: b });
+            points.push(Point { x: 0.0, y: a, z: b });
+        }
+    }
+    points
+}
+
+fn params() -> IcpParams {
+    IcpParams {
+        max_iterations: 100,
+        max_distance: 1.0,
+        tolerance: 1e-6,
+    }
+}
+
+#[test]
+fn recovers_known_transform() {
+    // z 축으로 5° 회전하고 (0.2, -0.1, 0.05) 만큼 이동
+    let angle = 5.0f32.to_radians() / 2.0;
+    let expected = Pose {
+        position: [0.2, -0.1, 0.05],
+        orientation: [0.0, 0.0, angle.sin(), angle.cos()],
+    };
+    let target = corner();
+    // source 는 target 을 역변환한 포인트 (source 를 expected 로 옮기면 target)
+    let inverse = Pose {
+        position: [0.0; 3],
+        orientation: [0.0, 0.0, -angle.sin(), angle.cos()],
+    };
+    let source: Vec<Point> = target
+        .iter()
+        .map(|point| {
+            inverse.apply(&Point {
+                x: point.x - expected.position[0],
+                y: point.y - expected.position[1],
+                z: point.z - expected.position[2],
+            })
+        })
+        .collect();
+
+    let alignment = align(&source, &target, &Pose::IDENTITY, &params());
+
+    assert!(alignment.converged);
+    assert!(alignment.fitness > 0.99, "{}", alignment.fitness);
+    assert!(alignment.rmse < 0.01, "{}", alignment.rmse);
+    for (actual, expected) in alignment.transform.position.iter().zip(expected.position) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "{:?}",
+            alignment.transform
+        );
+    }
+    let dot: f32 = alignment
+        .transform
+        .orientation
+        .iter()
+        .zip(expected.orientation)
+        .map(|(a, b)| a * b)
+        .sum();
+    assert!(dot.abs() > 0.9999, "{:?}", alignment.transform);
+}
+
+#[test]
+fn no_overlap_gives_zero_fitness() {
+    let target = corner();
+    let source: Vec<Point> = target
+        .iter()
+        .map(|point| Point {
+            x: point.x + 100.0,
+            y: point.y,
+            z: point.z,
+        })
+        .collect();
+
+    let alignment = align(&source, &target, &Pose::IDENTITY, &params());
+
+    assert!(!alignment.converged);
+    assert_eq!(alignment.iterations, 0);
+    assert_eq!(alignment.fitness, 0.0);
+}
+
+#[test]
+fn compose_applies_other_pose_first() {
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    let yaw = Pose {
+        position: [1.0, 0.0, 0.0],
+        orientation: [0.0, 0.0, half, half],
+    };
+    let shift = Pose {
+        position: [1.0, 0.0, 0.0],
+        orientation: [0.0, 0.0, 0.0, 1.0],
+    };
+    let point = Point {
+        x: 1.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    let composed = yaw.compose(&shift).apply(&point);
+    let sequential = yaw.apply(&shift.apply(&point));
+
+    assert!((composed.x - sequential.x).abs() < 1e-5);
+    assert!((composed.y - sequential.y).abs() < 1e-5);
+    assert!((composed.x - 1.0).abs() < 1e-5 && (composed.y - 2.0).abs() < 1e-5);
+}
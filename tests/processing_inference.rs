@@ -0,0 +1,44 @@
+//! 엣지 추론 입력/출력 변환 테스트
+//!
+//! 포인트 클라우드가 채널 × 수평 각도 거리 영상으로 바뀌는지, 첫 번째 출력에서 분류 이름을 고르는지 확인합니다.
+
+use lidar_server::lidar::{Point, PointCloud};
+use lidar_server::processing::inference::{classify, range_image};
+use lidar_server::processing::InferenceOutput;
+
+#[test]
+fn range_image_keeps_nearest_point_per_cell() {
+    let cloud = |points: Vec<(f32, f32)>| PointCloud {
+        points: points
+            .into_iter()
+            .map(|(x, y)| Point { x, y, z: 0.0 })
+            .collect(),
+    };
+    let points = vec![
+        // 0° 방향 (열 2), 가까운 포인트 유지
+        cloud(vec![(10.0, 0.0), (5.0, 0.0)]),
+        // 90° 방향 (열 3), 정규화 거리보다 멀면 1
+        cloud(vec![(0.0, 500.0)]),
+        // 행 수를 넘는 채널은 무시
+        cloud(vec![(1.0, 0.0)]),
+    ];
+
+    let image = range_image(&points, 2, 4, 100.0);
+    assert_eq!(image, vec![0.0, 0.0, 0.05, 0.0, 0.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn classify_picks_highest_label() {
+    let labels = vec!["car".to_string(), "person".to_string()];
+    let outputs = vec![InferenceOutput {
+        shape: vec![1, 2],
+        values: vec![0.2, 0.7],
+    }];
+
+    assert_eq!(
+        classify(&outputs, &labels),
+        Some(("person".to_string(), 0.7))
+    );
+    assert_eq!(classify(&outputs, &labels[..1]), None);
+    assert_eq!(classify(&[], &labels), None);
+}
@@ -0,0 +1,133 @@
+//! 프레임 정렬 버퍼 테스트
+//!
+//! 순서가 바뀌어 도착한 프레임을 `max_delay_ms` 가 지난 뒤 시각 순으로 전달하고, 이미 전달한 프레임보다
+//! 이른 프레임은 버려 텔레메트리의 `late_drops` 로 집계되며, `next_release_ms` 가 가장 이른 대기 프레임을
+//! 따라가는지 확인합니다.
+
+use lidar_server::common::data::LiDARPacket;
+use lidar_server::lidar::LiDARKey;
+use lidar_server::logging::TraceId;
+use lidar_server::processing::{ClockSkew, Reorder, ReorderBuffer, ReorderConfig};
+use lidar_server::telemetry::{TelemetryConfig, TelemetryStore};
+use std::net::Ipv4Addr;
+
+fn key(last: u8) -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, last), 5000, 1, 0)
+}
+
+/// 수신 시각과 구분용 payload 를 가진 프레임
+fn packet(key: LiDARKey, time_ms: u64, tag: u8) -> LiDARPacket {
+    LiDARPacket {
+        key,
+        payload: vec![tag],
+        replay: false,
+        time_ms,
+        trace_id: TraceId::default(),
+    }
+}
+
+fn buffer(skew: Vec<ClockSkew>) -> ReorderBuffer {
+    ReorderBuffer::new(&ReorderConfig {
+        enabled: true,
+        max_delay_ms: 50,
+        max_pending: 1024,
+        skew,
+    })
+}
+
+fn tags(packets: Vec<LiDARPacket>) -> Vec<u8> {
+    packets
+        .into_iter()
+        .map(|packet| packet.payload[0])
+        .collect()
+}
+
+#[test]
+fn out_of_order_frames_are_released_in_order() {
+    let mut reorder = buffer(Vec::new());
+    assert_eq!(
+        reorder.push(packet(key(200), 1030, 3)),
+        Reorder::Buffered { depth: 0 }
+    );
+    assert_eq!(
+        reorder.push(packet(key(201), 1010, 1)),
+        Reorder::Buffered { depth: 1 }
+    );
+    assert_eq!(
+        reorder.push(packet(key(200), 1020, 2)),
+        Reorder::Buffered { depth: 1 }
+    );
+
+    // 가장 이른 프레임 시각 + max_delay_ms 전에는 전달하지 않음
+    assert!(reorder.release(1059).is_empty());
+    assert_eq!(tags(reorder.release(1070)), vec![1, 2]);
+    assert_eq!(tags(reorder.release(1200)), vec![3]);
+    assert!(reorder.release(2000).is_empty());
+}
+
+#[test]
+fn clock_skew_is_applied_before_ordering() {
+    // 201 은 수신 시각이 30 ms 늦게 찍히는 LiDAR
+    let mut reorder = buffer(vec![ClockSkew {
+        lidar: key(201),
+        offset_ms: 30,
+    }]);
+    reorder.push(packet(key(200), 1010, 2));
+    reorder.push(packet(key(201), 1030, 1));
+    assert_eq!(tags(reorder.release(1100)), vec![1, 2]);
+}
+
+#[tokio::test]
+async fn late_frames_are_dropped_and_counted() {
+    let telemetry = TelemetryStore::new(TelemetryConfig::default());
+    let mut reorder = buffer(Vec::new());
+    reorder.push(packet(key(200), 1000, 1));
+    reorder.push(packet(key(200), 1020, 2));
+    assert_eq!(tags(reorder.release(1050)), vec![1]);
+
+    // 이미 전달한 1000 보다 이른 프레임은 버리고, 아직 전달하지 않은 1020 보다 이른 프레임은 정렬
+    let late = reorder.push(packet(key(200), 990, 9));
+    assert_eq!(late, Reorder::Late);
+    telemetry.observe_reorder(key(200), late).await;
+    let on_time = reorder.push(packet(key(200), 1010, 3));
+    assert_eq!(on_time, Reorder::Buffered { depth: 1 });
+    telemetry.observe_reorder(key(200), on_time).await;
+
+    assert_eq!(tags(reorder.release(1100)), vec![3, 2]);
+    let samples = telemetry.sample().await;
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].late_drops, 1);
+    assert_eq!(samples[0].reorder_depth, 1);
+}
+
+#[test]
+fn next_release_follows_the_earliest_frame() {
+    let mut reorder = buffer(Vec::new());
+    assert_eq!(reorder.next_release_ms(), None);
+
+    reorder.push(packet(key(200), 1030, 2));
+    assert_eq!(reorder.next_release_ms(), Some(1080));
+    reorder.push(packet(key(201), 1010, 1));
+    assert_eq!(reorder.next_release_ms(), Some(1060));
+
+    assert_eq!(tags(reorder.release(1060)), vec![1]);
+    assert_eq!(reorder.next_release_ms(), Some(1080));
+    assert_eq!(tags(reorder.release(1080)), vec![2]);
+    assert_eq!(reorder.next_release_ms(), None);
+}
+
+#[test]
+fn pending_limit_releases_early() {
+    let mut reorder = ReorderBuffer::new(&ReorderConfig {
+        enabled: true,
+        max_delay_ms: 50,
+        max_pending: 2,
+        skew: Vec::new(),
+    });
+    for (time_ms, tag) in [(1030, 3), (1010, 1), (1020, 2)] {
+        reorder.push(packet(key(200), time_ms, tag));
+    }
+    // 대기 중인 프레임이 max_pending 을 넘으면 기다리지 않고 가장 이른 프레임부터 전달
+    assert_eq!(tags(reorder.release(1000)), vec![1]);
+    assert_eq!(reorder.next_release_ms(), Some(1070));
+}
@@ -0,0 +1,118 @@
+//! 물체 속도 및 과속 알림 테스트
+//!
+//! 추적한 물체의 속력과 진행 방향이 설치 자세에 따라 월드 좌표로 계산되고,
+//! 과속 규칙이 크기 조건에 맞는 물체에만 알림을 발생시키는지 확인합니다.
+
+use lidar_server::alert::{AlertConfig, AlertEngine, AlertState};
+use lidar_server::lidar::{LiDARKey, Point, PointCloud};
+use lidar_server::processing::tracker::heading;
+use lidar_server::processing::{Extrinsic, ObjectTracker, Pose, TrackedObjects, TrackingConfig};
+use lidar_server::ws::client::OutputFrame;
+use serde_json::json;
+use std::net::Ipv4Addr;
+
+fn key() -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, 200), 5000, 1, 0)
+}
+
+/// 중심이 `(x, 0)` 인 0.4 m 크기 포인트 묶음
+fn blob(x: f32) -> Vec<PointCloud> {
+    let mut cloud = PointCloud::new();
+    for i in 0..5 {
+        for j in 0..5 {
+            cloud.add_point(Point {
+                x: x - 0.2 + i as f32 * 0.1,
+                y: -0.2 + j as f32 * 0.1,
+                z: 1.0,
+            });
+        }
+    }
+    vec![cloud]
+}
+
+/// +x 방향으로 10 m/s 로 움직이는 물체를 추적한 마지막 프레임의 물체 목록
+async fn track(config: TrackingConfig) -> TrackedObjects {
+    let tracker = ObjectTracker::new(TrackingConfig {
+        min_hits: 2,
+        ..config
+    });
+    let mut objects = None;
+    for frame in 0..4u64 {
+        let time_ms = 1000 + frame * 100;
+        let steps = tracker.observe(key(), time_ms, &blob(frame as f32)).await;
+        objects = Some(tracker.objects(key(), time_ms, &steps));
+    }
+    objects.unwrap()
+}
+
+#[tokio::test]
+async fn speed_and_heading_in_lidar_frame() {
+    let objects = track(TrackingConfig::default()).await;
+
+    assert_eq!(objects.frame, OutputFrame::Lidar);
+    assert_eq!(objects.objects.len(), 1);
+    let object = &objects.objects[0];
+    assert!((object.speed - 10.0).abs() < 0.01, "{}", object.speed);
+    assert!(object.heading.abs() < 0.1, "{}", object.heading);
+}
+
+#[test]
+fn heading_stays_below_360() {
+    // +x 축에서 시계 방향으로 아주 조금 기운 방향은 360° 로 반올림되지 않고 0°
+    let tiny = heading([10.0, -1e-7]);
+    assert!((0.0..360.0).contains(&tiny), "{}", tiny);
+    assert!(tiny.abs() < 0.1, "{}", tiny);
+    assert_eq!(heading([0.0, 0.0]), 0.0);
+    assert!((heading([0.0, 1.0]) - 90.0).abs() < 1e-4);
+    assert!((heading([0.0, -1.0]) - 270.0).abs() < 1e-4);
+}
+
+#[tokio::test]
+async fn extrinsic_rotates_heading_into_world_frame() {
+    // z 축으로 90° 회전해 설치한 LiDAR 의 +x 는 월드 +y
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    let objects = track(TrackingConfig {
+        extrinsics: vec![Extrinsic {
+            lidar: key(),
+            pose: Pose {
+                position: [100.0, 0.0, 5.0],
+                orientation: [0.0, 0.0, half, half],
+            },
+        }],
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(objects.frame, OutputFrame::World);
+    let object = &objects.objects[0];
+    assert!((object.speed - 10.0).abs() < 0.01, "{}", object.speed);
+    assert!((object.heading - 90.0).abs() < 0.1, "{}", object.heading);
+    assert!((object.position[0] - 100.0).abs() < 0.01);
+    assert!((object.position[1] - 3.0).abs() < 0.01);
+}
+
+#[tokio::test]
+async fn over_speed_rule_respects_min_size() {
+    let objects = track(TrackingConfig::default()).await;
+    let engine = |min_size: f32| {
+        let config: AlertConfig = serde_json::from_value(json!({
+            "enabled": true,
+            "rules": [{
+                "name": "speeding",
+                "type": "over_speed",
+                "max_speed": 8.0,
+                "min_size": min_size
+            }]
+        }))
+        .unwrap();
+        AlertEngine::new(config)
+    };
+
+    let alerts = engine(0.1).observe_objects(&objects).await;
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].kind, "over_speed");
+    assert_eq!(alerts[0].state, AlertState::Firing);
+
+    // 0.4 m 물체는 차량 크기 조건에 맞지 않음
+    assert!(engine(2.0).observe_objects(&objects).await.is_empty());
+}
@@ -0,0 +1,182 @@
+//! 정적 TF 트리 테스트
+//!
+//! 설치 자세를 사이트 이름이 붙은 TF 트리로 내보내고, 부모 좌표계를 이어 붙여
+//! 다시 월드 좌표 설치 자세로 가져오는지 확인합니다.
+
+use lidar_server::lidar::LiDARKey;
+use lidar_server::processing::{
+    Extrinsic, Pose, TfFormat, TfFrame, TfTree, TrackingConfig, WORLD_FRAME,
+};
+use lidar_server::site::{SiteConfig, SiteDefinition};
+use std::net::Ipv4Addr;
+
+fn key(last: u8) -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, last), 5000, 1, 0)
+}
+
+fn sites() -> SiteConfig {
+    SiteConfig {
+        sites: vec![SiteDefinition {
+            name: "building-a".to_string(),
+            lidars: vec![key(200), key(201)],
+            quorum: None,
+            offline_ms: 5000,
+        }],
+        ..Default::default()
+    }
+}
+
+fn pose(position: [f32; 3]) -> Pose {
+    Pose {
+        position,
+        orientation: [0.0, 0.0, 0.0, 1.0],
+    }
+}
+
+#[test]
+fn export_names_frames_by_site() {
+    let extrinsics = vec![
+        Extrinsic {
+            lidar: key(201),
+            pose: pose([1.0, 2.0, 3.0]),
+        },
+        Extrinsic {
+            lidar: key(50),
+            pose: pose([0.0, 0.0, 1.0]),
+        },
+    ];
+
+    let tree = TfTree::from_extrinsics(&extrinsics, &sites());
+
+    assert_eq!(tree.frames[0].name, "building-a/lidar_1");
+    assert_eq!(tree.frames[1].name, "lidar_192.168.123.50_5000_1_0");
+    assert!(tree.frames.iter().all(|frame| frame.parent == WORLD_FRAME));
+
+    let text = tree.encode(TfFormat::Json).unwrap();
+    let resolved = TfTree::decode(&text, TfFormat::Json)
+        .unwrap()
+        .resolve(&sites())
+        .unwrap();
+    assert_eq!(resolved.len(), 2);
+    assert_eq!(resolved[0].lidar, key(201));
+    assert_eq!(resolved[0].pose.position, [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn resolve_composes_parent_frames() {
+    // 사이트 좌표계를 z 축으로 90° 회전하고 (10, 0, 0) 에 둠
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    let tree = TfTree {
+        frames: vec![
+            TfFrame {
+                name: "building-a/lidar_0".to_string(),
+                parent: "building-a".to_string(),
+                lidar: None,
+                pose: pose([1.0, 0.0, 5.0]),
+            },
+            TfFrame {
+                name: "building-a".to_string(),
+                parent: WORLD_FRAME.to_string(),
+                lidar: None,
+                pose: Pose {
+                    position: [10.0, 0.0, 0.0],
+                    orientation: [0.0, 0.0, half, half],
+                },
+            },
+        ],
+    };
+
+    let resolved = tree.resolve(&sites()).unwrap();
+
+    // 사이트 좌표계는 LiDAR 가 아니므로 LiDAR 좌표계만 반환
+    assert_eq!(resolved.len(), 1);
+    assert_eq!(resolved[0].lidar, key(200));
+    let position = resolved[0].pose.position;
+    assert!((position[0] - 10.0).abs() < 1e-5, "{:?}", position);
+    assert!((position[1] - 1.0).abs() < 1e-5, "{:?}", position);
+    assert!((position[2] - 5.0).abs() < 1e-5, "{:?}", position);
+}
+
+#[test]
+fn resolve_rejects_cycles_and_unknown_parents() {
+    let frame = |name: &str, parent: &str| TfFrame {
+        name: name.to_string(),
+        parent: parent.to_string(),
+        lidar: None,
+        pose: pose([0.0; 3]),
+    };
+
+    let cycle = TfTree {
+        frames: vec![frame("a", "b"), frame("b", "a")],
+    };
+    assert!(cycle.resolve(&sites()).unwrap_err().contains("cyclic"));
+
+    let orphan = TfTree {
+        frames: vec![frame("a", "missing")],
+    };
+    assert!(orphan
+        .resolve(&sites())
+        .unwrap_err()
+        .contains("unknown parent"));
+}
+
+#[test]
+fn import_overrides_configured_extrinsics() {
+    let path = std::env::temp_dir().join(format!("lidar-tf-{}.json", std::process::id()));
+    let tree = TfTree::from_extrinsics(
+        &[Extrinsic {
+            lidar: key(200),
+            pose: pose([5.0, 5.0, 5.0]),
+        }],
+        &sites(),
+    );
+    std::fs::write(&path, tree.encode(TfFormat::Json).unwrap()).unwrap();
+
+    let mut config = TrackingConfig {
+        extrinsics: vec![
+            Extrinsic {
+                lidar: key(200),
+                pose: pose([0.0; 3]),
+            },
+            Extrinsic {
+                lidar: key(201),
+                pose: pose([1.0, 0.0, 0.0]),
+            },
+        ],
+        tf_tree: Some(path.clone()),
+        ..Default::default()
+    };
+    let result = config.import_tf_tree(&sites());
+    std::fs::remove_file(&path).ok();
+    result.unwrap();
+
+    assert_eq!(config.extrinsics.len(), 2);
+    assert_eq!(
+        config.extrinsic(&key(200)).unwrap().position,
+        [5.0, 5.0, 5.0]
+    );
+    assert_eq!(
+        config.extrinsic(&key(201)).unwrap().position,
+        [1.0, 0.0, 0.0]
+    );
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_round_trip() {
+    let tree = TfTree::from_extrinsics(
+        &[Extrinsic {
+            lidar: key(200),
+            pose: pose([1.0, 2.0, 3.0]),
+        }],
+        &sites(),
+    );
+
+    let text = tree.encode(TfFormat::Yaml).unwrap();
+    assert!(text.contains("building-a/lidar_0"));
+    let resolved = TfTree::decode(&text, TfFormat::Yaml)
+        .unwrap()
+        .resolve(&sites())
+        .unwrap();
+    assert_eq!(resolved[0].pose.position, [1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,57 @@
+//! 복셀 누적 테스트
+//!
+//! CPU 복셀 좌표 계산이 묶음 크기와 관계없이 포인트 순서대로 좌표를 채우는지,
+//! 여러 LiDAR 의 포인트가 같은 복셀에서 평균 위치 하나로 합쳐지는지 확인합니다.
+
+use lidar_server::lidar::{Point, PointCloud};
+use lidar_server::processing::{voxel_keys, VoxelBackend, VoxelConfig, Voxelizer};
+
+fn point(x: f32, y: f32, z: f32) -> Point {
+    Point { x, y, z }
+}
+
+#[test]
+fn keys_follow_point_order() {
+    // 묶음 크기(8)를 넘는 포인트 수로 나머지 처리까지 확인
+    let points: Vec<Point> = (0..11)
+        .map(|i| point(i as f32 * 0.25, -0.05, 0.0))
+        .collect();
+    let mut keys = vec![[9, 9, 9]];
+
+    voxel_keys(&points, 0.5, &mut keys);
+    assert_eq!(keys.len(), 11);
+    assert_eq!(keys[0], [0, -1, 0]);
+    assert_eq!(keys[3], [1, -1, 0]);
+    assert_eq!(keys[10], [5, -1, 0]);
+}
+
+#[tokio::test]
+async fn fused_points_share_voxel() {
+    let config = VoxelConfig {
+        enabled: true,
+        size: 1.0,
+        // GPU 가 없거나 feature 가 꺼져 있으면 CPU 로 계산
+        backend: VoxelBackend::Gpu,
+        gpu_min_points: 1,
+    };
+    let voxelizer = Voxelizer::new(&config).await;
+    let first = PointCloud {
+        points: vec![point(2.2, 0.5, 0.5), point(0.0, 0.0, 0.0)],
+    };
+    let second = PointCloud {
+        points: vec![
+            point(2.4, 0.5, 0.5),
+            point(f32::NAN, 1.0, 1.0),
+            point(-3.5, 0.5, 0.5),
+        ],
+    };
+
+    let grid = voxelizer.voxelize([&first, &second]);
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.count(&[2, 0, 0]), 2);
+    assert_eq!(grid.count(&[-4, 0, 0]), 1);
+
+    let centroids = grid.centroids();
+    let merged = centroids.points.iter().find(|point| point.x > 0.0).unwrap();
+    assert!((merged.x - 2.3).abs() < 1e-5);
+}
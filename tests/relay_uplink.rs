@@ -0,0 +1,43 @@
+//! 클라우드 수집 서버 연결(uplink) 설정 테스트
+//!
+//! 재연결 대기 시간이 실패할 때마다 두 배로 늘고 최대값을 넘지 않는지, 전송량 예산을 넘으면
+//! 이벤트는 버리고 응답은 예산을 갚을 때까지 기다리는지 확인합니다.
+
+use lidar_server::relay::{RelayConfig, UplinkBudget, UplinkConfig};
+use std::time::{Duration, Instant};
+
+#[test]
+fn backoff_doubles_up_to_max() {
+    let config: RelayConfig = serde_json::from_str(
+        r#"{ "uplinks": [ { "url": "wss://cloud.example.com/sites/connect", "max_reconnect_ms": 5000 } ] }"#,
+    )
+    .unwrap();
+    let uplink: &UplinkConfig = &config.uplinks[0];
+    assert_eq!(uplink.reconnect_ms, 1000);
+
+    let backoff: Vec<u64> = (1..=5)
+        .map(|failures| uplink.backoff_ms(failures))
+        .collect();
+    assert_eq!(backoff, vec![1000, 2000, 4000, 5000, 5000]);
+    assert_eq!(uplink.backoff_ms(u32::MAX), 5000);
+}
+
+#[test]
+fn budget_drops_events_and_delays_replies() {
+    // 8 kbps = 1000 bytes/s, 처음에는 1초 분량을 모아 둔 상태
+    let mut budget = UplinkBudget::new(8);
+    let start = Instant::now();
+
+    assert!(budget.try_send(600, start));
+    assert!(!budget.try_send(600, start));
+    assert_eq!(budget.dropped(), 1);
+
+    // 응답은 버리지 않고 예산을 빌려 씀, 빌린 예산은 다음 응답 전에 갚음
+    assert_eq!(budget.reserve(900, start), Duration::ZERO);
+    let wait = budget.reserve(100, start);
+    assert_eq!(wait, Duration::from_millis(500));
+    assert!(!budget.try_send(1, start + Duration::from_millis(500)));
+
+    // 예산이 다시 채워지면 이벤트 전송 가능
+    assert!(budget.try_send(500, start + Duration::from_millis(1600)));
+}
@@ -0,0 +1,52 @@
+//! 부하 테스트(`bench`) 옵션과 결과 계산 테스트
+//!
+//! 명령행 인자 해석과 단계 구성, 손실 비율/지연 시간 백분위와 감당 가능 판단을 확인합니다.
+#![cfg(feature = "bench")]
+
+use lidar_server::sim::bench::{stage_report, BenchOptions};
+use std::time::Duration;
+
+#[test]
+fn options_are_parsed() {
+    let options = BenchOptions::parse(&[
+        "--sensors",
+        "10",
+        "--clients",
+        "3",
+        "--duration",
+        "40",
+        "--steps",
+        "4",
+    ])
+    .unwrap();
+    assert_eq!(options.sensors, 10);
+    assert_eq!(options.clients, 3);
+    assert_eq!(options.duration, Duration::from_secs(40));
+    assert_eq!(options.stages(), vec![(3, 1), (5, 2), (8, 3), (10, 3)]);
+
+    assert!(BenchOptions::parse(&["--sensors", "0"]).is_err());
+    assert!(BenchOptions::parse(&["--sensors"]).is_err());
+    assert!(BenchOptions::parse(&["--duration", "4", "--steps", "4"]).is_err());
+    assert!(BenchOptions::parse(&["--unknown", "1"]).is_err());
+}
+
+#[test]
+fn stage_limits() {
+    let options = BenchOptions::default();
+    let mut latencies: Vec<u64> = (1..=100).rev().map(|ms| ms * 1000).collect();
+    let stage = stage_report(4, 2, 1000, 1000, 2000, &mut latencies, &options);
+    assert_eq!(stage.ws_drop_ratio, 0.0);
+    assert_eq!(stage.p50_ms, 50.0);
+    assert_eq!(stage.p99_ms, 99.0);
+    assert_eq!(stage.max_ms, 100.0);
+    assert!(stage.sustainable);
+
+    // 클라이언트 두 개 중 하나가 절반만 받음
+    let stage = stage_report(4, 2, 1000, 990, 1500, &mut latencies, &options);
+    assert_eq!(stage.udp_drop_ratio, 0.01);
+    assert_eq!(stage.ws_drop_ratio, 0.25);
+    assert!(!stage.sustainable);
+
+    let mut slow = vec![250_000; 10];
+    assert!(!stage_report(1, 1, 10, 10, 10, &mut slow, &options).sustainable);
+}
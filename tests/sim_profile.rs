@@ -0,0 +1,138 @@
+//! 시뮬레이션 프로파일 테스트
+//!
+//! 프로파일로 만든 가상 스트림이 파서를 통과하고, 그 스트림에서 다시 추출한 프로파일이
+//! 원래 프로파일의 스캔 간격, 채널 순서, 포인트 수, 거리 분포와 비슷한지 확인합니다.
+
+use bincode::config::standard;
+use bincode::encode_to_vec;
+use lidar_server::lidar::kanavi_mobility::{KanaviMobilityData, KanaviMobilityParser};
+use lidar_server::lidar::{CompanyInfo, LiDARKey, LiDARParser};
+use lidar_server::record::format::{write_entry, write_header, RecordEntry};
+use lidar_server::sim::{
+    extract_profile, ChannelProfile, ProfileBuilder, SensorProfile, SyntheticSensor,
+};
+use std::net::Ipv4Addr;
+
+const LIDAR_IP: Ipv4Addr = Ipv4Addr::new(192, 168, 123, 200);
+
+fn profile() -> SensorProfile {
+    SensorProfile {
+        product_line: 3,
+        lidar_id: 1,
+        frames: 0,
+        frame_interval_ms: 40.0,
+        frame_jitter_ms: 2.0,
+        channels: [1u8, 0]
+            .into_iter()
+            .map(|channel| ChannelProfile {
+                channel,
+                points: 480,
+                mean_distance: 12.0 + channel as f64 * 4.0,
+                distance_stddev: 3.0,
+                no_return_ratio: 0.1,
+            })
+            .collect(),
+    }
+}
+
+/// 가상 LiDAR 가 만든 (수신 시각, 프레임) 목록
+fn synthesize(scans: usize) -> Vec<(u64, Vec<u8>)> {
+    let mut sensor = SyntheticSensor::new(profile(), 7);
+    let mut time_ms = 0.0;
+    let mut frames = Vec::new();
+    for _ in 0..scans {
+        for frame in sensor.next_scan() {
+            frames.push((time_ms as u64, frame));
+        }
+        time_ms += sensor.next_interval().as_secs_f64() * 1000.0;
+    }
+    frames
+}
+
+fn assert_similar(actual: &SensorProfile, expected: &SensorProfile) {
+    assert_eq!(actual.product_line, expected.product_line);
+    assert_eq!(actual.lidar_id, expected.lidar_id);
+    assert!((actual.frame_interval_ms - expected.frame_interval_ms).abs() < 1.0);
+    assert!((actual.frame_jitter_ms - expected.frame_jitter_ms).abs() < 1.0);
+    let order: Vec<u8> = actual.channels.iter().map(|c| c.channel).collect();
+    assert_eq!(order, vec![1, 0]);
+    for (a, e) in actual.channels.iter().zip(&expected.channels) {
+        assert_eq!(a.points, e.points);
+        assert!((a.mean_distance - e.mean_distance).abs() < 0.5, "{:?}", a);
+        assert!(
+            (a.distance_stddev - e.distance_stddev).abs() < 0.5,
+            "{:?}",
+            a
+        );
+        assert!(
+            (a.no_return_ratio - e.no_return_ratio).abs() < 0.02,
+            "{:?}",
+            a
+        );
+    }
+}
+
+#[test]
+fn synthesized_frames_parse() {
+    let mut parser = KanaviMobilityParser::new();
+    for (_, frame) in synthesize(3) {
+        assert_eq!(frame.len(), 8 + 480 * 2);
+        let data = parser.parse(LIDAR_IP, &frame).frame().expect("valid frame");
+        let data = data
+            .as_any()
+            .downcast_ref::<KanaviMobilityData>()
+            .expect("Kanavi data");
+        assert_eq!(data.get_mode(), 0xDD);
+    }
+}
+
+#[test]
+fn same_seed_same_stream() {
+    assert_eq!(synthesize(2), synthesize(2));
+}
+
+#[test]
+fn profile_round_trip() {
+    let key = LiDARKey::new(LIDAR_IP, 5000, 3, 1);
+    let mut builder = ProfileBuilder::default();
+    // 설정 응답 등 포인트 클라우드가 아닌 프레임은 무시
+    builder.observe(key, 0, &[0xFA, 0x03, 0x01, 0xF0, 0x00, 0x00, 0x00, 0x09]);
+    for (time_ms, frame) in synthesize(500) {
+        builder.observe(key, time_ms, &frame);
+    }
+    let sensors = builder.finish();
+    assert_eq!(sensors.len(), 1);
+    assert_eq!(sensors[0].frames, 500);
+    assert_similar(&sensors[0], &profile());
+}
+
+#[test]
+fn profile_from_recording() {
+    let path = std::env::temp_dir().join(format!("lidar-sim-{}.lrec", std::process::id()));
+    let key = LiDARKey::new(LIDAR_IP, 5000, 3, 1);
+    let mut file = std::fs::File::create(&path).unwrap();
+    write_header(&mut file).unwrap();
+    let mut parser = KanaviMobilityParser::new();
+    for (time_ms, frame) in synthesize(300) {
+        let data = parser.parse(LIDAR_IP, &frame).frame().unwrap();
+        let data = data.as_any().downcast_ref::<KanaviMobilityData>().unwrap();
+        let mut payload = vec![CompanyInfo::KanaviMobility as u8];
+        payload.extend(encode_to_vec(data, standard()).unwrap());
+        write_entry(
+            &mut file,
+            &RecordEntry {
+                time_ms: 1_700_000_000_000 + time_ms,
+                key,
+                payload,
+            },
+        )
+        .unwrap();
+    }
+    drop(file);
+
+    let profile = extract_profile(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(profile.sensors.len(), 1);
+    assert!(profile.duration_ms > 11_000);
+    assert_similar(&profile.sensors[0], &self::profile());
+}
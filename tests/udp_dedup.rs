@@ -0,0 +1,101 @@
+//! 중복 데이터그램 제거 테스트
+//!
+//! 같은 LiDAR 의 같은 데이터그램은 `window_ms` 안에서만 중복으로 버리고, LiDAR 키가 다르면
+//! 서로 영향을 주지 않으며, LiDAR 별 보관 개수(`max_entries`)와 오래된 LiDAR 정리가 맞는지 확인합니다.
+
+use lidar_server::lidar::LiDARKey;
+use lidar_server::udp::config::DedupConfig;
+use lidar_server::udp::dedup::Deduplicator;
+use std::net::Ipv4Addr;
+use std::time::Duration;
+
+fn key(last: u8, lidar_id: u8) -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, last), 5000, 1, lidar_id)
+}
+
+fn dedup(window_ms: u64, max_entries: usize) -> Deduplicator {
+    Deduplicator::new(&DedupConfig {
+        enabled: true,
+        window_ms,
+        max_entries,
+    })
+}
+
+#[test]
+fn duplicate_within_window_is_dropped() {
+    let mut dedup = dedup(50, 8);
+    assert!(!dedup.is_duplicate(key(200, 0), b"frame-1"));
+    assert!(dedup.is_duplicate(key(200, 0), b"frame-1"));
+    // 다른 데이터그램은 중복이 아님
+    assert!(!dedup.is_duplicate(key(200, 0), b"frame-2"));
+
+    // 창이 지나면 같은 데이터그램도 새로 받음 (정지 화면처럼 같은 프레임이 반복되는 경우)
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(!dedup.is_duplicate(key(200, 0), b"frame-1"));
+    assert!(dedup.is_duplicate(key(200, 0), b"frame-1"));
+}
+
+#[test]
+fn lidar_keys_do_not_collide() {
+    let mut dedup = dedup(1000, 8);
+    assert!(!dedup.is_duplicate(key(200, 0), b"frame"));
+    // 같은 내용이라도 송신 주소나 LiDAR ID 가 다르면 다른 LiDAR 의 데이터
+    assert!(!dedup.is_duplicate(key(201, 0), b"frame"));
+    assert!(!dedup.is_duplicate(key(200, 1), b"frame"));
+
+    assert!(dedup.is_duplicate(key(200, 0), b"frame"));
+    assert!(dedup.is_duplicate(key(201, 0), b"frame"));
+    assert!(dedup.is_duplicate(key(200, 1), b"frame"));
+}
+
+#[test]
+fn entries_are_bounded_per_lidar() {
+    let mut dedup = dedup(60_000, 4);
+    for i in 0..4u8 {
+        assert!(!dedup.is_duplicate(key(200, 0), &[i]));
+    }
+    let full = dedup.memory_bytes();
+
+    // 다섯 번째 해시를 넣으면 가장 오래된 해시(0)를 잊음
+    assert!(!dedup.is_duplicate(key(200, 0), &[4]));
+    assert!(!dedup.is_duplicate(key(200, 0), &[0]));
+    for i in 2..5u8 {
+        assert!(dedup.is_duplicate(key(200, 0), &[i]));
+    }
+    for i in 5..100u8 {
+        dedup.is_duplicate(key(200, 0), &[i]);
+    }
+    assert_eq!(dedup.memory_bytes(), full);
+
+    // 0 이면 1 로 보고 직전 데이터그램만 비교
+    let mut single = Deduplicator::new(&DedupConfig {
+        enabled: true,
+        window_ms: 60_000,
+        max_entries: 0,
+    });
+    assert!(!single.is_duplicate(key(200, 0), b"a"));
+    assert!(single.is_duplicate(key(200, 0), b"a"));
+    assert!(!single.is_duplicate(key(200, 0), b"b"));
+    assert!(!single.is_duplicate(key(200, 0), b"a"));
+}
+
+#[test]
+fn evict_idle_counts_removed_lidars() {
+    let mut dedup = dedup(50, 8);
+    assert_eq!(dedup.evict_idle(), 0);
+    assert!(!dedup.is_duplicate(key(200, 0), b"frame"));
+    assert!(!dedup.is_duplicate(key(201, 0), b"frame"));
+    assert_eq!(dedup.evict_idle(), 0);
+
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(!dedup.is_duplicate(key(202, 0), b"frame"));
+    let three = dedup.memory_bytes();
+    assert_eq!(dedup.evict_idle(), 2);
+    // LiDAR 마다 해시 하나씩이므로 남은 기록은 1/3
+    assert_eq!(dedup.memory_bytes() * 3, three);
+    assert_eq!(dedup.evict_idle(), 0);
+
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(dedup.evict_idle(), 1);
+    assert_eq!(dedup.memory_bytes(), 0);
+}
@@ -0,0 +1,117 @@
+//! 파싱 실패 송신 주소 격리 테스트
+//!
+//! 파싱 실패 비율이 허용량을 넘으면 격리하고 실패 데이터그램 샘플을 남기며,
+//! 대기 시간 후 다시 파싱하여 복구하거나 더 오래 격리하는지 확인합니다.
+
+use lidar_server::udp::breaker::{BreakerEvent, CircuitBreaker};
+use lidar_server::udp::config::ErrorBudgetConfig;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+fn addr(last: u8) -> SocketAddr {
+    SocketAddr::from((Ipv4Addr::new(192, 168, 123, last), 5000))
+}
+
+fn config() -> ErrorBudgetConfig {
+    ErrorBudgetConfig {
+        enabled: true,
+        min_frames: 10,
+        max_error_ratio: 0.5,
+        retry_ms: 20,
+        max_retry_ms: 50,
+        sample_frames: 2,
+        sample_bytes: 4,
+        ..ErrorBudgetConfig::default()
+    }
+}
+
+/// 실패 프레임만 보내서 격리될 때의 이벤트
+fn trip(breaker: &CircuitBreaker, sender: SocketAddr) -> BreakerEvent {
+    for frame in 0..100u8 {
+        assert!(breaker.allow(sender));
+        if let Some(event) = breaker.record(sender, false, &[0xFA, 0x03, 0x01, 0xDD, frame]) {
+            return event;
+        }
+    }
+    panic!("sender was not quarantined");
+}
+
+#[test]
+fn failing_sender_is_quarantined() {
+    let breaker = CircuitBreaker::new(&config());
+    // 허용량 안의 실패는 격리하지 않음
+    for frame in 0..20 {
+        assert_eq!(breaker.record(addr(1), frame % 3 != 0, &[0xFA]), None);
+    }
+
+    let BreakerEvent::Quarantined {
+        addr: sender,
+        error_ratio,
+        frames,
+        retry,
+        samples,
+    } = trip(&breaker, addr(2))
+    else {
+        panic!("expected quarantine");
+    };
+    assert_eq!(sender, addr(2));
+    assert_eq!(error_ratio, 1.0);
+    assert_eq!(frames, 10);
+    assert_eq!(retry, Duration::from_millis(20));
+    assert_eq!(
+        samples,
+        vec![vec![0xFA, 0x03, 0x01, 0xDD], vec![0xFA, 0x03, 0x01, 0xDD]]
+    );
+    assert!(breaker.is_quarantined(&addr(2)));
+    assert!(!breaker.allow(addr(2)));
+    // 다른 송신 주소는 영향 없음
+    assert!(breaker.allow(addr(1)));
+    assert!(!breaker.is_quarantined(&addr(1)));
+}
+
+#[test]
+fn quarantine_backs_off_and_recovers() {
+    let breaker = CircuitBreaker::new(&config());
+    trip(&breaker, addr(3));
+
+    // 다시 시도해도 실패하면 대기 시간을 두 배로 (최대 max_retry_ms)
+    std::thread::sleep(Duration::from_millis(30));
+    let BreakerEvent::Quarantined { retry, .. } = trip(&breaker, addr(3)) else {
+        panic!("expected quarantine");
+    };
+    assert_eq!(retry, Duration::from_millis(40));
+    std::thread::sleep(Duration::from_millis(50));
+    let BreakerEvent::Quarantined { retry, .. } = trip(&breaker, addr(3)) else {
+        panic!("expected quarantine");
+    };
+    assert_eq!(retry, Duration::from_millis(50));
+
+    // 대기 시간 후 정상 프레임을 min_frames 만큼 받으면 복구
+    std::thread::sleep(Duration::from_millis(60));
+    assert!(breaker.allow(addr(3)));
+    for _ in 0..9 {
+        assert_eq!(breaker.record(addr(3), true, &[]), None);
+    }
+    assert_eq!(
+        breaker.record(addr(3), true, &[]),
+        Some(BreakerEvent::Recovered { addr: addr(3) })
+    );
+    assert!(!breaker.is_quarantined(&addr(3)));
+
+    // 복구 후 다시 격리되면 처음 대기 시간부터
+    let BreakerEvent::Quarantined { retry, .. } = trip(&breaker, addr(3)) else {
+        panic!("expected quarantine");
+    };
+    assert_eq!(retry, Duration::from_millis(20));
+}
+
+#[test]
+fn idle_senders_are_evicted() {
+    let breaker = CircuitBreaker::new(&config());
+    breaker.record(addr(4), true, &[]);
+    trip(&breaker, addr(5));
+    std::thread::sleep(Duration::from_millis(10));
+    // 격리 중인 송신 주소는 유지
+    assert_eq!(breaker.evict_idle(Duration::from_millis(5)), 1);
+    assert!(breaker.is_quarantined(&addr(5)));
+}
@@ -0,0 +1,71 @@
+//! UDP 송신 주소 필터 테스트
+//!
+//! CIDR 주소 범위 파싱과 허용/차단 목록 적용, 거부 통계 기록을 확인합니다.
+
+use lidar_server::lidar::ParseStatsStore;
+use lidar_server::udp::config::SourceFilterConfig;
+use lidar_server::udp::filter::{Ipv4Net, Rejection, SourceFilter};
+use std::net::{Ipv4Addr, SocketAddr};
+
+fn addr(last: u8) -> SocketAddr {
+    SocketAddr::from((Ipv4Addr::new(192, 168, 123, last), 5000))
+}
+
+#[test]
+fn parse_cidr() {
+    let net: Ipv4Net = "192.168.123.77/24".parse().unwrap();
+    assert_eq!(net.to_string(), "192.168.123.0/24");
+    assert!(net.contains(Ipv4Addr::new(192, 168, 123, 200)));
+    assert!(!net.contains(Ipv4Addr::new(192, 168, 124, 1)));
+
+    let host: Ipv4Net = "10.0.0.1".parse().unwrap();
+    assert_eq!(host.to_string(), "10.0.0.1/32");
+    let any: Ipv4Net = "0.0.0.0/0".parse().unwrap();
+    assert!(any.contains(Ipv4Addr::new(8, 8, 8, 8)));
+
+    assert!("10.0.0.0/33".parse::<Ipv4Net>().is_err());
+    assert!("10.0.0/8".parse::<Ipv4Net>().is_err());
+    assert!(serde_json::from_str::<SourceFilterConfig>(r#"{ "allow": ["nope"] }"#).is_err());
+}
+
+#[test]
+fn deny_overrides_allow() {
+    let config: SourceFilterConfig =
+        serde_json::from_str(r#"{ "allow": ["192.168.123.0/24"], "deny": ["192.168.123.99"] }"#)
+            .unwrap();
+    let filter = SourceFilter::new(&config);
+
+    assert_eq!(filter.check(&addr(200)), None);
+    assert_eq!(filter.check(&addr(99)), Some(Rejection::Denied));
+    assert_eq!(
+        filter.check(&SocketAddr::from(([10, 0, 0, 1], 5000))),
+        Some(Rejection::NotAllowed)
+    );
+}
+
+#[test]
+fn empty_allow_list_accepts_everything_not_denied() {
+    let config = SourceFilterConfig {
+        deny: vec!["192.168.123.0/28".parse().unwrap()],
+        ..Default::default()
+    };
+    let filter = SourceFilter::new(&config);
+
+    assert_eq!(filter.check(&addr(5)), Some(Rejection::Denied));
+    assert_eq!(filter.check(&addr(200)), None);
+    assert!(!SourceFilterConfig::default().is_enabled());
+}
+
+#[test]
+fn rejections_are_counted_by_reason() {
+    let stats = ParseStatsStore::default();
+    assert_eq!(stats.rejected().last_rejected_ms, None);
+
+    stats.record_rejected(Rejection::Denied);
+    stats.record_rejected(Rejection::NotAllowed);
+    stats.record_rejected(Rejection::NotAllowed);
+
+    let rejected = stats.rejected();
+    assert_eq!((rejected.denied, rejected.not_allowed), (1, 2));
+    assert!(rejected.last_rejected_ms.is_some());
+}
@@ -0,0 +1,62 @@
+//! 네트워크 인터페이스 hot-plug 테스트
+//!
+//! 인터페이스가 나타나거나 사라질 때 멀티캐스트 가입/탈퇴와 인터페이스별 통계가 갱신되는지 확인합니다.
+
+use lidar_server::udp::socket::{multicast_interfaces, sync_multicast};
+use std::net::Ipv4Addr;
+use tokio::net::UdpSocket;
+
+const GROUP: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 5);
+
+async fn socket() -> UdpSocket {
+    UdpSocket::bind("0.0.0.0:0").await.unwrap()
+}
+
+#[tokio::test]
+async fn failed_join_is_retried_until_interface_removed() {
+    let socket = socket().await;
+    // 로컬 인터페이스가 아닌 주소 (TEST-NET-1)
+    let missing = Ipv4Addr::new(192, 0, 2, 1);
+    let mut joins = Vec::new();
+
+    assert!(sync_multicast(&socket, GROUP, &mut joins, &[missing]));
+    assert_eq!(joins.len(), 1);
+    assert!(!joins[0].joined && joins[0].present);
+    assert!(joins[0].error.is_some());
+    assert_eq!(joins[0].failures, 1);
+
+    // 계속 실패하면 재시도만 하고 변경으로 보지 않음
+    assert!(!sync_multicast(&socket, GROUP, &mut joins, &[missing]));
+    assert_eq!(joins[0].failures, 2);
+
+    assert!(sync_multicast(&socket, GROUP, &mut joins, &[]));
+    assert!(!joins[0].present);
+    assert_eq!(joins[0].leaves, 0);
+    assert!(!sync_multicast(&socket, GROUP, &mut joins, &[]));
+}
+
+#[tokio::test]
+async fn interface_rejoins_after_reappearing() {
+    let Some(&ip) = multicast_interfaces().first() else {
+        return;
+    };
+    let socket = socket().await;
+    let mut joins = Vec::new();
+
+    sync_multicast(&socket, GROUP, &mut joins, &[ip]);
+    if !joins[0].joined {
+        // 멀티캐스트를 지원하지 않는 환경
+        return;
+    }
+    assert_eq!(joins[0].joins, 1);
+    assert!(!sync_multicast(&socket, GROUP, &mut joins, &[ip]));
+
+    assert!(sync_multicast(&socket, GROUP, &mut joins, &[]));
+    assert!(!joins[0].joined && !joins[0].present);
+    assert_eq!(joins[0].leaves, 1);
+
+    assert!(sync_multicast(&socket, GROUP, &mut joins, &[ip]));
+    assert!(joins[0].joined && joins[0].present);
+    assert_eq!(joins[0].joins, 2);
+    assert_eq!(joins.len(), 1);
+}
@@ -0,0 +1,156 @@
+//! 송신 주소별 상태 정리 테스트
+//!
+//! 오래된 송신 주소의 파싱 통계와 중복 제거 기록, 파서를 삭제하고, 송신 주소별 파서 버퍼를 따로 보관하며
+//! 메모리 사용량을 송신 주소별/합계로 집계하는지 확인합니다.
+
+use lidar_server::lidar::kanavi_mobility::KanaviMobilityParser;
+use lidar_server::lidar::traits::{LiDARParser, ParseOutcome};
+use lidar_server::lidar::{CompanyInfo, LiDARKey, ParseError, ParseStatsStore};
+use lidar_server::udp::config::DedupConfig;
+use lidar_server::udp::dedup::Deduplicator;
+use lidar_server::udp::reassembly::SenderParsers;
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+fn addr(last: u8) -> SocketAddr {
+    SocketAddr::from((Ipv4Addr::new(192, 168, 123, last), 5000))
+}
+
+#[tokio::test]
+async fn idle_senders_are_evicted() {
+    let stats = ParseStatsStore::default();
+    stats.record_ok(addr(200)).await;
+    stats.record_error(addr(201), &ParseError::Checksum).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    stats.record_ok(addr(202)).await;
+
+    assert_eq!(stats.memory().await.senders, 3);
+    assert_eq!(stats.evict_stale(50).await, 2);
+
+    let list = stats.list().await;
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].ip, addr(202).ip());
+
+    let memory = stats.memory().await;
+    assert_eq!(memory.senders, 1);
+    assert_eq!(memory.evicted_senders, 2);
+    assert!(memory.parse_stats_bytes > 0);
+}
+
+#[tokio::test]
+async fn memory_totals_include_recorded_state() {
+    let stats = ParseStatsStore::default();
+    stats.record_ok(addr(200)).await;
+    stats.record_ok(addr(201)).await;
+    // 파싱 통계가 아직 없는 송신 주소의 버퍼도 합계에 포함
+    let buffers = HashMap::from([(addr(200), 24), (addr(202), 8)]);
+    stats.record_memory(1000, &buffers).await;
+    stats.record_buffer_reset(addr(201)).await;
+
+    let memory = stats.memory().await;
+    assert_eq!(memory.dedup_bytes, 1000);
+    assert_eq!(memory.parser_buffer_bytes, 32);
+    assert_eq!(
+        memory.total_bytes,
+        memory.parse_stats_bytes
+            + memory.dedup_bytes
+            + memory.parser_buffer_bytes
+            + memory.point_pool.pooled_bytes
+    );
+    assert_eq!(memory.buffer_resets, 1);
+
+    // 송신 주소별 버퍼 크기와 비운 횟수
+    let list = stats.list().await;
+    let per_sender: Vec<_> = list
+        .iter()
+        .map(|stats| (stats.port, stats.buffered_bytes, stats.buffer_resets))
+        .collect();
+    assert_eq!(list[0].ip, addr(200).ip());
+    assert_eq!(per_sender, vec![(5000, 24, 0), (5000, 0, 1)]);
+}
+
+#[test]
+fn dedup_forgets_idle_lidars() {
+    let mut dedup = Deduplicator::new(&DedupConfig {
+        enabled: true,
+        window_ms: 50,
+        max_entries: 8,
+    });
+    let key = LiDARKey::new(Ipv4Addr::new(192, 168, 123, 200), 5000, 1, 0);
+    assert!(!dedup.is_duplicate(key, b"frame"));
+    assert!(dedup.memory_bytes() > 0);
+
+    assert_eq!(dedup.evict_idle(), 0);
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(dedup.evict_idle(), 1);
+    assert_eq!(dedup.memory_bytes(), 0);
+}
+
+#[test]
+fn parser_buffer_can_be_reset() {
+    let mut parser = KanaviMobilityParser::new();
+    // 헤더는 맞지만 데이터 길이(0xFFFF)만큼 오지 않은 프레임 조각
+    let fragment = [0xFA, 0x01, 0x00, 0xDD, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00];
+    for _ in 0..2 {
+        assert!(matches!(
+            parser.parse(Ipv4Addr::LOCALHOST, &fragment),
+            ParseOutcome::NeedMoreData
+        ));
+    }
+    assert_eq!(parser.buffered_len(), fragment.len() * 2);
+
+    parser.reset();
+    assert_eq!(parser.buffered_len(), 0);
+}
+
+/// 데이터 길이(0xFFFF)만큼 오지 않은 프레임 조각
+const FRAGMENT: [u8; 10] = [0xFA, 0x01, 0x00, 0xDD, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00];
+
+fn buffer_fragment(parsers: &mut SenderParsers, addr: SocketAddr) -> usize {
+    let parser = parsers
+        .get_mut(addr, CompanyInfo::KanaviMobility)
+        .expect("kanavi driver");
+    assert!(matches!(
+        parser.parse(Ipv4Addr::LOCALHOST, &FRAGMENT),
+        ParseOutcome::NeedMoreData
+    ));
+    parser.buffered_len()
+}
+
+#[test]
+fn parser_buffers_are_kept_per_sender() {
+    let mut parsers = SenderParsers::default();
+    assert!(parsers.is_empty());
+    assert_eq!(buffer_fragment(&mut parsers, addr(200)), FRAGMENT.len());
+    assert_eq!(buffer_fragment(&mut parsers, addr(200)), FRAGMENT.len() * 2);
+    // 다른 송신 주소의 조각은 이어 붙지 않음
+    assert_eq!(buffer_fragment(&mut parsers, addr(201)), FRAGMENT.len());
+    assert_eq!(parsers.len(), 2);
+
+    // 한 송신 주소의 버퍼를 비워도 다른 송신 주소의 조각은 유지
+    parsers
+        .get_mut(addr(200), CompanyInfo::KanaviMobility)
+        .unwrap()
+        .reset();
+    let buffered = parsers.buffered();
+    assert_eq!(buffered[&addr(200)], 0);
+    assert_eq!(buffered[&addr(201)], FRAGMENT.len());
+}
+
+#[test]
+fn idle_sender_parsers_are_evicted() {
+    let mut parsers = SenderParsers::default();
+    buffer_fragment(&mut parsers, addr(200));
+    buffer_fragment(&mut parsers, addr(201));
+    assert_eq!(parsers.evict_idle(Duration::from_millis(50)), 0);
+
+    std::thread::sleep(Duration::from_millis(100));
+    buffer_fragment(&mut parsers, addr(201));
+    assert_eq!(parsers.evict_idle(Duration::from_millis(50)), 1);
+    assert_eq!(parsers.len(), 1);
+    assert_eq!(
+        parsers.buffered(),
+        HashMap::from([(addr(201), FRAGMENT.len() * 2)])
+    );
+}
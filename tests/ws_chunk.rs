@@ -0,0 +1,100 @@
+//! 큰 응답 조각 전송 테스트
+//!
+//! 크기 제한을 넘는 응답을 `chunk` 메시지로 나누고 `ChunkAssembler` 로 다시 합쳐
+//! 원래 메시지와 같은지, 순서가 어긋난 조각을 거부하는지 확인합니다.
+
+use axum::extract::ws::Message;
+use lidar_server::ws::chunk::{split, ChunkAssembler, ChunkBody};
+use lidar_server::ws::protocol::{Envelope, EnvelopeKind};
+use serde_json::json;
+
+/// 조각 메시지에서 조각 내용 꺼내기
+fn chunks(messages: &[Message]) -> Vec<ChunkBody> {
+    messages
+        .iter()
+        .map(|message| {
+            let Message::Text(text) = message else {
+                panic!("chunk must be a text message");
+            };
+            let envelope: Envelope = serde_json::from_str(text.as_str()).unwrap();
+            assert_eq!(envelope.kind, EnvelopeKind::Chunk);
+            assert_eq!(envelope.command, "teaching");
+            assert_eq!(envelope.id, Some(json!(9)));
+            envelope.chunk.unwrap()
+        })
+        .collect()
+}
+
+fn reassemble(chunks: &[ChunkBody]) -> Vec<u8> {
+    let mut assembler = ChunkAssembler::default();
+    let (last, rest) = chunks.split_last().unwrap();
+    for chunk in rest {
+        assert_eq!(assembler.push(chunk), Ok(None));
+    }
+    assembler
+        .push(last)
+        .unwrap()
+        .expect("last chunk completes message")
+}
+
+#[test]
+fn small_message_is_not_split() {
+    let message = Message::Text("{\"type\":\"response\"}".into());
+    let messages = split(message.clone(), 1024, Some(&json!(9)), "teaching");
+    assert_eq!(messages, vec![message]);
+}
+
+#[test]
+fn text_message_round_trips() {
+    // 여러 byte 문자가 조각 경계에 걸리도록 구성
+    let text = "티칭 영역 ".repeat(500);
+    let messages = split(
+        Message::Text(text.clone().into()),
+        1000,
+        Some(&json!(9)),
+        "teaching",
+    );
+    let chunks = chunks(&messages);
+    assert!(chunks.len() > 1);
+    for (index, chunk) in chunks.iter().enumerate() {
+        assert_eq!(chunk.part, index + 1);
+        assert_eq!(chunk.parts, chunks.len());
+        assert_eq!(chunk.size, text.len());
+        assert!(!chunk.base64);
+        assert!(chunk.data.len() <= 1000);
+    }
+    assert_eq!(reassemble(&chunks), text.as_bytes());
+}
+
+#[test]
+fn binary_message_round_trips() {
+    let data: Vec<u8> = (0..5000u32).map(|i| (i % 251) as u8).collect();
+    let messages = split(
+        Message::Binary(data.clone().into()),
+        1024,
+        Some(&json!(9)),
+        "teaching",
+    );
+    let chunks = chunks(&messages);
+    assert_eq!(chunks.len(), 5);
+    assert!(chunks.iter().all(|chunk| chunk.base64));
+    assert_eq!(reassemble(&chunks), data);
+}
+
+#[test]
+fn out_of_order_chunks_are_rejected() {
+    let text = "x".repeat(3000);
+    let chunks = chunks(&split(
+        Message::Text(text.into()),
+        1024,
+        Some(&json!(9)),
+        "teaching",
+    ));
+
+    let mut assembler = ChunkAssembler::default();
+    assert!(assembler.push(&chunks[1]).is_err());
+    assert_eq!(assembler.push(&chunks[0]), Ok(None));
+    assert!(assembler.push(&chunks[2]).is_err());
+    // 처음 조각부터 다시 받으면 정상 처리
+    assert_eq!(reassemble(&chunks).len(), 3000);
+}
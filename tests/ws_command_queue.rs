@@ -0,0 +1,114 @@
+//! LiDAR 별 설정 명령 대기열 테스트
+//!
+//! LiDAR 마다 한 번에 하나의 명령만 진행하고, 대기 중인 요청은 앞에 남은 명령 수(`command_queued` 의
+//! `position`)를 받으며, 대기 요청이 `max_pending_commands` 를 넘거나 0 일 때 진행 중이면 거부하는지 확인합니다.
+
+use lidar_server::lidar::LiDARKey;
+use lidar_server::ws::queue::{CommandQueue, CommandQueueStatus, CommandTurn};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use std::time::Duration;
+
+fn key(last: u8) -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, last), 5000, 1, 0)
+}
+
+/// 서버의 대기 루프처럼 순서가 바뀔 때마다 `position` 을 기록하며 차례를 기다림
+async fn wait_turn(mut turn: CommandTurn) -> (CommandTurn, Vec<usize>) {
+    let mut positions = vec![turn.position()];
+    while turn.position() > 0 {
+        let position = turn.position();
+        while turn.position() == position {
+            turn.changed().await;
+        }
+        positions.push(turn.position());
+    }
+    (turn, positions)
+}
+
+#[test]
+fn one_command_in_flight_per_lidar() {
+    let queue = CommandQueue::new(4);
+    let first = queue.enter(key(200)).unwrap();
+    let second = queue.enter(key(200)).unwrap();
+    // 다른 LiDAR 의 명령은 기다리지 않음
+    let other = queue.enter(key(201)).unwrap();
+    assert_eq!(first.position(), 0);
+    assert_eq!(second.position(), 1);
+    assert_eq!(other.position(), 0);
+
+    drop(first);
+    assert_eq!(second.position(), 0);
+    assert_eq!(other.position(), 0);
+
+    // 대기 중인 요청이 먼저 끊겨도 뒤의 요청이 앞으로 당겨짐
+    let third = queue.enter(key(200)).unwrap();
+    let fourth = queue.enter(key(200)).unwrap();
+    assert_eq!((third.position(), fourth.position()), (1, 2));
+    drop(third);
+    assert_eq!(fourth.position(), 1);
+    drop(second);
+    assert_eq!(fourth.position(), 0);
+}
+
+#[tokio::test]
+async fn queued_requests_see_their_position() {
+    let queue = CommandQueue::new(4);
+    let first = queue.enter(key(200)).unwrap();
+    let second = queue.enter(key(200)).unwrap();
+    let third = queue.enter(key(200)).unwrap();
+
+    let waiter = tokio::spawn(wait_turn(third));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!waiter.is_finished());
+    drop(first);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!waiter.is_finished());
+    drop(second);
+
+    let (turn, positions) = tokio::time::timeout(Duration::from_secs(1), waiter)
+        .await
+        .expect("turn after the commands ahead finish")
+        .unwrap();
+    assert_eq!(positions, vec![2, 1, 0]);
+
+    let status = CommandQueueStatus {
+        key: key(200),
+        command: "set_config".to_string(),
+        position: turn.position(),
+    };
+    let status = serde_json::to_value(&status).unwrap();
+    assert_eq!(status["command"], json!("set_config"));
+    assert_eq!(status["position"], json!(0));
+    assert_eq!(status["key"]["ip"], json!("192.168.123.200"));
+}
+
+#[test]
+fn rejects_when_pending_limit_is_reached() {
+    let queue = CommandQueue::new(2);
+    let _running = queue.enter(key(200)).unwrap();
+    let _first = queue.enter(key(200)).unwrap();
+    let second = queue.enter(key(200)).unwrap();
+    let error = queue.enter(key(200)).err().expect("queue is full");
+    assert!(error.contains("busy"), "{}", error);
+    assert!(error.contains("2 commands pending"), "{}", error);
+
+    // 다른 LiDAR 의 대기열은 영향 없음
+    assert!(queue.enter(key(201)).is_ok());
+
+    // 대기 요청이 빠지면 다시 받음
+    drop(second);
+    assert!(queue.enter(key(200)).is_ok());
+}
+
+#[test]
+fn zero_pending_rejects_while_busy() {
+    let queue = CommandQueue::new(0);
+    let running = queue.enter(key(200)).unwrap();
+    assert_eq!(running.position(), 0);
+    assert!(queue.enter(key(200)).is_err());
+
+    drop(running);
+    let next = queue.enter(key(200)).unwrap();
+    assert_eq!(next.position(), 0);
+}
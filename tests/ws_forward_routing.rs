@@ -0,0 +1,113 @@
+//! WebSocket → LiDAR 명령 전달 대상 테스트
+//!
+//! 클라이언트가 보낸 명령 프레임을 장치 저장소의 LiDAR 로만 전달하는지 확인합니다.
+//! 등록되지 않았거나 오프라인인 LiDAR 대상 명령과 명령 프레임이 아닌 데이터는 에러로 거부하고,
+//! 같은 제품 라인/ID 의 LiDAR 가 여러 주소에 있거나 전체 대상(0xFF) 명령이면 온라인 LiDAR 마다 현재 송신 주소로 보냅니다.
+
+use lidar_server::lidar::kanavi_mobility::command::{
+    BROADCAST_LIDAR_ID, BROADCAST_PRODUCT_LINE, PARAM_MOTOR_SPEED_SET,
+};
+use lidar_server::lidar::kanavi_mobility::KanaviFrameBuilder;
+use lidar_server::lidar::{CompanyInfo, LiDARRegistry, LiDARStatus};
+use lidar_server::ws::routing::forward_targets;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+fn source(last: u8) -> SocketAddrV4 {
+    SocketAddrV4::new(Ipv4Addr::new(192, 168, 123, last), 5000)
+}
+
+/// (송신 주소, 제품 라인, ID, 온라인 여부) 목록으로 만든 장치 목록
+async fn lidars(devices: &[(SocketAddrV4, u8, u8, bool)]) -> Vec<LiDARStatus> {
+    let registry = LiDARRegistry::default();
+    let mut lidars = Vec::new();
+    for &(source, product_line, lidar_id, online) in devices {
+        let key = registry
+            .update(
+                CompanyInfo::KanaviMobility,
+                source,
+                product_line,
+                lidar_id,
+                None,
+                None,
+            )
+            .await;
+        lidars.push(LiDARStatus {
+            info: registry.get(&key).await.unwrap(),
+            online,
+            frame_rate: None,
+            motor_speed: None,
+        });
+    }
+    lidars
+}
+
+fn motor_speed_set(product_line: u8, lidar_id: u8) -> Vec<u8> {
+    KanaviFrameBuilder::new(product_line, lidar_id)
+        .motor_speed_set(2)
+        .build()
+}
+
+#[tokio::test]
+async fn routes_to_registered_address() {
+    let lidars = lidars(&[(source(200), 3, 0, true), (source(201), 3, 1, true)]).await;
+    let targets = forward_targets(&motor_speed_set(3, 1), &lidars).unwrap();
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].0, lidars[1].info.key);
+    assert_eq!(targets[0].1, SocketAddr::V4(source(201)));
+}
+
+#[tokio::test]
+async fn rejects_unknown_and_offline_lidars() {
+    let lidars = lidars(&[(source(200), 3, 0, true), (source(201), 3, 1, false)]).await;
+
+    let unknown = forward_targets(&motor_speed_set(3, 7), &lidars).unwrap_err();
+    assert!(unknown.starts_with("Unknown LiDAR"), "{}", unknown);
+    let offline = forward_targets(&motor_speed_set(3, 1), &lidars).unwrap_err();
+    assert!(offline.starts_with("LiDAR offline"), "{}", offline);
+    assert!(forward_targets(&motor_speed_set(3, 0), &[]).is_err());
+}
+
+#[tokio::test]
+async fn rejects_invalid_frames() {
+    let lidars = lidars(&[(source(200), 3, 0, true)]).await;
+    let frame = motor_speed_set(3, 0);
+
+    assert!(forward_targets(b"{\"command\": \"unknown\"}", &lidars).is_err());
+    assert!(forward_targets(&frame[..frame.len() - 1], &lidars).is_err());
+    let mut corrupted = frame.clone();
+    corrupted[7] ^= 0x01;
+    assert!(forward_targets(&corrupted, &lidars).is_err());
+    let mut trailing = frame.clone();
+    trailing.push(0);
+    assert!(forward_targets(&trailing, &lidars).is_err());
+    assert_eq!(frame[4], PARAM_MOTOR_SPEED_SET);
+    assert!(forward_targets(&frame, &lidars).is_ok());
+}
+
+#[tokio::test]
+async fn same_id_at_several_addresses() {
+    let lidars = lidars(&[
+        (source(200), 3, 0, true),
+        (source(201), 3, 0, true),
+        (source(202), 3, 0, false),
+        (source(203), 1, 0, true),
+    ])
+    .await;
+
+    let mut targets: Vec<SocketAddr> = forward_targets(&motor_speed_set(3, 0), &lidars)
+        .unwrap()
+        .into_iter()
+        .map(|(_, target)| target)
+        .collect();
+    targets.sort();
+    assert_eq!(
+        targets,
+        vec![SocketAddr::V4(source(200)), SocketAddr::V4(source(201))]
+    );
+
+    // 전체 대상 명령은 온라인 LiDAR 모두
+    let broadcast = motor_speed_set(BROADCAST_PRODUCT_LINE, BROADCAST_LIDAR_ID);
+    assert_eq!(forward_targets(&broadcast, &lidars).unwrap().len(), 3);
+    let product_line = motor_speed_set(1, BROADCAST_LIDAR_ID);
+    assert_eq!(forward_targets(&product_line, &lidars).unwrap().len(), 1);
+}
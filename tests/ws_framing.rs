@@ -0,0 +1,52 @@
+//! binary WS 메시지 CRC32 봉투 테스트
+//!
+//! 클라이언트 SDK 가 같은 규칙으로 검증할 수 있도록 헤더 배치와 손상/잘림 검출을 확인합니다.
+
+use lidar_server::ws::framing::{unwrap, wrap, BinaryFraming, HEADER_LEN, MAGIC, VERSION};
+
+#[test]
+fn wrap_layout_matches_documented_header() {
+    // CRC-32(IEEE) 표준 검사값
+    let frame = wrap(b"123456789");
+    assert_eq!(frame.len(), HEADER_LEN + 9);
+    assert_eq!(frame[..4], MAGIC);
+    assert_eq!(frame[4], VERSION);
+    assert_eq!(frame[5..9], 9u32.to_be_bytes());
+    assert_eq!(frame[9..13], 0xCBF4_3926u32.to_be_bytes());
+    assert_eq!(&frame[HEADER_LEN..], b"123456789");
+}
+
+#[test]
+fn unwrap_returns_original_payload() {
+    for payload in [&b""[..], &[0x01, 0x02, 0x03], &[0xAB; 4096]] {
+        assert_eq!(unwrap(&wrap(payload)), Ok(payload));
+    }
+}
+
+#[test]
+fn unwrap_rejects_corrupted_frames() {
+    let frame = wrap(&[0x10; 64]);
+
+    for i in 0..frame.len() {
+        let mut corrupted = frame.clone();
+        corrupted[i] ^= 0x01;
+        assert!(unwrap(&corrupted).is_err(), "bit flip at {} accepted", i);
+    }
+    for len in 0..frame.len() {
+        assert!(
+            unwrap(&frame[..len]).is_err(),
+            "truncation to {} accepted",
+            len
+        );
+    }
+    let mut extended = frame.clone();
+    extended.push(0x00);
+    assert!(unwrap(&extended).is_err());
+}
+
+#[test]
+fn framing_mode_wraps_only_when_enabled() {
+    assert_eq!(BinaryFraming::default(), BinaryFraming::None);
+    assert_eq!(BinaryFraming::None.wrap(b"data"), None);
+    assert_eq!(BinaryFraming::Crc32.wrap(b"data"), Some(wrap(b"data")));
+}
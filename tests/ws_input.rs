@@ -0,0 +1,242 @@
+//! WebSocket 입력 경로 fuzz 테스트
+//!
+//! 임의의 바이트/변형된 요청을 입력 검증, 명령 변환, 응답 직렬화에 넣어 panic 없이
+//! 구조화된 결과(요청/전달/에러)가 나오는지 확인합니다.
+
+use lidar_server::ws::input::{validate_binary, validate_text, Inbound};
+use lidar_server::ws::message::{
+    DiscoverRequest, RequestError, WsRequest, DEFAULT_DISCOVER_TIMEOUT_MS, MAX_DISCOVER_TIMEOUT_MS,
+};
+use lidar_server::ws::protocol::{ErrorCode, ProtocolVersion, Reply};
+
+const MAX_MESSAGE_SIZE: usize = 64 * 1024;
+const ITERATIONS: usize = 20_000;
+
+const SEEDS: [&str; 8] = [
+    r#"{"command":"lidar_list"}"#,
+    r#"{"id":1,"command":"hello","data":{"protocol_version":2}}"#,
+    r#"{"id":"a","command":"subscribe","data":{"lidars":[{"ip":"192.168.123.200","port":5000,"product_line":3,"lidar_id":0}]}}"#,
+    r#"{"command":"subscribe"}"#,
+    r#"{"command":"unsubscribe","data":null}"#,
+    r#"{"command":"get_schema"}"#,
+    r#"{"command":"unknown","data":[1,2,3]}"#,
+    r#"[1,2,3]"#,
+];
+
+/// 재현 가능한 xorshift 난수 생성기
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+}
+
+fn mutate(rng: &mut Rng, seed: &str) -> Vec<u8> {
+    let mut data = seed.as_bytes().to_vec();
+    for _ in 0..=rng.below(4) {
+        match rng.below(4) {
+            0 if !data.is_empty() => {
+                let i = rng.below(data.len());
+                data[i] = rng.next() as u8;
+            }
+            1 => {
+                let i = rng.below(data.len() + 1);
+                data.insert(i, b"{}[]\":,0-9ez\\"[rng.below(13)]);
+            }
+            2 if !data.is_empty() => {
+                let i = rng.below(data.len());
+                data.truncate(i);
+            }
+            _ => {
+                let i = rng.below(data.len() + 1);
+                let len = rng.below(8);
+                let extra = rng.bytes(len);
+                data.splice(i..i, extra);
+            }
+        }
+    }
+    data
+}
+
+/// 입력 검증 결과를 명령 변환/응답 직렬화까지 진행
+fn exercise(input: Inbound, version: ProtocolVersion) {
+    match input {
+        Inbound::Request(request) => {
+            let reply = match WsRequest::parse(&request) {
+                Ok(_) => return,
+                Err(RequestError::UnknownCommand) => {
+                    Reply::error(&request, ErrorCode::UnknownCommand, "unknown")
+                }
+                Err(RequestError::Invalid(message)) => {
+                    Reply::error(&request, ErrorCode::InvalidRequest, &message)
+                }
+            };
+            reply.to_json(version).expect("error reply must serialize");
+        }
+        Inbound::Forward(data) => assert!(data.len() <= MAX_MESSAGE_SIZE),
+        Inbound::Reject { id, code, message } => {
+            let reply = Reply {
+                command: String::new(),
+                id,
+                result: Err((code, message)),
+            };
+            reply.to_json(version).expect("reject reply must serialize");
+        }
+    }
+}
+
+#[test]
+fn random_text_never_panics() {
+    let mut rng = Rng(0x5eed_1234_abcd_ef01);
+    for _ in 0..ITERATIONS {
+        let data = if rng.below(2) == 0 {
+            let len = rng.below(256);
+            rng.bytes(len)
+        } else {
+            let seed = SEEDS[rng.below(SEEDS.len())];
+            mutate(&mut rng, seed)
+        };
+        let text = String::from_utf8_lossy(&data);
+        for version in [ProtocolVersion::V1, ProtocolVersion::V2] {
+            exercise(validate_text(&text, version, MAX_MESSAGE_SIZE), version);
+        }
+    }
+}
+
+#[test]
+fn random_binary_is_forwarded_without_utf8_assumption() {
+    let mut rng = Rng(0x0bad_f00d_dead_beef);
+    for _ in 0..ITERATIONS {
+        let len = rng.below(512);
+        let data = rng.bytes(len);
+        match validate_binary(&data, MAX_MESSAGE_SIZE) {
+            Inbound::Forward(forward) => assert_eq!(forward, data),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn oversized_message_is_rejected() {
+    let text = "a".repeat(MAX_MESSAGE_SIZE + 1);
+    for version in [ProtocolVersion::V1, ProtocolVersion::V2] {
+        assert!(matches!(
+            validate_text(&text, version, MAX_MESSAGE_SIZE),
+            Inbound::Reject {
+                code: ErrorCode::MessageTooLarge,
+                ..
+            }
+        ));
+    }
+    assert!(matches!(
+        validate_binary(text.as_bytes(), MAX_MESSAGE_SIZE),
+        Inbound::Reject {
+            code: ErrorCode::MessageTooLarge,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn v2_rejects_invalid_json_and_unknown_fields() {
+    assert!(matches!(
+        validate_text("{not json", ProtocolVersion::V2, MAX_MESSAGE_SIZE),
+        Inbound::Reject {
+            code: ErrorCode::InvalidJson,
+            ..
+        }
+    ));
+    match validate_text(
+        r#"{"id":7,"command":"lidar_list","extra":true}"#,
+        ProtocolVersion::V2,
+        MAX_MESSAGE_SIZE,
+    ) {
+        Inbound::Reject { id, code, .. } => {
+            assert_eq!(code, ErrorCode::InvalidRequest);
+            assert_eq!(id, Some(serde_json::json!(7)));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn v1_forwards_non_request_text() {
+    match validate_text("{not json", ProtocolVersion::V1, MAX_MESSAGE_SIZE) {
+        Inbound::Forward(data) => assert_eq!(data, b"{not json"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn invalid_ip_and_unknown_data_fields_are_invalid_requests() {
+    for text in [
+        r#"{"command":"subscribe","data":{"lidars":[{"ip":"300.1.1.1","port":1,"product_line":0,"lidar_id":0}]}}"#,
+        r#"{"command":"subscribe","data":{"lidars":null,"extra":1}}"#,
+        r#"{"command":"hello","data":{"protocol_version":2,"extra":1}}"#,
+        r#"{"command":"hello"}"#,
+        r#"{"command":"subscribe","data":{"roi":{"type":"box","min":[1.0,0.0],"max":[0.0,1.0]}}}"#,
+        r#"{"command":"subscribe","data":{"roi":{"type":"polygon","points":[[0.0,0.0],[1.0,0.0]]}}}"#,
+        r#"{"command":"subscribe","data":{"roi":{"type":"circle","radius":1.0}}}"#,
+        r#"{"command":"diagnostics"}"#,
+        r#"{"command":"admin_disconnect","data":"not-a-uuid"}"#,
+        r#"{"command":"admin_discover","data":{"timeout":1000}}"#,
+    ] {
+        let Inbound::Request(request) = validate_text(text, ProtocolVersion::V1, MAX_MESSAGE_SIZE)
+        else {
+            panic!("not a request: {}", text);
+        };
+        assert!(
+            matches!(WsRequest::parse(&request), Err(RequestError::Invalid(_))),
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+fn admin_discover_timeout() {
+    for (text, timeout_ms) in [
+        (
+            r#"{"command":"admin_discover"}"#,
+            Ok(DEFAULT_DISCOVER_TIMEOUT_MS),
+        ),
+        (
+            r#"{"command":"admin_discover","data":{"timeout_ms":5000}}"#,
+            Ok(5000),
+        ),
+        (
+            r#"{"command":"admin_discover","data":{"timeout_ms":0}}"#,
+            Err(()),
+        ),
+    ] {
+        let Inbound::Request(request) = validate_text(text, ProtocolVersion::V1, MAX_MESSAGE_SIZE)
+        else {
+            panic!("not a request: {}", text);
+        };
+        let Ok(WsRequest::AdminDiscover(discover)) = WsRequest::parse(&request) else {
+            panic!("not admin_discover: {}", text);
+        };
+        assert_eq!(
+            discover.unwrap_or_default().timeout_ms().map_err(|_| ()),
+            timeout_ms,
+            "{}",
+            text
+        );
+    }
+    let too_long = DiscoverRequest {
+        timeout_ms: Some(MAX_DISCOVER_TIMEOUT_MS + 1),
+    };
+    assert!(too_long.timeout_ms().is_err());
+}
@@ -0,0 +1,51 @@
+//! JSON 숫자 자릿수 제한 테스트
+//!
+//! `precision` 을 설정하면 f32 좌표가 소수점 아래 자릿수로 반올림되어 JSON 크기가 줄어들고,
+//! 정수 값과 설정하지 않은 경우의 출력은 바뀌지 않는지 확인합니다.
+
+use lidar_server::lidar::{Point, PointCloud};
+use lidar_server::ws::to_json_string;
+use serde_json::json;
+
+#[test]
+fn floats_are_rounded() {
+    let value = json!({ "time_ms": 1_700_000_000_123u64, "x": 0.1f32, "y": -2.00049, "z": 3.0 });
+    assert_eq!(
+        to_json_string(&value, Some(3)).unwrap(),
+        r#"{"time_ms":1700000000123,"x":0.1,"y":-2.0,"z":3.0}"#
+    );
+    assert_eq!(
+        to_json_string(&value, None).unwrap(),
+        serde_json::to_string(&value).unwrap()
+    );
+}
+
+#[test]
+fn point_cloud_shrinks() {
+    let cloud = PointCloud {
+        points: (0..400)
+            .map(|i| {
+                let angle = i as f32 * 0.25f32.to_radians();
+                Point {
+                    x: angle.cos() * 12.34,
+                    y: angle.sin() * 12.34,
+                    z: 0.2,
+                }
+            })
+            .collect(),
+    };
+    let full = to_json_string(&cloud, None).unwrap();
+    let rounded = to_json_string(&cloud, Some(3)).unwrap();
+
+    // 필드 이름과 구분 기호는 그대로이므로 좌표 자릿수만큼(15% 이상) 줄어듦
+    assert!(
+        rounded.len() * 100 <= full.len() * 85,
+        "{} -> {}",
+        full.len(),
+        rounded.len()
+    );
+    let parsed: PointCloud = serde_json::from_str(&rounded).unwrap();
+    for (a, b) in parsed.points.iter().zip(&cloud.points) {
+        assert!((a.x - b.x).abs() <= 5e-4 && (a.y - b.y).abs() <= 5e-4);
+    }
+}
@@ -0,0 +1,32 @@
+//! REST 설정 명령 테스트
+//!
+//! `POST /lidars/{ip}/{port}/{product_line}/{lidar_id}/command` 가 OpenAPI 문서에 있고,
+//! 요청 본문이 WebSocket `set_config` 명령의 `data` 와 같은 필드(LiDAR 키 제외)만 받는지 확인합니다.
+
+use lidar_server::ws::rest::{ApiDoc, LiDARCommand};
+use serde_json::json;
+use utoipa::OpenApi;
+
+#[test]
+fn command_path_is_documented() {
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let path = &doc["paths"]["/lidars/{ip}/{port}/{product_line}/{lidar_id}/command"];
+    assert!(path["post"].is_object());
+    assert!(doc["components"]["schemas"]["LiDARCommand"].is_object());
+}
+
+#[test]
+fn command_body_matches_set_config() {
+    let command: LiDARCommand =
+        serde_json::from_value(json!({ "param": 160, "data": [3] })).unwrap();
+    assert_eq!((command.param, command.data), (160, vec![3]));
+    assert!(!command.validate_only);
+
+    // LiDAR 키는 경로에서만 받음
+    assert!(serde_json::from_value::<LiDARCommand>(json!({
+        "lidar": { "ip": "192.168.123.200", "port": 5000, "product_line": 3, "lidar_id": 0 },
+        "param": 160,
+        "data": [3]
+    }))
+    .is_err());
+}
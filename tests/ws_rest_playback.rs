@@ -0,0 +1,50 @@
+//! REST 녹화 재생 제어 테스트
+//!
+//! `GET`/`POST /playback` 가 OpenAPI 문서에 있고, 요청 본문이 WebSocket `playback` 명령의 `data` 와
+//! 같은 형식(재생/일시 정지/탐색/속도 변경)으로 읽히는지 확인합니다.
+
+use lidar_server::ws::message::PlaybackRequest;
+use lidar_server::ws::rest::ApiDoc;
+use serde_json::{json, Value};
+use utoipa::OpenApi;
+
+fn request(value: Value) -> Result<PlaybackRequest, serde_json::Error> {
+    serde_json::from_value(value)
+}
+
+#[test]
+fn playback_paths_are_documented() {
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let path = &doc["paths"]["/playback"];
+    assert!(path["get"].is_object());
+    assert!(path["post"].is_object());
+    assert!(doc["components"]["schemas"]["PlaybackRequest"].is_object());
+    assert!(doc["components"]["schemas"]["PlaybackStatus"].is_object());
+}
+
+#[test]
+fn playback_body_matches_ws_command() {
+    let start = request(json!({ "action": "start", "name": "session.lrec" }));
+    let Ok(PlaybackRequest::Start { name, speed }) = start else {
+        panic!("start request");
+    };
+    // 속도를 생략하면 1.0
+    assert_eq!((name.as_str(), speed), ("session.lrec", 1.0));
+
+    let seek = request(json!({ "action": "seek", "position_ms": 10000 }));
+    assert!(matches!(
+        seek,
+        Ok(PlaybackRequest::Seek { position_ms: 10000 })
+    ));
+
+    let speed = request(json!({ "action": "speed", "speed": 0.5 }));
+    let Ok(PlaybackRequest::Speed { speed }) = speed else {
+        panic!("speed request");
+    };
+    assert_eq!(speed, 0.5);
+
+    let pause = request(json!({ "action": "pause" }));
+    assert!(matches!(pause, Ok(PlaybackRequest::Pause)));
+
+    assert!(request(json!({ "action": "rewind" })).is_err());
+}
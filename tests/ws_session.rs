@@ -0,0 +1,92 @@
+//! 클라이언트 세션 저장 테스트
+//!
+//! 세션 토큰별 구독 설정이 파일에 저장되어 저장소를 다시 읽어도 복원되는지,
+//! 구독 해제 시 삭제되고 최대 세션 수를 넘으면 오래된 세션부터 삭제되는지 확인합니다.
+
+use lidar_server::lidar::LiDARKey;
+use lidar_server::ws::client::Subscription;
+use lidar_server::ws::session::{validate_session_token, SessionStore, MAX_SESSION_TOKEN_LEN};
+use lidar_server::ws::{SessionConfig, WireEncoding};
+use std::net::Ipv4Addr;
+use std::path::PathBuf;
+
+fn config(name: &str, max_sessions: usize) -> SessionConfig {
+    let file = std::env::temp_dir().join(format!(
+        "lidar-sessions-{}-{}.json",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&file);
+    SessionConfig {
+        enabled: true,
+        file,
+        max_sessions,
+    }
+}
+
+fn cleanup(file: PathBuf) {
+    let _ = std::fs::remove_file(file);
+}
+
+fn subscription() -> Subscription {
+    Subscription {
+        lidars: Some(vec![LiDARKey::new(
+            Ipv4Addr::new(192, 168, 123, 200),
+            5000,
+            3,
+            0,
+        )]),
+        encoding: WireEncoding::Json,
+        precision: Some(3),
+        preset: Some("dashboard".to_string()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn session_survives_reload() {
+    let config = config("reload", 16);
+    let sessions = SessionStore::load(config.clone()).unwrap();
+    assert!(sessions.get("dashboard-1").await.is_none());
+    sessions
+        .save("dashboard-1", Some(subscription()))
+        .await
+        .unwrap();
+
+    // 서버를 다시 시작한 것처럼 파일에서 다시 읽음
+    let reloaded = SessionStore::load(config.clone()).unwrap();
+    let restored = reloaded.get("dashboard-1").await.unwrap();
+    assert_eq!(restored.lidars, subscription().lidars);
+    assert_eq!(restored.precision, Some(3));
+    assert_eq!(restored.preset.as_deref(), Some("dashboard"));
+
+    reloaded.save("dashboard-1", None).await.unwrap();
+    assert!(reloaded.is_empty().await);
+    assert!(SessionStore::load(config.clone())
+        .unwrap()
+        .get("dashboard-1")
+        .await
+        .is_none());
+    cleanup(config.file);
+}
+
+#[tokio::test]
+async fn oldest_session_is_evicted() {
+    let config = config("evict", 2);
+    let sessions = SessionStore::load(config.clone()).unwrap();
+    for token in ["a", "b", "c"] {
+        sessions.save(token, Some(subscription())).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+    }
+    assert_eq!(sessions.len().await, 2);
+    assert!(sessions.get("a").await.is_none());
+    assert!(sessions.get("c").await.is_some());
+    cleanup(config.file);
+}
+
+#[test]
+fn token_length_is_checked() {
+    assert!(validate_session_token("dashboard-1").is_ok());
+    assert!(validate_session_token("").is_err());
+    assert!(validate_session_token(&"x".repeat(MAX_SESSION_TOKEN_LEN + 1)).is_err());
+}
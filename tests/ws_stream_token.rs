@@ -0,0 +1,109 @@
+//! 스트림 토큰 테스트
+//!
+//! 발급한 토큰이 한 번만 사용되고 만료되면 사용할 수 없는지, 스트림 연결에서 `hello` 외의 입력을 거부하는지 확인합니다.
+
+use lidar_server::lidar::LiDARKey;
+use lidar_server::ws::client::Subscription;
+use lidar_server::ws::input::{restrict_to_stream, validate_binary, validate_text, Inbound};
+use lidar_server::ws::protocol::{ErrorCode, ProtocolVersion};
+use lidar_server::ws::stream_token::StreamTokens;
+use lidar_server::ws::StreamTokenConfig;
+use std::net::Ipv4Addr;
+
+fn key() -> LiDARKey {
+    LiDARKey::new(Ipv4Addr::new(192, 168, 123, 200), 5000, 3, 0)
+}
+
+fn subscription() -> Subscription {
+    Subscription {
+        lidars: Some(vec![key()]),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn token_is_redeemed_once() {
+    let tokens = StreamTokens::new(StreamTokenConfig::default());
+    let grant = tokens
+        .mint(key(), Some("viewer".to_string()), subscription(), None)
+        .await
+        .unwrap();
+    assert_eq!(grant.url, format!("/ws/stream/{}", grant.token));
+
+    let redeemed = tokens.redeem(&grant.token).await.unwrap();
+    assert_eq!(redeemed.lidars, Some(vec![key()]));
+    assert!(tokens.redeem(&grant.token).await.is_none());
+    assert!(tokens.redeem("unknown").await.is_none());
+}
+
+#[tokio::test]
+async fn expired_token_and_limits() {
+    let tokens = StreamTokens::new(StreamTokenConfig {
+        max_ttl_ms: 1000,
+        max_pending: 1,
+        ..Default::default()
+    });
+    assert!(tokens
+        .mint(key(), None, subscription(), Some(5000))
+        .await
+        .is_err());
+    assert!(tokens
+        .mint(key(), None, subscription(), Some(0))
+        .await
+        .is_err());
+
+    let grant = tokens
+        .mint(key(), None, subscription(), Some(1))
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    assert!(tokens.redeem(&grant.token).await.is_none());
+
+    // 만료된 토큰은 발급할 때 정리되므로 대기 토큰 수 제한에 걸리지 않음
+    tokens
+        .mint(key(), None, subscription(), Some(1))
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    tokens
+        .mint(key(), None, subscription(), Some(1000))
+        .await
+        .unwrap();
+    assert!(tokens
+        .mint(key(), None, subscription(), Some(1000))
+        .await
+        .is_err());
+    assert_eq!(tokens.pending().await, 1);
+}
+
+#[test]
+fn stream_connection_allows_only_hello() {
+    let inbound =
+        |text: &str| restrict_to_stream(validate_text(text, ProtocolVersion::V2, 64 * 1024));
+    let rejected = |inbound: Inbound| {
+        matches!(
+            inbound,
+            Inbound::Reject {
+                code: ErrorCode::InvalidRequest,
+                ..
+            }
+        )
+    };
+
+    assert!(matches!(
+        inbound(r#"{"id":1,"command":"hello","data":{"protocol_version":2}}"#),
+        Inbound::Request(_)
+    ));
+    assert!(rejected(inbound(
+        r#"{"id":2,"command":"hello","data":{"protocol_version":2,"resume":"00000000-0000-0000-0000-000000000000"}}"#
+    )));
+    assert!(rejected(inbound(
+        r#"{"id":2,"command":"hello","data":{"protocol_version":2,"session":"dashboard-1"}}"#
+    )));
+    assert!(rejected(inbound(r#"{"id":3,"command":"subscribe"}"#)));
+    assert!(rejected(inbound(r#"{"id":4,"command":"unsubscribe"}"#)));
+    assert!(rejected(restrict_to_stream(validate_binary(
+        &[0xFA, 0x03, 0x00],
+        64 * 1024
+    ))));
+}
@@ -0,0 +1,92 @@
+//! TypeScript 타입 정의(`/types.d.ts`) 생성 테스트
+//!
+//! JSON Schema/OpenAPI 에서 참조하는 모든 타입이 선언되고, 최상위 메시지 타입이 생성되는지 확인합니다.
+
+use std::collections::HashSet;
+
+use lidar_server::ws::rest::ApiDoc;
+use lidar_server::ws::schema::schemas;
+use lidar_server::ws::typescript::type_definitions;
+use serde_json::Value;
+use utoipa::OpenApi;
+
+/// 생성된 파일에서 선언한 타입 이름
+fn declared(output: &str) -> HashSet<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("export interface ")
+                .or_else(|| line.strip_prefix("export type "))
+        })
+        .filter_map(|rest| rest.split([' ', '=']).next())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 스키마의 모든 `$ref` 대상 이름
+fn references(value: &Value, names: &mut HashSet<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(reference) = map.get("$ref").and_then(Value::as_str) {
+                names.insert(reference.rsplit('/').next().unwrap().to_string());
+            }
+            map.values().for_each(|value| references(value, names));
+        }
+        Value::Array(values) => values.iter().for_each(|value| references(value, names)),
+        _ => {}
+    }
+}
+
+#[test]
+fn declares_root_message_types() {
+    let declared = declared(&type_definitions());
+    for name in [
+        "RequestMessage",
+        "ResponseMessage",
+        "Envelope",
+        "WsRequest",
+        "WsResponse",
+        "WsEvent",
+        "ServerConfig",
+        "HealthReport",
+        "ClientInfo",
+    ] {
+        assert!(declared.contains(name), "{} is not declared", name);
+    }
+}
+
+#[test]
+fn declares_every_referenced_type() {
+    let declared = declared(&type_definitions());
+    let mut referenced = HashSet::new();
+    references(&schemas(), &mut referenced);
+    references(
+        &serde_json::to_value(ApiDoc::openapi()).unwrap(),
+        &mut referenced,
+    );
+    let mut missing: Vec<_> = referenced.difference(&declared).collect();
+    missing.sort();
+    assert!(missing.is_empty(), "undeclared types: {:?}", missing);
+}
+
+#[test]
+fn output_is_balanced() {
+    let output = type_definitions();
+    for (open, close) in [('{', '}'), ('[', ']'), ('(', ')')] {
+        // 문자열 리터럴/주석 밖의 괄호만 비교
+        let code: String = output
+            .lines()
+            .map(str::trim_start)
+            .filter(|line| !line.starts_with("/**") && !line.starts_with('*'))
+            .filter(|line| !line.starts_with("//"))
+            .collect();
+        let code = code.split('"').step_by(2).collect::<String>();
+        assert_eq!(
+            code.matches(open).count(),
+            code.matches(close).count(),
+            "unbalanced {}{}",
+            open,
+            close
+        );
+    }
+}